@@ -94,6 +94,23 @@ pub enum AppError {
     /// **Context**: Field name and specific validation message
     #[error("Validation error: {field} - {message}")]
     ValidationError { field: String, message: String },
+    
+    /// Export Job Not Found Error
+    /// 
+    /// **When**: Polling or downloading an export job that doesn't exist
+    /// (or whose artifact has already been cleaned up by retention)
+    /// **HTTP Status**: 404 Not Found
+    /// **Context**: Export job ID that was requested
+    #[error("Export job not found: {id}")]
+    ExportNotFound { id: Uuid },
+    
+    /// Export Not Ready Error
+    /// 
+    /// **When**: Trying to download an export that hasn't completed yet
+    /// **HTTP Status**: 409 Conflict
+    /// **Context**: Export job ID and its current status
+    #[error("Export job {id} is not ready for download (status: {status})")]
+    ExportNotReady { id: Uuid, status: String },
 }
 
 /// HTTP Response Error Implementation
@@ -168,6 +185,20 @@ impl ResponseError for AppError {
                 "details": message,  // Could be omitted in production for security
                 "code": 500
             })),
+            
+            // 404 Not Found - Export job doesn't exist (or was cleaned up)
+            AppError::ExportNotFound { id } => HttpResponse::NotFound().json(json!({
+                "error": "not_found",
+                "message": format!("Export job with ID {} not found", id),
+                "code": 404
+            })),
+            
+            // 409 Conflict - Export exists but has no downloadable artifact yet
+            AppError::ExportNotReady { id, status } => HttpResponse::Conflict().json(json!({
+                "error": "export_not_ready",
+                "message": format!("Export job {} is not ready for download (status: {})", id, status),
+                "code": 409
+            })),
         }
     }
 }
@@ -0,0 +1,110 @@
+//! # Export HTTP Handlers
+//!
+//! HTTP handling layer for asynchronous exports.
+//!
+//! ## Polling Flow:
+//! 1. `POST /exports` → `202 Accepted` with the pending job and a `Location` header
+//! 2. `GET /exports/{id}` → status and progress; `download_url` once completed
+//! 3. `GET /exports/{id}/download` → the artifact as a file attachment
+//!
+//! ## Key Actix-Web Concepts:
+//! - **202 Accepted**: The request was valid but the work happens later
+//! - **NamedFile**: `actix-files` streams the artifact from disk instead of
+//!   buffering the whole export in memory
+
+use crate::models::CreateExportDto;
+use crate::services::ExportService;
+use actix_files::NamedFile;
+use actix_web::http::header::{self, ContentDisposition, DispositionParam, DispositionType};
+use actix_web::{mime, web, HttpRequest, HttpResponse, ResponseError, Result};
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Export Handler Structure
+///
+/// Groups the export endpoints as associated functions, mirroring `UserHandler`.
+pub struct ExportHandler;
+
+impl ExportHandler {
+    /// Create Export Handler
+    ///
+    /// **HTTP Method**: POST /exports
+    /// **Purpose**: Enqueues a background export of all users
+    ///
+    /// ## HTTP Status Codes:
+    /// - `202 Accepted`: Export job enqueued
+    /// - `400 Bad Request`: Unknown export format
+    pub async fn create_export(
+        data: web::Data<Arc<dyn ExportService>>,
+        payload: web::Json<CreateExportDto>,
+    ) -> Result<HttpResponse> {
+        match data.create_export(payload.into_inner()).await {
+            Ok(job) => Ok(HttpResponse::Accepted()
+                // Point clients at the status endpoint they should poll
+                .insert_header((header::LOCATION, format!("/exports/{}", job.id)))
+                .json(json!({
+                    "success": true,
+                    "data": job
+                }))),
+            Err(e) => Ok(e.error_response()),
+        }
+    }
+
+    /// Get Export Status Handler
+    ///
+    /// **HTTP Method**: GET /exports/{id}
+    /// **Purpose**: Returns status, progress and (when ready) the download URL
+    pub async fn get_export(
+        data: web::Data<Arc<dyn ExportService>>,
+        path: web::Path<Uuid>,
+    ) -> Result<HttpResponse> {
+        let export_id = path.into_inner();
+
+        match data.get_export(export_id).await {
+            Ok(job) => Ok(HttpResponse::Ok().json(json!({
+                "success": true,
+                "data": job
+            }))),
+            Err(e) => Ok(e.error_response()),
+        }
+    }
+
+    /// Download Export Handler
+    ///
+    /// **HTTP Method**: GET /exports/{id}/download
+    /// **Purpose**: Streams the completed artifact as an attachment
+    ///
+    /// ## HTTP Status Codes:
+    /// - `200 OK`: File body
+    /// - `404 Not Found`: Unknown job, or artifact already removed by retention
+    /// - `409 Conflict`: Export still pending/running or it failed
+    pub async fn download_export(
+        req: HttpRequest,
+        data: web::Data<Arc<dyn ExportService>>,
+        path: web::Path<Uuid>,
+    ) -> Result<HttpResponse> {
+        let export_id = path.into_inner();
+
+        let artifact = match data.get_export_artifact(export_id).await {
+            Ok(artifact) => artifact,
+            Err(e) => return Ok(e.error_response()),
+        };
+
+        let content_type: mime::Mime = artifact
+            .format
+            .content_type()
+            .parse()
+            .unwrap_or(mime::APPLICATION_OCTET_STREAM);
+
+        let file = NamedFile::open_async(&artifact.path)
+            .await?
+            .set_content_type(content_type)
+            .set_content_disposition(ContentDisposition {
+                disposition: DispositionType::Attachment,
+                parameters: vec![DispositionParam::Filename(artifact.file_name)],
+            });
+
+        Ok(file.into_response(&req))
+    }
+}
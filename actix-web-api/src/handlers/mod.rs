@@ -1,3 +1,5 @@
 pub mod user_handler;
+pub mod export_handler;
 
-pub use user_handler::*;
\ No newline at end of file
+pub use user_handler::*;
+pub use export_handler::*;
\ No newline at end of file
@@ -19,27 +19,56 @@
 //! - `Middleware`: Cross-cutting concerns like logging and tracing
 
 // Module declarations - these make the modules available to this crate
+//
+// Some modules keep reference API that nothing calls yet (`ValidationUtils`,
+// `UserHandler::new`, `find_by_email`, `get_connection`, the `User` entity
+// re-export). It is there to be read and extended, so those lints are allowed
+// per module here instead of on each item.
+#[allow(dead_code)]
 mod db;         // Database connection management
+#[allow(unused_imports)]
 mod entities;   // SeaORM entity models
 mod errors;     // Custom error types and HTTP error responses
+#[allow(dead_code)]
 mod handlers;   // HTTP request handlers (controllers in MVC terms)
+mod middleware; // Custom Actix-Web middleware
 mod models;     // Domain models and DTOs
+#[allow(dead_code)]
 mod repositories; // Data access layer abstractions
 mod routes;     // Route definitions and configuration
+mod secrets;    // Secret resolution (env, files, Vault)
 mod services;   // Business logic layer
+#[allow(dead_code)]
 mod utils;      // Shared utilities and helpers
 
 // Actix-Web core imports
 use actix_web::{middleware::Logger, web, App, HttpServer};
 // Our application layers
-use repositories::{PostgresUserRepository, UserRepository};
+use repositories::{
+    ExportJobRepository, InMemoryExportJobRepository, PostgresUserRepository, UserRepository,
+};
+use middleware::{ClientMetadataMiddleware, GeoIpDatabase};
 use routes::configure_routes;
-use services::{UserService, UserServiceImpl};
+use secrets::SecretsConfig;
+use services::{
+    spawn_export_cleanup, AuditLog, ExportConfig, ExportService, ExportServiceImpl,
+    TracingAuditLog, UserService, UserServiceImpl,
+};
 // Standard library for shared ownership across threads
 use std::sync::Arc;
 // Tracing middleware for request logging
 use tracing_actix_web::TracingLogger;
 
+/// Application Services
+/// 
+/// All services shared with the Actix workers. Each one is registered as its own
+/// `web::Data<Arc<dyn Trait>>` so handlers only extract what they need.
+struct AppServices {
+    user_service: Arc<dyn UserService>,
+    export_service: Arc<dyn ExportService>,
+    audit_log: Arc<dyn AuditLog>,
+}
+
 /// Dependency Injection Container
 /// 
 /// This function demonstrates the **Dependency Injection** pattern in Rust.
@@ -53,21 +82,43 @@ use tracing_actix_web::TracingLogger;
 /// 
 /// ## Error Handling Pattern:
 /// Database errors are converted to IO errors for the main function
-async fn setup_dependencies() -> std::io::Result<Arc<dyn UserService>> {
+async fn setup_dependencies(export_config: ExportConfig) -> std::io::Result<AppServices> {
+    // Build the secret provider first - sensitive values are never read from env directly
+    let secrets = SecretsConfig::from_env()
+        .and_then(SecretsConfig::build)
+        .map_err(|e| std::io::Error::other(format!("Secrets setup failed: {}", e)))?;
+    
     // Initialize database connection pool
     // This creates a connection pool that can be shared across all requests
-    let db_connection = db::init_db().await.map_err(|e| {
+    let db_connection = db::init_db(secrets.as_ref()).await.map_err(|e| {
         // Convert database errors to IO errors for main function compatibility
-        std::io::Error::new(std::io::ErrorKind::Other, format!("Database connection failed: {}", e))
+        std::io::Error::other(format!("Database connection failed: {}", e))
     })?;
     
     // Create repository layer with PostgreSQL implementation
     // Arc<dyn Trait> allows us to use trait objects for dependency injection
     let user_repository: Arc<dyn UserRepository> = Arc::new(PostgresUserRepository::new(db_connection));
     
-    // Create service layer with injected repository
-    // The service layer doesn't know about the database - it only knows about the repository trait
-    Ok(Arc::new(UserServiceImpl::new(user_repository)))
+    // Export jobs are short-lived bookkeeping, so they live in memory
+    let export_job_repository: Arc<dyn ExportJobRepository> = Arc::new(InMemoryExportJobRepository::new());
+    
+    // Create service layer with injected repositories
+    // The service layer doesn't know about the database - it only knows about the repository traits
+    let user_service: Arc<dyn UserService> = Arc::new(UserServiceImpl::new(user_repository.clone()));
+    let export_service: Arc<dyn ExportService> = Arc::new(ExportServiceImpl::new(
+        export_job_repository,
+        user_repository,
+        export_config,
+    ));
+    
+    // Audit events go to the dedicated `audit` tracing target
+    let audit_log: Arc<dyn AuditLog> = Arc::new(TracingAuditLog);
+    
+    Ok(AppServices {
+        user_service,
+        export_service,
+        audit_log,
+    })
 }
 
 /// Application Entry Point
@@ -86,9 +137,27 @@ async fn main() -> std::io::Result<()> {
     // This sets up tracing for the entire application
     tracing_subscriber::fmt::init();
     
+    // Load environment variables from .env file (if present)
+    // `.ok()` means we don't fail if `.env` doesn't exist (e.g. in containers)
+    dotenvy::dotenv().ok();
+    
+    // Export settings are read once: the service and the retention task share them
+    let export_config = ExportConfig::from_env();
+    let cleanup_interval = export_config.cleanup_interval;
+    
     // Setup dependency injection
     // This creates all our services and repositories
-    let user_service = setup_dependencies().await?;
+    let services = setup_dependencies(export_config).await?;
+    let user_service = services.user_service;
+    let export_service = services.export_service;
+    
+    // Client metadata middleware: User-Agent parsing, optional GeoIP, audit logging
+    // The GeoIP database is loaded once and shared by every worker
+    let client_metadata = ClientMetadataMiddleware::new(GeoIpDatabase::from_env(), services.audit_log);
+    
+    // Start the export retention task
+    // Old export artifacts are deleted periodically in the background
+    spawn_export_cleanup(export_service.clone(), cleanup_interval);
     
     tracing::info!("Starting Actix-Web API server on http://localhost:8080");
     
@@ -101,10 +170,14 @@ async fn main() -> std::io::Result<()> {
             // web::Data wraps our service in application-managed state
             // This allows handlers to access the service via dependency injection
             .app_data(web::Data::new(user_service.clone()))
+            .app_data(web::Data::new(export_service.clone()))
             // Configure all routes
             // This calls our route configuration function
             .configure(configure_routes)
             // Add middleware (applied in reverse order)
+            // ClientMetadataMiddleware runs inside TracingLogger so its span and
+            // audit events can see the request's root span and request ID
+            .wrap(client_metadata.clone())
             // TracingLogger provides detailed request tracing
             .wrap(TracingLogger::default())
             // Logger provides basic request logging
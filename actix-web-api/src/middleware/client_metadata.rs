@@ -0,0 +1,374 @@
+//! # Client Metadata Enrichment Middleware
+//!
+//! This middleware runs before every handler and answers "who is calling?":
+//!
+//! 1. **Network Info**: Client IP (proxy-aware) and the raw peer IP
+//! 2. **User-Agent Parsing**: Browser, version, OS and device category (woothee)
+//! 3. **GeoIP (optional)**: Country and city from a local MaxMind MMDB file
+//!
+//! The result is attached to the request in three places:
+//! - **Request extensions**: handlers take a `ClientMetadata` argument, or read
+//!   `req.extensions().get::<ClientMetadata>()`
+//! - **Tracing span**: A `client_metadata` span wraps the rest of the request, so
+//!   every log line emitted by handlers carries the client fields
+//! - **Audit log**: State-changing requests (POST/PUT/PATCH/DELETE) are recorded
+//!   with their outcome for security forensics, failed ones included
+//!
+//! ## Middleware Order:
+//! Register it *inside* `TracingLogger` (i.e. `.wrap()` it first) so its span is
+//! nested under the request's root span and the request ID is available.
+//!
+//! ## Actix-Web Middleware Pattern:
+//! - `Transform`: Factory that wraps the next service once per worker
+//! - `Service`: The per-request logic, returning a boxed future
+
+use crate::models::{AuditEvent, ClientMetadata};
+use crate::services::AuditLog;
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::{header, Method};
+use actix_web::{Error, HttpMessage};
+use maxminddb::{geoip2, Reader};
+use std::future::{ready, Future, Ready};
+use std::net::{IpAddr, SocketAddr};
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
+use tracing::Instrument;
+use tracing_actix_web::RequestId;
+
+/// Local GeoIP Database
+///
+/// Wraps a MaxMind reader loaded fully into memory. Lookups are lock-free,
+/// so one reader is shared by every worker.
+pub struct GeoIpDatabase {
+    reader: Reader<Vec<u8>>,
+}
+
+impl GeoIpDatabase {
+    /// Opens an MMDB file (e.g. `GeoLite2-City.mmdb`)
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, maxminddb::MaxMindDBError> {
+        Ok(Self {
+            reader: Reader::open_readfile(path)?,
+        })
+    }
+
+    /// Opens the database named by `GEOIP_DB_PATH`, if any
+    ///
+    /// GeoIP is optional: a missing variable disables it silently, and an
+    /// unreadable file disables it with a warning instead of failing startup.
+    pub fn from_env() -> Option<Self> {
+        let path = std::env::var("GEOIP_DB_PATH").ok()?;
+        match Self::open(&path) {
+            Ok(database) => {
+                tracing::info!(path = %path, "GeoIP database loaded");
+                Some(database)
+            }
+            Err(e) => {
+                tracing::warn!(path = %path, error = %e, "GeoIP database unavailable, continuing without it");
+                None
+            }
+        }
+    }
+
+    /// Looks up country ISO code and English city name for an address
+    fn lookup(&self, ip: IpAddr) -> (Option<String>, Option<String>) {
+        let Ok(record) = self.reader.lookup::<geoip2::City>(ip) else {
+            // Private ranges and unknown addresses simply have no location
+            return (None, None);
+        };
+
+        let country = record
+            .country
+            .and_then(|country| country.iso_code)
+            .map(str::to_string);
+        let city = record
+            .city
+            .and_then(|city| city.names)
+            .and_then(|names| names.get("en").map(|name| name.to_string()));
+
+        (country, city)
+    }
+}
+
+/// Client Metadata Middleware Factory
+///
+/// Cheap to clone (everything is behind `Arc`), which is what the
+/// `HttpServer::new` app factory needs since it runs once per worker.
+#[derive(Clone)]
+pub struct ClientMetadataMiddleware {
+    geoip: Option<Arc<GeoIpDatabase>>,
+    audit_log: Arc<dyn AuditLog>,
+}
+
+impl ClientMetadataMiddleware {
+    /// Creates the middleware with an optional GeoIP database and an audit sink
+    pub fn new(geoip: Option<GeoIpDatabase>, audit_log: Arc<dyn AuditLog>) -> Self {
+        Self {
+            geoip: geoip.map(Arc::new),
+            audit_log,
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for ClientMetadataMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = ClientMetadataService<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(ClientMetadataService {
+            service,
+            geoip: self.geoip.clone(),
+            audit_log: Arc::clone(&self.audit_log),
+        }))
+    }
+}
+
+/// Client Metadata Middleware Service
+///
+/// The per-worker service created by `ClientMetadataMiddleware`.
+pub struct ClientMetadataService<S> {
+    service: S,
+    geoip: Option<Arc<GeoIpDatabase>>,
+    audit_log: Arc<dyn AuditLog>,
+}
+
+impl<S> ClientMetadataService<S> {
+    /// Builds the metadata for one request
+    fn extract(&self, req: &ServiceRequest) -> ClientMetadata {
+        let ip = req
+            .connection_info()
+            .realip_remote_addr()
+            .and_then(parse_ip);
+        let peer_ip = req.peer_addr().map(|addr| addr.ip());
+
+        let user_agent = req
+            .headers()
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        let mut metadata = ClientMetadata {
+            ip,
+            peer_ip,
+            ..ClientMetadata::default()
+        };
+
+        if let Some(parsed) = user_agent.as_deref().and_then(|ua| woothee::parser::Parser::new().parse(ua)) {
+            metadata.browser = known(parsed.name);
+            metadata.browser_version = known(parsed.version);
+            metadata.os = known(parsed.os);
+            metadata.device_category = known(parsed.category);
+        }
+        metadata.user_agent = user_agent;
+
+        if let (Some(geoip), Some(ip)) = (&self.geoip, ip) {
+            let (country, city) = geoip.lookup(ip);
+            metadata.country = country;
+            metadata.city = city;
+        }
+
+        metadata
+    }
+}
+
+impl<S, B> Service<ServiceRequest> for ClientMetadataService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let metadata = self.extract(&req);
+
+        // Everything below this span (handlers, services) inherits the client fields
+        let span = tracing::info_span!(
+            "client_metadata",
+            client.ip = ?metadata.ip,
+            client.browser = ?metadata.browser,
+            client.os = ?metadata.os,
+            client.device = ?metadata.device_category,
+            client.country = ?metadata.country,
+        );
+
+        // Only state-changing requests are audited; reads would drown the log
+        let audit = is_audited(req.method()).then(|| {
+            let request_id = req.extensions().get::<RequestId>().map(|id| **id);
+            (request_id, req.method().to_string(), req.path().to_string(), metadata.clone())
+        });
+
+        req.extensions_mut().insert(metadata);
+
+        let audit_log = Arc::clone(&self.audit_log);
+        let fut = self.service.call(req);
+
+        Box::pin(
+            async move {
+                let result = fut.await;
+
+                if let Some((request_id, method, path, client)) = audit {
+                    let status = match &result {
+                        Ok(res) => res.status(),
+                        // Turned into a response further out; record the status it will get
+                        Err(err) => err.as_response_error().status_code(),
+                    };
+                    audit_log
+                        .record(AuditEvent::new(request_id, method, path, status.as_u16(), client))
+                        .await;
+                }
+
+                result
+            }
+            .instrument(span),
+        )
+    }
+}
+
+/// Whether requests with this method are written to the audit log
+fn is_audited(method: &Method) -> bool {
+    matches!(*method, Method::POST | Method::PUT | Method::PATCH | Method::DELETE)
+}
+
+/// Parses `1.2.3.4`, `1.2.3.4:5678` or `[::1]:5678` into an IP address
+fn parse_ip(addr: &str) -> Option<IpAddr> {
+    addr.parse::<IpAddr>()
+        .ok()
+        .or_else(|| addr.parse::<SocketAddr>().ok().map(|socket| socket.ip()))
+}
+
+/// woothee reports unknown fields as "UNKNOWN"; treat those as absent
+fn known(value: &str) -> Option<String> {
+    (!value.is_empty() && value != woothee::woothee::VALUE_UNKNOWN).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::{call_and_read_body_json, call_service, init_service, TestRequest};
+    use actix_web::{error, web, App, HttpResponse};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    const CHROME_ON_WINDOWS: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 \
+        (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+    /// Audit log that keeps events in memory so tests can inspect them
+    #[derive(Default)]
+    struct RecordingAuditLog {
+        events: Mutex<Vec<AuditEvent>>,
+    }
+
+    #[async_trait]
+    impl AuditLog for RecordingAuditLog {
+        async fn record(&self, event: AuditEvent) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+
+    async fn echo_metadata(metadata: ClientMetadata) -> HttpResponse {
+        HttpResponse::Ok().json(metadata)
+    }
+
+    #[actix_web::test]
+    async fn test_user_agent_is_parsed_into_extensions() {
+        let audit_log = Arc::new(RecordingAuditLog::default());
+        let app = init_service(
+            App::new()
+                .wrap(ClientMetadataMiddleware::new(None, audit_log.clone()))
+                .route("/whoami", web::get().to(echo_metadata)),
+        )
+        .await;
+
+        let req = TestRequest::get()
+            .uri("/whoami")
+            .insert_header((header::USER_AGENT, CHROME_ON_WINDOWS))
+            .insert_header(("X-Forwarded-For", "203.0.113.7"))
+            .to_request();
+        let body: serde_json::Value = call_and_read_body_json(&app, req).await;
+
+        assert_eq!(body["browser"], "Chrome");
+        assert_eq!(body["os"], "Windows 10");
+        assert_eq!(body["device_category"], "pc");
+        assert_eq!(body["ip"], "203.0.113.7");
+        assert!(body["country"].is_null());
+
+        // Reads are not audited
+        assert!(audit_log.events.lock().unwrap().is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_state_changing_requests_are_audited() {
+        let audit_log = Arc::new(RecordingAuditLog::default());
+        let app = init_service(
+            App::new()
+                .wrap(ClientMetadataMiddleware::new(None, audit_log.clone()))
+                .route("/users", web::post().to(HttpResponse::Created)),
+        )
+        .await;
+
+        let req = TestRequest::post()
+            .uri("/users")
+            .insert_header((header::USER_AGENT, CHROME_ON_WINDOWS))
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status().as_u16(), 201);
+
+        let events = audit_log.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].method, "POST");
+        assert_eq!(events[0].path, "/users");
+        assert_eq!(events[0].status, 201);
+        assert_eq!(events[0].client.browser.as_deref(), Some("Chrome"));
+    }
+
+    #[actix_web::test]
+    async fn test_failed_requests_are_audited() {
+        let audit_log = Arc::new(RecordingAuditLog::default());
+        let app = init_service(
+            App::new()
+                // Inner middleware that rejects every request with an error
+                .wrap_fn(|_req, _srv| async {
+                    Err::<ServiceResponse, _>(error::ErrorForbidden("denied"))
+                })
+                .wrap(ClientMetadataMiddleware::new(None, audit_log.clone()))
+                .route("/users/1", web::delete().to(HttpResponse::NoContent)),
+        )
+        .await;
+
+        let req = TestRequest::delete().uri("/users/1").to_request();
+        let err = app.call(req).await.expect_err("the inner middleware fails the request");
+        assert_eq!(err.as_response_error().status_code().as_u16(), 403);
+
+        let events = audit_log.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!((events[0].method.as_str(), events[0].status), ("DELETE", 403));
+    }
+
+    #[actix_web::test]
+    async fn test_extractor_fails_without_the_middleware() {
+        let app = init_service(App::new().route("/whoami", web::get().to(echo_metadata))).await;
+
+        let req = TestRequest::get().uri("/whoami").to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status().as_u16(), 500);
+    }
+
+    #[test]
+    fn test_parse_ip_accepts_addresses_with_ports() {
+        assert_eq!(parse_ip("10.0.0.1"), Some("10.0.0.1".parse().unwrap()));
+        assert_eq!(parse_ip("10.0.0.1:8080"), Some("10.0.0.1".parse().unwrap()));
+        assert_eq!(parse_ip("[::1]:8080"), Some("::1".parse().unwrap()));
+        assert_eq!(parse_ip("unknown"), None);
+    }
+}
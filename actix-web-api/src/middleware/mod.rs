@@ -0,0 +1,3 @@
+pub mod client_metadata;
+
+pub use client_metadata::*;
\ No newline at end of file
@@ -0,0 +1,48 @@
+//! # Audit Event Model
+//!
+//! An audit event records a security-relevant action: what was done, to which
+//! resource, with what outcome, and by which client. Events are append-only -
+//! they are never updated after being written.
+
+use crate::models::ClientMetadata;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Audit Event
+///
+/// ## Field Meanings:
+/// - `request_id`: Correlates the event with the request's tracing spans
+/// - `method` / `path` / `status`: The HTTP action and its outcome
+/// - `client`: Enriched client metadata captured by middleware
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditEvent {
+    pub id: Uuid,
+    pub request_id: Option<Uuid>,
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+    pub client: ClientMetadata,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl AuditEvent {
+    /// Creates a new audit event timestamped now
+    pub fn new(
+        request_id: Option<Uuid>,
+        method: String,
+        path: String,
+        status: u16,
+        client: ClientMetadata,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            request_id,
+            method,
+            path,
+            status,
+            client,
+            occurred_at: Utc::now(),
+        }
+    }
+}
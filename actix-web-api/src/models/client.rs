@@ -0,0 +1,59 @@
+//! # Client Metadata Model
+//!
+//! Describes **who is calling** the API, as far as we can tell from the request:
+//! network addresses, the parsed User-Agent and (optionally) a GeoIP location.
+//!
+//! ## Where It Comes From:
+//! The client metadata middleware builds one `ClientMetadata` per request and
+//! stores it in the request extensions. Handlers can extract it like any other
+//! Actix extractor (see the `FromRequest` impl below), and the audit log
+//! records it for security forensics.
+//!
+//! ## Trust Note:
+//! `ip` honours `Forwarded`/`X-Forwarded-For`, which clients can forge unless a
+//! trusted proxy overwrites them. `peer_ip` is the socket address and can't be
+//! spoofed, so both are kept.
+
+use crate::errors::AppError;
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpMessage, HttpRequest};
+use serde::Serialize;
+use std::future::{ready, Ready};
+use std::net::IpAddr;
+
+/// Client Metadata
+///
+/// ## Field Meanings:
+/// - `ip`: Best guess at the real client IP (proxy headers considered)
+/// - `peer_ip`: Address of the TCP peer (the proxy, when there is one)
+/// - `user_agent`: Raw `User-Agent` header value
+/// - `browser` / `browser_version` / `os` / `device_category`: Parsed User-Agent
+/// - `country` / `city`: GeoIP lookup result (only when a database is configured)
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct ClientMetadata {
+    pub ip: Option<IpAddr>,
+    pub peer_ip: Option<IpAddr>,
+    pub user_agent: Option<String>,
+    pub browser: Option<String>,
+    pub browser_version: Option<String>,
+    pub os: Option<String>,
+    pub device_category: Option<String>,
+    pub country: Option<String>,
+    pub city: Option<String>,
+}
+
+/// Extractor: `async fn handler(client: ClientMetadata)`
+///
+/// Reads the copy the middleware stored in the request extensions. A route
+/// outside `ClientMetadataMiddleware` has none, which is a wiring mistake
+/// rather than a client error, so it fails with 500.
+impl FromRequest for ClientMetadata {
+    type Error = AppError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(req.extensions().get::<ClientMetadata>().cloned().ok_or_else(|| AppError::InternalError {
+            message: "ClientMetadata extracted without ClientMetadataMiddleware".to_string(),
+        }))
+    }
+}
@@ -0,0 +1,210 @@
+//! # Export Job Models and DTOs
+//!
+//! This module contains the **domain model** and **DTOs** for asynchronous exports.
+//! Large exports are not generated inside the request/response cycle; instead
+//! the client enqueues a job and polls it until the artifact is ready.
+//!
+//! ## Export Lifecycle:
+//! ```
+//! POST /exports → Pending → Running → Completed → (retention expires) → removed
+//!                                   ↘ Failed
+//! ```
+//!
+//! ## Key Design Patterns:
+//! - **State Machine**: `ExportStatus` models the job lifecycle explicitly
+//! - **DTO Pattern**: The response DTO hides the on-disk artifact path
+//! - **Derived Data**: Progress percentage and download URL are computed, not stored
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Export Output Format
+///
+/// The file formats an export job can produce.
+///
+/// ## Serde Annotations:
+/// - `rename_all = "lowercase"`: Clients send `"csv"` / `"ndjson"`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// Newline-delimited JSON, one object per line
+    Ndjson,
+}
+
+impl ExportFormat {
+    /// File extension used for the generated artifact
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Ndjson => "ndjson",
+        }
+    }
+
+    /// Content type sent when the artifact is downloaded
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Ndjson => "application/x-ndjson",
+        }
+    }
+}
+
+/// Export Job Status
+///
+/// ## State Transitions:
+/// - `Pending`: Job is queued, the background task hasn't started yet
+/// - `Running`: Records are being written to the artifact
+/// - `Completed`: Artifact is ready for download
+/// - `Failed`: Something went wrong; see the job's `error` field
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl ExportStatus {
+    /// Whether the job has reached a final state
+    pub fn is_finished(&self) -> bool {
+        matches!(self, ExportStatus::Completed | ExportStatus::Failed)
+    }
+}
+
+impl fmt::Display for ExportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            ExportStatus::Pending => "pending",
+            ExportStatus::Running => "running",
+            ExportStatus::Completed => "completed",
+            ExportStatus::Failed => "failed",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+/// Export Job Domain Model
+///
+/// Tracks a single background export from enqueue to cleanup.
+///
+/// ## Field Meanings:
+/// - `total_records` / `processed_records`: Progress counters updated by the worker
+/// - `file_path`: Location of the artifact on disk (never exposed to clients)
+/// - `finished_at`: When the job completed or failed; retention is measured from here
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportJob {
+    pub id: Uuid,
+    pub format: ExportFormat,
+    pub status: ExportStatus,
+    pub total_records: usize,
+    pub processed_records: usize,
+    pub file_path: Option<PathBuf>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl ExportJob {
+    /// Creates a new pending export job
+    pub fn new(format: ExportFormat) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            format,
+            status: ExportStatus::Pending,
+            total_records: 0,
+            processed_records: 0,
+            file_path: None,
+            error: None,
+            created_at: Utc::now(),
+            finished_at: None,
+        }
+    }
+
+    /// Percentage of records written so far (0-100)
+    ///
+    /// An export with no records is reported as 100% once completed.
+    pub fn progress(&self) -> u8 {
+        if self.status == ExportStatus::Completed {
+            return 100;
+        }
+        if self.total_records == 0 {
+            return 0;
+        }
+        ((self.processed_records * 100) / self.total_records).min(100) as u8
+    }
+}
+
+/// Create Export Data Transfer Object
+///
+/// Input for `POST /exports`. The format defaults to CSV when omitted.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateExportDto {
+    #[serde(default = "default_export_format")]
+    pub format: ExportFormat,
+}
+
+fn default_export_format() -> ExportFormat {
+    ExportFormat::Csv
+}
+
+/// Export Job Response Data Transfer Object
+///
+/// Output for `POST /exports` and `GET /exports/{id}`.
+///
+/// ## Computed Fields:
+/// - `progress`: Percentage derived from the record counters
+/// - `download_url`: Only present once the job has completed
+/// - `expires_at`: When retention cleanup will remove the artifact
+#[derive(Debug, Serialize)]
+pub struct ExportJobResponseDto {
+    pub id: Uuid,
+    pub format: ExportFormat,
+    pub status: ExportStatus,
+    pub progress: u8,
+    pub processed_records: usize,
+    pub total_records: usize,
+    pub download_url: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl ExportJobResponseDto {
+    /// Builds the response DTO, computing expiry from the retention period
+    pub fn from_job(job: ExportJob, retention: chrono::Duration) -> Self {
+        let download_url = (job.status == ExportStatus::Completed)
+            .then(|| format!("/exports/{}/download", job.id));
+
+        Self {
+            id: job.id,
+            format: job.format,
+            status: job.status,
+            progress: job.progress(),
+            processed_records: job.processed_records,
+            total_records: job.total_records,
+            download_url,
+            error: job.error,
+            created_at: job.created_at,
+            finished_at: job.finished_at,
+            expires_at: job.finished_at.map(|finished| finished + retention),
+        }
+    }
+}
+
+/// Export Artifact
+///
+/// Describes a completed export's file so the handler can stream it back.
+/// Kept separate from the response DTO because it contains a server-side path.
+#[derive(Debug, Clone)]
+pub struct ExportArtifact {
+    pub path: PathBuf,
+    pub format: ExportFormat,
+    pub file_name: String,
+}
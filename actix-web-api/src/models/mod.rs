@@ -1,3 +1,9 @@
 pub mod user;
+pub mod export;
+pub mod client;
+pub mod audit;
 
-pub use user::*;
\ No newline at end of file
+pub use user::*;
+pub use export::*;
+pub use client::*;
+pub use audit::*;
\ No newline at end of file
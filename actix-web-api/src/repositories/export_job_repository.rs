@@ -0,0 +1,111 @@
+//! # Export Job Repository
+//!
+//! Storage abstraction for background export jobs.
+//!
+//! Export jobs are short-lived bookkeeping records: they only need to survive
+//! until their artifact is downloaded or cleaned up by retention, so the default
+//! implementation keeps them in memory. The trait keeps the door open for a
+//! database-backed implementation if jobs ever need to survive restarts.
+
+use crate::errors::{AppError, AppResult};
+use crate::models::ExportJob;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Export Job Repository Trait
+///
+/// This trait defines the **contract** for export job persistence.
+///
+/// ## Async Trait Pattern:
+/// - `#[async_trait]`: Required for async methods in traits
+/// - `Send + Sync`: Jobs are updated from background tasks on other threads
+#[async_trait]
+pub trait ExportJobRepository: Send + Sync {
+    /// Stores a newly created job
+    async fn insert(&self, job: ExportJob) -> AppResult<ExportJob>;
+
+    /// Finds a job by its unique identifier
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<ExportJob>>;
+
+    /// Replaces an existing job with its updated state
+    ///
+    /// **Business Rules Enforced:**
+    /// - Job existence validation
+    async fn update(&self, job: ExportJob) -> AppResult<ExportJob>;
+
+    /// Removes and returns every finished job that finished before `cutoff`
+    ///
+    /// Returning the removed jobs lets the caller clean up their artifacts.
+    async fn remove_finished_before(&self, cutoff: DateTime<Utc>) -> AppResult<Vec<ExportJob>>;
+}
+
+/// In-Memory Export Job Repository
+///
+/// Uses the same `Arc<RwLock<HashMap>>` layout as `InMemoryUserRepository`.
+///
+/// ## Limitations:
+/// - **No Persistence**: Job records are lost on restart (artifacts on disk are
+///   left behind and should be cleaned by the retention task of the next run)
+pub struct InMemoryExportJobRepository {
+    jobs: Arc<RwLock<HashMap<Uuid, ExportJob>>>,
+}
+
+impl InMemoryExportJobRepository {
+    /// Creates a new, empty job repository
+    pub fn new() -> Self {
+        Self {
+            jobs: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+/// Default implementation for convenience
+impl Default for InMemoryExportJobRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ExportJobRepository for InMemoryExportJobRepository {
+    async fn insert(&self, job: ExportJob) -> AppResult<ExportJob> {
+        let mut jobs = self.jobs.write().await;
+        jobs.insert(job.id, job.clone());
+        Ok(job)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<ExportJob>> {
+        let jobs = self.jobs.read().await;
+        Ok(jobs.get(&id).cloned())
+    }
+
+    async fn update(&self, job: ExportJob) -> AppResult<ExportJob> {
+        let mut jobs = self.jobs.write().await;
+
+        match jobs.get_mut(&job.id) {
+            Some(existing) => {
+                *existing = job.clone();
+                Ok(job)
+            }
+            None => Err(AppError::ExportNotFound { id: job.id }),
+        }
+    }
+
+    async fn remove_finished_before(&self, cutoff: DateTime<Utc>) -> AppResult<Vec<ExportJob>> {
+        let mut jobs = self.jobs.write().await;
+
+        // Collect IDs first - we can't remove from the map while iterating it
+        let expired: Vec<Uuid> = jobs
+            .values()
+            .filter(|job| job.status.is_finished())
+            .filter(|job| job.finished_at.is_some_and(|finished| finished < cutoff))
+            .map(|job| job.id)
+            .collect();
+
+        Ok(expired.into_iter().filter_map(|id| jobs.remove(&id)).collect())
+    }
+}
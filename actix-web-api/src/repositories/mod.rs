@@ -1,5 +1,7 @@
 pub mod user_repository;
 pub mod postgres_user_repository;
+pub mod export_job_repository;
 
 pub use user_repository::*;
-pub use postgres_user_repository::*;
\ No newline at end of file
+pub use postgres_user_repository::*;
+pub use export_job_repository::*;
\ No newline at end of file
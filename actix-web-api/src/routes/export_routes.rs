@@ -0,0 +1,24 @@
+//! # Export Routes Configuration
+//!
+//! Maps the asynchronous export endpoints to their handlers:
+//! - `POST /exports` - Enqueue an export job
+//! - `GET /exports/{id}` - Poll job status and progress
+//! - `GET /exports/{id}/download` - Download the finished artifact
+
+use crate::handlers::ExportHandler;
+use actix_web::web;
+
+/// Configure Export-Related Routes
+///
+/// Groups all export endpoints under the `/exports` scope.
+pub fn configure_export_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/exports")
+            // POST /exports - Enqueue a new export job
+            .route("", web::post().to(ExportHandler::create_export))
+            // GET /exports/{id} - Poll an export job
+            .route("/{id}", web::get().to(ExportHandler::get_export))
+            // GET /exports/{id}/download - Download a completed export
+            .route("/{id}/download", web::get().to(ExportHandler::download_export)),
+    );
+}
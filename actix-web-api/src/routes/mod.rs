@@ -1,3 +1,5 @@
 pub mod user_routes;
+pub mod export_routes;
 
-pub use user_routes::*;
\ No newline at end of file
+pub use user_routes::*;
+pub use export_routes::*;
\ No newline at end of file
@@ -18,6 +18,7 @@
 //! - `DELETE /users/{id}` - Delete resource
 
 use crate::handlers::UserHandler;
+use crate::routes::configure_export_routes;
 use actix_web::{web, HttpResponse, Result};
 use serde_json::json;
 
@@ -86,5 +87,7 @@ pub fn configure_routes(cfg: &mut web::ServiceConfig) {
         // Add health check endpoint
         .route("/health", web::get().to(health_check))
         // Add all user-related routes
-        .configure(configure_user_routes);
+        .configure(configure_user_routes)
+        // Add asynchronous export routes
+        .configure(configure_export_routes);
 }
@@ -0,0 +1,141 @@
+//! # Cached Secret Provider
+//!
+//! Wraps another provider and caches results for a fixed TTL.
+//!
+//! ## Why Cache?
+//! - **Latency**: Vault lookups are network round trips
+//! - **Rotation**: Because entries expire, a rotated secret (new file contents,
+//!   new Vault version) is picked up within one TTL, without a restart
+//! - **Resilience**: If a refresh fails, the last known value keeps being served
+//!   (with a warning) rather than failing requests during a Vault outage
+
+use super::{SecretError, SecretProvider};
+use async_trait::async_trait;
+use secrecy::SecretString;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+/// A cached lookup result (a miss is cached too, to avoid hammering sources)
+struct CachedSecret {
+    value: Option<SecretString>,
+    fetched_at: Instant,
+}
+
+/// Cached Secret Provider
+pub struct CachedSecretProvider {
+    inner: Arc<dyn SecretProvider>,
+    ttl: Duration,
+    cache: RwLock<HashMap<String, CachedSecret>>,
+}
+
+impl CachedSecretProvider {
+    /// Wraps `inner`, keeping each result for `ttl`
+    pub fn new(inner: Arc<dyn SecretProvider>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl SecretProvider for CachedSecretProvider {
+    fn source_name(&self) -> &'static str {
+        self.inner.source_name()
+    }
+
+    async fn get(&self, name: &str) -> Result<Option<SecretString>, SecretError> {
+        if let Some(cached) = self.cache.read().await.get(name) {
+            if cached.fetched_at.elapsed() < self.ttl {
+                return Ok(cached.value.clone());
+            }
+        }
+
+        match self.inner.get(name).await {
+            Ok(value) => {
+                let entry = CachedSecret {
+                    value: value.clone(),
+                    fetched_at: Instant::now(),
+                };
+                self.cache.write().await.insert(name.to_string(), entry);
+                Ok(value)
+            }
+            Err(e) => {
+                // Serve the stale value if we have one; rotation can wait
+                let cache = self.cache.read().await;
+                match cache.get(name).and_then(|cached| cached.value.clone()) {
+                    Some(stale) => {
+                        tracing::warn!(secret = name, error = %e, "Secret refresh failed, serving cached value");
+                        Ok(Some(stale))
+                    }
+                    None => Err(e),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secrecy::ExposeSecret;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Source that returns a new value per call, or fails once `fail` is set
+    #[derive(Default)]
+    struct RotatingProvider {
+        calls: AtomicUsize,
+        fail: std::sync::atomic::AtomicBool,
+    }
+
+    #[async_trait]
+    impl SecretProvider for RotatingProvider {
+        fn source_name(&self) -> &'static str {
+            "rotating"
+        }
+
+        async fn get(&self, _name: &str) -> Result<Option<SecretString>, SecretError> {
+            if self.fail.load(Ordering::SeqCst) {
+                return Err(SecretError::Vault {
+                    message: "sealed".to_string(),
+                });
+            }
+            let version = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(Some(SecretString::from(format!("v{version}"))))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_values_are_cached_until_ttl_expires() {
+        let inner = Arc::new(RotatingProvider::default());
+        let cached = CachedSecretProvider::new(inner.clone(), Duration::from_millis(50));
+
+        assert_eq!(cached.require("JWT_SIGNING_KEY").await.unwrap().expose_secret(), "v1");
+        assert_eq!(cached.require("JWT_SIGNING_KEY").await.unwrap().expose_secret(), "v1");
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+
+        // After the TTL the rotated value is picked up
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(cached.require("JWT_SIGNING_KEY").await.unwrap().expose_secret(), "v2");
+    }
+
+    #[tokio::test]
+    async fn test_stale_value_is_served_when_refresh_fails() {
+        let inner = Arc::new(RotatingProvider::default());
+        let cached = CachedSecretProvider::new(inner.clone(), Duration::ZERO);
+
+        assert_eq!(cached.require("SMTP_PASSWORD").await.unwrap().expose_secret(), "v1");
+
+        inner.fail.store(true, Ordering::SeqCst);
+        assert_eq!(cached.require("SMTP_PASSWORD").await.unwrap().expose_secret(), "v1");
+
+        // Nothing cached for this name, so the error surfaces
+        assert!(matches!(
+            cached.get("OTHER_SECRET").await,
+            Err(SecretError::Vault { .. })
+        ));
+    }
+}
@@ -0,0 +1,106 @@
+//! # Secrets Configuration
+//!
+//! Builds the application's secret provider from a few **non-sensitive**
+//! bootstrap variables. The resulting lookup order is:
+//!
+//! 1. **Environment** (`.env` / process env) - easy local overrides
+//! 2. **Files** (`<NAME>_FILE`, then `SECRETS_DIR`) - Docker / Kubernetes secrets
+//! 3. **Vault** (only when `VAULT_ADDR` is set)
+//!
+//! All layers sit behind one cache, so rotated values appear within
+//! `SECRETS_CACHE_TTL_SECS`.
+//!
+//! ## Rotation and the Database:
+//! The database pool resolves `DATABASE_URL` and `DATABASE_PASSWORD` once, when
+//! it connects at startup (see `db.rs`). A rotated database password is only
+//! used after a restart; the cache TTL does not reach existing connections.
+
+use super::{
+    CachedSecretProvider, EnvSecretProvider, FileSecretProvider, LayeredSecretProvider,
+    SecretError, SecretProvider, VaultConfig, VaultSecretProvider, DEFAULT_SECRETS_DIR,
+};
+use crate::utils::settings::env_setting;
+use secrecy::SecretString;
+use std::env;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Secrets Configuration
+///
+/// ## Environment Variables:
+/// - `SECRETS_DIR`: Directory of secret files (default: `/run/secrets`)
+/// - `SECRETS_CACHE_TTL_SECS`: How long resolved secrets are cached (default: 300,
+///   also used with a warning when the value isn't a number of seconds)
+/// - `VAULT_ADDR`: Enables the Vault source when set
+/// - `VAULT_TOKEN` / `VAULT_TOKEN_FILE`: Vault token (required with `VAULT_ADDR`)
+/// - `VAULT_KV_MOUNT`: KV v2 mount (default: `secret`)
+/// - `VAULT_SECRET_PATH`: Entry path (default: `actix-web-api`)
+#[derive(Debug, Clone)]
+pub struct SecretsConfig {
+    pub secrets_dir: PathBuf,
+    pub cache_ttl: Duration,
+    pub vault: Option<VaultConfig>,
+}
+
+impl SecretsConfig {
+    /// Reads secrets configuration from the environment
+    pub fn from_env() -> Result<Self, SecretError> {
+        let secrets_dir = env::var("SECRETS_DIR").unwrap_or_else(|_| DEFAULT_SECRETS_DIR.to_string());
+
+        let cache_ttl_secs = env_setting("SECRETS_CACHE_TTL_SECS", |value| value.parse().ok(), 300);
+
+        let vault = match env::var("VAULT_ADDR") {
+            Ok(addr) => Some(VaultConfig {
+                addr,
+                token: vault_token()?,
+                mount: env::var("VAULT_KV_MOUNT").unwrap_or_else(|_| "secret".to_string()),
+                path: env::var("VAULT_SECRET_PATH").unwrap_or_else(|_| "actix-web-api".to_string()),
+            }),
+            Err(_) => None,
+        };
+
+        Ok(Self {
+            secrets_dir: PathBuf::from(secrets_dir),
+            cache_ttl: Duration::from_secs(cache_ttl_secs),
+            vault,
+        })
+    }
+
+    /// Builds the layered, cached provider described in the module docs
+    pub fn build(self) -> Result<Arc<dyn SecretProvider>, SecretError> {
+        let mut layers: Vec<Arc<dyn SecretProvider>> = vec![
+            Arc::new(EnvSecretProvider),
+            Arc::new(FileSecretProvider::new(self.secrets_dir)),
+        ];
+
+        if let Some(vault) = self.vault {
+            tracing::info!(addr = %vault.addr, path = %vault.path, "Vault secret source enabled");
+            layers.push(Arc::new(VaultSecretProvider::new(vault)?));
+        }
+
+        Ok(Arc::new(CachedSecretProvider::new(
+            Arc::new(LayeredSecretProvider::new(layers)),
+            self.cache_ttl,
+        )))
+    }
+}
+
+/// Reads the Vault token from `VAULT_TOKEN` or the file named by `VAULT_TOKEN_FILE`
+fn vault_token() -> Result<SecretString, SecretError> {
+    if let Ok(token) = env::var("VAULT_TOKEN") {
+        return Ok(SecretString::from(token));
+    }
+
+    let path = env::var("VAULT_TOKEN_FILE").map_err(|_| SecretError::Config {
+        message: "VAULT_ADDR is set but neither VAULT_TOKEN nor VAULT_TOKEN_FILE is".to_string(),
+    })?;
+
+    std::fs::read_to_string(&path)
+        .map(|token| SecretString::from(token.trim()))
+        .map_err(|source| SecretError::Io {
+            name: "VAULT_TOKEN".to_string(),
+            path: path.into(),
+            source,
+        })
+}
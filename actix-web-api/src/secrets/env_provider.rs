@@ -0,0 +1,32 @@
+//! # Environment Variable Secret Source
+//!
+//! Reads secrets straight from the process environment (including values
+//! loaded from `.env`). Convenient for local development; production setups
+//! should prefer files or Vault so secrets don't leak through `/proc` or
+//! crash dumps.
+
+use super::{SecretError, SecretProvider};
+use async_trait::async_trait;
+use secrecy::SecretString;
+use std::env;
+
+/// Environment Secret Provider
+///
+/// The secret name is used as the variable name. Empty values are treated
+/// as unset, so `DATABASE_PASSWORD=` in `.env` doesn't shadow other sources.
+#[derive(Debug, Default)]
+pub struct EnvSecretProvider;
+
+#[async_trait]
+impl SecretProvider for EnvSecretProvider {
+    fn source_name(&self) -> &'static str {
+        "env"
+    }
+
+    async fn get(&self, name: &str) -> Result<Option<SecretString>, SecretError> {
+        Ok(env::var(name)
+            .ok()
+            .filter(|value| !value.is_empty())
+            .map(SecretString::from))
+    }
+}
@@ -0,0 +1,61 @@
+//! # File Secret Source (Docker / Kubernetes Secrets)
+//!
+//! Reads secrets from files, the way orchestrators mount them:
+//!
+//! 1. **Explicit path**: `<NAME>_FILE=/path/to/file` (the official Docker image convention)
+//! 2. **Secrets directory**: `<dir>/<name in lowercase>`, e.g. `/run/secrets/database_password`
+//!
+//! Files are read on every lookup, so a rotated file is picked up as soon as
+//! the cache in front of this provider expires.
+
+use super::{SecretError, SecretProvider};
+use async_trait::async_trait;
+use secrecy::SecretString;
+use std::env;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+/// Default mount point for Docker Swarm and Compose secrets
+pub const DEFAULT_SECRETS_DIR: &str = "/run/secrets";
+
+/// File Secret Provider
+#[derive(Debug, Clone)]
+pub struct FileSecretProvider {
+    dir: PathBuf,
+}
+
+impl FileSecretProvider {
+    /// Creates a provider that looks for secret files in `dir`
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+#[async_trait]
+impl SecretProvider for FileSecretProvider {
+    fn source_name(&self) -> &'static str {
+        "file"
+    }
+
+    async fn get(&self, name: &str) -> Result<Option<SecretString>, SecretError> {
+        // An explicit `<NAME>_FILE` must exist; a missing file in the
+        // secrets directory just means this source doesn't have the secret
+        let (path, explicit) = match env::var(format!("{name}_FILE")) {
+            Ok(path) => (PathBuf::from(path), true),
+            Err(_) => (self.dir.join(name.to_lowercase()), false),
+        };
+
+        match tokio::fs::read_to_string(&path).await {
+            // Editors and `echo` add a trailing newline that isn't part of the secret
+            Ok(contents) => Ok(Some(SecretString::from(
+                contents.trim_end_matches(['\r', '\n']),
+            ))),
+            Err(e) if e.kind() == ErrorKind::NotFound && !explicit => Ok(None),
+            Err(source) => Err(SecretError::Io {
+                name: name.to_string(),
+                path,
+                source,
+            }),
+        }
+    }
+}
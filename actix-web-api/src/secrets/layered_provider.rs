@@ -0,0 +1,87 @@
+//! # Layered Secret Provider
+//!
+//! Combines several sources into one, asking each in order and returning the
+//! first hit. This is what lets the same binary read `DATABASE_URL` from
+//! `.env` in development, a mounted file under Docker, and Vault in production.
+
+use super::{SecretError, SecretProvider};
+use async_trait::async_trait;
+use secrecy::SecretString;
+use std::sync::Arc;
+
+/// Layered Secret Provider
+///
+/// ## Failure Policy:
+/// A source that *fails* (unreadable file, Vault down) stops the lookup with
+/// an error instead of falling through. Falling through could silently pick
+/// up a stale or less privileged value from a lower layer.
+pub struct LayeredSecretProvider {
+    layers: Vec<Arc<dyn SecretProvider>>,
+}
+
+impl LayeredSecretProvider {
+    /// Creates a provider that consults `layers` in order
+    pub fn new(layers: Vec<Arc<dyn SecretProvider>>) -> Self {
+        Self { layers }
+    }
+}
+
+#[async_trait]
+impl SecretProvider for LayeredSecretProvider {
+    fn source_name(&self) -> &'static str {
+        "layered"
+    }
+
+    async fn get(&self, name: &str) -> Result<Option<SecretString>, SecretError> {
+        for layer in &self.layers {
+            if let Some(secret) = layer.get(name).await? {
+                tracing::debug!(secret = name, source = layer.source_name(), "Secret resolved");
+                return Ok(Some(secret));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secrets::FileSecretProvider;
+    use secrecy::ExposeSecret;
+
+    fn temp_secrets_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("secrets-test-{name}-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_first_layer_with_the_secret_wins() {
+        let primary = temp_secrets_dir("primary");
+        let fallback = temp_secrets_dir("fallback");
+        std::fs::write(primary.join("layered_test_password"), "from-primary\n").unwrap();
+        std::fs::write(fallback.join("layered_test_password"), "from-fallback").unwrap();
+        std::fs::write(fallback.join("layered_test_key"), "only-in-fallback").unwrap();
+
+        let provider = LayeredSecretProvider::new(vec![
+            Arc::new(FileSecretProvider::new(&primary)),
+            Arc::new(FileSecretProvider::new(&fallback)),
+        ]);
+
+        // Trailing newline is stripped from file secrets
+        let password = provider.require("LAYERED_TEST_PASSWORD").await.unwrap();
+        assert_eq!(password.expose_secret(), "from-primary");
+
+        let key = provider.require("LAYERED_TEST_KEY").await.unwrap();
+        assert_eq!(key.expose_secret(), "only-in-fallback");
+
+        assert!(matches!(
+            provider.require("LAYERED_TEST_MISSING").await,
+            Err(SecretError::NotFound { .. })
+        ));
+
+        std::fs::remove_dir_all(primary).ok();
+        std::fs::remove_dir_all(fallback).ok();
+    }
+}
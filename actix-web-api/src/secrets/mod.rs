@@ -0,0 +1,15 @@
+pub mod cached_provider;
+pub mod config;
+pub mod env_provider;
+pub mod file_provider;
+pub mod layered_provider;
+pub mod provider;
+pub mod vault_provider;
+
+pub use cached_provider::*;
+pub use config::*;
+pub use env_provider::*;
+pub use file_provider::*;
+pub use layered_provider::*;
+pub use provider::*;
+pub use vault_provider::*;
\ No newline at end of file
@@ -0,0 +1,72 @@
+//! # Secret Provider Abstraction
+//!
+//! This module defines how the application **resolves sensitive values**
+//! (database credentials, signing keys, SMTP passwords) without caring where
+//! they are stored.
+//!
+//! ## Clean Architecture Position:
+//! ```
+//! main.rs (DI) → SecretProvider → env / files / Vault
+//!       ↓
+//! db.rs, services (receive resolved secrets, never read env directly)
+//! ```
+//!
+//! ## Key Patterns:
+//! - **Trait Object**: `Arc<dyn SecretProvider>` lets sources be layered and swapped
+//! - **`SecretString`**: Values are zeroized on drop and redacted in `Debug` output
+//! - **`Option` vs Error**: A source that doesn't have a secret returns `Ok(None)`;
+//!   errors are reserved for sources that failed to answer
+
+use async_trait::async_trait;
+use secrecy::SecretString;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Secret Resolution Errors
+#[derive(Error, Debug)]
+pub enum SecretError {
+    /// No configured source had the secret
+    #[error("Secret '{name}' is not configured in any secret source")]
+    NotFound { name: String },
+
+    /// A secret file exists (or was explicitly configured) but can't be read
+    #[error("Failed to read secret '{name}' from {}: {source}", path.display())]
+    Io {
+        name: String,
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// Vault was unreachable or returned an unexpected response
+    #[error("Vault request failed: {message}")]
+    Vault { message: String },
+
+    /// Secret source configuration is incomplete or invalid
+    #[error("Invalid secrets configuration: {message}")]
+    Config { message: String },
+}
+
+/// Secret Provider Trait
+///
+/// Secret names use the same `UPPER_SNAKE_CASE` as environment variables
+/// (e.g. `DATABASE_URL`). Each source maps that name onto its own layout.
+///
+/// ## Async Trait Pattern:
+/// - `#[async_trait]`: Remote sources (Vault) need network IO
+/// - `Send + Sync`: Shared across the application behind `Arc`
+#[async_trait]
+pub trait SecretProvider: Send + Sync {
+    /// Short source name used in logs (never logs values)
+    fn source_name(&self) -> &'static str;
+
+    /// Looks up a secret, returning `Ok(None)` when this source doesn't have it
+    async fn get(&self, name: &str) -> Result<Option<SecretString>, SecretError>;
+
+    /// Looks up a secret that must exist
+    async fn require(&self, name: &str) -> Result<SecretString, SecretError> {
+        self.get(name).await?.ok_or_else(|| SecretError::NotFound {
+            name: name.to_string(),
+        })
+    }
+}
@@ -0,0 +1,129 @@
+//! # HashiCorp Vault Secret Source
+//!
+//! Reads secrets from a single entry in Vault's **KV version 2** engine:
+//!
+//! ```text
+//! GET {VAULT_ADDR}/v1/{mount}/data/{path}      (X-Vault-Token: ...)
+//! → { "data": { "data": { "database_password": "...", ... } } }
+//! ```
+//!
+//! Secret names map to lowercase keys in that entry (`DATABASE_PASSWORD` →
+//! `database_password`), matching the file provider's naming. Writing a new
+//! version of the entry in Vault rotates the secret; the application sees it
+//! once the cache in front of this provider expires.
+
+use super::{SecretError, SecretProvider};
+use async_trait::async_trait;
+use secrecy::{ExposeSecret, SecretString};
+use serde_json::Value;
+use std::time::Duration;
+
+/// Upper bound for a single Vault request, so startup can't hang on Vault
+const VAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Vault Connection Settings
+#[derive(Debug, Clone)]
+pub struct VaultConfig {
+    /// Vault server address, e.g. `https://vault.internal:8200`
+    pub addr: String,
+    /// Token with read access to the entry
+    pub token: SecretString,
+    /// KV v2 mount point (default: `secret`)
+    pub mount: String,
+    /// Entry path inside the mount (default: `actix-web-api`)
+    pub path: String,
+}
+
+/// Vault Secret Provider
+pub struct VaultSecretProvider {
+    client: reqwest::Client,
+    config: VaultConfig,
+}
+
+impl VaultSecretProvider {
+    /// Creates a provider for the given Vault entry
+    pub fn new(config: VaultConfig) -> Result<Self, SecretError> {
+        let client = reqwest::Client::builder()
+            .timeout(VAULT_REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| SecretError::Vault {
+                message: format!("failed to build HTTP client: {e}"),
+            })?;
+
+        Ok(Self { client, config })
+    }
+
+    /// URL of the KV v2 entry holding this application's secrets
+    fn entry_url(&self) -> String {
+        format!(
+            "{}/v1/{}/data/{}",
+            self.config.addr.trim_end_matches('/'),
+            self.config.mount.trim_matches('/'),
+            self.config.path.trim_matches('/'),
+        )
+    }
+}
+
+#[async_trait]
+impl SecretProvider for VaultSecretProvider {
+    fn source_name(&self) -> &'static str {
+        "vault"
+    }
+
+    async fn get(&self, name: &str) -> Result<Option<SecretString>, SecretError> {
+        let vault_error = |e: reqwest::Error| SecretError::Vault {
+            message: e.to_string(),
+        };
+
+        let response = self
+            .client
+            .get(self.entry_url())
+            .header("X-Vault-Token", self.config.token.expose_secret())
+            .send()
+            .await
+            .map_err(vault_error)?;
+
+        // A missing entry is "not in this source", not a failure
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let body: Value = response
+            .error_for_status()
+            .map_err(vault_error)?
+            .json()
+            .await
+            .map_err(vault_error)?;
+
+        Ok(kv2_value(&body, name).map(SecretString::from))
+    }
+}
+
+/// Extracts a secret from a KV v2 read response
+fn kv2_value(body: &Value, name: &str) -> Option<String> {
+    body.pointer("/data/data")?
+        .get(name.to_lowercase())?
+        .as_str()
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_kv2_value_reads_lowercase_key() {
+        let body = json!({
+            "data": {
+                "data": { "database_password": "s3cret", "port": 5432 },
+                "metadata": { "version": 3 }
+            }
+        });
+
+        assert_eq!(kv2_value(&body, "DATABASE_PASSWORD").as_deref(), Some("s3cret"));
+        assert_eq!(kv2_value(&body, "JWT_SIGNING_KEY"), None);
+        // Only string values are secrets
+        assert_eq!(kv2_value(&body, "PORT"), None);
+    }
+}
@@ -0,0 +1,55 @@
+//! # Audit Log Service
+//!
+//! This module defines where **audit events** go.
+//!
+//! ## Why a Trait?
+//! The default implementation writes events to a dedicated `audit` tracing
+//! target, so they can be routed to their own sink (file, SIEM, log shipper)
+//! with a subscriber filter like `RUST_LOG=audit=info`. A database-backed
+//! implementation can be swapped in later without touching the middleware.
+
+use crate::models::AuditEvent;
+use async_trait::async_trait;
+
+/// Audit Log Trait
+///
+/// ## Async Trait Pattern:
+/// - `#[async_trait]`: Sinks may need to do IO (database, network)
+/// - `Send + Sync`: Shared by every Actix worker
+#[async_trait]
+pub trait AuditLog: Send + Sync {
+    /// Appends an event to the audit log
+    ///
+    /// Recording must not fail the request that triggered it, so
+    /// implementations handle their own errors instead of returning them.
+    async fn record(&self, event: AuditEvent);
+}
+
+/// Tracing-Backed Audit Log
+///
+/// Emits each event as a structured `tracing` event on the `audit` target.
+pub struct TracingAuditLog;
+
+#[async_trait]
+impl AuditLog for TracingAuditLog {
+    async fn record(&self, event: AuditEvent) {
+        let client = &event.client;
+        tracing::info!(
+            target: "audit",
+            audit_id = %event.id,
+            request_id = ?event.request_id,
+            method = %event.method,
+            path = %event.path,
+            status = event.status,
+            client.ip = ?client.ip,
+            client.peer_ip = ?client.peer_ip,
+            client.user_agent = ?client.user_agent,
+            client.browser = ?client.browser,
+            client.os = ?client.os,
+            client.device = ?client.device_category,
+            client.country = ?client.country,
+            client.city = ?client.city,
+            "Audit event recorded"
+        );
+    }
+}
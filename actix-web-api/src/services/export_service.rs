@@ -0,0 +1,601 @@
+//! # Export Service - Background Jobs
+//!
+//! This module contains the **business logic** for asynchronous data exports.
+//! It is responsible for:
+//!
+//! 1. **Job Orchestration**: Enqueuing exports and running them on background tasks
+//! 2. **Artifact Generation**: Writing users as CSV or NDJSON files on disk
+//! 3. **Progress Tracking**: Updating record counters while the export runs
+//! 4. **Retention**: Removing old jobs and their artifacts on a schedule
+//!
+//! ## Clean Architecture Position:
+//! ```
+//! HTTP Request → Routes → Handlers → **[EXPORT SERVICE]** → Repositories
+//!                                          ↓ tokio::spawn
+//!                                    background export task → export directory
+//! ```
+//!
+//! ## Why Background Jobs?
+//! Generating a large export inside the request would hold the connection open
+//! (and a worker busy) for the whole duration. Returning `202 Accepted` with a
+//! job ID lets clients poll cheaply and download once the file is ready.
+
+use crate::errors::{internal_error, AppError, AppResult};
+use crate::models::{
+    CreateExportDto, ExportArtifact, ExportFormat, ExportJob, ExportJobResponseDto, ExportStatus,
+    UserResponseDto,
+};
+use crate::repositories::{ExportJobRepository, UserRepository};
+use crate::utils::settings::env_setting;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::borrow::Cow;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncWriteExt, BufWriter};
+use uuid::Uuid;
+
+/// How many records are written between progress updates
+///
+/// Updating the job after every record would contend on the repository lock;
+/// batching keeps progress reasonably fresh without that overhead.
+const PROGRESS_BATCH_SIZE: usize = 100;
+
+/// Export Configuration
+///
+/// ## Environment Variables:
+/// - `EXPORT_DIR`: Directory for generated artifacts (default: `exports`)
+/// - `EXPORT_RETENTION_HOURS`: How long finished exports are kept (default: 24)
+/// - `EXPORT_CLEANUP_INTERVAL_SECS`: How often retention runs (default: 600)
+#[derive(Debug, Clone)]
+pub struct ExportConfig {
+    pub export_dir: PathBuf,
+    pub retention: chrono::Duration,
+    pub cleanup_interval: Duration,
+}
+
+impl ExportConfig {
+    const DEFAULT_RETENTION_HOURS: i64 = 24;
+    const DEFAULT_CLEANUP_INTERVAL_SECS: u64 = 600;
+
+    /// Reads export configuration from the environment, falling back to defaults
+    ///
+    /// Values that don't parse or are out of range are logged and replaced by
+    /// the default: a zero cleanup interval would make `tokio::time::interval`
+    /// panic, and so would a retention too large for `chrono::Duration`.
+    pub fn from_env() -> Self {
+        let export_dir = env::var("EXPORT_DIR").unwrap_or_else(|_| "exports".to_string());
+
+        let retention = env_setting(
+            "EXPORT_RETENTION_HOURS",
+            |value| {
+                value
+                    .parse::<i64>()
+                    .ok()
+                    .filter(|hours| *hours >= 0)
+                    .and_then(chrono::Duration::try_hours)
+            },
+            chrono::Duration::hours(Self::DEFAULT_RETENTION_HOURS),
+        );
+
+        let cleanup_interval = env_setting(
+            "EXPORT_CLEANUP_INTERVAL_SECS",
+            |value| {
+                value
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|secs| *secs > 0)
+                    .map(Duration::from_secs)
+            },
+            Duration::from_secs(Self::DEFAULT_CLEANUP_INTERVAL_SECS),
+        );
+
+        Self {
+            export_dir: PathBuf::from(export_dir),
+            retention,
+            cleanup_interval,
+        }
+    }
+}
+
+/// Export Service Trait
+///
+/// This trait defines the **contract** for export operations.
+///
+/// ## Async Trait Pattern:
+/// - `#[async_trait]`: Enables async functions in traits
+/// - `Send + Sync`: Shared across Actix workers and the cleanup task
+#[async_trait]
+pub trait ExportService: Send + Sync {
+    /// Enqueues a new export and starts it in the background
+    async fn create_export(&self, create_dto: CreateExportDto) -> AppResult<ExportJobResponseDto>;
+
+    /// Returns the current status and progress of an export
+    async fn get_export(&self, id: Uuid) -> AppResult<ExportJobResponseDto>;
+
+    /// Returns the downloadable artifact of a completed export
+    async fn get_export_artifact(&self, id: Uuid) -> AppResult<ExportArtifact>;
+
+    /// Removes expired jobs and artifacts, returning how many files were deleted
+    async fn cleanup_expired(&self) -> AppResult<usize>;
+}
+
+/// Export Service Implementation
+///
+/// ## Dependency Injection Pattern:
+/// Depends on repository abstractions for both users (the data being exported)
+/// and export jobs (the bookkeeping), following the same constructor injection
+/// as `UserServiceImpl`.
+pub struct ExportServiceImpl {
+    jobs: Arc<dyn ExportJobRepository>,
+    users: Arc<dyn UserRepository>,
+    config: ExportConfig,
+}
+
+impl ExportServiceImpl {
+    /// Creates a new ExportService with the provided repositories and configuration
+    pub fn new(
+        jobs: Arc<dyn ExportJobRepository>,
+        users: Arc<dyn UserRepository>,
+        config: ExportConfig,
+    ) -> Self {
+        Self { jobs, users, config }
+    }
+
+    /// Looks up a job or returns a domain-specific not found error
+    async fn find_job(&self, id: Uuid) -> AppResult<ExportJob> {
+        self.jobs
+            .find_by_id(id)
+            .await?
+            .ok_or(AppError::ExportNotFound { id })
+    }
+}
+
+#[async_trait]
+impl ExportService for ExportServiceImpl {
+    /// Create Export Business Logic
+    ///
+    /// 1. Store a pending job so it can be polled immediately
+    /// 2. Spawn the export on a background task
+    /// 3. Return the pending job without waiting for the export
+    async fn create_export(&self, create_dto: CreateExportDto) -> AppResult<ExportJobResponseDto> {
+        let job = self.jobs.insert(ExportJob::new(create_dto.format)).await?;
+
+        // The background task owns its own handles to the repositories,
+        // so it keeps running after this request has been answered
+        let jobs = Arc::clone(&self.jobs);
+        let users = Arc::clone(&self.users);
+        let export_dir = self.config.export_dir.clone();
+        let background_job = job.clone();
+        tokio::spawn(async move {
+            run_export(background_job, jobs, users, export_dir).await;
+        });
+
+        tracing::info!(export_id = %job.id, format = ?job.format, "Export job enqueued");
+        Ok(ExportJobResponseDto::from_job(job, self.config.retention))
+    }
+
+    async fn get_export(&self, id: Uuid) -> AppResult<ExportJobResponseDto> {
+        let job = self.find_job(id).await?;
+        Ok(ExportJobResponseDto::from_job(job, self.config.retention))
+    }
+
+    /// Get Export Artifact Business Logic
+    ///
+    /// Only completed jobs have an artifact; anything else is a conflict
+    /// with the job's current state rather than a missing resource.
+    async fn get_export_artifact(&self, id: Uuid) -> AppResult<ExportArtifact> {
+        let job = self.find_job(id).await?;
+
+        match (job.status, job.file_path) {
+            (ExportStatus::Completed, Some(path)) => Ok(ExportArtifact {
+                file_name: format!("users-export-{}.{}", job.id, job.format.extension()),
+                path,
+                format: job.format,
+            }),
+            (status, _) => Err(AppError::ExportNotReady {
+                id,
+                status: status.to_string(),
+            }),
+        }
+    }
+
+    /// Cleanup Business Logic
+    ///
+    /// 1. Drop job records that finished before the retention cutoff
+    /// 2. Delete their artifacts
+    /// 3. Sweep orphaned artifacts (e.g. left behind by a previous process)
+    async fn cleanup_expired(&self) -> AppResult<usize> {
+        let cutoff = Utc::now() - self.config.retention;
+        let mut removed_files = 0;
+
+        for job in self.jobs.remove_finished_before(cutoff).await? {
+            if let Some(path) = job.file_path {
+                if remove_artifact(&path).await {
+                    removed_files += 1;
+                }
+            }
+        }
+
+        removed_files +=
+            sweep_orphaned_artifacts(&self.config.export_dir, self.jobs.as_ref(), cutoff).await?;
+
+        if removed_files > 0 {
+            tracing::info!(removed_files, "Export retention cleanup finished");
+        }
+        Ok(removed_files)
+    }
+}
+
+/// Spawns the periodic retention task
+///
+/// Runs `cleanup_expired` every `interval` for the lifetime of the process.
+/// Failures are logged rather than propagated so one bad run doesn't stop
+/// future cleanups.
+pub fn spawn_export_cleanup(service: Arc<dyn ExportService>, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = service.cleanup_expired().await {
+                tracing::warn!(error = %e, "Export retention cleanup failed");
+            }
+        }
+    });
+}
+
+/// Background export task
+///
+/// Any error is recorded on the job as `Failed` instead of being returned,
+/// because nobody is awaiting this task - clients learn the outcome by polling.
+async fn run_export(
+    mut job: ExportJob,
+    jobs: Arc<dyn ExportJobRepository>,
+    users: Arc<dyn UserRepository>,
+    export_dir: PathBuf,
+) {
+    let result = write_export(&mut job, jobs.as_ref(), users.as_ref(), &export_dir).await;
+
+    match result {
+        Ok(path) => {
+            job.status = ExportStatus::Completed;
+            job.file_path = Some(path);
+            tracing::info!(export_id = %job.id, records = job.processed_records, "Export completed");
+        }
+        Err(e) => {
+            job.status = ExportStatus::Failed;
+            job.error = Some(e.to_string());
+            tracing::error!(export_id = %job.id, error = %e, "Export failed");
+        }
+    }
+    job.finished_at = Some(Utc::now());
+
+    if let Err(e) = jobs.update(job).await {
+        tracing::error!(error = %e, "Failed to record export result");
+    }
+}
+
+/// Writes every user to the artifact file, updating progress as it goes
+async fn write_export(
+    job: &mut ExportJob,
+    jobs: &dyn ExportJobRepository,
+    users: &dyn UserRepository,
+    export_dir: &Path,
+) -> AppResult<PathBuf> {
+    job.status = ExportStatus::Running;
+    *job = jobs.update(job.clone()).await?;
+
+    // Note: `find_all` loads every user at once; a paginated repository query
+    // would let very large exports stream from the database as well
+    let users: Vec<UserResponseDto> = users
+        .find_all()
+        .await?
+        .into_iter()
+        .map(UserResponseDto::from)
+        .collect();
+    job.total_records = users.len();
+    *job = jobs.update(job.clone()).await?;
+
+    tokio::fs::create_dir_all(export_dir).await.map_err(io_error)?;
+    let path = export_dir.join(format!("{}.{}", job.id, job.format.extension()));
+    let file = tokio::fs::File::create(&path).await.map_err(io_error)?;
+    let mut writer = BufWriter::new(file);
+
+    if job.format == ExportFormat::Csv {
+        writer.write_all(b"id,email,name,created_at,updated_at\n").await.map_err(io_error)?;
+    }
+
+    for (index, user) in users.iter().enumerate() {
+        let line = match job.format {
+            ExportFormat::Csv => csv_row(user),
+            ExportFormat::Ndjson => ndjson_row(user)?,
+        };
+        writer.write_all(line.as_bytes()).await.map_err(io_error)?;
+
+        job.processed_records = index + 1;
+        if job.processed_records.is_multiple_of(PROGRESS_BATCH_SIZE) {
+            *job = jobs.update(job.clone()).await?;
+        }
+    }
+
+    writer.flush().await.map_err(io_error)?;
+    Ok(path)
+}
+
+/// Formats a user as one CSV line (including the trailing newline)
+fn csv_row(user: &UserResponseDto) -> String {
+    format!(
+        "{},{},{},{},{}\n",
+        user.id,
+        csv_field(&user.email),
+        csv_field(&user.name),
+        user.created_at.to_rfc3339(),
+        user.updated_at.to_rfc3339()
+    )
+}
+
+/// Formats a user as one NDJSON line (including the trailing newline)
+fn ndjson_row(user: &UserResponseDto) -> AppResult<String> {
+    let json = serde_json::to_string(user)
+        .map_err(|e| internal_error(&format!("Failed to serialize export record: {}", e)))?;
+    Ok(format!("{}\n", json))
+}
+
+/// Quotes a CSV field when it contains separators, quotes or line breaks
+///
+/// Embedded quotes are escaped by doubling them, per RFC 4180.
+fn csv_field(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+/// Deletes an artifact, returning whether a file was actually removed
+async fn remove_artifact(path: &Path) -> bool {
+    match tokio::fs::remove_file(path).await {
+        Ok(()) => true,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => false,
+        Err(e) => {
+            tracing::warn!(path = %path.display(), error = %e, "Failed to remove export artifact");
+            false
+        }
+    }
+}
+
+/// Deletes artifacts last modified before `cutoff` that no job tracks anymore
+///
+/// Artifacts are named after their job's ID, so a file whose name parses as
+/// the ID of a job still in the repository is left alone - the job's own
+/// retention removes it. Anything else is an orphan, e.g. left behind by a
+/// previous process whose in-memory jobs were lost on restart.
+async fn sweep_orphaned_artifacts(
+    export_dir: &Path,
+    jobs: &dyn ExportJobRepository,
+    cutoff: chrono::DateTime<Utc>,
+) -> AppResult<usize> {
+    let Ok(mut entries) = tokio::fs::read_dir(export_dir).await else {
+        // Nothing has been exported yet
+        return Ok(0);
+    };
+
+    let mut removed = 0;
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let modified = match entry.metadata().await.and_then(|meta| meta.modified()) {
+            Ok(modified) => chrono::DateTime::<Utc>::from(modified),
+            Err(_) => continue,
+        };
+        if modified >= cutoff {
+            continue;
+        }
+
+        let path = entry.path();
+        let job_id = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| Uuid::parse_str(stem).ok());
+        if let Some(id) = job_id {
+            if jobs.find_by_id(id).await?.is_some() {
+                continue;
+            }
+        }
+
+        if remove_artifact(&path).await {
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// Converts IO failures while writing artifacts into application errors
+fn io_error(e: std::io::Error) -> AppError {
+    internal_error(&format!("Export file operation failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreateUserDto;
+    use crate::repositories::{InMemoryExportJobRepository, InMemoryUserRepository};
+
+    fn test_config(retention: chrono::Duration) -> ExportConfig {
+        ExportConfig {
+            export_dir: env::temp_dir().join(format!("export-test-{}", Uuid::new_v4())),
+            retention,
+            cleanup_interval: Duration::from_secs(60),
+        }
+    }
+
+    async fn service_with_users(config: ExportConfig) -> ExportServiceImpl {
+        let users: Arc<dyn UserRepository> = Arc::new(InMemoryUserRepository::new());
+        for (email, name) in [("ada@example.com", "Ada"), ("alan@example.com", "Turing, Alan")] {
+            users
+                .create(CreateUserDto {
+                    email: email.to_string(),
+                    name: name.to_string(),
+                })
+                .await
+                .unwrap();
+        }
+        ExportServiceImpl::new(Arc::new(InMemoryExportJobRepository::new()), users, config)
+    }
+
+    async fn wait_until_finished(service: &ExportServiceImpl, id: Uuid) -> ExportJobResponseDto {
+        for _ in 0..100 {
+            let job = service.get_export(id).await.unwrap();
+            if job.status.is_finished() {
+                return job;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("export {} did not finish in time", id);
+    }
+
+    #[test]
+    fn test_csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("Turing, Alan"), "\"Turing, Alan\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[tokio::test]
+    async fn test_csv_export_completes_with_download_url() {
+        let config = test_config(chrono::Duration::hours(1));
+        let export_dir = config.export_dir.clone();
+        let service = service_with_users(config).await;
+
+        let created = service
+            .create_export(CreateExportDto { format: ExportFormat::Csv })
+            .await
+            .unwrap();
+        let job = wait_until_finished(&service, created.id).await;
+
+        assert_eq!(job.status, ExportStatus::Completed);
+        assert_eq!(job.progress, 100);
+        assert_eq!(job.total_records, 2);
+        assert_eq!(job.download_url, Some(format!("/exports/{}/download", job.id)));
+
+        let artifact = service.get_export_artifact(job.id).await.unwrap();
+        let contents = std::fs::read_to_string(&artifact.path).unwrap();
+        assert_eq!(contents.lines().count(), 3);
+        assert!(contents.starts_with("id,email,name,created_at,updated_at\n"));
+        assert!(contents.contains("\"Turing, Alan\""));
+
+        std::fs::remove_dir_all(export_dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_export_writes_one_object_per_line() {
+        let config = test_config(chrono::Duration::hours(1));
+        let export_dir = config.export_dir.clone();
+        let service = service_with_users(config).await;
+
+        let created = service
+            .create_export(CreateExportDto { format: ExportFormat::Ndjson })
+            .await
+            .unwrap();
+        wait_until_finished(&service, created.id).await;
+
+        let artifact = service.get_export_artifact(created.id).await.unwrap();
+        let contents = std::fs::read_to_string(&artifact.path).unwrap();
+        for line in contents.lines() {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert!(value.get("email").is_some());
+        }
+        assert_eq!(contents.lines().count(), 2);
+
+        std::fs::remove_dir_all(export_dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unknown_export_is_not_found() {
+        let service = service_with_users(test_config(chrono::Duration::hours(1))).await;
+        let result = service.get_export(Uuid::new_v4()).await;
+        assert!(matches!(result, Err(AppError::ExportNotFound { .. })));
+    }
+
+    // ExportConfig::from_env reads fixed variable names, so tests that set
+    // them take turns
+    static EXPORT_ENV: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    fn config_with(retention_hours: &str, cleanup_interval_secs: &str) -> ExportConfig {
+        env::set_var("EXPORT_RETENTION_HOURS", retention_hours);
+        env::set_var("EXPORT_CLEANUP_INTERVAL_SECS", cleanup_interval_secs);
+        ExportConfig::from_env()
+    }
+
+    #[test]
+    fn test_out_of_range_settings_fall_back_to_defaults() {
+        let _env = EXPORT_ENV.lock().unwrap();
+        let default_retention = chrono::Duration::hours(ExportConfig::DEFAULT_RETENTION_HOURS);
+        let default_interval = Duration::from_secs(ExportConfig::DEFAULT_CLEANUP_INTERVAL_SECS);
+
+        let config = config_with(" 48 ", " 30 ");
+        assert_eq!(config.retention, chrono::Duration::hours(48));
+        assert_eq!(config.cleanup_interval, Duration::from_secs(30));
+
+        // Negative retention, and a zero interval that would panic the cleanup task
+        let config = config_with("-1", "0");
+        assert_eq!(config.retention, default_retention);
+        assert_eq!(config.cleanup_interval, default_interval);
+
+        // Too large for chrono::Duration, and not a number at all
+        let config = config_with(&i64::MAX.to_string(), "soon");
+        assert_eq!(config.retention, default_retention);
+        assert_eq!(config.cleanup_interval, default_interval);
+
+        env::remove_var("EXPORT_RETENTION_HOURS");
+        env::remove_var("EXPORT_CLEANUP_INTERVAL_SECS");
+        let config = ExportConfig::from_env();
+        assert_eq!((config.retention, config.cleanup_interval), (default_retention, default_interval));
+    }
+
+    #[tokio::test]
+    async fn test_sweep_keeps_artifacts_of_tracked_jobs() {
+        let config = test_config(chrono::Duration::zero());
+        let export_dir = config.export_dir.clone();
+        let jobs: Arc<dyn ExportJobRepository> = Arc::new(InMemoryExportJobRepository::new());
+        let users = Arc::new(InMemoryUserRepository::new());
+        let service = ExportServiceImpl::new(jobs.clone(), users, config);
+
+        // A job that is still being written, and a file no job knows about
+        let running = jobs.insert(ExportJob::new(ExportFormat::Csv)).await.unwrap();
+        std::fs::create_dir_all(&export_dir).unwrap();
+        let tracked = export_dir.join(format!("{}.csv", running.id));
+        let orphan = export_dir.join(format!("{}.csv", Uuid::new_v4()));
+        std::fs::write(&tracked, "id\n").unwrap();
+        std::fs::write(&orphan, "id\n").unwrap();
+
+        assert_eq!(service.cleanup_expired().await.unwrap(), 1);
+        assert!(tracked.exists());
+        assert!(!orphan.exists());
+
+        std::fs::remove_dir_all(export_dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_removes_expired_jobs_and_artifacts() {
+        let config = test_config(chrono::Duration::zero());
+        let export_dir = config.export_dir.clone();
+        let service = service_with_users(config).await;
+
+        let created = service
+            .create_export(CreateExportDto { format: ExportFormat::Csv })
+            .await
+            .unwrap();
+        wait_until_finished(&service, created.id).await;
+        let artifact = service.get_export_artifact(created.id).await.unwrap();
+
+        assert_eq!(service.cleanup_expired().await.unwrap(), 1);
+        assert!(!artifact.path.exists());
+        assert!(matches!(
+            service.get_export(created.id).await,
+            Err(AppError::ExportNotFound { .. })
+        ));
+
+        std::fs::remove_dir_all(export_dir).unwrap();
+    }
+}
@@ -1,3 +1,7 @@
 pub mod user_service;
+pub mod export_service;
+pub mod audit_service;
 
-pub use user_service::*;
\ No newline at end of file
+pub use user_service::*;
+pub use export_service::*;
+pub use audit_service::*;
\ No newline at end of file
@@ -1 +1,2 @@
+pub mod settings;
 pub mod validation;
\ No newline at end of file
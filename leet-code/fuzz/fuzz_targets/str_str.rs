@@ -0,0 +1,30 @@
+#![no_main]
+
+// The byte-based str_str solutions against str::find, which reports the
+// same byte offset. Needles are cut from the haystack half the time, since
+// two arbitrary strings almost never contain one another.
+
+use leet_code::problems::first_occurence::{str_str_kmp, str_str_rabin_karp, str_str_v2};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (String, String, Option<(usize, usize)>)| {
+    let (haystack, needle, cut) = input;
+    let needle = match cut {
+        Some((start, len)) if !haystack.is_empty() => {
+            let start = start % haystack.len();
+            let end = (start + len % 16).min(haystack.len());
+            String::from_utf8_lossy(&haystack.as_bytes()[start..end]).into_owned()
+        }
+        _ => needle,
+    };
+
+    let expected = haystack.find(&needle).map_or(-1, |i| i as i32);
+    for (name, solution) in [
+        ("str_str_v2", str_str_v2 as fn(String, String) -> i32),
+        ("str_str_kmp", str_str_kmp),
+        ("str_str_rabin_karp", str_str_rabin_karp),
+    ] {
+        let got = solution(haystack.clone(), needle.clone());
+        assert_eq!(got, expected, "{}({:?}, {:?})", name, haystack, needle);
+    }
+});
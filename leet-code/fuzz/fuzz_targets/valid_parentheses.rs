@@ -0,0 +1,31 @@
+#![no_main]
+
+// is_valid against the textbook definition: a string is valid when removing
+// "()", "[]" and "{}" pairs over and over leaves nothing. Arbitrary text is
+// almost never all brackets, so each input is also mapped onto the six
+// bracket characters and checked a second time.
+
+use leet_code::problems::valid_parentheses::is_valid;
+use libfuzzer_sys::fuzz_target;
+
+fn reference(s: &str) -> bool {
+    if !s.chars().all(|c| "()[]{}".contains(c)) {
+        return false;
+    }
+    let mut s = s.to_string();
+    loop {
+        let shorter = s.replace("()", "").replace("[]", "").replace("{}", "");
+        if shorter.len() == s.len() {
+            return s.is_empty();
+        }
+        s = shorter;
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let text = String::from_utf8_lossy(data).into_owned();
+    let brackets: String = data.iter().map(|b| b"()[]{}"[*b as usize % 6] as char).collect();
+    for s in [text, brackets] {
+        assert_eq!(is_valid(s.clone()), reference(&s), "is_valid({:?})", s);
+    }
+});
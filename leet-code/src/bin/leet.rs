@@ -0,0 +1,480 @@
+// leet: list the solved problems, run their examples, or try them on
+// testcases copied from LeetCode.
+//
+//   cargo run --bin leet -- list
+//   cargo run --bin leet -- list --by-tag
+//   cargo run --bin leet -- list --tag two-pointers --difficulty easy
+//   cargo run --bin leet -- list --random --tag tree
+//   cargo run --bin leet -- run two_sum
+//   cargo run --bin leet -- run two_sum valid_parentheses
+//   cargo run --bin leet -- run --all
+//   cargo run --bin leet -- try two_sum '[2,7,11,15]' 9
+//   pbpaste | cargo run --bin leet -- try two_sum
+//   cargo run --bin leet -- run max_depth --dot cases.dot
+//   cargo run --release --bin leet -- complexity --all
+//   cargo run --bin leet -- practice --difficulty medium
+//   cargo run --bin leet -- practice number_of_islands
+//   cargo run --release --bin leet -- report > report.md
+//   cargo run --release --bin leet -- report --csv --tag tree > report.csv
+//
+// `try` takes one parameter per argument, or reads the testcase box's
+// contents (one parameter per line, any number of testcases) from stdin.
+// `--dot <file>` also writes the trees or lists in the cases as Graphviz.
+// `--tag` (repeatable; a problem needs every one), `--difficulty` and
+// `--random` (one of the matching problems, for practice) pick problems by
+// their metadata instead of by name for list, run and complexity.
+// `run` also checks the cases in cases/<problem>.toml or .json, if any
+// ($LEET_CASES points it at another directory).
+// `complexity` times each solution on growing generated inputs and prints
+// the big-O class that fits, flagging anything quadratic or worse.
+// `practice` picks a random problem (taking --tag and --difficulty) and
+// shows its statement; `practice <problem>` then runs the solution on its
+// examples and case files without showing them, and records the attempt in
+// .progress.toml ($LEET_PROGRESS points it at another file).
+// `report` checks and times every solution and variant of the named or
+// selected problems (all of them by default) and prints a Markdown table,
+// or CSV with `--csv`, of the cases each passes and its time next to the
+// fastest solution's.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process::{self, ExitCode};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use leet_code::complexity::{pseudo_random, Estimate};
+use leet_code::practice::{self, Attempt, Progress};
+use leet_code::report;
+use leet_code::{cases, find, Filter, Outcome, Runnable};
+
+const USAGE: &str = "usage: leet list [--by-tag] | leet run <problem>... | leet run --all | leet try <problem> [<parameter>...] [--dot <file>] | leet complexity <problem>... | leet complexity --all | leet practice [<problem>] | leet report [<problem>...] [--csv]
+  list, run, complexity and report also take --tag <tag> (repeatable), --difficulty <easy|medium|hard> and --random; practice takes --tag and --difficulty";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let options = match Options::take(&mut args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+    let dot = options.dot;
+    if options.by_tag && args.first() != Some(&"list") {
+        eprintln!("--by-tag only applies to `leet list`");
+        return ExitCode::FAILURE;
+    }
+    if options.csv && args.first() != Some(&"report") {
+        eprintln!("--csv only applies to `leet report`");
+        return ExitCode::FAILURE;
+    }
+    if options.selecting() && !matches!(args.as_slice(), ["list" | "run" | "complexity" | "practice" | "report", ..]) {
+        eprintln!("--tag, --difficulty and --random apply to list, run, complexity, practice and report");
+        return ExitCode::FAILURE;
+    }
+
+    match args.as_slice() {
+        ["list"] => match options.select() {
+            Some(selected) if options.by_tag => {
+                list_by_tag(&selected);
+                ExitCode::SUCCESS
+            }
+            Some(selected) => {
+                list(&selected);
+                ExitCode::SUCCESS
+            }
+            None => ExitCode::FAILURE,
+        },
+        ["run", "--all"] if dot.is_none() => match options.select() {
+            Some(selected) => run(selected),
+            None => ExitCode::FAILURE,
+        },
+        ["run"] if dot.is_none() && options.selecting() => match options.select() {
+            Some(selected) => run(selected),
+            None => ExitCode::FAILURE,
+        },
+        ["run", names @ ..] if !names.is_empty() && !options.selecting() => {
+            let Some(selected) = select(names) else {
+                return ExitCode::FAILURE;
+            };
+            if let Some(path) = dot {
+                let [problem] = selected[..] else {
+                    eprintln!("--dot draws one problem at a time");
+                    return ExitCode::FAILURE;
+                };
+                if let Err(message) = write_dot(problem, None, path) {
+                    eprintln!("{}", message);
+                    return ExitCode::FAILURE;
+                }
+            }
+            run(selected)
+        }
+        ["try", name, parameters @ ..] => {
+            let Some(problem) = find(name) else {
+                eprintln!("unknown problem '{}', see `leet list`", name);
+                return ExitCode::FAILURE;
+            };
+            let testcases = if parameters.is_empty() {
+                let mut stdin = String::new();
+                if let Err(err) = io::stdin().read_to_string(&mut stdin) {
+                    eprintln!("could not read stdin: {}", err);
+                    return ExitCode::FAILURE;
+                }
+                stdin
+            } else {
+                parameters.join("\n")
+            };
+            if let Some(path) = dot {
+                if let Err(message) = write_dot(problem, Some(&testcases), path) {
+                    eprintln!("{}", message);
+                    return ExitCode::FAILURE;
+                }
+            }
+            try_testcases(problem, &testcases)
+        }
+        ["complexity", "--all"] if dot.is_none() => match options.select() {
+            Some(selected) => {
+                complexity(selected);
+                ExitCode::SUCCESS
+            }
+            None => ExitCode::FAILURE,
+        },
+        ["complexity"] if dot.is_none() && options.selecting() => match options.select() {
+            Some(selected) => {
+                complexity(selected);
+                ExitCode::SUCCESS
+            }
+            None => ExitCode::FAILURE,
+        },
+        ["complexity", names @ ..] if dot.is_none() && !names.is_empty() && !options.selecting() => match select(names) {
+            Some(selected) => {
+                complexity(selected);
+                ExitCode::SUCCESS
+            }
+            None => ExitCode::FAILURE,
+        },
+        ["practice"] if dot.is_none() => match (Options { random: true, ..options }).select() {
+            Some(selected) => pick(selected[0]),
+            None => ExitCode::FAILURE,
+        },
+        ["practice", name] if dot.is_none() && !options.selecting() => match find(name) {
+            Some(problem) => attempt(problem),
+            None => {
+                eprintln!("unknown problem '{}', see `leet list`", name);
+                ExitCode::FAILURE
+            }
+        },
+        ["report"] if dot.is_none() => match options.select() {
+            Some(selected) => compare(selected, options.csv),
+            None => ExitCode::FAILURE,
+        },
+        ["report", names @ ..] if dot.is_none() && !options.selecting() => match select(names) {
+            Some(selected) => compare(selected, options.csv),
+            None => ExitCode::FAILURE,
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// The flags, taken out of the arguments wherever they appear
+#[derive(Default)]
+struct Options<'a> {
+    dot: Option<&'a str>,
+    filter: Filter,
+    random: bool,
+    by_tag: bool,
+    csv: bool,
+}
+
+impl<'a> Options<'a> {
+    fn take(args: &mut Vec<&'a str>) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut i = 0;
+        while i < args.len() {
+            match args[i] {
+                "--random" => options.random = true,
+                "--by-tag" => options.by_tag = true,
+                "--csv" => options.csv = true,
+                flag @ ("--dot" | "--tag" | "--difficulty") => {
+                    let Some(&value) = args.get(i + 1) else {
+                        return Err(format!("{} needs a value", flag));
+                    };
+                    match flag {
+                        "--dot" => options.dot = Some(value),
+                        "--tag" => options.filter.tags.push(value.to_string()),
+                        _ => options.filter.difficulty = Some(value.parse()?),
+                    }
+                    args.remove(i);
+                }
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            }
+            args.remove(i);
+        }
+        Ok(options)
+    }
+
+    // Whether the problems are picked by the flags rather than by name
+    fn selecting(&self) -> bool {
+        self.filter != Filter::default() || self.random
+    }
+
+    // The problems that pass the filter, or one of them with --random
+    fn select(&self) -> Option<Vec<&'static dyn Runnable>> {
+        let selected = self.filter.apply();
+        if selected.is_empty() {
+            eprintln!("no problem matches, see `leet list --by-tag`");
+            return None;
+        }
+        if !self.random {
+            return Some(selected);
+        }
+        // Practice picks need to differ between runs, not to be good random numbers
+        let seed = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_nanos() as u64);
+        let pick = pseudo_random(seed ^ u64::from(process::id())).nth(1).unwrap_or(0) as usize % selected.len();
+        Some(vec![selected[pick]])
+    }
+}
+
+fn select(names: &[&str]) -> Option<Vec<&'static dyn Runnable>> {
+    let mut selected = Vec::new();
+    for name in names {
+        match find(name) {
+            Some(problem) => selected.push(problem),
+            None => {
+                eprintln!("unknown problem '{}', see `leet list`", name);
+                return None;
+            }
+        }
+    }
+    Some(selected)
+}
+
+fn list(selected: &[&dyn Runnable]) {
+    for problem in selected {
+        println!("{}", summary(*problem));
+    }
+}
+
+// Every tag in use, then the selected problems that have it
+fn list_by_tag(selected: &[&dyn Runnable]) {
+    let mut by_tag: BTreeMap<&str, Vec<&dyn Runnable>> = BTreeMap::new();
+    for problem in selected {
+        for tag in problem.tags() {
+            by_tag.entry(tag).or_default().push(*problem);
+        }
+    }
+    for (tag, problems) in by_tag {
+        println!("{} ({})", tag, problems.len());
+        for problem in problems {
+            println!("  {}", summary(problem));
+        }
+    }
+}
+
+fn summary(problem: &dyn Runnable) -> String {
+    format!(
+        "{:>5}  {:<20} {:<6}  {} [{}]",
+        problem.id(),
+        problem.name(),
+        problem.difficulty(),
+        problem.title(),
+        problem.tags().join(", ")
+    )
+}
+
+fn run(selected: Vec<&dyn Runnable>) -> ExitCode {
+    let dir = cases::dir();
+    let mut failed = 0;
+    for problem in selected {
+        println!("{}. {} ({})", problem.id(), problem.title(), problem.name());
+        for outcome in problem.check() {
+            failed += report(&outcome);
+        }
+        match cases::check(problem, &dir) {
+            Ok(files) => {
+                for (path, outcomes) in files {
+                    println!("  from {}", path.display());
+                    for outcome in outcomes {
+                        failed += report(&outcome);
+                    }
+                }
+            }
+            Err(err) => {
+                failed += 1;
+                println!("  Fail  {}", err);
+            }
+        }
+    }
+
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("{} case(s) failed", failed);
+        ExitCode::FAILURE
+    }
+}
+
+// Prints one outcome, returning how many failures it adds
+fn report(outcome: &Outcome) -> usize {
+    let input = match outcome.variant {
+        Some(variant) => format!("[{}] {}", variant, outcome.input),
+        None => outcome.input.clone(),
+    };
+    if outcome.passed {
+        println!("  Ok    {} => {}", input, outcome.got);
+        return 0;
+    }
+    println!("  Fail  {} => {} (expected {})", input, outcome.got, outcome.expected);
+    for line in outcome.detail.iter().flat_map(|detail| detail.lines()) {
+        println!("          {}", line);
+    }
+    1
+}
+
+fn complexity(selected: Vec<&dyn Runnable>) {
+    let mut flagged = 0;
+    for problem in selected {
+        println!("{}. {} ({})", problem.id(), problem.title(), problem.name());
+        let estimates = problem.complexity();
+        if estimates.is_empty() {
+            println!("  no input generator");
+        }
+        for Estimate { variant, samples, class } in estimates {
+            let solution = match variant {
+                Some(variant) => format!("[{}]", variant),
+                None => "main".to_string(),
+            };
+            let (Some(&(first, _)), Some(&(last, time))) = (samples.first(), samples.last()) else {
+                continue;
+            };
+            let label = class.map_or_else(|| "?".to_string(), |class| class.to_string());
+            let mut line = format!("  {:<11} {:<12} n = {}..{}, {:.2?} at {}", label, solution, first, last, time, last);
+            if class.is_some_and(|class| class.is_quadratic_or_worse()) {
+                flagged += 1;
+                line.push_str("  <- quadratic or worse");
+            }
+            println!("{}", line);
+        }
+    }
+    if flagged > 0 {
+        eprintln!("{} solution(s) look quadratic or worse", flagged);
+    }
+}
+
+fn try_testcases(problem: &dyn Runnable, testcases: &str) -> ExitCode {
+    match problem.solve(testcases) {
+        Ok(solved) => {
+            println!("{}. {} ({})", problem.id(), problem.title(), problem.name());
+            for (input, output) in solved {
+                println!("  {} => {}", input, output);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("invalid testcase for {}: {}", problem.name(), err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// The report on every solution of the selected problems, on stdout
+fn compare(selected: Vec<&dyn Runnable>, csv: bool) -> ExitCode {
+    let dir = cases::dir();
+    let mut rows = Vec::new();
+    let mut broken = 0;
+    for problem in selected {
+        match report::rows(problem, &dir) {
+            Ok(problem_rows) => rows.extend(problem_rows),
+            Err(err) => {
+                broken += 1;
+                eprintln!("{}", err);
+            }
+        }
+    }
+    print!("{}", if csv { report::csv(&rows) } else { report::markdown(&rows) });
+
+    let failing = rows.iter().filter(|row| row.failed()).count();
+    if failing > 0 {
+        eprintln!("{} solution(s) failed a case", failing);
+    }
+    if failing + broken == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+// Seconds since the Unix epoch, as the progress file keeps them
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+}
+
+// Shows the problem to practise and starts its clock
+fn pick(problem: &dyn Runnable) -> ExitCode {
+    let path = Progress::path();
+    if let Err(err) = Progress::update(&path, |progress| progress.start(problem.name(), now())) {
+        eprintln!("{}", err);
+        return ExitCode::FAILURE;
+    }
+    println!("{}\n", summary(problem));
+    match practice::statement(problem.name()) {
+        Some(statement) => println!("{}\n", statement),
+        None => println!("The statement is at {}\n", practice::url(problem.title())),
+    }
+    println!("Solve it in src/problems/{}.rs, then check it on the hidden cases with", problem.name());
+    println!("  cargo run --bin leet -- practice {}", problem.name());
+    ExitCode::SUCCESS
+}
+
+// Runs the main solution on the examples and case files without showing
+// them, and records how many pass
+fn attempt(problem: &dyn Runnable) -> ExitCode {
+    let files = match cases::check(problem, &cases::dir()) {
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let outcomes: Vec<Outcome> = problem.check().into_iter().chain(files.into_iter().flat_map(|(_, outcomes)| outcomes)).collect();
+    let main: Vec<&Outcome> = outcomes.iter().filter(|outcome| outcome.variant.is_none()).collect();
+    let passed = main.iter().filter(|outcome| outcome.passed).count();
+    let attempt = Attempt { at: now(), passed, total: main.len() };
+
+    let path = Progress::path();
+    let record = match Progress::update(&path, |progress| progress.record(problem.name(), attempt).clone()) {
+        Ok(record) => record,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("{}. {} ({})", problem.id(), problem.title(), problem.name());
+    println!("  attempt {}: {}/{} hidden cases pass", record.attempts.len(), passed, main.len());
+    if !attempt.is_solved() {
+        return ExitCode::FAILURE;
+    }
+    match record.solve_time() {
+        Some(seconds) => println!("  solved in {}", practice::format_duration(seconds)),
+        None => println!("  solved"),
+    }
+    ExitCode::SUCCESS
+}
+
+fn write_dot(problem: &dyn Runnable, testcases: Option<&str>, path: &str) -> Result<(), String> {
+    let dot = problem
+        .dot(testcases)
+        .map_err(|err| format!("invalid testcase for {}: {}", problem.name(), err))?
+        .ok_or_else(|| format!("{} has no tree or list to draw", problem.name()))?;
+    fs::write(path, dot).map_err(|err| format!("could not write {}: {}", path, err))?;
+    eprintln!("wrote {}", path);
+    Ok(())
+}
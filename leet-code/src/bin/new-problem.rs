@@ -0,0 +1,76 @@
+// new-problem: start a problem from the template, ready for its solution.
+//
+//   cargo run --bin new-problem -- --id 226 --title "Invert Binary Tree" --tags tree
+//   cargo run --bin new-problem -- --id 15 --title "3Sum" --name three_sum --difficulty medium --tags array,two-pointers
+//
+// Writes src/problems/<name>.rs (a solution stub and its Problem impl,
+// registered), adds it to src/problems/mod.rs and creates an empty
+// cases/<name>.toml. The name is the title in snake case unless --name is
+// given; --tags takes a comma-separated list and can be repeated;
+// --difficulty defaults to medium. $LEET_ROOT points it at another crate
+// directory.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use leet_code::scaffold::{module_name, NewProblem, ScaffoldError};
+use leet_code::{problems, Difficulty};
+
+const USAGE: &str =
+    "usage: new-problem --id <number> --title <title> [--name <module>] [--difficulty <easy|medium|hard>] [--tags <tag,...>]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let problem = match parse(&args) {
+        Ok(problem) => problem,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(taken) = problems::all().iter().find(|taken| taken.id() == problem.id || taken.name() == problem.name) {
+        eprintln!("{}. {} ({}) is already solved", taken.id(), taken.title(), taken.name());
+        return ExitCode::FAILURE;
+    }
+
+    match problem.create(&root()) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+            println!("next: LeetCode's signature and examples, then `cargo run --bin leet -- run {}`", problem.name);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse(args: &[String]) -> Result<NewProblem, String> {
+    let (mut id, mut title, mut name, mut difficulty) = (None, None, None, None);
+    let mut tags = Vec::new();
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("{} needs a value", flag))?;
+        match flag.as_str() {
+            "--id" => id = Some(value.parse::<u32>().map_err(|_| format!("--id takes a number, not '{}'", value))?),
+            "--title" => title = Some(value),
+            "--name" => name = Some(value),
+            "--difficulty" => difficulty = Some(value.parse()?),
+            "--tags" => tags.extend(value.split(',').map(str::trim).filter(|tag| !tag.is_empty()).map(String::from)),
+            _ => return Err(format!("unknown flag '{}'", flag)),
+        }
+    }
+    let id = id.ok_or("--id is required")?;
+    let title = title.ok_or("--title is required")?;
+
+    let name = module_name(name.unwrap_or(title)).ok_or_else(|| ScaffoldError::Name { title: title.clone() }.to_string())?;
+    Ok(NewProblem { id, title: title.clone(), name, difficulty: difficulty.unwrap_or(Difficulty::Medium), tags })
+}
+
+fn root() -> PathBuf {
+    env::var_os("LEET_ROOT").map_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).to_path_buf(), PathBuf::from)
+}
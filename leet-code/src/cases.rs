@@ -0,0 +1,183 @@
+//! Test cases kept in files next to the crate rather than in the code, so
+//! new ones can be added without recompiling.
+//!
+//! `cases/<problem>.toml` and `cases/<problem>.json` are both optional.
+//! Each holds a list of cases whose `input` and `expected` are written in
+//! LeetCode's testcase format (see [`parse`](crate::parse)), one parameter
+//! per line:
+//!
+//! ```toml
+//! [[case]]
+//! input = """
+//! [2,7,11,15]
+//! 9
+//! """
+//! expected = "[0,1]"
+//! ```
+//!
+//! ```json
+//! { "case": [{ "input": "[2,7,11,15]\n9", "expected": "[0,1]" }] }
+//! ```
+//!
+//! `expected` is compared with what `Problem::run` returns, so it has to be
+//! in the same form: group_anagram, for one, sorts its groups.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::parse::ParseError;
+use crate::{Outcome, Runnable};
+
+/// The file extensions looked for, in the order they are checked.
+pub const EXTENSIONS: [&str; 2] = ["toml", "json"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct FileCase {
+    pub input: String,
+    pub expected: String,
+}
+
+#[derive(Deserialize)]
+struct CaseFile {
+    #[serde(default)]
+    case: Vec<FileCase>,
+}
+
+#[derive(Debug)]
+pub enum CasesError {
+    /// The file exists but could not be read.
+    Io { path: PathBuf, error: io::Error },
+    /// The file is not valid TOML or JSON, or does not have the case layout.
+    Format { path: PathBuf, message: String },
+    /// Case number `index` (from 1) does not fit the problem's types.
+    Case { path: PathBuf, index: usize, error: ParseError },
+}
+
+impl fmt::Display for CasesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CasesError::Io { path, error } => write!(f, "could not read {}: {}", path.display(), error),
+            CasesError::Format { path, message } => write!(f, "{}: {}", path.display(), message.trim_end()),
+            CasesError::Case { path, index, error } => write!(f, "{}: case {}: {}", path.display(), index, error),
+        }
+    }
+}
+
+impl Error for CasesError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CasesError::Io { error, .. } => Some(error),
+            CasesError::Format { .. } => None,
+            CasesError::Case { error, .. } => Some(error),
+        }
+    }
+}
+
+/// `$LEET_CASES` if set, otherwise the crate's own `cases/` directory.
+pub fn dir() -> PathBuf {
+    std::env::var_os("LEET_CASES").map_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("cases"), PathBuf::from)
+}
+
+/// The cases in each of `problem`'s files under `dir`, by file. Missing
+/// files are skipped.
+pub fn load(dir: &Path, problem: &str) -> Result<Vec<(PathBuf, Vec<FileCase>)>, CasesError> {
+    let mut files = Vec::new();
+    for extension in EXTENSIONS {
+        let path = dir.join(format!("{}.{}", problem, extension));
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+            Err(error) => return Err(CasesError::Io { path, error }),
+        };
+        let parsed = match extension {
+            "toml" => toml::from_str::<CaseFile>(&text).map_err(|err| err.to_string()),
+            _ => serde_json::from_str::<CaseFile>(&text).map_err(|err| err.to_string()),
+        };
+        match parsed {
+            Ok(file) => files.push((path, file.case)),
+            Err(message) => return Err(CasesError::Format { path, message }),
+        }
+    }
+    Ok(files)
+}
+
+/// Runs `problem`'s file cases under `dir` through the solution and each
+/// of its variants, by file.
+pub fn check(problem: &dyn Runnable, dir: &Path) -> Result<Vec<(PathBuf, Vec<Outcome>)>, CasesError> {
+    let mut checked = Vec::new();
+    for (path, cases) in load(dir, problem.name())? {
+        let mut outcomes = Vec::new();
+        for (i, case) in cases.iter().enumerate() {
+            match problem.check_case(case) {
+                Ok(ran) => outcomes.extend(ran),
+                Err(error) => return Err(CasesError::Case { path, index: i + 1, error }),
+            }
+        }
+        checked.push((path, outcomes));
+    }
+    Ok(checked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find;
+
+    // A fresh temporary directory holding `files`, removed when dropped
+    fn cases_dir(name: &str, files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::Builder::new().prefix(&format!("leet-code-cases-{}-", name)).tempdir().unwrap();
+        for (file, text) in files {
+            std::fs::write(dir.path().join(file), text).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn reads_toml_and_json_files() {
+        let dir = cases_dir(
+            "both",
+            &[
+                ("two_sum.toml", "[[case]]\ninput = \"\"\"\n[3,3]\n6\n\"\"\"\nexpected = \"[0,1]\"\n"),
+                ("two_sum.json", r#"{"case": [{"input": "[1,5,9]\n14", "expected": "[1,2]"}]}"#),
+            ],
+        );
+        let checked = check(find("two_sum").unwrap(), dir.path()).unwrap();
+        let files: Vec<_> = checked.iter().map(|(path, _)| path.file_name().unwrap().to_str().unwrap()).collect();
+        assert_eq!(files, ["two_sum.toml", "two_sum.json"]);
+        assert!(checked.iter().all(|(_, outcomes)| outcomes.iter().filter(|outcome| outcome.variant.is_none()).count() == 1));
+        assert!(checked.iter().flat_map(|(_, outcomes)| outcomes).all(|outcome| outcome.passed));
+    }
+
+    #[test]
+    fn runs_variants_and_reports_wrong_expectations() {
+        let dir = cases_dir("wrong", &[("sell_stock.toml", "[[case]]\ninput = \"[1,2]\"\nexpected = \"7\"\n")]);
+        let (_, outcomes) = check(find("sell_stock").unwrap(), dir.path()).unwrap().remove(0);
+        assert_eq!(outcomes.iter().map(|outcome| outcome.variant).collect::<Vec<_>>(), [None, Some("v2"), Some("streaming")]);
+        assert!(outcomes.iter().all(|outcome| !outcome.passed && outcome.got == "1"));
+    }
+
+    #[test]
+    fn missing_files_are_no_cases() {
+        let dir = cases_dir("empty", &[]);
+        assert!(check(find("two_sum").unwrap(), dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn errors_name_the_file_and_case() {
+        let dir = cases_dir(
+            "bad",
+            &[
+                ("two_sum.toml", "[[case]]\ninput = \"[1]\\n1\"\nexpected = \"[]\"\n[[case]]\ninput = \"[1]\"\nexpected = \"[]\"\n"),
+                ("sell_stock.json", "{\"case\": ["),
+            ],
+        );
+        let error = check(find("two_sum").unwrap(), dir.path()).unwrap_err().to_string();
+        assert!(error.ends_with("two_sum.toml: case 2: testcase is missing a parameter"), "{}", error);
+        let error = check(find("sell_stock").unwrap(), dir.path()).unwrap_err();
+        assert!(matches!(error, CasesError::Format { .. }), "{}", error);
+    }
+}
@@ -0,0 +1,211 @@
+//! Estimates how a solution's running time grows with its input.
+//!
+//! [`measure`] times a solution on generated inputs of doubling size until
+//! a single run gets slow, and [`fit`] picks the growth [`Class`] whose
+//! curve best matches the timings. It is a rough check, not a proof: it is
+//! there to catch a solution that is quadratic by accident, like a
+//! `chars().nth(i)` inside a loop over `i`.
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The first input size tried.
+pub const START_SIZE: usize = 256;
+/// Sizes stop doubling here even if the solution is still fast.
+pub const MAX_SIZE: usize = 1 << 16;
+/// Sizes stop doubling once one run takes longer than this.
+pub const SLOW_RUN: Duration = Duration::from_millis(20);
+
+// Each sample is the best of this many batches, and a batch repeats the run
+// until it takes about BATCH_TIME, so that short runs are not all timer noise
+const BATCHES: usize = 3;
+const BATCH_TIME: Duration = Duration::from_millis(1);
+const MAX_REPEATS: u128 = 1_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Class {
+    Constant,
+    Logarithmic,
+    Linear,
+    Linearithmic,
+    Quadratic,
+    Cubic,
+}
+
+impl Class {
+    pub const ALL: [Class; 6] = [
+        Class::Constant,
+        Class::Logarithmic,
+        Class::Linear,
+        Class::Linearithmic,
+        Class::Quadratic,
+        Class::Cubic,
+    ];
+
+    /// The shape of the curve, up to a constant factor.
+    pub fn grows(self, n: f64) -> f64 {
+        match self {
+            Class::Constant => 1.0,
+            Class::Logarithmic => n.log2(),
+            Class::Linear => n,
+            Class::Linearithmic => n * n.log2(),
+            Class::Quadratic => n * n,
+            Class::Cubic => n * n * n,
+        }
+    }
+
+    /// Whether this is worth flagging: none of the problems here needs to
+    /// be quadratic.
+    pub fn is_quadratic_or_worse(self) -> bool {
+        self >= Class::Quadratic
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Class::Constant => "O(1)",
+            Class::Logarithmic => "O(log n)",
+            Class::Linear => "O(n)",
+            Class::Linearithmic => "O(n log n)",
+            Class::Quadratic => "O(n²)",
+            Class::Cubic => "O(n³)",
+        };
+        f.pad(label)
+    }
+}
+
+/// The timings of one solution and the class that fits them.
+#[derive(Debug, Clone)]
+pub struct Estimate {
+    /// Which of [`Problem::variants`](crate::Problem::variants) ran, `None`
+    /// for the main solution.
+    pub variant: Option<&'static str>,
+    /// Input size and the time of one run, in increasing size.
+    pub samples: Vec<(usize, Duration)>,
+    /// `None` when there were too few samples to tell.
+    pub class: Option<Class>,
+}
+
+/// Times `run` on `generate(size)` for doubling sizes, from [`START_SIZE`]
+/// until a run takes longer than [`SLOW_RUN`] or the size reaches
+/// [`MAX_SIZE`]. Inputs are generated and cloned outside the timed section.
+pub fn measure<I: Clone, O>(generate: impl Fn(usize) -> I, run: impl Fn(I) -> O) -> Vec<(usize, Duration)> {
+    let mut samples = Vec::new();
+    let mut size = START_SIZE;
+    while size <= MAX_SIZE {
+        let input = generate(size);
+        let time = best_time(&input, &run);
+        samples.push((size, time));
+
+        if time > SLOW_RUN {
+            break;
+        }
+        size *= 2;
+    }
+    samples
+}
+
+// The fastest of BATCHES batches, per run. Every run is timed on its own,
+// with its clone made just before: with many inputs alive at once, freeing
+// them gets slower the bigger they are, which would bend every curve.
+fn best_time<I: Clone, O>(input: &I, run: impl Fn(I) -> O) -> Duration {
+    let timed = |input: I| {
+        let started = Instant::now();
+        black_box(run(input));
+        started.elapsed()
+    };
+    let first = timed(input.clone());
+    let repeats = (BATCH_TIME.as_nanos() / first.as_nanos().max(1)).clamp(1, MAX_REPEATS) as u32;
+
+    let mut best = first;
+    for _ in 0..BATCHES {
+        let total: Duration = (0..repeats).map(|_| timed(input.clone())).sum();
+        best = best.min(total / repeats);
+    }
+    best
+}
+
+/// The class whose curve, scaled by the best constant, is closest to the
+/// timings. Compared on a log scale, so every size counts the same however
+/// long it took. Caches and allocators bend real timings a little, so a
+/// faster-growing class has to fit at least twice as well to be picked over
+/// a slower-growing one. `None` with fewer than three samples.
+pub fn fit(samples: &[(usize, Duration)]) -> Option<Class> {
+    if samples.len() < 3 {
+        return None;
+    }
+    let error = |class: Class| {
+        // ln(time) - ln(f(n)) is flat when the class fits; its spread is the error
+        let residuals: Vec<f64> = samples
+            .iter()
+            .map(|&(n, time)| time.as_secs_f64().max(1e-9).ln() - class.grows(n as f64).ln())
+            .collect();
+        let mean = residuals.iter().sum::<f64>() / residuals.len() as f64;
+        residuals.iter().map(|r| (r - mean).powi(2)).sum::<f64>()
+    };
+
+    let mut best = (Class::Constant, error(Class::Constant));
+    for class in &Class::ALL[1..] {
+        let error = error(*class);
+        if error < best.1 / 2.0 {
+            best = (*class, error);
+        }
+    }
+    Some(best.0)
+}
+
+/// Deterministic pseudo-random numbers for generating inputs, so every
+/// measurement sees the same data.
+pub fn pseudo_random(seed: u64) -> impl Iterator<Item = u64> {
+    std::iter::successors(Some(seed), |x| Some(x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407)))
+        .map(|x| x >> 33)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Timings that follow `class` exactly, as from a perfectly quiet machine
+    fn curve(class: Class) -> Vec<(usize, Duration)> {
+        (8..16).map(|p| 1 << p).map(|n| (n, Duration::from_secs_f64(class.grows(n as f64) * 1e-9))).collect()
+    }
+
+    #[test]
+    fn fits_every_class_to_its_own_curve() {
+        for class in Class::ALL {
+            assert_eq!(fit(&curve(class)), Some(class), "{}", class);
+        }
+    }
+
+    #[test]
+    fn fit_tolerates_noise() {
+        let noisy: Vec<_> = curve(Class::Quadratic)
+            .into_iter()
+            .zip([1.2, 0.9, 1.1, 0.8, 1.0, 1.3, 0.9, 1.1])
+            .map(|((n, time), factor)| (n, time.mul_f64(factor)))
+            .collect();
+        assert_eq!(fit(&noisy), Some(Class::Quadratic));
+    }
+
+    #[test]
+    fn too_few_samples_are_not_fitted() {
+        assert_eq!(fit(&curve(Class::Linear)[..2]), None);
+    }
+
+    #[test]
+    fn measure_stops_at_slow_runs() {
+        let samples = measure(|n| n, |n| std::thread::sleep(Duration::from_micros(n as u64 * 20)));
+        assert!(samples.len() < 5, "{:?}", samples);
+        assert!(samples.last().unwrap().1 > SLOW_RUN);
+    }
+
+    #[test]
+    fn classes_print_as_big_o() {
+        assert_eq!(format!("{:<8}|", Class::Linear), "O(n)    |");
+        assert_eq!(Class::Quadratic.to_string(), "O(n²)");
+        assert!(Class::Cubic.is_quadratic_or_worse());
+        assert!(!Class::Linearithmic.is_quadratic_or_worse());
+    }
+}
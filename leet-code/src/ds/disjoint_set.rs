@@ -0,0 +1,187 @@
+// Union-find over the elements 0..n. Each set is a tree of parent links
+// whose root names the set. Two tricks keep the trees flat:
+// • union by rank hangs the shallower tree under the deeper one, so a tree
+//   of rank r has at least 2^r elements and no path is longer than log2(n)
+// • path compression points every element `find` passes straight at the
+//   root, so later finds on them take one step
+// Together they make each operation nearly O(1) amortized.
+
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    // An upper bound on each root's tree height; meaningless for non-roots
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    /// `n` elements, each in a set of its own.
+    pub fn new(n: usize) -> Self {
+        DisjointSet { parent: (0..n).collect(), rank: vec![0; n], size: vec![1; n], count: n }
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The number of disjoint sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The root of `x`'s set, the same for every element of it.
+    ///
+    /// # Panics
+    ///
+    /// If `x` is not below [`DisjointSet::len`].
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Second pass: point the whole path at the root
+        let mut node = x;
+        while self.parent[node] != root {
+            node = std::mem::replace(&mut self.parent[node], root);
+        }
+        root
+    }
+
+    /// Merges the sets of `a` and `b`. `false` if they were already one set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (low, high) = if self.rank[a] < self.rank[b] { (a, b) } else { (b, a) };
+        self.parent[low] = high;
+        self.size[high] += self.size[low];
+        if self.rank[low] == self.rank[high] {
+            self.rank[high] += 1;
+        }
+        self.count -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// How many elements are in `x`'s set.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn unions_merge_sets() {
+        let mut sets = DisjointSet::new(5);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(3, 4));
+        assert!(!sets.union(1, 0));
+        assert!(sets.connected(0, 1) && !sets.connected(1, 3));
+        assert_eq!((sets.count(), sets.size_of(4), sets.size_of(2)), (3, 2, 1));
+        assert!(sets.union(1, 4));
+        assert_eq!((sets.count(), sets.size_of(0)), (2, 4));
+        assert!(DisjointSet::new(0).is_empty());
+    }
+
+    // Up to 24 elements and a list of unions between them
+    fn unions() -> impl Strategy<Value = (usize, Vec<(usize, usize)>)> {
+        (1..=24usize).prop_flat_map(|n| (Just(n), prop::collection::vec((0..n, 0..n), 0..40)))
+    }
+
+    fn union_all(n: usize, unions: &[(usize, usize)]) -> DisjointSet {
+        let mut sets = DisjointSet::new(n);
+        for &(a, b) in unions {
+            sets.union(a, b);
+        }
+        sets
+    }
+
+    proptest! {
+        // `connected` is an equivalence relation whatever the unions were
+        #[test]
+        fn connected_is_reflexive_and_symmetric((n, unions) in unions()) {
+            let mut sets = union_all(n, &unions);
+            for a in 0..n {
+                prop_assert!(sets.connected(a, a));
+                for b in 0..n {
+                    prop_assert_eq!(sets.connected(a, b), sets.connected(b, a));
+                }
+            }
+        }
+
+        #[test]
+        fn connected_is_transitive((n, unions) in unions()) {
+            let mut sets = union_all(n, &unions);
+            for a in 0..n {
+                for b in 0..n {
+                    for c in 0..n {
+                        if sets.connected(a, b) && sets.connected(b, c) {
+                            prop_assert!(sets.connected(a, c), "{} ~ {} ~ {}", a, b, c);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Against the naive version: every element carries its set's label,
+        // and a union relabels one whole set
+        #[test]
+        fn counts_match_relabelling((n, unions) in unions()) {
+            let mut sets = DisjointSet::new(n);
+            let mut labels: Vec<usize> = (0..n).collect();
+            for (a, b) in unions {
+                let merged = labels[a] != labels[b];
+                prop_assert_eq!(sets.union(a, b), merged, "union({}, {})", a, b);
+                if merged {
+                    let (from, to) = (labels[b], labels[a]);
+                    labels.iter_mut().filter(|label| **label == from).for_each(|label| *label = to);
+                }
+            }
+
+            let mut distinct = labels.clone();
+            distinct.sort();
+            distinct.dedup();
+            prop_assert_eq!(sets.count(), distinct.len());
+            for x in 0..n {
+                let root = sets.find(x);
+                // Roots are their own parent, and compression leaves x one step away
+                prop_assert_eq!(sets.find(root), root);
+                prop_assert_eq!(sets.parent[x], root);
+                prop_assert_eq!(sets.size_of(x), labels.iter().filter(|&&label| label == labels[x]).count());
+            }
+        }
+    }
+
+    #[test]
+    fn rank_keeps_trees_shallow() {
+        // Unions of equal halves: the worst case for rank, rank log2(n) at the end
+        let n = 1 << 10;
+        let mut sets = DisjointSet::new(n);
+        let mut width = 1;
+        while width < n {
+            for start in (0..n).step_by(2 * width) {
+                sets.union(start, start + width);
+            }
+            width *= 2;
+        }
+        assert_eq!(sets.count(), 1);
+        let root = sets.find(0);
+        assert_eq!(sets.rank[root], 10);
+        assert!(sets.rank.iter().all(|&rank| rank <= 10));
+    }
+}
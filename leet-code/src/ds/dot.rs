@@ -0,0 +1,44 @@
+//! Graphviz output, for looking at a structure instead of reading it:
+//!
+//! ```text
+//! cargo run --bin leet -- run max_depth --dot cases.dot
+//! dot -Tsvg cases.dot > cases.svg
+//! ```
+
+/// Structures that can be drawn with Graphviz.
+pub trait ToDot {
+    /// Node and edge statements, with every node id starting with `prefix`
+    /// so several structures can share one graph.
+    fn dot_statements(&self, prefix: &str) -> String;
+
+    /// A complete DOT document holding just this structure.
+    fn to_dot(&self) -> String {
+        format!("digraph {{\n{}}}\n", self.dot_statements("n"))
+    }
+}
+
+/// One DOT document with a labelled box per `(label, statements)` pair.
+pub fn clusters(parts: &[(String, String)]) -> String {
+    let mut out = String::from("digraph {\n");
+    for (i, (label, statements)) in parts.iter().enumerate() {
+        out.push_str(&format!("subgraph cluster_{} {{\nlabel=\"{}\";\n{}}}\n", i, escape(label), statements));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Escapes text for use inside a quoted DOT string.
+pub fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clusters_are_labelled_and_escaped() {
+        let dot = clusters(&[("\"ab\"".to_string(), "x;\n".to_string()), ("[]".to_string(), String::new())]);
+        assert_eq!(dot, "digraph {\nsubgraph cluster_0 {\nlabel=\"\\\"ab\\\"\";\nx;\n}\nsubgraph cluster_1 {\nlabel=\"[]\";\n}\n}\n");
+    }
+}
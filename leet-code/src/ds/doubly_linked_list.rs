@@ -0,0 +1,458 @@
+use std::cell::{Ref, RefCell};
+use std::rc::{Rc, Weak};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Two doubly linked lists with the same operations, for problems like LRU
+// Cache that need to unlink a node they already hold in O(1):
+// • DoublyLinkedList: nodes on the heap, `next` links own the node after
+//   them and `prev` links are `Weak`, so the list has no reference cycle
+// • ArenaList: nodes in one Vec, linked by index, with a generation per slot
+//   so a handle to a removed node cannot reach whatever reuses its slot
+//
+// Push returns a handle to the new node; `remove` takes it back out. A
+// handle only works on the list that made it.
+
+// Tells lists apart, so a handle from one list is refused by another
+fn next_list_id() -> u64 {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    NEXT.fetch_add(1, Ordering::Relaxed)
+}
+
+type Link<T> = Option<Rc<RefCell<Node<T>>>>;
+
+struct Node<T> {
+    val: T,
+    prev: Option<Weak<RefCell<Node<T>>>>,
+    next: Link<T>,
+}
+
+pub struct DoublyLinkedList<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    id: u64,
+}
+
+/// Points at one node of a [`DoublyLinkedList`] without keeping it alive.
+pub struct Handle<T> {
+    node: Weak<RefCell<Node<T>>>,
+    list: u64,
+}
+
+// Derived Clone would require T: Clone
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Handle { node: self.node.clone(), list: self.list }
+    }
+}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn new() -> Self {
+        DoublyLinkedList { head: None, tail: None, len: 0, id: next_list_id() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push_front(&mut self, val: T) -> Handle<T> {
+        let node = Rc::new(RefCell::new(Node { val, prev: None, next: self.head.take() }));
+        match &node.borrow().next {
+            Some(old_head) => old_head.borrow_mut().prev = Some(Rc::downgrade(&node)),
+            None => self.tail = Some(Rc::clone(&node)),
+        }
+        self.len += 1;
+        let handle = Handle { node: Rc::downgrade(&node), list: self.id };
+        self.head = Some(node);
+        handle
+    }
+
+    pub fn push_back(&mut self, val: T) -> Handle<T> {
+        let node = Rc::new(RefCell::new(Node { val, prev: self.tail.as_ref().map(Rc::downgrade), next: None }));
+        match self.tail.replace(Rc::clone(&node)) {
+            Some(old_tail) => old_tail.borrow_mut().next = Some(Rc::clone(&node)),
+            None => self.head = Some(Rc::clone(&node)),
+        }
+        self.len += 1;
+        Handle { node: Rc::downgrade(&node), list: self.id }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head.clone()?;
+        Some(self.take(head))
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail.clone()?;
+        Some(self.take(tail))
+    }
+
+    /// Removes the node `handle` points at in O(1). `None` if it was
+    /// already removed or belongs to another list.
+    pub fn remove(&mut self, handle: &Handle<T>) -> Option<T> {
+        if handle.list != self.id {
+            return None;
+        }
+        let node = handle.node.upgrade()?;
+        Some(self.take(node))
+    }
+
+    pub fn front(&self) -> Option<Ref<'_, T>> {
+        self.head.as_ref().map(|node| Ref::map(node.borrow(), |node| &node.val))
+    }
+
+    pub fn back(&self) -> Option<Ref<'_, T>> {
+        self.tail.as_ref().map(|node| Ref::map(node.borrow(), |node| &node.val))
+    }
+
+    /// Values from front to back.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut values = Vec::with_capacity(self.len);
+        let mut next = self.head.clone();
+        while let Some(node) = next {
+            let node = node.borrow();
+            values.push(node.val.clone());
+            next = node.next.clone();
+        }
+        values
+    }
+
+    // Links `node`'s neighbours (or the list's ends) to each other, then
+    // moves the value out. `node` must be the last strong reference left
+    // once the list lets go of it, which holds as handles are Weak.
+    fn take(&mut self, node: Rc<RefCell<Node<T>>>) -> T {
+        let (prev, next) = {
+            let mut node = node.borrow_mut();
+            (node.prev.take().and_then(|prev| prev.upgrade()), node.next.take())
+        };
+        match &next {
+            Some(next) => next.borrow_mut().prev = prev.as_ref().map(Rc::downgrade),
+            None => self.tail = prev.clone(),
+        }
+        match prev {
+            Some(prev) => prev.borrow_mut().next = next,
+            None => self.head = next,
+        }
+        self.len -= 1;
+        match Rc::try_unwrap(node) {
+            Ok(node) => node.into_inner().val,
+            Err(_) => unreachable!("a detached node is only referenced by weak handles"),
+        }
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The derived drop would free node after node through nested `next` drops,
+// one stack frame each. This walks the list, freeing the values front to
+// back.
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        self.tail = None;
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            next = node.borrow_mut().next.take();
+        }
+    }
+}
+
+struct Slot<T> {
+    // `None` while the slot is on the free list
+    val: Option<T>,
+    prev: Option<usize>,
+    next: Option<usize>,
+    generation: u32,
+}
+
+/// The same list with its nodes in a `Vec`: no `Rc`, no `RefCell`, and
+/// removed slots are reused by later pushes.
+pub struct ArenaList<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
+    id: u64,
+}
+
+/// Points at one node of an [`ArenaList`]. Stops working once that node is
+/// removed, even if its slot is reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArenaHandle {
+    index: usize,
+    generation: u32,
+    list: u64,
+}
+
+impl<T> ArenaList<T> {
+    pub fn new() -> Self {
+        ArenaList { slots: Vec::new(), free: Vec::new(), head: None, tail: None, len: 0, id: next_list_id() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push_front(&mut self, val: T) -> ArenaHandle {
+        let handle = self.alloc(val, None, self.head);
+        match self.head {
+            Some(old_head) => self.slots[old_head].prev = Some(handle.index),
+            None => self.tail = Some(handle.index),
+        }
+        self.head = Some(handle.index);
+        handle
+    }
+
+    pub fn push_back(&mut self, val: T) -> ArenaHandle {
+        let handle = self.alloc(val, self.tail, None);
+        match self.tail {
+            Some(old_tail) => self.slots[old_tail].next = Some(handle.index),
+            None => self.head = Some(handle.index),
+        }
+        self.tail = Some(handle.index);
+        handle
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        Some(self.take(head))
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        Some(self.take(tail))
+    }
+
+    /// Removes the node `handle` points at in O(1). `None` if it was
+    /// already removed or belongs to another list.
+    pub fn remove(&mut self, handle: ArenaHandle) -> Option<T> {
+        self.get(handle)?;
+        Some(self.take(handle.index))
+    }
+
+    pub fn get(&self, handle: ArenaHandle) -> Option<&T> {
+        if handle.list != self.id {
+            return None;
+        }
+        let slot = self.slots.get(handle.index)?;
+        if slot.generation != handle.generation {
+            return None;
+        }
+        slot.val.as_ref()
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.slots[self.head?].val.as_ref()
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.slots[self.tail?].val.as_ref()
+    }
+
+    /// Iterates over the values from front to back.
+    pub fn iter(&self) -> ArenaIter<'_, T> {
+        ArenaIter { list: self, next: self.head }
+    }
+
+    fn alloc(&mut self, val: T, prev: Option<usize>, next: Option<usize>) -> ArenaHandle {
+        self.len += 1;
+        let index = match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index];
+                slot.val = Some(val);
+                slot.prev = prev;
+                slot.next = next;
+                index
+            }
+            None => {
+                self.slots.push(Slot { val: Some(val), prev, next, generation: 0 });
+                self.slots.len() - 1
+            }
+        };
+        ArenaHandle { index, generation: self.slots[index].generation, list: self.id }
+    }
+
+    // Unlinks the occupied slot at `index` and puts it on the free list
+    // under a new generation
+    fn take(&mut self, index: usize) -> T {
+        let slot = &mut self.slots[index];
+        let (prev, next) = (slot.prev.take(), slot.next.take());
+        let val = slot.val.take().expect("take is only called on occupied slots");
+        slot.generation = slot.generation.wrapping_add(1);
+        match next {
+            Some(next) => self.slots[next].prev = prev,
+            None => self.tail = prev,
+        }
+        match prev {
+            Some(prev) => self.slots[prev].next = next,
+            None => self.head = next,
+        }
+        self.free.push(index);
+        self.len -= 1;
+        val
+    }
+}
+
+impl<T> Default for ArenaList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct ArenaIter<'a, T> {
+    list: &'a ArenaList<T>,
+    next: Option<usize>,
+}
+
+impl<'a, T> Iterator for ArenaIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let slot = &self.list.slots[self.next?];
+        self.next = slot.next;
+        slot.val.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    // Records its id in the shared log when dropped
+    struct Tracked(i32, Rc<RefCell<Vec<i32>>>);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.1.borrow_mut().push(self.0);
+        }
+    }
+
+    #[test]
+    fn pushes_and_pops_at_both_ends() {
+        let mut list = DoublyLinkedList::new();
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(1);
+        assert_eq!(list.to_vec(), [1, 2, 3]);
+        assert_eq!((*list.front().unwrap(), *list.back().unwrap(), list.len()), (1, 3, 3));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.is_empty() && list.front().is_none() && list.back().is_none());
+    }
+
+    #[test]
+    fn removes_through_handles() {
+        let mut list = DoublyLinkedList::new();
+        let handles: Vec<_> = (0..5).map(|i| list.push_back(i)).collect();
+        assert_eq!(list.remove(&handles[2]), Some(2));
+        assert_eq!(list.remove(&handles[0]), Some(0));
+        assert_eq!(list.remove(&handles[4]), Some(4));
+        assert_eq!(list.to_vec(), [1, 3]);
+        assert_eq!((*list.front().unwrap(), *list.back().unwrap()), (1, 3));
+
+        // Already removed, or from another list
+        assert_eq!(list.remove(&handles[2]), None);
+        let mut other = DoublyLinkedList::new();
+        let foreign = other.push_back(9);
+        assert_eq!(list.remove(&foreign), None);
+        assert_eq!(other.remove(&handles[1].clone()), None);
+        assert_eq!((list.len(), other.len()), (2, 1));
+
+        // A popped node's handle is dead too
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.remove(&handles[1]), None);
+        assert_eq!(list.remove(&handles[3]), Some(3));
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn frees_every_node_and_value() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut list = DoublyLinkedList::new();
+        let handles: Vec<_> = (0..6).map(|i| list.push_back(Tracked(i, Rc::clone(&log)))).collect();
+
+        // A removed value is dropped by whoever took it, and its node with it
+        drop(list.remove(&handles[3]));
+        assert_eq!(*log.borrow(), [3]);
+        assert!(handles[3].node.upgrade().is_none());
+
+        drop(list);
+        assert_eq!(*log.borrow(), [3, 0, 1, 2, 4, 5]);
+        assert!(handles.iter().all(|handle| handle.node.upgrade().is_none()));
+    }
+
+    #[test]
+    fn drops_long_lists_on_a_small_stack() {
+        thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(|| {
+                let mut list = DoublyLinkedList::new();
+                for i in 0..1_000_000 {
+                    list.push_back(i);
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn arena_matches_the_rc_list() {
+        let mut list = ArenaList::new();
+        let handles: Vec<_> = (0..5).map(|i| list.push_back(i)).collect();
+        list.push_front(-1);
+        assert_eq!(list.remove(handles[2]), Some(2));
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_front(), Some(-1));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 3]);
+        assert_eq!((list.front(), list.back(), list.len()), (Some(&0), Some(&3), 3));
+        assert_eq!(list.get(handles[1]), Some(&1));
+        assert_eq!(list.remove(handles[2]), None);
+        assert_eq!(ArenaList::<i32>::new().remove(handles[0]), None);
+    }
+
+    #[test]
+    fn arena_handles_do_not_outlive_their_node() {
+        let mut list = ArenaList::new();
+        let old = list.push_back("old");
+        list.remove(old);
+        // The new node reuses the slot, under a new generation
+        let new = list.push_back("new");
+        assert_eq!(list.slots.len(), 1);
+        assert_eq!(list.get(old), None);
+        assert_eq!(list.remove(old), None);
+        assert_eq!(list.remove(new), Some("new"));
+        assert!(list.is_empty() && list.iter().next().is_none());
+    }
+
+    #[test]
+    fn arena_frees_every_value() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut list = ArenaList::new();
+        let handles: Vec<_> = (0..4).map(|i| list.push_front(Tracked(i, Rc::clone(&log)))).collect();
+        drop(list.remove(handles[1]));
+        list.push_back(Tracked(4, Rc::clone(&log)));
+        drop(list);
+        let mut dropped = log.borrow().clone();
+        dropped.sort();
+        assert_eq!(dropped, [0, 1, 2, 3, 4]);
+    }
+}
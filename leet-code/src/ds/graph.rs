@@ -0,0 +1,324 @@
+use std::collections::VecDeque;
+
+use super::dot::ToDot;
+use super::DisjointSet;
+
+/// A graph stored as adjacency lists over nodes `0..n`, the shape most
+/// LeetCode graph problems reduce to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    pub adjacency: Vec<Vec<usize>>,
+    pub directed: bool,
+}
+
+impl Graph {
+    /// `n` nodes joined by `edges` in both directions.
+    pub fn undirected(n: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adjacency = vec![Vec::new(); n];
+        for &(a, b) in edges {
+            adjacency[a].push(b);
+            adjacency[b].push(a);
+        }
+        Graph { adjacency, directed: false }
+    }
+
+    /// `n` nodes with an edge from `a` to `b` for every `(a, b)`.
+    pub fn directed(n: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adjacency = vec![Vec::new(); n];
+        for &(a, b) in edges {
+            adjacency[a].push(b);
+        }
+        Graph { adjacency, directed: true }
+    }
+
+    /// The cells of `grid` as nodes, numbered row by row (`row * width +
+    /// column`), with an edge between two cells side by side or one above
+    /// the other when `joined` says so. Number of Islands is the components
+    /// of `Graph::grid(&grid, |a, b| *a == '1' && *b == '1')` that hold land.
+    pub fn grid<T>(grid: &[Vec<T>], joined: impl Fn(&T, &T) -> bool) -> Self {
+        let width = grid.first().map_or(0, Vec::len);
+        let mut edges = Vec::new();
+        for (r, row) in grid.iter().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                let node = r * width + c;
+                if c + 1 < row.len() && joined(cell, &row[c + 1]) {
+                    edges.push((node, node + 1));
+                }
+                if let Some(below) = grid.get(r + 1).and_then(|below| below.get(c)) {
+                    if joined(cell, below) {
+                        edges.push((node, node + width));
+                    }
+                }
+            }
+        }
+        Graph::undirected(grid.len() * width, &edges)
+    }
+
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    /// Visits every node reachable from `starts` breadth-first, nearest
+    /// first. Each start not reached from an earlier one begins a new tree,
+    /// so passing every node walks the whole graph.
+    pub fn bfs(&self, starts: impl IntoIterator<Item = usize>) -> Bfs<'_> {
+        Bfs { graph: self, starts: starts.into_iter().collect(), queue: VecDeque::new(), seen: vec![false; self.len()] }
+    }
+
+    /// Like [`Graph::bfs`], but depth-first: each node comes before the
+    /// nodes first reached through it (preorder), neighbours in list order.
+    pub fn dfs(&self, starts: impl IntoIterator<Item = usize>) -> Dfs<'_> {
+        Dfs { graph: self, starts: starts.into_iter().collect(), stack: Vec::new(), seen: vec![false; self.len()] }
+    }
+
+    /// An order of the nodes with every edge pointing forward, or `None` if
+    /// a cycle makes that impossible (Kahn's algorithm: keep taking a node
+    /// nothing points to any more). Meant for directed graphs; in an
+    /// undirected one every edge is a cycle of two.
+    pub fn topological_sort(&self) -> Option<Vec<usize>> {
+        let mut incoming = vec![0; self.len()];
+        for &to in self.adjacency.iter().flatten() {
+            incoming[to] += 1;
+        }
+        let mut ready: VecDeque<usize> = (0..self.len()).filter(|&node| incoming[node] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(node) = ready.pop_front() {
+            order.push(node);
+            for &to in &self.adjacency[node] {
+                incoming[to] -= 1;
+                if incoming[to] == 0 {
+                    ready.push_back(to);
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Whether following edges can lead back to where it started. In an
+    /// undirected graph going back along the edge just taken does not
+    /// count, but a second edge between the same two nodes does.
+    pub fn has_cycle(&self) -> bool {
+        if self.directed {
+            return self.topological_sort().is_none();
+        }
+        // Each edge is stored from both ends; take it from the lower one. An
+        // edge between two nodes already connected closes a cycle
+        let mut sets = DisjointSet::new(self.len());
+        let mut loops = vec![0; self.len()];
+        for (from, neighbours) in self.adjacency.iter().enumerate() {
+            for &to in neighbours {
+                if from == to {
+                    // A self-loop is listed twice, both times from `from`
+                    loops[from] += 1;
+                    if loops[from] == 2 {
+                        return true;
+                    }
+                } else if from < to && !sets.union(from, to) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+/// One node reached by [`Graph::bfs`] or [`Graph::dfs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Visit {
+    pub node: usize,
+    /// The node it was reached from, `None` for a start.
+    pub parent: Option<usize>,
+    /// Edges from its start; the shortest path's length in a BFS.
+    pub depth: usize,
+}
+
+impl Visit {
+    fn start(node: usize) -> Self {
+        Visit { node, parent: None, depth: 0 }
+    }
+
+    fn child(&self, node: usize) -> Self {
+        Visit { node, parent: Some(self.node), depth: self.depth + 1 }
+    }
+}
+
+pub struct Bfs<'a> {
+    graph: &'a Graph,
+    starts: VecDeque<usize>,
+    queue: VecDeque<Visit>,
+    // Marked when queued, so no node is queued twice
+    seen: Vec<bool>,
+}
+
+impl Iterator for Bfs<'_> {
+    type Item = Visit;
+
+    fn next(&mut self) -> Option<Visit> {
+        if self.queue.is_empty() {
+            let start = std::iter::from_fn(|| self.starts.pop_front()).find(|&start| !self.seen[start])?;
+            self.seen[start] = true;
+            self.queue.push_back(Visit::start(start));
+        }
+        let visit = self.queue.pop_front()?;
+        for &next in &self.graph.adjacency[visit.node] {
+            if !self.seen[next] {
+                self.seen[next] = true;
+                self.queue.push_back(visit.child(next));
+            }
+        }
+        Some(visit)
+    }
+}
+
+pub struct Dfs<'a> {
+    graph: &'a Graph,
+    starts: VecDeque<usize>,
+    stack: Vec<Visit>,
+    // Marked when visited: a node can be on the stack more than once, and
+    // the visit that counts is the last one pushed
+    seen: Vec<bool>,
+}
+
+impl Iterator for Dfs<'_> {
+    type Item = Visit;
+
+    fn next(&mut self) -> Option<Visit> {
+        loop {
+            let visit = match self.stack.pop() {
+                Some(visit) => visit,
+                None => Visit::start(self.starts.pop_front()?),
+            };
+            if self.seen[visit.node] {
+                continue;
+            }
+            self.seen[visit.node] = true;
+            // Reversed, so the first neighbour is on top
+            for &next in self.graph.adjacency[visit.node].iter().rev() {
+                if !self.seen[next] {
+                    self.stack.push(visit.child(next));
+                }
+            }
+            return Some(visit);
+        }
+    }
+}
+
+/// LeetCode's `[[a, b], ...]` edge lists as pairs.
+pub fn pairs(edges: &[Vec<i32>]) -> Vec<(usize, usize)> {
+    edges.iter().map(|edge| (edge[0] as usize, edge[1] as usize)).collect()
+}
+
+impl ToDot for Graph {
+    fn dot_statements(&self, prefix: &str) -> String {
+        let mut out = String::new();
+        for node in 0..self.adjacency.len() {
+            out.push_str(&format!("{}{} [label=\"{}\"];\n", prefix, node, node));
+        }
+        for (from, neighbours) in self.adjacency.iter().enumerate() {
+            for &to in neighbours {
+                if self.directed {
+                    out.push_str(&format!("{}{} -> {}{};\n", prefix, from, prefix, to));
+                } else if from <= to {
+                    // Each undirected edge is stored twice; draw it once
+                    out.push_str(&format!("{}{} -> {}{} [dir=none];\n", prefix, from, prefix, to));
+                }
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undirected_edges_are_drawn_once() {
+        let dot = Graph::undirected(3, &[(0, 1), (1, 2)]).to_dot();
+        assert_eq!(dot.matches("->").count(), 2);
+        assert!(dot.contains("n1 -> n2 [dir=none];"));
+    }
+
+    #[test]
+    fn directed_edges_keep_their_direction() {
+        let graph = Graph::directed(2, &[(1, 0)]);
+        assert_eq!(graph.adjacency, vec![vec![], vec![0]]);
+        assert!(graph.to_dot().contains("n1 -> n0;"));
+    }
+
+    fn nodes(visits: impl Iterator<Item = Visit>) -> Vec<usize> {
+        visits.map(|visit| visit.node).collect()
+    }
+
+    #[test]
+    fn bfs_goes_level_by_level() {
+        //   0 - 1 - 3
+        //    \  |
+        //      2 - 4     5
+        let graph = Graph::undirected(6, &[(0, 1), (0, 2), (1, 2), (1, 3), (2, 4)]);
+        let visits: Vec<_> = graph.bfs([0]).collect();
+        assert_eq!(nodes(visits.iter().copied()), [0, 1, 2, 3, 4]);
+        assert_eq!(visits.iter().map(|visit| visit.depth).collect::<Vec<_>>(), [0, 1, 1, 2, 2]);
+        assert_eq!(visits[4].parent, Some(2));
+        // Later starts pick up what the earlier ones did not reach
+        assert_eq!(nodes(graph.bfs([3, 5, 0])), [3, 1, 0, 2, 4, 5]);
+    }
+
+    #[test]
+    fn dfs_goes_deep_first() {
+        let graph = Graph::undirected(6, &[(0, 1), (0, 2), (1, 2), (1, 3), (2, 4)]);
+        let visits: Vec<_> = graph.dfs([0]).collect();
+        assert_eq!(nodes(visits.iter().copied()), [0, 1, 2, 4, 3]);
+        assert_eq!(visits[2], Visit { node: 2, parent: Some(1), depth: 2 });
+        let roots: Vec<_> = graph.dfs(0..6).filter(|visit| visit.parent.is_none()).map(|visit| visit.node).collect();
+        assert_eq!(roots, [0, 5]);
+    }
+
+    #[test]
+    fn traversals_reach_every_node_once() {
+        let graph = Graph::grid(&vec![vec![1; 30]; 30], |_, _| true);
+        for order in [nodes(graph.bfs([0])), nodes(graph.dfs([0]))] {
+            let mut sorted = order.clone();
+            sorted.sort();
+            assert_eq!(sorted, (0..900).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn topological_sort_respects_every_edge() {
+        let graph = Graph::directed(6, &[(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)]);
+        let order = graph.topological_sort().unwrap();
+        let position = |node: usize| order.iter().position(|&n| n == node).unwrap();
+        for (from, neighbours) in graph.adjacency.iter().enumerate() {
+            assert!(neighbours.iter().all(|&to| position(from) < position(to)), "{:?}", order);
+        }
+        assert_eq!(Graph::directed(3, &[(0, 1), (1, 2), (2, 0)]).topological_sort(), None);
+        assert_eq!(Graph::directed(0, &[]).topological_sort(), Some(vec![]));
+    }
+
+    #[test]
+    fn finds_cycles_both_ways() {
+        assert!(!Graph::directed(3, &[(0, 1), (1, 2), (0, 2)]).has_cycle());
+        assert!(Graph::directed(3, &[(0, 1), (1, 2), (2, 1)]).has_cycle());
+        assert!(Graph::directed(1, &[(0, 0)]).has_cycle());
+
+        assert!(!Graph::undirected(4, &[(0, 1), (1, 2), (1, 3)]).has_cycle());
+        assert!(Graph::undirected(3, &[(0, 1), (1, 2), (2, 0)]).has_cycle());
+        assert!(Graph::undirected(2, &[(0, 1), (1, 0)]).has_cycle());
+        assert!(Graph::undirected(2, &[(1, 1)]).has_cycle());
+    }
+
+    #[test]
+    fn grid_joins_neighbouring_cells() {
+        let grid = vec![vec!['1', '1', '0'], vec!['0', '1', '0'], vec!['1', '0', '1']];
+        let graph = Graph::grid(&grid, |a, b| *a == '1' && *b == '1');
+        assert_eq!(graph.len(), 9);
+        assert_eq!(graph.adjacency[1], [0, 4]);
+        assert!(graph.adjacency[6].is_empty());
+        assert_eq!(pairs(&[vec![0, 1], vec![2, 3]]), [(0, 1), (2, 3)]);
+    }
+}
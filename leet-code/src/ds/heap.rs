@@ -0,0 +1,205 @@
+// A binary min-heap on a Vec: the children of index i are 2i + 1 and
+// 2i + 2, and no child is smaller than its parent, so the smallest value is
+// always at index 0. `std::collections::BinaryHeap` is a max-heap; this is
+// the one merge-k-lists and top-k style problems reach for, written out.
+
+/// The smallest value comes out first.
+#[derive(Debug, Clone)]
+pub struct MinHeap<T> {
+    data: Vec<T>,
+}
+
+impl<T: Ord> MinHeap<T> {
+    pub fn new() -> Self {
+        MinHeap { data: Vec::new() }
+    }
+
+    /// Turns `data` into a heap in O(n), by sifting down every parent from
+    /// the last one up: most nodes are near the bottom and move little.
+    pub fn heapify(data: Vec<T>) -> Self {
+        let mut heap = MinHeap { data };
+        for i in (0..heap.data.len() / 2).rev() {
+            heap.sift_down(i);
+        }
+        heap
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// The smallest value, without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    pub fn push(&mut self, val: T) {
+        self.data.push(val);
+        self.sift_up(self.data.len() - 1);
+    }
+
+    /// Removes the smallest value: the last leaf takes its place and sinks
+    /// back down.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let last = self.data.len() - 1;
+        self.data.swap(0, last);
+        let min = self.data.pop();
+        self.sift_down(0);
+        min
+    }
+
+    /// The values, smallest first.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut sorted = Vec::with_capacity(self.len());
+        while let Some(val) = self.pop() {
+            sorted.push(val);
+        }
+        sorted
+    }
+
+    // Swaps the value at `i` with its parent while it is smaller
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.data[i] >= self.data[parent] {
+                break;
+            }
+            self.data.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    // Swaps the value at `i` with its smaller child while that child is
+    // smaller
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let (left, right) = (2 * i + 1, 2 * i + 2);
+            let mut smallest = i;
+            if left < self.data.len() && self.data[left] < self.data[smallest] {
+                smallest = left;
+            }
+            if right < self.data.len() && self.data[right] < self.data[smallest] {
+                smallest = right;
+            }
+            if smallest == i {
+                return;
+            }
+            self.data.swap(i, smallest);
+            i = smallest;
+        }
+    }
+}
+
+impl<T: Ord> Default for MinHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for MinHeap<T> {
+    fn from(data: Vec<T>) -> Self {
+        Self::heapify(data)
+    }
+}
+
+impl<T: Ord> FromIterator<T> for MinHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::heapify(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord> Extend<T> for MinHeap<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for val in iter {
+            self.push(val);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complexity::pseudo_random;
+    use proptest::prelude::*;
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    // Every parent is no larger than its children
+    fn is_heap<T: Ord>(heap: &MinHeap<T>) -> bool {
+        (1..heap.data.len()).all(|i| heap.data[(i - 1) / 2] <= heap.data[i])
+    }
+
+    #[test]
+    fn pops_smallest_first() {
+        let mut heap = MinHeap::new();
+        for val in [5, 3, 8, 1, 9, 1] {
+            heap.push(val);
+        }
+        assert_eq!(heap.peek(), Some(&1));
+        assert_eq!(heap.len(), 6);
+        assert_eq!(heap.into_sorted_vec(), [1, 1, 3, 5, 8, 9]);
+        assert_eq!(MinHeap::<i32>::new().pop(), None);
+    }
+
+    #[derive(Debug, Clone)]
+    enum Op {
+        Push(i32),
+        Pop,
+    }
+
+    // Twice as many pushes as pops, of small values so there are plenty of ties
+    fn ops() -> impl Strategy<Value = Vec<Op>> {
+        prop::collection::vec(prop_oneof![2 => (0..50).prop_map(Op::Push), 1 => Just(Op::Pop)], 0..300)
+    }
+
+    proptest! {
+        // Checked step by step against BinaryHeap<Reverse<_>>
+        #[test]
+        fn matches_std_binary_heap(ops in ops()) {
+            let mut ours = MinHeap::new();
+            let mut std = BinaryHeap::new();
+            for op in ops {
+                match op {
+                    Op::Push(val) => {
+                        ours.push(val);
+                        std.push(Reverse(val));
+                    }
+                    Op::Pop => prop_assert_eq!(ours.pop(), std.pop().map(|Reverse(val)| val)),
+                }
+                prop_assert_eq!(ours.peek(), std.peek().map(|Reverse(val)| val));
+                prop_assert_eq!(ours.len(), std.len());
+                prop_assert!(is_heap(&ours));
+            }
+        }
+    }
+
+    #[test]
+    fn heapify_matches_sorting() {
+        for len in 0..100 {
+            let values: Vec<i64> = pseudo_random(len as u64).take(len).map(|x| (x % 1000) as i64 - 500).collect();
+            let heap = MinHeap::heapify(values.clone());
+            assert!(is_heap(&heap), "{:?}", values);
+            let mut sorted = values;
+            sorted.sort();
+            assert_eq!(heap.into_sorted_vec(), sorted);
+        }
+    }
+
+    #[test]
+    fn collects_and_extends() {
+        let mut heap: MinHeap<_> = ["pear", "fig", "apple"].into_iter().collect();
+        heap.extend(["banana", "cherry"]);
+        assert_eq!(heap.pop(), Some("apple"));
+        assert_eq!(MinHeap::from(vec![(2, 'b'), (1, 'z')]).peek(), Some(&(1, 'z')));
+        // A max-heap is a MinHeap of Reverse
+        let mut max: MinHeap<_> = [3, 7, 5].into_iter().map(Reverse).collect();
+        assert_eq!(max.pop(), Some(Reverse(7)));
+    }
+}
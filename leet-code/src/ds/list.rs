@@ -0,0 +1,336 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use super::dot::{self, ToDot};
+
+// Definition for singly-linked list, generic over the value so problems on
+// strings or pairs can share it. `ListNode` on its own is LeetCode's
+// `ListNode` of `i32`.
+#[derive(PartialEq, Eq, Debug)]
+pub struct ListNode<T = i32> {
+    pub val: T,
+    pub next: Option<Box<ListNode<T>>>,
+}
+
+/// A whole list as LeetCode passes it around: `None` is the empty list.
+pub type List<T = i32> = Option<Box<ListNode<T>>>;
+
+impl<T> ListNode<T> {
+    #[inline]
+    pub fn new(val: T) -> Self {
+        ListNode { next: None, val }
+    }
+
+    /// Builds a list holding `values` in order.
+    pub fn from_vec(values: Vec<T>) -> List<T> {
+        values.into_iter().rev().fold(None, |next, val| Some(Box::new(ListNode { val, next })))
+    }
+
+    /// Values from this node to the end of the list.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Iterates over the values from this node to the end of the list.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: Some(self) }
+    }
+}
+
+// The derived Clone and Drop would recurse once per node, which overflows
+// the stack on lists of around a million nodes. Both walk the list instead.
+impl<T: Clone> Clone for ListNode<T> {
+    fn clone(&self) -> Self {
+        let mut head = ListNode::new(self.val.clone());
+        let mut tail = &mut head;
+        for val in self.iter().skip(1) {
+            tail = tail.next.insert(Box::new(ListNode::new(val.clone())));
+        }
+        head
+    }
+}
+
+impl<T> Drop for ListNode<T> {
+    fn drop(&mut self) {
+        let mut next = self.next.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a ListNode<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.next?;
+        self.next = node.next.as_deref();
+        Some(&node.val)
+    }
+}
+
+/// Prints the list from this node on: `1 -> 2 -> 3`.
+impl<T: fmt::Display> fmt::Display for ListNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, val) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, " -> ")?;
+            }
+            write!(f, "{}", val)?;
+        }
+        Ok(())
+    }
+}
+
+/// Draws the list left to right, from this node on.
+impl<T: fmt::Display> ToDot for ListNode<T> {
+    fn dot_statements(&self, prefix: &str) -> String {
+        let mut out = String::new();
+        let mut ids = Vec::new();
+        for (i, val) in self.iter().enumerate() {
+            out.push_str(&format!("{}{} [label=\"{}\", shape=box];\n", prefix, i, dot::escape(&val.to_string())));
+            if i > 0 {
+                out.push_str(&format!("{}{} -> {}{};\n", prefix, i - 1, prefix, i));
+            }
+            ids.push(format!("{}{}", prefix, i));
+        }
+        out.push_str(&format!("{{ rank=same; {} }}\n", ids.join("; ")));
+        out
+    }
+}
+
+/// Like [`ListNode::to_vec`], but also covers the empty list.
+pub fn to_vec<T: Clone>(list: &List<T>) -> Vec<T> {
+    list.as_ref().map_or_else(Vec::new, |head| head.to_vec())
+}
+
+/// Like `Display`, but also covers the empty list, shown as `[]`.
+pub fn format<T: fmt::Display>(list: &List<T>) -> String {
+    list.as_ref().map_or_else(|| "[]".to_string(), |head| head.to_string())
+}
+
+/// The middle node, or the second of the two middle ones in a list of even
+/// length (LeetCode 876). `fast` moves two nodes for each one of `slow`.
+pub fn middle_node<T>(list: &List<T>) -> Option<&ListNode<T>> {
+    let mut slow = list.as_deref()?;
+    let mut fast = slow;
+    while let Some(next) = fast.next.as_deref() {
+        slow = slow.next.as_deref()?;
+        match next.next.as_deref() {
+            Some(after) => fast = after,
+            None => break,
+        }
+    }
+    Some(slow)
+}
+
+// A node that can be pointed at from more than one place, which is what a
+// list with a cycle needs: its tail links back to a node that the one
+// before it already owns. `Box` lists have a single owner per node and
+// cannot loop.
+pub struct SharedNode<T = i32> {
+    pub val: T,
+    pub next: SharedList<T>,
+}
+
+/// A list whose tail may link back into itself: `None` is the empty list.
+pub type SharedList<T = i32> = Option<Rc<RefCell<SharedNode<T>>>>;
+
+impl<T> SharedNode<T> {
+    /// Builds a list holding `values` in order whose tail links back to the
+    /// node at index `pos`, as in LeetCode's cycle problems. `None` leaves
+    /// the list without a cycle.
+    ///
+    /// A list with a cycle keeps itself alive through its `Rc`s; pass it to
+    /// [`unlink`] to free it.
+    pub fn from_vec(values: Vec<T>, pos: Option<usize>) -> SharedList<T> {
+        assert!(pos.is_none_or(|pos| pos < values.len()), "pos {:?} is past the end of the list", pos);
+        let nodes: Vec<_> = values.into_iter().map(|val| Rc::new(RefCell::new(SharedNode { val, next: None }))).collect();
+        for pair in nodes.windows(2) {
+            pair[0].borrow_mut().next = Some(Rc::clone(&pair[1]));
+        }
+        if let (Some(pos), Some(tail)) = (pos, nodes.last()) {
+            tail.borrow_mut().next = Some(Rc::clone(&nodes[pos]));
+        }
+        nodes.into_iter().next()
+    }
+}
+
+// A derived Debug would follow `next` around a cycle forever
+impl<T: fmt::Debug> fmt::Debug for SharedNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedNode").field("val", &self.val).finish_non_exhaustive()
+    }
+}
+
+fn shared_next<T>(node: &Rc<RefCell<SharedNode<T>>>) -> SharedList<T> {
+    node.borrow().next.clone()
+}
+
+// Floyd's tortoise and hare: the node where `slow` (one step at a time) and
+// `fast` (two) meet, which only happens inside a cycle.
+fn meeting_point<T>(head: &SharedList<T>) -> SharedList<T> {
+    let mut slow = head.clone()?;
+    let mut fast = head.clone()?;
+    loop {
+        fast = shared_next(&fast).and_then(|next| shared_next(&next))?;
+        slow = shared_next(&slow)?;
+        if Rc::ptr_eq(&slow, &fast) {
+            return Some(slow);
+        }
+    }
+}
+
+/// Whether following `next` from `head` ever comes back to a node already
+/// seen (LeetCode 141), in O(1) extra space.
+pub fn has_cycle<T>(head: &SharedList<T>) -> bool {
+    meeting_point(head).is_some()
+}
+
+/// The first node of the cycle, where the tail links back to (LeetCode
+/// 142), or `None` without a cycle.
+///
+/// If the cycle starts `a` nodes from the head, the meeting point is as far
+/// from the start of the cycle going forward as the head is, modulo the
+/// cycle's length. So two walkers, one from the head and one from the
+/// meeting point, meet again right at the entry.
+pub fn cycle_entry<T>(head: &SharedList<T>) -> SharedList<T> {
+    let mut from_meeting = meeting_point(head)?;
+    let mut from_head = head.clone()?;
+    while !Rc::ptr_eq(&from_head, &from_meeting) {
+        from_head = shared_next(&from_head)?;
+        from_meeting = shared_next(&from_meeting)?;
+    }
+    Some(from_head)
+}
+
+/// Takes every `next` link out of the list, one node at a time. This breaks
+/// a cycle, which otherwise keeps its nodes alive forever, and frees a long
+/// list without recursing once per node.
+pub fn unlink<T>(head: SharedList<T>) {
+    let mut next = head;
+    while let Some(node) = next {
+        next = node.borrow_mut().next.take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn round_trips() {
+        let list = ListNode::from_vec(vec![1, 2, 3]).unwrap();
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        assert_eq!(ListNode::<i32>::from_vec(vec![]), None);
+        assert_eq!(ListNode::from_vec(vec![7]), Some(Box::new(ListNode::new(7))));
+        assert_eq!(to_vec::<i32>(&None), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn iterates_from_any_node() {
+        let list = ListNode::from_vec(vec![1, 2, 3]).unwrap();
+        assert_eq!(list.iter().sum::<i32>(), 6);
+        assert_eq!(list.next.as_ref().unwrap().iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn displays_as_a_chain() {
+        assert_eq!(format(&ListNode::from_vec(vec![1, 2, 3])), "1 -> 2 -> 3");
+        assert_eq!(format(&ListNode::from_vec(vec![4])), "4");
+        assert_eq!(format::<i32>(&None), "[]");
+    }
+
+    #[test]
+    fn dot_chains_the_nodes() {
+        let dot = ListNode::from_vec(vec![1, 2]).unwrap().to_dot();
+        assert_eq!(dot, "digraph {\nn0 [label=\"1\", shape=box];\nn1 [label=\"2\", shape=box];\nn0 -> n1;\n{ rank=same; n0; n1 }\n}\n");
+    }
+
+    #[test]
+    fn works_for_any_value_type() {
+        let words = ListNode::from_vec(vec!["to".to_string(), "be".to_string()]);
+        assert_eq!(format(&words), "to -> be");
+        assert_eq!(to_vec(&words), vec!["to", "be"]);
+
+        let pairs = ListNode::from_vec(vec![(1, 'a'), (2, 'b')]).unwrap();
+        assert_eq!(pairs.iter().map(|&(n, _)| n).sum::<i32>(), 3);
+        assert!(ListNode::from_vec(vec!["\"q\""]).unwrap().to_dot().contains("label=\"\\\"q\\\"\""));
+    }
+
+    #[test]
+    fn clones_and_drops_long_lists_on_a_small_stack() {
+        let len = thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(|| {
+                let list = ListNode::from_vec((0..1_000_000).collect());
+                let copy = list.clone();
+                drop(list);
+                copy.unwrap().iter().count()
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!(len, 1_000_000);
+    }
+
+    #[test]
+    fn middle_node_takes_the_second_of_two() {
+        let middle = |values: Vec<i32>| middle_node(&ListNode::from_vec(values)).map(|node| node.val);
+        assert_eq!(middle(vec![1, 2, 3, 4, 5]), Some(3));
+        assert_eq!(middle(vec![1, 2, 3, 4, 5, 6]), Some(4));
+        assert_eq!(middle(vec![1, 2]), Some(2));
+        assert_eq!(middle(vec![1]), Some(1));
+        assert_eq!(middle(vec![]), None);
+    }
+
+    #[test]
+    fn finds_cycles_and_where_they_start() {
+        // LeetCode 141/142 examples: [3,2,0,-4] pos 1, [1,2] pos 0, [1] no cycle
+        for (values, pos) in [(vec![3, 2, 0, -4], Some(1)), (vec![1, 2], Some(0)), (vec![1], None), (vec![1], Some(0))] {
+            let head = SharedNode::from_vec(values.clone(), pos);
+            assert_eq!(has_cycle(&head), pos.is_some(), "{:?} pos {:?}", values, pos);
+            let entry = cycle_entry(&head).map(|node| node.borrow().val);
+            assert_eq!(entry, pos.map(|pos| values[pos]), "{:?} pos {:?}", values, pos);
+            unlink(head);
+        }
+        assert!(!has_cycle::<i32>(&None));
+        assert!(cycle_entry::<i32>(&None).is_none());
+    }
+
+    #[test]
+    fn cycle_entry_is_the_node_itself() {
+        // Every position in a longer list, with repeated values so only the node identity tells
+        for pos in 0..20 {
+            let head = SharedNode::from_vec(vec![7; 20], Some(pos));
+            let mut expected = head.clone().unwrap();
+            for _ in 0..pos {
+                expected = shared_next(&expected).unwrap();
+            }
+            assert!(Rc::ptr_eq(&cycle_entry(&head).unwrap(), &expected), "pos {}", pos);
+            unlink(head);
+        }
+    }
+
+    #[test]
+    fn unlink_frees_a_cyclic_list() {
+        let head = SharedNode::from_vec(vec![1, 2, 3], Some(0));
+        let weak = Rc::downgrade(head.as_ref().unwrap());
+        // The tail's link keeps the head alive after the last handle is gone...
+        drop(head);
+        assert!(weak.upgrade().is_some());
+        // ...until the links are taken out
+        unlink(weak.upgrade());
+        assert!(weak.upgrade().is_none());
+        assert_eq!(format!("{:?}", SharedNode { val: 1, next: None }), "SharedNode { val: 1, .. }");
+    }
+}
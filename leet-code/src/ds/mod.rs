@@ -0,0 +1,23 @@
+//! Data structures shared by the solutions. Lists and trees are shaped
+//! exactly like the definitions LeetCode puts above each problem so
+//! solutions paste back unchanged; the heap, the tries, the disjoint set,
+//! the doubly linked lists and the graph traversals are the building blocks
+//! solutions would otherwise each write for themselves.
+
+pub mod disjoint_set;
+pub mod dot;
+pub mod doubly_linked_list;
+pub mod graph;
+pub mod heap;
+pub mod list;
+pub mod tree;
+pub mod trie;
+
+pub use disjoint_set::DisjointSet;
+pub use dot::ToDot;
+pub use doubly_linked_list::{ArenaList, DoublyLinkedList};
+pub use graph::Graph;
+pub use heap::MinHeap;
+pub use list::{List, ListNode, SharedList, SharedNode};
+pub use tree::{Tree, TreeNode};
+pub use trie::{Dictionary, HashTrie, Trie};
@@ -0,0 +1,371 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+use super::dot::{self, ToDot};
+
+// Definition for a binary tree node, generic over the value so problems on
+// strings or pairs can share it. `TreeNode` on its own is LeetCode's
+// `TreeNode` of `i32`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TreeNode<T = i32> {
+    pub val: T,
+    pub left: Option<Rc<RefCell<TreeNode<T>>>>,
+    pub right: Option<Rc<RefCell<TreeNode<T>>>>,
+}
+
+/// A whole tree as LeetCode passes it around: `None` is the empty tree.
+pub type Tree<T = i32> = Option<Rc<RefCell<TreeNode<T>>>>;
+
+impl<T> TreeNode<T> {
+    #[inline]
+    pub fn new(val: T) -> Self {
+        TreeNode { val, left: None, right: None }
+    }
+
+    /// A node with no children, ready to hang off another node.
+    pub fn leaf(val: T) -> Tree<T> {
+        Self::branch(val, None, None)
+    }
+
+    pub fn branch(val: T, left: Tree<T>, right: Tree<T>) -> Tree<T> {
+        Some(Rc::new(RefCell::new(TreeNode { val, left, right })))
+    }
+
+    /// Builds a tree from LeetCode's level-order notation, where `None` marks
+    /// a missing child: `[1, 2, 3, None, 5]` is 1 with children 2 and 3, and
+    /// 5 as the right child of 2.
+    pub fn from_level_order(values: &[Option<T>]) -> Tree<T>
+    where
+        T: Clone,
+    {
+        let mut values = values.iter();
+        let root = Rc::new(RefCell::new(TreeNode::new(values.next()?.clone()?)));
+        let mut parents = VecDeque::from([Rc::clone(&root)]);
+
+        while let Some(parent) = parents.pop_front() {
+            for is_left in [true, false] {
+                let Some(value) = values.next() else {
+                    return Some(root);
+                };
+                if let Some(val) = value {
+                    let child = Rc::new(RefCell::new(TreeNode::new(val.clone())));
+                    parents.push_back(Rc::clone(&child));
+                    let mut parent = parent.borrow_mut();
+                    if is_left {
+                        parent.left = Some(child);
+                    } else {
+                        parent.right = Some(child);
+                    }
+                }
+            }
+        }
+        Some(root)
+    }
+
+    /// The inverse of [`TreeNode::from_level_order`] for the subtree rooted
+    /// here, without trailing `None`s.
+    pub fn to_level_order(&self) -> Vec<Option<T>>
+    where
+        T: Clone,
+    {
+        // Breadth-first order lists every node's two child slots in turn
+        let mut values = vec![Some(self.val.clone())];
+        self.breadth_first(|node, _| {
+            for child in [&node.left, &node.right] {
+                values.push(child.as_ref().map(|child| child.borrow().val.clone()));
+            }
+        });
+        while matches!(values.last(), Some(None)) {
+            values.pop();
+        }
+        values
+    }
+
+    /// Visits the subtree rooted here level by level, left to right, with
+    /// each node's depth (0 for this node). The queue-based traversal behind
+    /// [`level_order`] and the other level helpers.
+    pub fn breadth_first(&self, mut visit: impl FnMut(&TreeNode<T>, usize)) {
+        visit(self, 0);
+        let mut queue: VecDeque<(Rc<RefCell<TreeNode<T>>>, usize)> =
+            [&self.left, &self.right].into_iter().flatten().map(|child| (Rc::clone(child), 1)).collect();
+        while let Some((node, depth)) = queue.pop_front() {
+            let node = node.borrow();
+            visit(&node, depth);
+            queue.extend([&node.left, &node.right].into_iter().flatten().map(|child| (Rc::clone(child), depth + 1)));
+        }
+    }
+}
+
+/// Prints the subtree in LeetCode's level-order notation: `[1,2,3,null,5]`.
+impl<T: fmt::Display + Clone> fmt::Display for TreeNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, value) in self.to_level_order().iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            match value {
+                Some(val) => write!(f, "{}", val)?,
+                None => write!(f, "null")?,
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+/// Draws the subtree top-down. An only child gets an invisible sibling so
+/// Graphviz keeps it on the correct side.
+impl<T: fmt::Display> ToDot for TreeNode<T> {
+    fn dot_statements(&self, prefix: &str) -> String {
+        let mut out = String::new();
+        dot_node(self, prefix, &mut 0, &mut out);
+        out
+    }
+}
+
+// Numbers nodes in preorder; returns the id given to `node`.
+fn dot_node<T: fmt::Display>(node: &TreeNode<T>, prefix: &str, next_id: &mut usize, out: &mut String) -> String {
+    let id = format!("{}{}", prefix, next_id);
+    *next_id += 1;
+    out.push_str(&format!("{} [label=\"{}\"];\n", id, dot::escape(&node.val.to_string())));
+    if node.left.is_none() && node.right.is_none() {
+        return id;
+    }
+    for child in [&node.left, &node.right] {
+        match child {
+            Some(child) => {
+                let child_id = dot_node(&child.borrow(), prefix, next_id, out);
+                out.push_str(&format!("{} -> {};\n", id, child_id));
+            }
+            None => {
+                let gap = format!("{}{}", prefix, next_id);
+                *next_id += 1;
+                out.push_str(&format!("{} [style=invis];\n{} -> {} [style=invis];\n", gap, id, gap));
+            }
+        }
+    }
+    id
+}
+
+/// Like `Display`, but also covers the empty tree, shown as `[]`.
+pub fn format<T: fmt::Display + Clone>(tree: &Tree<T>) -> String {
+    tree.as_ref().map_or_else(|| "[]".to_string(), |root| root.borrow().to_string())
+}
+
+/// The values on each level, top down and left to right (LeetCode 102).
+pub fn level_order<T: Clone>(tree: &Tree<T>) -> Vec<Vec<T>> {
+    let mut levels: Vec<Vec<T>> = Vec::new();
+    if let Some(root) = tree {
+        root.borrow().breadth_first(|node, depth| {
+            if depth == levels.len() {
+                levels.push(Vec::new());
+            }
+            levels[depth].push(node.val.clone());
+        });
+    }
+    levels
+}
+
+/// [`level_order`] with every other level read right to left, starting
+/// with the second (LeetCode 103).
+pub fn zigzag_level_order<T: Clone>(tree: &Tree<T>) -> Vec<Vec<T>> {
+    let mut levels = level_order(tree);
+    for level in levels.iter_mut().skip(1).step_by(2) {
+        level.reverse();
+    }
+    levels
+}
+
+/// The last value on each level: what is seen looking at the tree from the
+/// right (LeetCode 199).
+pub fn right_side_view<T: Clone>(tree: &Tree<T>) -> Vec<T> {
+    let mut view: Vec<T> = Vec::new();
+    if let Some(root) = tree {
+        root.borrow().breadth_first(|node, depth| {
+            // Later nodes on a level are further right, so each overwrites the last
+            if depth == view.len() {
+                view.push(node.val.clone());
+            } else {
+                view[depth] = node.val.clone();
+            }
+        });
+    }
+    view
+}
+
+/// A degenerate, linked-list shaped tree of `len` nodes where every node
+/// only has a left child. Recursive solutions need one stack frame per node
+/// on it.
+pub fn left_spine(len: usize) -> Tree {
+    (0..len as i32).rev().fold(None, |below, val| TreeNode::branch(val, below, None))
+}
+
+/// Drops a tree without recursing. The derived drop goes one frame per
+/// level, so a deep enough spine would overflow the stack while dropping.
+pub fn drop_iteratively<T>(tree: Tree<T>) {
+    let mut pending: Vec<Rc<RefCell<TreeNode<T>>>> = tree.into_iter().collect();
+    while let Some(node) = pending.pop() {
+        let mut node = node.borrow_mut();
+        pending.extend(node.left.take());
+        pending.extend(node.right.take());
+    }
+}
+
+/// Draws the tree top-down with one node per line, left child first:
+///
+/// ```text
+/// 1
+/// ├── 2
+/// │   ├── ·
+/// │   └── 5
+/// └── 3
+/// ```
+///
+/// A node with a single child still shows the missing side as `·`, so left
+/// and right can be told apart.
+pub fn render_tree<T: fmt::Display>(root: &TreeNode<T>) -> String {
+    let mut out = root.val.to_string();
+    render_children(root, "", &mut out);
+    out
+}
+
+fn render_children<T: fmt::Display>(node: &TreeNode<T>, prefix: &str, out: &mut String) {
+    if node.left.is_none() && node.right.is_none() {
+        return;
+    }
+    for (child, is_last) in [(&node.left, false), (&node.right, true)] {
+        let (branch, indent) = if is_last { ("└── ", "    ") } else { ("├── ", "│   ") };
+        out.push('\n');
+        out.push_str(prefix);
+        out.push_str(branch);
+        match child {
+            Some(child) => {
+                let child = child.borrow();
+                out.push_str(&child.val.to_string());
+                render_children(&child, &format!("{}{}", prefix, indent), out);
+            }
+            None => out.push('·'),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_level_order_with_gaps() {
+        let root = TreeNode::from_level_order(&[Some(1), Some(2), Some(3), None, Some(5)]).unwrap();
+        let root = root.borrow();
+        assert_eq!(root.val, 1);
+        let left = root.left.as_ref().unwrap().borrow();
+        assert_eq!(left.left, None);
+        assert_eq!(left.right.as_ref().unwrap().borrow().val, 5);
+        assert_eq!(root.right.as_ref().unwrap().borrow().val, 3);
+    }
+
+    #[test]
+    fn empty_input_is_an_empty_tree() {
+        assert_eq!(TreeNode::<i32>::from_level_order(&[]), None);
+        assert_eq!(TreeNode::<i32>::from_level_order(&[None]), None);
+    }
+
+    #[test]
+    fn level_order_round_trips() {
+        let values = [Some(3), Some(9), Some(20), None, None, Some(15), Some(7)];
+        let root = TreeNode::from_level_order(&values).unwrap();
+        assert_eq!(root.borrow().to_level_order(), values);
+
+        // A gap in the middle is kept, trailing gaps are not.
+        let root = TreeNode::from_level_order(&[Some(1), Some(2), Some(3), None, Some(4), None, None]).unwrap();
+        assert_eq!(root.borrow().to_level_order(), [Some(1), Some(2), Some(3), None, Some(4)]);
+    }
+
+    #[test]
+    fn constructors_match_level_order() {
+        let built = TreeNode::branch(1, TreeNode::branch(2, None, TreeNode::leaf(5)), TreeNode::leaf(3));
+        assert_eq!(built, TreeNode::from_level_order(&[Some(1), Some(2), Some(3), None, Some(5)]));
+    }
+
+    #[test]
+    fn displays_like_leetcode() {
+        assert_eq!(format(&TreeNode::from_level_order(&[Some(1), Some(2), Some(3), None, Some(5)])), "[1,2,3,null,5]");
+        assert_eq!(format(&TreeNode::leaf(4)), "[4]");
+        assert_eq!(format::<i32>(&None), "[]");
+    }
+
+    #[test]
+    fn renders_branches_and_gaps() {
+        let root = TreeNode::from_level_order(&[Some(1), Some(2), Some(3), None, Some(5), Some(6)]).unwrap();
+        let expected = "\
+1
+├── 2
+│   ├── ·
+│   └── 5
+└── 3
+    ├── 6
+    └── ·";
+        assert_eq!(render_tree(&root.borrow()), expected);
+        assert_eq!(render_tree(&TreeNode::new(7)), "7");
+    }
+
+    #[test]
+    fn dot_keeps_an_only_child_on_its_side() {
+        let root = TreeNode::from_level_order(&[Some(1), None, Some(2)]).unwrap();
+        let dot = root.borrow().to_dot();
+        let expected = "\
+digraph {
+n0 [label=\"1\"];
+n1 [style=invis];
+n0 -> n1 [style=invis];
+n2 [label=\"2\"];
+n0 -> n2;
+}
+";
+        assert_eq!(dot, expected);
+    }
+
+    #[test]
+    fn left_spine_only_goes_left() {
+        let spine = left_spine(3);
+        assert_eq!(spine, TreeNode::from_level_order(&[Some(0), Some(1), None, Some(2)]));
+        drop_iteratively(spine);
+        assert_eq!(left_spine(0), None);
+    }
+
+    #[test]
+    fn works_for_any_value_type() {
+        let words = TreeNode::from_level_order(&[Some("m"), Some("a"), Some("z"), None, Some("c")]);
+        assert_eq!(format(&words), "[m,a,z,null,c]");
+        let words = words.unwrap();
+        assert_eq!(render_tree(&words.borrow()), "m\n├── a\n│   ├── ·\n│   └── c\n└── z");
+
+        let pairs = TreeNode::branch((1, 'x'), TreeNode::leaf((2, 'y')), None).unwrap();
+        assert_eq!(pairs.borrow().to_level_order(), [Some((1, 'x')), Some((2, 'y'))]);
+        assert!(TreeNode::new("a\"b").to_dot().contains("label=\"a\\\"b\""));
+    }
+
+    #[test]
+    fn level_helpers_share_one_traversal() {
+        // 3 / 9 20 / 15 7, plus a lone 8 under 9's right
+        let root = TreeNode::from_level_order(&[Some(3), Some(9), Some(20), None, Some(8), Some(15), Some(7)]);
+        assert_eq!(level_order(&root), [vec![3], vec![9, 20], vec![8, 15, 7]]);
+        assert_eq!(zigzag_level_order(&root), [vec![3], vec![20, 9], vec![8, 15, 7]]);
+        assert_eq!(right_side_view(&root), [3, 20, 7]);
+
+        let mut depths = Vec::new();
+        root.unwrap().borrow().breadth_first(|node, depth| depths.push((node.val, depth)));
+        assert_eq!(depths, [(3, 0), (9, 1), (20, 1), (8, 2), (15, 2), (7, 2)]);
+    }
+
+    #[test]
+    fn right_side_view_sees_left_nodes_below_a_short_right() {
+        let root = TreeNode::from_level_order(&[Some(1), Some(2), Some(3), Some(4)]);
+        assert_eq!(right_side_view(&root), [1, 3, 4]);
+        assert_eq!(zigzag_level_order(&left_spine(3)), [[0], [1], [2]]);
+        assert!(level_order::<i32>(&None).is_empty());
+        assert!(right_side_view::<i32>(&None).is_empty());
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+// Two prefix trees behind one trait, so solutions and benchmarks can swap
+// them:
+// • Trie: 26 child slots per node, indexed by letter. Only takes lowercase
+//   ASCII, as the LeetCode problems promise, and finds a child in one step
+// • HashTrie: a HashMap of children per node. Takes any char and only pays
+//   for the children a node has
+//
+// In patterns for `matches`, `.` stands for any one character (LeetCode 211).
+
+/// A set of words that can also be asked about prefixes and patterns.
+pub trait Dictionary {
+    fn insert(&mut self, word: &str);
+    /// Whether `word` itself was inserted.
+    fn search(&self, word: &str) -> bool;
+    /// Whether some inserted word starts with `prefix`.
+    fn starts_with(&self, prefix: &str) -> bool;
+    /// Whether some inserted word matches `pattern`, where `.` is any one
+    /// character.
+    fn matches(&self, pattern: &str) -> bool;
+}
+
+#[derive(Debug, Default)]
+pub struct Trie {
+    root: Node,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    children: [Option<Box<Node>>; 26],
+    is_word: bool,
+}
+
+// The child slot for `c`, or None outside a-z
+fn slot(c: u8) -> Option<usize> {
+    c.is_ascii_lowercase().then(|| (c - b'a') as usize)
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // The node `prefix` leads to, if any word starts with it
+    fn walk(&self, prefix: &str) -> Option<&Node> {
+        prefix.bytes().try_fold(&self.root, |node, c| node.children[slot(c)?].as_deref())
+    }
+}
+
+impl Node {
+    fn matches(&self, pattern: &[u8]) -> bool {
+        match pattern.split_first() {
+            None => self.is_word,
+            Some((b'.', rest)) => self.children.iter().flatten().any(|child| child.matches(rest)),
+            Some((&c, rest)) => slot(c).and_then(|i| self.children[i].as_deref()).is_some_and(|child| child.matches(rest)),
+        }
+    }
+}
+
+impl Dictionary for Trie {
+    /// # Panics
+    ///
+    /// If `word` has anything but lowercase ASCII letters.
+    fn insert(&mut self, word: &str) {
+        let mut node = &mut self.root;
+        for c in word.bytes() {
+            let i = slot(c).unwrap_or_else(|| panic!("Trie only holds lowercase ASCII words, not {:?}", word));
+            node = node.children[i].get_or_insert_with(Box::default);
+        }
+        node.is_word = true;
+    }
+
+    fn search(&self, word: &str) -> bool {
+        self.walk(word).is_some_and(|node| node.is_word)
+    }
+
+    fn starts_with(&self, prefix: &str) -> bool {
+        self.walk(prefix).is_some()
+    }
+
+    fn matches(&self, pattern: &str) -> bool {
+        self.root.matches(pattern.as_bytes())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct HashTrie {
+    root: HashNode,
+}
+
+#[derive(Debug, Default)]
+struct HashNode {
+    children: HashMap<char, HashNode>,
+    is_word: bool,
+}
+
+impl HashTrie {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn walk(&self, prefix: &str) -> Option<&HashNode> {
+        prefix.chars().try_fold(&self.root, |node, c| node.children.get(&c))
+    }
+}
+
+impl HashNode {
+    fn matches(&self, pattern: &[char]) -> bool {
+        match pattern.split_first() {
+            None => self.is_word,
+            Some(('.', rest)) => self.children.values().any(|child| child.matches(rest)),
+            Some((c, rest)) => self.children.get(c).is_some_and(|child| child.matches(rest)),
+        }
+    }
+}
+
+impl Dictionary for HashTrie {
+    fn insert(&mut self, word: &str) {
+        let node = word.chars().fold(&mut self.root, |node, c| node.children.entry(c).or_default());
+        node.is_word = true;
+    }
+
+    fn search(&self, word: &str) -> bool {
+        self.walk(word).is_some_and(|node| node.is_word)
+    }
+
+    fn starts_with(&self, prefix: &str) -> bool {
+        self.walk(prefix).is_some()
+    }
+
+    fn matches(&self, pattern: &str) -> bool {
+        self.root.matches(&pattern.chars().collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complexity::pseudo_random;
+    use std::collections::HashSet;
+
+    fn check_words<D: Dictionary + Default>() {
+        let mut dictionary = D::default();
+        for word in ["apple", "app", "bad", "dad", "mad"] {
+            dictionary.insert(word);
+        }
+        assert!(dictionary.search("app") && dictionary.search("apple"));
+        assert!(!dictionary.search("appl") && !dictionary.search("apples") && !dictionary.search(""));
+        assert!(dictionary.starts_with("appl") && dictionary.starts_with("") && !dictionary.starts_with("b.d"));
+        assert!(dictionary.matches(".ad") && dictionary.matches("b..") && dictionary.matches("a.p.e"));
+        assert!(!dictionary.matches("pad") && !dictionary.matches("..") && !dictionary.matches("...."));
+
+        dictionary.insert("");
+        assert!(dictionary.search("") && dictionary.matches(""));
+    }
+
+    #[test]
+    fn array_trie_finds_words_prefixes_and_patterns() {
+        check_words::<Trie>();
+        assert!(!Trie::new().search("Apple"));
+    }
+
+    #[test]
+    fn hash_trie_finds_words_prefixes_and_patterns() {
+        check_words::<HashTrie>();
+        let mut trie = HashTrie::new();
+        trie.insert("héllo");
+        assert!(trie.search("héllo") && trie.matches("h.llo") && !trie.search("hello"));
+    }
+
+    #[test]
+    #[should_panic(expected = "lowercase ASCII")]
+    fn array_trie_rejects_other_letters() {
+        Trie::new().insert("Apple");
+    }
+
+    #[test]
+    fn both_agree_with_a_hash_set() {
+        let mut letters = pseudo_random(4).map(|x| (b'a' + (x % 3) as u8) as char);
+        let words: Vec<String> = (0..200).map(|i| letters.by_ref().take(1 + i % 6).collect()).collect();
+        let (inserted, queries) = words.split_at(100);
+        let set: HashSet<&str> = inserted.iter().map(String::as_str).collect();
+        let mut trie = Trie::new();
+        let mut hash_trie = HashTrie::new();
+        for word in inserted {
+            trie.insert(word);
+            hash_trie.insert(word);
+        }
+        for query in queries {
+            let prefix = set.iter().any(|word| word.starts_with(query.as_str()));
+            let pattern: String = query.chars().enumerate().map(|(i, c)| if i % 2 == 0 { '.' } else { c }).collect();
+            let matching = set.iter().any(|word| {
+                word.len() == pattern.len() && word.chars().zip(pattern.chars()).all(|(w, p)| p == '.' || w == p)
+            });
+            for dictionary in [&trie as &dyn Dictionary, &hash_trie] {
+                assert_eq!(dictionary.search(query), set.contains(query.as_str()), "{}", query);
+                assert_eq!(dictionary.starts_with(query), prefix, "{}", query);
+                assert_eq!(dictionary.matches(&pattern), matching, "{}", pattern);
+            }
+        }
+    }
+}
@@ -0,0 +1,357 @@
+//! LeetCode solutions, one module per problem under [`problems`].
+//!
+//! Linked lists and trees live in [`ds`]. Every problem implements
+//! [`Problem`], which pairs the solution with its metadata and the examples
+//! from the statement; the `leet` binary lists and runs them, and
+//! [`complexity`] estimates how their running time grows.
+
+use std::fmt::{self, Debug};
+use std::str::FromStr;
+
+use parse::{FromLeet, ParseError};
+
+pub mod cases;
+pub mod complexity;
+pub mod ds;
+pub mod memo;
+pub mod parse;
+pub mod practice;
+pub mod problems;
+pub mod report;
+pub mod scaffold;
+pub mod wasm;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Medium => "Medium",
+            Difficulty::Hard => "Hard",
+        };
+        f.pad(label)
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    /// Any case: `easy`, `Medium`, `HARD`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "easy" => Ok(Difficulty::Easy),
+            "medium" => Ok(Difficulty::Medium),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(format!("unknown difficulty '{}', expected easy, medium or hard", s)),
+        }
+    }
+}
+
+/// An alternative solution: its name and the function that runs a case.
+pub type Variant<I, O> = (&'static str, fn(I) -> O);
+
+/// A solved problem.
+///
+/// `run` adapts one test case to the LeetCode signature, so `Input` and
+/// `Output` can be plain values (a `Vec<i32>` rather than a linked list)
+/// that are easy to write down in `examples`, and that [`parse`] can read
+/// from LeetCode's own testcase format.
+pub trait Problem {
+    type Input: Debug + Clone + FromLeet;
+    type Output: Debug + PartialEq + FromLeet;
+
+    /// LeetCode's problem number.
+    fn id(&self) -> u32;
+    /// Module name, used on the command line (`leet run two_sum`).
+    fn name(&self) -> &'static str;
+    fn title(&self) -> &'static str;
+    fn difficulty(&self) -> Difficulty;
+    /// LeetCode's topic tags, lower-case and hyphenated (`hash-table`).
+    fn tags(&self) -> &'static [&'static str];
+
+    /// Cases with their expected output, mostly from the statement.
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)>;
+    fn run(&self, case: Self::Input) -> Self::Output;
+
+    /// Other solutions kept alongside the main one, by name. They are
+    /// checked against the same examples.
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        Vec::new()
+    }
+
+    /// How a case is shown by the runner.
+    fn describe(&self, case: &Self::Input) -> String {
+        format!("{:?}", case)
+    }
+
+    /// Extra lines shown under a failing case, such as the shape of a tree.
+    fn detail(&self, _case: &Self::Input) -> Option<String> {
+        None
+    }
+
+    /// DOT statements for the structure in a case (see [`ds::dot`]), with
+    /// node ids starting with `prefix`. `None` for problems over plain values.
+    fn draw(&self, _case: &Self::Input, _prefix: &str) -> Option<String> {
+        None
+    }
+
+    /// A case of about `size` elements for [`complexity`] to time the
+    /// solutions on. `None` for problems that are not measured.
+    fn generate(&self, _size: usize) -> Option<Self::Input> {
+        None
+    }
+}
+
+/// The object-safe side of [`Problem`], so problems with different `Input`
+/// and `Output` types can share one list.
+pub trait Runnable: Sync {
+    fn id(&self) -> u32;
+    fn name(&self) -> &'static str;
+    fn title(&self) -> &'static str;
+    fn difficulty(&self) -> Difficulty;
+    fn tags(&self) -> &'static [&'static str];
+    /// The names of [`Problem::variants`], in order.
+    fn variant_names(&self) -> Vec<&'static str>;
+    /// Runs every example through the solution and each of its variants.
+    fn check(&self) -> Vec<Outcome>;
+    /// Like `check`, for one case read from a file (see [`cases`]).
+    fn check_case(&self, case: &cases::FileCase) -> Result<Vec<Outcome>, ParseError>;
+    /// Runs testcases copied from LeetCode, returning each one as shown by
+    /// `describe` with the solution's output.
+    fn solve(&self, testcases: &str) -> Result<Vec<(String, String)>, ParseError>;
+    /// One DOT document drawing the structure in each of `testcases`, or in
+    /// each example when there are none. `None` if the problem has nothing
+    /// to draw.
+    fn dot(&self, testcases: Option<&str>) -> Result<Option<String>, ParseError>;
+    /// Times the solution and each of its variants on generated cases of
+    /// growing size. Empty if the problem has no generator.
+    fn complexity(&self) -> Vec<complexity::Estimate>;
+}
+
+impl<P: Problem + Sync> Runnable for P {
+    fn id(&self) -> u32 {
+        Problem::id(self)
+    }
+
+    fn name(&self) -> &'static str {
+        Problem::name(self)
+    }
+
+    fn title(&self) -> &'static str {
+        Problem::title(self)
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Problem::difficulty(self)
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        Problem::tags(self)
+    }
+
+    fn variant_names(&self) -> Vec<&'static str> {
+        self.variants().into_iter().map(|(name, _)| name).collect()
+    }
+
+    fn check(&self) -> Vec<Outcome> {
+        let variants = self.variants();
+        self.examples().into_iter().flat_map(|(case, expected)| outcomes(self, &variants, case, &expected)).collect()
+    }
+
+    fn check_case(&self, case: &cases::FileCase) -> Result<Vec<Outcome>, ParseError> {
+        let input = parse::parse_case::<P::Input>(&case.input)?;
+        let expected = parse::parse_case::<P::Output>(&case.expected)?;
+        Ok(outcomes(self, &self.variants(), input, &expected))
+    }
+
+    fn solve(&self, testcases: &str) -> Result<Vec<(String, String)>, ParseError> {
+        let cases = parse::parse_cases::<P::Input>(testcases)?;
+        Ok(cases
+            .into_iter()
+            .map(|case| (self.describe(&case), format!("{:?}", self.run(case))))
+            .collect())
+    }
+
+    fn dot(&self, testcases: Option<&str>) -> Result<Option<String>, ParseError> {
+        let cases = match testcases {
+            Some(text) => parse::parse_cases::<P::Input>(text)?,
+            None => self.examples().into_iter().map(|(case, _)| case).collect(),
+        };
+        let mut parts = Vec::new();
+        for (i, case) in cases.iter().enumerate() {
+            let Some(statements) = self.draw(case, &format!("c{}_", i)) else {
+                return Ok(None);
+            };
+            parts.push((self.describe(case), statements));
+        }
+        Ok(Some(ds::dot::clusters(&parts)))
+    }
+
+    fn complexity(&self) -> Vec<complexity::Estimate> {
+        if self.generate(complexity::START_SIZE).is_none() {
+            return Vec::new();
+        }
+        let generate = |size| self.generate(size).expect("generate returned a case for one size but not another");
+        let estimate = |variant, samples: Vec<_>| complexity::Estimate { variant, class: complexity::fit(&samples), samples };
+
+        let mut estimates = vec![estimate(None, complexity::measure(generate, |case| self.run(case)))];
+        for (name, variant) in self.variants() {
+            estimates.push(estimate(Some(name), complexity::measure(generate, variant)));
+        }
+        estimates
+    }
+}
+
+// Runs one case through the solution and each variant, main solution first
+fn outcomes<P: Problem>(
+    problem: &P,
+    variants: &[Variant<P::Input, P::Output>],
+    case: P::Input,
+    expected: &P::Output,
+) -> Vec<Outcome> {
+    let input = problem.describe(&case);
+    let detail = problem.detail(&case);
+    let mut ran = Vec::new();
+    for (name, variant) in variants {
+        ran.push((Some(*name), variant(case.clone())));
+    }
+    ran.insert(0, (None, problem.run(case)));
+
+    let mut outcomes = Vec::new();
+    for (variant, got) in ran {
+        let mut outcome = Outcome::new(input.clone(), expected, &got);
+        outcome.variant = variant;
+        if !outcome.passed {
+            outcome.detail = detail.clone();
+        }
+        outcomes.push(outcome);
+    }
+    outcomes
+}
+
+/// One example checked against a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub input: String,
+    pub expected: String,
+    pub got: String,
+    pub passed: bool,
+    /// Which of [`Problem::variants`] ran, `None` for the main solution.
+    pub variant: Option<&'static str>,
+    /// From [`Problem::detail`], kept for failing cases only.
+    pub detail: Option<String>,
+}
+
+impl Outcome {
+    pub fn new<T: Debug + PartialEq>(input: impl Into<String>, expected: T, got: T) -> Self {
+        Outcome {
+            input: input.into(),
+            expected: format!("{:?}", expected),
+            got: format!("{:?}", got),
+            passed: expected == got,
+            variant: None,
+            detail: None,
+        }
+    }
+}
+
+/// Looks a problem up by its module name.
+pub fn find(name: &str) -> Option<&'static dyn Runnable> {
+    problems::all().iter().copied().find(|problem| problem.name() == name)
+}
+
+/// Narrows problems down by their metadata. The default matches every
+/// problem.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    /// Tags a problem must all have.
+    pub tags: Vec<String>,
+    pub difficulty: Option<Difficulty>,
+}
+
+impl Filter {
+    pub fn matches(&self, problem: &dyn Runnable) -> bool {
+        self.tags.iter().all(|tag| problem.tags().contains(&tag.as_str()))
+            && self.difficulty.is_none_or(|difficulty| problem.difficulty() == difficulty)
+    }
+
+    /// The registered problems that match, by id.
+    pub fn apply(&self) -> Vec<&'static dyn Runnable> {
+        problems::all().iter().copied().filter(|problem| self.matches(*problem)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_by_name() {
+        assert_eq!(find("two_sum").map(|problem| problem.id()), Some(1));
+        assert!(find("three_sum").is_none());
+    }
+
+    #[test]
+    fn solve_reads_leetcode_testcases() {
+        let two_sum = find("two_sum").unwrap();
+        let solved = two_sum.solve("[2,7,11,15]\n9\n[3,2,4]\n6").unwrap();
+        assert_eq!(solved[0].1, "[0, 1]");
+        assert_eq!(solved[1].1, "[1, 2]");
+        assert_eq!(two_sum.solve("[2,7,11,15]"), Err(ParseError::MissingArgument));
+    }
+
+    #[test]
+    fn detail_is_kept_for_failures_only() {
+        let outcomes = find("max_depth").unwrap().check();
+        assert!(outcomes.iter().all(|outcome| outcome.passed && outcome.detail.is_none()));
+    }
+
+    #[test]
+    fn dot_draws_structures_only() {
+        let dot = find("max_depth").unwrap().dot(Some("[1,null,2]")).unwrap().unwrap();
+        assert!(dot.starts_with("digraph {\nsubgraph cluster_0 {\nlabel=\"[1,null,2]\";\n"));
+        assert!(dot.contains("c0_0 -> c0_2;"));
+        assert_eq!(find("two_sum").unwrap().dot(None), Ok(None));
+    }
+
+    #[test]
+    fn variants_run_after_the_main_solution() {
+        let outcomes = find("sell_stock").unwrap().check();
+        let variants: Vec<_> = outcomes.iter().take(2).map(|outcome| outcome.variant).collect();
+        assert_eq!(variants, [None, Some("v2")]);
+        assert_eq!(outcomes[0].input, outcomes[1].input);
+    }
+
+    #[test]
+    fn outcome_compares_values_not_text() {
+        assert!(Outcome::new("x", 1.0, 1.0).passed);
+        assert!(!Outcome::new("x", vec![1], vec![2]).passed);
+    }
+
+    #[test]
+    fn difficulty_parses_in_any_case() {
+        assert_eq!("easy".parse(), Ok(Difficulty::Easy));
+        assert_eq!("Hard".parse(), Ok(Difficulty::Hard));
+        assert!("simple".parse::<Difficulty>().unwrap_err().contains("'simple'"));
+    }
+
+    #[test]
+    fn filter_wants_every_tag_and_the_difficulty() {
+        let names = |filter: Filter| filter.apply().iter().map(|problem| problem.name()).collect::<Vec<_>>();
+        let tags = |tags: &[&str]| tags.iter().map(|tag| tag.to_string()).collect();
+        assert_eq!(names(Filter::default()).len(), problems::all().len());
+        assert_eq!(
+            names(Filter { tags: tags(&["two-pointers"]), difficulty: Some(Difficulty::Easy) }),
+            ["first_occurence", "is_palindrome"]
+        );
+        assert_eq!(names(Filter { tags: tags(&["string", "stack"]), difficulty: None }), ["valid_parentheses"]);
+        assert_eq!(names(Filter { tags: tags(&["linked-list"]), difficulty: Some(Difficulty::Hard) }), ["merge_k_lists"]);
+        assert!(names(Filter { tags: tags(&["tree"]), difficulty: Some(Difficulty::Hard) }).is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+//! Memoized recursion without threading a cache through every call.
+//!
+//! [`memoize!`](crate::memoize) wraps a recursive function so each distinct
+//! set of arguments is computed once:
+//!
+//! ```
+//! leet_code::memoize! {
+//!     fn fib(n: u64) -> u64 {
+//!         if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+//!     }
+//! }
+//! assert_eq!(fib(90), 2880067194370816120);
+//! ```
+//!
+//! The body is written exactly like the naive recursion; its recursive
+//! calls go through the wrapper and so through the cache. Each function
+//! gets a thread-local [`Cache`] that only lives as long as the outermost
+//! call: the next call starts empty, so repeated calls cost the same and
+//! memory is not held between them.
+//!
+//! The arguments are the key, so they have to be owned `Clone + Hash + Eq`
+//! values (no references) and the result `Clone`. Generic functions are not
+//! supported.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Wraps `fn name(args) -> Ret { body }` so results are cached by arguments
+/// for the duration of the outermost call. See [`memo`](crate::memo).
+#[macro_export]
+macro_rules! memoize {
+    ($(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty $body:block) => {
+        $(#[$attr])*
+        $vis fn $name($($arg: $ty),*) -> $ret {
+            ::std::thread_local! {
+                static CACHE: $crate::memo::Cache<($($ty,)*), $ret> = $crate::memo::Cache::new();
+            }
+            let key = ($(::std::clone::Clone::clone(&$arg),)*);
+            CACHE.with(|cache| cache.get_or_insert_with(key, move || -> $ret { $body }))
+        }
+    };
+}
+
+/// The results of one outermost call and everything it recursed into.
+pub struct Cache<K, V> {
+    values: RefCell<HashMap<K, V>>,
+    // Calls being computed; the values are dropped when it gets back to 0
+    depth: Cell<usize>,
+}
+
+impl<K: Hash + Eq, V: Clone> Cache<K, V> {
+    pub fn new() -> Self {
+        Cache { values: RefCell::new(HashMap::new()), depth: Cell::new(0) }
+    }
+
+    /// The cached value for `key`, or `compute`'s, cached for the rest of
+    /// the outermost call. `compute` may call back into this cache.
+    pub fn get_or_insert_with(&self, key: K, compute: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.values.borrow().get(&key) {
+            return value.clone();
+        }
+        let _call = Call::enter(self);
+        // No borrow is held here, so the recursion can use the cache
+        let value = compute();
+        self.values.borrow_mut().insert(key, value.clone());
+        value
+    }
+
+    /// How many results are cached, 0 outside a call.
+    pub fn len(&self) -> usize {
+        self.values.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.borrow().is_empty()
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Cache<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Counts a call in progress. Dropped on return or unwind, so a panic does
+// not leave stale results behind for the next call
+struct Call<'a, K, V> {
+    cache: &'a Cache<K, V>,
+}
+
+impl<'a, K, V> Call<'a, K, V> {
+    fn enter(cache: &'a Cache<K, V>) -> Self {
+        cache.depth.set(cache.depth.get() + 1);
+        Call { cache }
+    }
+}
+
+impl<K, V> Drop for Call<'_, K, V> {
+    fn drop(&mut self) {
+        let depth = self.cache.depth.get() - 1;
+        self.cache.depth.set(depth);
+        if depth == 0 {
+            self.cache.values.borrow_mut().clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    thread_local! {
+        static CALLS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    memoize! {
+        fn counted_fib(n: u64) -> u64 {
+            CALLS.with(|calls| calls.set(calls.get() + 1));
+            if n < 2 { n } else { counted_fib(n - 1) + counted_fib(n - 2) }
+        }
+    }
+
+    memoize! {
+        /// Paths through a grid moving right or down, with `return` in the body.
+        pub(crate) fn paths(rows: usize, columns: usize) -> u64 {
+            if rows == 1 || columns == 1 {
+                return 1;
+            }
+            paths(rows - 1, columns) + paths(rows, columns - 1)
+        }
+    }
+
+    #[test]
+    fn computes_each_argument_once() {
+        CALLS.with(|calls| calls.set(0));
+        assert_eq!(counted_fib(50), 12586269025);
+        assert_eq!(CALLS.with(Cell::get), 51);
+        assert_eq!(paths(17, 17), 601080390);
+    }
+
+    #[test]
+    fn starts_empty_on_every_outermost_call() {
+        CALLS.with(|calls| calls.set(0));
+        counted_fib(30);
+        counted_fib(30);
+        assert_eq!(CALLS.with(Cell::get), 62);
+    }
+
+    #[test]
+    fn a_panic_clears_the_cache() {
+        let cache = Cache::new();
+        let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            cache.get_or_insert_with(1, || {
+                cache.get_or_insert_with(2, || 20);
+                assert_eq!(cache.len(), 1);
+                panic!("halfway")
+            })
+        }));
+        assert!(outcome.is_err());
+        assert!(cache.is_empty() && cache.depth.get() == 0);
+        assert_eq!(cache.get_or_insert_with(2, || 22), 22);
+    }
+}
@@ -0,0 +1,337 @@
+//! Reads testcases in the format LeetCode shows them: one parameter per
+//! line, written as JSON-ish values such as `[1,2,null,3]`, `[[1,2],[3,4]]`
+//! or `"abc"`. Several testcases can follow each other, just like in the
+//! site's testcase box.
+
+use std::error::Error;
+use std::fmt;
+use std::vec;
+
+/// A parsed value, before it is given a Rust type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Str(String),
+    Array(Vec<Value>),
+}
+
+impl Value {
+    fn kind(&self) -> String {
+        match self {
+            Value::Null => "null".to_string(),
+            Value::Bool(b) => b.to_string(),
+            Value::Int(n) => n.to_string(),
+            Value::Str(s) => format!("{:?}", s),
+            Value::Array(_) => "an array".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The text is not a valid value; `position` is a byte offset.
+    Syntax { position: usize, expected: &'static str },
+    /// The value is well formed but not what the parameter needs.
+    Type { expected: &'static str, found: String },
+    /// The input ended in the middle of a testcase.
+    MissingArgument,
+    /// Values were left over where exactly one testcase was expected.
+    ExtraValues,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Syntax { position, expected } => write!(f, "expected {} at byte {}", expected, position),
+            ParseError::Type { expected, found } => write!(f, "expected {}, found {}", expected, found),
+            ParseError::MissingArgument => write!(f, "testcase is missing a parameter"),
+            ParseError::ExtraValues => write!(f, "expected one testcase, found more values"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every value in `text`, in order.
+pub fn parse_values(text: &str) -> Result<Vec<Value>, ParseError> {
+    let mut parser = Parser { text: text.as_bytes(), position: 0 };
+    let mut values = Vec::new();
+    parser.skip_whitespace();
+    while parser.position < parser.text.len() {
+        values.push(parser.value()?);
+        parser.skip_whitespace();
+    }
+    Ok(values)
+}
+
+/// Parses `text` as one or more testcases for a problem taking `T`.
+pub fn parse_cases<T: FromLeet>(text: &str) -> Result<Vec<T>, ParseError> {
+    let mut args = parse_values(text)?.into_iter();
+    let mut cases = Vec::new();
+    while args.len() > 0 {
+        cases.push(T::from_args(&mut args)?);
+    }
+    Ok(cases)
+}
+
+/// Parses `text` as exactly one testcase.
+pub fn parse_case<T: FromLeet>(text: &str) -> Result<T, ParseError> {
+    let mut args = parse_values(text)?.into_iter();
+    let case = T::from_args(&mut args)?;
+    if args.len() > 0 {
+        return Err(ParseError::ExtraValues);
+    }
+    Ok(case)
+}
+
+struct Parser<'a> {
+    text: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn value(&mut self) -> Result<Value, ParseError> {
+        match self.peek() {
+            Some(b'[') => self.array(),
+            Some(b'"') => self.string().map(Value::Str),
+            Some(b'-' | b'0'..=b'9') => self.int(),
+            Some(b'a'..=b'z') => self.word(),
+            _ => Err(self.expected("a value")),
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, ParseError> {
+        self.position += 1;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(Value::Array(items));
+        }
+        loop {
+            self.skip_whitespace();
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Value::Array(items));
+                }
+                _ => return Err(self.expected("',' or ']'")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.position += 1;
+        let mut bytes = Vec::new();
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    self.position += 1;
+                    // Only whole UTF-8 sequences are ever copied in.
+                    return Ok(String::from_utf8(bytes).expect("input was a str"));
+                }
+                Some(b'\\') => {
+                    self.position += 1;
+                    let unescaped = match self.peek() {
+                        Some(b'"') => b'"',
+                        Some(b'\\') => b'\\',
+                        Some(b'/') => b'/',
+                        Some(b'n') => b'\n',
+                        Some(b't') => b'\t',
+                        _ => return Err(self.expected("an escape")),
+                    };
+                    bytes.push(unescaped);
+                    self.position += 1;
+                }
+                Some(byte) => {
+                    bytes.push(byte);
+                    self.position += 1;
+                }
+                None => return Err(self.expected("'\"'")),
+            }
+        }
+    }
+
+    fn int(&mut self) -> Result<Value, ParseError> {
+        let start = self.position;
+        if self.peek() == Some(b'-') {
+            self.position += 1;
+        }
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.position += 1;
+        }
+        let digits = std::str::from_utf8(&self.text[start..self.position]).expect("ascii digits");
+        digits.parse().map(Value::Int).map_err(|_| ParseError::Syntax { position: start, expected: "an integer" })
+    }
+
+    fn word(&mut self) -> Result<Value, ParseError> {
+        let start = self.position;
+        while matches!(self.peek(), Some(b'a'..=b'z')) {
+            self.position += 1;
+        }
+        match &self.text[start..self.position] {
+            b"null" => Ok(Value::Null),
+            b"true" => Ok(Value::Bool(true)),
+            b"false" => Ok(Value::Bool(false)),
+            _ => Err(ParseError::Syntax { position: start, expected: "null, true or false" }),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(byte) if byte.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.position).copied()
+    }
+
+    fn expected(&self, expected: &'static str) -> ParseError {
+        ParseError::Syntax { position: self.position, expected }
+    }
+}
+
+/// Types a [`Value`] can be turned into.
+pub trait FromLeet: Sized {
+    fn from_value(value: Value) -> Result<Self, ParseError>;
+
+    /// Reads this type's share of a testcase: one value, except for tuples,
+    /// which take one per parameter.
+    fn from_args(args: &mut vec::IntoIter<Value>) -> Result<Self, ParseError> {
+        Self::from_value(args.next().ok_or(ParseError::MissingArgument)?)
+    }
+}
+
+impl FromLeet for i32 {
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        match value {
+            Value::Int(n) => i32::try_from(n).map_err(|_| ParseError::Type { expected: "an i32", found: n.to_string() }),
+            other => Err(ParseError::Type { expected: "an integer", found: other.kind() }),
+        }
+    }
+}
+
+impl FromLeet for bool {
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        match value {
+            Value::Bool(b) => Ok(b),
+            other => Err(ParseError::Type { expected: "true or false", found: other.kind() }),
+        }
+    }
+}
+
+impl FromLeet for String {
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        match value {
+            Value::Str(s) => Ok(s),
+            other => Err(ParseError::Type { expected: "a string", found: other.kind() }),
+        }
+    }
+}
+
+/// A one-character string, as in the `"1"`/`"0"` grids of Number of
+/// Islands.
+impl FromLeet for char {
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        match value {
+            Value::Str(s) if s.chars().count() == 1 => Ok(s.chars().next().unwrap()),
+            other => Err(ParseError::Type { expected: "a one-character string", found: other.kind() }),
+        }
+    }
+}
+
+/// `null` is `None`, as in level-order trees.
+impl<T: FromLeet> FromLeet for Option<T> {
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        match value {
+            Value::Null => Ok(None),
+            other => T::from_value(other).map(Some),
+        }
+    }
+}
+
+impl<T: FromLeet> FromLeet for Vec<T> {
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        match value {
+            Value::Array(items) => items.into_iter().map(T::from_value).collect(),
+            other => Err(ParseError::Type { expected: "an array", found: other.kind() }),
+        }
+    }
+}
+
+impl<A: FromLeet, B: FromLeet> FromLeet for (A, B) {
+    fn from_value(value: Value) -> Result<Self, ParseError> {
+        match value {
+            Value::Array(items) if items.len() == 2 => {
+                let mut items = items.into_iter();
+                Self::from_args(&mut items)
+            }
+            other => Err(ParseError::Type { expected: "a pair", found: other.kind() }),
+        }
+    }
+
+    fn from_args(args: &mut vec::IntoIter<Value>) -> Result<Self, ParseError> {
+        Ok((A::from_args(args)?, B::from_args(args)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_nested_arrays_and_nulls() {
+        assert_eq!(parse_cases::<Vec<Vec<i32>>>("[[1,2],[3,4]]"), Ok(vec![vec![vec![1, 2], vec![3, 4]]]));
+        assert_eq!(parse_cases::<Vec<Option<i32>>>("[1,2,null,3]"), Ok(vec![vec![Some(1), Some(2), None, Some(3)]]));
+        assert_eq!(parse_cases::<Vec<i32>>(" [ -1 , 0 ]\n[]"), Ok(vec![vec![-1, 0], vec![]]));
+    }
+
+    #[test]
+    fn parses_strings_with_escapes() {
+        assert_eq!(parse_cases::<String>(r#""a \"b\"\\n""#), Ok(vec![r#"a "b"\n"#.to_string()]));
+        assert_eq!(parse_cases::<Vec<String>>(r#"["eat","","ünï"]"#), Ok(vec![vec!["eat".to_string(), String::new(), "ünï".to_string()]]));
+    }
+
+    #[test]
+    fn chars_are_one_character_strings() {
+        assert_eq!(parse_cases::<Vec<Vec<char>>>(r#"[["1","0"],["0","é"]]"#), Ok(vec![vec![vec!['1', '0'], vec!['0', 'é']]]));
+        assert_eq!(
+            parse_cases::<char>(r#""10""#),
+            Err(ParseError::Type { expected: "a one-character string", found: "\"10\"".to_string() })
+        );
+    }
+
+    #[test]
+    fn tuples_take_one_line_per_parameter() {
+        let cases = parse_cases::<(Vec<i32>, i32)>("[2,7,11,15]\n9\n[3,3]\n6");
+        assert_eq!(cases, Ok(vec![(vec![2, 7, 11, 15], 9), (vec![3, 3], 6)]));
+        assert_eq!(parse_cases::<(Vec<i32>, i32)>("[2,7]"), Err(ParseError::MissingArgument));
+    }
+
+    #[test]
+    fn parse_case_wants_exactly_one() {
+        assert_eq!(parse_case::<(Vec<i32>, i32)>("[3,3]\n6\n"), Ok((vec![3, 3], 6)));
+        assert_eq!(parse_case::<i32>(""), Err(ParseError::MissingArgument));
+        assert_eq!(parse_case::<i32>("1 2"), Err(ParseError::ExtraValues));
+    }
+
+    #[test]
+    fn reports_where_and_what_went_wrong() {
+        assert_eq!(parse_values("[1,2"), Err(ParseError::Syntax { position: 4, expected: "',' or ']'" }));
+        assert_eq!(parse_values("nil"), Err(ParseError::Syntax { position: 0, expected: "null, true or false" }));
+        assert_eq!(
+            parse_cases::<i32>("\"9\""),
+            Err(ParseError::Type { expected: "an integer", found: "\"9\"".to_string() })
+        );
+        assert_eq!(
+            parse_cases::<i32>("4294967296"),
+            Err(ParseError::Type { expected: "an i32", found: "4294967296".to_string() })
+        );
+    }
+}
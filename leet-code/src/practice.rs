@@ -0,0 +1,238 @@
+//! `leet practice`: solve a problem against its cases without seeing them,
+//! and keep a record of the attempts.
+//!
+//! The record is TOML, so it is easy to read or reset by hand:
+//!
+//! ```toml
+//! [problems.two_sum]
+//! started = 1760000000
+//! solved = 1760000754
+//!
+//! [[problems.two_sum.attempts]]
+//! at = 1760000512
+//! passed = 3
+//! total = 6
+//! ```
+//!
+//! Times are seconds since the Unix epoch. The file is `$LEET_PROGRESS`, or
+//! `.progress.toml` in this crate.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug)]
+pub enum ProgressError {
+    Io { path: PathBuf, error: io::Error },
+    /// The file is not valid TOML, or does not have the progress layout.
+    Format { path: PathBuf, message: String },
+}
+
+impl fmt::Display for ProgressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgressError::Io { path, error } => write!(f, "could not access {}: {}", path.display(), error),
+            ProgressError::Format { path, message } => {
+                write!(f, "{} is not a valid progress file: {}", path.display(), message.trim_end())
+            }
+        }
+    }
+}
+
+impl Error for ProgressError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ProgressError::Io { error, .. } => Some(error),
+            ProgressError::Format { .. } => None,
+        }
+    }
+}
+
+/// Every problem practised, by name. BTreeMap keeps the file sorted.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Progress {
+    #[serde(default)]
+    pub problems: BTreeMap<String, Record>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    /// When it was last picked.
+    pub started: Option<u64>,
+    /// When every case first passed after that.
+    pub solved: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attempts: Vec<Attempt>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub at: u64,
+    pub passed: usize,
+    pub total: usize,
+}
+
+impl Attempt {
+    pub fn is_solved(&self) -> bool {
+        self.total > 0 && self.passed == self.total
+    }
+}
+
+impl Record {
+    /// Seconds from being picked to being solved.
+    pub fn solve_time(&self) -> Option<u64> {
+        Some(self.solved?.saturating_sub(self.started?))
+    }
+}
+
+impl Progress {
+    /// `$LEET_PROGRESS` if set, otherwise `.progress.toml` in this crate.
+    pub fn path() -> PathBuf {
+        std::env::var_os("LEET_PROGRESS").map_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join(".progress.toml"), PathBuf::from)
+    }
+
+    /// A missing file is a fresh start, not an error.
+    pub fn load(path: &Path) -> Result<Self, ProgressError> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Progress::default()),
+            Err(error) => return Err(ProgressError::Io { path: path.to_path_buf(), error }),
+        };
+        toml::from_str(&text).map_err(|err| ProgressError::Format { path: path.to_path_buf(), message: err.to_string() })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ProgressError> {
+        // Only maps, lists and numbers, which TOML always takes
+        let text = toml::to_string_pretty(self).expect("progress serializes to TOML");
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|error| ProgressError::Io { path: dir.to_path_buf(), error })?;
+        }
+        std::fs::write(path, text).map_err(|error| ProgressError::Io { path: path.to_path_buf(), error })
+    }
+
+    /// Loads the file at `path`, applies `change` and saves it again.
+    pub fn update<T>(path: &Path, change: impl FnOnce(&mut Progress) -> T) -> Result<T, ProgressError> {
+        let mut progress = Progress::load(path)?;
+        let changed = change(&mut progress);
+        progress.save(path)?;
+        Ok(changed)
+    }
+
+    /// Starts the clock on `name` again; earlier attempts are kept.
+    pub fn start(&mut self, name: &str, now: u64) {
+        let record = self.problems.entry(name.to_string()).or_default();
+        record.started = Some(now);
+        record.solved = None;
+    }
+
+    /// Adds an attempt, marking the problem solved the first time every
+    /// case passes.
+    pub fn record(&mut self, name: &str, attempt: Attempt) -> &Record {
+        let record = self.problems.entry(name.to_string()).or_default();
+        record.attempts.push(attempt);
+        if attempt.is_solved() && record.solved.is_none() {
+            record.solved = Some(attempt.at);
+        }
+        record
+    }
+}
+
+/// The comment at the top of `src/problems/<name>.rs`, where the problem
+/// statement is kept, without the `//`. `None` if there is none.
+pub fn statement(name: &str) -> Option<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/problems").join(format!("{}.rs", name));
+    let source = std::fs::read_to_string(path).ok()?;
+    let lines: Vec<&str> = source
+        .lines()
+        .map_while(|line| line.strip_prefix("//"))
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// The problem's page: "Two Sum" is https://leetcode.com/problems/two-sum/.
+pub fn url(title: &str) -> String {
+    let words: Vec<String> = title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect();
+    format!("https://leetcode.com/problems/{}/", words.join("-"))
+}
+
+/// `754` is `12m 34s`.
+pub fn format_duration(seconds: u64) -> String {
+    match (seconds / 3600, seconds / 60 % 60, seconds % 60) {
+        (0, 0, s) => format!("{}s", s),
+        (0, m, s) => format!("{}m {}s", m, s),
+        (h, m, _) => format!("{}h {}m", h, m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `<name>.toml` in a fresh directory, removed when the TempDir is dropped
+    fn temp_file(name: &str) -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::Builder::new().prefix("leet-code-progress-").tempdir().unwrap();
+        let path = dir.path().join(format!("{}.toml", name));
+        (dir, path)
+    }
+
+    #[test]
+    fn records_attempts_until_solved() {
+        let mut progress = Progress::default();
+        progress.start("two_sum", 100);
+        progress.record("two_sum", Attempt { at: 160, passed: 2, total: 6 });
+        let record = progress.record("two_sum", Attempt { at: 854, passed: 6, total: 6 });
+        assert_eq!((record.solved, record.solve_time()), (Some(854), Some(754)));
+        // Later attempts do not move the solve time, a restart clears it
+        progress.record("two_sum", Attempt { at: 900, passed: 6, total: 6 });
+        assert_eq!(progress.problems["two_sum"].solved, Some(854));
+        progress.start("two_sum", 1000);
+        let record = &progress.problems["two_sum"];
+        assert_eq!((record.solved, record.attempts.len()), (None, 3));
+        assert!(!Attempt { at: 0, passed: 0, total: 0 }.is_solved());
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let (dir, path) = temp_file("round-trip");
+        let saved = Progress::update(&path, |progress| {
+            progress.start("max_depth", 5);
+            progress.record("max_depth", Attempt { at: 65, passed: 3, total: 3 });
+            std::mem::take(progress)
+        })
+        .unwrap();
+        // `take` left an empty progress to be saved
+        assert_eq!(Progress::load(&path).unwrap(), Progress::default());
+        saved.save(&path).unwrap();
+        assert_eq!(Progress::load(&path).unwrap(), saved);
+        assert_eq!(Progress::load(&dir.path().join("missing.toml")).unwrap(), Progress::default());
+    }
+
+    #[test]
+    fn invalid_file_names_its_path() {
+        let (_dir, path) = temp_file("invalid");
+        std::fs::write(&path, "problems = 42").unwrap();
+        let err = Progress::load(&path).unwrap_err();
+        assert!(matches!(err, ProgressError::Format { .. }));
+        assert!(err.to_string().contains("leet-code-progress"), "{}", err);
+    }
+
+    #[test]
+    fn statement_is_the_header_comment() {
+        let header = statement("count_components").unwrap();
+        assert!(header.starts_with("Given n nodes labelled 0..n"), "{}", header);
+        assert!(header.contains("Constraints:\n1 <= n <= 2000"));
+        assert_eq!(statement("two_sum"), None);
+        assert_eq!(url("Pow(x, n)"), "https://leetcode.com/problems/pow-x-n/");
+        assert_eq!(url("Two Sum"), "https://leetcode.com/problems/two-sum/");
+        assert_eq!([5, 754, 7384].map(format_duration), ["5s", "12m 34s", "2h 3m"]);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{Difficulty, Problem};
+
+pub fn search(nums: Vec<i32>, target: i32) -> i32 {
+    binary_search_by(&nums, &target).map_or(-1, |i| i as i32)
+}
+
+/// Searches a sorted slice like [`slice::binary_search`]: `Ok` with the
+/// index of a match (the first, when there are several), or `Err` with the
+/// index where `target` would be inserted to keep the slice sorted.
+pub fn binary_search_by<T: Ord>(sorted: &[T], target: &T) -> Result<usize, usize> {
+    let i = lower_bound(sorted, target);
+    match sorted.get(i) {
+        Some(found) if found == target => Ok(i),
+        _ => Err(i),
+    }
+}
+
+/// The index of the first element not less than `target`, or `sorted.len()`.
+pub fn lower_bound<T: Ord>(sorted: &[T], target: &T) -> usize {
+    partition(sorted, |x| x < target)
+}
+
+/// The index of the first element greater than `target`, or `sorted.len()`.
+/// `lower_bound..upper_bound` is the run of elements equal to `target`.
+pub fn upper_bound<T: Ord>(sorted: &[T], target: &T) -> usize {
+    partition(sorted, |x| x <= target)
+}
+
+// The first index where `before` turns false, given that it is true for a
+// prefix of the slice and false after. [left, right) is the part not yet
+// decided, so it never needs a signed or out-of-range index.
+fn partition<T>(sorted: &[T], before: impl Fn(&T) -> bool) -> usize {
+    let mut left = 0;
+    let mut right = sorted.len();
+    while left < right {
+        let mid = left + (right - left) / 2; // left + right could overflow
+        if before(&sorted[mid]) {
+            left = mid + 1;
+        } else {
+            right = mid;
+        }
+    }
+    left
+}
+
+pub struct BinarySearch;
+
+impl Problem for BinarySearch {
+    type Input = (Vec<i32>, i32);
+    type Output = i32;
+
+    fn id(&self) -> u32 {
+        704
+    }
+
+    fn name(&self) -> &'static str {
+        "binary_search"
+    }
+
+    fn title(&self) -> &'static str {
+        "Binary Search"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["array", "binary-search"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            ((vec![0, 1, 2, 4, 5, 6, 7], 0), 0),
+            ((vec![0, 1, 2, 4, 5, 6, 7], 3), -1),
+            ((vec![], 0), -1), // Edge case: empty array
+            ((vec![1], 1), 0), // Edge case: single element found
+            ((vec![1], 2), -1), // Edge case: single element not found
+            ((vec![2, 5], 5), 1), // Edge case: two elements, target found
+            ((vec![2, 5], 3), -1), // Edge case: two elements, target not found
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        let (nums, target) = case;
+        search(nums, target)
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        Some(((0..size as i32).collect(), size as i32 - 1))
+    }
+}
+
+register_problem!(BinarySearch);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Sorted vectors over a narrow range, so there are runs of duplicates,
+    // and targets a little below, between and above the values
+    fn sorted_vec() -> impl Strategy<Value = Vec<i32>> {
+        prop::collection::vec(-20..=20, 0..=40).prop_map(|mut nums| {
+            nums.sort();
+            nums
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn matches_slice_binary_search(nums in sorted_vec(), target in -22..=22) {
+            let expected = nums.binary_search(&target);
+            match binary_search_by(&nums, &target) {
+                // With duplicates either search may land on any of them
+                Ok(i) => {
+                    prop_assert!(expected.is_ok());
+                    prop_assert_eq!(nums[i], target);
+                }
+                Err(i) => prop_assert_eq!(Err(i), expected),
+            }
+        }
+
+        #[test]
+        fn bounds_match_partition_point(nums in sorted_vec(), target in -22..=22) {
+            let lower = lower_bound(&nums, &target);
+            let upper = upper_bound(&nums, &target);
+            prop_assert_eq!(lower, nums.partition_point(|&x| x < target));
+            prop_assert_eq!(upper, nums.partition_point(|&x| x <= target));
+            prop_assert_eq!(upper - lower, nums.iter().filter(|&&x| x == target).count());
+            prop_assert_eq!(binary_search_by(&nums, &target).ok(), (lower < upper).then_some(lower));
+        }
+    }
+
+    #[test]
+    fn works_for_any_ord() {
+        let words = ["apple", "banana", "banana", "cherry"];
+        assert_eq!(binary_search_by(&words, &"banana"), Ok(1));
+        assert_eq!(binary_search_by(&words, &"blueberry"), Err(3));
+        assert_eq!(upper_bound(&words, &"banana"), 3);
+        assert_eq!(lower_bound::<u8>(&[], &0), 0);
+    }
+
+    #[test]
+    fn search_wraps_the_generic_version() {
+        for (case, expected) in BinarySearch.examples() {
+            assert_eq!(search(case.0.clone(), case.1), expected, "{:?}", case);
+        }
+        assert_eq!(search(vec![1, 3, 3, 3, 5], 3), 1);
+    }
+}
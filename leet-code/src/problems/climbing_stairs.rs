@@ -0,0 +1,100 @@
+// You are climbing a staircase that takes n steps to reach the top. Each
+// time you can climb either 1 or 2 steps. In how many distinct ways can you
+// climb to the top?
+// Example 1:
+// Input: n = 2
+// Output: 2
+// Explanation: 1 + 1, or 2.
+// Example 2:
+// Input: n = 3
+// Output: 3
+// Explanation: 1 + 1 + 1, 1 + 2, or 2 + 1.
+// Constraints:
+// 1 <= n <= 45
+
+use crate::memoize;
+use crate::{Difficulty, Problem, Variant};
+
+// The last climb was 1 step or 2, so the ways to n are the ways to n - 1
+// plus the ways to n - 2: Fibonacci. Straight from that, the recursion
+// solves the same smaller stairs over and over, about 1.6^n calls. Kept
+// out of the variants: `leet complexity` would never get past its first
+// size.
+pub fn climb_stairs_naive(n: i32) -> i32 {
+    if n <= 2 {
+        return n;
+    }
+    climb_stairs_naive(n - 1) + climb_stairs_naive(n - 2)
+}
+
+memoize! {
+    /// The same recursion, each n computed once.
+    pub fn climb_stairs(n: i32) -> i32 {
+        if n <= 2 {
+            return n;
+        }
+        climb_stairs(n - 1) + climb_stairs(n - 2)
+    }
+}
+
+// Bottom-up, keeping only the last two counts.
+pub fn climb_stairs_bottom_up(n: i32) -> i32 {
+    let (mut before, mut last) = (1, 1);
+    for _ in 1..n {
+        (before, last) = (last, before + last);
+    }
+    last
+}
+
+pub struct ClimbingStairs;
+
+impl Problem for ClimbingStairs {
+    type Input = i32;
+    type Output = i32;
+
+    fn id(&self) -> u32 {
+        70
+    }
+
+    fn name(&self) -> &'static str {
+        "climbing_stairs"
+    }
+
+    fn title(&self) -> &'static str {
+        "Climbing Stairs"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["math", "dynamic-programming", "memoization"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![(2, 2), (3, 3), (1, 1), (10, 89), (45, 1836311903)]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        climb_stairs(case)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![("bottom-up", climb_stairs_bottom_up)]
+    }
+}
+
+register_problem!(ClimbingStairs);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn naive_recursion_agrees() {
+        for n in 1..=25 {
+            assert_eq!(climb_stairs_naive(n), climb_stairs(n), "{}", n);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// Given an integer array nums, return true if any value appears more than once in the array, otherwise return false.
+// Example 1:
+// Input: nums = [1, 2, 3, 3]
+// Output: true
+// Example 2:
+// Input: nums = [1, 2, 3, 4]
+// Output: false
+
+use std::collections::HashSet;
+use crate::{Difficulty, Problem, Variant};
+
+/// Every strategy, by name, in the order the runner and benchmarks use.
+pub const STRATEGIES: [Variant<Vec<i32>, bool>; 3] = [
+    ("hash_set", contains_duplicate),
+    ("sort", contains_duplicate_sort),
+    ("bitset", contains_duplicate_bitset),
+];
+
+// One pass, stopping at the first value seen twice. O(n) expected time,
+// O(n) extra space.
+pub fn contains_duplicate(nums: Vec<i32>) -> bool {
+    let mut set = HashSet::with_capacity(nums.len());
+    for n in nums {
+        if !set.insert(n) {
+            return true; // Duplicate found
+        }
+    }
+    false // No duplicates found
+}
+
+// Sorting puts equal values next to each other. O(n log n) time, no extra
+// space beyond the input it already owns, and no early exit.
+pub fn contains_duplicate_sort(mut nums: Vec<i32>) -> bool {
+    nums.sort_unstable();
+    nums.windows(2).any(|pair| pair[0] == pair[1])
+}
+
+// One bit per value between the smallest and the largest. Only worth it when
+// the values are bounded: once the bitset would need more words than there
+// are numbers, this sorts instead.
+pub fn contains_duplicate_bitset(nums: Vec<i32>) -> bool {
+    let (Some(&min), Some(&max)) = (nums.iter().min(), nums.iter().max()) else {
+        return false;
+    };
+    let span = (max as i64 - min as i64) as usize + 1;
+    if span.div_ceil(64) > nums.len() {
+        return contains_duplicate_sort(nums);
+    }
+
+    let mut seen = vec![0u64; span.div_ceil(64)];
+    for n in nums {
+        let bit = (n as i64 - min as i64) as usize;
+        let (word, mask) = (bit / 64, 1u64 << (bit % 64));
+        if seen[word] & mask != 0 {
+            return true;
+        }
+        seen[word] |= mask;
+    }
+    false
+}
+
+pub struct ContainsDuplicate;
+
+impl Problem for ContainsDuplicate {
+    type Input = Vec<i32>;
+    type Output = bool;
+
+    fn id(&self) -> u32 {
+        217
+    }
+
+    fn name(&self) -> &'static str {
+        "contains_duplicate"
+    }
+
+    fn title(&self) -> &'static str {
+        "Contains Duplicate"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["array", "hash-table", "sorting"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (vec![1, 2, 3, 3], true),
+            (vec![1, 2, 3, 4], false),
+            (vec![1, 2, 3, 4, 5, 1], true),
+            (vec![], false),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        contains_duplicate(case)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        STRATEGIES[1..].to_vec()
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // No duplicate, so every strategy looks at every number
+        Some((0..size as i32).rev().collect())
+    }
+}
+
+register_problem!(ContainsDuplicate);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(nums: &[i32], expected: bool) {
+        for (name, strategy) in STRATEGIES {
+            assert_eq!(strategy(nums.to_vec()), expected, "{} on {:?}", name, nums);
+        }
+    }
+
+    #[test]
+    fn all_strategies_agree_on_examples() {
+        for (case, expected) in ContainsDuplicate.examples() {
+            check(&case, expected);
+        }
+    }
+
+    #[test]
+    fn all_strategies_handle_the_extremes_of_i32() {
+        check(&[i32::MIN, i32::MAX], false);
+        check(&[i32::MAX, 0, i32::MAX], true);
+        check(&[i32::MIN, -1, i32::MIN], true);
+        check(&[7], false);
+    }
+
+    #[test]
+    fn bitset_covers_a_dense_range() {
+        let mut nums: Vec<i32> = (-500..500).rev().collect();
+        assert!(!contains_duplicate_bitset(nums.clone()));
+        nums.push(499);
+        assert!(contains_duplicate_bitset(nums));
+    }
+}
@@ -0,0 +1,100 @@
+// Given n nodes labelled 0..n and a list of undirected edges, return the
+// number of connected components in the graph.
+// Example 1:
+// Input: n = 5, edges = [[0,1],[1,2],[3,4]]
+// Output: 2
+// Example 2:
+// Input: n = 5, edges = [[0,1],[1,2],[2,3],[3,4]]
+// Output: 1
+// Constraints:
+// 1 <= n <= 2000
+// 1 <= edges.length <= 5000
+// There are no repeated edges.
+
+use crate::complexity::pseudo_random;
+use crate::ds::graph::pairs;
+use crate::ds::{DisjointSet, Graph, ToDot};
+use crate::{Difficulty, Problem, Variant};
+
+// Every edge merges two sets, or joins two nodes already connected; what is
+// left is one set per component.
+pub fn count_components(n: i32, edges: Vec<Vec<i32>>) -> i32 {
+    let mut sets = DisjointSet::new(n as usize);
+    for edge in &edges {
+        sets.union(edge[0] as usize, edge[1] as usize);
+    }
+    sets.count() as i32
+}
+
+// Depth-first from every node not yet seen, one search per component: each
+// one starts a new tree in the traversal.
+pub fn count_components_dfs(n: i32, edges: Vec<Vec<i32>>) -> i32 {
+    let graph = Graph::undirected(n as usize, &pairs(&edges));
+    graph.dfs(0..graph.len()).filter(|visit| visit.parent.is_none()).count() as i32
+}
+
+pub struct CountComponents;
+
+impl Problem for CountComponents {
+    type Input = (i32, Vec<Vec<i32>>);
+    type Output = i32;
+
+    fn id(&self) -> u32 {
+        323
+    }
+
+    fn name(&self) -> &'static str {
+        "count_components"
+    }
+
+    fn title(&self) -> &'static str {
+        "Number of Connected Components in an Undirected Graph"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Medium
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["depth-first-search", "breadth-first-search", "union-find", "graph"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            ((5, vec![vec![0, 1], vec![1, 2], vec![3, 4]]), 2),
+            ((5, vec![vec![0, 1], vec![1, 2], vec![2, 3], vec![3, 4]]), 1),
+            ((1, vec![]), 1),
+            ((4, vec![vec![2, 3], vec![1, 2], vec![1, 3]]), 2), // A cycle is still one component
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        let (n, edges) = case;
+        count_components(n, edges)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![("dfs", |(n, edges)| count_components_dfs(n, edges))]
+    }
+
+    fn draw(&self, case: &Self::Input, prefix: &str) -> Option<String> {
+        let (n, edges) = case;
+        Some(Graph::undirected(*n as usize, &pairs(edges)).dot_statements(prefix))
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // Random edges between nodes of the same tenth, so about ten components
+        let n = size.max(10);
+        let mut random = pseudo_random(10).map(|x| x as usize % n);
+        let edges = (0..n)
+            .map(|_| {
+                let a = random.next().unwrap();
+                let b = a - a % 10 + random.next().unwrap() % 10;
+                vec![a as i32, b.min(n - 1) as i32]
+            })
+            .collect();
+        Some((n as i32, edges))
+    }
+}
+
+register_problem!(CountComponents);
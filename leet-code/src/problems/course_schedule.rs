@@ -0,0 +1,154 @@
+// There are numCourses courses labelled 0..numCourses. prerequisites[i] =
+// [a, b] means course b has to be taken before course a. Return true if
+// every course can be finished.
+// Example 1:
+// Input: numCourses = 2, prerequisites = [[1,0]]
+// Output: true
+// Example 2:
+// Input: numCourses = 2, prerequisites = [[1,0],[0,1]]
+// Output: false
+// Explanation: each course needs the other one first.
+// Constraints:
+// 1 <= numCourses <= 2000
+// 0 <= prerequisites.length <= 5000
+// All the pairs prerequisites[i] are unique.
+
+use crate::complexity::pseudo_random;
+use crate::ds::{Graph, ToDot};
+use crate::{Difficulty, Problem, Variant};
+
+// Edges point from a course to the ones it unlocks
+fn schedule(num_courses: i32, prerequisites: &[Vec<i32>]) -> Graph {
+    let edges: Vec<_> = prerequisites.iter().map(|pair| (pair[1] as usize, pair[0] as usize)).collect();
+    Graph::directed(num_courses as usize, &edges)
+}
+
+// The courses can be taken in a topological order of the schedule, which
+// exists exactly when no chain of prerequisites comes back on itself.
+pub fn can_finish(num_courses: i32, prerequisites: Vec<Vec<i32>>) -> bool {
+    schedule(num_courses, &prerequisites).topological_sort().is_some()
+}
+
+// Depth-first, marking each course in progress until every course it
+// unlocks has been explored: reaching a course still in progress means a
+// cycle.
+pub fn can_finish_dfs(num_courses: i32, prerequisites: Vec<Vec<i32>>) -> bool {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        New,
+        InProgress,
+        Done,
+    }
+    let graph = schedule(num_courses, &prerequisites);
+    let mut state = vec![State::New; graph.len()];
+    for start in 0..graph.len() {
+        if state[start] != State::New {
+            continue;
+        }
+        // Each frame is a course and how many of its edges have been followed
+        let mut stack = vec![(start, 0)];
+        state[start] = State::InProgress;
+        while let Some((node, followed)) = stack.last_mut() {
+            let node = *node;
+            match graph.adjacency[node].get(*followed) {
+                Some(&next) => {
+                    *followed += 1;
+                    match state[next] {
+                        State::InProgress => return false,
+                        State::New => {
+                            state[next] = State::InProgress;
+                            stack.push((next, 0));
+                        }
+                        State::Done => {}
+                    }
+                }
+                None => {
+                    state[node] = State::Done;
+                    stack.pop();
+                }
+            }
+        }
+    }
+    true
+}
+
+pub struct CourseSchedule;
+
+impl Problem for CourseSchedule {
+    type Input = (i32, Vec<Vec<i32>>);
+    type Output = bool;
+
+    fn id(&self) -> u32 {
+        207
+    }
+
+    fn name(&self) -> &'static str {
+        "course_schedule"
+    }
+
+    fn title(&self) -> &'static str {
+        "Course Schedule"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Medium
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["depth-first-search", "breadth-first-search", "graph", "topological-sort"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            ((2, vec![vec![1, 0]]), true),
+            ((2, vec![vec![1, 0], vec![0, 1]]), false),
+            ((1, vec![]), true),
+            ((4, vec![vec![1, 0], vec![2, 0], vec![3, 1], vec![3, 2]]), true), // Two paths to 3 are not a cycle
+            ((4, vec![vec![1, 0], vec![2, 1], vec![3, 2], vec![1, 3]]), false),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        let (num_courses, prerequisites) = case;
+        can_finish(num_courses, prerequisites)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![("dfs", |(num_courses, prerequisites)| can_finish_dfs(num_courses, prerequisites))]
+    }
+
+    fn draw(&self, case: &Self::Input, prefix: &str) -> Option<String> {
+        let (num_courses, prerequisites) = case;
+        Some(schedule(*num_courses, prerequisites).dot_statements(prefix))
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // Every prerequisite is a lower-numbered course, so all can be
+        // finished and both solutions have to look at every edge
+        let n = size.max(2);
+        let mut random = pseudo_random(14).map(|x| x as usize);
+        let prerequisites = (0..2 * n)
+            .map(|_| {
+                let course = 1 + random.next().unwrap() % (n - 1);
+                vec![course as i32, (random.next().unwrap() % course) as i32]
+            })
+            .collect();
+        Some((n as i32, prerequisites))
+    }
+}
+
+register_problem!(CourseSchedule);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solutions_agree_on_random_schedules() {
+        for seed in 0..50 {
+            let mut random = pseudo_random(seed).map(|x| (x % 8) as i32);
+            let prerequisites: Vec<Vec<i32>> = (0..8).map(|_| vec![random.next().unwrap(), random.next().unwrap()]).collect();
+            assert_eq!(can_finish(8, prerequisites.clone()), can_finish_dfs(8, prerequisites.clone()), "{:?}", prerequisites);
+        }
+    }
+}
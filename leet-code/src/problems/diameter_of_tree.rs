@@ -0,0 +1,175 @@
+use std::rc::Rc;
+use std::cell::RefCell;
+use crate::{Difficulty, Problem, Variant};
+use crate::ds::tree;
+use crate::ds::{ToDot, Tree, TreeNode};
+
+pub fn diameter_of_binary_tree(root: Option<Rc<RefCell<TreeNode>>>) -> i32 {
+    let mut res = 0;
+
+    fn dfs(node: Option<Rc<RefCell<TreeNode>>>, res: &mut i32) -> i32 {
+        if let Some(n) = node {
+            let n = n.borrow();
+            let left = dfs(n.left.clone(), res);
+            let right = dfs(n.right.clone(), res);
+            *res = (*res).max(left + right);
+            return 1 + left.max(right);
+        }
+        0
+    }
+
+    dfs(root, &mut res);
+    res
+}
+
+// The same post-order as the recursive dfs, driven by an explicit stack:
+// a node's second visit (`children_done`) finds its children's heights on
+// top of `heights`.
+pub fn diameter_of_binary_tree_iterative(root: Option<Rc<RefCell<TreeNode>>>) -> i32 {
+    let mut res = 0;
+    let mut stack = vec![(root, false)];
+    let mut heights: Vec<i32> = Vec::new();
+    while let Some((node, children_done)) = stack.pop() {
+        let Some(node) = node else {
+            heights.push(0);
+            continue;
+        };
+        if children_done {
+            let right = heights.pop().unwrap_or(0);
+            let left = heights.pop().unwrap_or(0);
+            res = res.max(left + right);
+            heights.push(1 + left.max(right));
+        } else {
+            let (left, right) = {
+                let borrowed = node.borrow();
+                (borrowed.left.clone(), borrowed.right.clone())
+            };
+            stack.push((Some(node), true));
+            stack.push((right, false));
+            stack.push((left, false));
+        }
+    }
+    res
+}
+
+// The recursive dfs, borrowing each node for the walk instead of cloning the
+// child handles.
+pub fn diameter_of_binary_tree_borrowed(root: &Tree) -> i32 {
+    fn height(node: &Tree, res: &mut i32) -> i32 {
+        let Some(node) = node else {
+            return 0;
+        };
+        let node = node.borrow();
+        let left = height(&node.left, res);
+        let right = height(&node.right, res);
+        *res = (*res).max(left + right);
+        1 + left.max(right)
+    }
+
+    let mut res = 0;
+    height(root, &mut res);
+    res
+}
+
+pub struct DiameterOfTree;
+
+impl Problem for DiameterOfTree {
+    type Input = Vec<Option<i32>>;
+    type Output = i32;
+
+    fn id(&self) -> u32 {
+        543
+    }
+
+    fn name(&self) -> &'static str {
+        "diameter_of_tree"
+    }
+
+    fn title(&self) -> &'static str {
+        "Diameter of Binary Tree"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["tree", "depth-first-search", "binary-tree"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (vec![Some(1), Some(2), Some(3), Some(4), Some(5)], 3),
+            (vec![Some(1), Some(2)], 1),
+            (vec![Some(1)], 0),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        diameter_of_binary_tree(TreeNode::from_level_order(&case))
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![
+            ("iterative", |case| diameter_of_binary_tree_iterative(TreeNode::from_level_order(&case))),
+            ("borrowed", |case| diameter_of_binary_tree_borrowed(&TreeNode::from_level_order(&case))),
+        ]
+    }
+
+    fn describe(&self, case: &Self::Input) -> String {
+        tree::format(&TreeNode::from_level_order(case))
+    }
+
+    fn detail(&self, case: &Self::Input) -> Option<String> {
+        TreeNode::from_level_order(case).map(|root| tree::render_tree(&root.borrow()))
+    }
+
+    fn draw(&self, case: &Self::Input, prefix: &str) -> Option<String> {
+        let root = TreeNode::from_level_order(case);
+        Some(root.map_or_else(String::new, |root| root.borrow().dot_statements(prefix)))
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // A complete tree, shallow enough for the recursive solutions
+        Some((0..size as i32).map(Some).collect())
+    }
+}
+
+register_problem!(DiameterOfTree);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    // 64 KiB would not hold a recursive walk down a 100_000-node spine
+    #[test]
+    fn iterative_handles_a_deep_spine_on_a_small_stack() {
+        let result = thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(|| {
+                let spine = tree::left_spine(100_000);
+                let result = diameter_of_binary_tree_iterative(spine.clone());
+                tree::drop_iteratively(spine);
+                result
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!(result, 99_999);
+    }
+
+    #[test]
+    fn borrowed_agrees_with_the_cloning_versions() {
+        let mut cases: Vec<_> = DiameterOfTree.examples().into_iter().map(|(case, _)| case).collect();
+        cases.push(DiameterOfTree.generate(1000).unwrap());
+        cases.push(vec![Some(1), Some(2), None, Some(3), Some(4), Some(5), None, None, Some(6)]);
+        for case in cases {
+            let root = TreeNode::from_level_order(&case);
+            assert_eq!(diameter_of_binary_tree_borrowed(&root), diameter_of_binary_tree(root.clone()), "{:?}", case);
+        }
+        let spine = tree::left_spine(1000);
+        assert_eq!(diameter_of_binary_tree_borrowed(&spine), 999);
+        tree::drop_iteratively(spine);
+    }
+}
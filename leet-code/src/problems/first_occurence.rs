@@ -0,0 +1,251 @@
+use crate::{Difficulty, Problem, Variant};
+
+pub fn str_str(haystack: String, needle: String) -> i32 {
+    let mut current_index: i32 = -1;
+    if needle.is_empty() {
+        return 0;
+    }
+    if haystack.is_empty() || haystack.len() < needle.len() {
+        return -1;
+    }
+
+    for i in 0..haystack.len() {
+        if let Some(h) = haystack.chars().nth(i) {
+            if let Some(n) = needle.chars().next() {
+                if h == n {
+                    current_index = i as i32;
+                    for j in 0..needle.len() {
+                        if let Some(h2) = haystack.chars().nth(i + j) {
+                            if let Some(n2) = needle.chars().nth(j) {
+                                if h2 != n2 {
+                                    current_index = -1;
+                                    break;
+                                }
+                            }
+                        } else {
+                            current_index = -1;
+                            break;
+                        }
+                    }
+                    if current_index != -1 {
+                        break
+                    }
+                }
+            }
+        }
+    }
+    current_index
+}
+
+pub fn str_str_v2(haystack: String, needle: String) -> i32 {
+    if needle.is_empty() {
+        return 0;
+    }
+
+    let hay = haystack.as_bytes();
+    let nee = needle.as_bytes();
+    let h_len = hay.len();
+    let n_len = nee.len();
+
+    if n_len > h_len {
+        return -1;
+    }
+
+    for i in 0..=h_len - n_len {
+        if &hay[i..i + n_len] == nee {
+            return i as i32;
+        }
+    }
+
+    -1
+}
+
+// Knuth-Morris-Pratt: on a mismatch the needle slides along by what it
+// already knows about its own prefixes, so no haystack byte is read twice.
+// O(haystack + needle).
+pub fn str_str_kmp(haystack: String, needle: String) -> i32 {
+    let hay = haystack.as_bytes();
+    let nee = needle.as_bytes();
+    if nee.is_empty() {
+        return 0;
+    }
+
+    // border[i]: length of the longest proper prefix of nee[..=i] that is
+    // also a suffix of it
+    let mut border = vec![0; nee.len()];
+    let mut k = 0;
+    for i in 1..nee.len() {
+        while k > 0 && nee[i] != nee[k] {
+            k = border[k - 1];
+        }
+        if nee[i] == nee[k] {
+            k += 1;
+        }
+        border[i] = k;
+    }
+
+    let mut matched = 0;
+    for (i, &byte) in hay.iter().enumerate() {
+        while matched > 0 && byte != nee[matched] {
+            matched = border[matched - 1];
+        }
+        if byte == nee[matched] {
+            matched += 1;
+        }
+        if matched == nee.len() {
+            return (i + 1 - nee.len()) as i32;
+        }
+    }
+    -1
+}
+
+const RK_BASE: u64 = 256;
+const RK_MODULUS: u64 = 1_000_000_007;
+
+// Rabin-Karp: compares a rolling hash of each window with the needle's and
+// only compares bytes when the hashes agree. Expected O(haystack + needle);
+// quadratic only if nearly every window collides.
+pub fn str_str_rabin_karp(haystack: String, needle: String) -> i32 {
+    let hay = haystack.as_bytes();
+    let nee = needle.as_bytes();
+    let n_len = nee.len();
+    if n_len == 0 {
+        return 0;
+    }
+    if n_len > hay.len() {
+        return -1;
+    }
+
+    let hash = |bytes: &[u8]| bytes.iter().fold(0, |h, &b| (h * RK_BASE + b as u64) % RK_MODULUS);
+    // Weight of the byte leaving the window: RK_BASE^(n_len - 1)
+    let leading = (1..n_len).fold(1, |w, _| w * RK_BASE % RK_MODULUS);
+    let target = hash(nee);
+    let mut window = hash(&hay[..n_len]);
+
+    for i in 0..=hay.len() - n_len {
+        if window == target && &hay[i..i + n_len] == nee {
+            return i as i32;
+        }
+        if i + n_len < hay.len() {
+            window = (window + RK_MODULUS - hay[i] as u64 * leading % RK_MODULUS) % RK_MODULUS;
+            window = (window * RK_BASE + hay[i + n_len] as u64) % RK_MODULUS;
+        }
+    }
+    -1
+}
+
+pub struct FirstOccurrence;
+
+impl Problem for FirstOccurrence {
+    type Input = (String, String);
+    type Output = i32;
+
+    fn id(&self) -> u32 {
+        28
+    }
+
+    fn name(&self) -> &'static str {
+        "first_occurence"
+    }
+
+    fn title(&self) -> &'static str {
+        "Find the Index of the First Occurrence in a String"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["two-pointers", "string", "string-matching"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (("hello".to_string(), "ll".to_string()), 2),
+            (("aaaaa".to_string(), "bba".to_string()), -1),
+            (("mississippi".to_string(), "issipi".to_string()), -1),
+            (("abc".to_string(), "c".to_string()), 2),
+            (("abcde".to_string(), "f".to_string()), -1),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        let (haystack, needle) = case;
+        str_str(haystack, needle)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![
+            ("v2", |(haystack, needle)| str_str_v2(haystack, needle)),
+            ("kmp", |(haystack, needle)| str_str_kmp(haystack, needle)),
+            ("rabin-karp", |(haystack, needle)| str_str_rabin_karp(haystack, needle)),
+        ]
+    }
+
+    fn describe(&self, case: &Self::Input) -> String {
+        let (haystack, needle) = case;
+        format!("{:?} in {:?}", needle, haystack)
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // "ab" repeated with the needle only at the very end
+        Some(("ab".repeat(size / 2) + "abc", "abc".to_string()))
+    }
+}
+
+register_problem!(FirstOccurrence);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Solution = fn(String, String) -> i32;
+
+    const SOLUTIONS: [(&str, Solution); 4] = [
+        ("str_str", str_str),
+        ("str_str_v2", str_str_v2),
+        ("str_str_kmp", str_str_kmp),
+        ("str_str_rabin_karp", str_str_rabin_karp),
+    ];
+
+    fn check(haystack: &str, needle: &str, expected: i32) {
+        for (name, solution) in SOLUTIONS {
+            let got = solution(haystack.to_string(), needle.to_string());
+            assert_eq!(got, expected, "{}({:?}, {:?})", name, haystack, needle);
+        }
+    }
+
+    #[test]
+    fn all_solutions_agree_on_examples() {
+        for ((haystack, needle), expected) in FirstOccurrence.examples() {
+            check(&haystack, &needle, expected);
+        }
+    }
+
+    #[test]
+    fn all_solutions_handle_empty_and_whole_strings() {
+        check("", "", 0);
+        check("abc", "", 0);
+        check("", "a", -1);
+        check("abc", "abc", 0);
+        check("ab", "abc", -1);
+    }
+
+    #[test]
+    fn all_solutions_recover_from_partial_matches() {
+        check("aaaaab", "aab", 3);
+        check("abababc", "ababc", 2);
+        check("aabaaabaaac", "aabaaac", 4);
+        check("abcabd", "abd", 3);
+    }
+
+    #[test]
+    fn linear_solutions_handle_a_pathological_haystack() {
+        let haystack = "a".repeat(100_000) + "b";
+        let needle = "a".repeat(1_000) + "b";
+        for solution in [str_str_kmp, str_str_rabin_karp] {
+            assert_eq!(solution(haystack.clone(), needle.clone()), 99_000);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+// Given an array of strings strs, group all anagrams together into sublists. You may return the output in any order.
+// An anagram is a string that contains the exact same characters as another string, but the order of the characters can be different.
+// Example 1:
+// Input: strs = ["act","pots","tops","cat","stop","hat"]
+// Output: [["hat"],["act", "cat"],["stop", "pots", "tops"]]
+// Example 2:
+// Input: strs = ["x"]
+// Output: [["x"]]
+// Example 3:
+// Input: strs = [""]
+// Output: [[""]]
+// Constraints:
+// 1 <= strs.length <= 1000.
+// 0 <= strs[i].length <= 100
+// strs[i] is made up of lowercase English letters.
+
+use std::collections::HashMap;
+use crate::{Difficulty, Problem, Variant};
+use crate::complexity::pseudo_random;
+
+pub fn group_anagrams(strs: Vec<String>) -> Vec<Vec<String>> {
+    let mut map: HashMap<[i32; 26], Vec<String>> = HashMap::new();
+
+    for s in strs {
+        let mut count = [0; 26];
+        for c in s.chars() {
+            count[c as usize - 'a' as usize] += 1;
+        }
+        map.entry(count).or_insert(vec![]).push(s);
+    }
+
+    map.into_values().collect()
+}
+
+pub fn group_anagrams_sorted(strs: Vec<String>) -> Vec<Vec<String>> {
+    let mut map: HashMap<Vec<u8>, Vec<String>> = HashMap::new();
+
+    for s in strs {
+        let mut key = s.as_bytes().to_vec();
+        key.sort_unstable();
+        map.entry(key).or_default().push(s);
+    }
+
+    map.into_values().collect()
+}
+
+/// Groups come back in any order, and so do the words in them: sorts both,
+/// so that two groupings compare equal when they group the same words.
+pub fn normalize(mut groups: Vec<Vec<String>>) -> Vec<Vec<String>> {
+    for group in &mut groups {
+        group.sort();
+    }
+    groups.sort();
+    groups
+}
+
+pub struct GroupAnagrams;
+
+impl Problem for GroupAnagrams {
+    type Input = Vec<String>;
+    type Output = Vec<Vec<String>>;
+
+    fn id(&self) -> u32 {
+        49
+    }
+
+    fn name(&self) -> &'static str {
+        "group_anagram"
+    }
+
+    fn title(&self) -> &'static str {
+        "Group Anagrams"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Medium
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["array", "hash-table", "string", "sorting"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (words(&["act", "pots", "tops", "cat", "stop", "hat"]), groups(&[&["act", "cat"], &["hat"], &["pots", "stop", "tops"]])),
+            (words(&["x"]), groups(&[&["x"]])),
+            (words(&[""]), groups(&[&[""]])),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        normalize(group_anagrams(case))
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![("sorted", |strs| normalize(group_anagrams_sorted(strs)))]
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // Five-letter words over "abcde", so many of them are anagrams
+        let mut letters = pseudo_random(3).map(|x| (b'a' + (x % 5) as u8) as char);
+        Some((0..size).map(|_| letters.by_ref().take(5).collect()).collect())
+    }
+}
+
+register_problem!(GroupAnagrams);
+
+fn words(words: &[&str]) -> Vec<String> {
+    words.iter().map(|s| s.to_string()).collect()
+}
+
+fn groups(groups: &[&[&str]]) -> Vec<Vec<String>> {
+    groups.iter().map(|group| words(group)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_ignores_order() {
+        let a = groups(&[&["tops", "pots"], &["hat"], &["cat", "act"]]);
+        let b = groups(&[&["act", "cat"], &["pots", "tops"], &["hat"]]);
+        assert_eq!(normalize(a), normalize(b.clone()));
+        assert_eq!(normalize(b), groups(&[&["act", "cat"], &["hat"], &["pots", "tops"]]));
+    }
+
+    #[test]
+    fn sorted_keys_group_like_counts() {
+        let mut cases: Vec<_> = GroupAnagrams.examples().into_iter().map(|(case, _)| case).collect();
+        cases.extend([vec![], words(&["ab", "ba", "aab", "aba", "abb", "", ""]), GroupAnagrams.generate(500).unwrap()]);
+        for case in cases {
+            assert_eq!(
+                normalize(group_anagrams_sorted(case.clone())),
+                normalize(group_anagrams(case.clone())),
+                "{:?}",
+                case
+            );
+        }
+    }
+
+    #[test]
+    fn repeated_words_stay_in_one_group() {
+        assert_eq!(normalize(group_anagrams_sorted(words(&["ab", "ab", "ba"]))), groups(&[&["ab", "ab", "ba"]]));
+    }
+}
@@ -0,0 +1,114 @@
+// A trie (prefix tree) with insert, search and startsWith. LeetCode calls
+// the methods in order: ["Trie", "insert", "search"] with arguments
+// [[], ["apple"], ["apple"]] gives [null, null, true].
+
+use crate::ds::trie::{Dictionary, HashTrie, Trie};
+use crate::{Difficulty, Problem, Variant};
+use crate::complexity::pseudo_random;
+
+/// Runs LeetCode's calls on `D`: `null` for the constructor and insert,
+/// the answer for search and startsWith.
+pub fn replay<D: Dictionary + Default>(commands: &[String], args: &[Vec<String>]) -> Vec<Option<bool>> {
+    let mut trie = D::default();
+    let word = |i: usize| args[i].first().map_or("", String::as_str);
+    commands
+        .iter()
+        .enumerate()
+        .map(|(i, command)| match command.as_str() {
+            "Trie" => {
+                trie = D::default();
+                None
+            }
+            "insert" => {
+                trie.insert(word(i));
+                None
+            }
+            "search" => Some(trie.search(word(i))),
+            "startsWith" => Some(trie.starts_with(word(i))),
+            _ => panic!("unknown Trie command {:?}", command),
+        })
+        .collect()
+}
+
+pub struct ImplementTrie;
+
+impl Problem for ImplementTrie {
+    type Input = (Vec<String>, Vec<Vec<String>>);
+    type Output = Vec<Option<bool>>;
+
+    fn id(&self) -> u32 {
+        208
+    }
+
+    fn name(&self) -> &'static str {
+        "implement_trie"
+    }
+
+    fn title(&self) -> &'static str {
+        "Implement Trie (Prefix Tree)"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Medium
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["hash-table", "string", "design", "trie"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (
+                calls(&[
+                    ("Trie", ""),
+                    ("insert", "apple"),
+                    ("search", "apple"),
+                    ("search", "app"),
+                    ("startsWith", "app"),
+                    ("insert", "app"),
+                    ("search", "app"),
+                ]),
+                vec![None, None, Some(true), Some(false), Some(true), None, Some(true)],
+            ),
+            (
+                calls(&[("Trie", ""), ("startsWith", "a"), ("insert", "ab"), ("search", "a"), ("startsWith", "a")]),
+                vec![None, Some(false), None, Some(false), Some(true)],
+            ),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        let (commands, args) = case;
+        replay::<Trie>(&commands, &args)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![("hash-map", |(commands, args)| replay::<HashTrie>(&commands, &args))]
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // Inserts of 8-letter words, then as many searches and prefix checks
+        let mut letters = pseudo_random(8).map(|x| (b'a' + (x % 26) as u8) as char);
+        let mut words: Vec<String> = (0..size / 2).map(|_| letters.by_ref().take(8).collect()).collect();
+        let mut commands = vec!["Trie".to_string()];
+        let mut args = vec![vec![]];
+        for (i, word) in words.drain(..).enumerate() {
+            commands.push("insert".to_string());
+            args.push(vec![word.clone()]);
+            commands.push(if i % 2 == 0 { "search" } else { "startsWith" }.to_string());
+            args.push(vec![word[..4 + i % 5].to_string()]);
+        }
+        Some((commands, args))
+    }
+}
+
+register_problem!(ImplementTrie);
+
+/// LeetCode's two arrays from (command, argument) pairs; an empty argument
+/// means the call takes none.
+pub fn calls(calls: &[(&str, &str)]) -> (Vec<String>, Vec<Vec<String>>) {
+    calls
+        .iter()
+        .map(|&(command, arg)| (command.to_string(), if arg.is_empty() { vec![] } else { vec![arg.to_string()] }))
+        .unzip()
+}
@@ -0,0 +1,119 @@
+// Given two strings s and t, return true if the two strings are anagrams of each other, otherwise return false.
+// An anagram is a string that contains the exact same characters as another string, but the order of the charaters can be different.
+// Example 1:
+// Input: s = "racecar", t = "carrace"
+// Output: true
+// Example 2:
+// Input: s = "jar", t = "jam"
+// Output: false
+// Constraints:
+// s and t consist of lowercase English letters.
+use std::collections::HashMap;
+use crate::{Difficulty, Problem, Variant};
+use crate::complexity::pseudo_random;
+pub fn is_anagram(s: String, t: String) -> bool {
+    if s.len() != t.len() {
+        return false;
+    }
+    let mut s_map = HashMap::new();
+    let mut t_map = HashMap::new();
+    for a in s.chars() {
+        *s_map.entry(a).or_insert(0) += 1;
+    }
+    for a in t.chars() {
+        *t_map.entry(a).or_insert(0) += 1;
+    }
+    for (key, value) in s_map {
+        if t_map.get(&key) != Some(&value) {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn is_anagram_v2(s: String, t: String) -> bool {
+    if s.len() != t.len() {
+        return false;
+    }
+    let mut char_counts = HashMap::new();
+    for char_s in s.chars() {
+        *char_counts.entry(char_s).or_insert(0) += 1;
+    }
+    for char_t in t.chars() {
+        let count = char_counts.entry(char_t).or_default();
+        if *count == 0 {
+            return false;
+        }
+        *count -= 1;
+    }
+    true
+}
+
+pub struct ValidAnagram;
+
+impl Problem for ValidAnagram {
+    type Input = (String, String);
+    type Output = bool;
+
+    fn id(&self) -> u32 {
+        242
+    }
+
+    fn name(&self) -> &'static str {
+        "is_anagram"
+    }
+
+    fn title(&self) -> &'static str {
+        "Valid Anagram"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["hash-table", "string", "sorting"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (("racecar".to_string(), "carrace".to_string()), true),
+            (("jar".to_string(), "jam".to_string()), false),
+            (("anagram".to_string(), "nagaram".to_string()), true),
+            (("rat".to_string(), "car".to_string()), false),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        let (s, t) = case;
+        is_anagram(s, t)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![("v2", |case| {
+            let (s, t) = case;
+            is_anagram_v2(s, t)
+        })]
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        let s: String = pseudo_random(13).take(size).map(|x| (b'a' + (x % 26) as u8) as char).collect();
+        let t = s.chars().rev().collect();
+        Some((s, t))
+    }
+}
+
+register_problem!(ValidAnagram);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_anagram_v2_agrees_on_examples() {
+        for (case, expected) in ValidAnagram.examples() {
+            let (s, t) = &case;
+            assert_eq!(is_anagram_v2(s.clone(), t.clone()), expected, "{:?}", case);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{Difficulty, Problem, Variant};
+use crate::complexity::pseudo_random;
+
+// Three readings of "letters and digits, ignoring case", which agree on
+// ASCII and differ beyond it:
+// • is_palindrome:         chars. Combining marks are not alphanumeric, so
+//                          the accent in a decomposed "é" (e + U+0301) is
+//                          dropped and it reads as "e"
+// • is_palindrome_v2:      bytes. Anything non-ASCII is skipped, so "ñ" and
+//                          "é" do not count at all
+// • is_palindrome_unicode: grapheme clusters, what a reader sees as one
+//                          character, marks included. Not normalized: a
+//                          precomposed "é" and a decomposed one differ
+
+pub fn is_palindrome(s: String) -> bool {
+    let s_cleared = s
+        .chars()
+        .filter(|ch| ch.is_alphanumeric())
+        .flat_map(|ch| ch.to_lowercase())
+        .collect::<String>();
+    let s_rev: String = s_cleared.chars().rev().collect();
+    s_cleared == s_rev
+}
+
+pub fn is_palindrome_v2(s: String) -> bool {
+    let bytes = s.as_bytes();
+    let mut l = 0;
+    let mut r = bytes.len().saturating_sub(1);
+
+    while l < r {
+        while l < r && !is_alphanumeric(bytes[l]) {
+            l += 1;
+        }
+        while r > l && !is_alphanumeric(bytes[r]) {
+            r -= 1;
+        }
+
+        if l < r && to_lower(bytes[l]) != to_lower(bytes[r]) {
+            return false;
+        }
+
+        l += 1;
+        r = r.saturating_sub(1);
+    }
+
+    true
+}
+
+// Keeps the graphemes that start with a letter or digit, lowercased, and
+// compares them with their reverse: a cluster is reversed as a whole, so its
+// marks stay on their letter.
+pub fn is_palindrome_unicode(s: String) -> bool {
+    let letters: Vec<String> = s
+        .graphemes(true)
+        .filter(|grapheme| grapheme.chars().next().is_some_and(char::is_alphanumeric))
+        .map(str::to_lowercase)
+        .collect();
+    letters.iter().eq(letters.iter().rev())
+}
+
+fn is_alphanumeric(c: u8) -> bool {
+    c.is_ascii_uppercase() ||
+    c.is_ascii_lowercase() ||
+    c.is_ascii_digit()
+}
+
+fn to_lower(c: u8) -> u8 {
+    if c.is_ascii_uppercase() {
+        c + 32
+    } else {
+        c
+    }
+}
+
+
+pub struct ValidPalindrome;
+
+impl Problem for ValidPalindrome {
+    type Input = String;
+    type Output = bool;
+
+    fn id(&self) -> u32 {
+        125
+    }
+
+    fn name(&self) -> &'static str {
+        "is_palindrome"
+    }
+
+    fn title(&self) -> &'static str {
+        "Valid Palindrome"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["two-pointers", "string"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            ("A man, a plan, a canal: Panama".to_string(), true),
+            ("race a car".to_string(), false),
+            (" ".to_string(), true),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        is_palindrome(case)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![("v2", is_palindrome_v2), ("unicode", is_palindrome_unicode)]
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // A palindrome, so neither solution can stop early
+        let half: String = pseudo_random(11).take(size / 2).map(|x| (b'a' + (x % 26) as u8) as char).collect();
+        let mirrored: String = half.chars().rev().collect();
+        Some(half + &mirrored)
+    }
+}
+
+register_problem!(ValidPalindrome);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn is_palindrome_v2_agrees_on_examples() {
+        for (case, expected) in ValidPalindrome.examples() {
+            assert_eq!(is_palindrome_v2(case.clone()), expected, "{:?}", case);
+        }
+    }
+
+    proptest! {
+        // Any printable ASCII, where the three must agree
+        #[test]
+        fn all_three_agree_on_ascii(s in "[ -~]{0,64}") {
+            let expected = is_palindrome(s.clone());
+            prop_assert_eq!(is_palindrome_v2(s.clone()), expected);
+            prop_assert_eq!(is_palindrome_unicode(s), expected);
+        }
+
+        // Random strings are almost never palindromes, so also mirror some,
+        // with the case of the mirrored letters flipped
+        #[test]
+        fn all_three_accept_mirrored_ascii(half in "[ -~]{0,32}") {
+            let mirrored: String = half.chars().rev().map(|c| if c.is_ascii_lowercase() { c.to_ascii_uppercase() } else { c.to_ascii_lowercase() }).collect();
+            let s = half + &mirrored;
+            prop_assert!(is_palindrome(s.clone()));
+            prop_assert!(is_palindrome_v2(s.clone()));
+            prop_assert!(is_palindrome_unicode(s));
+        }
+    }
+
+    // (input, is_palindrome, is_palindrome_v2, is_palindrome_unicode)
+    const NON_ASCII: [(&str, bool, bool, bool); 6] = [
+        // Letters outside ASCII: v2 does not see them
+        ("ña", false, true, false),
+        ("Ésé", true, true, true),
+        ("Ésè", false, true, false),
+        // e + combining acute, then e: the chars version drops the accent
+        ("e\u{301}e", true, true, false),
+        ("e\u{301}xe\u{301}", true, true, true),
+        // Precomposed é against a decomposed one: not normalized, so unequal
+        ("\u{e9}e\u{301}", false, true, false),
+    ];
+
+    #[test]
+    fn non_ascii_differences_are_as_documented() {
+        for (input, chars, bytes, graphemes) in NON_ASCII {
+            let s = input.to_string();
+            assert_eq!(
+                (is_palindrome(s.clone()), is_palindrome_v2(s.clone()), is_palindrome_unicode(s)),
+                (chars, bytes, graphemes),
+                "{:?}",
+                input
+            );
+        }
+    }
+}
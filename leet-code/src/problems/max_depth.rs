@@ -0,0 +1,120 @@
+use std::{cell::RefCell, rc::Rc};
+use std::cmp::max;
+use crate::{Difficulty, Problem, Variant};
+use crate::ds::tree;
+use crate::ds::{ToDot, TreeNode};
+
+pub fn max_depth(root: Option<Rc<RefCell<TreeNode>>>) -> i32 {
+    if root.is_none() {
+        return 0;
+    }
+    let root = root.unwrap();
+    let depth = 1;
+    let left_depth = max_depth(root.borrow().left.clone());
+    let right_depth = max_depth(root.borrow().right.clone());
+    depth + max(left_depth, right_depth)
+}
+
+// Depth-first with an explicit stack of (node, depth), so a deep tree costs
+// heap instead of call stack.
+pub fn max_depth_iterative(root: Option<Rc<RefCell<TreeNode>>>) -> i32 {
+    let mut deepest = 0;
+    let mut stack: Vec<(Rc<RefCell<TreeNode>>, i32)> = root.into_iter().map(|node| (node, 1)).collect();
+    while let Some((node, depth)) = stack.pop() {
+        deepest = deepest.max(depth);
+        let node = node.borrow();
+        for child in [&node.left, &node.right].into_iter().flatten() {
+            stack.push((Rc::clone(child), depth + 1));
+        }
+    }
+    deepest
+}
+
+pub struct MaxDepth;
+
+impl Problem for MaxDepth {
+    type Input = Vec<Option<i32>>;
+    type Output = i32;
+
+    fn id(&self) -> u32 {
+        104
+    }
+
+    fn name(&self) -> &'static str {
+        "max_depth"
+    }
+
+    fn title(&self) -> &'static str {
+        "Maximum Depth of Binary Tree"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["tree", "depth-first-search", "breadth-first-search", "binary-tree"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (vec![Some(1), Some(2), Some(3)], 2),
+            (vec![Some(3), Some(9), Some(20), None, None, Some(15), Some(7)], 3),
+            (vec![], 0),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        max_depth(TreeNode::from_level_order(&case))
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![
+            ("iterative", |case| max_depth_iterative(TreeNode::from_level_order(&case))),
+            ("level-order", |case| tree::level_order(&TreeNode::from_level_order(&case)).len() as i32),
+        ]
+    }
+
+    fn describe(&self, case: &Self::Input) -> String {
+        tree::format(&TreeNode::from_level_order(case))
+    }
+
+    fn detail(&self, case: &Self::Input) -> Option<String> {
+        TreeNode::from_level_order(case).map(|root| tree::render_tree(&root.borrow()))
+    }
+
+    fn draw(&self, case: &Self::Input, prefix: &str) -> Option<String> {
+        let root = TreeNode::from_level_order(case);
+        Some(root.map_or_else(String::new, |root| root.borrow().dot_statements(prefix)))
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // A complete tree, shallow enough for the recursive solutions
+        Some((0..size as i32).map(Some).collect())
+    }
+}
+
+register_problem!(MaxDepth);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    // 64 KiB would not hold a recursive walk down a 100_000-node spine
+    #[test]
+    fn iterative_handles_a_deep_spine_on_a_small_stack() {
+        let result = thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(|| {
+                let spine = tree::left_spine(100_000);
+                let result = max_depth_iterative(spine.clone());
+                tree::drop_iteratively(spine);
+                result
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!(result, 100_000);
+    }
+}
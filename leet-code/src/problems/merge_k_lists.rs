@@ -0,0 +1,167 @@
+// You are given an array of k linked-lists lists, each linked-list is
+// sorted in ascending order. Merge all the linked-lists into one sorted
+// linked-list and return it.
+// Example 1:
+// Input: lists = [[1,4,5],[1,3,4],[2,6]]
+// Output: [1,1,2,3,4,4,5,6]
+// Example 2:
+// Input: lists = []
+// Output: []
+// Constraints:
+// 0 <= k <= 10^4
+// 0 <= lists[i].length <= 500
+// lists[i] is sorted in ascending order.
+// The sum of lists[i].length will not exceed 10^4.
+
+use crate::ds::list::{self, to_vec};
+use crate::ds::{ListNode, MinHeap, ToDot};
+use crate::problems::merge_two_list::merge_two_lists;
+use crate::{Difficulty, Problem, Variant};
+
+// The heap holds the head of every list that has nodes left, as its value
+// and the list's index, so the smallest head is always on top: N nodes in
+// all cost O(N log k).
+pub fn merge_k_lists(lists: Vec<Option<Box<ListNode>>>) -> Option<Box<ListNode>> {
+    let mut lists = lists;
+    let mut heads: MinHeap<(i32, usize)> =
+        lists.iter().enumerate().filter_map(|(i, list)| Some((list.as_ref()?.val, i))).collect();
+
+    let mut dummy = Box::new(ListNode::new(0));
+    let mut tail = &mut dummy;
+    while let Some((_, i)) = heads.pop() {
+        let mut node = lists[i].take().unwrap();
+        lists[i] = node.next.take();
+        if let Some(next) = &lists[i] {
+            heads.push((next.val, i));
+        }
+        tail = tail.next.insert(node);
+    }
+    dummy.next.take()
+}
+
+// Merges neighbouring lists in rounds, halving their number each time, so
+// every node goes through log k merges: O(N log k) without a heap.
+pub fn merge_k_lists_divide(lists: Vec<Option<Box<ListNode>>>) -> Option<Box<ListNode>> {
+    let mut lists = lists;
+    while lists.len() > 1 {
+        let mut merged = Vec::with_capacity(lists.len().div_ceil(2));
+        let mut pairs = lists.into_iter();
+        while let Some(first) = pairs.next() {
+            merged.push(merge_two_lists(first, pairs.next().flatten()));
+        }
+        lists = merged;
+    }
+    lists.pop().flatten()
+}
+
+// Merges each list into the result so far. The result is walked again for
+// every list, so the first lists' nodes are merged up to k times: O(N k).
+pub fn merge_k_lists_pairwise(lists: Vec<Option<Box<ListNode>>>) -> Option<Box<ListNode>> {
+    lists.into_iter().fold(None, merge_two_lists)
+}
+
+fn to_lists(case: Vec<Vec<i32>>) -> Vec<Option<Box<ListNode>>> {
+    case.into_iter().map(ListNode::from_vec).collect()
+}
+
+pub struct MergeKLists;
+
+impl Problem for MergeKLists {
+    type Input = Vec<Vec<i32>>;
+    type Output = Vec<i32>;
+
+    fn id(&self) -> u32 {
+        23
+    }
+
+    fn name(&self) -> &'static str {
+        "merge_k_lists"
+    }
+
+    fn title(&self) -> &'static str {
+        "Merge k Sorted Lists"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Hard
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["linked-list", "divide-and-conquer", "heap-priority-queue", "merge-sort"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (vec![vec![1, 4, 5], vec![1, 3, 4], vec![2, 6]], vec![1, 1, 2, 3, 4, 4, 5, 6]),
+            (vec![], vec![]),
+            (vec![vec![]], vec![]),
+            (vec![vec![], vec![-2, 0], vec![], vec![-3], vec![0, 7]], vec![-3, -2, 0, 0, 7]),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        to_vec(&merge_k_lists(to_lists(case)))
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![
+            ("divide-and-conquer", |case| to_vec(&merge_k_lists_divide(to_lists(case)))),
+            ("pairwise", |case| to_vec(&merge_k_lists_pairwise(to_lists(case)))),
+        ]
+    }
+
+    fn describe(&self, case: &Self::Input) -> String {
+        let lists: Vec<String> = to_lists(case.clone()).iter().map(list::format).collect();
+        format!("[{}]", lists.join(", "))
+    }
+
+    fn draw(&self, case: &Self::Input, prefix: &str) -> Option<String> {
+        let lists = to_lists(case.clone());
+        let drawn = lists.iter().enumerate().filter_map(|(i, list)| Some(list.as_ref()?.dot_statements(&format!("{}l{}n", prefix, i))));
+        Some(drawn.collect())
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // About √size lists of interleaved values, so every merge alternates
+        // between its lists and k grows with the input
+        let k = ((size as f64).sqrt() as usize).max(1);
+        Some((0..k).map(|j| (0..size / k).map(|i| (i * k + j) as i32).collect()).collect())
+    }
+}
+
+register_problem!(MergeKLists);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complexity::pseudo_random;
+
+    #[test]
+    fn solutions_agree_on_random_lists() {
+        for seed in 0..50 {
+            let mut random = pseudo_random(seed).map(|x| (x % 20) as i32 - 10);
+            let k = seed as usize % 7;
+            let case: Vec<Vec<i32>> = (0..k)
+                .map(|j| {
+                    let mut values: Vec<i32> = random.by_ref().take(j * 3 % 5).collect();
+                    values.sort_unstable();
+                    values
+                })
+                .collect();
+            let mut expected = case.concat();
+            expected.sort_unstable();
+            for merge in [merge_k_lists, merge_k_lists_divide, merge_k_lists_pairwise] {
+                assert_eq!(to_vec(&merge(to_lists(case.clone()))), expected, "{:?}", case);
+            }
+        }
+    }
+
+    #[test]
+    fn reuses_the_nodes() {
+        let lists = to_lists(vec![vec![2, 9], vec![4]]);
+        let first = lists[0].as_deref().unwrap() as *const ListNode;
+        let merged = merge_k_lists(lists).unwrap();
+        assert!(std::ptr::eq(&*merged, first));
+        assert_eq!(merged.to_vec(), vec![2, 4, 9]);
+    }
+}
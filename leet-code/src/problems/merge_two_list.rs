@@ -0,0 +1,123 @@
+use crate::{Difficulty, Problem};
+use crate::ds::list::{self, to_vec};
+use crate::ds::{ListNode, ToDot};
+
+pub fn merge_two_lists(
+    list1: Option<Box<ListNode>>,
+    list2: Option<Box<ListNode>>,
+) -> Option<Box<ListNode>> {
+    let mut pointer1 = list1;
+    let mut pointer2 = list2;
+
+    let mut dummy = Box::new(ListNode { val: 0, next: None });
+    let mut tail = &mut dummy;
+
+    while pointer1.is_some() || pointer2.is_some() {
+        match (pointer1.take(), pointer2.take()) {
+            (Some(mut p1), Some(mut p2)) => {
+                if p1.val < p2.val {
+                    pointer1 = p1.next.take();
+                    pointer2 = Some(p2);
+                    tail.next = Some(p1);
+                } else {
+                    pointer2 = p2.next.take();
+                    pointer1 = Some(p1);
+                    tail.next = Some(p2);
+                }
+                tail = tail.next.as_mut().unwrap();
+            }
+            (Some(mut p1), None) => {
+                pointer1 = p1.next.take();
+                tail.next = Some(p1);
+                tail = tail.next.as_mut().unwrap();
+            }
+            (None, Some(mut p2)) => {
+                pointer2 = p2.next.take();
+                tail.next = Some(p2);
+                tail = tail.next.as_mut().unwrap();
+            }
+            (None, None) => break,
+        }
+    }
+
+    dummy.next.take()
+}
+
+
+pub struct MergeTwoLists;
+
+impl Problem for MergeTwoLists {
+    type Input = (Vec<i32>, Vec<i32>);
+    type Output = Vec<i32>;
+
+    fn id(&self) -> u32 {
+        21
+    }
+
+    fn name(&self) -> &'static str {
+        "merge_two_list"
+    }
+
+    fn title(&self) -> &'static str {
+        "Merge Two Sorted Lists"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["linked-list", "recursion"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            ((vec![1, 2, 4], vec![1, 3, 4]), vec![1, 1, 2, 3, 4, 4]),
+            ((vec![], vec![]), vec![]),
+            ((vec![], vec![0]), vec![0]),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        let (list1, list2) = case;
+        to_vec(&merge_two_lists(ListNode::from_vec(list1), ListNode::from_vec(list2)))
+    }
+
+    fn describe(&self, case: &Self::Input) -> String {
+        let (list1, list2) = case;
+        let (list1, list2) = (ListNode::from_vec(list1.clone()), ListNode::from_vec(list2.clone()));
+        format!("{} and {}", list::format(&list1), list::format(&list2))
+    }
+
+    fn draw(&self, case: &Self::Input, prefix: &str) -> Option<String> {
+        let (list1, list2) = case;
+        let draw = |values: &Vec<i32>, prefix: String| {
+            ListNode::from_vec(values.clone()).map_or_else(String::new, |head| head.dot_statements(&prefix))
+        };
+        Some(draw(list1, format!("{}a", prefix)) + &draw(list2, format!("{}b", prefix)))
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // Interleaved, so the merge alternates between the lists
+        Some(((0..size as i32 / 2).map(|i| 2 * i).collect(), (0..size as i32 / 2).map(|i| 2 * i + 1).collect()))
+    }
+}
+
+register_problem!(MergeTwoLists);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_tail_of_the_longer_list() {
+        let merged = merge_two_lists(ListNode::from_vec(vec![1, 5, 6, 7]), ListNode::from_vec(vec![2]));
+        assert_eq!(to_vec(&merged), vec![1, 2, 5, 6, 7]);
+    }
+
+    #[test]
+    fn keeps_duplicates_and_negatives() {
+        let merged = merge_two_lists(ListNode::from_vec(vec![-1, 3]), ListNode::from_vec(vec![-1, 3])).unwrap();
+        assert_eq!(merged.iter().copied().collect::<Vec<_>>(), vec![-1, -1, 3, 3]);
+    }
+}
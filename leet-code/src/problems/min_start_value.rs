@@ -0,0 +1,118 @@
+use crate::{Difficulty, Problem, Variant};
+use crate::complexity::pseudo_random;
+
+// The start value has to lift the lowest prefix sum to at least 1, and
+// never go below 1 itself. One pass: scan keeps the running sum, in i64 so
+// a long run of large values cannot overflow, and fold its minimum. An
+// answer beyond i32 saturates at i32::MAX.
+pub fn min_start_value(nums: impl Iterator<Item = i32>) -> i32 {
+    let lowest = nums
+        .scan(0i64, |sum, n| {
+            *sum += i64::from(n);
+            Some(*sum)
+        })
+        .fold(0, i64::min);
+    i32::try_from(1 - lowest).unwrap_or(i32::MAX)
+}
+
+// The first version, for comparison: every prefix sum is kept in a Vec.
+pub fn min_start_value_vec(nums: Vec<i32>) -> i32 {
+    if nums.is_empty() {
+        return 1;
+    }
+    let mut new_vec: Vec<i32> = vec![];
+    let mut smallest_value = 0;
+    for (i,n) in nums.iter().enumerate() {
+        if i == 0 {
+            new_vec.push(*n);
+        } else {
+            new_vec.push(new_vec[i - 1] + n);
+        }
+        if new_vec[i] < smallest_value {
+            smallest_value = new_vec[i];
+        }
+    }
+    smallest_value.abs() + 1
+}
+
+pub struct MinStartValue;
+
+impl Problem for MinStartValue {
+    type Input = Vec<i32>;
+    type Output = i32;
+
+    fn id(&self) -> u32 {
+        1413
+    }
+
+    fn name(&self) -> &'static str {
+        "min_start_value"
+    }
+
+    fn title(&self) -> &'static str {
+        "Minimum Value to Get Positive Step by Step Sum"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["array", "prefix-sum"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (vec![1, 2, -3], 1),
+            (vec![1, 2, -5, 3], 3),
+            (vec![-1, -2, -3], 7),
+            (vec![-3, 2, -3, 4, 2], 5),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        min_start_value(case.into_iter())
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![("vec", min_start_value_vec)]
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        Some(pseudo_random(17).take(size).map(|x| (x % 201) as i32 - 100).collect())
+    }
+}
+
+register_problem!(MinStartValue);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        // Within LeetCode's limits (up to 100 values in -100..=100) the
+        // prefix sums fit easily, so the two versions must agree
+        #[test]
+        fn scan_agrees_with_the_vec_version(nums in prop::collection::vec(-100..=100, 0..=100)) {
+            prop_assert_eq!(min_start_value(nums.iter().copied()), min_start_value_vec(nums));
+        }
+    }
+
+    #[test]
+    fn empty_input_needs_a_start_of_one() {
+        assert_eq!(min_start_value(std::iter::empty()), 1);
+        assert_eq!(min_start_value_vec(vec![]), 1);
+    }
+
+    #[test]
+    fn large_values_do_not_overflow() {
+        // The running sum climbs to almost 2^32 before coming down to -7, where
+        // an i32 sum would have overflowed
+        let nums = [i32::MAX, i32::MAX, i32::MIN, i32::MIN, -5];
+        assert_eq!(min_start_value(nums.into_iter()), 8);
+        // A start above i32::MAX saturates
+        assert_eq!(min_start_value([i32::MIN].into_iter()), i32::MAX);
+        assert_eq!(min_start_value([i32::MIN, i32::MIN].into_iter()), i32::MAX);
+    }
+}
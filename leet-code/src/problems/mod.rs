@@ -0,0 +1,60 @@
+// Every problem registers itself next to its code with `register_problem!`,
+// so a new problem only needs its `pub mod` line here. The entries are
+// gathered by the linker (see `inventory`); `all()` sorts them by id.
+
+use std::sync::OnceLock;
+
+use crate::Runnable;
+
+// For `register_problem!` in crates that do not depend on inventory themselves
+#[doc(hidden)]
+pub use inventory;
+
+/// Adds a problem to [`all`]: `register_problem!(TwoSum);` in the module
+/// that defines `TwoSum`.
+#[macro_export]
+macro_rules! register_problem {
+    ($problem:expr) => {
+        $crate::problems::inventory::submit! { $crate::problems::Registration(&$problem) }
+    };
+}
+
+pub mod binary_search;
+pub mod climbing_stairs;
+pub mod contains_duplicate;
+pub mod count_components;
+pub mod course_schedule;
+pub mod diameter_of_tree;
+pub mod first_occurence;
+pub mod group_anagram;
+pub mod implement_trie;
+pub mod is_anagram;
+pub mod is_palindrome;
+pub mod max_depth;
+pub mod merge_k_lists;
+pub mod merge_two_list;
+pub mod min_start_value;
+pub mod number_of_islands;
+pub mod redundant_connection;
+pub mod reverse_linked_list;
+pub mod sell_stock;
+pub mod tree_balanced;
+pub mod two_sum;
+pub mod unique_paths;
+pub mod valid_parentheses;
+pub mod word_dictionary;
+
+pub struct Registration(pub &'static dyn Runnable);
+
+inventory::collect!(Registration);
+
+/// Every registered problem, in LeetCode order.
+pub fn all() -> &'static [&'static dyn Runnable] {
+    static ALL: OnceLock<Vec<&'static dyn Runnable>> = OnceLock::new();
+
+    ALL.get_or_init(|| {
+        let mut problems: Vec<_> = inventory::iter::<Registration>.into_iter().map(|registration| registration.0).collect();
+        problems.sort_by_key(|problem| problem.id());
+        problems
+    })
+}
@@ -0,0 +1,131 @@
+// Given an m x n grid of '1's (land) and '0's (water), return the number of
+// islands. An island is land connected horizontally or vertically, and is
+// surrounded by water; everything outside the grid is water.
+// Example 1:
+// Input: grid = [
+//   ["1","1","1","1","0"],
+//   ["1","1","0","1","0"],
+//   ["1","1","0","0","0"],
+//   ["0","0","0","0","0"]
+// ]
+// Output: 1
+// Example 2:
+// Input: grid = [
+//   ["1","1","0","0","0"],
+//   ["1","1","0","0","0"],
+//   ["0","0","1","0","0"],
+//   ["0","0","0","1","1"]
+// ]
+// Output: 3
+// Constraints:
+// 1 <= m, n <= 300
+// grid[i][j] is '0' or '1'.
+
+use crate::complexity::pseudo_random;
+use crate::ds::graph::Visit;
+use crate::ds::{DisjointSet, Graph};
+use crate::{Difficulty, Problem, Variant};
+
+// Neighbouring land cells are joined; water cells are left on their own
+fn land(grid: &[Vec<char>]) -> Graph {
+    Graph::grid(grid, |a, b| *a == '1' && *b == '1')
+}
+
+// Where each traversal starts a new tree, from a land cell: one per island
+fn count_trees(grid: &[Vec<char>], starts: impl Iterator<Item = Visit>) -> i32 {
+    let width = grid.first().map_or(0, Vec::len);
+    starts.filter(|visit| visit.parent.is_none() && grid[visit.node / width][visit.node % width] == '1').count() as i32
+}
+
+// A depth-first search from every cell not reached yet.
+pub fn num_islands(grid: Vec<Vec<char>>) -> i32 {
+    let graph = land(&grid);
+    count_trees(&grid, graph.dfs(0..graph.len()))
+}
+
+pub fn num_islands_bfs(grid: Vec<Vec<char>>) -> i32 {
+    let graph = land(&grid);
+    count_trees(&grid, graph.bfs(0..graph.len()))
+}
+
+// Every land cell starts as an island; joining it to land on its right or
+// below merges two of them, unless they were already one.
+pub fn num_islands_union_find(grid: Vec<Vec<char>>) -> i32 {
+    let width = grid.first().map_or(0, Vec::len);
+    let mut sets = DisjointSet::new(grid.len() * width);
+    let mut islands = 0;
+    for (r, row) in grid.iter().enumerate() {
+        for (c, &cell) in row.iter().enumerate() {
+            if cell != '1' {
+                continue;
+            }
+            islands += 1;
+            let node = r * width + c;
+            if c + 1 < width && row[c + 1] == '1' && sets.union(node, node + 1) {
+                islands -= 1;
+            }
+            if r + 1 < grid.len() && grid[r + 1][c] == '1' && sets.union(node, node + width) {
+                islands -= 1;
+            }
+        }
+    }
+    islands
+}
+
+pub struct NumberOfIslands;
+
+impl Problem for NumberOfIslands {
+    type Input = Vec<Vec<char>>;
+    type Output = i32;
+
+    fn id(&self) -> u32 {
+        200
+    }
+
+    fn name(&self) -> &'static str {
+        "number_of_islands"
+    }
+
+    fn title(&self) -> &'static str {
+        "Number of Islands"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Medium
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["array", "depth-first-search", "breadth-first-search", "union-find", "matrix"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        let grid = |rows: &[&str]| rows.iter().map(|row| row.chars().collect()).collect();
+        vec![
+            (grid(&["11110", "11010", "11000", "00000"]), 1),
+            (grid(&["11000", "11000", "00100", "00011"]), 3),
+            (grid(&["0"]), 0),
+            (grid(&["101", "010", "101"]), 5), // Diagonals do not connect
+            (grid(&["111", "101", "111"]), 1),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        num_islands(case)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![("bfs", num_islands_bfs), ("union-find", num_islands_union_find)]
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // A square of about `size` cells, a bit over half of them land
+        let side = (size as f64).sqrt().ceil().max(1.0) as usize;
+        let mut random = pseudo_random(15);
+        let grid = (0..side)
+            .map(|_| (0..side).map(|_| if random.next().unwrap() % 5 < 3 { '1' } else { '0' }).collect())
+            .collect();
+        Some(grid)
+    }
+}
+
+register_problem!(NumberOfIslands);
@@ -0,0 +1,98 @@
+// A tree of n nodes labelled 1..n had one extra edge added, between two
+// different nodes. Given the n edges, return an edge that can be removed so
+// the rest is a tree of n nodes; if there are several, the one that comes
+// last in the input.
+// Example 1:
+// Input: edges = [[1,2],[1,3],[2,3]]
+// Output: [2,3]
+// Example 2:
+// Input: edges = [[1,2],[2,3],[3,4],[1,4],[1,5]]
+// Output: [1,4]
+// Constraints:
+// n == edges.length
+// 3 <= n <= 1000
+// There are no repeated edges. The given graph is connected.
+
+use crate::complexity::pseudo_random;
+use crate::ds::DisjointSet;
+use crate::{Difficulty, Problem};
+
+// The extra edge is the only one whose ends are already connected when it
+// comes up. Any edge of the cycle could go, but the others all come before
+// it, so it is also the last one that could.
+pub fn find_redundant_connection(edges: Vec<Vec<i32>>) -> Vec<i32> {
+    let mut sets = DisjointSet::new(edges.len() + 1);
+    for edge in edges {
+        if !sets.union(edge[0] as usize, edge[1] as usize) {
+            return edge;
+        }
+    }
+    vec![]
+}
+
+pub struct RedundantConnection;
+
+impl Problem for RedundantConnection {
+    type Input = Vec<Vec<i32>>;
+    type Output = Vec<i32>;
+
+    fn id(&self) -> u32 {
+        684
+    }
+
+    fn name(&self) -> &'static str {
+        "redundant_connection"
+    }
+
+    fn title(&self) -> &'static str {
+        "Redundant Connection"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Medium
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["depth-first-search", "breadth-first-search", "union-find", "graph"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (vec![vec![1, 2], vec![1, 3], vec![2, 3]], vec![2, 3]),
+            (vec![vec![1, 2], vec![2, 3], vec![3, 4], vec![1, 4], vec![1, 5]], vec![1, 4]),
+            (vec![vec![3, 4], vec![1, 2], vec![2, 4], vec![3, 5], vec![2, 5]], vec![2, 5]),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        find_redundant_connection(case)
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // A random tree (each node hangs off an earlier one) with a last
+        // edge from n back to 1, or to 2 if n already hangs off 1
+        let n = size.max(3) as i32;
+        let mut random = pseudo_random(12);
+        let mut edges: Vec<Vec<i32>> = (2..=n).map(|node| vec![1 + (random.next().unwrap() % (node as u64 - 1)) as i32, node]).collect();
+        let back_to = if edges[n as usize - 2][0] == 1 { 2 } else { 1 };
+        edges.push(vec![back_to, n]);
+        Some(edges)
+    }
+}
+
+register_problem!(RedundantConnection);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_cases_have_one_extra_edge() {
+        for size in [3, 4, 10, 1000] {
+            let edges = RedundantConnection.generate(size).unwrap();
+            assert_eq!(edges.len(), size);
+            let redundant = find_redundant_connection(edges.clone());
+            assert_eq!(redundant, *edges.last().unwrap(), "{:?}", edges);
+        }
+    }
+}
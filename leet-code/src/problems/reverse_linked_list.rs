@@ -0,0 +1,108 @@
+use crate::{Difficulty, Problem, Variant};
+use crate::ds::list::{self, to_vec};
+use crate::ds::{ListNode, ToDot};
+
+pub fn reverse_list(head: Option<Box<ListNode>>) -> Option<Box<ListNode>> {
+    let mut new_head: Option<Box<ListNode>> = None;
+    let mut head = head.clone();
+    while head.is_some() {
+        new_head = Some(Box::new(ListNode {
+            val: head.as_ref().unwrap().val,
+            next: new_head,
+        }));
+        head = head.unwrap().next.take();
+    }
+    new_head
+}
+
+// Re-links the existing nodes instead of copying them: each node is taken off
+// the front of `head` and pushed onto the front of the reversed list.
+pub fn reverse_list_in_place(head: Option<Box<ListNode>>) -> Option<Box<ListNode>> {
+    let mut head = head;
+    let mut reversed = None;
+    while let Some(mut node) = head {
+        head = node.next.take();
+        node.next = reversed;
+        reversed = Some(node);
+    }
+    reversed
+}
+
+pub struct ReverseLinkedList;
+
+impl Problem for ReverseLinkedList {
+    type Input = Vec<i32>;
+    type Output = Vec<i32>;
+
+    fn id(&self) -> u32 {
+        206
+    }
+
+    fn name(&self) -> &'static str {
+        "reverse_linked_list"
+    }
+
+    fn title(&self) -> &'static str {
+        "Reverse Linked List"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["linked-list", "recursion"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (vec![1, 2, 3], vec![3, 2, 1]),
+            (vec![1, 2], vec![2, 1]),
+            (vec![], vec![]),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        to_vec(&reverse_list(ListNode::from_vec(case)))
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![("in-place", |case| to_vec(&reverse_list_in_place(ListNode::from_vec(case))))]
+    }
+
+    fn describe(&self, case: &Self::Input) -> String {
+        list::format(&ListNode::from_vec(case.clone()))
+    }
+
+    fn draw(&self, case: &Self::Input, prefix: &str) -> Option<String> {
+        Some(ListNode::from_vec(case.clone()).map_or_else(String::new, |head| head.dot_statements(prefix)))
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        Some((0..size as i32).collect())
+    }
+}
+
+register_problem!(ReverseLinkedList);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reversing_twice_restores_the_list() {
+        let values: Vec<i32> = (1..=10).collect();
+        let twice = reverse_list(reverse_list(ListNode::from_vec(values.clone()))).unwrap();
+        assert_eq!(twice.to_vec(), values);
+    }
+
+    #[test]
+    fn in_place_reuses_the_nodes() {
+        let head = ListNode::from_vec(vec![1, 2, 3]);
+        let last: *const ListNode = head.as_ref().unwrap().next.as_ref().unwrap().next.as_deref().unwrap();
+        let reversed = reverse_list_in_place(head).unwrap();
+        assert!(std::ptr::eq(&*reversed, last));
+        assert_eq!(reversed.to_vec(), vec![3, 2, 1]);
+        assert_eq!(reverse_list_in_place(None), None);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Difficulty, Problem, Variant};
+use crate::complexity::pseudo_random;
+
+pub fn max_profit(prices: Vec<i32>) -> i32 {
+    let mut min_price = i32::MAX;
+    let mut max_profit = 0;
+
+    for price in prices {
+        if price < min_price {
+            min_price = price;
+        } else {
+            let profit = price - min_price;
+            if profit > max_profit {
+                max_profit = profit;
+            }
+        }
+    }
+
+    max_profit
+}
+
+pub fn max_profit_v2(prices: Vec<i32>) -> i32 {
+    let mut l = 0;
+    let mut r = 1;
+    let mut max = 0;
+    while r < prices.len() {
+        if prices[l] < prices[r] {
+            let profit = prices[r] - prices[l];
+            if profit > max {
+                max = profit;
+            }
+        } else {
+            l = r;
+        }
+        r += 1;
+    }
+    max
+}
+
+/// max_profit over prices as they arrive, without collecting them first.
+pub fn max_profit_streaming(prices: impl Iterator<Item = i32>) -> i32 {
+    best_profits(prices).last().unwrap_or(0)
+}
+
+/// The best profit so far after each price: the n-th item is what
+/// max_profit would return for the first n prices.
+pub fn best_profits(prices: impl Iterator<Item = i32>) -> impl Iterator<Item = i32> {
+    let mut min_price = i32::MAX;
+    let mut best = 0;
+    prices.map(move |price| {
+        min_price = min_price.min(price);
+        best = best.max(price - min_price);
+        best
+    })
+}
+
+pub struct SellStock;
+
+impl Problem for SellStock {
+    type Input = Vec<i32>;
+    type Output = i32;
+
+    fn id(&self) -> u32 {
+        121
+    }
+
+    fn name(&self) -> &'static str {
+        "sell_stock"
+    }
+
+    fn title(&self) -> &'static str {
+        "Best Time to Buy and Sell Stock"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["array", "dynamic-programming"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (vec![7, 1, 5, 3, 6, 4], 5),
+            (vec![7, 6, 4, 3, 1], 0),
+            (vec![1], 0),
+            (vec![1, 2, 3, 4, 5], 4),
+            (vec![5, 5, 5, 5], 0),
+            (vec![1, 2, 3, 4, 5, 6], 5),
+            (vec![6, 5, 4, 3, 2, 1], 0),
+            (vec![1000, 2000, 3000, 4000, 5000], 4000),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        max_profit(case)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![
+            ("v2", max_profit_v2),
+            ("streaming", |prices: Vec<i32>| max_profit_streaming(prices.into_iter())),
+        ]
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        Some(pseudo_random(7).take(size).map(|x| (x % 10_000) as i32).collect())
+    }
+}
+
+register_problem!(SellStock);
+
+#[cfg(test)]
+mod tests {
+    use super::*;   
+    #[test]
+    fn test_max_profit_basic() {
+        assert_eq!(max_profit(vec![7, 1, 5, 3, 6, 4]), 5);
+    }   
+    #[test]
+    fn test_max_profit_no_profit() {
+        assert_eq!(max_profit(vec![7, 6, 4, 3, 1]), 0);
+    }
+    #[test]
+    fn test_max_profit_single_day() {
+        assert_eq!(max_profit(vec![1]), 0);
+    }
+    #[test]
+    fn test_max_profit_multiple_days() {
+        assert_eq!(max_profit(vec![1, 2, 3, 4, 5]), 4);
+    }
+    #[test]
+    fn test_max_profit_same_price() {
+        assert_eq!(max_profit(vec![5, 5, 5, 5]), 0);
+    }
+    #[test]
+    fn test_max_profit_increasing_prices() {
+        assert_eq!(max_profit(vec![1, 2, 3, 4, 5, 6]), 5);
+    }
+    #[test]
+    fn test_max_profit_decreasing_prices() {
+        assert_eq!(max_profit(vec![6, 5, 4, 3, 2, 1]), 0);
+    }
+    #[test]
+    fn test_max_profit_large_numbers() {
+        assert_eq!(max_profit(vec![1000, 2000, 3000, 4000, 5000]), 4000);
+    }
+
+    #[test]
+    fn max_profit_v2_agrees_on_examples() {
+        for (case, expected) in SellStock.examples() {
+            assert_eq!(max_profit_v2(case.clone()), expected, "{:?}", case);
+        }
+    }
+
+    #[test]
+    fn streaming_agrees_with_the_vec_versions() {
+        let mut cases: Vec<_> = SellStock.examples().into_iter().map(|(case, _)| case).collect();
+        cases.extend([vec![], vec![3, 1], SellStock.generate(1000).unwrap()]);
+        for case in cases {
+            let expected = max_profit(case.clone());
+            assert_eq!(max_profit_v2(case.clone()), expected, "{:?}", case);
+            assert_eq!(max_profit_streaming(case.iter().copied()), expected, "{:?}", case);
+        }
+    }
+
+    #[test]
+    fn best_profits_are_max_profit_of_each_prefix() {
+        let prices = SellStock.generate(200).unwrap();
+        let running: Vec<_> = best_profits(prices.iter().copied()).collect();
+        assert_eq!(running.len(), prices.len());
+        for (n, best) in running.iter().enumerate() {
+            assert_eq!(*best, max_profit(prices[..=n].to_vec()), "after {} prices", n + 1);
+        }
+        assert_eq!(best_profits([7, 1, 5, 3, 6, 4].into_iter()).collect::<Vec<_>>(), [0, 0, 4, 4, 5, 5]);
+    }
+
+    #[test]
+    fn best_profits_is_lazy() {
+        // An endless rising price never ends, but each step is available at once
+        let mut running = best_profits((1..).map(|day| day * 2));
+        assert_eq!(running.nth(999), Some(1998));
+    }
+}
@@ -0,0 +1,169 @@
+use std::{cell::RefCell, rc::Rc};
+use crate::{Difficulty, Problem, Variant};
+use crate::ds::tree;
+use crate::ds::{ToDot, Tree, TreeNode};
+
+pub fn is_balanced(root: Option<Rc<RefCell<TreeNode>>>) -> bool {
+    fn dfs(root: Option<Rc<RefCell<TreeNode>>>) -> (i32, bool) {
+        if let Some(root_rc) = root {
+            let (left_height, left_balanced) = dfs(root_rc.borrow().left.clone());
+            let (right_height, right_balanced) = dfs(root_rc.borrow().right.clone());
+            let difference = right_height - left_height;
+            let is_balanced = difference.abs() <= 1 && left_balanced && right_balanced;
+            return (1 + left_height.max(right_height), is_balanced);
+        }
+        (0, true)
+    }
+    let (_, is_balanced) = dfs(root);
+    is_balanced
+}
+
+// Post-order with an explicit stack. A node is pushed twice: once to visit
+// its children, then again (`children_done`) to combine their heights,
+// which by then are the top two entries of `heights`.
+pub fn is_balanced_iterative(root: Option<Rc<RefCell<TreeNode>>>) -> bool {
+    let mut stack = vec![(root, false)];
+    let mut heights: Vec<i32> = Vec::new();
+    while let Some((node, children_done)) = stack.pop() {
+        let Some(node) = node else {
+            heights.push(0);
+            continue;
+        };
+        if children_done {
+            let right = heights.pop().unwrap_or(0);
+            let left = heights.pop().unwrap_or(0);
+            if (left - right).abs() > 1 {
+                return false;
+            }
+            heights.push(1 + left.max(right));
+        } else {
+            let (left, right) = {
+                let borrowed = node.borrow();
+                (borrowed.left.clone(), borrowed.right.clone())
+            };
+            stack.push((Some(node), true));
+            stack.push((right, false));
+            stack.push((left, false));
+        }
+    }
+    true
+}
+
+// Walks the tree through `&` borrows of each node instead of cloning the
+// child handles, and stops at the first unbalanced subtree (`None`).
+pub fn is_balanced_borrowed(root: &Tree) -> bool {
+    fn height(node: &Tree) -> Option<i32> {
+        let Some(node) = node else {
+            return Some(0);
+        };
+        let node = node.borrow();
+        let left = height(&node.left)?;
+        let right = height(&node.right)?;
+        ((left - right).abs() <= 1).then_some(1 + left.max(right))
+    }
+    height(root).is_some()
+}
+
+pub struct BalancedTree;
+
+impl Problem for BalancedTree {
+    type Input = Vec<Option<i32>>;
+    type Output = bool;
+
+    fn id(&self) -> u32 {
+        110
+    }
+
+    fn name(&self) -> &'static str {
+        "tree_balanced"
+    }
+
+    fn title(&self) -> &'static str {
+        "Balanced Binary Tree"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["tree", "depth-first-search", "binary-tree"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (vec![Some(1), Some(2), Some(4), Some(8)], true),
+            // Unbalanced tree
+            (vec![Some(1), Some(2), Some(4), Some(3), None, None, None, Some(3)], false),
+            (vec![], true),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        is_balanced(TreeNode::from_level_order(&case))
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![
+            ("iterative", |case| is_balanced_iterative(TreeNode::from_level_order(&case))),
+            ("borrowed", |case| is_balanced_borrowed(&TreeNode::from_level_order(&case))),
+        ]
+    }
+
+    fn describe(&self, case: &Self::Input) -> String {
+        tree::format(&TreeNode::from_level_order(case))
+    }
+
+    fn detail(&self, case: &Self::Input) -> Option<String> {
+        TreeNode::from_level_order(case).map(|root| tree::render_tree(&root.borrow()))
+    }
+
+    fn draw(&self, case: &Self::Input, prefix: &str) -> Option<String> {
+        let root = TreeNode::from_level_order(case);
+        Some(root.map_or_else(String::new, |root| root.borrow().dot_statements(prefix)))
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // A complete tree, shallow enough for the recursive solutions
+        Some((0..size as i32).map(Some).collect())
+    }
+}
+
+register_problem!(BalancedTree);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    // 64 KiB would not hold a recursive walk down a 100_000-node spine
+    #[test]
+    fn iterative_handles_a_deep_spine_on_a_small_stack() {
+        let result = thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(|| {
+                let spine = tree::left_spine(100_000);
+                let result = is_balanced_iterative(spine.clone());
+                tree::drop_iteratively(spine);
+                result
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        assert!(!result);
+    }
+
+    #[test]
+    fn borrowed_agrees_with_the_cloning_versions() {
+        let mut cases: Vec<_> = BalancedTree.examples().into_iter().map(|(case, _)| case).collect();
+        cases.push(BalancedTree.generate(1000).unwrap());
+        cases.push(vec![Some(1), None, Some(2), None, Some(3)]);
+        for case in cases {
+            let root = TreeNode::from_level_order(&case);
+            assert_eq!(is_balanced_borrowed(&root), is_balanced(root.clone()), "{:?}", case);
+        }
+        let spine = tree::left_spine(1000);
+        assert!(!is_balanced_borrowed(&spine));
+        tree::drop_iteratively(spine);
+    }
+}
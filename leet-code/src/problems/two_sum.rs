@@ -0,0 +1,253 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use crate::{Difficulty, Problem, Variant};
+
+pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {
+    let mut map = HashMap::new();
+    
+    for (i, num) in nums.iter().enumerate() {
+        let complement = target - num;
+        
+        if let Some(&index) = map.get(&complement) {
+            return vec![index as i32, i as i32];
+        }
+        
+        map.insert(num, i);
+    }
+    
+    vec![]
+}
+
+// Two pointers closing in from both ends of sorted input (LeetCode 167,
+// with 0-based indices): a sum too small can only grow by moving `l` right.
+pub fn two_sum_sorted(numbers: Vec<i32>, target: i32) -> Vec<i32> {
+    if numbers.is_empty() {
+        return vec![];
+    }
+    let (mut l, mut r) = (0, numbers.len() - 1);
+    while l < r {
+        match (numbers[l] as i64 + numbers[r] as i64).cmp(&(target as i64)) {
+            Ordering::Less => l += 1,
+            Ordering::Greater => r -= 1,
+            Ordering::Equal => return vec![l as i32, r as i32],
+        }
+    }
+    vec![]
+}
+
+// Every pair of indices i < j with nums[i] + nums[j] == target, in order.
+// Each number is paired with all the earlier indices of its complement.
+pub fn two_sum_all_pairs(nums: Vec<i32>, target: i32) -> Vec<Vec<i32>> {
+    let mut seen: HashMap<i64, Vec<i32>> = HashMap::new();
+    let mut pairs = Vec::new();
+
+    for (j, &num) in nums.iter().enumerate() {
+        if let Some(indices) = seen.get(&(target as i64 - num as i64)) {
+            pairs.extend(indices.iter().map(|&i| vec![i, j as i32]));
+        }
+        seen.entry(num as i64).or_default().push(j as i32);
+    }
+
+    pairs.sort();
+    pairs
+}
+
+// The distinct sets of k values (not indices) that add up to target, as in
+// 3Sum (k = 3, target 0) and 4Sum. Sorted, each level fixes one value and
+// recurses on the rest, down to the two-pointer scan of two_sum_sorted.
+pub fn k_sum(mut nums: Vec<i32>, k: usize, target: i64) -> Vec<Vec<i32>> {
+    nums.sort_unstable();
+    let mut found = Vec::new();
+    k_sum_of_sorted(&nums, k, target, &mut Vec::new(), &mut found);
+    found
+}
+
+fn k_sum_of_sorted(nums: &[i32], k: usize, target: i64, prefix: &mut Vec<i32>, found: &mut Vec<Vec<i32>>) {
+    if k == 0 || nums.len() < k {
+        return;
+    }
+    if k == 1 {
+        if nums.binary_search_by(|&x| (x as i64).cmp(&target)).is_ok() {
+            found.push([prefix.as_slice(), &[target as i32]].concat());
+        }
+        return;
+    }
+    if k == 2 {
+        let (mut l, mut r) = (0, nums.len() - 1);
+        while l < r {
+            match (nums[l] as i64 + nums[r] as i64).cmp(&target) {
+                Ordering::Less => l += 1,
+                Ordering::Greater => r -= 1,
+                Ordering::Equal => {
+                    found.push([prefix.as_slice(), &[nums[l], nums[r]]].concat());
+                    // Skip the copies of nums[l] so the same pair is not found again
+                    l += 1;
+                    while l < r && nums[l] == nums[l - 1] {
+                        l += 1;
+                    }
+                    r -= 1;
+                }
+            }
+        }
+        return;
+    }
+    for i in 0..nums.len() {
+        if i > 0 && nums[i] == nums[i - 1] {
+            continue;
+        }
+        prefix.push(nums[i]);
+        k_sum_of_sorted(&nums[i + 1..], k - 1, target - nums[i] as i64, prefix, found);
+        prefix.pop();
+    }
+}
+
+// two_sum_sorted on the indices ordered by value, mapped back to positions
+fn two_sum_by_sorting((nums, target): (Vec<i32>, i32)) -> Vec<i32> {
+    let mut order: Vec<usize> = (0..nums.len()).collect();
+    order.sort_by_key(|&i| nums[i]);
+    let sorted = order.iter().map(|&i| nums[i]).collect();
+    let mut pair: Vec<i32> = two_sum_sorted(sorted, target).iter().map(|&i| order[i as usize] as i32).collect();
+    pair.sort();
+    pair
+}
+
+// k_sum finds the values; the indices are those of their first occurrences,
+// the second taken after skipping the first
+fn two_sum_by_k_sum((nums, target): (Vec<i32>, i32)) -> Vec<i32> {
+    let Some(values) = k_sum(nums.clone(), 2, target as i64).into_iter().next() else {
+        return vec![];
+    };
+    let i = nums.iter().position(|&x| x == values[0]).unwrap();
+    let j = nums.iter().enumerate().position(|(j, &x)| j != i && x == values[1]).unwrap();
+    let mut pair = vec![i as i32, j as i32];
+    pair.sort();
+    pair
+}
+
+pub struct TwoSum;
+
+impl Problem for TwoSum {
+    type Input = (Vec<i32>, i32);
+    type Output = Vec<i32>;
+
+    fn id(&self) -> u32 {
+        1
+    }
+
+    fn name(&self) -> &'static str {
+        "two_sum"
+    }
+
+    fn title(&self) -> &'static str {
+        "Two Sum"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["array", "hash-table"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            ((vec![2, 7, 11, 15], 9), vec![0, 1]),
+            ((vec![3, 2, 4], 6), vec![1, 2]),
+            ((vec![3, 3], 6), vec![0, 1]),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        let (nums, target) = case;
+        two_sum(nums, target)
+    }
+
+    // LeetCode promises exactly one answer, so the first of all pairs is it
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![
+            ("sorted", two_sum_by_sorting),
+            ("all-pairs", |(nums, target)| two_sum_all_pairs(nums, target).into_iter().next().unwrap_or_default()),
+            ("k-sum", two_sum_by_k_sum),
+        ]
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // The pair is the last two numbers, so the whole array is scanned
+        let nums: Vec<i32> = (0..size as i32).collect();
+        Some((nums, 2 * size as i32 - 3))
+    }
+}
+
+register_problem!(TwoSum);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_sum_basic() {
+        assert_eq!(two_sum(vec![2, 7, 11, 15], 9), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_two_sum_different_order() {
+        assert_eq!(two_sum(vec![3, 2, 4], 6), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_two_sum_same_number() {
+        assert_eq!(two_sum(vec![3, 3], 6), vec![0, 1]);
+    }
+
+    #[test]
+    fn sorted_closes_in_from_both_ends() {
+        assert_eq!(two_sum_sorted(vec![2, 7, 11, 15], 9), vec![0, 1]);
+        assert_eq!(two_sum_sorted(vec![-3, -1, 0, 2, 5], 1), vec![1, 3]);
+        assert_eq!(two_sum_sorted(vec![1, 2], 5), Vec::<i32>::new());
+        assert_eq!(two_sum_sorted(vec![], 0), Vec::<i32>::new());
+        assert_eq!(two_sum_sorted(vec![i32::MAX - 1, i32::MAX], -1), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn all_pairs_lists_every_index_pair() {
+        assert_eq!(two_sum_all_pairs(vec![1, 5, 3, 3, 1, 5], 6), [[0, 1], [0, 5], [1, 4], [2, 3], [4, 5]]);
+        assert_eq!(two_sum_all_pairs(vec![2, 2, 2], 4), [[0, 1], [0, 2], [1, 2]]);
+        assert!(two_sum_all_pairs(vec![1, 2, 3], 10).is_empty());
+    }
+
+    #[test]
+    fn k_sum_solves_three_and_four_sum() {
+        // LeetCode 15 and 18
+        assert_eq!(k_sum(vec![-1, 0, 1, 2, -1, -4], 3, 0), [[-1, -1, 2], [-1, 0, 1]]);
+        assert_eq!(k_sum(vec![0, 0, 0, 0], 3, 0), [[0, 0, 0]]);
+        assert_eq!(k_sum(vec![1, 0, -1, 0, -2, 2], 4, 0), [[-2, -1, 1, 2], [-2, 0, 0, 2], [-1, 0, 0, 1]]);
+        assert_eq!(k_sum(vec![2, 2, 2, 2, 2], 4, 8), [[2, 2, 2, 2]]);
+        // Sums past i32 are why the target is an i64
+        assert_eq!(k_sum(vec![1_000_000_000; 4], 4, 4_000_000_000), [[1_000_000_000; 4]]);
+        assert_eq!(k_sum(vec![3, 1, 3], 1, 3), [[3]]);
+        assert!(k_sum(vec![1, 2], 3, 3).is_empty());
+    }
+
+    #[test]
+    fn k_sum_agrees_with_brute_force() {
+        let nums: Vec<i32> = crate::complexity::pseudo_random(9).take(14).map(|x| (x % 11) as i32 - 5).collect();
+        for target in -8..=8 {
+            let mut brute = Vec::new();
+            for a in 0..nums.len() {
+                for b in a + 1..nums.len() {
+                    for c in b + 1..nums.len() {
+                        if nums[a] + nums[b] + nums[c] == target {
+                            let mut triple = vec![nums[a], nums[b], nums[c]];
+                            triple.sort();
+                            brute.push(triple);
+                        }
+                    }
+                }
+            }
+            brute.sort();
+            brute.dedup();
+            assert_eq!(k_sum(nums.clone(), 3, target as i64), brute, "target {}", target);
+        }
+    }
+}
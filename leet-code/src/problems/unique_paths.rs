@@ -0,0 +1,104 @@
+// A robot starts at the top-left corner of an m x n grid and can only move
+// down or right. How many unique paths lead to the bottom-right corner?
+// Example 1:
+// Input: m = 3, n = 7
+// Output: 28
+// Example 2:
+// Input: m = 3, n = 2
+// Output: 3
+// Explanation: right -> down -> down, down -> down -> right, or
+// down -> right -> down.
+// Constraints:
+// 1 <= m, n <= 100
+// The answer is at most 2 * 10^9.
+
+use crate::memoize;
+use crate::{Difficulty, Problem, Variant};
+
+// The last move came from above or from the left. Recursing on both solves
+// each cell once per path through it, C(m + n - 2, m - 1) leaves in all;
+// kept out of the variants, like climb_stairs_naive.
+pub fn unique_paths_naive(m: i32, n: i32) -> i32 {
+    if m == 1 || n == 1 {
+        return 1;
+    }
+    unique_paths_naive(m - 1, n) + unique_paths_naive(m, n - 1)
+}
+
+memoize! {
+    /// The same recursion, each cell computed once: O(m * n).
+    pub fn unique_paths(m: i32, n: i32) -> i32 {
+        if m == 1 || n == 1 {
+            return 1;
+        }
+        unique_paths(m - 1, n) + unique_paths(m, n - 1)
+    }
+}
+
+// Row by row: a cell's count is the one above it, already in the row, plus
+// the one to its left.
+pub fn unique_paths_bottom_up(m: i32, n: i32) -> i32 {
+    let mut row = vec![1; n as usize];
+    for _ in 1..m {
+        for c in 1..row.len() {
+            row[c] += row[c - 1];
+        }
+    }
+    row[n as usize - 1]
+}
+
+pub struct UniquePaths;
+
+impl Problem for UniquePaths {
+    type Input = (i32, i32);
+    type Output = i32;
+
+    fn id(&self) -> u32 {
+        62
+    }
+
+    fn name(&self) -> &'static str {
+        "unique_paths"
+    }
+
+    fn title(&self) -> &'static str {
+        "Unique Paths"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Medium
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["math", "dynamic-programming", "combinatorics", "memoization"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![((3, 7), 28), ((3, 2), 3), ((1, 1), 1), ((1, 100), 1), ((17, 17), 601080390)]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        let (m, n) = case;
+        unique_paths(m, n)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![("bottom-up", |(m, n)| unique_paths_bottom_up(m, n))]
+    }
+}
+
+register_problem!(UniquePaths);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn naive_recursion_agrees() {
+        for m in 1..=8 {
+            for n in 1..=8 {
+                assert_eq!(unique_paths_naive(m, n), unique_paths(m, n), "{}x{}", m, n);
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Difficulty, Problem};
+
+pub fn is_valid(s: String) -> bool {
+    let mut stack = Vec::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '(' => stack.push(')'),
+            '{' => stack.push('}'),
+            '[' => stack.push(']'),
+            ')' | '}' | ']' => {
+                if stack.pop() != Some(ch) {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+    }
+    stack.is_empty()
+}
+
+pub struct ValidParentheses;
+
+impl Problem for ValidParentheses {
+    type Input = String;
+    type Output = bool;
+
+    fn id(&self) -> u32 {
+        20
+    }
+
+    fn name(&self) -> &'static str {
+        "valid_parentheses"
+    }
+
+    fn title(&self) -> &'static str {
+        "Valid Parentheses"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["string", "stack"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            ("()".to_string(), true),
+            ("()[]{}".to_string(), true),
+            ("(]".to_string(), false),
+            ("([)]".to_string(), false),
+            ("{[]}".to_string(), true),
+            ("".to_string(), true), // Edge case: empty string
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        is_valid(case)
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        Some("([{".repeat(size / 6) + &"}])".repeat(size / 6))
+    }
+}
+
+register_problem!(ValidParentheses);
@@ -0,0 +1,106 @@
+// A word set whose search takes patterns, where '.' stands for any letter.
+// Called like LeetCode 208: ["WordDictionary", "addWord", "search"] with
+// [[], ["bad"], [".ad"]] gives [null, null, true].
+
+use crate::ds::trie::{Dictionary, HashTrie, Trie};
+use crate::problems::implement_trie::calls;
+use crate::{Difficulty, Problem, Variant};
+use crate::complexity::pseudo_random;
+
+/// Runs LeetCode's calls on `D`: `null` for the constructor and addWord,
+/// the answer for search.
+pub fn replay<D: Dictionary + Default>(commands: &[String], args: &[Vec<String>]) -> Vec<Option<bool>> {
+    let mut dictionary = D::default();
+    let word = |i: usize| args[i].first().map_or("", String::as_str);
+    commands
+        .iter()
+        .enumerate()
+        .map(|(i, command)| match command.as_str() {
+            "WordDictionary" => {
+                dictionary = D::default();
+                None
+            }
+            "addWord" => {
+                dictionary.insert(word(i));
+                None
+            }
+            "search" => Some(dictionary.matches(word(i))),
+            _ => panic!("unknown WordDictionary command {:?}", command),
+        })
+        .collect()
+}
+
+pub struct WordDictionary;
+
+impl Problem for WordDictionary {
+    type Input = (Vec<String>, Vec<Vec<String>>);
+    type Output = Vec<Option<bool>>;
+
+    fn id(&self) -> u32 {
+        211
+    }
+
+    fn name(&self) -> &'static str {
+        "word_dictionary"
+    }
+
+    fn title(&self) -> &'static str {
+        "Design Add and Search Words Data Structure"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Medium
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["string", "depth-first-search", "design", "trie"]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        vec![
+            (
+                calls(&[
+                    ("WordDictionary", ""),
+                    ("addWord", "bad"),
+                    ("addWord", "dad"),
+                    ("addWord", "mad"),
+                    ("search", "pad"),
+                    ("search", "bad"),
+                    ("search", ".ad"),
+                    ("search", "b.."),
+                ]),
+                vec![None, None, None, None, Some(false), Some(true), Some(true), Some(true)],
+            ),
+            (
+                calls(&[("WordDictionary", ""), ("addWord", "a"), ("search", "."), ("search", "a."), ("search", "..")]),
+                vec![None, None, Some(true), Some(false), Some(false)],
+            ),
+        ]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        let (commands, args) = case;
+        replay::<Trie>(&commands, &args)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input, Self::Output>> {
+        vec![("hash-map", |(commands, args)| replay::<HashTrie>(&commands, &args))]
+    }
+
+    fn generate(&self, size: usize) -> Option<Self::Input> {
+        // Words over "abcd" with every other letter of the searches blanked,
+        // so each search explores several branches
+        let mut letters = pseudo_random(6).map(|x| (b'a' + (x % 4) as u8) as char);
+        let mut commands = vec!["WordDictionary".to_string()];
+        let mut args = vec![vec![]];
+        for i in 0..size / 2 {
+            let word: String = letters.by_ref().take(8).collect();
+            let pattern = word.chars().enumerate().map(|(j, c)| if (i + j) % 2 == 0 { '.' } else { c }).collect();
+            commands.extend(["addWord".to_string(), "search".to_string()]);
+            args.extend([vec![word], vec![pattern]]);
+        }
+        Some((commands, args))
+    }
+}
+
+register_problem!(WordDictionary);
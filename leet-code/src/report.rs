@@ -0,0 +1,204 @@
+//! `leet report`: every solution of each problem side by side, with how
+//! many of the shared cases it passes and how its running time compares
+//! with the problem's other solutions, as Markdown or CSV.
+//!
+//! The cases are the examples and the case files (see [`cases`]); the
+//! timings are the ones [`Runnable::complexity`] takes, so a problem without
+//! a generator only gets the correctness columns. The slow solutions stop
+//! growing their inputs first, so a problem's solutions are compared at the
+//! largest size all of them were timed at.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::cases::{self, CasesError};
+use crate::complexity::Class;
+use crate::Runnable;
+
+/// One solution's line in the report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub id: u32,
+    pub problem: &'static str,
+    /// Which of [`Problem::variants`](crate::Problem::variants) this is,
+    /// `None` for the main solution.
+    pub variant: Option<&'static str>,
+    pub passed: usize,
+    pub total: usize,
+    pub class: Option<Class>,
+    /// The size the problem's solutions are compared at, and this one's
+    /// time there. `None` for problems that are not timed.
+    pub time: Option<(usize, Duration)>,
+    /// `time` over the fastest solution's: 1.0 for the fastest.
+    pub relative: Option<f64>,
+}
+
+impl Row {
+    pub fn solution(&self) -> &'static str {
+        self.variant.unwrap_or("main")
+    }
+
+    pub fn failed(&self) -> bool {
+        self.passed < self.total
+    }
+}
+
+/// Checks and times the main solution and every variant of `problem`, with
+/// the case files under `dir`: one row each, main solution first.
+pub fn rows(problem: &dyn Runnable, dir: &Path) -> Result<Vec<Row>, CasesError> {
+    let mut outcomes = problem.check();
+    for (_, checked) in cases::check(problem, dir)? {
+        outcomes.extend(checked);
+    }
+    let estimates = problem.complexity();
+    let size = estimates.iter().map(|estimate| estimate.samples.last().map_or(0, |&(n, _)| n)).min();
+    let estimate = |variant| estimates.iter().find(|estimate| estimate.variant == variant);
+    let time_at = |variant| {
+        let size = size?;
+        let &(_, time) = estimate(variant)?.samples.iter().find(|&&(n, _)| n == size)?;
+        Some((size, time))
+    };
+
+    let solutions: Vec<Option<&'static str>> =
+        std::iter::once(None).chain(problem.variant_names().into_iter().map(Some)).collect();
+    let fastest = solutions.iter().filter_map(|&variant| time_at(variant)).map(|(_, time)| time).min();
+    Ok(solutions
+        .into_iter()
+        .map(|variant| {
+            let ran: Vec<bool> = outcomes.iter().filter(|outcome| outcome.variant == variant).map(|outcome| outcome.passed).collect();
+            let time = time_at(variant);
+            Row {
+                id: problem.id(),
+                problem: problem.name(),
+                variant,
+                passed: ran.iter().filter(|&&passed| passed).count(),
+                total: ran.len(),
+                class: estimate(variant).and_then(|estimate| estimate.class),
+                time,
+                relative: time
+                    .zip(fastest.filter(|fastest| !fastest.is_zero()))
+                    .map(|((_, time), fastest)| time.as_secs_f64() / fastest.as_secs_f64()),
+            }
+        })
+        .collect())
+}
+
+/// A Markdown table, one line per solution, under a line counting them.
+pub fn markdown(rows: &[Row]) -> String {
+    let mut problems: Vec<u32> = rows.iter().map(|row| row.id).collect();
+    problems.dedup();
+    let failing = rows.iter().filter(|row| row.failed()).count();
+    let mut out = format!(
+        "# Solutions compared\n\n{} solution(s) of {} problem(s), {} failing a case. Times are at the largest size every solution of the problem was timed at.\n\n",
+        rows.len(),
+        problems.len(),
+        failing
+    );
+    out.push_str("| problem | solution | cases | growth | time | relative |\n");
+    out.push_str("|---|---|---|---|---|---|\n");
+    for row in rows {
+        // The problem is named on its first row only
+        let problem = if row.variant.is_none() { format!("{}. {}", row.id, row.problem) } else { String::new() };
+        let mut cases = format!("{}/{}", row.passed, row.total);
+        if row.failed() {
+            let _ = write!(cases, " ({} failing)", row.total - row.passed);
+        }
+        let (growth, time, relative) = match row.time {
+            Some((n, time)) => (
+                row.class.map_or_else(|| "?".to_string(), |class| class.to_string()),
+                format!("{:.2?} at n = {}", time, n),
+                row.relative.map_or_else(|| "-".to_string(), |relative| format!("{:.2}x", relative)),
+            ),
+            None => ("-".to_string(), "-".to_string(), "-".to_string()),
+        };
+        let _ = writeln!(out, "| {} | {} | {} | {} | {} | {} |", problem, row.solution(), cases, growth, time, relative);
+    }
+    out
+}
+
+/// The same columns as CSV, with empty fields where there is no timing and
+/// the time in nanoseconds.
+pub fn csv(rows: &[Row]) -> String {
+    let mut out = String::from("id,problem,solution,passed,total,growth,size,nanos,relative\n");
+    for row in rows {
+        let fields = [
+            row.id.to_string(),
+            row.problem.to_string(),
+            row.solution().to_string(),
+            row.passed.to_string(),
+            row.total.to_string(),
+            row.class.map_or_else(String::new, |class| class.to_string()),
+            row.time.map_or_else(String::new, |(n, _)| n.to_string()),
+            row.time.map_or_else(String::new, |(_, time)| time.as_nanos().to_string()),
+            row.relative.map_or_else(String::new, |relative| format!("{:.3}", relative)),
+        ];
+        let fields: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+// Quoted when it holds a comma, a quote or a line break, as RFC 4180 has it
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find;
+
+    fn row(variant: Option<&'static str>, passed: usize, time: Option<u64>, relative: Option<f64>) -> Row {
+        Row {
+            id: 121,
+            problem: "sell_stock",
+            variant,
+            passed,
+            total: 6,
+            class: time.map(|_| Class::Linear),
+            time: time.map(|nanos| (65536, Duration::from_nanos(nanos))),
+            relative,
+        }
+    }
+
+    #[test]
+    fn rows_cover_every_solution() {
+        let rows = rows(find("sell_stock").unwrap(), Path::new(env!("CARGO_MANIFEST_DIR")).join("cases").as_path()).unwrap();
+        let solutions: Vec<&str> = rows.iter().map(Row::solution).collect();
+        assert_eq!(solutions, ["main", "v2", "streaming"]);
+        assert!(rows.iter().all(|row| row.total > 0 && !row.failed()));
+        // Every solution is timed at the same size, the fastest at 1.0x
+        let sizes: Vec<usize> = rows.iter().map(|row| row.time.unwrap().0).collect();
+        assert!(sizes.iter().all(|&size| size == sizes[0]), "{:?}", sizes);
+        let relative: Vec<f64> = rows.iter().map(|row| row.relative.unwrap()).collect();
+        assert!(relative.contains(&1.0) && relative.iter().all(|&r| r >= 1.0), "{:?}", relative);
+
+        let untimed = super::rows(find("climbing_stairs").unwrap(), Path::new("no-such-dir")).unwrap();
+        assert!(untimed.iter().all(|row| row.time.is_none() && row.relative.is_none()));
+    }
+
+    #[test]
+    fn markdown_names_each_problem_once() {
+        let rows = [row(None, 6, Some(1_500), Some(1.0)), row(Some("v2"), 5, Some(3_000), Some(2.0)), row(Some("memo"), 6, None, None)];
+        let table = markdown(&rows);
+        assert!(table.contains("3 solution(s) of 1 problem(s), 1 failing a case."), "{}", table);
+        assert!(table.contains("| 121. sell_stock | main | 6/6 | O(n) | 1.50µs at n = 65536 | 1.00x |\n"), "{}", table);
+        assert!(table.contains("|  | v2 | 5/6 (1 failing) | O(n) | 3.00µs at n = 65536 | 2.00x |\n"), "{}", table);
+        assert!(table.ends_with("|  | memo | 6/6 | - | - | - |\n"), "{}", table);
+    }
+
+    #[test]
+    fn csv_leaves_missing_timings_empty() {
+        let rows = [row(None, 6, Some(1_500), Some(1.0)), row(Some("memo, bottom-up"), 6, None, None)];
+        let lines: Vec<String> = csv(&rows).lines().map(String::from).collect();
+        assert_eq!(lines[0], "id,problem,solution,passed,total,growth,size,nanos,relative");
+        assert_eq!(lines[1], "121,sell_stock,main,6,6,O(n),65536,1500,1.000");
+        assert_eq!(lines[2], "121,sell_stock,\"memo, bottom-up\",6,6,,,,");
+    }
+}
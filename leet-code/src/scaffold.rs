@@ -0,0 +1,265 @@
+//! The files a new problem starts from, for the `new-problem` binary: a
+//! module with a solution stub and its `Problem` impl, a `pub mod` line in
+//! `src/problems/mod.rs`, and an empty `cases/<problem>.toml`.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::Difficulty;
+
+const MODULE: &str = r#"// {id}. {title}
+// TODO: the statement, examples and constraints from LeetCode.
+
+use crate::{Difficulty, Problem};
+
+// TODO: LeetCode's signature; Input and Output below follow it.
+pub fn {name}(_nums: Vec<i32>) -> i32 {
+    todo!()
+}
+
+pub struct {type_name};
+
+impl Problem for {type_name} {
+    type Input = Vec<i32>;
+    type Output = i32;
+
+    fn id(&self) -> u32 {
+        {id}
+    }
+
+    fn name(&self) -> &'static str {
+        "{name}"
+    }
+
+    fn title(&self) -> &'static str {
+        "{title}"
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::{difficulty:?}
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &[{tags}]
+    }
+
+    fn examples(&self) -> Vec<(Self::Input, Self::Output)> {
+        // TODO: the examples from the statement, as (input, expected)
+        vec![]
+    }
+
+    fn run(&self, case: Self::Input) -> Self::Output {
+        {name}(case)
+    }
+}
+
+register_problem!({type_name});
+"#;
+
+const CASES: &str = r#"# More cases for {name}, in LeetCode's testcase format: one parameter
+# per line of `input`, and `expected` as `leet run` prints it.
+#
+# [[case]]
+# input = """
+# [1,2,3]
+# """
+# expected = "0"
+"#;
+
+/// A problem to create.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewProblem {
+    pub id: u32,
+    pub title: String,
+    /// The module, file and function name.
+    pub name: String,
+    pub difficulty: Difficulty,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The title gives no usable name, and none was passed.
+    Name { title: String },
+    /// Creating the problem would overwrite something.
+    Exists { path: PathBuf },
+    Io { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Name { title } => write!(f, "no module name in {:?}, pass --name", title),
+            ScaffoldError::Exists { path } => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScaffoldError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// `title` in snake case, the way the modules here are named: "Invert
+/// Binary Tree" is `invert_binary_tree`. `None` unless it starts with a
+/// letter, as a Rust identifier has to.
+pub fn module_name(title: &str) -> Option<String> {
+    let words: Vec<String> = title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect();
+    let name = words.join("_");
+    name.starts_with(|c: char| c.is_ascii_lowercase()).then_some(name)
+}
+
+// invert_binary_tree is InvertBinaryTree
+fn type_name(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|first| first.to_ascii_uppercase().to_string() + chars.as_str()).unwrap_or_default()
+        })
+        .collect()
+}
+
+/// `mod_rs` with `pub mod name;` among the other `pub mod` lines, which are
+/// kept in order. `None` if it is already there.
+pub fn add_module(mod_rs: &str, name: &str) -> Option<String> {
+    let line = format!("pub mod {};", name);
+    let mut lines: Vec<&str> = mod_rs.lines().collect();
+    if lines.contains(&line.as_str()) {
+        return None;
+    }
+    let modules = lines.iter().position(|l| l.starts_with("pub mod "));
+    let at = match modules {
+        Some(first) => {
+            let after = lines[first..].iter().position(|l| !l.starts_with("pub mod ") || *l > line.as_str());
+            first + after.unwrap_or(lines.len() - first)
+        }
+        None => lines.len(),
+    };
+    lines.insert(at, &line);
+    Some(lines.join("\n") + "\n")
+}
+
+impl NewProblem {
+    /// A Medium problem named after its title with no tags, or an error if
+    /// the title gives no name.
+    pub fn new(id: u32, title: &str) -> Result<Self, ScaffoldError> {
+        let name = module_name(title).ok_or_else(|| ScaffoldError::Name { title: title.to_string() })?;
+        Ok(NewProblem { id, title: title.to_string(), name, difficulty: Difficulty::Medium, tags: Vec::new() })
+    }
+
+    /// The source of `src/problems/<name>.rs`.
+    pub fn module(&self) -> String {
+        let tags: Vec<String> = self.tags.iter().map(|tag| format!("{:?}", tag)).collect();
+        MODULE
+            .replace("{id}", &self.id.to_string())
+            .replace("{title}", &self.title.replace('"', "\\\""))
+            .replace("{name}", &self.name)
+            .replace("{type_name}", &type_name(&self.name))
+            .replace("{difficulty:?}", &format!("{:?}", self.difficulty))
+            .replace("{tags}", &tags.join(", "))
+    }
+
+    /// The contents of `cases/<name>.toml`: no cases yet, and how to write one.
+    pub fn case_file(&self) -> String {
+        CASES.replace("{name}", &self.name)
+    }
+
+    /// Writes the module and case file under the crate directory `root` and
+    /// adds the module to `src/problems/mod.rs`, returning the paths written.
+    /// Nothing is written if the module or the case file exists already.
+    pub fn create(&self, root: &Path) -> Result<Vec<PathBuf>, ScaffoldError> {
+        let module = root.join("src/problems").join(format!("{}.rs", self.name));
+        let cases = root.join("cases").join(format!("{}.toml", self.name));
+        let mod_rs = root.join("src/problems/mod.rs");
+        for path in [&module, &cases] {
+            if path.exists() {
+                return Err(ScaffoldError::Exists { path: path.clone() });
+            }
+        }
+        let listed = read(&mod_rs)?;
+        let Some(listed) = add_module(&listed, &self.name) else {
+            return Err(ScaffoldError::Exists { path: mod_rs });
+        };
+
+        write(&module, &self.module())?;
+        write(&cases, &self.case_file())?;
+        write(&mod_rs, &listed)?;
+        Ok(vec![module, cases, mod_rs])
+    }
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    std::fs::read_to_string(path).map_err(|error| ScaffoldError::Io { path: path.to_path_buf(), error })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|error| ScaffoldError::Io { path: dir.to_path_buf(), error })?;
+    }
+    std::fs::write(path, contents).map_err(|error| ScaffoldError::Io { path: path.to_path_buf(), error })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_follow_the_title() {
+        assert_eq!(module_name("Invert Binary Tree").as_deref(), Some("invert_binary_tree"));
+        assert_eq!(module_name("Pow(x, n)").as_deref(), Some("pow_x_n"));
+        assert_eq!(module_name("  Two-Sum II ").as_deref(), Some("two_sum_ii"));
+        assert_eq!(module_name("3Sum"), None);
+        assert_eq!(type_name("pow_x_n"), "PowXN");
+    }
+
+    #[test]
+    fn modules_are_added_in_order() {
+        let mod_rs = "// Problems\n\npub mod binary_search;\npub mod two_sum;\n\npub fn all() {}\n";
+        let added = add_module(mod_rs, "max_depth").unwrap();
+        assert_eq!(added, "// Problems\n\npub mod binary_search;\npub mod max_depth;\npub mod two_sum;\n\npub fn all() {}\n");
+        let last = add_module(&added, "word_break").unwrap();
+        assert!(last.contains("pub mod two_sum;\npub mod word_break;\n\n"), "{}", last);
+        assert_eq!(add_module(&added, "max_depth"), None);
+    }
+
+    #[test]
+    fn module_fills_in_the_metadata() {
+        let mut problem = NewProblem::new(226, "Invert Binary Tree").unwrap();
+        problem.difficulty = Difficulty::Easy;
+        problem.tags = vec!["tree".to_string(), "depth-first-search".to_string()];
+        let module = problem.module();
+        assert!(module.starts_with("// 226. Invert Binary Tree\n"));
+        assert!(module.contains("pub fn invert_binary_tree("));
+        assert!(module.contains("impl Problem for InvertBinaryTree {"));
+        assert!(module.contains("Difficulty::Easy"));
+        assert!(module.contains(r#"&["tree", "depth-first-search"]"#));
+        assert!(module.trim_end().ends_with("register_problem!(InvertBinaryTree);"));
+        assert!(!module.contains("{name}") && !module.contains("{id}"));
+    }
+
+    #[test]
+    fn create_writes_the_files_once() {
+        let dir = tempfile::Builder::new().prefix("leet-code-scaffold-").tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("src/problems")).unwrap();
+        std::fs::write(root.join("src/problems/mod.rs"), "pub mod two_sum;\n").unwrap();
+
+        let problem = NewProblem::new(226, "Invert Binary Tree").unwrap();
+        let written = problem.create(root).unwrap();
+        assert_eq!(written.len(), 3);
+        assert_eq!(std::fs::read_to_string(root.join("src/problems/mod.rs")).unwrap(), "pub mod invert_binary_tree;\npub mod two_sum;\n");
+        assert!(std::fs::read_to_string(root.join("cases/invert_binary_tree.toml")).unwrap().contains("[[case]]"));
+        assert!(matches!(problem.create(root), Err(ScaffoldError::Exists { .. })));
+    }
+}
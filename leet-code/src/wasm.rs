@@ -0,0 +1,103 @@
+//! Every registered problem, callable from JavaScript: the exports behind
+//! the playground page in `wasm/index.html`, where a testcase pasted from
+//! LeetCode runs through any solution in the browser.
+//!
+//! Building it:
+//!
+//! ```text
+//! rustup target add wasm32-unknown-unknown
+//! cargo install wasm-bindgen-cli
+//! cargo build -p leet-code --lib --release --target wasm32-unknown-unknown
+//! wasm-bindgen --target web --out-dir leet-code/wasm/pkg \
+//!     target/wasm32-unknown-unknown/release/leet_code.wasm
+//! python3 -m http.server -d leet-code/wasm
+//! ```
+//!
+//! The exports go through the registry rather than one per problem, so a
+//! new problem is in the playground as soon as it is registered. They take
+//! LeetCode's testcase format (see [`parse`](crate::parse)) and return JSON.
+//! Off wasm32 they are plain functions, tested natively.
+
+use serde_json::{json, Value};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{find, problems};
+
+/// Problems register themselves from constructors (see `inventory`), and a
+/// wasm module that is called into many times only runs those when
+/// `__wasm_call_ctors` is called, so the start function calls it once, as
+/// soon as the module is instantiated.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(start)]
+pub fn start() {
+    extern "C" {
+        fn __wasm_call_ctors();
+    }
+    // SAFETY: inventory's constructors only link static registrations and
+    // are safe to run more than once
+    unsafe { __wasm_call_ctors() }
+}
+
+/// `[{"id", "name", "title", "difficulty", "tags"}, ...]`, in LeetCode
+/// order.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn problem_list() -> String {
+    let listed: Vec<Value> = problems::all()
+        .iter()
+        .map(|problem| {
+            json!({
+                "id": problem.id(),
+                "name": problem.name(),
+                "title": problem.title(),
+                "difficulty": problem.difficulty().to_string(),
+                "tags": problem.tags(),
+            })
+        })
+        .collect();
+    Value::Array(listed).to_string()
+}
+
+/// Runs `name`'s solution on `testcases`, one parameter per line as in the
+/// site's testcase box: `{"results": [{"input", "output"}, ...]}`, or
+/// `{"error": "..."}` for an unknown problem or a testcase that does not
+/// parse.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn solve(name: &str, testcases: &str) -> String {
+    let Some(problem) = find(name) else {
+        return json!({ "error": format!("unknown problem '{}'", name) }).to_string();
+    };
+    match problem.solve(testcases) {
+        Ok(solved) => {
+            let results: Vec<Value> = solved.into_iter().map(|(input, output)| json!({ "input": input, "output": output })).collect();
+            json!({ "results": results }).to_string()
+        }
+        Err(err) => json!({ "error": format!("invalid testcase for {}: {}", name, err) }).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_every_problem() {
+        let listed: Vec<Value> = serde_json::from_str(&problem_list()).unwrap();
+        assert_eq!(listed.len(), problems::all().len());
+        assert_eq!(listed[0]["name"], "two_sum");
+        assert_eq!(listed[0]["difficulty"], "Easy");
+        assert_eq!(listed[0]["tags"][0], "array");
+    }
+
+    #[test]
+    fn solves_pasted_testcases() {
+        let solved: Value = serde_json::from_str(&solve("two_sum", "[2,7,11,15]\n9\n[3,3]\n6")).unwrap();
+        assert_eq!(solved["results"].as_array().unwrap().len(), 2);
+        assert_eq!(solved["results"][0]["output"], "[0, 1]");
+
+        let unknown: Value = serde_json::from_str(&solve("three_sum", "[]")).unwrap();
+        assert_eq!(unknown["error"], "unknown problem 'three_sum'");
+        let invalid: Value = serde_json::from_str(&solve("two_sum", "[2,7")).unwrap();
+        assert_eq!(invalid["error"], "invalid testcase for two_sum: expected ',' or ']' at byte 4");
+    }
+}
@@ -0,0 +1,203 @@
+// Runs the bundled examples and the cases/ files of every registered
+// problem, so adding a problem with `register_problem!` is all it takes to
+// have it tested.
+
+use std::collections::HashSet;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use leet_code::{cases, find, problems};
+
+#[test]
+fn every_problem_passes_its_examples() {
+    let mut failures = Vec::new();
+    for problem in problems::all() {
+        let outcomes = problem.check();
+        if outcomes.is_empty() {
+            failures.push(format!("{}: no examples", problem.name()));
+        }
+        for outcome in outcomes.iter().filter(|outcome| !outcome.passed) {
+            failures.push(format!(
+                "{}{}: {} => {} (expected {})",
+                problem.name(),
+                outcome.variant.map(|variant| format!(" [{}]", variant)).unwrap_or_default(),
+                outcome.input,
+                outcome.got,
+                outcome.expected
+            ));
+            failures.extend(outcome.detail.clone());
+        }
+    }
+    assert!(failures.is_empty(), "{} failing case(s):\n{}", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn every_case_file_passes() {
+    let dir = cases::dir();
+    let mut failures = Vec::new();
+    for problem in problems::all() {
+        match cases::check(*problem, &dir) {
+            Ok(files) => {
+                for (path, outcomes) in files {
+                    for outcome in outcomes.iter().filter(|outcome| !outcome.passed) {
+                        failures.push(format!(
+                            "{}{}: {} => {} (expected {})",
+                            path.display(),
+                            outcome.variant.map(|variant| format!(" [{}]", variant)).unwrap_or_default(),
+                            outcome.input,
+                            outcome.got,
+                            outcome.expected
+                        ));
+                    }
+                }
+            }
+            Err(err) => failures.push(err.to_string()),
+        }
+    }
+    assert!(failures.is_empty(), "{} failing case(s):\n{}", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn every_case_file_names_a_problem() {
+    for entry in std::fs::read_dir(cases::dir()).unwrap() {
+        let path = entry.unwrap().path();
+        let extension = path.extension().and_then(|extension| extension.to_str()).unwrap_or_default();
+        assert!(cases::EXTENSIONS.contains(&extension), "{} is not a case file", path.display());
+        let name = path.file_stem().unwrap().to_str().unwrap();
+        assert!(find(name).is_some(), "{} does not name a problem", path.display());
+    }
+}
+
+#[test]
+fn names_are_unique_and_ordered_by_id() {
+    let names: HashSet<_> = problems::all().iter().map(|problem| problem.name()).collect();
+    assert_eq!(names.len(), problems::all().len());
+    assert!(problems::all().windows(2).all(|pair| pair[0].id() < pair[1].id()));
+}
+
+#[test]
+fn every_problem_module_registers_itself() {
+    // `name()` is the module name, so a module without `register_problem!` shows up here
+    let names: HashSet<_> = problems::all().iter().map(|problem| problem.name()).collect();
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/src/problems");
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let module = path.file_stem().unwrap().to_str().unwrap();
+        if module != "mod" {
+            assert!(names.contains(module), "{} is not registered", module);
+        }
+    }
+}
+
+#[test]
+fn every_problem_has_metadata() {
+    for problem in problems::all() {
+        assert!(!problem.title().is_empty(), "{} has no title", problem.name());
+        assert!(!problem.tags().is_empty(), "{} has no tags", problem.name());
+    }
+}
+
+#[test]
+fn leet_runs_everything() {
+    let output = Command::new(env!("CARGO_BIN_EXE_leet")).args(["run", "--all"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for problem in problems::all() {
+        assert!(stdout.contains(problem.title()), "{} missing from output", problem.name());
+    }
+    assert!(!stdout.contains("Fail"));
+}
+
+#[test]
+fn leet_rejects_unknown_problems() {
+    let output = Command::new(env!("CARGO_BIN_EXE_leet")).args(["run", "three_sum"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown problem 'three_sum'"));
+}
+
+#[test]
+fn leet_filters_by_tag_and_difficulty() {
+    let leet = |args: &[&str]| Command::new(env!("CARGO_BIN_EXE_leet")).args(args).output().unwrap();
+    let output = leet(&["list", "--tag", "two-pointers", "--difficulty", "easy"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let names: Vec<_> = stdout.lines().map(|line| line.split_whitespace().nth(1).unwrap()).collect();
+    assert_eq!(names, ["first_occurence", "is_palindrome"]);
+
+    let output = leet(&["list", "--by-tag", "--tag", "stack"]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout).lines().filter(|line| !line.starts_with(' ')).count(), 2);
+
+    let output = leet(&["run", "--random", "--tag", "linked-list"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().filter(|line| !line.starts_with(' ')).count(), 1, "{}", stdout);
+
+    let output = leet(&["run", "--tag", "no-such-tag"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no problem matches"));
+}
+
+#[test]
+fn leet_tries_pasted_testcases() {
+    let output = Command::new(env!("CARGO_BIN_EXE_leet")).args(["try", "two_sum", "[2,7,11,15]", "9"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("=> [0, 1]"));
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_leet"))
+        .args(["try", "tree_balanced"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"[3,9,20,null,null,15,7]\n[1,2,2,3,3,null,null,4,4]\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("[3,9,20,null,null,15,7] => true"), "{}", stdout);
+    assert!(stdout.contains("[1,2,2,3,3,null,null,4,4] => false"), "{}", stdout);
+}
+
+#[test]
+fn leet_explains_bad_testcases() {
+    let output = Command::new(env!("CARGO_BIN_EXE_leet")).args(["try", "two_sum", "[2,7", "9"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid testcase for two_sum: expected ',' or ']'"));
+}
+
+#[test]
+fn leet_writes_dot_files() {
+    let dir = tempfile::Builder::new().prefix("leet-dot-").tempdir().unwrap();
+    let path = dir.path().join("cases.dot");
+    let path_arg = path.to_str().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_leet")).args(["run", "max_depth", "--dot", path_arg]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let dot = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(dot.matches("subgraph cluster_").count(), 3);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_leet"))
+        .args(["try", "reverse_linked_list", "[4,5]", "--dot", path_arg])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(std::fs::read_to_string(&path).unwrap().contains("c0_0 -> c0_1;"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_leet")).args(["run", "two_sum", "--dot", path_arg]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("two_sum has no tree or list to draw"));
+}
+
+// Timed with the wall clock, so a busy machine (other tests running in
+// parallel, a debug build) can stop the sizes early and bend the fit. Run it
+// on its own: cargo test --release -p leet-code --test problems -- --ignored
+#[test]
+#[ignore = "timing-based, run in release with `-- --ignored`"]
+fn leet_flags_quadratic_solutions() {
+    let output = Command::new(env!("CARGO_BIN_EXE_leet")).args(["complexity", "first_occurence"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let line = |solution: &str| stdout.lines().find(|line| line.contains(solution)).unwrap_or_default().to_string();
+    // str_str calls chars().nth(i) for every i
+    assert!(line(" main ").contains("quadratic or worse"), "{}", stdout);
+    assert!(line("[kmp]").starts_with("  O(n"), "{}", stdout);
+    assert!(!line("[kmp]").contains("quadratic"), "{}", stdout);
+}
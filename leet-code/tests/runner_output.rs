@@ -0,0 +1,125 @@
+// Snapshots of what the binaries print, so a change to the output's layout,
+// or to what a solution returns, shows up as a diff to review rather than
+// going unnoticed. The snapshots live in tests/snapshots/; after an
+// intended change, accept the new ones with `cargo insta review` (or run
+// the tests with INSTA_UPDATE=always and check the diff).
+//
+// Paths are replaced by placeholders, so the snapshots do not depend on
+// where the crate is checked out.
+
+use std::path::Path;
+use std::process::Command;
+
+use insta::assert_snapshot;
+use tempfile::TempDir;
+
+const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+// A fresh temporary directory holding `files`, named after the test and
+// unique to this run, so concurrent runs do not share it. It is removed
+// when dropped
+fn temp_dir(name: &str, files: &[(&str, &str)]) -> TempDir {
+    let dir = tempfile::Builder::new().prefix(&format!("leet-code-{}-", name)).tempdir().unwrap();
+    for (file, text) in files {
+        let path = dir.path().join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, text).unwrap();
+    }
+    dir
+}
+
+// The command line, stdout, stderr if any and the exit code, with `dir`
+// shown as `placeholder`
+fn transcript(command: &mut Command, args: &[&str], dir: &Path, placeholder: &str) -> String {
+    let output = command.args(args).output().unwrap();
+    let shown: Vec<String> = args.iter().map(|arg| if arg.contains(' ') { format!("{:?}", arg) } else { arg.to_string() }).collect();
+    let mut text = format!("$ {}\n{}", shown.join(" "), String::from_utf8_lossy(&output.stdout));
+    if !output.stderr.is_empty() {
+        text += &format!("--- stderr\n{}", String::from_utf8_lossy(&output.stderr));
+    }
+    text += &format!("--- exit code {}\n", output.status.code().unwrap_or(-1));
+    text.replace(dir.to_str().unwrap(), placeholder)
+}
+
+// `leet` reading its case files from `cases`
+fn leet(args: &[&str], cases: &Path) -> String {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_leet"));
+    command.env("LEET_CASES", cases);
+    transcript(&mut command, args, cases, "<cases>")
+}
+
+#[test]
+fn run_prints_examples_variants_and_case_files() {
+    let cases = Path::new(CRATE_DIR).join("cases");
+    assert_snapshot!(leet(&["run", "two_sum", "max_depth", "valid_parentheses"], &cases));
+}
+
+#[test]
+fn run_reports_failures_with_detail() {
+    let cases = temp_dir(
+        "failures",
+        &[
+            ("sell_stock.toml", "[[case]]\ninput = \"[7,1,5,3,6,4]\"\nexpected = \"4\"\n"),
+            ("max_depth.json", r#"{"case": [{"input": "[1,2,null,3]", "expected": "2"}]}"#),
+        ],
+    );
+    assert_snapshot!(leet(&["run", "sell_stock", "max_depth"], cases.path()));
+}
+
+#[test]
+fn run_reports_malformed_case_files() {
+    let cases = temp_dir("malformed", &[("two_sum.toml", "[[case]]\ninput = \"[1]\"\nexpected = \"[]\"\n")]);
+    assert_snapshot!(leet(&["run", "two_sum"], cases.path()));
+}
+
+#[test]
+fn try_prints_each_testcase() {
+    let cases = temp_dir("try", &[]);
+    assert_snapshot!(leet(&["try", "tree_balanced", "[3,9,20,null,null,15,7]", "[1,2,2,3,3,null,null,4,4]"], cases.path()));
+    assert_snapshot!("try_bad_testcase", leet(&["try", "two_sum", "[2,7", "9"], cases.path()));
+}
+
+#[test]
+fn list_by_tag_groups_problems() {
+    let cases = temp_dir("list", &[]);
+    assert_snapshot!(leet(&["list", "--by-tag", "--tag", "linked-list"], cases.path()));
+}
+
+#[test]
+fn practice_hides_the_cases_and_records_attempts() {
+    let cases = temp_dir("practice", &[("sell_stock.toml", "[[case]]\ninput = \"[7,1,5,3,6,4]\"\nexpected = \"4\"\n")]);
+    let progress = cases.path().join("progress.toml");
+    let practice = |args: &[&str]| {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_leet"));
+        command.env("LEET_CASES", cases.path()).env("LEET_PROGRESS", &progress);
+        transcript(&mut command, args, cases.path(), "<cases>")
+    };
+    assert_snapshot!(practice(&["practice", "sell_stock"]));
+    assert_snapshot!("practice_solved", practice(&["practice", "two_sum"]));
+
+    // Picking a problem starts its clock; the statement is the module's header
+    let picked = practice(&["practice", "--tag", "topological-sort"]);
+    assert!(picked.contains("Course Schedule") && picked.contains("Constraints:"), "{}", picked);
+    let recorded = std::fs::read_to_string(&progress).unwrap();
+    assert!(recorded.contains("[problems.course_schedule]\nstarted = "), "{}", recorded);
+    assert!(recorded.contains("[[problems.sell_stock.attempts]]") && recorded.contains("[problems.two_sum]\nsolved = "), "{}", recorded);
+}
+
+#[test]
+fn report_lists_every_solution() {
+    // Neither problem has a generator, so the output has no timings. The
+    // case file is wrong (a 3 x 3 grid has 6 paths) to show a failing row
+    let cases = temp_dir("report", &[("unique_paths.toml", "[[case]]\ninput = \"3\\n3\"\nexpected = \"5\"\n")]);
+    assert_snapshot!(leet(&["report", "unique_paths", "climbing_stairs"], cases.path()));
+    assert_snapshot!("report_csv", leet(&["report", "--csv", "climbing_stairs"], cases.path()));
+}
+
+#[test]
+fn new_problem_writes_the_template() {
+    let root = temp_dir("new-problem", &[("src/problems/mod.rs", "pub mod two_sum;\n")]);
+    let mut command = Command::new(env!("CARGO_BIN_EXE_new-problem"));
+    command.env("LEET_ROOT", root.path());
+    let args = ["--id", "226", "--title", "Invert Binary Tree", "--tags", "tree,depth-first-search"];
+    assert_snapshot!(transcript(&mut command, &args, root.path(), "<root>"));
+    assert_snapshot!("new_problem_module", std::fs::read_to_string(root.path().join("src/problems/invert_binary_tree.rs")).unwrap());
+}
@@ -0,0 +1,134 @@
+// Terminal colors are plain ANSI escape sequences: "\x1b[31m" switches to
+// red and "\x1b[0m" resets. They only make sense on a terminal - redirected
+// to a file or a CI log they show up as garbage like `[31m[ERROR][0m`.
+//
+// ColorMode::Auto (the default) therefore colors only when:
+// • NO_COLOR is unset or empty (https://no-color.org), and
+// • stdout is a terminal (std::io::IsTerminal)
+
+use std::fmt;
+use std::io::IsTerminal;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Whether human-readable log lines contain terminal colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    /// Color when stdout is a terminal and `NO_COLOR` is not set.
+    #[default]
+    Auto,
+    /// Always color, even when redirected or `NO_COLOR` is set.
+    Always,
+    /// Never color.
+    Never,
+}
+
+impl ColorMode {
+    const ALL: [ColorMode; 3] = [ColorMode::Auto, ColorMode::Always, ColorMode::Never];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ColorMode::Auto => "auto",
+            ColorMode::Always => "always",
+            ColorMode::Never => "never",
+        }
+    }
+}
+
+impl fmt::Display for ColorMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// Returned when a string is not a color mode name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorModeError(String);
+
+impl fmt::Display for ParseColorModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown color mode '{}' (expected auto, always or never)", self.0)
+    }
+}
+
+impl std::error::Error for ParseColorModeError {}
+
+impl FromStr for ColorMode {
+    type Err = ParseColorModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ColorMode::ALL
+            .into_iter()
+            .find(|mode| mode.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| ParseColorModeError(s.to_string()))
+    }
+}
+
+// ===== GLOBAL SETTING =====
+
+static MODE: AtomicU8 = AtomicU8::new(ColorMode::Auto as u8);
+
+/// Changes the color mode for every thread.
+pub fn set_color_mode(mode: ColorMode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+pub fn color_mode() -> ColorMode {
+    ColorMode::ALL[MODE.load(Ordering::Relaxed) as usize]
+}
+
+/// Whether log lines written to stdout right now get colors.
+pub fn use_color() -> bool {
+    // The environment and the terminal don't change while we run
+    static AUTO: OnceLock<bool> = OnceLock::new();
+
+    match color_mode() {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => {
+            *AUTO.get_or_init(|| auto_color(std::env::var_os("NO_COLOR").as_deref(), std::io::stdout().is_terminal()))
+        }
+    }
+}
+
+fn auto_color(no_color: Option<&std::ffi::OsStr>, is_terminal: bool) -> bool {
+    let no_color = no_color.is_some_and(|value| !value.is_empty());
+    is_terminal && !no_color
+}
+
+// ===== PAINTING =====
+
+pub(crate) const DIMMED: &str = "2";
+pub(crate) const ITALIC: &str = "3";
+pub(crate) const GREEN: &str = "32";
+
+pub(crate) fn paint(text: &str, style: &str) -> String {
+    format!("\x1b[{}m{}\x1b[0m", style, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsStr;
+
+    #[test]
+    fn auto_needs_a_terminal_and_no_no_color() {
+        assert!(auto_color(None, true));
+        assert!(auto_color(Some(OsStr::new("")), true));
+        assert!(!auto_color(Some(OsStr::new("1")), true));
+        assert!(!auto_color(None, false));
+    }
+
+    #[test]
+    fn parses_mode_names() {
+        assert_eq!("Always".parse(), Ok(ColorMode::Always));
+        assert_eq!("never".parse(), Ok(ColorMode::Never));
+        assert!("sometimes".parse::<ColorMode>().is_err());
+    }
+
+    #[test]
+    fn paints_with_ansi_codes() {
+        assert_eq!(paint("ok", GREEN), "\x1b[32mok\x1b[0m");
+    }
+}
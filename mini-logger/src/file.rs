@@ -0,0 +1,258 @@
+// ===== FILE OUTPUT WITH ROTATION =====
+//
+// FileSink appends log lines to a file and rotates it by size:
+//
+//   app.log      ← current file, rotated once it would exceed max_bytes
+//   app.log.1    ← previous file
+//   app.log.2    ← older, up to max_files; the oldest is deleted
+//
+// INTERIOR MUTABILITY:
+// • Writing needs `&mut` access to the BufWriter, but the sink is shared
+//   between every logging thread and the flusher thread through an Arc
+// • Arc<Mutex<Writer>> gives each of them `&mut Writer` one at a time, so
+//   FileSink's own methods only need `&self`
+//
+// BUFFERING AND FLUSHING:
+// • Lines go into a BufWriter and reach the disk in large chunks
+// • A background thread flushes every `flush_every`, so a crash loses at most
+//   that much output
+// • Drop stops the thread and flushes whatever is left - which only happens
+//   if the sink is dropped: std::process::exit skips destructors
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Settings for [`FileSink::open`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileOptions {
+    /// Rotate before a write would make the file larger than this.
+    pub max_bytes: u64,
+    /// How many rotated files (`.1`, `.2`, ...) to keep; 0 keeps none.
+    pub max_files: usize,
+    /// How often the background thread flushes buffered lines.
+    pub flush_every: Duration,
+}
+
+impl Default for FileOptions {
+    /// 1 MiB per file, 3 rotated files, flushed every second.
+    fn default() -> Self {
+        FileOptions { max_bytes: 1024 * 1024, max_files: 3, flush_every: Duration::from_secs(1) }
+    }
+}
+
+// The part that needs `&mut`, guarded by FileSink's mutex
+struct Writer {
+    path: PathBuf,
+    options: FileOptions,
+    file: BufWriter<File>,
+    size: u64,
+}
+
+impl Writer {
+    fn open(path: PathBuf, options: FileOptions) -> io::Result<Writer> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Writer { path, options, file: BufWriter::new(file), size })
+    }
+
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let bytes = line.len() as u64 + 1;
+        // A line longer than max_bytes still gets written, to a file of its own
+        if self.size > 0 && self.size + bytes > self.options.max_bytes {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{}", line)?;
+        self.size += bytes;
+        Ok(())
+    }
+
+    // app.log.2 → app.log.3, app.log.1 → app.log.2, app.log → app.log.1
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        // With max_files == 0 the current file is simply truncated below
+        let max_files = self.options.max_files;
+        if max_files > 0 {
+            remove_if_exists(&rotated_path(&self.path, max_files))?;
+            for n in (1..max_files).rev() {
+                let from = rotated_path(&self.path, n);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(&self.path, n + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        }
+
+        let file = OpenOptions::new().create(true).write(true).truncate(true).open(&self.path)?;
+        self.file = BufWriter::new(file);
+        self.size = 0;
+        Ok(())
+    }
+}
+
+fn rotated_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}", n));
+    path.with_file_name(name)
+}
+
+fn remove_if_exists(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// A log file with size-based rotation and a background flusher.
+///
+/// Everything buffered is flushed when the sink is dropped.
+pub struct FileSink {
+    writer: Arc<Mutex<Writer>>,
+    path: PathBuf,
+    // Dropping the sender tells the flusher to stop
+    stop: Option<Sender<()>>,
+    flusher: Option<JoinHandle<()>>,
+}
+
+impl FileSink {
+    /// Opens `path` for appending, creating it and its directory if needed.
+    pub fn open(path: impl Into<PathBuf>, options: FileOptions) -> io::Result<FileSink> {
+        let path = path.into();
+        let writer = Arc::new(Mutex::new(Writer::open(path.clone(), options)?));
+
+        let (stop, stopped) = mpsc::channel::<()>();
+        let flusher = {
+            let writer = Arc::clone(&writer);
+            thread::Builder::new().name("mini-logger-flush".to_string()).spawn(move || {
+                // Wakes up every interval until the sender is dropped
+                while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(options.flush_every) {
+                    let _ = lock(&writer).file.flush();
+                }
+            })?
+        };
+
+        Ok(FileSink { writer, path, stop: Some(stop), flusher: Some(flusher) })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Appends `line` and a newline, rotating first if the file is full.
+    pub fn write_line(&self, line: &str) -> io::Result<()> {
+        lock(&self.writer).write_line(line)
+    }
+
+    /// Writes buffered lines to disk now instead of waiting for the flusher.
+    pub fn flush(&self) -> io::Result<()> {
+        lock(&self.writer).file.flush()
+    }
+}
+
+impl Drop for FileSink {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(flusher) = self.flusher.take() {
+            let _ = flusher.join();
+        }
+        let _ = self.flush();
+    }
+}
+
+// A panic while holding the lock leaves the writer usable, so ignore poisoning
+fn lock(writer: &Mutex<Writer>) -> MutexGuard<'_, Writer> {
+    writer.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("mini-logger-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn never_flush(max_bytes: u64, max_files: usize) -> FileOptions {
+        FileOptions { max_bytes, max_files, flush_every: Duration::from_secs(3600) }
+    }
+
+    #[test]
+    fn drop_flushes_buffered_lines() {
+        let dir = temp_dir("drop");
+        let path = dir.join("app.log");
+
+        let sink = FileSink::open(&path, never_flush(1024, 1)).unwrap();
+        sink.write_line("first").unwrap();
+        sink.write_line("second").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "", "still buffered");
+        drop(sink);
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first\nsecond\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rotates_by_size_and_keeps_max_files() {
+        let dir = temp_dir("rotate");
+        let path = dir.join("app.log");
+
+        // Each line is 6 bytes, so two fit in 12
+        let sink = FileSink::open(&path, never_flush(12, 2)).unwrap();
+        for line in ["line1", "line2", "line3", "line4", "line5", "line6", "line7"] {
+            sink.write_line(line).unwrap();
+        }
+        drop(sink);
+
+        let read = |n| fs::read_to_string(rotated_path(&path, n)).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "line7\n");
+        assert_eq!(read(1), "line5\nline6\n");
+        assert_eq!(read(2), "line3\nline4\n");
+        assert!(!rotated_path(&path, 3).exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn appends_to_an_existing_file() {
+        let dir = temp_dir("append");
+        let path = dir.join("app.log");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "earlier\n").unwrap();
+
+        let sink = FileSink::open(&path, never_flush(1024, 1)).unwrap();
+        sink.write_line("later").unwrap();
+        sink.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "earlier\nlater\n");
+        drop(sink);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn background_thread_flushes_periodically() {
+        let dir = temp_dir("flusher");
+        let path = dir.join("app.log");
+        let options = FileOptions { flush_every: Duration::from_millis(10), ..FileOptions::default() };
+
+        let sink = FileSink::open(&path, options).unwrap();
+        sink.write_line("eventually on disk").unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while fs::read_to_string(&path).unwrap().is_empty() {
+            assert!(Instant::now() < deadline, "flusher never ran");
+            thread::sleep(Duration::from_millis(5));
+        }
+        drop(sink);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
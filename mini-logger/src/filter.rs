@@ -0,0 +1,239 @@
+// Level filtering happens twice, and the macros check both BEFORE formatting
+// the message, so a suppressed debug!("{:?}", big_value) costs almost nothing:
+//
+// • Compile time: STATIC_MIN_LEVEL is a const picked by cargo features. A
+//   `level >= STATIC_MIN_LEVEL` test on a const folds away, and the disabled
+//   log calls are removed from the binary entirely. Changing it means
+//   rebuilding.
+// • Runtime: a global AtomicU8 read with one relaxed load per log call. It can
+//   change while the program runs (set_level, or an env var at startup), but
+//   the check itself is always compiled in.
+//
+// PER-TARGET FILTERS (env_logger style):
+//   "warn"                            everything at warn and above
+//   "info,smart_pointers=debug"       info by default, debug in one module
+//   "traits=warn,db"                  a bare name turns on every level for it
+//
+// Module directives sit behind an RwLock, but a flag lets the common case of
+// "no directives" skip the lock entirely.
+
+use crate::{Level, ParseLevelError};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// The least severe level compiled into the binary.
+///
+/// `Trace` unless one of the `min-level-*` cargo features is enabled, e.g.
+/// `mini-logger = { path = "../mini-logger", features = ["min-level-info"] }`.
+/// When several are enabled the most severe wins.
+pub const STATIC_MIN_LEVEL: Level = if cfg!(feature = "min-level-error") {
+    Level::Error
+} else if cfg!(feature = "min-level-warn") {
+    Level::Warn
+} else if cfg!(feature = "min-level-info") {
+    Level::Info
+} else if cfg!(feature = "min-level-debug") {
+    Level::Debug
+} else {
+    Level::Trace
+};
+
+// ===== FILTER SPECS =====
+
+/// A default level plus per-target overrides, parsed from specs like
+/// `"info,smart_pointers=debug,traits=warn"`.
+///
+/// A directive name matches a target made of the same leading path segments,
+/// with or without the crate name: `smart_pointers` and
+/// `rust_basics::smart_pointers` both match the target
+/// `rust_basics::smart_pointers::rc`, but not `rust_basics::smart_pointers_2`.
+/// When several match, the longest name wins.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Filter {
+    /// Level for targets no directive matches; `None` keeps the current one.
+    pub default: Option<Level>,
+    /// `(name, level)` pairs, longest name first.
+    pub directives: Vec<(String, Level)>,
+}
+
+impl Filter {
+    /// The level a target is filtered at, or `None` if no directive matches.
+    pub fn level_for(&self, target: &str) -> Option<Level> {
+        self.directives
+            .iter()
+            .find(|(name, _)| matches(name, target))
+            .map(|(_, level)| *level)
+    }
+}
+
+fn matches(name: &str, target: &str) -> bool {
+    let starts_with_name =
+        |path: &str| path.strip_prefix(name).is_some_and(|rest| rest.is_empty() || rest.starts_with("::"));
+    starts_with_name(target) || target.split_once("::").is_some_and(|(_, rest)| starts_with_name(rest))
+}
+
+/// Returned when a filter spec has an invalid directive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFilterError {
+    directive: String,
+    source: ParseLevelError,
+}
+
+impl fmt::Display for ParseFilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid directive '{}': {}", self.directive, self.source)
+    }
+}
+
+impl std::error::Error for ParseFilterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = ParseFilterError;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::default();
+        // A name given twice keeps its last level
+        let mut set = |name: &str, level| {
+            filter.directives.retain(|(existing, _)| existing != name);
+            filter.directives.push((name.to_string(), level));
+        };
+        let mut default = None;
+
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let invalid = |source| ParseFilterError { directive: directive.to_string(), source };
+            match directive.split_once('=') {
+                Some((name, level)) => {
+                    set(name.trim(), level.parse().map_err(invalid)?);
+                }
+                // "warn" is a default level, "traits" a module with every level on
+                None => match directive.parse() {
+                    Ok(level) => default = Some(level),
+                    Err(_) => set(directive, Level::Trace),
+                },
+            }
+        }
+
+        filter.default = default;
+        filter.directives.sort_by_key(|(name, _)| std::cmp::Reverse(name.len()));
+        Ok(filter)
+    }
+}
+
+// ===== GLOBAL STATE =====
+
+static MIN_LEVEL: AtomicU8 = AtomicU8::new(Level::Trace as u8);
+static HAS_DIRECTIVES: AtomicBool = AtomicBool::new(false);
+static DIRECTIVES: RwLock<Filter> = RwLock::new(Filter { default: None, directives: Vec::new() });
+
+fn directives() -> RwLockReadGuard<'static, Filter> {
+    DIRECTIVES.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn directives_mut() -> RwLockWriteGuard<'static, Filter> {
+    DIRECTIVES.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Suppresses every record less severe than `level`, on all threads, except
+/// for targets with their own directive (see [`set_filter`]).
+pub fn set_level(level: Level) {
+    MIN_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// The default level: the least severe one written for targets without a
+/// directive. Starts at `Trace` (everything).
+pub fn level() -> Level {
+    Level::ALL[MIN_LEVEL.load(Ordering::Relaxed) as usize]
+}
+
+/// Replaces the per-target directives, and the default level if the filter
+/// has one.
+pub fn set_filter(filter: Filter) {
+    if let Some(level) = filter.default {
+        set_level(level);
+    }
+    let mut directives = directives_mut();
+    HAS_DIRECTIVES.store(!filter.directives.is_empty(), Ordering::Relaxed);
+    *directives = filter;
+}
+
+/// The current default level and directives, e.g. to restore them later.
+pub fn filter() -> Filter {
+    Filter { default: Some(level()), directives: directives().directives.clone() }
+}
+
+/// Whether a record at `level` for `target` would be written.
+#[inline]
+pub fn enabled(level: Level, target: &str) -> bool {
+    if level < STATIC_MIN_LEVEL {
+        return false;
+    }
+    let min = if HAS_DIRECTIVES.load(Ordering::Relaxed) {
+        directives().level_for(target).unwrap_or_else(self::level)
+    } else {
+        self::level()
+    };
+    level >= min
+}
+
+/// Applies the filter spec in the environment variable `var`, e.g.
+/// `MY_APP_LOG=info,db=debug`.
+///
+/// An unset or empty variable changes nothing; neither does an invalid one,
+/// which is reported instead.
+pub fn init_from_env(var: &str) -> Result<(), ParseFilterError> {
+    match std::env::var(var) {
+        Ok(spec) if !spec.trim().is_empty() => {
+            set_filter(spec.parse()?);
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_defaults_directives_and_bare_names() {
+        let filter: Filter = "info, smart_pointers=debug,traits=WARN,db,".parse().unwrap();
+        assert_eq!(filter.default, Some(Level::Info));
+        assert_eq!(filter.level_for("rust_basics::smart_pointers"), Some(Level::Debug));
+        assert_eq!(filter.level_for("rust_basics::traits"), Some(Level::Warn));
+        assert_eq!(filter.level_for("db"), Some(Level::Trace));
+        assert_eq!(filter.level_for("rust_basics::macros"), None);
+    }
+
+    #[test]
+    fn names_match_whole_path_segments() {
+        assert!(matches("smart_pointers", "rust_basics::smart_pointers::rc"));
+        assert!(matches("rust_basics", "rust_basics::traits"));
+        assert!(matches("rust_basics::traits", "rust_basics::traits"));
+        assert!(!matches("smart_pointers", "rust_basics::smart_pointers_2"));
+        assert!(!matches("rc", "rust_basics::smart_pointers::rc"));
+    }
+
+    #[test]
+    fn longest_match_wins_and_later_duplicates_override() {
+        let filter: Filter = "rust_basics=warn,rust_basics::traits=trace,db=info,ab=warn,db=error".parse().unwrap();
+        assert_eq!(filter.level_for("rust_basics::traits::inner"), Some(Level::Trace));
+        assert_eq!(filter.level_for("rust_basics::errors"), Some(Level::Warn));
+        assert_eq!(filter.level_for("db"), Some(Level::Error));
+        assert_eq!(filter.default, None);
+    }
+
+    #[test]
+    fn reports_the_bad_directive() {
+        let err = "info,db=loud".parse::<Filter>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid directive 'db=loud': unknown log level 'loud' (expected trace, debug, info, warn or error)"
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use crate::Record;
+use crate::color::{self, DIMMED, GREEN, ITALIC};
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+/// How records are written out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// One colored line for people reading a terminal:
+    ///
+    /// `[2024-05-01 12:30:00] [WARN] pool is full [size=8] - app (src/db.rs:7)`
+    #[default]
+    Human,
+    /// One JSON object per line for log collectors and `jq`:
+    ///
+    /// `{"timestamp":"2024-05-01T12:30:00+00:00","level":"WARN","target":"app::db","message":"pool is full","fields":{"size":"8"},"file":"src/db.rs","line":7}`
+    Json,
+}
+
+impl Format {
+    /// Renders `record` as a single line, without a trailing newline.
+    ///
+    /// `color` only affects [`Format::Human`]; JSON never contains escape codes.
+    /// [`use_color`](crate::use_color) tells whether stdout should get them.
+    pub fn render(self, record: &Record, color: bool) -> String {
+        match self {
+            Format::Human => human(record, color),
+            Format::Json => json(record),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Format::Human => "human",
+            Format::Json => "json",
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// Returned when a string is not a format name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFormatError(String);
+
+impl fmt::Display for ParseFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown log format '{}' (expected human or json)", self.0)
+    }
+}
+
+impl std::error::Error for ParseFormatError {}
+
+impl FromStr for Format {
+    type Err = ParseFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [Format::Human, Format::Json]
+            .into_iter()
+            .find(|format| format.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| ParseFormatError(s.to_string()))
+    }
+}
+
+// ===== HUMAN =====
+
+fn human(record: &Record, color: bool) -> String {
+    let timestamp = record.timestamp.format("%Y-%m-%d %H:%M:%S").to_string();
+    let level = format!("[{}]", record.level);
+    let context = record.context.as_ref().map(|context| format!("Context: {}", context));
+    let location = &record.location;
+
+    let (timestamp, level, context, app) = if color {
+        (
+            color::paint(&timestamp, DIMMED),
+            color::paint(&level, record.level.color()),
+            context.map(|context| color::paint(&context, ITALIC)),
+            color::paint(location.app, GREEN),
+        )
+    } else {
+        (timestamp, level, context, location.app.to_string())
+    };
+
+    let mut line = format!("[{}] {} {}", timestamp, level, record.message);
+    if !record.fields.is_empty() {
+        let fields: Vec<_> = record.fields.iter().map(|(key, value)| format!("{}={}", key, value)).collect();
+        let _ = write!(line, " [{}]", fields.join(", "));
+    }
+    if let Some(context) = context {
+        let _ = write!(line, " | {}", context);
+    }
+    let _ = write!(line, " - {} ({}:{})", app, location.file, location.line);
+    line
+}
+
+// ===== JSON =====
+//
+// Written by hand rather than with serde_json: every value is a string or a
+// number, so all it takes is escaping strings correctly.
+
+fn json(record: &Record) -> String {
+    let location = &record.location;
+    let mut out = String::from("{");
+    let _ = write!(out, "\"timestamp\":{}", json_string(&record.timestamp.to_rfc3339()));
+    let _ = write!(out, ",\"level\":{}", json_string(record.level.as_str()));
+    let _ = write!(out, ",\"target\":{}", json_string(record.target));
+    let _ = write!(out, ",\"message\":{}", json_string(&record.message));
+    if let Some(context) = &record.context {
+        let _ = write!(out, ",\"context\":{}", json_string(context));
+    }
+
+    out.push_str(",\"fields\":{");
+    for (i, (key, value)) in record.fields.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let _ = write!(out, "{}:{}", json_string(key), json_string(value));
+    }
+    out.push('}');
+
+    let _ = write!(out, ",\"module\":{}", json_string(location.module_path));
+    let _ = write!(out, ",\"file\":{}", json_string(location.file));
+    let _ = write!(out, ",\"line\":{}}}", location.line);
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Level, Location};
+    use chrono::{Local, TimeZone};
+
+    fn record(context: Option<&str>, fields: Vec<(&'static str, String)>) -> Record {
+        let location = Location { app: "demo", module_path: "demo::db", file: "src/db.rs", line: 7 };
+        let mut record = Record::new(Level::Warn, "db", "pool is full".to_string(), context.map(String::from), fields, location);
+        record.timestamp = Local.with_ymd_and_hms(2024, 5, 1, 12, 30, 0).unwrap();
+        record
+    }
+
+    #[test]
+    fn formats_plain_lines() {
+        assert_eq!(
+            Format::Human.render(&record(None, vec![]), false),
+            "[2024-05-01 12:30:00] [WARN] pool is full - demo (src/db.rs:7)"
+        );
+    }
+
+    #[test]
+    fn formats_fields_and_context() {
+        let fields = vec![("size", "8".to_string()), ("waiting", "3".to_string())];
+        assert_eq!(
+            Format::Human.render(&record(Some("connection_pool"), fields), false),
+            "[2024-05-01 12:30:00] [WARN] pool is full [size=8, waiting=3] | Context: connection_pool - demo (src/db.rs:7)"
+        );
+    }
+
+    #[test]
+    fn colors_only_when_asked() {
+        let record = record(Some("connection_pool"), vec![]);
+        let colored = Format::Human.render(&record, true);
+        assert!(colored.contains("\x1b[33m[WARN]\x1b[0m"));
+        assert!(colored.contains("\x1b[32mdemo\x1b[0m"));
+        assert!(!Format::Human.render(&record, false).contains('\x1b'));
+    }
+
+    #[test]
+    fn json_is_valid_and_complete() {
+        let fields = vec![("user", "\"bob\"\n".to_string())];
+        let line = Format::Json.render(&record(Some("auth"), fields), true);
+        assert!(!line.contains('\n') && !line.contains('\u{1b}'));
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["level"], "WARN");
+        assert_eq!(value["target"], "db");
+        assert_eq!(value["message"], "pool is full");
+        assert_eq!(value["context"], "auth");
+        assert_eq!(value["fields"]["user"], "\"bob\"\n");
+        assert_eq!(value["file"], "src/db.rs");
+        assert_eq!(value["line"], 7);
+        assert!(value["timestamp"].as_str().unwrap().starts_with("2024-05-01T12:30:00"));
+    }
+
+    #[test]
+    fn json_escapes_control_characters() {
+        assert_eq!(json_string("a\u{7}b\\"), r#""a\u0007b\\""#);
+    }
+
+    #[test]
+    fn parses_format_names() {
+        assert_eq!("JSON".parse(), Ok(Format::Json));
+        assert_eq!("human".parse(), Ok(Format::Human));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// How important a log message is, from least to most severe.
+///
+/// Levels are ordered, so `Level::Warn > Level::Debug`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    /// Every level, least severe first.
+    pub const ALL: [Level; 5] = [Level::Trace, Level::Debug, Level::Info, Level::Warn, Level::Error];
+
+    /// The upper-case name printed in log lines, e.g. `"WARN"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Level::Trace => "TRACE",
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        }
+    }
+
+    // ANSI color code (see color.rs)
+    pub(crate) fn color(self) -> &'static str {
+        match self {
+            Level::Trace => "35", // magenta
+            Level::Debug => "36", // cyan
+            Level::Info => "34",  // blue
+            Level::Warn => "33",  // yellow
+            Level::Error => "31", // red
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// Returned when a string is not a level name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLevelError(String);
+
+impl fmt::Display for ParseLevelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown log level '{}' (expected trace, debug, info, warn or error)", self.0)
+    }
+}
+
+impl std::error::Error for ParseLevelError {}
+
+impl FromStr for Level {
+    type Err = ParseLevelError;
+
+    /// Case-insensitive: `"warn"`, `"WARN"` and `"Warn"` all parse.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| ParseLevelError(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_are_ordered_by_severity() {
+        assert!(Level::Trace < Level::Debug);
+        assert!(Level::Warn < Level::Error);
+        assert_eq!(Level::ALL.iter().max(), Some(&Level::Error));
+    }
+
+    #[test]
+    fn parses_names_case_insensitively() {
+        assert_eq!("warn".parse(), Ok(Level::Warn));
+        assert_eq!(" ERROR ".parse(), Ok(Level::Error));
+        assert_eq!(
+            "loud".parse::<Level>().unwrap_err().to_string(),
+            "unknown log level 'loud' (expected trace, debug, info, warn or error)"
+        );
+    }
+
+    #[test]
+    fn display_respects_padding() {
+        assert_eq!(format!("[{:<5}]", Level::Info), "[INFO ]");
+    }
+}
@@ -0,0 +1,336 @@
+//! A small, dependency-light logger built from declarative macros.
+//!
+//! Grew out of the `macros` module of `rust-basics`, and is kept small enough
+//! to read in one sitting:
+//!
+//! ```
+//! use mini_logger::{debug, error, info, warn};
+//!
+//! info!("server started on port {}", 8080);
+//! warn!("cache miss rate at {}%", 40);
+//! error!("upstream timed out", context: "billing");
+//! debug!(std::env::consts::OS);
+//! info!("order placed"; id = 42, total = 9.99);
+//! ```
+//!
+//! Each macro builds a [`Record`] with the caller's crate, module, file and
+//! line, then writes it to stdout as one line in the current [`Format`]
+//! (colored only on a terminal without `NO_COLOR`, see [`ColorMode`]):
+//!
+//! ```text
+//! [2024-05-01 12:30:00] [INFO] order placed [id=42, total=9.99] - my-app (src/main.rs:7)
+//! {"timestamp":"2024-05-01T12:30:00+00:00","level":"INFO","target":"my_app","message":"order placed","fields":{"id":"42","total":"9.99"},"module":"my_app","file":"src/main.rs","line":7}
+//! ```
+//!
+//! The format is chosen at runtime with [`set_format`]. Records less severe
+//! than [`level`] are dropped; see [`set_level`], [`init_from_env`] and, for
+//! removing log calls at compile time, [`STATIC_MIN_LEVEL`]. A [`Filter`]
+//! such as `"info,db=debug"` sets levels per target, which is the caller's
+//! module unless the macro is given `target: "..."`.
+//!
+//! [`log_to_file`] additionally appends every record to a rotating log file.
+//! Tests can use [`capture`] to collect records instead of printing them.
+
+mod color;
+mod file;
+mod filter;
+mod format;
+mod level;
+mod macros;
+mod record;
+
+pub use color::{ColorMode, ParseColorModeError, color_mode, set_color_mode, use_color};
+pub use file::{FileOptions, FileSink};
+pub use filter::{
+    Filter, ParseFilterError, STATIC_MIN_LEVEL, enabled, filter, init_from_env, level, set_filter, set_level,
+};
+pub use format::{Format, ParseFormatError};
+pub use level::{Level, ParseLevelError};
+pub use record::{Location, Record};
+
+use std::cell::RefCell;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+// Human unless set_format(Format::Json) was called
+static JSON: AtomicBool = AtomicBool::new(false);
+
+/// Switches the output format for every thread. Takes effect immediately.
+pub fn set_format(format: Format) {
+    JSON.store(format == Format::Json, Ordering::Relaxed);
+}
+
+/// The current output format.
+pub fn format() -> Format {
+    if JSON.load(Ordering::Relaxed) { Format::Json } else { Format::Human }
+}
+
+// Set while a FileGuard from log_to_file is alive
+static FILE: Mutex<Option<Arc<FileSink>>> = Mutex::new(None);
+
+fn installed_file() -> MutexGuard<'static, Option<Arc<FileSink>>> {
+    FILE.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Also writes every record to `path`, without colors, until the returned
+/// guard is dropped. Replaces a file set up by an earlier call.
+///
+/// ```no_run
+/// let _log_file = mini_logger::log_to_file("logs/app.log", Default::default())?;
+/// mini_logger::info!("written to stdout and logs/app.log");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn log_to_file(path: impl Into<PathBuf>, options: FileOptions) -> io::Result<FileGuard> {
+    let sink = Arc::new(FileSink::open(path, options)?);
+    *installed_file() = Some(Arc::clone(&sink));
+    Ok(FileGuard { sink })
+}
+
+/// Returned by [`log_to_file`]. Dropping it stops file logging and flushes
+/// the file, so keep it alive for as long as you log (e.g. in `main`).
+#[must_use = "file logging stops as soon as the guard is dropped"]
+pub struct FileGuard {
+    sink: Arc<FileSink>,
+}
+
+impl FileGuard {
+    pub fn path(&self) -> &Path {
+        self.sink.path()
+    }
+}
+
+impl Drop for FileGuard {
+    fn drop(&mut self) {
+        let mut installed = installed_file();
+        if installed.as_ref().is_some_and(|sink| Arc::ptr_eq(sink, &self.sink)) {
+            *installed = None;
+        }
+        drop(installed);
+        // Usually the last reference, whose own Drop flushes too - but a
+        // thread in the middle of emit() may still hold one
+        let _ = self.sink.flush();
+    }
+}
+
+thread_local! {
+    // Some(..) while `capture` is running on this thread
+    static CAPTURED: RefCell<Option<Vec<Record>>> = const { RefCell::new(None) };
+}
+
+/// Writes a record. This is what the macros call after building one.
+pub fn emit(record: Record) {
+    // Handed back only when nobody is capturing
+    let uncaptured = CAPTURED.with_borrow_mut(|captured| match captured {
+        Some(records) => {
+            records.push(record);
+            None
+        }
+        None => Some(record),
+    });
+
+    let Some(record) = uncaptured else { return };
+    println!("{}", format().render(&record, use_color()));
+
+    // Cloning the Arc lets the global lock go before the file write
+    let file = installed_file().clone();
+    if let Some(file) = file
+        && let Err(err) = file.write_line(&format().render(&record, false))
+    {
+        eprintln!("mini-logger: could not write to {}: {}", file.path().display(), err);
+    }
+}
+
+/// Runs `f` and returns every record it logged on the current thread,
+/// without printing them.
+///
+/// ```
+/// let records = mini_logger::capture(|| mini_logger::warn!("low disk"));
+/// assert_eq!(records[0].message, "low disk");
+/// ```
+pub fn capture(f: impl FnOnce()) -> Vec<Record> {
+    let outer = CAPTURED.replace(Some(Vec::new()));
+    f();
+    CAPTURED.replace(outer).unwrap_or_default()
+}
+
+// Used by the macro expansions; not part of the public API
+#[doc(hidden)]
+pub mod __private {
+    use crate::{Level, Location, Record};
+
+    pub fn log(
+        level: Level,
+        target: &'static str,
+        message: String,
+        context: Option<String>,
+        fields: Vec<(&'static str, String)>,
+        location: Location,
+    ) {
+        crate::emit(Record::new(level, target, message, context, fields, location));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::sync::{Mutex, MutexGuard};
+
+    // The level is global, so tests that log or change it take turns
+    fn exclusive() -> MutexGuard<'static, ()> {
+        static LOCK: Mutex<()> = Mutex::new(());
+        LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    #[test]
+    fn macros_capture_level_message_and_location() {
+        let _guard = exclusive();
+        let name = "Alice";
+        let records = capture(|| {
+            info!("user {} logged in", name);
+            warn!("inline {name}");
+            error!("pool exhausted", context: "db");
+            debug!(6 * 7);
+            trace!(String::from("owned message"));
+            log!(info, "explicit level");
+        });
+
+        let summary: Vec<_> = records
+            .iter()
+            .map(|r| (r.level, r.message.as_str(), r.context.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (Level::Info, "user Alice logged in", None),
+                (Level::Warn, "inline Alice", None),
+                (Level::Error, "pool exhausted", Some("db")),
+                (Level::Debug, "42", None),
+                (Level::Trace, "owned message", None),
+                (Level::Info, "explicit level", None),
+            ]
+        );
+
+        let location = records[0].location;
+        assert_eq!(location.app, "mini-logger");
+        assert_eq!(location.module_path, "mini_logger::tests");
+        assert_eq!(location.file, file!());
+        assert_eq!(records[0].target, "mini_logger::tests");
+    }
+
+    #[test]
+    fn fields_keep_their_order_and_values() {
+        let _guard = exclusive();
+        let user_id = 123;
+        let records = capture(|| info!("User action"; user_id = user_id, action = "login", ip = "10.0.0.1",));
+
+        assert_eq!(records[0].message, "User action");
+        assert_eq!(
+            records[0].fields,
+            [("user_id", "123".to_string()), ("action", "login".to_string()), ("ip", "10.0.0.1".to_string())]
+        );
+        assert_eq!(records[0].field("action"), Some("login"));
+        assert_eq!(records[0].field("missing"), None);
+    }
+
+    #[test]
+    fn nested_captures_keep_their_own_records() {
+        let _guard = exclusive();
+        let outer = capture(|| {
+            info!("outer");
+            let inner = capture(|| info!("inner"));
+            assert_eq!(inner.len(), 1);
+            assert_eq!(inner[0].message, "inner");
+        });
+        assert_eq!(outer.len(), 1);
+        assert_eq!(outer[0].message, "outer");
+    }
+
+    #[test]
+    fn records_below_the_level_are_dropped_without_being_formatted() {
+        let _guard = exclusive();
+        let formatted = Cell::new(0);
+        let expensive = || {
+            formatted.set(formatted.get() + 1);
+            "details"
+        };
+
+        set_level(Level::Warn);
+        assert!(!enabled(Level::Info, "any") && enabled(Level::Warn, "any"));
+        let records = capture(|| {
+            trace!("{}", expensive());
+            debug!(expensive());
+            info!("hidden"; cost = expensive());
+            warn!("shown: {}", expensive());
+            error!("also shown", context: expensive());
+        });
+        set_level(Level::Trace);
+
+        let levels: Vec<_> = records.iter().map(|r| r.level).collect();
+        assert_eq!(levels, [Level::Warn, Level::Error]);
+        assert_eq!(formatted.get(), 2);
+    }
+
+    #[test]
+    fn level_comes_from_the_environment() {
+        let _guard = exclusive();
+        // SAFETY: the variable is unique to this test and only read under the lock
+        unsafe { std::env::set_var("MINI_LOGGER_TEST_LEVEL", "error") };
+        init_from_env("MINI_LOGGER_TEST_LEVEL").unwrap();
+        assert_eq!(level(), Level::Error);
+
+        unsafe { std::env::set_var("MINI_LOGGER_TEST_LEVEL", "db=verbose") };
+        assert!(init_from_env("MINI_LOGGER_TEST_LEVEL").is_err());
+        assert_eq!(level(), Level::Error);
+
+        assert!(init_from_env("MINI_LOGGER_TEST_UNSET").is_ok());
+        set_level(Level::Trace);
+        assert_eq!(STATIC_MIN_LEVEL, Level::Trace);
+    }
+
+    #[test]
+    fn directives_filter_by_target() {
+        let _guard = exclusive();
+        set_filter("warn,mini_logger::tests=info,db=trace,http=error".parse().unwrap());
+        let records = capture(|| {
+            debug!("module: too verbose");
+            info!("module: shown");
+            trace!(target: "db", "db: everything shown");
+            warn!(target: "http", "http: too verbose");
+            info!(target: "other", "other: default is warn");
+            error!(target: "other", "other: shown", context: "default");
+        });
+        set_filter(Filter::default());
+        set_level(Level::Trace);
+        assert_eq!(filter(), Filter { default: Some(Level::Trace), directives: vec![] });
+
+        let messages: Vec<_> = records.iter().map(|r| (r.target, r.message.as_str())).collect();
+        assert_eq!(
+            messages,
+            [("mini_logger::tests", "module: shown"), ("db", "db: everything shown"), ("other", "other: shown")]
+        );
+    }
+
+    #[test]
+    fn log_to_file_writes_uncolored_lines_until_the_guard_drops() {
+        let _guard = exclusive();
+        let dir = std::env::temp_dir().join(format!("mini-logger-{}-guard", std::process::id()));
+        let path = dir.join("app.log");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        set_color_mode(ColorMode::Always);
+        let log_file = log_to_file(&path, FileOptions::default()).unwrap();
+        info!("to the file"; attempt = 1);
+        drop(log_file);
+        info!("after the guard");
+        set_color_mode(ColorMode::Auto);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(contents.lines().count(), 1);
+        assert!(contents.contains("[INFO] to the file [attempt=1] - mini-logger"));
+        assert!(!contents.contains('\x1b'));
+    }
+}
@@ -0,0 +1,165 @@
+// The exported macros. `#[macro_export]` places them at the crate root
+// (`mini_logger::info!`), and every path inside them starts with `$crate` so
+// they expand correctly in crates that never import anything else from us.
+
+/// Logs a message at the given level.
+///
+/// ```
+/// use mini_logger::log;
+///
+/// let user = "alice";
+/// log!(info, "user {} logged in", user);
+/// log!(warn, "disk almost full");
+/// log!(error, "query failed", context: "connection_pool");
+/// log!(info, "order placed"; id = 42, total = 9.99);
+/// log!(debug, target: "http", "GET /health");
+/// ```
+///
+/// The level is one of `trace`, `debug`, `info`, `warn` or `error`; anything
+/// else is a compile error. The target, used for per-module filtering (see
+/// [`Filter`](crate::Filter)), defaults to `module_path!()`; an explicit one
+/// must be a `&'static str`. Nothing is formatted or evaluated unless the
+/// level is [`enabled`](crate::enabled) for the target.
+#[macro_export]
+macro_rules! log {
+    ($level:ident, target: $target:expr, $($rest:tt)+) => {
+        $crate::__log!($target, $level, $($rest)+)
+    };
+
+    ($level:ident, $($rest:tt)+) => {
+        $crate::__log!(::std::module_path!(), $level, $($rest)+)
+    };
+}
+
+// The message forms of log!, once the target is known
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log {
+    // The filter check wraps everything else, so disabled calls never build
+    // the message, context or fields
+    (@emit $target:expr, $level:ident, $message:expr, $context:expr, $fields:expr) => {{
+        let level = $crate::__level!($level);
+        let target: &'static str = $target;
+        if $crate::enabled(level, target) {
+            $crate::__private::log(level, target, $message, $context, $fields, $crate::__location!());
+        }
+    }};
+
+    // Message with additional context
+    ($target:expr, $level:ident, $message:expr, context: $context:expr $(,)?) => {
+        $crate::__log!(@emit $target, $level,
+            ::std::string::ToString::to_string(&$message),
+            ::std::option::Option::Some(::std::string::ToString::to_string(&$context)),
+            ::std::vec::Vec::new()
+        )
+    };
+
+    // Message with structured key = value fields
+    ($target:expr, $level:ident, $message:expr; $($key:ident = $value:expr),+ $(,)?) => {
+        $crate::__log!(@emit $target, $level,
+            ::std::string::ToString::to_string(&$message),
+            ::std::option::Option::None,
+            ::std::vec![$((::std::stringify!($key), ::std::string::ToString::to_string(&$value))),+]
+        )
+    };
+
+    // Format string with optional arguments
+    ($target:expr, $level:ident, $format:literal $(, $($arg:tt)*)?) => {
+        $crate::__log!(@emit $target, $level,
+            ::std::format!($format $(, $($arg)*)?),
+            ::std::option::Option::None,
+            ::std::vec::Vec::new()
+        )
+    };
+
+    // Any other value that implements Display
+    ($target:expr, $level:ident, $message:expr $(,)?) => {
+        $crate::__log!(@emit $target, $level,
+            ::std::string::ToString::to_string(&$message),
+            ::std::option::Option::None,
+            ::std::vec::Vec::new()
+        )
+    };
+}
+
+/// Logs at [`Level::Trace`](crate::Level::Trace). Accepts the same forms as [`log!`].
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::log!(trace, $($arg)+)
+    };
+}
+
+/// Logs at [`Level::Debug`](crate::Level::Debug). Accepts the same forms as [`log!`].
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::log!(debug, $($arg)+)
+    };
+}
+
+/// Logs at [`Level::Info`](crate::Level::Info). Accepts the same forms as [`log!`].
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::log!(info, $($arg)+)
+    };
+}
+
+/// Logs at [`Level::Warn`](crate::Level::Warn). Accepts the same forms as [`log!`].
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => {
+        $crate::log!(warn, $($arg)+)
+    };
+}
+
+/// Logs at [`Level::Error`](crate::Level::Error). Accepts the same forms as [`log!`].
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => {
+        $crate::log!(error, $($arg)+)
+    };
+}
+
+// Turns the level identifier into a `Level` at compile time
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __level {
+    (trace) => {
+        $crate::Level::Trace
+    };
+    (debug) => {
+        $crate::Level::Debug
+    };
+    (info) => {
+        $crate::Level::Info
+    };
+    (warn) => {
+        $crate::Level::Warn
+    };
+    (error) => {
+        $crate::Level::Error
+    };
+    ($other:ident) => {
+        ::std::compile_error!(::std::concat!(
+            "unknown log level `",
+            ::std::stringify!($other),
+            "`, expected trace, debug, info, warn or error"
+        ))
+    };
+}
+
+// Expands at the call site, so env!/file!/line! describe the caller
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __location {
+    () => {
+        $crate::Location {
+            app: ::std::env!("CARGO_PKG_NAME"),
+            module_path: ::std::module_path!(),
+            file: ::std::file!(),
+            line: ::std::line!(),
+        }
+    };
+}
@@ -0,0 +1,47 @@
+use crate::Level;
+use chrono::{DateTime, Local};
+
+/// Where a log call was written, captured by the macros at compile time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    /// `CARGO_PKG_NAME` of the crate that called the macro.
+    pub app: &'static str,
+    /// `module_path!()` of the call site.
+    pub module_path: &'static str,
+    pub file: &'static str,
+    pub line: u32,
+}
+
+/// One log message, ready to be written.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub timestamp: DateTime<Local>,
+    pub level: Level,
+    /// What filters match against: `module_path!()` unless the call passed
+    /// `target: "..."`.
+    pub target: &'static str,
+    pub message: String,
+    /// Set by the `context:` form of the macros.
+    pub context: Option<String>,
+    /// Key-value pairs from the `message; key = value, ...` form, in call order.
+    pub fields: Vec<(&'static str, String)>,
+    pub location: Location,
+}
+
+impl Record {
+    pub fn new(
+        level: Level,
+        target: &'static str,
+        message: String,
+        context: Option<String>,
+        fields: Vec<(&'static str, String)>,
+        location: Location,
+    ) -> Self {
+        Record { timestamp: Local::now(), level, target, message, context, fields, location }
+    }
+
+    /// Looks up a field by key.
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields.iter().find(|(k, _)| *k == key).map(|(_, value)| value.as_str())
+    }
+}
@@ -0,0 +1,31 @@
+// Uses the macros the way another crate would: through their exported
+// paths only, with nothing else from mini_logger in scope.
+
+#[test]
+fn macros_work_from_another_crate() {
+    let attempts = 3;
+    let records = mini_logger::capture(|| {
+        mini_logger::error!("failed after {} attempts", attempts);
+        mini_logger::info!("saved", context: "settings");
+    });
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].level, mini_logger::Level::Error);
+    assert_eq!(records[0].message, "failed after 3 attempts");
+    assert_eq!(records[1].context.as_deref(), Some("settings"));
+    assert_eq!(records[1].location.module_path, "exported_macros");
+    assert!(records[1].location.file.ends_with("exported_macros.rs"));
+}
+
+#[test]
+fn imported_macros_do_not_need_the_crate_in_scope() {
+    use mini_logger::{debug, warn};
+
+    let records = mini_logger::capture(|| {
+        warn!("imported");
+        debug!(target: "elsewhere", "also imported");
+    });
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].target, "exported_macros");
+    assert_eq!(records[1].target, "elsewhere");
+}
@@ -0,0 +1,49 @@
+// Reading criterion's results back, for the tables the benchmarks print
+// after a run.
+//
+// Criterion stores each result in <criterion dir>/<group>/<function>/<parameter>/new/estimates.json.
+// The directory follows criterion's own lookup: $CRITERION_HOME, then
+// $CARGO_TARGET_DIR/criterion, then <target dir>/criterion.
+
+// Each bench compiles its own copy of this module, and not every bench
+// formats times itself
+#![allow(dead_code)]
+
+use std::fmt::Display;
+use std::path::PathBuf;
+
+pub fn criterion_dir() -> PathBuf {
+    if let Some(home) = std::env::var_os("CRITERION_HOME") {
+        return PathBuf::from(home);
+    }
+    if let Some(target) = std::env::var_os("CARGO_TARGET_DIR") {
+        return PathBuf::from(target).join("criterion");
+    }
+    // CARGO_TARGET_TMPDIR is <target dir>/tmp
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .parent()
+        .map(|target| target.join("criterion"))
+        .unwrap_or_else(|| PathBuf::from("target/criterion"))
+}
+
+/// The mean time of `BenchmarkId::new(function, parameter)` in `group`, in
+/// nanoseconds. `None` if it has not been run.
+pub fn mean_nanos(group: &str, function: &str, parameter: impl Display) -> Option<f64> {
+    let path = criterion_dir()
+        .join(group)
+        .join(function)
+        .join(parameter.to_string())
+        .join("new/estimates.json");
+    let json: serde_json::Value = serde_json::from_slice(&std::fs::read(path).ok()?).ok()?;
+    json["mean"]["point_estimate"].as_f64()
+}
+
+pub fn format_nanos(nanos: f64) -> String {
+    if nanos >= 1_000_000.0 {
+        format!("{:.2} ms", nanos / 1_000_000.0)
+    } else if nanos >= 1_000.0 {
+        format!("{:.2} µs", nanos / 1_000.0)
+    } else {
+        format!("{:.1} ns", nanos)
+    }
+}
@@ -0,0 +1,253 @@
+// ===== DISPATCH BENCHMARKS =====
+//
+// Backs up the "PERFORMANCE COMPARISON" printed by traits.rs by rendering the
+// same Printable items through four dispatch strategies:
+// • static:   <T: Printable> over homogeneous slices (monomorphized, inlinable)
+// • enum:     PrintableItem + match (closed set, stored inline)
+// • dyn_ref:  &dyn Printable (vtable call, items live elsewhere)
+// • box_dyn:  Box<dyn Printable> (vtable call + one heap allocation per item)
+//
+// Two groups:
+// • dispatch_render:    Calling render() on 300 items - dispatch cost vs real work
+// • dispatch_construct: Building the collection - where Box pays for allocations
+//
+// Run with: cargo bench -p rust-basics --bench dispatch_benchmarks
+// A markdown table summarising the mean times is printed at the end.
+//
+// READING THE RESULTS:
+// • A vtable call costs a few nanoseconds; next to real work (formatting a
+//   String) the render strategies land within noise of each other
+// • The measurable price of Box<dyn> is the per-item allocation at construction
+
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use rust_basics::traits::{Article, Book, Printable, PrintableItem, Tweet};
+use std::hint::black_box;
+use std::ops::Deref;
+
+mod common;
+use common::{criterion_dir, format_nanos};
+
+const ITEMS_PER_TYPE: usize = 100;
+const GROUPS: [&str; 2] = ["dispatch_render", "dispatch_construct"];
+const STRATEGIES: [&str; 4] = ["static", "enum", "dyn_ref", "box_dyn"];
+
+// ===== SAMPLE DATA =====
+
+fn article(i: usize) -> Article {
+    Article {
+        title: format!("Understanding Traits, part {}", i),
+        content: "Traits are a fundamental concept in Rust...".to_string(),
+        author: "Rust Developer".to_string(),
+    }
+}
+
+fn tweet(i: usize) -> Tweet {
+    Tweet {
+        username: "rustlang".to_string(),
+        content: format!("Release notes #{} are out! 🦀", i),
+        reply_to: i.is_multiple_of(2).then(|| "ferris".to_string()),
+    }
+}
+
+fn book(i: usize) -> Book {
+    Book {
+        title: "Programming Rust".to_string(),
+        author: "Jim Blandy".to_string(),
+        pages: 400 + i as u32,
+    }
+}
+
+struct Samples {
+    articles: Vec<Article>,
+    tweets: Vec<Tweet>,
+    books: Vec<Book>,
+}
+
+impl Samples {
+    fn new() -> Self {
+        Samples {
+            articles: (0..ITEMS_PER_TYPE).map(article).collect(),
+            tweets: (0..ITEMS_PER_TYPE).map(tweet).collect(),
+            books: (0..ITEMS_PER_TYPE).map(book).collect(),
+        }
+    }
+
+    // Interleaved so the dynamic strategies see a realistic mix of types
+    fn enums(&self) -> Vec<PrintableItem> {
+        (0..ITEMS_PER_TYPE)
+            .flat_map(|i| {
+                [
+                    PrintableItem::Article(self.articles[i].clone()),
+                    PrintableItem::Tweet(self.tweets[i].clone()),
+                    PrintableItem::Book(book(i)),
+                ]
+            })
+            .collect()
+    }
+
+    fn dyn_refs(&self) -> Vec<&dyn Printable> {
+        (0..ITEMS_PER_TYPE)
+            .flat_map(|i| {
+                [
+                    &self.articles[i] as &dyn Printable,
+                    &self.tweets[i],
+                    &self.books[i],
+                ]
+            })
+            .collect()
+    }
+
+    fn boxed(&self) -> Vec<Box<dyn Printable>> {
+        (0..ITEMS_PER_TYPE)
+            .flat_map(|i| -> [Box<dyn Printable>; 3] {
+                [
+                    Box::new(self.articles[i].clone()),
+                    Box::new(self.tweets[i].clone()),
+                    Box::new(book(i)),
+                ]
+            })
+            .collect()
+    }
+}
+
+// ===== RENDER LOOPS =====
+
+// Monomorphized per T - the call to render() can be inlined
+fn render_slice<T: Printable>(items: &[T], buffer: &mut String) -> usize {
+    let mut total = 0;
+    for item in items {
+        buffer.clear();
+        item.render(buffer);
+        total += buffer.len();
+    }
+    total
+}
+
+// Works for &dyn and Box<dyn> alike - every call goes through the vtable
+fn render_dyn<'a, P: Deref<Target = dyn Printable + 'a>>(
+    items: &[P],
+    buffer: &mut String,
+) -> usize {
+    let mut total = 0;
+    for item in items {
+        buffer.clear();
+        item.render(buffer);
+        total += buffer.len();
+    }
+    total
+}
+
+// ===== BENCHMARKS =====
+
+fn bench_render(c: &mut Criterion) {
+    let samples = Samples::new();
+    let enums = samples.enums();
+    let dyn_refs = samples.dyn_refs();
+    let boxed = samples.boxed();
+    let items = ITEMS_PER_TYPE * 3;
+
+    let mut group = c.benchmark_group("dispatch_render");
+    let mut buffer = String::with_capacity(128);
+
+    group.bench_function(BenchmarkId::new("static", items), |b| {
+        b.iter(|| {
+            render_slice(black_box(&samples.articles), &mut buffer)
+                + render_slice(black_box(&samples.tweets), &mut buffer)
+                + render_slice(black_box(&samples.books), &mut buffer)
+        })
+    });
+    group.bench_function(BenchmarkId::new("enum", items), |b| {
+        b.iter(|| render_slice(black_box(&enums), &mut buffer))
+    });
+    group.bench_function(BenchmarkId::new("dyn_ref", items), |b| {
+        b.iter(|| render_dyn(black_box(&dyn_refs), &mut buffer))
+    });
+    group.bench_function(BenchmarkId::new("box_dyn", items), |b| {
+        b.iter(|| render_dyn(black_box(&boxed), &mut buffer))
+    });
+
+    group.finish();
+}
+
+fn bench_construct(c: &mut Criterion) {
+    let samples = Samples::new();
+    let items = ITEMS_PER_TYPE * 3;
+
+    let mut group = c.benchmark_group("dispatch_construct");
+
+    // Owning strategies clone the same data; dyn_ref only borrows it, which is
+    // exactly why &dyn is cheap to build but can't outlive the originals
+    group.bench_function(BenchmarkId::new("static", items), |b| {
+        b.iter(|| {
+            (
+                black_box(&samples.articles).clone(),
+                black_box(&samples.tweets).clone(),
+                (0..ITEMS_PER_TYPE).map(book).collect::<Vec<_>>(),
+            )
+        })
+    });
+    group.bench_function(BenchmarkId::new("enum", items), |b| {
+        b.iter(|| black_box(&samples).enums())
+    });
+    group.bench_function(BenchmarkId::new("dyn_ref", items), |b| {
+        b.iter(|| black_box(&samples).dyn_refs())
+    });
+    group.bench_function(BenchmarkId::new("box_dyn", items), |b| {
+        b.iter(|| black_box(&samples).boxed())
+    });
+
+    group.finish();
+}
+
+// ===== RESULTS TABLE =====
+
+fn mean_nanos(group: &str, strategy: &str) -> Option<f64> {
+    common::mean_nanos(group, strategy, ITEMS_PER_TYPE * 3)
+}
+
+fn print_results_table() {
+    println!(
+        "\n## Dispatch benchmark results ({} items)\n",
+        ITEMS_PER_TYPE * 3
+    );
+    println!("| group | strategy | mean | per item | vs static |");
+    println!("|---|---|---|---|---|");
+
+    for group in GROUPS {
+        let Some(baseline) = mean_nanos(group, "static") else {
+            println!(
+                "| {} | (no results found in {}) | | | |",
+                group,
+                criterion_dir().display()
+            );
+            continue;
+        };
+        for strategy in STRATEGIES {
+            let Some(mean) = mean_nanos(group, strategy) else {
+                continue;
+            };
+            println!(
+                "| {} | {} | {} | {} | {:.2}x |",
+                group,
+                strategy,
+                format_nanos(mean),
+                format_nanos(mean / (ITEMS_PER_TYPE * 3) as f64),
+                mean / baseline
+            );
+        }
+    }
+}
+
+criterion_group!(benches, bench_render, bench_construct);
+
+// Expanded criterion_main! so the table can be printed after the run
+fn main() {
+    benches();
+
+    Criterion::default().configure_from_args().final_summary();
+
+    // `cargo test --benches` runs each benchmark once without measuring
+    if std::env::args().any(|arg| arg == "--bench") {
+        print_results_table();
+    }
+}
@@ -0,0 +1,51 @@
+// ===== PARSING BENCHMARKS =====
+//
+// Backs up the comparison in parsing.rs: the same log lines parsed three
+// ways, plus the cost of compiling a regex on every call.
+// • parse_log_lines: regex vs hand-rolled vs nom over the sample lines
+// • regex_compile:   a LazyLock'd regex vs Regex::new per call
+//
+// Run with: cargo bench -p rust-basics --bench parsing_benchmarks
+//
+// READING THE RESULTS:
+// • Hand-rolled and nom both walk the line once with no backtracking and
+//   usually land close together; the regex pays for its general-purpose
+//   engine and for resolving capture groups
+// • Compiling per call is orders of magnitude slower than any of them
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rust_basics::parsing::{
+    SAMPLE_LINES, parse_by_hand, parse_with_nom, parse_with_regex, parse_with_regex_compiled_per_call,
+};
+use std::hint::black_box;
+
+fn bench_parsers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_log_lines");
+    let lines = SAMPLE_LINES.len();
+
+    group.bench_function(BenchmarkId::new("regex", lines), |b| {
+        b.iter(|| SAMPLE_LINES.iter().filter(|line| parse_with_regex(black_box(line)).is_ok()).count())
+    });
+    group.bench_function(BenchmarkId::new("hand", lines), |b| {
+        b.iter(|| SAMPLE_LINES.iter().filter(|line| parse_by_hand(black_box(line)).is_ok()).count())
+    });
+    group.bench_function(BenchmarkId::new("nom", lines), |b| {
+        b.iter(|| SAMPLE_LINES.iter().filter(|line| parse_with_nom(black_box(line)).is_ok()).count())
+    });
+
+    group.finish();
+}
+
+fn bench_compile(c: &mut Criterion) {
+    let mut group = c.benchmark_group("regex_compile");
+
+    group.bench_function("compiled_once", |b| b.iter(|| parse_with_regex(black_box(SAMPLE_LINES[0])).is_ok()));
+    group.bench_function("compiled_per_call", |b| {
+        b.iter(|| parse_with_regex_compiled_per_call(black_box(SAMPLE_LINES[0])))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parsers, bench_compile);
+criterion_main!(benches);
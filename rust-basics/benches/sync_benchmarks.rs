@@ -0,0 +1,307 @@
+// ===== SYNCHRONIZATION BENCHMARKS =====
+//
+// smart_pointers.rs shares state between threads the simplest way: an
+// Arc<Mutex<T>> inside SharedCounter and SharedData. This measures what that
+// costs against the alternatives, as the number of threads fighting over the
+// same data grows:
+// • mutex:   the real SharedCounter / SharedData (Arc<Mutex<T>>)
+// • rwlock:  Arc<RwLock<T>> - many readers or one writer
+// • atomic:  Arc<AtomicI64> - no lock at all (counters only)
+// • sharded: one slot per thread, cache-line padded, combined when read
+//
+// Two groups, each run with 1, 2, 4 and 8 threads:
+// • sync_counter:   every thread increments the shared counter OPS times
+// • sync_read_mostly: SharedData with 1 push per 9 get_sum() reads
+//
+// Each sample spawns the threads once (inside thread::scope) and times the
+// whole batch, so the id's time is for threads × OPS operations. Criterion
+// also reports it as throughput (elements/s).
+//
+// Run with: cargo bench -p rust-basics --bench sync_benchmarks
+// A markdown table with the time per operation is printed at the end.
+//
+// READING THE RESULTS:
+// • Uncontended (1 thread), a Mutex lock/unlock is a pair of atomic
+//   instructions - ~10-20ns. An atomic increment is one, and a sharded
+//   local slot is barely more than a plain add
+// • With more threads on more cores, every strategy that touches ONE
+//   memory location (mutex, rwlock, atomic) slows down per operation: the
+//   cache line holding it must move between cores on every write. A lock
+//   adds waiting (and possibly sleeping) on top of that
+// • sharded stays flat because each thread writes its own cache line. The
+//   price is paid on reads - get() has to visit every shard - and in memory
+// • RwLock only wins when reads dominate AND each read holds the lock long
+//   enough (get_sum over a growing Vec) for overlap to matter. For a single
+//   integer, its extra bookkeeping makes it slower than a Mutex
+// • sync_read_mostly gets slower per operation with more threads even
+//   without contention: every thread pushes OPS / 10 values, so with 8
+//   threads each get_sum() adds up a Vec several times longer
+// • On a machine with few cores (`nproc`), threads take turns instead of
+//   running at once, so there is little true contention: expect the
+//   thread counts to look alike per operation, with mutex/rwlock paying
+//   occasionally for a thread preempted while holding the lock
+
+use criterion::measurement::WallTime;
+use criterion::{BenchmarkGroup, BenchmarkId, Criterion, Throughput, criterion_group};
+use rust_basics::smart_pointers::{SharedCounter, SharedData};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+mod common;
+use common::{criterion_dir, mean_nanos};
+
+const OPS: usize = 10_000;
+const THREADS: [usize; 4] = [1, 2, 4, 8];
+const GROUPS: [&str; 2] = ["sync_counter", "sync_read_mostly"];
+const STRATEGIES: [&str; 4] = ["mutex", "rwlock", "atomic", "sharded"];
+
+// ===== COUNTERS =====
+//
+// The calls SharedCounter and SharedData offer, so every strategy runs the
+// identical workload. `slot` tells sharded implementations which thread is
+// calling; the shared ones ignore it.
+
+trait Counter: Send + Sync {
+    fn increment(&self, slot: usize);
+    fn get(&self) -> i64;
+}
+
+impl Counter for SharedCounter {
+    fn increment(&self, _slot: usize) {
+        SharedCounter::increment(self);
+    }
+
+    fn get(&self) -> i64 {
+        self.get_count().into()
+    }
+}
+
+#[derive(Default)]
+struct RwLockCounter(Arc<RwLock<i64>>);
+
+impl Counter for RwLockCounter {
+    fn increment(&self, _slot: usize) {
+        *self.0.write().unwrap() += 1;
+    }
+
+    fn get(&self) -> i64 {
+        *self.0.read().unwrap()
+    }
+}
+
+#[derive(Default)]
+struct AtomicCounter(Arc<AtomicI64>);
+
+impl Counter for AtomicCounter {
+    // Relaxed is enough: the count isn't used to publish other data
+    fn increment(&self, _slot: usize) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn get(&self) -> i64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+// 64-byte alignment puts each slot on its own cache line. Without it, 8
+// AtomicI64s share one line and the cores fight over it anyway (false
+// sharing) - almost as slow as a single atomic
+#[repr(align(64))]
+#[derive(Default)]
+struct Padded<T>(T);
+
+struct ShardedCounter(Arc<[Padded<AtomicI64>]>);
+
+impl ShardedCounter {
+    fn new(shards: usize) -> Self {
+        ShardedCounter((0..shards).map(|_| Padded::default()).collect())
+    }
+}
+
+impl Counter for ShardedCounter {
+    fn increment(&self, slot: usize) {
+        self.0[slot % self.0.len()].0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn get(&self) -> i64 {
+        self.0.iter().map(|shard| shard.0.load(Ordering::Relaxed)).sum()
+    }
+}
+
+// ===== READ-MOSTLY DATA =====
+
+trait Data: Send + Sync {
+    fn add_value(&self, slot: usize, value: i32);
+    fn get_sum(&self) -> i32;
+}
+
+impl Data for SharedData {
+    fn add_value(&self, _slot: usize, value: i32) {
+        SharedData::add_value(self, value);
+    }
+
+    fn get_sum(&self) -> i32 {
+        SharedData::get_sum(self)
+    }
+}
+
+#[derive(Default)]
+struct RwLockData(Arc<RwLock<Vec<i32>>>);
+
+impl Data for RwLockData {
+    fn add_value(&self, _slot: usize, value: i32) {
+        self.0.write().unwrap().push(value);
+    }
+
+    fn get_sum(&self) -> i32 {
+        self.0.read().unwrap().iter().sum()
+    }
+}
+
+struct ShardedData(Arc<[Padded<Mutex<Vec<i32>>>]>);
+
+impl ShardedData {
+    fn new(shards: usize) -> Self {
+        ShardedData((0..shards).map(|_| Padded::default()).collect())
+    }
+}
+
+impl Data for ShardedData {
+    fn add_value(&self, slot: usize, value: i32) {
+        self.0[slot % self.0.len()].0.lock().unwrap().push(value);
+    }
+
+    // Locks each shard in turn: cheap, but not a snapshot of one moment
+    fn get_sum(&self) -> i32 {
+        self.0.iter().map(|shard| shard.0.lock().unwrap().iter().sum::<i32>()).sum()
+    }
+}
+
+// ===== WORKLOADS =====
+
+// Times `threads` threads each running `op(slot, i)` OPS times, started
+// together. Thread spawn cost is included but amortized over OPS calls
+fn run_threads(threads: usize, op: &(dyn Fn(usize, usize) + Sync)) -> Duration {
+    let start = Instant::now();
+    thread::scope(|scope| {
+        for slot in 0..threads {
+            scope.spawn(move || {
+                for i in 0..OPS {
+                    op(slot, i);
+                }
+            });
+        }
+    });
+    start.elapsed()
+}
+
+fn count<C: Counter>(counter: &C, threads: usize) -> Duration {
+    let elapsed = run_threads(threads, &|slot, _| counter.increment(slot));
+    black_box(counter.get());
+    elapsed
+}
+
+fn read_mostly<D: Data>(data: &D, threads: usize) -> Duration {
+    run_threads(threads, &|slot, i| {
+        if i % 10 == 0 {
+            data.add_value(slot, i as i32);
+        } else {
+            black_box(data.get_sum());
+        }
+    })
+}
+
+// Every sample starts from fresh state built by `make`, outside the timing,
+// so the SharedData Vecs don't keep growing from one sample to the next
+fn bench_strategy<S>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    strategy: &str,
+    threads: usize,
+    make: impl Fn() -> S,
+    run: impl Fn(&S, usize) -> Duration,
+) {
+    group.bench_function(BenchmarkId::new(strategy, threads), |b| {
+        b.iter_custom(|iters| (0..iters).map(|_| run(&make(), threads)).sum())
+    });
+}
+
+fn bench_counter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sync_counter");
+    for threads in THREADS {
+        group.throughput(Throughput::Elements((threads * OPS) as u64));
+        bench_strategy(&mut group, "mutex", threads, SharedCounter::new, count);
+        bench_strategy(&mut group, "rwlock", threads, RwLockCounter::default, count);
+        bench_strategy(&mut group, "atomic", threads, AtomicCounter::default, count);
+        bench_strategy(&mut group, "sharded", threads, || ShardedCounter::new(threads), count);
+    }
+    group.finish();
+}
+
+// 1000 values, so get_sum() has something to add up
+fn seeded<D: Data>(data: D, shards: usize) -> D {
+    for value in 0..1_000 {
+        data.add_value(value % shards, 1);
+    }
+    data
+}
+
+fn bench_read_mostly(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sync_read_mostly");
+    for threads in THREADS {
+        group.throughput(Throughput::Elements((threads * OPS) as u64));
+        bench_strategy(&mut group, "mutex", threads, || seeded(SharedData::new(), 1), read_mostly);
+        bench_strategy(&mut group, "rwlock", threads, || seeded(RwLockData::default(), 1), read_mostly);
+        bench_strategy(&mut group, "sharded", threads, || seeded(ShardedData::new(threads), threads), read_mostly);
+    }
+    group.finish();
+}
+
+// ===== RESULTS TABLE =====
+
+// Time per operation: the sample covers threads × OPS of them
+fn per_op(group: &str, strategy: &str, threads: usize) -> String {
+    mean_nanos(group, strategy, threads)
+        .map_or_else(|| "-".to_string(), |mean| format!("{:.1} ns", mean / (threads * OPS) as f64))
+}
+
+fn print_results_table() {
+    let cores = thread::available_parallelism().map_or(1, |n| n.get());
+    println!("\n## Synchronization benchmark results (time per operation, {} core(s))\n", cores);
+    let header: Vec<String> = THREADS.iter().map(|threads| format!("{} thread(s)", threads)).collect();
+    println!("| group | strategy | {} |", header.join(" | "));
+    println!("|---|---|{}", "---|".repeat(THREADS.len()));
+
+    for group in GROUPS {
+        if mean_nanos(group, "mutex", 1).is_none() {
+            println!("| {} | (no results found in {}) |", group, criterion_dir().display());
+            continue;
+        }
+        for strategy in STRATEGIES {
+            let cells: Vec<String> = THREADS.iter().map(|&threads| per_op(group, strategy, threads)).collect();
+            if cells.iter().all(|cell| cell == "-") {
+                continue;
+            }
+            println!("| {} | {} | {} |", group, strategy, cells.join(" | "));
+        }
+    }
+    if cores < *THREADS.last().unwrap_or(&1) {
+        println!("\nOnly {} core(s): the higher thread counts time-slice rather than contend.", cores);
+    }
+}
+
+criterion_group!(benches, bench_counter, bench_read_mostly);
+
+// Expanded criterion_main! so the table can be printed after the run
+fn main() {
+    benches();
+
+    Criterion::default().configure_from_args().final_summary();
+
+    // `cargo test --benches` runs each benchmark once without measuring
+    if std::env::args().any(|arg| arg == "--bench") {
+        print_results_table();
+    }
+}
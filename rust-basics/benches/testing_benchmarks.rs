@@ -0,0 +1,41 @@
+// ===== CRITERION BENCHMARKS =====
+//
+// Run with: cargo bench -p rust-basics --bench testing_benchmarks
+// Reports land in target/criterion/ (HTML with plots).
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rust_basics::testing::{
+    fibonacci_iterative, fibonacci_recursive, parse_duration, run_length_encode,
+};
+use std::hint::black_box;
+
+// Comparing two implementations over several input sizes
+fn bench_fibonacci(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fibonacci");
+
+    for n in [10u32, 15, 20] {
+        group.bench_with_input(BenchmarkId::new("recursive", n), &n, |b, &n| {
+            b.iter(|| fibonacci_recursive(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("iterative", n), &n, |b, &n| {
+            b.iter(|| fibonacci_iterative(black_box(n)))
+        });
+    }
+
+    group.finish();
+}
+
+// Single-function benchmarks
+fn bench_parsing(c: &mut Criterion) {
+    c.bench_function("parse_duration 1h30m15s", |b| {
+        b.iter(|| parse_duration(black_box("1h30m15s")))
+    });
+
+    let text = "aaaabbbcccccccccdde".repeat(50);
+    c.bench_function("run_length_encode 950 chars", |b| {
+        b.iter(|| run_length_encode(black_box(&text)))
+    });
+}
+
+criterion_group!(benches, bench_fibonacci, bench_parsing);
+criterion_main!(benches);
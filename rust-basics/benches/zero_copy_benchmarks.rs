@@ -0,0 +1,69 @@
+// ===== ZERO-COPY BENCHMARKS =====
+//
+// Backs up the claim in zero_copy.rs: returning Cow<str> instead of String
+// saves one allocation per field that needs no unescaping. Each parser has
+// an always-allocate twin sharing its scanning code, so the difference is
+// the allocations alone.
+// • csv_plain:     1000 rows where only every 10th row has an escaped field
+// • csv_escaped:   1000 rows where every field is quoted with "" inside
+// • query_string:  a typical query string, few escapes
+//
+// Run with: cargo bench -p rust-basics --bench zero_copy_benchmarks
+//
+// READING THE RESULTS:
+// • csv_plain: cow should win clearly - owned makes ~4 allocations per row,
+//   cow makes one every 10 rows
+// • csv_escaped: both allocate for every field, so they land close
+//   together - Cow only pays off when borrowing is the common case
+// • Both still allocate the Vec that holds each row's fields; reusing one
+//   Vec across rows would shave that off too
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use rust_basics::zero_copy::{SAMPLE_QUERY, parse_csv, parse_csv_owned, parse_query, parse_query_owned};
+use std::hint::black_box;
+
+const ROWS: usize = 1_000;
+
+fn plain_csv() -> String {
+    (0..ROWS)
+        .map(|row| {
+            if row % 10 == 0 {
+                format!("{},user{},\"said \"\"hi\"\"\",{}\n", row, row, row * 7)
+            } else {
+                format!("{},user{},\"Springfield, IL\",{}\n", row, row, row * 7)
+            }
+        })
+        .collect()
+}
+
+fn escaped_csv() -> String {
+    (0..ROWS).map(|row| format!("\"{}\"\"\",\"u\"\"{}\",\"\"\"q\"\"\",\"{}\"\"\"\n", row, row, row * 7)).collect()
+}
+
+fn bench_csv(c: &mut Criterion) {
+    for (group_name, input) in [("csv_plain", plain_csv()), ("csv_escaped", escaped_csv())] {
+        let mut group = c.benchmark_group(group_name);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+
+        group.bench_function(BenchmarkId::new("cow", ROWS), |b| {
+            b.iter(|| parse_csv(black_box(&input)).map(|rows| rows.len()))
+        });
+        group.bench_function(BenchmarkId::new("owned", ROWS), |b| {
+            b.iter(|| parse_csv_owned(black_box(&input)).map(|rows| rows.len()))
+        });
+
+        group.finish();
+    }
+}
+
+fn bench_query(c: &mut Criterion) {
+    let mut group = c.benchmark_group("query_string");
+
+    group.bench_function("cow", |b| b.iter(|| parse_query(black_box(SAMPLE_QUERY)).map(|pairs| pairs.len())));
+    group.bench_function("owned", |b| b.iter(|| parse_query_owned(black_box(SAMPLE_QUERY)).map(|pairs| pairs.len())));
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_csv, bench_query);
+criterion_main!(benches);
@@ -0,0 +1,56 @@
+// ===== EXERCISE: ACTORS - A BANK ACCOUNT THREAD =====
+//
+// The actor pattern with std threads instead of tokio tasks: one thread owns
+// the balance, and AccountHandle turns method calls into messages.
+//
+// REQUIREMENTS:
+// • spawn() starts a thread that owns a balance of 0 and handles requests
+//   one at a time until every handle is dropped
+// • deposit(amount) adds to the balance and returns the new balance
+// • withdraw(amount) returns Err(InsufficientFunds) and leaves the balance
+//   alone if there isn't enough; otherwise it returns the new balance
+// • balance() returns the current balance
+// • Handles are Clone, and clones talk to the same account
+//
+// HINT: Each Request carries a std::sync::mpsc::Sender for its reply; the
+// handle creates a fresh channel per call and waits on its Receiver.
+
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+
+#[derive(Debug, PartialEq)]
+pub struct InsufficientFunds;
+
+pub enum Request {
+    Deposit(u64, Sender<u64>),
+    Withdraw(u64, Sender<Result<u64, InsufficientFunds>>),
+    Balance(Sender<u64>),
+}
+
+#[derive(Clone)]
+pub struct AccountHandle {
+    mailbox: Sender<Request>,
+}
+
+impl AccountHandle {
+    pub fn spawn() -> AccountHandle {
+        let (mailbox, requests) = mpsc::channel::<Request>();
+        thread::spawn(move || {
+            let mut balance = 0u64;
+            todo!()
+        });
+        AccountHandle { mailbox }
+    }
+
+    pub fn deposit(&self, amount: u64) -> u64 {
+        todo!()
+    }
+
+    pub fn withdraw(&self, amount: u64) -> Result<u64, InsufficientFunds> {
+        todo!()
+    }
+
+    pub fn balance(&self) -> u64 {
+        todo!()
+    }
+}
@@ -0,0 +1,36 @@
+// ===== EXERCISE: ASYNC/AWAIT - A HAND-WRITTEN FUTURE =====
+//
+// Implement a future that needs to be polled several times before it is done.
+//
+// REQUIREMENTS:
+// • ReadyAfter::new(n, value) completes on the (n + 1)-th poll with `value`
+//   (n = 0 means ready on the first poll)
+// • Every time it returns Poll::Pending it must call wake_by_ref() on the
+//   context's waker, otherwise a real executor would never poll it again
+// • It never needs to be polled again after returning Ready
+//
+// HINT: Keep a counter in the struct; ReadyAfter is Unpin, so
+// `self.get_mut()` (or plain field access) works inside poll().
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub struct ReadyAfter<T> {
+    remaining: u32,
+    value: Option<T>,
+}
+
+impl<T> ReadyAfter<T> {
+    pub fn new(polls: u32, value: T) -> Self {
+        ReadyAfter { remaining: polls, value: Some(value) }
+    }
+}
+
+impl<T: Unpin> Future for ReadyAfter<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        todo!()
+    }
+}
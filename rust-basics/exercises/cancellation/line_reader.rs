@@ -0,0 +1,88 @@
+// ===== EXERCISE: CANCELLATION - A CANCEL-SAFE LINE READER =====
+//
+// read_line_buggy below reads bytes up to '\n'. It works when awaited to
+// the end, but it keeps the line so far in a local Vec: put it in a
+// select! loop next to a timer and every time the timer wins, the bytes it
+// already took from the source are dropped with the future. The next call
+// starts mid-line.
+//
+// Fix it: LineReader::next_line must survive being cancelled at any
+// .await and pick up where the cancelled call stopped.
+//
+// REQUIREMENTS:
+// • next_line() returns the next line without its '\n', or None once the
+//   source is empty and no partial line is left
+// • A final line without a trailing '\n' is still returned
+// • Dropping a next_line() future part-way through loses no bytes: the
+//   next call returns the whole line
+// • ByteSource::next_byte is cancel-safe already - don't change it
+//
+// HINT: Anything that must outlive one call belongs in self, not in a
+// local. std::mem::take(&mut self.buffer) hands the line out and leaves an
+// empty Vec behind.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub struct ByteSource {
+    bytes: VecDeque<u8>,
+}
+
+impl ByteSource {
+    pub fn new(text: &str) -> Self {
+        ByteSource { bytes: text.bytes().collect() }
+    }
+
+    // Like a socket read: not ready on the first poll. The byte is only
+    // removed when the future completes, so dropping it early is harmless
+    pub fn next_byte(&mut self) -> NextByte<'_> {
+        NextByte { source: self, polled: false }
+    }
+}
+
+pub struct NextByte<'a> {
+    source: &'a mut ByteSource,
+    polled: bool,
+}
+
+impl Future for NextByte<'_> {
+    type Output = Option<u8>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u8>> {
+        if !self.polled {
+            self.polled = true;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        Poll::Ready(self.source.bytes.pop_front())
+    }
+}
+
+// NOT cancel-safe: `line` lives in the future
+pub async fn read_line_buggy(source: &mut ByteSource) -> Option<String> {
+    let mut line = Vec::new();
+    while let Some(byte) = source.next_byte().await {
+        if byte == b'\n' {
+            return Some(String::from_utf8_lossy(&line).into_owned());
+        }
+        line.push(byte);
+    }
+    if line.is_empty() { None } else { Some(String::from_utf8_lossy(&line).into_owned()) }
+}
+
+pub struct LineReader {
+    source: ByteSource,
+    buffer: Vec<u8>,
+}
+
+impl LineReader {
+    pub fn new(source: ByteSource) -> Self {
+        LineReader { source, buffer: Vec::new() }
+    }
+
+    pub async fn next_line(&mut self) -> Option<String> {
+        todo!()
+    }
+}
@@ -0,0 +1,17 @@
+// ===== EXERCISE: COLLECTIONS - TOP WORDS =====
+//
+// Count words and return the most frequent ones.
+//
+// REQUIREMENTS:
+// • Words are split on whitespace and compared case-insensitively
+//   (count "Rust" and "rust" as "rust")
+// • Return at most k (word, count) pairs, highest count first
+// • Ties are broken alphabetically (a before b)
+//
+// HINT: HashMap's entry().or_insert(0), then sort a Vec of the pairs.
+
+use std::collections::HashMap;
+
+pub fn top_words(text: &str, k: usize) -> Vec<(String, usize)> {
+    todo!()
+}
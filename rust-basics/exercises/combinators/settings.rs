@@ -0,0 +1,37 @@
+// ===== EXERCISE: COMBINATORS - READING SETTINGS =====
+//
+// Settings arrive as strings in a HashMap (from a config file, say). Each
+// function below reads one of them. Every one can be written as a single
+// combinator chain - try it without a match or an if let.
+//
+// REQUIREMENTS:
+// • require(settings, key) returns the value, or Err("missing setting: <key>")
+// • port(settings) parses "port" if present: Ok(None) when absent,
+//   Ok(Some(n)) when it parses as a u16, Err when it doesn't
+// • timeout_secs(settings) parses "timeout" as a u64, falling back to 30
+//   when it is missing OR doesn't parse
+// • endpoint(settings) is Some("<host>:<port>") only when both "host" and
+//   "port" are set (port as written, not parsed)
+//
+// HINT: settings.get(key) gives Option<&String>; .map(String::as_str) makes
+// it an Option<&str>. Then: ok_or_else for require, map + transpose for
+// port, and_then + ok() + unwrap_or for timeout_secs, zip for endpoint.
+
+use std::collections::HashMap;
+use std::num::ParseIntError;
+
+pub fn require<'a>(settings: &'a HashMap<String, String>, key: &str) -> Result<&'a str, String> {
+    todo!()
+}
+
+pub fn port(settings: &HashMap<String, String>) -> Result<Option<u16>, ParseIntError> {
+    todo!()
+}
+
+pub fn timeout_secs(settings: &HashMap<String, String>) -> u64 {
+    todo!()
+}
+
+pub fn endpoint(settings: &HashMap<String, String>) -> Option<String> {
+    todo!()
+}
@@ -0,0 +1,25 @@
+// ===== EXERCISE: CONCURRENCY - PARALLEL COUNTING =====
+//
+// Split work across threads safely.
+//
+// REQUIREMENTS:
+// • parallel_word_count(texts, threads): total number of whitespace-separated
+//   words in all texts, computed by splitting `texts` into at most `threads`
+//   chunks processed on separate threads (threads >= 1)
+// • shared_counter(threads, increments): spawn `threads` threads that each add
+//   1 to a shared counter `increments` times; return the final value
+//
+// HINT: std::thread::scope lets threads borrow `texts`; Arc<AtomicU64> (or
+// Arc<Mutex<u64>>) for the shared counter.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+
+pub fn parallel_word_count(texts: &[&str], threads: usize) -> usize {
+    todo!()
+}
+
+pub fn shared_counter(threads: usize, increments: u64) -> u64 {
+    todo!()
+}
@@ -0,0 +1,34 @@
+// ===== EXERCISE: CONST EVAL - COMPILE-TIME TABLES =====
+//
+// The signatures below are already const fn, so the compiler rejects any
+// body that couldn't run at compile time: no for loops, no iterator
+// adaptors, no Vec. Once they work, a line like
+//     const POPCOUNT: [u8; 256] = popcount_table();
+// builds the table while compiling.
+//
+// REQUIREMENTS:
+// • pow(base, exp) returns base^exp (exp = 0 gives 1)
+// • popcount_table() returns a table whose entry i is the number of 1 bits
+//   in the byte i: table[0] == 0, table[7] == 3, table[255] == 8
+// • count_ones(bytes) adds up the 1 bits in all the bytes, using the table
+// • contains(haystack, needle) is true if the byte appears in haystack
+//
+// HINT: let mut i = 0; while i < len { ...; i += 1; } replaces every for
+// loop. A byte's bit count is (i & 1) plus the count of i >> 1 - an entry
+// the table already has.
+
+pub const fn pow(base: u64, exp: u32) -> u64 {
+    todo!()
+}
+
+pub const fn popcount_table() -> [u8; 256] {
+    todo!()
+}
+
+pub const fn count_ones(bytes: &[u8]) -> u32 {
+    todo!()
+}
+
+pub const fn contains(haystack: &[u8], needle: u8) -> bool {
+    todo!()
+}
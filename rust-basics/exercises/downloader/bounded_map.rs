@@ -0,0 +1,35 @@
+// ===== EXERCISE: DOWNLOADER - A BOUNDED PARALLEL MAP =====
+//
+// The downloader's core idea without async: run a job for every input, with
+// at most `limit` jobs running at once, and keep every result - successes
+// and failures - in input order.
+//
+// REQUIREMENTS:
+// • bounded_map(inputs, limit, job) calls job(input) once per input, on
+//   scoped threads, and returns the results in the order of `inputs`
+// • Never more than `limit` jobs run at the same time; a limit of 0 is
+//   treated as 1
+// • A failing job doesn't stop the others: its Err lands in its slot
+// • summarize(results) returns (successes, failure messages), the failures
+//   formatted as "<index>: <error>" in input order
+//
+// HINT: Split the inputs into `limit` groups that each run on one thread,
+// or share an AtomicUsize "next index" that `limit` workers pull from.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+pub fn bounded_map<T, R, E, F>(inputs: &[T], limit: usize, job: F) -> Vec<Result<R, E>>
+where
+    T: Sync,
+    R: Send,
+    E: Send,
+    F: Fn(&T) -> Result<R, E> + Sync,
+{
+    todo!()
+}
+
+pub fn summarize<R, E: std::fmt::Display>(results: Vec<Result<R, E>>) -> (Vec<R>, Vec<String>) {
+    todo!()
+}
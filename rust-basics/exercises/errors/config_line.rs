@@ -0,0 +1,42 @@
+// ===== EXERCISE: ERRORS - CONFIG LINES =====
+//
+// Parse "name = port" lines into typed values, reporting precise errors.
+//
+// REQUIREMENTS:
+// • parse_port(s): trims s; Err(InvalidPort(s_trimmed)) if it isn't a number,
+//   Err(ZeroPort) for 0, otherwise Ok(port)
+// • parse_line(line): splits on the FIRST '='; Err(MissingEquals) if there is
+//   none; Err(EmptyName) if the trimmed name is empty; otherwise
+//   Ok((trimmed name, port)) using parse_port for the right-hand side
+// • Display for ConfigError is already written - don't change it
+//
+// HINT: split_once, str::parse::<u16>, map_err and the ? operator.
+
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    MissingEquals,
+    EmptyName,
+    InvalidPort(String),
+    ZeroPort,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::MissingEquals => write!(f, "expected 'name = port'"),
+            ConfigError::EmptyName => write!(f, "name is empty"),
+            ConfigError::InvalidPort(raw) => write!(f, "'{}' is not a valid port", raw),
+            ConfigError::ZeroPort => write!(f, "port 0 is reserved"),
+        }
+    }
+}
+
+pub fn parse_port(s: &str) -> Result<u16, ConfigError> {
+    todo!()
+}
+
+pub fn parse_line(line: &str) -> Result<(String, u16), ConfigError> {
+    todo!()
+}
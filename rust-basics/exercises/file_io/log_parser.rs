@@ -0,0 +1,68 @@
+// ===== EXERCISE: FILE IO - A LOG-FILE PARSER =====
+//
+// Parse lines like "1714564800 ERROR disk full" (seconds, level, message)
+// from any BufRead - a file, stdin or an in-memory Cursor.
+//
+// TaskError below mirrors the one in errors.rs, minus thiserror: exercises
+// compile with plain rustc.
+//
+// REQUIREMENTS:
+// • parse_line splits a line into seconds, level and message (the message is
+//   the rest of the line and may contain spaces)
+// • Fewer than three fields: ValidationError { message: "missing fields" }
+// • Seconds that aren't a u64: ParseError { input: <the seconds field> }
+// • A level other than INFO, WARN or ERROR:
+//   ValidationError { message: "unknown level '<level>'" }
+// • error_messages returns the messages of every ERROR line, in order,
+//   skipping blank lines. The first bad line stops it with that line's error;
+//   a failing reader stops it with TaskError::IoError
+//
+// HINT: With From<io::Error> for TaskError, `line?` does the IO conversion.
+
+use std::fmt;
+use std::io::{self, BufRead};
+
+#[derive(Debug)]
+pub enum TaskError {
+    ValidationError { message: String },
+    ParseError { input: String },
+    IoError(io::Error),
+}
+
+impl fmt::Display for TaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskError::ValidationError { message } => write!(f, "Invalid input: {}", message),
+            TaskError::ParseError { input } => write!(f, "Failed to parse number: {}", input),
+            TaskError::IoError(_) => write!(f, "IO operation failed"),
+        }
+    }
+}
+
+impl From<io::Error> for TaskError {
+    fn from(e: io::Error) -> Self {
+        TaskError::IoError(e)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Level {
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct LogEntry {
+    pub seconds: u64,
+    pub level: Level,
+    pub message: String,
+}
+
+pub fn parse_line(line: &str) -> Result<LogEntry, TaskError> {
+    todo!()
+}
+
+pub fn error_messages<R: BufRead>(reader: R) -> Result<Vec<String>, TaskError> {
+    todo!()
+}
@@ -0,0 +1,55 @@
+// ===== EXERCISE: INSTRUMENTATION - A TINY SPAN STACK =====
+//
+// The core idea behind tracing's spans, without the crate: a Tracer keeps a
+// stack of the spans currently entered, and every event is written with the
+// path of spans it happened in.
+//
+//   let tracer = Tracer::default();
+//   let _request = tracer.enter("request");
+//   {
+//       let _db = tracer.enter("db");
+//       tracer.event("DEBUG", "query");     // "DEBUG request:db: query"
+//   }                                       // _db dropped - exits "db"
+//   tracer.event("INFO", "done");           // "INFO request: done"
+//
+// REQUIREMENTS:
+// • enter(name) pushes the span and returns a guard; dropping the guard
+//   exits the span again
+// • event(level, message) appends one line: "LEVEL span:span: message",
+//   outermost span first, or just "LEVEL message" outside every span
+// • lines() returns everything recorded so far, oldest first
+//
+// HINT: The guard holds &Tracer, and enter/event only take &self - keep the
+// stack and the lines in RefCells. Drop for SpanGuard pops the stack.
+
+use std::cell::RefCell;
+
+#[derive(Debug, Default)]
+pub struct Tracer {
+    stack: RefCell<Vec<String>>,
+    lines: RefCell<Vec<String>>,
+}
+
+pub struct SpanGuard<'a> {
+    tracer: &'a Tracer,
+}
+
+impl Tracer {
+    pub fn enter(&self, name: &str) -> SpanGuard<'_> {
+        todo!()
+    }
+
+    pub fn event(&self, level: &str, message: &str) {
+        todo!()
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.lines.borrow().clone()
+    }
+}
+
+impl Drop for SpanGuard<'_> {
+    fn drop(&mut self) {
+        todo!()
+    }
+}
@@ -0,0 +1,48 @@
+// ===== EXERCISE: ITERATORS - AN INTERLEAVE ADAPTOR =====
+//
+// Build an adaptor that alternates between two iterators, and attach it to
+// every iterator with an extension trait - the way iterators.rs adds
+// chunked() and windowed().
+//
+// REQUIREMENTS:
+// • a.interleave(b) yields a1, b1, a2, b2, ...
+// • When one side runs out, the rest of the other follows:
+//   [1, 2, 3].interleave([10]) → 1, 10, 2, 3
+// • Lazy: nothing is pulled from either side until next() is called, and
+//   each next() pulls only what it returns (plus a None from a side that
+//   just ran out)
+// • Fused: once both sides have returned None, next() returns None forever
+//   and never calls either side again - even if a side would resume
+//
+// HINT: Two `done` flags and a `b_next` flag for whose turn it is.
+
+pub struct Interleave<A, B> {
+    a: A,
+    b: B,
+    a_done: bool,
+    b_done: bool,
+    b_next: bool,
+}
+
+impl<A, B> Iterator for Interleave<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        todo!()
+    }
+}
+
+pub trait InterleaveExt: Iterator + Sized {
+    fn interleave<B>(self, other: B) -> Interleave<Self, B::IntoIter>
+    where
+        B: IntoIterator<Item = Self::Item>,
+    {
+        todo!()
+    }
+}
+
+impl<I: Iterator> InterleaveExt for I {}
@@ -0,0 +1,47 @@
+// ===== EXERCISE: KV STORE - AN EXPIRING MAP =====
+//
+// The heart of the kv_store capstone without the locks or the sockets: a
+// map whose entries can expire. Time is a plain tick counter passed into
+// every call, so tests never sleep - the same idea as kv_store's Clock.
+//
+// REQUIREMENTS:
+// • set(key, value, ttl, now) stores the value; with Some(ttl) the entry
+//   expires at tick now + ttl, with None it never expires. Setting a key
+//   again replaces both its value and its expiry
+// • get(key, now) returns the value only while now < expiry, so a ttl of 0
+//   expires at once
+// • len(now) counts live entries only
+// • purge(now) removes expired entries from memory and returns how many it
+//   removed; live entries are untouched
+//
+// HINT: Store (value, Option<u64>) pairs and write one is_live helper that
+// every method uses.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct ExpiringMap {
+    entries: HashMap<String, (String, Option<u64>)>,
+}
+
+impl ExpiringMap {
+    pub fn new() -> Self {
+        ExpiringMap::default()
+    }
+
+    pub fn set(&mut self, key: &str, value: &str, ttl: Option<u64>, now: u64) {
+        todo!()
+    }
+
+    pub fn get(&self, key: &str, now: u64) -> Option<&str> {
+        todo!()
+    }
+
+    pub fn len(&self, now: u64) -> usize {
+        todo!()
+    }
+
+    pub fn purge(&mut self, now: u64) -> usize {
+        todo!()
+    }
+}
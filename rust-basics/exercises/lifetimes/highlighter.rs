@@ -0,0 +1,26 @@
+// ===== EXERCISE: LIFETIMES - HIGHLIGHTER =====
+//
+// Return slices that borrow from the input instead of allocating new Strings.
+//
+// REQUIREMENTS:
+// • longest_word(text): the longest whitespace-separated word; on a tie the
+//   FIRST one wins; "" for empty input
+// • Highlighter::first_sentence(): everything up to and including the first
+//   '.', or the whole text if there is none
+// • The returned &str must outlive the Highlighter itself (see the signature)
+//
+// HINT: `'a` on the return type ties it to the text, not to `&self`.
+
+pub fn longest_word<'a>(text: &'a str) -> &'a str {
+    todo!()
+}
+
+pub struct Highlighter<'a> {
+    pub text: &'a str,
+}
+
+impl<'a> Highlighter<'a> {
+    pub fn first_sentence(&self) -> &'a str {
+        todo!()
+    }
+}
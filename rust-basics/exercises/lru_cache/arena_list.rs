@@ -0,0 +1,67 @@
+// ===== EXERCISE: LRU CACHE - AN ARENA LINKED LIST =====
+//
+// The linked list inside lru_cache.rs, on its own: a doubly linked list
+// whose nodes live in a Vec and link to each other by index. push_front()
+// hands back the node's index, and that index is how callers refer to the
+// node later - the way LruCache's HashMap does.
+//
+// REQUIREMENTS:
+// • push_front(value) puts the value at the front and returns its index
+// • move_to_front(index) relinks that node at the front, in O(1)
+// • remove(index) unlinks the node and returns its value; a removed (or
+//   never used) index returns None
+// • pop_back() removes and returns the value at the back
+// • Removed slots are reused by later pushes: the Vec never holds more
+//   slots than the most values ever in the list at once
+// • to_vec() lists the values front to back; len() counts them
+//
+// HINT: Write unlink(index) and link_front(index) helpers first - every
+// other method is one or two calls to them. Keep a Vec<usize> of free slots.
+
+pub struct Node<T> {
+    value: T,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+pub struct ArenaList<T> {
+    slots: Vec<Option<Node<T>>>,
+    free: Vec<usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
+}
+
+impl<T: Clone> ArenaList<T> {
+    pub fn new() -> Self {
+        ArenaList { slots: Vec::new(), free: Vec::new(), head: None, tail: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn slots(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn push_front(&mut self, value: T) -> usize {
+        todo!()
+    }
+
+    pub fn move_to_front(&mut self, index: usize) {
+        todo!()
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        todo!()
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        todo!()
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        todo!()
+    }
+}
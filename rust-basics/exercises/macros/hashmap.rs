@@ -0,0 +1,18 @@
+// ===== EXERCISE: MACROS - HASHMAP LITERAL =====
+//
+// Write a `hashmap!` macro that builds a HashMap from `key => value` pairs.
+//
+// REQUIREMENTS:
+// • hashmap!{} builds an empty map
+// • hashmap!{ "a" => 1, "b" => 2 } inserts each pair
+// • A trailing comma is allowed: hashmap!{ "a" => 1, }
+// • Keys and values are arbitrary expressions, each evaluated exactly once
+//
+// HINT: $( $key:expr => $value:expr ),* $(,)? and a repetition in the body.
+
+#[macro_export]
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        todo!("build a std::collections::HashMap")
+    }};
+}
@@ -0,0 +1,44 @@
+// ===== EXERCISE: NETWORKING - A FRAME DECODER =====
+//
+// TCP delivers bytes in whatever chunks the network produced. A decoder
+// buffers those chunks and hands out complete frames only - the same
+// length-prefixed format as networking.rs: a u32 big-endian length, then
+// that many bytes.
+//
+// REQUIREMENTS:
+// • encode(payload) returns the 4 length bytes followed by the payload
+// • push(chunk) appends received bytes; chunks may split a frame anywhere,
+//   even inside the length
+// • next_frame() returns the oldest complete frame and removes it from the
+//   buffer, Ok(None) if the next frame hasn't fully arrived yet
+// • A length above max_len is Err(FrameTooLarge(len)) - checked as soon as
+//   the 4 length bytes are in, without waiting for the payload
+//
+// HINT: Peek at buffer[..4] without removing it; drain(..4 + len) once the
+// whole frame is there.
+
+#[derive(Debug, PartialEq)]
+pub struct FrameTooLarge(pub usize);
+
+pub fn encode(payload: &[u8]) -> Vec<u8> {
+    todo!()
+}
+
+pub struct FrameDecoder {
+    max_len: usize,
+    buffer: Vec<u8>,
+}
+
+impl FrameDecoder {
+    pub fn new(max_len: usize) -> Self {
+        FrameDecoder { max_len, buffer: Vec::new() }
+    }
+
+    pub fn push(&mut self, chunk: &[u8]) {
+        todo!()
+    }
+
+    pub fn next_frame(&mut self) -> Result<Option<Vec<u8>>, FrameTooLarge> {
+        todo!()
+    }
+}
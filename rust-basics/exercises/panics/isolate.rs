@@ -0,0 +1,32 @@
+// ===== EXERCISE: PANICS - ISOLATING FAILURES =====
+//
+// A batch runner that keeps going when one job panics, and a helper that
+// keeps using a Mutex after some other thread panicked while holding it.
+//
+// REQUIREMENTS:
+// • panic_text(payload) returns the panic message: the payload of
+//   panic!("literal") is a &'static str, of panic!("{}", x) a String.
+//   Anything else becomes "unknown panic"
+// • run_isolated(jobs) runs every job in order and returns Ok(value) for
+//   the ones that returned, Err(message) for the ones that panicked - a
+//   panic must not stop the jobs after it
+// • lock_or_recover(mutex) returns the guard even if the mutex is poisoned
+//
+// HINT: std::panic::catch_unwind(job) returns Result<T, Box<dyn Any + Send>>.
+// A fn() -> u32 is UnwindSafe already. For the mutex,
+// lock().unwrap_or_else(PoisonError::into_inner).
+
+use std::any::Any;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+pub fn panic_text(payload: &(dyn Any + Send)) -> String {
+    todo!()
+}
+
+pub fn run_isolated(jobs: &[fn() -> u32]) -> Vec<Result<u32, String>> {
+    todo!()
+}
+
+pub fn lock_or_recover<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    todo!()
+}
@@ -0,0 +1,47 @@
+// ===== EXERCISE: PARSING - AN HTTP REQUEST LINE =====
+//
+// Hand-roll a parser for the first line of an HTTP request:
+//   GET /search?q=rust&page=2 HTTP/1.1
+//
+// Everything in RequestLine borrows from the input - no String allocations.
+//
+// REQUIREMENTS:
+// • Exactly three parts separated by single spaces: method, target, version;
+//   anything else is Err(Malformed)
+// • Methods GET, POST, PUT and DELETE (upper case only); others are
+//   Err(UnknownMethod(<method>))
+// • The target must start with '/', else Err(Malformed)
+// • The version must be "HTTP/1.0" or "HTTP/1.1", else
+//   Err(UnsupportedVersion(<version>))
+// • path is the target up to '?'; query holds the key=value pairs after it,
+//   separated by '&'. A pair without '=' has the value "". Empty pairs
+//   (from "a=1&&b=2" or a trailing '&') are skipped
+//
+// HINT: split_once returns borrowed halves, so the lifetimes work out.
+
+#[derive(Debug, PartialEq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RequestError<'a> {
+    Malformed,
+    UnknownMethod(&'a str),
+    UnsupportedVersion(&'a str),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RequestLine<'a> {
+    pub method: Method,
+    pub path: &'a str,
+    pub query: Vec<(&'a str, &'a str)>,
+    pub version: &'a str,
+}
+
+pub fn parse_request_line(line: &str) -> Result<RequestLine<'_>, RequestError<'_>> {
+    todo!()
+}
@@ -0,0 +1,53 @@
+// ===== EXERCISE: PATTERNS - A TYPESTATE DOOR =====
+//
+// A door that can only be opened while unlocked - enforced by the compiler.
+// Door<Locked> has no open() method at all, so `door.open()` on a locked
+// door doesn't compile.
+//
+// REQUIREMENTS:
+// • Door::new() returns a locked door that has been opened 0 times
+// • unlock(code): Ok(Door<Unlocked>) if code == CODE, otherwise Err with the
+//   same locked door handed back, so the caller can try again
+// • open() adds one to the count; times_opened() returns it
+// • lock() turns the door back into a Door<Locked>, keeping the count
+//
+// HINT: Changing state means building a new Door with a different State
+// parameter - PhantomData<State> costs nothing to create.
+
+use std::marker::PhantomData;
+
+pub const CODE: u32 = 1234;
+
+pub struct Locked;
+pub struct Unlocked;
+
+pub struct Door<State> {
+    opened: u32,
+    state: PhantomData<State>,
+}
+
+impl Door<Locked> {
+    pub fn new() -> Self {
+        todo!()
+    }
+
+    pub fn unlock(self, code: u32) -> Result<Door<Unlocked>, Door<Locked>> {
+        todo!()
+    }
+}
+
+impl Door<Unlocked> {
+    pub fn open(&mut self) {
+        todo!()
+    }
+
+    pub fn lock(self) -> Door<Locked> {
+        todo!()
+    }
+}
+
+impl<State> Door<State> {
+    pub fn times_opened(&self) -> u32 {
+        todo!()
+    }
+}
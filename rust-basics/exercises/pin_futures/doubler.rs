@@ -0,0 +1,33 @@
+// ===== EXERCISE: PIN - PROJECTING INTO A WRAPPED FUTURE =====
+//
+// `Doubler` wraps any future producing a u32 and doubles its output. The inner
+// future may be !Unpin, so poll() has to project the pin onto the field.
+//
+// REQUIREMENTS:
+// • Poll the inner future; Pending stays Pending, Ready(n) becomes Ready(n * 2)
+// • Doubler must NOT require F: Unpin
+//
+// HINT: unsafe { self.map_unchecked_mut(|d| &mut d.inner) } is sound here as
+// long as `inner` is never moved out of a pinned Doubler.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub struct Doubler<F> {
+    inner: F,
+}
+
+impl<F> Doubler<F> {
+    pub fn new(inner: F) -> Self {
+        Doubler { inner }
+    }
+}
+
+impl<F: Future<Output = u32>> Future for Doubler<F> {
+    type Output = u32;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+        todo!()
+    }
+}
@@ -0,0 +1,42 @@
+// ===== EXERCISE: SMART POINTERS - SHARED LOG =====
+//
+// Several components append to one log. Share it with Rc and mutate it
+// through RefCell.
+//
+// REQUIREMENTS:
+// • SharedLog::new() creates an empty log
+// • handle() returns a new SharedLog pointing at the SAME messages
+// • push() appends a message; every handle sees it
+// • messages() returns a copy of all messages in order
+// • owners() returns how many handles currently share the log
+//
+// HINT: Rc::clone, Rc::strong_count, RefCell::borrow / borrow_mut.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct SharedLog {
+    messages: Rc<RefCell<Vec<String>>>,
+}
+
+impl SharedLog {
+    pub fn new() -> Self {
+        todo!()
+    }
+
+    pub fn handle(&self) -> SharedLog {
+        todo!()
+    }
+
+    pub fn push(&self, message: &str) {
+        todo!()
+    }
+
+    pub fn messages(&self) -> Vec<String> {
+        todo!()
+    }
+
+    pub fn owners(&self) -> usize {
+        todo!()
+    }
+}
@@ -0,0 +1,31 @@
+// ===== EXERCISE: STRINGS - SLICING MULTIBYTE TEXT SAFELY =====
+//
+// &text[a..b] takes BYTE offsets and panics when one lands inside a
+// multibyte character. These functions cut text by characters or by a byte
+// budget without ever panicking, and return slices of the input - no
+// allocation.
+//
+// REQUIREMENTS:
+// • take_chars(text, n) returns the first n chars (all of text if it is
+//   shorter): take_chars("héllo", 2) == "hé"
+// • clip_bytes(text, max) returns the longest prefix that fits in max bytes
+//   without splitting a char: clip_bytes("héllo", 2) == "h"
+// • char_slice(text, start, end) returns chars start..end, or None if
+//   start > end or end is past the last char: char_slice("🦀ab", 1, 3) ==
+//   Some("ab")
+//
+// HINT: text.char_indices() yields (byte offset, char) - the byte offset of
+// the n-th char is where a slice of n chars ends. text.is_char_boundary(i)
+// tells you whether a byte offset is safe to cut at.
+
+pub fn take_chars(text: &str, n: usize) -> &str {
+    todo!()
+}
+
+pub fn clip_bytes(text: &str, max: usize) -> &str {
+    todo!()
+}
+
+pub fn char_slice(text: &str, start: usize, end: usize) -> Option<&str> {
+    todo!()
+}
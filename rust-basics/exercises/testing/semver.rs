@@ -0,0 +1,34 @@
+// ===== EXERCISE: TESTING - VERSIONS =====
+//
+// Code that is easy to test: pure functions with clear edge cases. The hidden
+// suite checks many of them - think about what could go wrong before running it.
+//
+// REQUIREMENTS:
+// • parse_version("1.2.3") == Some(Version { major: 1, minor: 2, patch: 3 })
+// • Surrounding whitespace is allowed; anything else invalid returns None
+//   (missing parts, extra parts, empty parts, non-digits, leading '+' or '-')
+// • bump() returns the next version: Major → x+1.0.0, Minor → x.y+1.0,
+//   Patch → x.y.z+1
+//
+// HINT: split('.') and check the part count; u32::parse accepts a leading '+'.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+pub enum Bump {
+    Major,
+    Minor,
+    Patch,
+}
+
+pub fn parse_version(input: &str) -> Option<Version> {
+    todo!()
+}
+
+pub fn bump(version: Version, part: Bump) -> Version {
+    todo!()
+}
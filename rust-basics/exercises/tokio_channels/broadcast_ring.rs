@@ -0,0 +1,44 @@
+// ===== EXERCISE: CHANNELS - A LAGGING BROADCAST BUFFER =====
+//
+// The core of tokio's broadcast channel, without the async parts: a fixed-size
+// ring of messages where each receiver keeps its own cursor, and slow
+// receivers are told how many messages they missed.
+//
+// REQUIREMENTS:
+// • Messages get sequence numbers 0, 1, 2, ... in send order
+// • The ring keeps only the last `capacity` messages
+// • recv(&mut cursor) returns the message with sequence number `cursor` and
+//   advances the cursor by one
+// • If that message was already overwritten: Err(Lagged(n)) where n is how
+//   many were skipped, and the cursor jumps to the oldest message still kept
+// • If cursor is past the newest message: Err(Empty), cursor unchanged
+//
+// HINT: A VecDeque<(u64, u32)> plus the next sequence number is enough.
+
+use std::collections::VecDeque;
+
+#[derive(Debug, PartialEq)]
+pub enum RecvError {
+    Lagged(u64),
+    Empty,
+}
+
+pub struct BroadcastRing {
+    capacity: usize,
+    next_seq: u64,
+    messages: VecDeque<(u64, u32)>,
+}
+
+impl BroadcastRing {
+    pub fn new(capacity: usize) -> Self {
+        BroadcastRing { capacity, next_seq: 0, messages: VecDeque::with_capacity(capacity) }
+    }
+
+    pub fn send(&mut self, value: u32) {
+        todo!()
+    }
+
+    pub fn recv(&self, cursor: &mut u64) -> Result<u32, RecvError> {
+        todo!()
+    }
+}
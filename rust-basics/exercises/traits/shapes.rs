@@ -0,0 +1,45 @@
+// ===== EXERCISE: TRAITS - SHAPES =====
+//
+// Implement the `Shape` trait for `Circle` and `Rectangle`, then sum the areas
+// of a heterogeneous collection.
+//
+// REQUIREMENTS:
+// • Circle::area is π·r², Rectangle::area is width·height
+// • name() has a default implementation returning "shape"; override it so
+//   Circle returns "circle" (Rectangle keeps the default)
+// • total_area() adds up the areas of every shape in the slice
+//
+// HINT: Trait objects (`Box<dyn Shape>`) dispatch to each type's own area().
+
+pub trait Shape {
+    fn area(&self) -> f64;
+
+    fn name(&self) -> String {
+        todo!("return the default name")
+    }
+}
+
+pub struct Circle {
+    pub radius: f64,
+}
+
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        todo!()
+    }
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        todo!()
+    }
+}
+
+pub fn total_area(shapes: &[Box<dyn Shape>]) -> f64 {
+    todo!()
+}
@@ -0,0 +1,30 @@
+// ===== EXERCISE: ZERO COPY - BORROW UNLESS YOU MUST =====
+//
+// Two small text cleaners that return Cow<str>. Each must hand back a slice
+// of its input (Cow::Borrowed) whenever the cleaned text is a slice of the
+// input, and build a String only when it isn't. The tests check which
+// variant you return, not just the text.
+//
+// REQUIREMENTS:
+// • unquote(field): strips one pair of surrounding double quotes, and turns
+//   \" inside them into ". A field without surrounding quotes is returned
+//   unchanged.
+//     unquote("plain") == "plain"          (borrowed)
+//     unquote("\"a b\"") == "a b"          (borrowed - a slice inside the quotes)
+//     unquote("\"say \\\"hi\\\"\"") == "say \"hi\""  (owned)
+// • collapse_spaces(text): replaces every run of spaces with a single
+//   space. Text with no double spaces is returned borrowed.
+//
+// HINT: Do the cheap check first (starts_with/ends_with, contains("  ")),
+// return Cow::Borrowed(&text[a..b]) when nothing needs changing, and only
+// then create a String.
+
+use std::borrow::Cow;
+
+pub fn unquote(field: &str) -> Cow<'_, str> {
+    todo!()
+}
+
+pub fn collapse_spaces(text: &str) -> Cow<'_, str> {
+    todo!()
+}
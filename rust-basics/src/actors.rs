@@ -0,0 +1,530 @@
+// ===== ACTORS DEEP STUDY =====
+//
+// WHAT IS AN ACTOR?
+// A task that owns some state and is the ONLY code that touches it. Others
+// can't reach the state - they send the actor messages, and it handles them
+// one at a time. No locks, because nothing is shared.
+//
+// THE THREE PIECES:
+// • The actor task: a loop over its mailbox, calling handle() per message
+// • The mailbox: a bounded tokio mpsc channel - a full mailbox makes senders
+//   wait, which is backpressure for free
+// • Handles: cheap, cloneable senders with typed async methods. A method that
+//   needs an answer puts a oneshot::Sender in the message and awaits the reply
+//
+// tokio_channels.rs built one of these by hand (spawn_kv_worker). This module
+// factors the loop and the request/reply plumbing out into a tiny framework,
+// then builds a counter and a key-value store on it.
+//
+// LIFECYCLE:
+//   spawn(actor) ──▶ handle.send(..) / handle.ask(..) ──▶ last handle dropped
+//                                                           │
+//   JoinHandle resolves to the actor's final state ◀── mailbox closed ◀┘
+
+use crate::report::Report;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ActorError {
+    #[error("the actor has stopped")]
+    Stopped,
+
+    #[error("the actor dropped the request without replying")]
+    NoReply,
+}
+
+// ===== 1. THE FRAMEWORK =====
+//
+// UNDERSTANDING THE Actor TRAIT:
+// • Message is usually an enum, one variant per request
+// • handle() takes &mut self: the actor has exclusive access to its state by
+//   construction, so plain fields do what a Mutex would elsewhere
+// • Send + 'static because the actor moves into a tokio task
+// • handle() is synchronous here - a slow handler delays every message
+//   behind it, the same trade-off as a long critical section
+
+pub trait Actor: Send + 'static {
+    type Message: Send + 'static;
+
+    fn handle(&mut self, message: Self::Message);
+}
+
+pub struct ActorHandle<A: Actor> {
+    mailbox: mpsc::Sender<A::Message>,
+}
+
+// Derived Clone would require A: Clone - the handle never clones the actor
+impl<A: Actor> Clone for ActorHandle<A> {
+    fn clone(&self) -> Self {
+        ActorHandle { mailbox: self.mailbox.clone() }
+    }
+}
+
+// Starts `actor` on its own task. The task ends once every handle is dropped,
+// and hands the actor back through the JoinHandle
+pub fn spawn<A: Actor>(mut actor: A, mailbox_size: usize) -> (ActorHandle<A>, JoinHandle<A>) {
+    let (mailbox, mut messages) = mpsc::channel(mailbox_size);
+    let task = tokio::spawn(async move {
+        while let Some(message) = messages.recv().await {
+            actor.handle(message);
+        }
+        actor
+    });
+    (ActorHandle { mailbox }, task)
+}
+
+impl<A: Actor> ActorHandle<A> {
+    // Fire and forget ("tell"): waits only for room in the mailbox
+    pub async fn send(&self, message: A::Message) -> Result<(), ActorError> {
+        self.mailbox.send(message).await.map_err(|_| ActorError::Stopped)
+    }
+
+    // Request/reply ("ask"): `request` wraps the reply channel in a message
+    pub async fn ask<R>(&self, request: impl FnOnce(oneshot::Sender<R>) -> A::Message) -> Result<R, ActorError> {
+        let (reply, response) = oneshot::channel();
+        self.send(request(reply)).await?;
+        response.await.map_err(|_| ActorError::NoReply)
+    }
+
+    pub fn is_alive(&self) -> bool {
+        !self.mailbox.is_closed()
+    }
+}
+
+// ===== 2. A COUNTER ACTOR =====
+
+#[derive(Debug, Default)]
+pub struct Counter {
+    value: u64,
+    handled: u64,
+}
+
+#[derive(Debug)]
+pub enum CounterMessage {
+    Increment(u64),
+    Get(oneshot::Sender<u64>),
+    // Replies with the value before the reset
+    Reset(oneshot::Sender<u64>),
+}
+
+impl Actor for Counter {
+    type Message = CounterMessage;
+
+    fn handle(&mut self, message: CounterMessage) {
+        self.handled += 1;
+        match message {
+            CounterMessage::Increment(by) => self.value += by,
+            // The asker may have given up; that's not the actor's problem
+            CounterMessage::Get(reply) => {
+                let _ = reply.send(self.value);
+            }
+            CounterMessage::Reset(reply) => {
+                let _ = reply.send(std::mem::take(&mut self.value));
+            }
+        }
+    }
+}
+
+impl Counter {
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn handled(&self) -> u64 {
+        self.handled
+    }
+}
+
+// A typed handle: callers see methods, never messages or channels
+#[derive(Clone)]
+pub struct CounterHandle(ActorHandle<Counter>);
+
+impl CounterHandle {
+    pub fn spawn() -> (CounterHandle, JoinHandle<Counter>) {
+        let (handle, task) = spawn(Counter::default(), 32);
+        (CounterHandle(handle), task)
+    }
+
+    pub async fn increment(&self, by: u64) -> Result<(), ActorError> {
+        self.0.send(CounterMessage::Increment(by)).await
+    }
+
+    pub async fn get(&self) -> Result<u64, ActorError> {
+        self.0.ask(CounterMessage::Get).await
+    }
+
+    pub async fn reset(&self) -> Result<u64, ActorError> {
+        self.0.ask(CounterMessage::Reset).await
+    }
+}
+
+// ===== 3. A KEY-VALUE ACTOR =====
+
+#[derive(Debug, Default)]
+pub struct KvStore {
+    entries: HashMap<String, String>,
+}
+
+#[derive(Debug)]
+pub enum KvMessage {
+    // Replies with the previous value
+    Set { key: String, value: String, reply: oneshot::Sender<Option<String>> },
+    Get { key: String, reply: oneshot::Sender<Option<String>> },
+    Delete { key: String, reply: oneshot::Sender<bool> },
+    // A sorted copy, so the caller never sees the map mid-update
+    Snapshot(oneshot::Sender<BTreeMap<String, String>>),
+}
+
+impl Actor for KvStore {
+    type Message = KvMessage;
+
+    fn handle(&mut self, message: KvMessage) {
+        match message {
+            KvMessage::Set { key, value, reply } => {
+                let _ = reply.send(self.entries.insert(key, value));
+            }
+            KvMessage::Get { key, reply } => {
+                let _ = reply.send(self.entries.get(&key).cloned());
+            }
+            KvMessage::Delete { key, reply } => {
+                let _ = reply.send(self.entries.remove(&key).is_some());
+            }
+            KvMessage::Snapshot(reply) => {
+                let _ = reply.send(self.entries.iter().map(|(k, v)| (k.clone(), v.clone())).collect());
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct KvHandle(ActorHandle<KvStore>);
+
+impl KvHandle {
+    pub fn spawn() -> (KvHandle, JoinHandle<KvStore>) {
+        let (handle, task) = spawn(KvStore::default(), 32);
+        (KvHandle(handle), task)
+    }
+
+    pub async fn set(&self, key: &str, value: &str) -> Result<Option<String>, ActorError> {
+        let (key, value) = (key.to_string(), value.to_string());
+        self.0.ask(|reply| KvMessage::Set { key, value, reply }).await
+    }
+
+    pub async fn get(&self, key: &str) -> Result<Option<String>, ActorError> {
+        let key = key.to_string();
+        self.0.ask(|reply| KvMessage::Get { key, reply }).await
+    }
+
+    pub async fn delete(&self, key: &str) -> Result<bool, ActorError> {
+        let key = key.to_string();
+        self.0.ask(|reply| KvMessage::Delete { key, reply }).await
+    }
+
+    pub async fn snapshot(&self) -> Result<BTreeMap<String, String>, ActorError> {
+        self.0.ask(KvMessage::Snapshot).await
+    }
+}
+
+// ===== 4. ACTORS VS Arc<Mutex<T>> =====
+//
+// THE SAME COUNTER, SHARED INSTEAD OF OWNED:
+// • Arc<Mutex<u64>>: every task locks, updates, unlocks. Simple, and for a
+//   tiny critical section usually faster - no message, no task switch
+// • Actor: every update is a message to one task. More machinery, but:
+//
+// WHERE ACTORS WIN:
+// • No lock to forget, hold too long, or take in the wrong order (deadlock)
+// • A std MutexGuard can't be held across .await; an actor's state can be
+//   used by async code freely because only one task ever sees it
+// • A bounded mailbox pushes back on callers when the actor falls behind
+// • Several updates that must happen together are one message - the actor
+//   handles it atomically without a transaction API
+//
+// WHERE LOCKS WIN:
+// • Reads from many tasks at once (RwLock), no round trip for a reply
+// • Less code for simple shared data
+
+#[derive(Clone, Default)]
+pub struct SharedCounter(Arc<Mutex<u64>>);
+
+impl SharedCounter {
+    pub fn increment(&self, by: u64) {
+        *self.0.lock().unwrap() += by;
+    }
+
+    pub fn get(&self) -> u64 {
+        *self.0.lock().unwrap()
+    }
+}
+
+// `tasks` tasks each add 1 `per_task` times; returns the total and the time
+pub async fn count_with_actor(tasks: u64, per_task: u64) -> (u64, Duration) {
+    let start = Instant::now();
+    let (counter, _task) = CounterHandle::spawn();
+    let workers: Vec<_> = (0..tasks)
+        .map(|_| {
+            let counter = counter.clone();
+            tokio::spawn(async move {
+                for _ in 0..per_task {
+                    counter.increment(1).await.unwrap();
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.await.unwrap();
+    }
+    // Messages are handled in order, so this Get comes after every Increment
+    (counter.get().await.unwrap(), start.elapsed())
+}
+
+pub async fn count_with_mutex(tasks: u64, per_task: u64) -> (u64, Duration) {
+    let start = Instant::now();
+    let counter = SharedCounter::default();
+    let workers: Vec<_> = (0..tasks)
+        .map(|_| {
+            let counter = counter.clone();
+            tokio::spawn(async move {
+                for _ in 0..per_task {
+                    counter.increment(1);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.await.unwrap();
+    }
+    (counter.get(), start.elapsed())
+}
+
+// ===== 5. DEMONSTRATION FUNCTION =====
+
+pub async fn actors_report() -> Report {
+    let mut report = Report::new("🦀 RUST ACTORS DEEP STUDY 🦀");
+
+    // ===== COUNTER =====
+    let section = report.section("1️⃣ COUNTER ACTOR:");
+    let (counter, task) = CounterHandle::spawn();
+    for by in [1, 2, 3] {
+        let _ = counter.increment(by).await;
+    }
+    section.example("After increments of 1, 2, 3: get()", format!("{:?}", counter.get().await));
+    let reset = counter.reset().await;
+    section.example("reset()", format!("returned {:?}, get() now {:?}", reset, counter.get().await));
+    drop(counter);
+    if let Ok(counter) = task.await {
+        section.example(
+            "Last handle dropped",
+            format!("actor stopped after {} messages, value {}", counter.handled(), counter.value()),
+        );
+    }
+
+    // ===== KEY-VALUE STORE =====
+    let section = report.section("2️⃣ KEY-VALUE ACTOR:");
+    let (kv, task) = KvHandle::spawn();
+    let writers: Vec<_> = ["en", "fr", "de"]
+        .into_iter()
+        .zip(["hello", "bonjour", "hallo"])
+        .map(|(key, value)| {
+            let kv = kv.clone();
+            tokio::spawn(async move { kv.set(key, value).await })
+        })
+        .collect();
+    for writer in writers {
+        let _ = writer.await;
+    }
+    section.example(r#"set("en", "hi") replaced"#, format!("{:?}", kv.set("en", "hi").await));
+    section
+        .example(r#"get("fr")"#, format!("{:?}", kv.get("fr").await))
+        .example(r#"delete("de")"#, format!("{:?}", kv.delete("de").await))
+        .example("snapshot()", format!("{:?}", kv.snapshot().await));
+    drop(kv);
+    let _ = task.await;
+
+    // ===== STOPPED ACTORS =====
+    let (counter, task) = CounterHandle::spawn();
+    task.abort();
+    let _ = task.await;
+    let after_abort = counter.increment(1).await.map_err(|e| e.to_string());
+    report
+        .section("3️⃣ TALKING TO A STOPPED ACTOR:")
+        .example("increment() after abort", format!("{:?}", after_abort));
+
+    // ===== VS MUTEX =====
+    let (actor_total, actor_elapsed) = count_with_actor(8, 1_000).await;
+    let (mutex_total, mutex_elapsed) = count_with_mutex(8, 1_000).await;
+    report
+        .section("4️⃣ ACTOR VS Arc<Mutex<T>>:")
+        .example("Actor: 8 tasks × 1000 increments", format!("{} in {:?}", actor_total, actor_elapsed))
+        .example("Mutex: 8 tasks × 1000 increments", format!("{} in {:?}", mutex_total, mutex_elapsed))
+        .text("Same answer - the actor pays for a message per update, the mutex for a lock");
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 ACTORS SUMMARY:")
+        .check("One task owns the state; everyone else sends messages")
+        .check("Bounded mailboxes give backpressure")
+        .check("oneshot channels carry replies back to the asker")
+        .check("Typed handles hide the messages behind ordinary async methods")
+        .check("Dropping the last handle stops the actor cleanly");
+
+    report
+}
+
+pub async fn demonstrate_actors() {
+    print!("{}", actors_report().await);
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct ActorsModule;
+
+impl LearningModule for ActorsModule {
+    fn number(&self) -> u8 {
+        13
+    }
+
+    fn name(&self) -> &'static str {
+        "actors"
+    }
+
+    fn title(&self) -> &'static str {
+        "ACTORS DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Actors with Channels"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["concurrency", "tokio_channels"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(demonstrate_actors())
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(actors_report()))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&ActorsModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// ACTOR BEST PRACTICES:
+// 1. Keep handle() short - it's a critical section for the whole actor
+// 2. Give the mailbox a bound; an unbounded one hides overload
+// 3. Expose typed handles, not raw message enums
+// 4. Treat a failed send as "the actor is gone" and stop, don't retry forever
+// 5. Ignore failed replies inside the actor: the asker may have timed out
+//
+// CHOOSING:
+// • A counter or a cache read by many tasks: Arc<Mutex<T>> or RwLock
+// • State with invariants across several fields, async work per request, or
+//   a resource that must be used by one task at a time: an actor
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn report_shows_replies_and_a_stopped_actor() {
+        let report = actors_report().await;
+        let counter = report.find("COUNTER ACTOR").unwrap();
+        assert_eq!(counter.output("After increments of 1, 2, 3: get()"), Some("Ok(6)"));
+        assert_eq!(counter.output("reset()"), Some("returned Ok(6), get() now Ok(0)"));
+
+        let kv = report.find("KEY-VALUE ACTOR").unwrap();
+        assert_eq!(kv.output(r#"get("fr")"#), Some(r#"Ok(Some("bonjour"))"#));
+
+        let stopped = report.find("STOPPED ACTOR").unwrap();
+        assert!(stopped.output("increment() after abort").unwrap().starts_with("Err("));
+
+        // Both approaches count every increment
+        let versus = report.find("ACTOR VS").unwrap();
+        assert!(versus.outputs().all(|output| output.starts_with("8000 in")));
+    }
+
+    #[tokio::test]
+    async fn counter_handles_messages_in_order() {
+        let (counter, task) = CounterHandle::spawn();
+        counter.increment(5).await.unwrap();
+        counter.increment(7).await.unwrap();
+        assert_eq!(counter.reset().await, Ok(12));
+        counter.increment(1).await.unwrap();
+        assert_eq!(counter.get().await, Ok(1));
+
+        drop(counter);
+        let counter = task.await.unwrap();
+        assert_eq!((counter.value(), counter.handled()), (1, 5));
+    }
+
+    #[tokio::test]
+    async fn kv_store_serializes_concurrent_writers() {
+        let (kv, _task) = KvHandle::spawn();
+        let writers: Vec<_> = (0..20)
+            .map(|n| {
+                let kv = kv.clone();
+                tokio::spawn(async move { kv.set(&format!("k{}", n % 5), &n.to_string()).await.unwrap() })
+            })
+            .collect();
+        let replaced = count_replaced(writers).await;
+        assert_eq!(replaced, 15, "every key but the first write of each replaces a value");
+
+        assert_eq!(kv.snapshot().await.unwrap().len(), 5);
+        assert_eq!(kv.delete("k0").await, Ok(true));
+        assert_eq!(kv.delete("k0").await, Ok(false));
+        assert_eq!(kv.get("k0").await, Ok(None));
+    }
+
+    async fn count_replaced(writers: Vec<JoinHandle<Option<String>>>) -> usize {
+        let mut replaced = 0;
+        for writer in writers {
+            replaced += usize::from(writer.await.unwrap().is_some());
+        }
+        replaced
+    }
+
+    #[tokio::test]
+    async fn stopped_actors_report_errors() {
+        let (counter, task) = CounterHandle::spawn();
+        assert!(counter.0.is_alive());
+        task.abort();
+        let _ = task.await;
+        assert!(!counter.0.is_alive());
+        assert_eq!(counter.increment(1).await, Err(ActorError::Stopped));
+        assert_eq!(counter.get().await, Err(ActorError::Stopped));
+    }
+
+    // An actor that drops requests instead of answering
+    struct Forgetful;
+
+    impl Actor for Forgetful {
+        type Message = oneshot::Sender<()>;
+
+        fn handle(&mut self, _reply: oneshot::Sender<()>) {}
+    }
+
+    #[tokio::test]
+    async fn dropped_replies_are_not_hangs() {
+        let (handle, _task) = spawn(Forgetful, 1);
+        assert_eq!(handle.ask(|reply| reply).await, Err(ActorError::NoReply));
+    }
+
+    #[tokio::test]
+    async fn actor_and_mutex_agree() {
+        assert_eq!(count_with_actor(4, 250).await.0, 1_000);
+        assert_eq!(count_with_mutex(4, 250).await.0, 1_000);
+    }
+}
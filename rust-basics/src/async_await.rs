@@ -0,0 +1,602 @@
+// ===== ASYNC/AWAIT DEEP STUDY =====
+//
+// WHAT IS ASYNC/AWAIT?
+// Async/await lets us write non-blocking code that reads like sequential code.
+// An `async fn` doesn't run when called - it returns a FUTURE, a value that
+// describes work which can make progress later. Nothing happens until something
+// POLLS the future (an executor, or another future that `.await`s it).
+//
+// THE MENTAL MODEL:
+// • Future: A state machine that can be polled for progress
+// • .await: "Poll this future; if it's not ready, yield control back to the executor"
+// • Waker: How a future says "poll me again, I can make progress now"
+// • Executor: The loop that polls futures (tokio, async-std, or our own below)
+//
+// WHY NOT JUST THREADS?
+// • Threads cost ~2-8MB of stack each and a kernel context switch to swap
+// • Futures cost only the size of their state machine and switch in user space
+// • Async shines for IO-bound work with many concurrent waits (servers, clients)
+// • Threads are still the right tool for CPU-bound work
+
+use crate::report::Report;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+use tokio::time::sleep;
+
+// ===== 1. FUTURES BY HAND =====
+//
+// UNDERSTANDING THE FUTURE TRAIT:
+// pub trait Future {
+//     type Output;
+//     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>;
+// }
+//
+// • Poll::Ready(value): The future is finished
+// • Poll::Pending: Not finished yet - the future MUST arrange for
+//   `cx.waker().wake()` to be called later, or it will never be polled again
+// • Pin<&mut Self>: Promises the future won't move in memory while polled
+//   (async state machines may hold references into themselves)
+
+// A FUTURE THAT NEEDS SEVERAL POLLS BEFORE IT COMPLETES
+// Each poll counts down by one and immediately asks to be polled again.
+pub struct Countdown {
+    remaining: u32,
+    polls: u32,
+}
+
+impl Countdown {
+    pub fn new(from: u32) -> Self {
+        Countdown { remaining: from, polls: 0 }
+    }
+}
+
+impl Future for Countdown {
+    type Output = u32; // Number of polls it took
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.polls += 1;
+
+        if self.remaining == 0 {
+            return Poll::Ready(self.polls);
+        }
+
+        self.remaining -= 1;
+
+        // IMPORTANT: Returning Pending without arranging a wake-up would hang forever.
+        // Here we wake ourselves right away; real futures hand the waker to an
+        // IO driver or timer that calls it when the event happens.
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+// A FUTURE THAT IS WOKEN FROM ANOTHER THREAD
+// This is how real leaf futures work: the work happens elsewhere, and the
+// waker is the bridge back to the executor.
+pub struct ThreadTimer {
+    duration: Duration,
+    started: bool,
+    done: Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl ThreadTimer {
+    pub fn new(duration: Duration) -> Self {
+        ThreadTimer {
+            duration,
+            started: false,
+            done: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+        }
+    }
+}
+
+impl Future for ThreadTimer {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.done.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+
+        // First poll: start the "IO" on a helper thread and give it our waker
+        if !self.started {
+            self.started = true;
+            let done = Arc::clone(&self.done);
+            let waker = cx.waker().clone();
+            let duration = self.duration;
+
+            thread::spawn(move || {
+                thread::sleep(duration);
+                done.store(true, Ordering::Release);
+                waker.wake(); // "Executor, poll me again!"
+            });
+        }
+
+        Poll::Pending
+    }
+}
+
+// ===== 2. EXECUTOR BASICS =====
+//
+// WHAT DOES AN EXECUTOR DO?
+// 1. Poll the future
+// 2. If Pending, sleep until the waker is called
+// 3. Repeat until Ready
+//
+// The simplest possible executor runs ONE future on the current thread.
+// Its waker unparks the thread, and the loop parks between polls.
+// tokio adds IO drivers, timers, task queues and work stealing on top of this.
+
+// WAKER THAT UNPARKS THE BLOCKED THREAD
+struct ThreadWaker {
+    thread: Thread,
+    wakes: AtomicUsize,
+}
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.wakes.fetch_add(1, Ordering::Relaxed);
+        self.thread.unpark();
+    }
+}
+
+// MINIMAL block_on EXECUTOR
+// Returns the output and how many times the future was woken.
+pub fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+    // Pin the future on the stack - it must not move once polled
+    let mut future = std::pin::pin!(future);
+
+    let thread_waker = Arc::new(ThreadWaker {
+        thread: thread::current(),
+        wakes: AtomicUsize::new(0),
+    });
+    let waker = Waker::from(Arc::clone(&thread_waker));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return (output, thread_waker.wakes.load(Ordering::Relaxed)),
+            // park() returns immediately if unpark() already happened,
+            // so a wake between poll and park is never lost
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+// ===== 3. ASYNC FN AND .await =====
+//
+// UNDERSTANDING ASYNC FUNCTIONS:
+// • `async fn f() -> T` is sugar for `fn f() -> impl Future<Output = T>`
+// • Calling it only BUILDS the future; `.await` drives it
+// • Each `.await` is a potential suspension point: locals that live across
+//   it are stored inside the generated state machine
+//
+// LAZINESS IS THE #1 SURPRISE:
+// let fut = fetch_user(1);   // Nothing has happened yet!
+// let user = fut.await;      // Now it runs
+
+pub async fn fetch_user(id: u32) -> String {
+    sleep(Duration::from_millis(50)).await; // Simulated network latency
+    format!("user-{}", id)
+}
+
+pub async fn fetch_orders(user: &str) -> Vec<String> {
+    sleep(Duration::from_millis(50)).await;
+    vec![format!("{}-order-1", user), format!("{}-order-2", user)]
+}
+
+// SEQUENTIAL AWAITS: Each step depends on the previous one
+pub async fn user_with_orders(id: u32) -> (String, Vec<String>) {
+    let user = fetch_user(id).await;
+    let orders = fetch_orders(&user).await; // Needs `user`, so it can't start earlier
+    (user, orders)
+}
+
+// ===== 4. CONCURRENCY WITH join! AND select! =====
+//
+// join!: Run several futures CONCURRENTLY on the same task, wait for ALL
+// • Total time ≈ the slowest future, not the sum
+// • No spawning, so futures may borrow local data
+//
+// select!: Run several futures, continue with the FIRST to finish
+// • The losing branches are DROPPED (cancelled) - see section 7
+// • Useful for timeouts, shutdown signals, racing replicas
+
+pub async fn fetch_three_users_sequentially() -> Vec<String> {
+    vec![fetch_user(1).await, fetch_user(2).await, fetch_user(3).await]
+}
+
+pub async fn fetch_three_users_concurrently() -> Vec<String> {
+    let (a, b, c) = tokio::join!(fetch_user(1), fetch_user(2), fetch_user(3));
+    vec![a, b, c]
+}
+
+// try_join!: Like join!, but short-circuits on the first error
+pub async fn parse_all(inputs: [&str; 2]) -> Result<(i32, i32), std::num::ParseIntError> {
+    let parse = |s: &str| {
+        let s = s.to_string();
+        async move { s.parse::<i32>() }
+    };
+    tokio::try_join!(parse(inputs[0]), parse(inputs[1]))
+}
+
+pub async fn fetch_with_deadline(latency: Duration, deadline: Duration) -> Result<String, String> {
+    tokio::select! {
+        user = async {
+            sleep(latency).await;
+            "user-42".to_string()
+        } => Ok(user),
+        _ = sleep(deadline) => Err(format!("timed out after {:?}", deadline)),
+    }
+}
+
+// ===== 5. SPAWNING TASKS =====
+//
+// UNDERSTANDING tokio::spawn:
+// • Hands the future to the runtime as an independent TASK
+// • Tasks run in parallel across worker threads (multi-threaded runtime)
+// • The future must be 'static (no borrowed locals) and Send
+// • Returns a JoinHandle - awaiting it yields Result<T, JoinError>
+//   (Err if the task panicked or was aborted)
+//
+// join! vs spawn:
+// • join!: Concurrency within ONE task, can borrow, no parallelism
+// • spawn: Separate tasks, needs 'static + Send, true parallelism
+
+pub async fn spawn_workers(count: u32) -> Vec<u32> {
+    // JoinSet collects many handles and yields results as tasks complete
+    let mut set = JoinSet::new();
+
+    for id in 0..count {
+        set.spawn(async move {
+            // Later tasks finish first, to show completion order ≠ spawn order
+            sleep(Duration::from_millis(10 * u64::from(count - id))).await;
+            id
+        });
+    }
+
+    let mut finished = Vec::new();
+    while let Some(result) = set.join_next().await {
+        finished.push(result.expect("task panicked"));
+    }
+    finished
+}
+
+// ===== 6. BLOCKING PITFALLS =====
+//
+// THE GOLDEN RULE: Never block inside async code.
+// A future only yields at .await points. Blocking calls (std::thread::sleep,
+// std::fs, heavy CPU loops, synchronous HTTP clients) hold the worker thread
+// hostage, so every other task scheduled on it stalls.
+//
+// FIXES:
+// • Use async equivalents (tokio::time::sleep, tokio::fs, async clients)
+// • Move blocking work to tokio::task::spawn_blocking (dedicated thread pool)
+// • For long CPU loops, yield periodically with tokio::task::yield_now()
+// • Don't hold a std::sync::Mutex guard across .await (use tokio::sync::Mutex
+//   if you must)
+
+pub fn expensive_checksum(data: &[u8]) -> u64 {
+    // Stand-in for CPU-heavy or blocking work
+    thread::sleep(Duration::from_millis(100));
+    data.iter().map(|&b| u64::from(b)).sum()
+}
+
+// ❌ BAD: Blocks the executor thread for the whole computation
+pub async fn checksum_blocking(data: Vec<u8>) -> u64 {
+    expensive_checksum(&data)
+}
+
+// ✅ GOOD: Runs on the blocking pool, the async worker stays free
+pub async fn checksum_offloaded(data: Vec<u8>) -> u64 {
+    tokio::task::spawn_blocking(move || expensive_checksum(&data))
+        .await
+        .expect("blocking task panicked")
+}
+
+// Measures how late a 10ms heartbeat gets while `work` runs on the same task set.
+// A current-thread runtime makes the effect obvious: one thread, no rescue.
+fn heartbeat_delay<F, Fut>(work: F) -> Duration
+where
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future<Output = u64> + Send + 'static,
+{
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .expect("failed to build runtime");
+
+    runtime.block_on(async move {
+        let worker = tokio::spawn(work());
+
+        let start = Instant::now();
+        sleep(Duration::from_millis(10)).await;
+        let heartbeat_late_by = start.elapsed().saturating_sub(Duration::from_millis(10));
+
+        worker.await.expect("worker panicked");
+        heartbeat_late_by
+    })
+}
+
+// ===== 7. CANCELLATION BY DROPPING =====
+//
+// UNDERSTANDING CANCELLATION:
+// • Dropping a future cancels it - it simply won't be polled again
+// • Code after the current .await never runs, but destructors (Drop) do
+// • select! drops losing branches; JoinHandle::abort() cancels spawned tasks
+// • Design futures so that stopping at any .await leaves state consistent
+
+// Cleanup and progress notes go here instead of stdout so the demo can
+// report what ran and what didn't
+pub type Log = Arc<Mutex<Vec<String>>>;
+
+pub struct Guard {
+    name: &'static str,
+    log: Log,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        self.log.lock().unwrap().push(format!("🧹 Dropped guard for '{}' (cleanup still runs)", self.name));
+    }
+}
+
+pub async fn slow_operation(log: Log) -> &'static str {
+    let _guard = Guard { name: "slow_operation", log: Arc::clone(&log) };
+    sleep(Duration::from_secs(10)).await;
+    log.lock().unwrap().push("This line never appears when cancelled".to_string());
+    "finished"
+}
+
+// ===== 8. DEMONSTRATION FUNCTION =====
+
+pub async fn async_await_report() -> Report {
+    let mut report = Report::new("🦀 RUST ASYNC/AWAIT DEEP STUDY 🦀");
+
+    // ===== HAND-WRITTEN FUTURES =====
+    let by_hand = report.section("1️⃣ FUTURES BY HAND & A MINIMAL EXECUTOR:");
+
+    let (polls, wakes) = block_on(Countdown::new(3));
+    by_hand.example("Countdown(3)", format!("completed after {} polls ({} self-wakes)", polls, wakes));
+
+    let start = Instant::now();
+    let ((), wakes) = block_on(ThreadTimer::new(Duration::from_millis(30)));
+    by_hand.example(
+        "ThreadTimer(30ms)",
+        format!("completed in {:?} with {} wake from another thread", start.elapsed(), wakes),
+    );
+
+    // Our executor can drive plain async blocks too (no tokio types inside)
+    let (sum, _) = block_on(async {
+        let a = async { 20 }.await;
+        let b = async { 22 }.await;
+        a + b
+    });
+    by_hand.example("async block on our executor", sum);
+
+    // ===== ASYNC FN & .AWAIT =====
+    let future = fetch_user(7);
+    let lazy = "Created future for fetch_user(7) - nothing has run yet (futures are lazy)";
+    let awaited = future.await;
+    let (user, orders) = user_with_orders(1).await;
+    report
+        .section("2️⃣ ASYNC FN AND .AWAIT:")
+        .text(lazy)
+        .example("Awaited result", awaited)
+        .example("Sequential dependent calls", format!("{} -> {:?}", user, orders));
+
+    // ===== JOIN! & SELECT! =====
+    let start = Instant::now();
+    let sequential = fetch_three_users_sequentially().await;
+    let sequential_time = start.elapsed();
+
+    let start = Instant::now();
+    let joined = fetch_three_users_concurrently().await;
+    let joined_time = start.elapsed();
+
+    let parsed = parse_all(["1", "2"]).await;
+    let failed = parse_all(["1", "x"]).await.map_err(|e| e.to_string());
+    let fast = fetch_with_deadline(Duration::from_millis(10), Duration::from_millis(50)).await;
+    let slow = fetch_with_deadline(Duration::from_millis(100), Duration::from_millis(50)).await;
+
+    report
+        .section("3️⃣ JOIN! AND SELECT!:")
+        .example("Sequential", format!("{:?} in {:?}", sequential, sequential_time))
+        .example("join!", format!("{:?} in {:?}", joined, joined_time))
+        .example(r#"try_join! ["1", "2"]"#, format!("{:?}", parsed))
+        .example(r#"try_join! ["1", "x"]"#, format!("{:?}", failed))
+        .example("select! fast (10ms vs 50ms deadline)", format!("{:?}", fast))
+        .example("select! slow (100ms vs 50ms deadline)", format!("{:?}", slow));
+
+    // ===== SPAWNING =====
+    let handle = tokio::spawn(async { fetch_user(99).await });
+    let joined = handle.await;
+    let order = spawn_workers(4).await;
+    report
+        .section("4️⃣ SPAWNING TASKS:")
+        .example("JoinHandle result", format!("{:?}", joined))
+        .example("JoinSet completion order (spawned 0..4)", format!("{:?}", order));
+
+    // ===== BLOCKING PITFALLS =====
+    // Run on a separate thread: each measurement builds its own single-threaded runtime
+    let (blocked, offloaded) = tokio::task::spawn_blocking(|| {
+        let blocked = heartbeat_delay(|| checksum_blocking(vec![1; 1024]));
+        let offloaded = heartbeat_delay(|| checksum_offloaded(vec![1; 1024]));
+        (blocked, offloaded)
+    })
+    .await
+    .expect("measurement panicked");
+
+    report
+        .section("5️⃣ BLOCKING PITFALLS:")
+        .text(format!("❌ Blocking call in async fn: 10ms heartbeat was {:?} late", blocked))
+        .text(format!("✅ spawn_blocking offload: 10ms heartbeat was {:?} late", offloaded));
+
+    // ===== CANCELLATION =====
+    let log = Log::default();
+    let raced = tokio::select! {
+        result = slow_operation(Arc::clone(&log)) => format!("slow_operation: {}", result),
+        _ = sleep(Duration::from_millis(20)) => "Timer won - slow_operation was dropped".to_string(),
+    };
+    log.lock().unwrap().push(raced);
+
+    let task = tokio::spawn(slow_operation(Arc::clone(&log)));
+    sleep(Duration::from_millis(20)).await;
+    task.abort();
+    let cancelled = task.await.unwrap_err().is_cancelled();
+
+    let cancellation = report.section("6️⃣ CANCELLATION BY DROPPING:");
+    for line in log.lock().unwrap().drain(..) {
+        cancellation.text(line);
+    }
+    cancellation.example("Aborted task -> is_cancelled", cancelled);
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 ASYNC/AWAIT CONCEPTS SUMMARY:")
+        .check("Futures are lazy state machines driven by poll()")
+        .check("Wakers tell the executor when to poll again")
+        .check("join! = concurrent in one task, spawn = parallel tasks")
+        .check("select! races futures and drops the losers")
+        .check("Never block the executor - use spawn_blocking")
+        .check("Cancellation = dropping; Drop still runs");
+
+    report
+}
+
+pub async fn demonstrate_async_await() {
+    print!("{}", async_await_report().await);
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct AsyncAwaitModule;
+
+impl LearningModule for AsyncAwaitModule {
+    fn number(&self) -> u8 {
+        6
+    }
+
+    fn name(&self) -> &'static str {
+        "async_await"
+    }
+
+    fn title(&self) -> &'static str {
+        "ASYNC/AWAIT DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Async/Await"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["traits", "errors"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(demonstrate_async_await())
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async_await_report()))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&AsyncAwaitModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// ASYNC BEST PRACTICES:
+// 1. Remember futures are lazy - nothing runs until polled
+// 2. Use join!/try_join! for independent work, sequential .await for dependent work
+// 3. Spawn when you need parallelism or a task that outlives the caller
+// 4. Always handle JoinError - tasks can panic or be cancelled
+// 5. Keep blocking and CPU-heavy work off the async workers
+// 6. Treat every .await as a place where your function may stop forever
+// 7. Prefer timeouts (select!/tokio::time::timeout) on anything remote
+//
+// WHEN TO USE EACH:
+// • async/await: Many concurrent IO waits (network, timers, channels)
+// • threads: CPU-bound parallel work
+// • spawn_blocking: Unavoidable blocking APIs inside async code
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn report_shows_results_and_cleanup_of_cancelled_futures() {
+        let report = async_await_report().await;
+        let by_hand = report.find("FUTURES BY HAND").unwrap();
+        assert_eq!(by_hand.output("Countdown(3)"), Some("completed after 4 polls (3 self-wakes)"));
+        assert_eq!(by_hand.output("async block on our executor"), Some("42"));
+
+        let spawning = report.find("SPAWNING TASKS").unwrap();
+        assert_eq!(spawning.output("JoinSet completion order (spawned 0..4)"), Some("[3, 2, 1, 0]"));
+
+        // Both cancelled calls ran their guard's Drop, neither got past the sleep
+        let cancellation = report.find("CANCELLATION").unwrap();
+        let lines: Vec<String> = cancellation.entries.iter().map(ToString::to_string).collect();
+        assert_eq!(lines.iter().filter(|line| line.contains("Dropped guard")).count(), 2);
+        assert!(!lines.iter().any(|line| line.contains("never appears")));
+        assert_eq!(cancellation.output("Aborted task -> is_cancelled"), Some("true"));
+    }
+
+    #[test]
+    fn countdown_is_polled_once_per_step_plus_one() {
+        let (polls, wakes) = block_on(Countdown::new(3));
+        assert_eq!(polls, 4);
+        assert_eq!(wakes, 3, "it woke itself after every Pending");
+    }
+
+    #[test]
+    fn thread_timer_is_woken_from_the_helper_thread() {
+        let start = Instant::now();
+        let ((), wakes) = block_on(ThreadTimer::new(Duration::from_millis(20)));
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert_eq!(wakes, 1);
+    }
+
+    #[tokio::test]
+    async fn join_runs_futures_concurrently() {
+        let start = Instant::now();
+        assert_eq!(fetch_three_users_concurrently().await, ["user-1", "user-2", "user-3"]);
+        // Three 50ms fetches overlap instead of adding up to 150ms
+        assert!(start.elapsed() < Duration::from_millis(140));
+
+        let (user, orders) = user_with_orders(7).await;
+        assert_eq!(user, "user-7");
+        assert_eq!(orders, ["user-7-order-1", "user-7-order-2"]);
+    }
+
+    #[tokio::test]
+    async fn try_join_and_select_short_circuit() {
+        assert_eq!(parse_all(["1", "2"]).await, Ok((1, 2)));
+        assert!(parse_all(["1", "two"]).await.is_err());
+
+        let fast = fetch_with_deadline(Duration::from_millis(1), Duration::from_millis(200)).await;
+        assert_eq!(fast.as_deref(), Ok("user-42"));
+        let slow = fetch_with_deadline(Duration::from_millis(200), Duration::from_millis(1)).await;
+        assert!(slow.unwrap_err().starts_with("timed out"));
+    }
+
+    #[tokio::test]
+    async fn join_set_yields_in_completion_order() {
+        // Worker i sleeps 10 * (count - i) ms, so the last one spawned finishes first
+        assert_eq!(spawn_workers(3).await, [2, 1, 0]);
+        assert_eq!(checksum_offloaded(vec![1, 2, 3]).await, 6);
+    }
+}
@@ -0,0 +1,88 @@
+// ===== NEW MODULE GENERATOR =====
+//
+// Scaffolds a learning module with everything the crate's checks expect:
+// the module file, its quiz, a first exercise, and their registrations
+// (see src/scaffold/mod.rs for the full list).
+//
+// USAGE:
+//   cargo run --bin new-module ring_buffers
+//   cargo run --bin new-module ring_buffers --title "RING BUFFERS" \
+//       --description "Fixed-size circular queues" --after collections,iterators
+//
+// The number is the next free one, and --after may only name modules that
+// already exist, which keeps registry.rs's ordering tests happy.
+
+use std::path::Path;
+use std::process::ExitCode;
+
+use clap::Parser;
+use rust_basics::registry;
+use rust_basics::scaffold::{self, ModuleSpec};
+
+#[derive(Parser, Debug)]
+#[command(name = "new-module", about = "🦀 Scaffold a new rust-basics learning module")]
+struct Cli {
+    /// Module name in snake_case, e.g. ring_buffers
+    name: String,
+
+    /// Heading shown when the module runs [default: the name in upper case]
+    #[arg(long)]
+    title: Option<String>,
+
+    /// One line for `list` and the lib.rs header
+    #[arg(long)]
+    description: Option<String>,
+
+    /// Modules worth studying first, comma separated
+    #[arg(long, value_name = "MODULES", value_delimiter = ',')]
+    after: Vec<String>,
+
+    /// Name of the first exercise file
+    #[arg(long, default_value = "warmup")]
+    exercise: String,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("❌ {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    if let Some(existing) = registry::find(&cli.name) {
+        return Err(format!("module {} '{}' already exists", existing.number(), existing.name()));
+    }
+    let number = u8::try_from(registry::modules().len() + 1).map_err(|_| "no module numbers left".to_string())?;
+
+    let mut spec = ModuleSpec::new(&cli.name, number).map_err(|err| err.to_string())?;
+    if let Some(title) = cli.title {
+        spec.title = title;
+    }
+    if let Some(description) = cli.description {
+        spec.description = description;
+    }
+    scaffold::validate_name(&cli.exercise).map_err(|err| err.to_string())?;
+    spec.exercise = cli.exercise;
+    for prerequisite in &cli.after {
+        let module = registry::find(prerequisite).ok_or_else(|| format!("unknown module '{}' in --after", prerequisite))?;
+        spec.prerequisites.push(module.name().to_string());
+    }
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let written = scaffold::create(root, &spec).map_err(|err| err.to_string())?;
+
+    println!("🦀 Created module {}: {}\n", spec.number, spec.name);
+    for path in written {
+        println!("   {}", path.display());
+    }
+    println!("\nNext:");
+    println!("   fill in the TODOs in src/{}.rs, its quiz and its exercise", spec.name);
+    println!("   cargo test -p rust-basics");
+    println!("   cargo run -- run {}", spec.name);
+    Ok(())
+}
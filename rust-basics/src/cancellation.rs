@@ -0,0 +1,499 @@
+// ===== ASYNC CANCELLATION & SELECT =====
+//
+// async_await.rs showed that dropping a future cancels it. This module is
+// about what that means in practice, because in async Rust cancellation
+// happens ALL THE TIME without anyone calling "cancel":
+// • tokio::select! drops every branch that didn't win
+// • tokio::time::timeout drops the inner future when time runs out
+// • Dropping a JoinSet aborts every task still in it
+//
+// A future can be stopped at ANY .await. Whatever it had done up to there
+// stays done; whatever it was holding in local variables is dropped:
+//
+//   async fn read_record(..) {
+//       let mut record = Vec::new();      ← lives inside the future
+//       loop { record.push(next().await) } ← cancelled here: record is gone,
+//   }                                        the bytes were already consumed
+//
+// CANCEL-SAFE means "if this future is dropped before it completes, nothing
+// is lost". Receiver::recv, sleep and TcpListener::accept are; reading a
+// whole frame, Sender::send (the value is dropped) and anything keeping
+// progress in locals are not.
+
+use crate::report::Report;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+use tokio::time::{sleep, timeout};
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{Stream, StreamExt};
+
+// Counts the futures dropped before they called complete() - i.e. cancelled
+pub struct CancelGuard {
+    cancelled: Arc<AtomicUsize>,
+    completed: bool,
+}
+
+impl CancelGuard {
+    pub fn new(cancelled: &Arc<AtomicUsize>) -> Self {
+        CancelGuard { cancelled: Arc::clone(cancelled), completed: false }
+    }
+
+    pub fn complete(&mut self) {
+        self.completed = true;
+    }
+}
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.cancelled.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+// ===== 1. tokio::select! =====
+//
+// UNDERSTANDING select!:
+// • Polls every branch; the first to complete runs its handler, and ALL
+//   the other branch futures are dropped - cancelled - right there
+// • Branches are polled in random order to be fair; `biased;` polls them
+//   top to bottom (put shutdown first so it always wins)
+// • `Some(x) = rx.recv() =>` disables the branch when the pattern doesn't
+//   match (the channel closed); `else =>` runs when every branch is disabled
+// • In a loop, each iteration creates NEW branch futures - which is exactly
+//   where cancellation bugs come from (section 3)
+
+async fn replica(name: &'static str, delay_ms: u64, mut guard: CancelGuard) -> &'static str {
+    sleep(Duration::from_millis(delay_ms)).await;
+    guard.complete();
+    name
+}
+
+// Asks two replicas, keeps the faster answer; returns it and how many
+// requests were cancelled
+pub async fn race_replicas(primary_ms: u64, backup_ms: u64) -> (&'static str, usize) {
+    let cancelled = Arc::new(AtomicUsize::new(0));
+    let winner = tokio::select! {
+        name = replica("primary", primary_ms, CancelGuard::new(&cancelled)) => name,
+        name = replica("backup", backup_ms, CancelGuard::new(&cancelled)) => name,
+    };
+    // The loser was dropped when select! finished
+    (winner, cancelled.load(Ordering::SeqCst))
+}
+
+// ===== 2. TIMEOUTS AND DEADLINES =====
+//
+// UNDERSTANDING TIMEOUTS:
+// • timeout(d, future) is a select! between the future and a sleep:
+//   Err(Elapsed) means the future was dropped unfinished
+// • A per-attempt timeout bounds one try; a DEADLINE bounds the whole
+//   operation, retries included
+// • For a deadline in a loop, create ONE sleep, pin it, and select! on
+//   &mut deadline every iteration. A fresh sleep(..) per iteration would
+//   restart the clock each time
+
+// Each attempt takes attempt_ms[i]; succeeds with the index of the first
+// attempt that finishes within per_try_ms, unless the deadline passes first
+pub async fn call_with_deadline(attempt_ms: &[u64], per_try_ms: u64, deadline_ms: u64) -> Result<usize, String> {
+    let deadline = sleep(Duration::from_millis(deadline_ms));
+    tokio::pin!(deadline);
+    for (attempt, &duration) in attempt_ms.iter().enumerate() {
+        let call = timeout(Duration::from_millis(per_try_ms), sleep(Duration::from_millis(duration)));
+        tokio::select! {
+            result = call => {
+                if result.is_ok() {
+                    return Ok(attempt);
+                }
+            }
+            _ = &mut deadline => return Err(format!("deadline of {}ms passed during attempt {}", deadline_ms, attempt)),
+        }
+    }
+    Err(format!("all {} attempts timed out", attempt_ms.len()))
+}
+
+// ===== 3. CANCELLATION SAFETY PITFALLS =====
+//
+// PITFALL 1 - A PARTIALLY CONSUMED STREAM:
+// • read_record pulls bytes until ';', collecting them in a local Vec
+// • In a select! loop next to a ticker, every tick cancels the read that
+//   was in progress. The bytes it took from the stream are gone with it -
+//   the next read starts mid-record
+// • The fix moves the progress OUT of the future: RecordReader keeps the
+//   buffer in self, so a new next_record() call resumes where the
+//   cancelled one stopped. stream.next() itself is cancel-safe, so nothing
+//   is lost between them
+//
+// PITFALL 2 - A LOST MESSAGE:
+// • select! { _ = tx.send(item) => .., _ = shutdown => break } moves item
+//   into the send future. If shutdown wins, that future - and item - is
+//   dropped: the message is neither sent nor kept
+// • The fix: reserve() a slot first (cancel-safe: nothing is moved), and
+//   only hand the item over once the permit exists
+//
+// OTHER COMMON ONES:
+// • AsyncReadExt::read_exact / read_to_end in select! lose partial reads
+// • Mutex::lock() in select! gives up its place in the queue
+// • Code after an .await that restores an invariant never runs - keep
+//   invariants true at every .await, or restore them in Drop
+
+// NOT cancel-safe: the record so far lives in this future
+pub async fn read_record<S: Stream<Item = u8> + Unpin>(stream: &mut S) -> Option<String> {
+    let mut record = Vec::new();
+    while let Some(byte) = stream.next().await {
+        if byte == b';' {
+            return Some(String::from_utf8_lossy(&record).into_owned());
+        }
+        record.push(byte);
+    }
+    None
+}
+
+// Cancel-safe: the record so far lives in the reader
+pub struct RecordReader<S> {
+    stream: S,
+    buffer: Vec<u8>,
+}
+
+impl<S: Stream<Item = u8> + Unpin> RecordReader<S> {
+    pub fn new(stream: S) -> Self {
+        RecordReader { stream, buffer: Vec::new() }
+    }
+
+    pub async fn next_record(&mut self) -> Option<String> {
+        while let Some(byte) = self.stream.next().await {
+            if byte == b';' {
+                return Some(String::from_utf8_lossy(&std::mem::take(&mut self.buffer)).into_owned());
+            }
+            self.buffer.push(byte);
+        }
+        None
+    }
+}
+
+enum Reader<S> {
+    Lossy(S),
+    Safe(RecordReader<S>),
+}
+
+impl<S: Stream<Item = u8> + Unpin> Reader<S> {
+    async fn next(&mut self) -> Option<String> {
+        match self {
+            Reader::Lossy(stream) => read_record(stream).await,
+            Reader::Safe(reader) => reader.next_record().await,
+        }
+    }
+}
+
+// Streams `text` one byte at a time with a tick after every byte, and reads
+// records in a biased select! loop that also handles the ticks. A tick
+// that arrives with a byte cancels the read in progress
+pub async fn read_records_while_ticking(text: &'static str, cancel_safe: bool) -> Vec<String> {
+    let (byte_sender, byte_receiver) = mpsc::channel(64);
+    let (tick_sender, mut ticks) = mpsc::channel(64);
+    let producer = tokio::spawn(async move {
+        for byte in text.bytes() {
+            if byte_sender.send(byte).await.is_err() || tick_sender.send(()).await.is_err() {
+                break;
+            }
+            sleep(Duration::from_millis(1)).await;
+        }
+    });
+
+    let bytes = ReceiverStream::new(byte_receiver);
+    let mut reader = if cancel_safe { Reader::Safe(RecordReader::new(bytes)) } else { Reader::Lossy(bytes) };
+
+    let mut records = Vec::new();
+    loop {
+        let record = tokio::select! {
+            biased;
+            record = reader.next() => record,
+            Some(()) = ticks.recv() => continue,
+        };
+        match record {
+            Some(record) => records.push(record),
+            None => break,
+        }
+    }
+    let _ = producer.await;
+    records
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Delivery {
+    pub delivered: Vec<u32>,
+    // Not sent before shutdown, but still here to retry or persist
+    pub kept: Vec<u32>,
+}
+
+// Sends items into a one-slot channel nobody reads until shutdown_ms has
+// passed - so shutdown always interrupts a send
+pub async fn send_until_shutdown(items: Vec<u32>, shutdown_ms: u64, cancel_safe: bool) -> Delivery {
+    let (sender, mut receiver) = mpsc::channel(1);
+    let shutdown = sleep(Duration::from_millis(shutdown_ms));
+    tokio::pin!(shutdown);
+    let mut kept = Vec::new();
+    let mut items = items.into_iter();
+
+    for item in items.by_ref() {
+        if cancel_safe {
+            tokio::select! {
+                permit = sender.reserve() => match permit {
+                    Ok(permit) => permit.send(item),
+                    Err(_) => {
+                        kept.push(item);
+                        break;
+                    }
+                },
+                _ = &mut shutdown => {
+                    kept.push(item);
+                    break;
+                }
+            }
+        } else {
+            tokio::select! {
+                result = sender.send(item) => {
+                    if result.is_err() {
+                        break;
+                    }
+                }
+                // `item` was moved into the send future, which is dropped here
+                _ = &mut shutdown => break,
+            }
+        }
+    }
+    kept.extend(items);
+
+    drop(sender);
+    let mut delivered = Vec::new();
+    while let Some(item) = receiver.recv().await {
+        delivered.push(item);
+    }
+    Delivery { delivered, kept }
+}
+
+// ===== 4. STRUCTURED CONCURRENCY WITH JoinSet =====
+//
+// UNDERSTANDING STRUCTURE:
+// • tokio::spawn's tasks outlive the code that spawned them: if the caller
+//   returns early (an error, a timeout, its own cancellation), orphaned
+//   tasks keep running with nobody waiting for their results
+// • A JoinSet owns its tasks: join_next() yields results as they finish,
+//   and dropping the set aborts whatever is still running - children can't
+//   outlive their scope
+// • Fail fast: on the first error, stop waiting and shutdown().await -
+//   it aborts the rest and waits until they have actually stopped
+// • For cooperative shutdown (letting tasks finish their current step),
+//   pair it with a CancellationToken (tokio_channels.rs)
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FanOut {
+    pub result: Result<Vec<u64>, String>,
+    pub completed: usize,
+    pub cancelled: usize,
+}
+
+// Runs every job (delay_ms, fails) at once; the first failure cancels the
+// rest
+pub async fn fetch_all(jobs: &[(u64, bool)]) -> FanOut {
+    let cancelled = Arc::new(AtomicUsize::new(0));
+    let mut set = JoinSet::new();
+    for (id, &(delay_ms, fails)) in jobs.iter().enumerate() {
+        let mut guard = CancelGuard::new(&cancelled);
+        set.spawn(async move {
+            sleep(Duration::from_millis(delay_ms)).await;
+            guard.complete();
+            if fails { Err(format!("job {} failed", id)) } else { Ok(delay_ms) }
+        });
+    }
+
+    let mut values = Vec::new();
+    let mut completed = 0;
+    let result = loop {
+        match set.join_next().await {
+            None => break Ok(values),
+            Some(Ok(Ok(value))) => {
+                completed += 1;
+                values.push(value);
+            }
+            Some(Ok(Err(message))) => {
+                completed += 1;
+                break Err(message);
+            }
+            Some(Err(join_error)) => break Err(join_error.to_string()),
+        }
+    };
+    // Aborts the tasks still running and waits for them to be dropped
+    set.shutdown().await;
+
+    FanOut { result, completed, cancelled: cancelled.load(Ordering::SeqCst) }
+}
+
+// ===== 5. DEMONSTRATION FUNCTION =====
+
+pub async fn cancellation_report() -> Report {
+    let mut report = Report::new("🦀 ASYNC CANCELLATION & SELECT 🦀");
+
+    let (winner, cancelled) = race_replicas(30, 5).await;
+    report
+        .section("1️⃣ tokio::select!:")
+        .example("race_replicas(primary 30ms, backup 5ms)", format!("{} wins, {} request cancelled", winner, cancelled));
+
+    let in_time = call_with_deadline(&[200, 200, 5], 50, 500).await;
+    let too_late = call_with_deadline(&[200, 200, 5], 50, 75).await;
+    report
+        .section("2️⃣ TIMEOUTS AND DEADLINES:")
+        .example("attempts [200ms, 200ms, 5ms], 50ms per try, 500ms deadline", format!("{:?}", in_time))
+        .example("attempts [200ms, 200ms, 5ms], 50ms per try, 75ms deadline", format!("{:?}", too_late));
+
+    let text = "alpha;beta;gamma;";
+    let local = read_records_while_ticking(text, false).await;
+    let kept = read_records_while_ticking(text, true).await;
+    let sent = send_until_shutdown(vec![1, 2, 3, 4], 20, false).await;
+    let reserved = send_until_shutdown(vec![1, 2, 3, 4], 20, true).await;
+    report
+        .section("3️⃣ CANCELLATION SAFETY PITFALLS:")
+        .text(format!("stream {:?} with a tick after every byte:", text))
+        .example("read_record (progress in a local)", format!("{:?}", local))
+        .example("RecordReader (progress in self)", format!("{:?}", kept))
+        .text("sending [1, 2, 3, 4] into a full channel, shutdown after 20ms:")
+        .example("send() in select!", format!("{:?}", sent))
+        .example("reserve() in select!", format!("{:?}", reserved));
+
+    let all_succeed = fetch_all(&[(10, false), (20, false), (5, false)]).await;
+    let one_fails = fetch_all(&[(200, false), (10, true), (300, false)]).await;
+    report
+        .section("4️⃣ STRUCTURED CONCURRENCY WITH JoinSet:")
+        .example("all succeed", format!("{:?}", all_succeed))
+        .example("job 1 fails after 10ms", format!("{:?}", one_fails));
+
+    report
+        .section("🎯 CANCELLATION SUMMARY:")
+        .check("select!, timeout and dropping a JoinSet all cancel by dropping futures")
+        .check("A future can stop at any .await - local progress is lost")
+        .check("Keep progress in a struct that outlives each call (RecordReader)")
+        .check("reserve() before send() so a cancelled send keeps the message")
+        .check("Pin one deadline for a whole retry loop")
+        .check("JoinSet ties task lifetimes to a scope; shutdown() fails fast cleanly");
+
+    report
+}
+
+pub async fn demonstrate_cancellation() {
+    print!("{}", cancellation_report().await);
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct CancellationModule;
+
+impl LearningModule for CancellationModule {
+    fn number(&self) -> u8 {
+        26
+    }
+
+    fn name(&self) -> &'static str {
+        "cancellation"
+    }
+
+    fn title(&self) -> &'static str {
+        "ASYNC CANCELLATION & SELECT"
+    }
+
+    fn description(&self) -> &'static str {
+        "select!, Timeouts, Cancel Safety, JoinSet"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["async_await", "tokio_channels"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(demonstrate_cancellation())
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(cancellation_report()))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&CancellationModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// CANCELLATION BEST PRACTICES:
+// 1. Before putting a future in select!, ask: what if it's dropped here?
+// 2. Check the docs - tokio marks each method's cancel safety
+// 3. Move in-progress state out of the future and into a long-lived struct
+// 4. Reserve capacity before giving up ownership of a message
+// 5. Use one pinned deadline, not a new timer per loop iteration
+// 6. Spawn children into a JoinSet so they can't outlive their parent
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn report_contrasts_lossy_and_safe_versions() {
+        let report = cancellation_report().await;
+        let select = report.find("tokio::select!").unwrap();
+        assert_eq!(select.output("race_replicas(primary 30ms, backup 5ms)"), Some("backup wins, 1 request cancelled"));
+
+        let pitfalls = report.find("CANCELLATION SAFETY").unwrap();
+        assert_eq!(pitfalls.output("RecordReader (progress in self)"), Some(r#"["alpha", "beta", "gamma"]"#));
+        assert_ne!(pitfalls.output("read_record (progress in a local)"), pitfalls.output("RecordReader (progress in self)"));
+
+        let joinset = report.find("JoinSet").unwrap();
+        assert!(joinset.output("job 1 fails after 10ms").unwrap().contains(r#"Err("job 1 failed")"#));
+    }
+
+    #[tokio::test]
+    async fn select_cancels_the_loser() {
+        assert_eq!(race_replicas(100, 1).await, ("backup", 1));
+        assert_eq!(race_replicas(1, 100).await, ("primary", 1));
+    }
+
+    #[tokio::test]
+    async fn deadline_spans_all_attempts() {
+        assert_eq!(call_with_deadline(&[500, 500, 1], 60, 1_000).await, Ok(2));
+        // Attempt 1 runs from ~60ms to ~120ms; the deadline lands inside it
+        assert_eq!(
+            call_with_deadline(&[500, 500, 1], 60, 90).await,
+            Err("deadline of 90ms passed during attempt 1".to_string())
+        );
+        assert_eq!(call_with_deadline(&[500], 10, 1_000).await, Err("all 1 attempts timed out".to_string()));
+    }
+
+    #[tokio::test]
+    async fn cancelled_reads_lose_bytes_unless_progress_is_kept() {
+        let text = "one;two;three;";
+        assert_eq!(read_records_while_ticking(text, true).await, ["one", "two", "three"]);
+        let lossy = read_records_while_ticking(text, false).await;
+        assert_ne!(lossy, ["one", "two", "three"], "ticks cancelled reads mid-record");
+    }
+
+    #[tokio::test]
+    async fn reserve_keeps_the_message_a_cancelled_send_loses() {
+        let lossy = send_until_shutdown(vec![1, 2, 3], 20, false).await;
+        assert_eq!(lossy, Delivery { delivered: vec![1], kept: vec![3] }, "2 was dropped with its send");
+
+        let safe = send_until_shutdown(vec![1, 2, 3], 20, true).await;
+        assert_eq!(safe, Delivery { delivered: vec![1], kept: vec![2, 3] });
+    }
+
+    #[tokio::test]
+    async fn joinset_fails_fast_and_cancels_siblings() {
+        let ok = fetch_all(&[(5, false), (1, false)]).await;
+        assert_eq!(ok, FanOut { result: Ok(vec![1, 5]), completed: 2, cancelled: 0 });
+
+        let failed = fetch_all(&[(1_000, false), (1, true), (1_000, false)]).await;
+        assert_eq!(failed.result, Err("job 1 failed".to_string()));
+        assert_eq!((failed.completed, failed.cancelled), (1, 2));
+    }
+}
@@ -0,0 +1,585 @@
+// ===== COLLECTIONS DEEP STUDY =====
+//
+// WHAT ARE COLLECTIONS?
+// The standard library's heap-allocated containers. Picking the right one is
+// mostly about ACCESS PATTERNS: how you insert, remove, look up and iterate.
+//
+// THE STANDARD COLLECTIONS:
+// • Vec<T>: Growable array - the default choice
+// • VecDeque<T>: Ring buffer - fast push/pop at BOTH ends
+// • HashMap<K, V>: Key → value, O(1) average, unordered
+// • BTreeMap<K, V>: Key → value, O(log n), SORTED by key
+// • HashSet<T> / BTreeSet<T>: Unique values (maps with () values)
+// • BinaryHeap<T>: Priority queue - always pops the largest item
+// • LinkedList<T>: Rarely the right answer (poor cache locality)
+
+use crate::report::Report;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// ===== 1. VEC<T> - CAPACITY AND ALLOCATION =====
+//
+// UNDERSTANDING VEC LAYOUT:
+// • Three words on the stack: pointer, length, capacity
+// • len: How many elements are initialized
+// • capacity: How many fit before the next reallocation
+// • When len == capacity, push() allocates a bigger buffer (typically 2x)
+//   and MOVES every element - amortized O(1), but each growth is O(n)
+//
+// CAPACITY TOOLS:
+// • Vec::with_capacity(n): Allocate once up front when the size is known
+// • reserve(n): Make room for n more elements
+// • shrink_to_fit(): Give unused capacity back to the allocator
+// • clear(): Drops elements but KEEPS capacity (great for reuse in loops)
+
+// Records each capacity change while pushing - shows the growth strategy
+pub fn vec_growth_steps(items: usize) -> Vec<usize> {
+    let mut v: Vec<u64> = Vec::new();
+    let mut capacities = vec![v.capacity()];
+
+    for i in 0..items {
+        v.push(i as u64);
+        if v.capacity() != *capacities.last().unwrap() {
+            capacities.push(v.capacity()); // A reallocation just happened
+        }
+    }
+
+    capacities
+}
+
+pub fn count_reallocations(items: usize, preallocate: bool) -> usize {
+    let mut v: Vec<u64> = if preallocate { Vec::with_capacity(items) } else { Vec::new() };
+    let mut reallocations = 0;
+    let mut last_capacity = v.capacity();
+
+    for i in 0..items {
+        v.push(i as u64);
+        if v.capacity() != last_capacity {
+            reallocations += 1;
+            last_capacity = v.capacity();
+        }
+    }
+
+    reallocations
+}
+
+// ===== 2. VECDEQUE<T> - DOUBLE-ENDED QUEUE =====
+//
+// UNDERSTANDING VECDEQUE:
+// • A ring buffer: head and tail wrap around one allocation
+// • push_front / pop_front are O(1) (Vec::insert(0, x) / remove(0) are O(n))
+// • Elements may be split in two slices - as_slices() / make_contiguous()
+// • Perfect for FIFO queues, sliding windows, BFS frontiers
+
+// Sliding window maximum with a monotonic deque (classic VecDeque use)
+pub fn sliding_window_max(values: &[i32], window: usize) -> Vec<i32> {
+    let mut deque: VecDeque<usize> = VecDeque::new(); // Indices, values decreasing
+    let mut result = Vec::new();
+
+    for (i, &value) in values.iter().enumerate() {
+        // Drop indices that fell out of the window (front)
+        if deque.front().is_some_and(|&front| front + window <= i) {
+            deque.pop_front();
+        }
+        // Drop smaller values - they can never be the max again (back)
+        while deque.back().is_some_and(|&back| values[back] <= value) {
+            deque.pop_back();
+        }
+        deque.push_back(i);
+
+        if i + 1 >= window {
+            result.push(values[deque[0]]);
+        }
+    }
+
+    result
+}
+
+// ===== 3. HASHMAP VS BTREEMAP =====
+//
+// HASHMAP<K, V>:
+// • O(1) average insert/lookup, iteration order is arbitrary (and randomized)
+// • Keys need Hash + Eq
+// • Default hasher (SipHash) resists HashDoS; faster hashers exist for trusted keys
+//
+// BTREEMAP<K, V>:
+// • O(log n) operations, iteration is always SORTED by key
+// • Keys need Ord
+// • Supports range queries: map.range(a..b), first_key_value(), last_key_value()
+// • Deterministic output - nice for reports, tests and snapshots
+
+pub fn scores_in_rank_order(scores: &[(&str, u32)]) -> Vec<(u32, String)> {
+    // Reverse(score) makes the BTreeMap iterate highest score first
+    let mut ranked: BTreeMap<(Reverse<u32>, String), ()> = BTreeMap::new();
+    for &(name, score) in scores {
+        ranked.insert((Reverse(score), name.to_string()), ());
+    }
+    ranked.into_keys().map(|(Reverse(score), name)| (score, name)).collect()
+}
+
+pub fn events_between(events: &BTreeMap<u32, &str>, from: u32, to: u32) -> Vec<String> {
+    events
+        .range(from..to) // Only possible because keys are sorted
+        .map(|(time, name)| format!("{}@{}", name, time))
+        .collect()
+}
+
+// ===== 4. THE ENTRY API =====
+//
+// UNDERSTANDING ENTRY:
+// map.entry(key) looks the key up ONCE and returns Occupied or Vacant.
+// It replaces the double-lookup "if contains_key { get_mut } else { insert }".
+//
+// COMMON PATTERNS:
+// • Counting:      *map.entry(k).or_insert(0) += 1;
+// • Grouping:      map.entry(k).or_default().push(v);
+// • Lazy default:  map.entry(k).or_insert_with(|| expensive());
+// • Update-or-init: map.entry(k).and_modify(|v| ...).or_insert(init);
+// • Full control:  match map.entry(k) { Entry::Occupied(e) => ..., Entry::Vacant(e) => ... }
+
+pub fn word_frequencies(text: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+    }
+    counts
+}
+
+pub fn group_by_length(words: &[&str]) -> BTreeMap<usize, Vec<String>> {
+    let mut groups: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+    for word in words {
+        groups.entry(word.len()).or_default().push(word.to_string());
+    }
+    groups
+}
+
+// and_modify + or_insert: track (min, max) per sensor in one lookup
+pub fn min_max_per_key(readings: &[(&str, i32)]) -> BTreeMap<String, (i32, i32)> {
+    let mut ranges: BTreeMap<String, (i32, i32)> = BTreeMap::new();
+    for &(sensor, value) in readings {
+        ranges
+            .entry(sensor.to_string())
+            .and_modify(|(min, max)| {
+                *min = (*min).min(value);
+                *max = (*max).max(value);
+            })
+            .or_insert((value, value));
+    }
+    ranges
+}
+
+// ===== 5. HASHSET<T> =====
+//
+// UNDERSTANDING HASHSET:
+// • A HashMap<T, ()> - unique values, O(1) membership test
+// • insert() returns false if the value was already present (handy for dedup)
+// • Set algebra: union, intersection, difference, symmetric_difference
+
+// Dedup while preserving first-seen order (sort + dedup would reorder)
+pub fn dedup_preserving_order(items: &[&str]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    items
+        .iter()
+        .filter(|item| seen.insert(**item)) // false for repeats
+        .map(|item| item.to_string())
+        .collect()
+}
+
+pub fn common_and_unique(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let a: HashSet<u32> = a.iter().copied().collect();
+    let b: HashSet<u32> = b.iter().copied().collect();
+
+    let mut common: Vec<u32> = a.intersection(&b).copied().collect();
+    let mut only_a: Vec<u32> = a.difference(&b).copied().collect();
+    common.sort_unstable();
+    only_a.sort_unstable();
+    (common, only_a)
+}
+
+// ===== 6. BINARYHEAP<T> - PRIORITY QUEUE =====
+//
+// UNDERSTANDING BINARYHEAP:
+// • A max-heap: pop() always returns the LARGEST item, O(log n)
+// • peek() is O(1)
+// • For a MIN-heap, wrap items in std::cmp::Reverse
+// • Items are compared with Ord - tuples compare field by field
+//   (so (priority, name) orders by priority, then name)
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Task {
+    pub priority: u8, // Compared first (derive uses field order)
+    pub name: String,
+}
+
+pub fn process_by_priority(tasks: Vec<Task>) -> Vec<String> {
+    let mut heap: BinaryHeap<Task> = tasks.into_iter().collect();
+    let mut order = Vec::new();
+    while let Some(task) = heap.pop() {
+        order.push(format!("{}(p{})", task.name, task.priority));
+    }
+    order
+}
+
+// Top-k smallest with a bounded max-heap: O(n log k) instead of sorting everything
+pub fn k_smallest(values: &[i32], k: usize) -> Vec<i32> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for &value in values {
+        heap.push(value);
+        if heap.len() > k {
+            heap.pop(); // Evict the largest - it can't be in the k smallest
+        }
+    }
+    heap.into_sorted_vec()
+}
+
+// ===== 7. MICRO-BENCHMARKS =====
+//
+// HOW THESE BENCHMARKS WORK:
+// • Time a closure over many iterations with Instant
+// • black_box() stops the optimizer from deleting work whose result is unused
+// • Numbers are indicative only - run in --release, and use a real harness
+//   (criterion) for anything you want to quote
+//
+// WHAT THEY SHOW:
+// • Preallocating a Vec avoids repeated reallocation
+// • Vec::insert(0, x) is O(n); VecDeque::push_front is O(1)
+// • Vec::contains is O(n); HashSet::contains is O(1) - but for tiny
+//   collections a linear scan is often just as fast
+
+pub fn bench<F: FnMut()>(iterations: u32, mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    start.elapsed() / iterations
+}
+
+pub struct BenchResult {
+    pub name: &'static str,
+    pub per_iteration: Duration,
+}
+
+pub fn run_collection_benchmarks(n: usize, iterations: u32) -> Vec<BenchResult> {
+    let lookup_vec: Vec<usize> = (0..n).collect();
+    let lookup_set: HashSet<usize> = (0..n).collect();
+
+    vec![
+        BenchResult {
+            name: "Vec::new + push",
+            per_iteration: bench(iterations, || {
+                let mut v = Vec::new();
+                for i in 0..n {
+                    v.push(i);
+                }
+                black_box(v);
+            }),
+        },
+        BenchResult {
+            name: "Vec::with_capacity + push",
+            per_iteration: bench(iterations, || {
+                let mut v = Vec::with_capacity(n);
+                for i in 0..n {
+                    v.push(i);
+                }
+                black_box(v);
+            }),
+        },
+        BenchResult {
+            name: "Vec::insert(0, x)",
+            per_iteration: bench(iterations, || {
+                let mut v = Vec::new();
+                for i in 0..n {
+                    v.insert(0, i);
+                }
+                black_box(v);
+            }),
+        },
+        BenchResult {
+            name: "VecDeque::push_front",
+            per_iteration: bench(iterations, || {
+                let mut d = VecDeque::new();
+                for i in 0..n {
+                    d.push_front(i);
+                }
+                black_box(d);
+            }),
+        },
+        BenchResult {
+            name: "Vec::contains (n lookups)",
+            per_iteration: bench(iterations, || {
+                for i in 0..n {
+                    black_box(lookup_vec.contains(&black_box(i)));
+                }
+            }),
+        },
+        BenchResult {
+            name: "HashSet::contains (n lookups)",
+            per_iteration: bench(iterations, || {
+                for i in 0..n {
+                    black_box(lookup_set.contains(&black_box(i)));
+                }
+            }),
+        },
+    ]
+}
+
+// ===== 8. CHOOSING THE RIGHT COLLECTION =====
+//
+// DECISION GUIDE:
+// • Need a list?                          → Vec (almost always)
+// • Push/pop at both ends?                → VecDeque
+// • Look up by key, order irrelevant?     → HashMap
+// • Look up by key, need sorted/range?    → BTreeMap
+// • Only care about membership?           → HashSet / BTreeSet
+// • Always want the min/max next?         → BinaryHeap
+// • Small n (< ~32)?                      → Vec with linear search is often fastest
+
+pub fn recommend_collection(need: &str) -> &'static str {
+    match need {
+        "list" | "stack" => "Vec<T>",
+        "queue" | "both ends" => "VecDeque<T>",
+        "lookup" => "HashMap<K, V>",
+        "sorted lookup" | "range query" => "BTreeMap<K, V>",
+        "unique" | "membership" => "HashSet<T>",
+        "priority" | "top-k" => "BinaryHeap<T>",
+        _ => "Vec<T> (when in doubt)",
+    }
+}
+
+// ===== 9. DEMONSTRATION FUNCTION =====
+
+pub fn collections_report() -> Report {
+    let mut report = Report::new("🦀 RUST COLLECTIONS DEEP STUDY 🦀");
+
+    // ===== VEC =====
+    let vec = report.section("1️⃣ VEC<T> - CAPACITY & ALLOCATION:");
+    vec.example("Capacity steps while pushing 100 items", format!("{:?}", vec_growth_steps(100)))
+        .example(
+            "Reallocations for 10,000 pushes",
+            format!(
+                "Vec::new = {}, Vec::with_capacity = {}",
+                count_reallocations(10_000, false),
+                count_reallocations(10_000, true)
+            ),
+        );
+
+    let mut buffer: Vec<u8> = Vec::with_capacity(64);
+    buffer.extend_from_slice(b"hello");
+    buffer.clear();
+    vec.example("After clear()", format!("len = {}, capacity = {} (allocation kept for reuse)", buffer.len(), buffer.capacity()));
+    buffer.shrink_to_fit();
+    vec.example("After shrink_to_fit()", format!("capacity = {}", buffer.capacity()));
+
+    // ===== VECDEQUE =====
+    let mut deque: VecDeque<i32> = VecDeque::with_capacity(4);
+    deque.push_back(2);
+    deque.push_back(3);
+    deque.push_front(1);
+    deque.push_front(0);
+    report
+        .section("2️⃣ VECDEQUE<T> - DOUBLE-ENDED QUEUE:")
+        .example("Deque after push_front/push_back", format!("{:?}", deque))
+        .example("Underlying slices (ring buffer may wrap)", format!("{:?}", deque.as_slices()))
+        .example(
+            "Sliding window max (k=3) of [1,3,-1,-3,5,3,6,7]",
+            format!("{:?}", sliding_window_max(&[1, 3, -1, -3, 5, 3, 6, 7], 3)),
+        );
+
+    // ===== MAPS =====
+    let ranked = scores_in_rank_order(&[("ana", 90), ("bo", 75), ("cy", 90), ("di", 82)]);
+    let events = BTreeMap::from([(5, "boot"), (12, "login"), (20, "deploy"), (31, "logout")]);
+    report
+        .section("3️⃣ HASHMAP VS BTREEMAP:")
+        .example("BTreeMap rank order (score desc, name asc)", format!("{:?}", ranked))
+        .example("BTreeMap range 10..25", format!("{:?}", events_between(&events, 10, 25)))
+        .example("First / last event", format!("{:?} / {:?}", events.first_key_value(), events.last_key_value()));
+
+    // ===== ENTRY API =====
+    let frequencies = word_frequencies("the cat and The hat and the bat");
+    let mut sorted: Vec<_> = frequencies.into_iter().collect();
+    sorted.sort();
+    report
+        .section("4️⃣ ENTRY API PATTERNS:")
+        .example("Counting with or_insert", format!("{:?}", sorted))
+        .example("Grouping with or_default", format!("{:?}", group_by_length(&["a", "to", "be", "cat", "dog", "I"])))
+        .example(
+            "Update-or-init with and_modify",
+            format!("{:?}", min_max_per_key(&[("temp", 21), ("hum", 40), ("temp", 18), ("temp", 25), ("hum", 38)])),
+        );
+
+    // ===== HASHSET =====
+    let (common, only_a) = common_and_unique(&[1, 2, 3, 4], &[3, 4, 5]);
+    report
+        .section("5️⃣ HASHSET<T>:")
+        .example("Order-preserving dedup", format!("{:?}", dedup_preserving_order(&["b", "a", "b", "c", "a"])))
+        .example("Intersection, difference (a - b)", format!("{:?}, {:?}", common, only_a));
+
+    // ===== BINARYHEAP =====
+    let order = process_by_priority(vec![
+        Task { priority: 1, name: "cleanup".to_string() },
+        Task { priority: 5, name: "outage".to_string() },
+        Task { priority: 3, name: "review".to_string() },
+    ]);
+    let mut min_heap = BinaryHeap::from([Reverse(5), Reverse(1), Reverse(3)]);
+    report
+        .section("6️⃣ BINARYHEAP<T> - PRIORITY QUEUE:")
+        .example("Processing order (max-heap)", format!("{:?}", order))
+        .example("3 smallest of [9,4,7,1,8,2,6]", format!("{:?}", k_smallest(&[9, 4, 7, 1, 8, 2, 6], 3)))
+        .example("Min-heap with Reverse pops", format!("{:?}", min_heap.pop().map(|Reverse(v)| v)));
+
+    // ===== BENCHMARKS =====
+    let benchmarks = report.section("7️⃣ MICRO-BENCHMARKS (n = 2,000, debug builds exaggerate differences):");
+    for result in run_collection_benchmarks(2_000, 20) {
+        benchmarks.example(result.name, format!("{:?} per iteration", result.per_iteration));
+    }
+
+    // ===== CHOOSING =====
+    let choosing = report.section("8️⃣ CHOOSING THE RIGHT COLLECTION:");
+    for need in ["stack", "both ends", "lookup", "range query", "membership", "top-k"] {
+        choosing.example(format!("Need {}", need), recommend_collection(need));
+    }
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 COLLECTIONS CONCEPTS SUMMARY:")
+        .check("Vec: default choice; preallocate when size is known")
+        .check("VecDeque: O(1) at both ends")
+        .check("HashMap for speed, BTreeMap for order and ranges")
+        .check("Entry API: one lookup for insert-or-update")
+        .check("HashSet for membership and set algebra")
+        .check("BinaryHeap for priorities and top-k");
+
+    report
+}
+
+pub fn demonstrate_collections() {
+    print!("{}", collections_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct CollectionsModule;
+
+impl LearningModule for CollectionsModule {
+    fn number(&self) -> u8 {
+        8
+    }
+
+    fn name(&self) -> &'static str {
+        "collections"
+    }
+
+    fn title(&self) -> &'static str {
+        "COLLECTIONS DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Collections"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["traits"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_collections() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { collections_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&CollectionsModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// COLLECTION BEST PRACTICES:
+// 1. Default to Vec; switch only when an access pattern demands it
+// 2. Use with_capacity/reserve when you know (roughly) how many items are coming
+// 3. Reuse buffers with clear() in hot loops instead of reallocating
+// 4. Use the entry API instead of contains_key + insert/get_mut
+// 5. Use BTreeMap when output order matters (reports, tests, snapshots)
+// 6. Measure before optimizing - cache locality often beats big-O for small n
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_covers_each_collection() {
+        let report = collections_report();
+        let heap = report.find("BINARYHEAP").unwrap();
+        assert_eq!(heap.output("Processing order (max-heap)"), Some(r#"["outage(p5)", "review(p3)", "cleanup(p1)"]"#));
+        assert_eq!(heap.output("Min-heap with Reverse pops"), Some("Some(1)"));
+        assert_eq!(
+            report.find("ENTRY API").unwrap().output("Counting with or_insert"),
+            Some(r#"[("and", 2), ("bat", 1), ("cat", 1), ("hat", 1), ("the", 3)]"#)
+        );
+        assert_eq!(report.find("CHOOSING").unwrap().outputs().count(), 6);
+    }
+
+    #[test]
+    fn vec_capacity_grows_geometrically() {
+        let steps = vec_growth_steps(100);
+        assert_eq!(steps[0], 0, "Vec::new() doesn't allocate");
+        assert!(steps.windows(2).all(|pair| pair[1] > pair[0]));
+        assert!(*steps.last().unwrap() >= 100);
+        // Doubling means few reallocations: about log2(n)
+        assert!(count_reallocations(1_000, false) <= 12);
+        assert_eq!(count_reallocations(1_000, true), 0);
+    }
+
+    #[test]
+    fn sliding_window_max_matches_a_naive_scan() {
+        let values = [1, 3, -1, -3, 5, 3, 6, 7];
+        assert_eq!(sliding_window_max(&values, 3), [3, 3, 5, 5, 6, 7]);
+        let naive: Vec<i32> = values.windows(2).map(|w| *w.iter().max().unwrap()).collect();
+        assert_eq!(sliding_window_max(&values, 2), naive);
+        assert!(sliding_window_max(&values, 9).is_empty());
+    }
+
+    #[test]
+    fn btreemap_orders_ranks_and_ranges() {
+        let ranked = scores_in_rank_order(&[("bo", 70), ("al", 90), ("cy", 70)]);
+        assert_eq!(ranked, [(90, "al".to_string()), (70, "bo".to_string()), (70, "cy".to_string())]);
+
+        let events = BTreeMap::from([(5, "boot"), (10, "login"), (20, "logout")]);
+        assert_eq!(events_between(&events, 5, 20), ["boot@5", "login@10"]);
+        assert!(events_between(&events, 11, 20).is_empty());
+    }
+
+    #[test]
+    fn entry_api_counts_groups_and_tracks_ranges() {
+        let counts = word_frequencies("the The cat THE");
+        assert_eq!((counts["the"], counts["cat"]), (3, 1));
+
+        let groups = group_by_length(&["a", "bb", "cc", "d"]);
+        assert_eq!(groups[&1], ["a", "d"]);
+        assert_eq!(groups[&2], ["bb", "cc"]);
+
+        let ranges = min_max_per_key(&[("t1", 5), ("t2", 1), ("t1", -2), ("t1", 9)]);
+        assert_eq!((ranges["t1"], ranges["t2"]), ((-2, 9), (1, 1)));
+    }
+
+    #[test]
+    fn sets_and_heaps() {
+        assert_eq!(dedup_preserving_order(&["b", "a", "b", "c", "a"]), ["b", "a", "c"]);
+        assert_eq!(common_and_unique(&[1, 2, 3, 3], &[3, 4, 2]), (vec![2, 3], vec![1]));
+
+        let task = |priority, name: &str| Task { priority, name: name.to_string() };
+        let order = process_by_priority(vec![task(1, "low"), task(9, "high"), task(5, "b"), task(5, "a")]);
+        // Equal priorities fall back to the name, largest first
+        assert_eq!(order, ["high(p9)", "b(p5)", "a(p5)", "low(p1)"]);
+
+        assert_eq!(k_smallest(&[7, 1, 9, 3, 5], 3), [1, 3, 5]);
+        assert_eq!(k_smallest(&[2, 1], 5), [1, 2]);
+        assert!(k_smallest(&[2, 1], 0).is_empty());
+    }
+}
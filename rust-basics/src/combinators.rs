@@ -0,0 +1,458 @@
+// ===== OPTION & RESULT COMBINATORS =====
+//
+// Option and Result are ordinary enums, so everything can be written with
+// match. Combinators are the methods that name the common shapes of those
+// matches, so the code says what it does instead of spelling out how:
+//
+//   match x { Some(v) => Some(f(v)), None => None }   →  x.map(f)
+//   match x { Some(v) => f(v), None => None }         →  x.and_then(f)
+//   match x { Some(v) => Ok(v), None => Err(e()) }    →  x.ok_or_else(e)
+//   match x { Some(v) => v, None => fallback() }      →  x.unwrap_or_else(fallback)
+//   match x { Some(v) if p(&v) => Some(v), _ => None } →  x.filter(p)
+//
+// Every section below has a BEFORE function written with match and an
+// AFTER function written with combinators. They return the same values -
+// the tests check it - so the only difference is how much there is to read.
+
+use std::collections::HashMap;
+use std::num::ParseIntError;
+
+use thiserror::Error;
+
+use crate::report::Report;
+
+// The data every example looks things up in: a small staff directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct Employee {
+    pub id: u32,
+    pub name: String,
+    pub nickname: Option<String>,
+    pub email: Option<String>,
+    pub manager: Option<u32>,
+}
+
+pub struct Directory {
+    employees: HashMap<u32, Employee>,
+}
+
+impl Directory {
+    pub fn new(employees: impl IntoIterator<Item = Employee>) -> Self {
+        Directory { employees: employees.into_iter().map(|e| (e.id, e)).collect() }
+    }
+
+    pub fn get(&self, id: u32) -> Option<&Employee> {
+        self.employees.get(&id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LookupError {
+    #[error("no employee with id {0}")]
+    NotFound(u32),
+    #[error("employee {0} has no email address")]
+    NoEmail(u32),
+}
+
+// ===== 1. map AND and_then =====
+//
+// UNDERSTANDING THE PAIR:
+// • map(f): f returns a plain value - Some(v) becomes Some(f(v))
+// • and_then(f): f returns an Option itself - the result is NOT wrapped
+//   twice. Using map there gives Option<Option<T>>, the usual hint that
+//   and_then was meant
+// • as_deref() turns &Option<String> into Option<&str> without cloning -
+//   the first step of most chains over borrowed fields
+// • Result has the same pair, plus map_err for the error side
+
+// BEFORE: two nested matches (clippy suggests the AFTER version)
+#[allow(clippy::manual_map)]
+pub fn email_domain_match(employee: &Employee) -> Option<String> {
+    match &employee.email {
+        Some(email) => match email.split_once('@') {
+            Some((_, domain)) => Some(domain.to_lowercase()),
+            None => None,
+        },
+        None => None,
+    }
+}
+
+// AFTER: borrow, split (which may fail), transform (which can't)
+pub fn email_domain(employee: &Employee) -> Option<String> {
+    employee.email.as_deref().and_then(|email| email.split_once('@')).map(|(_, domain)| domain.to_lowercase())
+}
+
+// ===== 2. FROM Option TO Result: ok_or AND ok_or_else =====
+//
+// UNDERSTANDING THE CONVERSION:
+// • None carries no reason. ok_or(err) attaches one, turning the Option
+//   into a Result that ? can propagate to the caller
+// • ok_or(expr) evaluates expr every time, even for Some; ok_or_else(|| ..)
+//   only builds the error when it is needed - use it when the error
+//   allocates (format!, String) or is costly to build
+// • The reverse is .ok(): Result → Option, dropping the error
+
+// BEFORE
+pub fn work_email_match(directory: &Directory, id: u32) -> Result<&str, LookupError> {
+    let employee = match directory.get(id) {
+        Some(employee) => employee,
+        None => return Err(LookupError::NotFound(id)),
+    };
+    match &employee.email {
+        Some(email) => Ok(email),
+        None => Err(LookupError::NoEmail(id)),
+    }
+}
+
+// AFTER: each step names its error, ? does the early return
+pub fn work_email(directory: &Directory, id: u32) -> Result<&str, LookupError> {
+    let employee = directory.get(id).ok_or(LookupError::NotFound(id))?;
+    employee.email.as_deref().ok_or(LookupError::NoEmail(id))
+}
+
+// ===== 3. filter AND THE unwrap_or FAMILY =====
+//
+// UNDERSTANDING DEFAULTS:
+// • filter(p) keeps Some(v) only if p(&v) - "present AND usable"
+// • unwrap_or(v): a default that already exists (a literal, a field)
+// • unwrap_or_else(|| ..): a default that costs something to build
+// • unwrap_or_default(): T::default() - 0, "", empty Vec
+// • or / or_else: try another Option before giving up
+// • Clippy's or_fun_call lint flags unwrap_or(compute()) for the same
+//   reason ok_or_else exists
+
+// BEFORE: a blank nickname counts as no nickname
+pub fn display_name_match(employee: &Employee) -> String {
+    match &employee.nickname {
+        Some(nickname) if !nickname.trim().is_empty() => nickname.trim().to_string(),
+        _ => match employee.name.split_whitespace().next() {
+            Some(first) => first.to_string(),
+            None => format!("#{}", employee.id),
+        },
+    }
+}
+
+// AFTER: the preference order reads top to bottom
+pub fn display_name(employee: &Employee) -> String {
+    employee
+        .nickname
+        .as_deref()
+        .map(str::trim)
+        .filter(|nickname| !nickname.is_empty())
+        .or_else(|| employee.name.split_whitespace().next())
+        .map_or_else(|| format!("#{}", employee.id), str::to_string)
+}
+
+// ===== 4. zip: BOTH OR NEITHER =====
+//
+// UNDERSTANDING zip:
+// • a.zip(b) is Some((x, y)) only when both are Some - two lookups that
+//   must both succeed
+// • Without it: a nested match, or if let (Some(x), Some(y)) = (a, b)
+// • For more than two, ? inside a closure or function is clearer than
+//   zip(..).zip(..) and its nested tuples
+
+// BEFORE
+pub fn reporting_line_match(directory: &Directory, id: u32) -> Option<String> {
+    let employee = directory.get(id);
+    let manager = match employee {
+        Some(employee) => match employee.manager {
+            Some(manager_id) => directory.get(manager_id),
+            None => None,
+        },
+        None => None,
+    };
+    match (employee, manager) {
+        (Some(employee), Some(manager)) => Some(format!("{} → {}", employee.name, manager.name)),
+        _ => None,
+    }
+}
+
+// AFTER
+pub fn reporting_line(directory: &Directory, id: u32) -> Option<String> {
+    let employee = directory.get(id);
+    let manager = employee.and_then(|e| e.manager).and_then(|m| directory.get(m));
+    employee.zip(manager).map(|(employee, manager)| format!("{} → {}", employee.name, manager.name))
+}
+
+// ===== 5. transpose: Option<Result> ⇄ Result<Option> =====
+//
+// UNDERSTANDING transpose:
+// • An optional setting that must parse if present: absent is fine,
+//   present-but-invalid is an error
+// • s.map(str::parse) gives Option<Result<T, E>>, which ? can't look into.
+//   transpose() flips it to Result<Option<T>, E>: the error comes out
+//   for ?, and None stays a valid "not set"
+// • Same trick for iterators of Results: collect::<Result<Vec<_>, _>>()
+
+// BEFORE
+pub fn parse_limit_match(value: Option<&str>) -> Result<Option<u32>, ParseIntError> {
+    match value {
+        Some(text) => match text.trim().parse() {
+            Ok(limit) => Ok(Some(limit)),
+            Err(err) => Err(err),
+        },
+        None => Ok(None),
+    }
+}
+
+// AFTER
+pub fn parse_limit(value: Option<&str>) -> Result<Option<u32>, ParseIntError> {
+    value.map(|text| text.trim().parse()).transpose()
+}
+
+// ===== 6. ? ON Option =====
+//
+// UNDERSTANDING ? FOR Option:
+// • In a function returning Option, expr? returns None early when expr is
+//   None - the same early return ? does for Err
+// • A chain of and_then calls that each need earlier values turns into
+//   nested closures; a few lines with ? keep every value in scope
+// • ? can't mix the two: inside an Option function, convert a Result with
+//   .ok()?; inside a Result function, convert an Option with .ok_or(..)?
+
+// BEFORE: every lookup is a match with its own return None
+#[allow(clippy::question_mark, clippy::manual_map)]
+pub fn skip_level_email_match(directory: &Directory, id: u32) -> Option<String> {
+    let employee = match directory.get(id) {
+        Some(employee) => employee,
+        None => return None,
+    };
+    let manager_id = match employee.manager {
+        Some(id) => id,
+        None => return None,
+    };
+    let manager = match directory.get(manager_id) {
+        Some(manager) => manager,
+        None => return None,
+    };
+    let skip_level = match manager.manager {
+        Some(id) => match directory.get(id) {
+            Some(skip_level) => skip_level,
+            None => return None,
+        },
+        None => return None,
+    };
+    match &skip_level.email {
+        Some(email) => Some(format!("{} <{}>", skip_level.name, email)),
+        None => None,
+    }
+}
+
+// AFTER
+pub fn skip_level_email(directory: &Directory, id: u32) -> Option<String> {
+    let manager = directory.get(directory.get(id)?.manager?)?;
+    let skip_level = directory.get(manager.manager?)?;
+    Some(format!("{} <{}>", skip_level.name, skip_level.email.as_ref()?))
+}
+
+// ===== 7. DEMONSTRATION FUNCTION =====
+
+pub fn sample_directory() -> Directory {
+    let employee = |id, name: &str, nickname: Option<&str>, email: Option<&str>, manager| Employee {
+        id,
+        name: name.to_string(),
+        nickname: nickname.map(str::to_string),
+        email: email.map(str::to_string),
+        manager,
+    };
+    Directory::new([
+        employee(1, "Grace Hopper", Some("Amazing Grace"), Some("grace@NAVY.mil"), None),
+        employee(2, "Alan Turing", Some("  "), Some("alan@bletchley.uk"), Some(1)),
+        employee(3, "Ada Lovelace", None, None, Some(2)),
+        employee(4, "", None, Some("not-an-address"), Some(9)),
+    ])
+}
+
+pub fn combinators_report() -> Report {
+    let mut report = Report::new("🦀 OPTION & RESULT COMBINATORS 🦀");
+    let directory = sample_directory();
+    let ids = [1, 2, 3, 4, 9];
+
+    let section = report.section("1️⃣ map AND and_then:");
+    section.text("email.as_deref().and_then(|e| e.split_once('@')).map(|(_, d)| d.to_lowercase())");
+    for id in [1, 3, 4] {
+        if let Some(employee) = directory.get(id) {
+            section.example(format!("email_domain(#{})", id), format!("{:?}", email_domain(employee)));
+        }
+    }
+
+    let section = report.section("2️⃣ ok_or AND ok_or_else:");
+    section.text("directory.get(id).ok_or(NotFound(id))?.email.as_deref().ok_or(NoEmail(id))");
+    for id in [2, 3, 9] {
+        let shown = match work_email(&directory, id) {
+            Ok(email) => format!("Ok({})", email),
+            Err(err) => format!("Err({})", err),
+        };
+        section.example(format!("work_email(#{})", id), shown);
+    }
+
+    let section = report.section("3️⃣ filter AND unwrap_or_else:");
+    section.text("nickname.as_deref().map(str::trim).filter(non-empty).or_else(first name).map_or_else(#id, to_string)");
+    for id in [1, 2, 3, 4] {
+        if let Some(employee) = directory.get(id) {
+            section.example(format!("display_name(#{})", id), display_name(employee));
+        }
+    }
+    section.example("directory.get(9).map(|e| e.id).unwrap_or_default()", directory.get(9).map(|e| e.id).unwrap_or_default());
+
+    let section = report.section("4️⃣ zip:");
+    section.text("employee.zip(manager).map(|(e, m)| ...)");
+    for id in [1, 3, 4] {
+        section.example(format!("reporting_line(#{})", id), format!("{:?}", reporting_line(&directory, id)));
+    }
+
+    let section = report.section("5️⃣ transpose:");
+    section.text("value.map(|text| text.trim().parse()).transpose()");
+    for value in [Some(" 50 "), None, Some("fifty")] {
+        section.example(format!("parse_limit({:?})", value), format!("{:?}", parse_limit(value)));
+    }
+
+    let section = report.section("6️⃣ ? ON Option:");
+    section.text("let manager = directory.get(directory.get(id)?.manager?)?; ...");
+    for id in [3, 2] {
+        section.example(format!("skip_level_email(#{})", id), format!("{:?}", skip_level_email(&directory, id)));
+    }
+
+    // Same answers, fewer lines: checked here as well as in the tests
+    let agree = ids.iter().all(|&id| {
+        directory.get(id).is_none_or(|e| email_domain(e) == email_domain_match(e) && display_name(e) == display_name_match(e))
+            && work_email(&directory, id) == work_email_match(&directory, id)
+            && reporting_line(&directory, id) == reporting_line_match(&directory, id)
+            && skip_level_email(&directory, id) == skip_level_email_match(&directory, id)
+    });
+    report
+        .section("7️⃣ BEFORE VS AFTER:")
+        .example("every match version == its combinator version", agree)
+        .bullet("skip_level_email: six matches, five of them `None => return None` → three lines with ?")
+        .bullet("email_domain: two nested matches → one chain");
+
+    report
+        .section("🎯 COMBINATORS SUMMARY:")
+        .check("map transforms the value; and_then chains a step that can fail")
+        .check("ok_or / ok_or_else give a None a reason, so ? can propagate it")
+        .check("filter and the unwrap_or family express \"present and usable, else ...\"")
+        .check("zip needs both; transpose flips Option<Result> for ?")
+        .check("? works on Option too - use it when a chain needs earlier values")
+        .check("Prefer the _else variants when the fallback allocates");
+
+    report
+}
+
+pub fn demonstrate_combinators() {
+    print!("{}", combinators_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct CombinatorsModule;
+
+impl LearningModule for CombinatorsModule {
+    fn number(&self) -> u8 {
+        22
+    }
+
+    fn name(&self) -> &'static str {
+        "combinators"
+    }
+
+    fn title(&self) -> &'static str {
+        "OPTION & RESULT COMBINATORS"
+    }
+
+    fn description(&self) -> &'static str {
+        "map, and_then, ok_or_else, transpose, ?"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["errors", "iterators"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_combinators() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { combinators_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&CombinatorsModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// COMBINATOR BEST PRACTICES:
+// 1. Reach for as_deref() / as_ref() first so the chain borrows
+// 2. If map gives Option<Option<T>>, you wanted and_then
+// 3. Turn None into an error as soon as you know why it's missing
+// 4. A chain that needs three earlier values is clearer as lines with ?
+// 5. Long chains are still code: break them where a name would help
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn employee(directory: &Directory, id: u32) -> &Employee {
+        directory.get(id).expect("sample employee")
+    }
+
+    #[test]
+    fn map_and_then_extract_the_domain() {
+        let directory = sample_directory();
+        assert_eq!(email_domain(employee(&directory, 1)).as_deref(), Some("navy.mil"));
+        assert_eq!(email_domain(employee(&directory, 3)), None, "no email");
+        assert_eq!(email_domain(employee(&directory, 4)), None, "no @");
+    }
+
+    #[test]
+    fn ok_or_attaches_the_reason() {
+        let directory = sample_directory();
+        assert_eq!(work_email(&directory, 2), Ok("alan@bletchley.uk"));
+        assert_eq!(work_email(&directory, 3), Err(LookupError::NoEmail(3)));
+        assert_eq!(work_email(&directory, 9), Err(LookupError::NotFound(9)));
+    }
+
+    #[test]
+    fn display_name_falls_back_in_order() {
+        let directory = sample_directory();
+        let names: Vec<String> = (1..=4).map(|id| display_name(employee(&directory, id))).collect();
+        assert_eq!(names, ["Amazing Grace", "Alan", "Ada", "#4"]);
+    }
+
+    #[test]
+    fn zip_transpose_and_question_mark() {
+        let directory = sample_directory();
+        assert_eq!(reporting_line(&directory, 3).as_deref(), Some("Ada Lovelace → Alan Turing"));
+        assert_eq!(reporting_line(&directory, 1), None, "no manager");
+        assert_eq!(reporting_line(&directory, 4), None, "manager 9 doesn't exist");
+
+        assert_eq!(parse_limit(Some(" 7 ")), Ok(Some(7)));
+        assert_eq!(parse_limit(None), Ok(None));
+        assert!(parse_limit(Some("seven")).is_err());
+
+        assert_eq!(skip_level_email(&directory, 3).as_deref(), Some("Grace Hopper <grace@NAVY.mil>"));
+        assert_eq!(skip_level_email(&directory, 2), None);
+    }
+
+    #[test]
+    fn before_and_after_agree() {
+        let directory = sample_directory();
+        for id in 0..=10 {
+            if let Some(e) = directory.get(id) {
+                assert_eq!(email_domain(e), email_domain_match(e));
+                assert_eq!(display_name(e), display_name_match(e));
+            }
+            assert_eq!(work_email(&directory, id), work_email_match(&directory, id));
+            assert_eq!(reporting_line(&directory, id), reporting_line_match(&directory, id));
+            assert_eq!(skip_level_email(&directory, id), skip_level_email_match(&directory, id));
+        }
+        for value in [Some("1"), Some(" 2 "), Some("x"), Some(""), None] {
+            assert_eq!(parse_limit(value), parse_limit_match(value));
+        }
+        let report = combinators_report();
+        assert_eq!(report.find("BEFORE VS AFTER").unwrap().output("every match version == its combinator version"), Some("true"));
+    }
+}
@@ -0,0 +1,761 @@
+// ===== CONCURRENCY PRIMITIVES DEEP STUDY =====
+//
+// WHAT IS "FEARLESS CONCURRENCY"?
+// Rust's type system catches data races at compile time. Two marker traits
+// do the heavy lifting:
+// • Send: A value can be MOVED to another thread
+// • Sync: A value can be SHARED (&T) between threads
+// If you try to share something unsafely (Rc, RefCell across threads), the
+// program simply doesn't compile.
+//
+// WHAT THE COMPILER CAN'T CATCH:
+// • Deadlocks (two threads waiting on each other's locks)
+// • Logical races (check-then-act without holding a lock)
+// • Contention (correct, but slow because everyone waits on one lock)
+//
+// THIS MODULE COVERS:
+// • std::thread::spawn and JoinHandle
+// • Scoped threads (borrowing stack data safely)
+// • mpsc channels (message passing)
+// • Mutex / RwLock and contention
+// • Condvar (waiting for a condition)
+// • Atomics and memory ordering
+// • A worker pool built from the pieces above
+
+use crate::report::Report;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+// ===== 1. SPAWNING THREADS =====
+//
+// UNDERSTANDING thread::spawn:
+// • Runs a closure on a new OS thread
+// • The closure must be 'static - it can outlive the caller, so it can't borrow locals
+// • `move` transfers ownership of captured values into the thread
+// • JoinHandle::join() waits for the thread and returns its result
+//   (Err if the thread panicked - panics don't cross thread boundaries)
+
+pub fn parallel_sum(numbers: Vec<u64>, threads: usize) -> u64 {
+    let chunk_size = numbers.len().div_ceil(threads).max(1);
+
+    // Each thread OWNS its chunk (moved in), so no sharing is needed
+    let handles: Vec<JoinHandle<u64>> = numbers
+        .chunks(chunk_size)
+        .map(|chunk| {
+            let chunk = chunk.to_vec();
+            thread::spawn(move || chunk.iter().sum())
+        })
+        .collect();
+
+    handles
+        .into_iter()
+        .map(|handle| handle.join().expect("worker thread panicked"))
+        .sum()
+}
+
+// ===== 2. SCOPED THREADS =====
+//
+// UNDERSTANDING thread::scope (Rust 1.63+):
+// • All threads spawned in the scope are joined before scope() returns
+// • Because of that guarantee, they may BORROW local data - no Arc, no cloning
+// • Mutable borrows work too, as long as each thread borrows a disjoint part
+
+pub fn scoped_sum(numbers: &[u64], threads: usize) -> u64 {
+    let chunk_size = numbers.len().div_ceil(threads).max(1);
+
+    thread::scope(|s| {
+        let handles: Vec<_> = numbers
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(move || chunk.iter().sum::<u64>())) // Borrows `numbers`
+            .collect();
+
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    })
+}
+
+pub fn scoped_double_in_place(numbers: &mut [u64], threads: usize) {
+    let chunk_size = numbers.len().div_ceil(threads).max(1);
+
+    thread::scope(|s| {
+        // chunks_mut hands out non-overlapping &mut slices - the borrow checker is happy
+        for chunk in numbers.chunks_mut(chunk_size) {
+            s.spawn(move || chunk.iter_mut().for_each(|n| *n *= 2));
+        }
+    }); // All threads joined here
+}
+
+// ===== 3. MESSAGE PASSING WITH mpsc =====
+//
+// "Do not communicate by sharing memory; share memory by communicating."
+//
+// mpsc = Multiple Producer, Single Consumer:
+// • channel(): Unbounded - send() never blocks (memory grows if consumer is slow)
+// • sync_channel(n): Bounded - send() blocks when n messages are queued (BACKPRESSURE)
+// • Clone the Sender for more producers
+// • The Receiver's iterator ends once ALL senders are dropped
+
+pub fn producer_consumer(producers: usize, items_each: usize) -> Vec<String> {
+    let (tx, rx): (Sender<String>, Receiver<String>) = mpsc::channel();
+
+    for id in 0..producers {
+        let tx = tx.clone(); // One sender per producer
+        thread::spawn(move || {
+            for item in 0..items_each {
+                tx.send(format!("p{}-item{}", id, item)).unwrap();
+            }
+        });
+    }
+
+    // IMPORTANT: Drop the original sender, or the loop below never ends
+    drop(tx);
+
+    rx.iter().collect()
+}
+
+// Bounded channel: returns how many sends had to wait for the consumer
+pub fn bounded_channel_backpressure(capacity: usize, items: usize) -> usize {
+    let (tx, rx) = mpsc::sync_channel::<usize>(capacity);
+
+    let producer = thread::spawn(move || {
+        let mut blocked_sends = 0;
+        for item in 0..items {
+            let start = Instant::now();
+            tx.send(item).unwrap();
+            if start.elapsed() > Duration::from_millis(2) {
+                blocked_sends += 1;
+            }
+        }
+        blocked_sends
+    });
+
+    // Slow consumer
+    for _ in rx.iter() {
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    producer.join().unwrap()
+}
+
+// ===== 4. SHARED STATE: MUTEX AND RWLOCK =====
+//
+// UNDERSTANDING Mutex<T>:
+// • The data lives INSIDE the lock - you can't touch it without locking
+// • lock() returns a guard; the lock is released when the guard is dropped
+// • Arc<Mutex<T>> is the standard way to share mutable state between threads
+// • Poisoning: if a thread panics while holding the lock, lock() returns Err
+//
+// UNDERSTANDING RwLock<T>:
+// • Many readers OR one writer
+// • Wins when reads dominate and critical sections are non-trivial
+// • Loses to Mutex for tiny critical sections (more bookkeeping per lock)
+//
+// CONTENTION:
+// Every thread waiting on the same lock is a thread not doing work.
+// Keep critical sections short, or give each thread its own data and merge.
+
+pub fn mutex_counter(threads: usize, increments: usize) -> (u64, Duration) {
+    let counter = Arc::new(Mutex::new(0u64));
+    let start = Instant::now();
+
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..increments {
+                    *counter.lock().unwrap() += 1; // Lock, increment, unlock - every time
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let total = *counter.lock().unwrap();
+    (total, start.elapsed())
+}
+
+// Same result without contention: each thread counts locally, then merges once
+pub fn local_then_merge_counter(threads: usize, increments: usize) -> (u64, Duration) {
+    let counter = Arc::new(Mutex::new(0u64));
+    let start = Instant::now();
+
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                let mut local = 0u64;
+                for _ in 0..increments {
+                    local += 1;
+                }
+                *counter.lock().unwrap() += local; // One lock per thread
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let total = *counter.lock().unwrap();
+    (total, start.elapsed())
+}
+
+// Read-heavy config: many readers share the lock, occasional writer
+pub fn rwlock_config_reads(readers: usize, reads_each: usize) -> (usize, String) {
+    let config = Arc::new(RwLock::new(String::from("v1")));
+    let reads = Arc::new(AtomicUsize::new(0));
+
+    let handles: Vec<_> = (0..readers)
+        .map(|_| {
+            let config = Arc::clone(&config);
+            let reads = Arc::clone(&reads);
+            thread::spawn(move || {
+                for _ in 0..reads_each {
+                    let value = config.read().unwrap(); // Shared lock - readers don't block each other
+                    if !value.is_empty() {
+                        reads.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            })
+        })
+        .collect();
+
+    // Writer waits until no readers hold the lock
+    *config.write().unwrap() = String::from("v2");
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let final_value = config.read().unwrap().clone();
+    (reads.load(Ordering::Relaxed), final_value)
+}
+
+// ===== 5. CONDVAR - WAITING FOR A CONDITION =====
+//
+// UNDERSTANDING Condvar:
+// • Lets a thread sleep until another thread says "something changed"
+// • Always paired with a Mutex protecting the condition
+// • wait() atomically releases the lock and sleeps; re-acquires it on wake
+// • SPURIOUS WAKEUPS: wait() may return without a notify - always re-check
+//   the condition in a loop (or use wait_while, which does it for you)
+
+// A BOUNDED BLOCKING QUEUE (what sync_channel does internally)
+pub struct BlockingQueue<T> {
+    items: Mutex<VecDeque<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+}
+
+impl<T> BlockingQueue<T> {
+    pub fn new(capacity: usize) -> Self {
+        BlockingQueue {
+            items: Mutex::new(VecDeque::with_capacity(capacity)),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity,
+        }
+    }
+
+    pub fn push(&self, item: T) {
+        let items = self.items.lock().unwrap();
+        // Sleep while full (wait_while re-checks after every wakeup)
+        let mut items = self
+            .not_full
+            .wait_while(items, |items| items.len() >= self.capacity)
+            .unwrap();
+        items.push_back(item);
+        self.not_empty.notify_one();
+    }
+
+    pub fn pop(&self) -> T {
+        let mut items = self.items.lock().unwrap();
+        // The manual form of wait_while: loop until the condition holds
+        while items.is_empty() {
+            items = self.not_empty.wait(items).unwrap();
+        }
+        let item = items.pop_front().unwrap();
+        self.not_full.notify_one();
+        item
+    }
+}
+
+// ===== 6. ATOMICS AND MEMORY ORDERING =====
+//
+// UNDERSTANDING ATOMICS:
+// • Lock-free operations on a single integer/bool/pointer
+// • Cheaper than a Mutex for counters and flags
+// • Every operation takes an Ordering that says what OTHER memory it synchronizes
+//
+// MEMORY ORDERINGS (weakest to strongest):
+// • Relaxed: Only this variable is atomic; no ordering with other memory.
+//   Perfect for counters/statistics where nobody reads "related" data.
+// • Release (on store): Everything I wrote BEFORE this store is visible to
+//   whoever Acquire-loads the value I stored.
+// • Acquire (on load): Pairs with Release - after seeing the value, I also
+//   see everything written before the Release store.
+// • AcqRel: Both, for read-modify-write operations (fetch_add, compare_exchange)
+// • SeqCst: Acquire/Release plus one global order of all SeqCst operations.
+//   The default choice when unsure; slightly more expensive on some CPUs.
+
+// RELAXED: A statistics counter
+pub fn relaxed_counter(threads: usize, increments: usize) -> u64 {
+    let hits = Arc::new(AtomicU64::new(0));
+
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let hits = Arc::clone(&hits);
+            thread::spawn(move || {
+                for _ in 0..increments {
+                    hits.fetch_add(1, Ordering::Relaxed); // Atomic, but orders nothing else
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    // join() synchronizes with the threads, so Relaxed is enough here too
+    hits.load(Ordering::Relaxed)
+}
+
+// RELEASE/ACQUIRE: Publishing data through a flag
+pub fn publish_with_flag() -> u64 {
+    let data = Arc::new(AtomicU64::new(0));
+    let ready = Arc::new(AtomicBool::new(false));
+
+    let consumer = {
+        let data = Arc::clone(&data);
+        let ready = Arc::clone(&ready);
+        thread::spawn(move || {
+            // Acquire: once we see `true`, we also see the data write below
+            while !ready.load(Ordering::Acquire) {
+                std::hint::spin_loop();
+            }
+            data.load(Ordering::Relaxed)
+        })
+    };
+
+    data.store(42, Ordering::Relaxed); // 1. Write the payload
+    ready.store(true, Ordering::Release); // 2. Publish: payload write happens-before this
+
+    consumer.join().unwrap()
+}
+
+// COMPARE-EXCHANGE: A minimal spinlock
+// (For learning only - real code should use Mutex, which parks instead of spinning)
+pub struct SpinLock {
+    locked: AtomicBool,
+}
+
+impl SpinLock {
+    pub const fn new() -> Self {
+        SpinLock { locked: AtomicBool::new(false) }
+    }
+
+    pub fn with_lock<R>(&self, f: impl FnOnce() -> R) -> R {
+        // Acquire on success: see everything the previous holder wrote
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            std::hint::spin_loop();
+        }
+
+        let result = f();
+
+        // Release: our writes become visible to the next Acquire
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+}
+
+impl Default for SpinLock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ===== 7. WORKER POOL =====
+//
+// PUTTING IT TOGETHER:
+// • Jobs are boxed closures sent over a channel
+// • Workers share ONE receiver behind Arc<Mutex<..>> (mpsc is single-consumer)
+// • Dropping the pool closes the channel; workers see Err from recv() and exit
+// • Drop joins every worker, so no job is lost on shutdown
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+pub struct WorkerPool {
+    workers: Vec<Worker>,
+    sender: Option<Sender<Job>>,
+}
+
+struct Worker {
+    id: usize,
+    handle: Option<JoinHandle<usize>>,
+}
+
+impl WorkerPool {
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "a pool needs at least one worker");
+
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..size)
+            .map(|id| {
+                let receiver = Arc::clone(&receiver);
+                let handle = thread::spawn(move || {
+                    let mut completed = 0;
+                    loop {
+                        // Hold the lock only while receiving, NOT while running the job
+                        let message = receiver.lock().unwrap().recv();
+                        match message {
+                            Ok(job) => {
+                                job();
+                                completed += 1;
+                            }
+                            Err(_) => break, // Channel closed: pool is shutting down
+                        }
+                    }
+                    completed
+                });
+                Worker { id, handle: Some(handle) }
+            })
+            .collect();
+
+        WorkerPool { workers, sender: Some(sender) }
+    }
+
+    pub fn execute<F>(&self, job: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.sender
+            .as_ref()
+            .expect("pool is shutting down")
+            .send(Box::new(job))
+            .expect("all workers have stopped");
+    }
+
+    // Graceful shutdown: finish queued jobs, report per-worker job counts
+    pub fn shutdown(mut self) -> Vec<(usize, usize)> {
+        self.join_workers()
+    }
+
+    fn join_workers(&mut self) -> Vec<(usize, usize)> {
+        drop(self.sender.take()); // Close the channel
+
+        self.workers
+            .iter_mut()
+            .filter_map(|worker| {
+                let handle = worker.handle.take()?;
+                Some((worker.id, handle.join().unwrap_or(0)))
+            })
+            .collect()
+    }
+}
+
+impl Drop for WorkerPool {
+    fn drop(&mut self) {
+        self.join_workers();
+    }
+}
+
+// ===== 8. DEMONSTRATION FUNCTION =====
+
+pub fn concurrency_report() -> Report {
+    let mut report = Report::new("🦀 RUST CONCURRENCY PRIMITIVES DEEP STUDY 🦀");
+
+    // ===== THREADS =====
+    let threads = report.section("1️⃣ THREAD::SPAWN & SCOPED THREADS:");
+
+    let numbers: Vec<u64> = (1..=1_000).collect();
+    threads
+        .example("parallel_sum (owned chunks, 4 threads)", parallel_sum(numbers.clone(), 4))
+        .example("scoped_sum (borrowed chunks, 4 threads)", scoped_sum(&numbers, 4));
+
+    let mut small = vec![1, 2, 3, 4, 5, 6];
+    scoped_double_in_place(&mut small, 3);
+    threads.example("scoped_double_in_place", format!("{:?}", small));
+
+    // ===== CHANNELS =====
+    let channels = report.section("2️⃣ MPSC CHANNELS:");
+
+    let messages = producer_consumer(3, 2);
+    channels.example(format!("Received {} messages from 3 producers", messages.len()), format!("{:?}", messages));
+
+    let blocked = bounded_channel_backpressure(2, 8);
+    channels.example("sync_channel(2) with a slow consumer", format!("{} of 8 sends blocked (backpressure)", blocked));
+
+    // ===== LOCKS =====
+    let locks = report.section("3️⃣ MUTEX / RWLOCK CONTENTION:");
+
+    let (total, contended) = mutex_counter(8, 50_000);
+    locks.example("Mutex locked per increment", format!("total {} in {:?}", total, contended));
+
+    let (total, merged) = local_then_merge_counter(8, 50_000);
+    locks.example("Local count, merge once", format!("total {} in {:?}", total, merged));
+
+    let (reads, final_value) = rwlock_config_reads(4, 1_000);
+    locks.example("RwLock", format!("{} concurrent reads, final config '{}'", reads, final_value));
+
+    // ===== CONDVAR =====
+    let queue = Arc::new(BlockingQueue::new(2));
+    let producer = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || {
+            for job in 1..=5 {
+                queue.push(job); // Blocks whenever 2 items are waiting
+            }
+        })
+    };
+    let consumed: Vec<i32> = (0..5).map(|_| queue.pop()).collect();
+    producer.join().unwrap();
+    report
+        .section("4️⃣ CONDVAR (BOUNDED BLOCKING QUEUE):")
+        .example("Consumed in order through capacity-2 queue", format!("{:?}", consumed));
+
+    // ===== ATOMICS =====
+    let atomics = report.section("5️⃣ ATOMICS & MEMORY ORDERING:");
+
+    atomics
+        .example("Relaxed fetch_add counter (8 × 50,000)", relaxed_counter(8, 50_000))
+        .example("Release/Acquire published value", publish_with_flag());
+
+    let lock = Arc::new(SpinLock::new());
+    let shared = Arc::new(AtomicU64::new(0));
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..1_000 {
+                    lock.with_lock(|| {
+                        // Non-atomic read-modify-write made safe by the spinlock
+                        let value = shared.load(Ordering::Relaxed);
+                        shared.store(value + 1, Ordering::Relaxed);
+                    });
+                }
+            });
+        }
+    });
+    atomics.example("SpinLock-protected load+store (4 × 1,000)", shared.load(Ordering::Relaxed));
+
+    // ===== WORKER POOL =====
+    let pool = WorkerPool::new(3);
+    let results = Arc::new(Mutex::new(Vec::new()));
+
+    for job in 0..9 {
+        let results = Arc::clone(&results);
+        pool.execute(move || {
+            thread::sleep(Duration::from_millis(5));
+            results.lock().unwrap().push(job * job);
+        });
+    }
+
+    let per_worker = pool.shutdown();
+    let mut results = results.lock().unwrap().clone();
+    results.sort();
+    report
+        .section("6️⃣ WORKER POOL:")
+        .example("Squares computed by the pool", format!("{:?}", results))
+        .example("Jobs per worker (id, count)", format!("{:?}", per_worker));
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 CONCURRENCY CONCEPTS SUMMARY:")
+        .check("Send/Sync make data races a compile error")
+        .check("Scoped threads can borrow; spawn needs 'static")
+        .check("Channels move data; Mutex/RwLock share it")
+        .check("Contention is the real cost - keep critical sections short")
+        .check("Condvar waits must re-check their condition")
+        .check("Relaxed for counters, Release/Acquire for publishing data");
+
+    report
+}
+
+pub fn demonstrate_concurrency() {
+    print!("{}", concurrency_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct ConcurrencyModule;
+
+impl LearningModule for ConcurrencyModule {
+    fn number(&self) -> u8 {
+        7
+    }
+
+    fn name(&self) -> &'static str {
+        "concurrency"
+    }
+
+    fn title(&self) -> &'static str {
+        "CONCURRENCY PRIMITIVES DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Concurrency Primitives"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["smart_pointers"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_concurrency() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { concurrency_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&ConcurrencyModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// CONCURRENCY BEST PRACTICES:
+// 1. Prefer message passing or thread-local work + merge over shared locks
+// 2. Use thread::scope when threads only need to borrow
+// 3. Never hold a lock while doing slow work (IO, sleeping, running a job)
+// 4. Use bounded channels to get backpressure instead of unbounded memory growth
+// 5. Start with SeqCst or a Mutex; weaken orderings only with a clear reason
+// 6. Always join threads (or use scope) so panics and results aren't lost
+//
+// WHEN TO USE EACH:
+// • mpsc channel: Pipelines, producer/consumer, fan-in
+// • Mutex: Shared mutable state with short critical sections
+// • RwLock: Read-mostly shared state
+// • Condvar: "Wait until X" coordination around a Mutex
+// • Atomics: Counters, flags, lock-free building blocks
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_totals_match_across_primitives() {
+        let report = concurrency_report();
+        let threads: Vec<_> = report.find("SCOPED THREADS").unwrap().outputs().collect();
+        assert_eq!(threads, ["500500", "500500", "[2, 4, 6, 8, 10, 12]"]);
+
+        let condvar = report.find("CONDVAR").unwrap();
+        assert_eq!(condvar.output("Consumed in order through capacity-2 queue"), Some("[1, 2, 3, 4, 5]"));
+
+        let atomics = report.find("ATOMICS").unwrap();
+        assert_eq!(atomics.output("Relaxed fetch_add counter (8 × 50,000)"), Some("400000"));
+        assert_eq!(atomics.output("SpinLock-protected load+store (4 × 1,000)"), Some("4000"));
+
+        let pool = report.find("WORKER POOL").unwrap();
+        assert_eq!(pool.output("Squares computed by the pool"), Some("[0, 1, 4, 9, 16, 25, 36, 49, 64]"));
+    }
+
+    #[test]
+    fn owned_and_scoped_sums_agree() {
+        let numbers: Vec<u64> = (1..=1_000).collect();
+        assert_eq!(parallel_sum(numbers.clone(), 4), 500_500);
+        assert_eq!(scoped_sum(&numbers, 3), 500_500);
+        // More threads than items, and no items at all
+        assert_eq!(scoped_sum(&numbers[..2], 8), 3);
+        assert_eq!(parallel_sum(Vec::new(), 4), 0);
+
+        let mut doubled = vec![1, 2, 3, 4, 5];
+        scoped_double_in_place(&mut doubled, 2);
+        assert_eq!(doubled, [2, 4, 6, 8, 10]);
+    }
+
+    #[test]
+    fn channel_delivers_every_message_once_in_per_producer_order() {
+        let messages = producer_consumer(3, 4);
+        assert_eq!(messages.len(), 12);
+        for producer in 0..3 {
+            let prefix = format!("p{}-", producer);
+            let mine: Vec<_> = messages.iter().filter(|m| m.starts_with(&prefix)).collect();
+            let expected: Vec<_> = (0..4).map(|item| format!("p{}-item{}", producer, item)).collect();
+            assert_eq!(mine, expected.iter().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn counters_never_lose_updates() {
+        assert_eq!(mutex_counter(4, 1_000).0, 4_000);
+        assert_eq!(local_then_merge_counter(4, 1_000).0, 4_000);
+        assert_eq!(relaxed_counter(4, 1_000), 4_000);
+        assert_eq!(publish_with_flag(), 42);
+
+        let (reads, value) = rwlock_config_reads(3, 50);
+        assert_eq!((reads, value.as_str()), (150, "v2"));
+    }
+
+    #[test]
+    fn blocking_queue_is_fifo_and_blocks_producers_when_full() {
+        let queue = Arc::new(BlockingQueue::new(2));
+        let producer = {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || (0..10).for_each(|i| queue.push(i)))
+        };
+        let popped: Vec<i32> = (0..10).map(|_| queue.pop()).collect();
+        producer.join().unwrap();
+        assert_eq!(popped, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn spin_lock_admits_one_holder_at_a_time() {
+        let lock = SpinLock::new();
+        let (inside, most_inside, total) = (AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0));
+        thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..500 {
+                        lock.with_lock(|| {
+                            let holders = inside.fetch_add(1, Ordering::SeqCst) + 1;
+                            most_inside.fetch_max(holders, Ordering::SeqCst);
+                            total.fetch_add(1, Ordering::Relaxed);
+                            inside.fetch_sub(1, Ordering::SeqCst);
+                        });
+                    }
+                });
+            }
+        });
+        assert_eq!(most_inside.into_inner(), 1);
+        assert_eq!(total.into_inner(), 2_000);
+    }
+
+    #[test]
+    fn worker_pool_runs_every_job_before_shutdown() {
+        let pool = WorkerPool::new(3);
+        let done = Arc::new(AtomicUsize::new(0));
+        for _ in 0..20 {
+            let done = Arc::clone(&done);
+            pool.execute(move || {
+                done.fetch_add(1, Ordering::Relaxed);
+            });
+        }
+        let per_worker = pool.shutdown();
+        assert_eq!(per_worker.len(), 3);
+        assert_eq!(per_worker.iter().map(|&(_, jobs)| jobs).sum::<usize>(), 20);
+        assert_eq!(done.load(Ordering::Relaxed), 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "a pool needs at least one worker")]
+    fn empty_worker_pool_is_rejected() {
+        WorkerPool::new(0);
+    }
+}
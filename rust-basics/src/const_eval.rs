@@ -0,0 +1,464 @@
+// ===== CONST FN & COMPILE-TIME EVALUATION =====
+//
+// Some values are known before the program runs: a CRC table, the primes
+// below 100, the set of characters a tokenizer accepts. Rust can compute
+// them while compiling, with ordinary Rust code:
+// • const fn: a function the compiler is able to run. It can still be
+//   called at runtime like any other function
+// • A const context - a const or static item, an array length, a const
+//   generic argument, a const { } block - forces the call to happen at
+//   compile time. Anywhere else it's a normal call
+// • A panic during compile-time evaluation is a compile error, so
+//   assert! in a const context is a build-time check
+//
+// Every "this can't compile" claim below is a compile_fail doc test:
+// `cargo test --doc -p rust-basics` checks that each one still fails.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::report::Report;
+
+// ===== 1. const fn =====
+//
+// UNDERSTANDING const fn:
+// • Allowed inside: arithmetic, let/let mut, if/match, while/loop,
+//   references (including &mut), indexing, calls to other const fns,
+//   panics - and floating point since Rust 1.82
+// • Not allowed: heap allocation, trait method calls (including operators
+//   on generic types), for loops (they call Iterator::next), I/O
+// • const fn is a promise in the signature: removing const later breaks
+//   every caller that uses it in a const context
+
+pub const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let rest = a % b;
+        a = b;
+        b = rest;
+    }
+    a
+}
+
+pub const fn is_prime(n: u32) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut divisor = 2;
+    while divisor * divisor <= n {
+        if n.is_multiple_of(divisor) {
+            return false;
+        }
+        divisor += 1;
+    }
+    true
+}
+
+// Evaluated once, by the compiler; the binary just contains `true`
+pub const MERSENNE_31_IS_PRIME: bool = is_prime(2_147_483_647);
+
+// ===== 2. LOOKUP TABLES =====
+//
+// UNDERSTANDING GENERATED TABLES:
+// • A table written out by hand is 256 magic numbers nobody can review. A
+//   const fn that builds it is the algorithm itself, and the compiler runs
+//   it - no build.rs, no lazy initialization, no runtime cost
+// • Without for loops, const code iterates with while and an index
+// • const _: () = assert!(...) checks the result at build time
+
+/// The CRC-32 (IEEE) table, one entry per byte value.
+///
+/// Built with `while` because a `for` loop calls `Iterator::next`, a trait
+/// method, which const fns can't do:
+///
+/// ```compile_fail,E0015
+/// const fn sum_to(n: u32) -> u32 {
+///     let mut sum = 0;
+///     for i in 0..n {
+///         sum += i;
+///     }
+///     sum
+/// }
+/// ```
+pub const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut crc = byte as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[byte] = crc;
+        byte += 1;
+    }
+    table
+}
+
+pub const CRC32_TABLE: [u32; 256] = crc32_table();
+
+pub const fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    let mut i = 0;
+    while i < bytes.len() {
+        crc = CRC32_TABLE[((crc ^ bytes[i] as u32) & 0xFF) as usize] ^ (crc >> 8);
+        i += 1;
+    }
+    !crc
+}
+
+// The published check value: a wrong table fails the build, not a test
+const _: () = assert!(crc32(b"123456789") == 0xCBF4_3926);
+
+// A const generic fn: N comes from the caller's type, so one function makes
+// tables of any length
+pub const fn first_primes<const N: usize>() -> [u32; N] {
+    let mut primes = [0; N];
+    let mut found = 0;
+    let mut candidate = 2;
+    while found < N {
+        if is_prime(candidate) {
+            primes[found] = candidate;
+            found += 1;
+        }
+        candidate += 1;
+    }
+    primes
+}
+
+pub const SMALL_PRIMES: [u32; 10] = first_primes();
+
+// ===== 3. CONST GENERICS MEET const fn =====
+//
+// UNDERSTANDING THE INTERPLAY:
+// • A const generic parameter (const N: usize) is a value in a type, so it
+//   must be known at compile time - a const fn can compute it
+// • const { assert!(N > 0) } inside a generic fn is checked for each N
+//   that is actually used, when that copy is compiled
+// • A const fn can return a whole struct, so a value can be BUILT at
+//   compile time with a chain of const methods
+
+/// A fixed-size set of small integers, `WORDS * 64` bits wide.
+///
+/// Every method is `const`, so a set can be built at compile time. Building
+/// one that is out of range is then a compile error instead of a panic:
+///
+/// ```compile_fail,E0080
+/// use rust_basics::const_eval::BitSet;
+///
+/// const TOO_BIG: BitSet<1> = BitSet::new().with(64);
+/// ```
+///
+/// A set with no words could hold nothing, so `new` refuses to compile for it:
+///
+/// ```compile_fail,E0080
+/// use rust_basics::const_eval::BitSet;
+///
+/// let empty = BitSet::<0>::new();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitSet<const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> BitSet<WORDS> {
+    pub const CAPACITY: usize = WORDS * 64;
+
+    pub const fn new() -> Self {
+        const { assert!(WORDS > 0, "a BitSet needs at least one word") };
+        BitSet { words: [0; WORDS] }
+    }
+
+    pub const fn with(mut self, bit: usize) -> Self {
+        assert!(bit < Self::CAPACITY, "bit out of range");
+        self.words[bit / 64] |= 1 << (bit % 64);
+        self
+    }
+
+    pub const fn contains(&self, bit: usize) -> bool {
+        bit < Self::CAPACITY && self.words[bit / 64] & (1 << (bit % 64)) != 0
+    }
+
+    pub const fn len(&self) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < WORDS {
+            count += self.words[i].count_ones() as usize;
+            i += 1;
+        }
+        count
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<const WORDS: usize> Default for BitSet<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The 128 ASCII characters fit in two words
+pub const fn ascii_set(chars: &[u8]) -> BitSet<2> {
+    let mut set = BitSet::new();
+    let mut i = 0;
+    while i < chars.len() {
+        set = set.with(chars[i] as usize);
+        i += 1;
+    }
+    set
+}
+
+pub const IDENTIFIER_START: BitSet<2> = ascii_set(b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_");
+
+pub fn is_identifier(text: &str) -> bool {
+    let mut bytes = text.bytes();
+    bytes.next().is_some_and(|b| IDENTIFIER_START.contains(b as usize))
+        && bytes.all(|b| IDENTIFIER_START.contains(b as usize) || b.is_ascii_digit())
+}
+
+// Words needed for a set of `bits` bits - usable as a const generic argument
+pub const fn words_for(bits: usize) -> usize {
+    bits.div_ceil(64)
+}
+
+/// A set that can hold every value of a `u8`; its size is computed by a const fn.
+///
+/// The argument has to be known at compile time - a runtime value can't
+/// size a type:
+///
+/// ```compile_fail,E0435
+/// fn buffer(len: usize) -> [u8; len] {
+///     [0; len]
+/// }
+/// ```
+pub type ByteSet = BitSet<{ words_for(256) }>;
+
+// ===== 4. static VS const =====
+//
+// UNDERSTANDING THE DIFFERENCE:
+// • const: a value with no address of its own. Every use is replaced by a
+//   fresh copy of the value, as if it were written out there
+// • static: ONE location in memory for the whole program, with a fixed
+//   address and a 'static lifetime
+// • Interior mutability (AtomicU32, Mutex) belongs in a static: a const
+//   Atomic would be a brand-new atomic at every use, so increments vanish
+//   (clippy's declare_interior_mutable_const lint catches this)
+// • static mut needs unsafe for every access and, in edition 2024, taking
+//   a reference to one is denied - use an atomic or a Mutex instead
+// • Large tables: a const used at runtime may be copied into each place
+//   that uses it; a static guarantees a single copy
+
+static REQUESTS: AtomicU32 = AtomicU32::new(0);
+
+pub fn next_request_id() -> u32 {
+    REQUESTS.fetch_add(1, Ordering::Relaxed) + 1
+}
+
+// A static's initializer is a const context too
+pub static CRC32_OF_HELLO: u32 = crc32(b"hello");
+
+// ===== 5. THE LIMITS =====
+//
+// UNDERSTANDING WHAT const EVALUATION CAN'T DO (the doc tests prove each):
+// • Allocate: String::from, Vec::push, Box::new are not const (E0015)
+// • Call trait methods: a + b on a generic T: Add is a call to
+//   Add::add, and const traits are not stable yet (E0015)
+// • Iterate with for (E0015, see crc32_table)
+// • Overflow or panic: the evaluation fails and so does the build (E0080)
+// • Depend on runtime values: a parameter can't size an array (E0435)
+// • Run forever: long evaluations trigger the long_running_const_eval lint
+// • Observe the environment: no files, no clock, no randomness
+
+/// Generic arithmetic isn't const - `+` on `T` calls the `Add` trait:
+///
+/// ```compile_fail,E0015
+/// const fn add<T: std::ops::Add<Output = T>>(a: T, b: T) -> T {
+///     a + b
+/// }
+/// ```
+///
+/// So this module's const fns use concrete integer types, and heap
+/// allocation is just as impossible:
+///
+/// ```compile_fail,E0015
+/// const GREETING: String = String::from("hello");
+/// ```
+///
+/// An overflow while evaluating a constant is a compile error, not a wrap:
+///
+/// ```compile_fail,E0080
+/// const TOO_BIG: u8 = u8::MAX + 1;
+/// ```
+pub const fn checked_scale(value: u32, factor: u32) -> Option<u32> {
+    value.checked_mul(factor)
+}
+
+// ===== 6. DEMONSTRATION FUNCTION =====
+
+pub fn const_eval_report() -> Report {
+    let mut report = Report::new("🦀 CONST FN & COMPILE-TIME EVALUATION 🦀");
+
+    // The same const fn, called at runtime with a value the compiler never saw
+    let runtime_input = std::env::args().count() as u32 + 96;
+    report
+        .section("1️⃣ const fn:")
+        .example("gcd(1071, 462)", gcd(1071, 462))
+        .example("MERSENNE_31_IS_PRIME (computed while compiling)", MERSENNE_31_IS_PRIME)
+        .example(format!("is_prime({}) (computed now)", runtime_input), is_prime(runtime_input));
+
+    let first: Vec<String> = CRC32_TABLE[..4].iter().map(|entry| format!("{:08x}", entry)).collect();
+    report
+        .section("2️⃣ LOOKUP TABLES:")
+        .example("CRC32_TABLE[..4]", first.join(" "))
+        .example("crc32(b\"123456789\") (also asserted at build time)", format!("{:08x}", crc32(b"123456789")))
+        .example("SMALL_PRIMES = first_primes::<10>()", format!("{:?}", SMALL_PRIMES))
+        .example("first_primes::<3>()", format!("{:?}", first_primes::<3>()));
+
+    let section = report.section("3️⃣ CONST GENERICS MEET const fn:");
+    section
+        .example("BitSet::<2>::CAPACITY", BitSet::<2>::CAPACITY)
+        .example("IDENTIFIER_START.len()", IDENTIFIER_START.len())
+        .example("ByteSet::CAPACITY (words_for(256) words)", ByteSet::CAPACITY);
+    for text in ["snake_case", "_private", "2fast", "kebab-case"] {
+        section.example(format!("is_identifier({:?})", text), is_identifier(text));
+    }
+
+    let ids: Vec<u32> = (0..3).map(|_| next_request_id()).collect();
+    report
+        .section("4️⃣ static VS const:")
+        .example("next_request_id() × 3 (one static AtomicU32)", format!("{:?}", ids))
+        .example("CRC32_OF_HELLO (a static, initialized at compile time)", format!("{:08x}", CRC32_OF_HELLO))
+        .example("std::ptr::eq(&CRC32_OF_HELLO, &CRC32_OF_HELLO)", std::ptr::eq(&CRC32_OF_HELLO, &CRC32_OF_HELLO));
+
+    report
+        .section("5️⃣ THE LIMITS (each one a compile_fail doc test):")
+        .bullet("const S: String = String::from(\"x\")  → E0015, no allocation")
+        .bullet("a + b for a generic T: Add             → E0015, no trait calls")
+        .bullet("for i in 0..n inside a const fn        → E0015, use while")
+        .bullet("const X: u8 = u8::MAX + 1              → E0080, overflow fails the build")
+        .bullet("BitSet::<1>::new().with(64) in a const → E0080, the panic fails the build")
+        .bullet("fn f(len: usize) -> [u8; len]          → E0435, lengths are compile-time")
+        .example("checked_scale(u32::MAX, 2) (at runtime, overflow is a value)", format!("{:?}", checked_scale(u32::MAX, 2)));
+
+    report
+        .section("🎯 CONST EVALUATION SUMMARY:")
+        .check("const fn runs at compile time in const contexts and at runtime elsewhere")
+        .check("Generate tables with const fns and while loops instead of pasting numbers")
+        .check("const _: () = assert!(...) turns a check into a build failure")
+        .check("Const generics take values a const fn computed")
+        .check("const is copied into each use; static has one address and can hold atomics")
+        .check("No allocation, trait calls or for loops in const fn (yet)");
+
+    report
+}
+
+pub fn demonstrate_const_eval() {
+    print!("{}", const_eval_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct ConstEvalModule;
+
+impl LearningModule for ConstEvalModule {
+    fn number(&self) -> u8 {
+        23
+    }
+
+    fn name(&self) -> &'static str {
+        "const_eval"
+    }
+
+    fn title(&self) -> &'static str {
+        "CONST FN & COMPILE-TIME EVALUATION"
+    }
+
+    fn description(&self) -> &'static str {
+        "const fn, Tables, static vs const"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["traits", "macros"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_const_eval() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { const_eval_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&ConstEvalModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// CONST EVALUATION BEST PRACTICES:
+// 1. Make small pure functions const fn - it costs nothing and enables
+//    compile-time use later
+// 2. Compute tables from their definition, and assert a known value
+// 3. Use const { assert!(..) } to reject bad const generic arguments
+// 4. Reach for static when you need one address or interior mutability
+// 5. Remember const fn is part of the API: removing it is a breaking change
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_fns_also_run_at_runtime() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        let primes: Vec<u32> = (0..20).filter(|&n| is_prime(n)).collect();
+        assert_eq!(primes, [2, 3, 5, 7, 11, 13, 17, 19]);
+        const { assert!(MERSENNE_31_IS_PRIME) };
+    }
+
+    #[test]
+    fn generated_tables_match_known_values() {
+        assert_eq!(CRC32_TABLE[1], 0x7707_3096);
+        assert_eq!(CRC32_TABLE[255], 0x2D02_EF8D);
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"The quick brown fox jumps over the lazy dog"), 0x414F_A339);
+        assert_eq!(SMALL_PRIMES, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(first_primes::<0>(), []);
+    }
+
+    #[test]
+    fn bit_sets_built_at_compile_time() {
+        const DIGITS: BitSet<1> = BitSet::new().with(0).with(9).with(63);
+        assert!(DIGITS.contains(9) && DIGITS.contains(63));
+        assert!(!DIGITS.contains(5) && !DIGITS.contains(64));
+        assert_eq!(DIGITS.len(), 3);
+        assert!(BitSet::<3>::default().is_empty());
+        assert_eq!(ByteSet::CAPACITY, 256);
+        assert_eq!(IDENTIFIER_START.len(), 53);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit out of range")]
+    fn out_of_range_bits_panic_at_runtime() {
+        let bit = std::hint::black_box(128);
+        let _ = BitSet::<2>::new().with(bit);
+    }
+
+    #[test]
+    fn identifiers_and_statics() {
+        assert!(is_identifier("_x1"));
+        assert!(!is_identifier("1x"));
+        assert!(!is_identifier(""));
+        assert!(!is_identifier("né"));
+
+        let first = next_request_id();
+        assert!(next_request_id() > first, "the static keeps counting");
+        assert_eq!(CRC32_OF_HELLO, crc32(b"hello"));
+        assert_eq!(checked_scale(3, 4), Some(12));
+    }
+}
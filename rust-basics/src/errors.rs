@@ -11,10 +11,19 @@
 // • Result<T, E>: Standard error return type
 // • ? operator: Propagates errors up the call stack
 
+use crate::report::Report;
 use thiserror::Error;
 use anyhow::{Context, Result as AnyhowResult};
+use miette::{Diagnostic, GraphicalReportHandler, GraphicalTheme, NamedSource, SourceSpan};
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::fmt;
 use std::fs;
+use std::future::Future;
 use std::io;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::task::Poll;
+use std::time::Duration;
+use mini_logger::{warn, Record};
 
 // ===== 1. CUSTOM ERROR TYPES WITH THISERROR =====
 //
@@ -114,7 +123,7 @@ pub fn process_user_data(user_id: u32) -> AnyhowResult<String> {
 // SIMULATING NETWORK OPERATIONS WITH CUSTOM TIMEOUTS:
 pub async fn fetch_data_with_timeout(url: &str, timeout_seconds: u64) -> Result<String, TaskError> {
     // SIMULATE NETWORK DELAY:
-    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    simulated_latency(Duration::from_millis(100)).await;
     
     // SIMULATE TIMEOUT ERROR:
     if timeout_seconds < 1 {
@@ -127,9 +136,23 @@ pub async fn fetch_data_with_timeout(url: &str, timeout_seconds: u64) -> Result<
     Ok(format!("Data from {}", url))
 }
 
+// CONDITIONAL COMPILATION:
+// tokio's timer is driven by an OS thread, and the browser has none to give
+// (std::thread::sleep even panics on wasm32-unknown-unknown). So each target
+// gets its own version of this function, and wasm32 skips the wait
+#[cfg(not(target_arch = "wasm32"))]
+async fn simulated_latency(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn simulated_latency(_duration: Duration) {}
+
 // ===== 6. VALIDATION WITH MULTIPLE CHECKS =====
 //
 // FUNCTION WITH MULTIPLE VALIDATION STEPS:
+// (the age bounds are spelled out as two comparisons on purpose)
+#[allow(clippy::manual_range_contains)]
 pub fn validate_user_input(name: &str, email: &str, age: &str) -> Result<(String, String, u32), TaskError> {
     // NAME VALIDATION:
     if name.is_empty() {
@@ -181,58 +204,61 @@ pub fn complex_operation(path: &str, number_str: &str) -> AnyhowResult<i32> {
 
 // ===== 8. DEMONSTRATION FUNCTIONS =====
 //
+// Each demonstration adds its section to the module's Report (see
+// errors_report() below and report.rs) instead of printing as it goes.
+//
 // FUNCTION TO DEMONSTRATE ERROR HANDLING:
-pub fn demonstrate_error_handling() {
-    println!("=== ERROR HANDLING DEMONSTRATIONS ===");
+fn error_handling_section(report: &mut Report) {
+    let section = report.section("1️⃣ ERROR HANDLING DEMONSTRATIONS:");
     
     // TESTING SUCCESSFUL OPERATIONS:
-    println!("\n1. Testing successful operations:");
-    match parse_number("42") {
-        Ok(num) => println!("   ✓ Parsed number: {}", num),
-        Err(e) => println!("   ✗ Error: {}", e),
-    }
+    let parsed = match parse_number("42") {
+        Ok(num) => format!("✓ Parsed number: {}", num),
+        Err(e) => format!("✗ Error: {}", e),
+    };
+    section.example("parse_number(\"42\")", parsed);
     
     // TESTING VALIDATION ERRORS:
-    println!("\n2. Testing validation errors:");
-    match validate_user_input("", "test@example.com", "25") {
-        Ok((name, email, age)) => println!("   ✓ Valid user: {} ({}) age {}", name, email, age),
-        Err(e) => println!("   ✗ Validation error: {}", e),
-    }
+    let validated = match validate_user_input("", "test@example.com", "25") {
+        Ok((name, email, age)) => format!("✓ Valid user: {} ({}) age {}", name, email, age),
+        Err(e) => format!("✗ Validation error: {}", e),
+    };
+    section.example("validate_user_input(\"\", \"test@example.com\", \"25\")", validated);
     
     // TESTING PARSE ERRORS:
-    println!("\n3. Testing parse errors:");
-    match parse_number("not_a_number") {
-        Ok(num) => println!("   ✓ Parsed: {}", num),
-        Err(e) => println!("   ✗ Parse error: {}", e),
-    }
+    let parsed = match parse_number("not_a_number") {
+        Ok(num) => format!("✓ Parsed: {}", num),
+        Err(e) => format!("✗ Parse error: {}", e),
+    };
+    section.example("parse_number(\"not_a_number\")", parsed);
     
     // TESTING FILE ERRORS:
-    println!("\n4. Testing file errors:");
-    match read_config_file("nonexistent.toml") {
-        Ok(content) => println!("   ✓ File content: {}", content),
-        Err(e) => println!("   ✗ File error: {}", e),
-    }
+    let read = match read_config_file("nonexistent.toml") {
+        Ok(content) => format!("✓ File content: {}", content),
+        Err(e) => format!("✗ File error: {}", e),
+    };
+    section.example("read_config_file(\"nonexistent.toml\")", read);
 }
 
 // ===== 9. ASYNC ERROR HANDLING =====
 //
 // ASYNC FUNCTION WITH ERROR HANDLING:
-pub async fn demonstrate_async_errors() {
-    println!("\n=== ASYNC ERROR HANDLING ===");
+async fn async_errors_section(report: &mut Report) {
+    let section = report.section("2️⃣ ASYNC ERROR HANDLING:");
     
     // TESTING TIMEOUT ERROR:
-    println!("\n1. Testing network timeout:");
-    match fetch_data_with_timeout("https://example.com", 0).await {
-        Ok(data) => println!("   ✓ Fetched: {}", data),
-        Err(e) => println!("   ✗ Network error: {}", e),
-    }
+    let fetched = match fetch_data_with_timeout("https://example.com", 0).await {
+        Ok(data) => format!("✓ Fetched: {}", data),
+        Err(e) => format!("✗ Network error: {}", e),
+    };
+    section.example("fetch_data_with_timeout(\"https://example.com\", 0).await", fetched);
     
     // TESTING SUCCESSFUL FETCH:
-    println!("\n2. Testing successful fetch:");
-    match fetch_data_with_timeout("https://example.com", 5).await {
-        Ok(data) => println!("   ✓ Fetched: {}", data),
-        Err(e) => println!("   ✗ Network error: {}", e),
-    }
+    let fetched = match fetch_data_with_timeout("https://example.com", 5).await {
+        Ok(data) => format!("✓ Fetched: {}", data),
+        Err(e) => format!("✗ Network error: {}", e),
+    };
+    section.example("fetch_data_with_timeout(\"https://example.com\", 5).await", fetched);
 }
 
 // ===== 10. ERROR PROPAGATION PATTERNS =====
@@ -254,6 +280,546 @@ pub fn propagate_errors(input: &str) -> Result<i32, TaskError> {
     Ok(number * 2)
 }
 
+// ===== 11. ANYHOW VS EYRE VS MIETTE =====
+//
+// THREE APPLICATION-LEVEL ERROR CRATES:
+// All three give you one error type that can hold any error plus context,
+// so application code can use `?` everywhere. They differ in how errors
+// are REPORTED to the person reading them:
+//
+// ┌───────────┬────────────────────┬────────────────────────┬─────────────────────────┐
+// │           │ anyhow             │ eyre                   │ miette                  │
+// ├───────────┼────────────────────┼────────────────────────┼─────────────────────────┤
+// │ Type      │ anyhow::Error      │ eyre::Report           │ miette::Report          │
+// │ Context   │ .context()         │ .wrap_err()            │ .wrap_err() / .context()│
+// │ Output    │ fixed text format  │ pluggable handler      │ source snippets, labels,│
+// │           │                    │ (color-eyre, custom)   │ help text, error codes  │
+// │ Best for  │ most apps and CLIs │ apps with their own    │ parsers, compilers,     │
+// │           │                    │ report style           │ config files            │
+// └───────────┴────────────────────┴────────────────────────┴─────────────────────────┘
+//
+// THE SAME FAILURE IN EACH:
+// Loading `port = 80a` from server.toml fails three layers deep:
+//   failed to load settings from server.toml
+//   └─ invalid port '80a' on line 2
+//      └─ invalid digit found in string   (std's ParseIntError)
+
+pub struct Setting<'a> {
+    pub line: usize,
+    // Byte offset of the value in the source, for miette's labels
+    pub offset: usize,
+    pub value: &'a str,
+}
+
+// Finds `key = value` in a config file's text
+pub fn find_setting<'a>(source: &'a str, key: &str) -> Option<Setting<'a>> {
+    for (index, line) in source.lines().enumerate() {
+        if let Some((name, value)) = line.split_once('=')
+            && name.trim() == key
+        {
+            let value = value.trim();
+            // `value` is a slice of `source`, so the pointers give its position
+            let offset = value.as_ptr() as usize - source.as_ptr() as usize;
+            return Some(Setting { line: index + 1, offset, value });
+        }
+    }
+    None
+}
+
+// ANYHOW: context strings stacked on the original error
+pub fn load_port_anyhow(file: &str, source: &str) -> AnyhowResult<u16> {
+    let setting = find_setting(source, "port").with_context(|| format!("no 'port' setting in {}", file))?;
+    setting
+        .value
+        .parse::<u16>()
+        .with_context(|| format!("invalid port '{}' on line {}", setting.value, setting.line))
+        .with_context(|| format!("failed to load settings from {}", file))
+}
+
+// EYRE: the same API under different names (wrap_err = context)...
+pub fn load_port_eyre(file: &str, source: &str) -> eyre::Result<u16> {
+    use eyre::WrapErr;
+
+    let setting = find_setting(source, "port").ok_or_else(|| eyre::eyre!("no 'port' setting in {}", file))?;
+    setting
+        .value
+        .parse::<u16>()
+        .wrap_err_with(|| format!("invalid port '{}' on line {}", setting.value, setting.line))
+        .wrap_err_with(|| format!("failed to load settings from {}", file))
+}
+
+// ...but the program picks how every Report is printed. color-eyre is the
+// popular handler; this one numbers the causes
+struct NumberedCauses;
+
+impl eyre::EyreHandler for NumberedCauses {
+    fn debug(&self, error: &(dyn std::error::Error + 'static), f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", error)?;
+        for (number, cause) in error_chain(error).enumerate().skip(1) {
+            write!(f, "\n  {}. {}", number, cause)?;
+        }
+        Ok(())
+    }
+}
+
+// Must run before the first Report is created; later calls change nothing
+pub fn install_eyre_handler() {
+    let _ = eyre::set_hook(Box::new(|_| Box::new(NumberedCauses)));
+}
+
+// MIETTE: a Diagnostic carries the source text, a span to underline, a code
+// and help, and the report handler draws them like a compiler error
+#[derive(Error, Debug, Diagnostic)]
+#[error("invalid port '{value}' on line {line}")]
+#[diagnostic(code(config::invalid_port), help("ports are whole numbers from 0 to 65535"))]
+pub struct InvalidPort {
+    value: String,
+    line: usize,
+    #[source_code]
+    source_code: NamedSource<String>,
+    #[label("{cause}")]
+    span: SourceSpan,
+    #[source]
+    cause: std::num::ParseIntError,
+}
+
+pub fn load_port_miette(file: &str, source: &str) -> miette::Result<u16> {
+    let setting = find_setting(source, "port").ok_or_else(|| miette::miette!("no 'port' setting in {}", file))?;
+    let port = setting.value.parse::<u16>().map_err(|cause| InvalidPort {
+        value: setting.value.to_string(),
+        line: setting.line,
+        source_code: NamedSource::new(file, source.to_string()),
+        span: (setting.offset, setting.value.len()).into(),
+        cause,
+    })?;
+    Ok(port)
+}
+
+// miette's graphical output without colors, so it reads the same everywhere
+pub fn render_diagnostic(report: &miette::Report) -> String {
+    let mut out = String::new();
+    let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
+    if handler.render_report(&mut out, report.as_ref()).is_err() {
+        out = format!("{:?}", report);
+    }
+    out
+}
+
+fn error_crates_section(report: &mut Report) {
+    let section = report.section("3️⃣ ANYHOW VS EYRE VS MIETTE:");
+    let (file, source) = ("server.toml", "host = \"localhost\"\nport = 80a\n");
+    section.text(format!("Loading the port from {}:\n{}", file, source.trim_end()));
+
+    section.text("anyhow - {} shows the top message, {:#} the chain on one line, {:?} every cause");
+    section.text("   (plus a backtrace when RUST_BACKTRACE=1):");
+    if let Err(e) = load_port_anyhow(file, source) {
+        section
+            .example("format!(\"{}\", e)", &e)
+            .example("format!(\"{:#}\", e)", format!("{:#}", e))
+            .example("format!(\"{:?}\", e)", format!("{:?}", e));
+    }
+
+    section.text("eyre - same API, but the installed handler decides the {:?} format:");
+    install_eyre_handler();
+    if let Err(e) = load_port_eyre(file, source) {
+        section.example("format!(\"{:?}\", e)", format!("{:?}", e));
+    }
+
+    section.text("miette - a diagnostic with source code, a labelled span, a code and help:");
+    if let Err(diagnostic) = load_port_miette(file, source) {
+        section.example("render_diagnostic(&report)", render_diagnostic(&diagnostic).trim_end());
+    }
+
+    let loaded = match load_port_anyhow(file, "port = 8080") {
+        Ok(port) => format!("✓ A valid file loads fine with any of them: port {}", port),
+        Err(e) => format!("✗ Error: {:#}", e),
+    };
+    section.example("load_port_anyhow(file, \"port = 8080\")", loaded);
+}
+
+// ===== 12. SOURCE CHAINS AND BACKTRACES =====
+//
+// UNDERSTANDING Error::source():
+// • Every std::error::Error can return the error that caused it; following
+//   source() until it returns None walks the causal chain
+// • thiserror fills it in from #[source] and #[from] fields
+// • Display should describe only THIS layer - repeating the cause in the
+//   message prints it twice once a report walks the chain
+// • map_err(|_| ...) throws the cause away: read_config_file's FileReadError
+//   has no source, so its chain ends at "Failed to read file"
+//
+// UNDERSTANDING BACKTRACES:
+// • std::backtrace::Backtrace::capture() records the call stack, but only if
+//   RUST_BACKTRACE=1 (or RUST_LIB_BACKTRACE=1) is set - otherwise it costs
+//   almost nothing and status() is Disabled
+// • Capture where the error is CREATED; by the time it's reported the
+//   interesting frames are gone
+// • anyhow and eyre capture one automatically in every Error/Report
+
+// Every error in the chain, starting with `error` itself
+pub fn error_chain<'a>(error: &'a (dyn std::error::Error + 'static)) -> impl Iterator<Item = &'a (dyn std::error::Error + 'static)> {
+    std::iter::successors(Some(error), |error| error.source())
+}
+
+// Formats an error and all its causes, like anyhow's {:?}:
+//
+// error: failed to load settings from missing.toml
+//
+// Caused by:
+//     1: IO operation failed
+//     2: No such file or directory (os error 2)
+pub fn report_error(error: &(dyn std::error::Error + 'static)) -> String {
+    let mut report = format!("error: {}", error);
+    let mut causes = error_chain(error).skip(1).peekable();
+    if causes.peek().is_some() {
+        report.push_str("\n\nCaused by:");
+        for (number, cause) in causes.enumerate() {
+            report.push_str(&format!("\n    {}: {}", number + 1, cause));
+        }
+    }
+    report
+}
+
+// The outer layer of a three-level chain, with a backtrace of where it failed.
+// Written by hand: thiserror only accepts Backtrace fields on nightly
+#[derive(Debug)]
+pub struct LoadError {
+    path: String,
+    source: TaskError,
+    backtrace: Backtrace,
+}
+
+impl LoadError {
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to load settings from {}", self.path)
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+pub fn load_settings(path: &str) -> Result<String, LoadError> {
+    // ? turns io::Error into TaskError::IoError, keeping it as the source
+    let read = || -> Result<String, TaskError> { Ok(fs::read_to_string(path)?) };
+    read().map_err(|source| LoadError { path: path.to_string(), source, backtrace: Backtrace::capture() })
+}
+
+fn error_chains_section(report: &mut Report) {
+    let section = report.section("4️⃣ SOURCE CHAINS AND BACKTRACES:");
+
+    if let Err(e) = load_settings("missing.toml") {
+        // Walking a chain with Error::source()
+        let chain: Vec<String> = error_chain(&e)
+            .enumerate()
+            .map(|(depth, cause)| format!("   {}└─ {}", "   ".repeat(depth), cause))
+            .collect();
+        section
+            .example("error_chain(&e)", chain.join("\n"))
+            .example("report_error(&e)", report_error(&e));
+
+        section.text("Backtrace captured in LoadError:");
+        match e.backtrace().status() {
+            BacktraceStatus::Captured => {
+                // Only our own frames - the full trace runs through tokio and std
+                let trace = e.backtrace().to_string();
+                section.text("   Captured, innermost frames of ours:");
+                for frame in trace.lines().filter(|line| line.contains("rust_basics::")).take(3) {
+                    section.text(format!("   {}", frame.trim()));
+                }
+            }
+            BacktraceStatus::Disabled => {
+                section.text("   Disabled - run with RUST_BACKTRACE=1 to capture one");
+            }
+            _ => {
+                section.text("   Not supported on this platform");
+            }
+        }
+    }
+
+    // map_err(|_| ...) drops the cause
+    if let Err(e) = read_config_file("missing.toml") {
+        section.example("report_error(&read_config_file(\"missing.toml\"))", report_error(&e));
+    }
+}
+
+// ===== 13. RETRYING TRANSIENT ERRORS =====
+//
+// UNDERSTANDING RETRIES:
+// • Only some failures are worth repeating: a timeout may succeed a moment
+//   later, a validation error never will
+// • The error type knows which is which, so it answers through a trait -
+//   the retry loop stays generic over any E: Retryable
+// • Exponential backoff doubles the wait after each failure so a struggling
+//   service gets room to recover; max_delay caps it
+// • Jitter randomizes each wait so many clients that failed together don't
+//   all retry at the same instant
+// • max_attempts bounds the whole thing - the last error is returned as-is
+
+pub trait Retryable {
+    fn is_retryable(&self) -> bool;
+}
+
+impl Retryable for TaskError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            TaskError::NetworkTimeout { .. } => true,
+            TaskError::IoError(e) => matches!(
+                e.kind(),
+                io::ErrorKind::TimedOut
+                    | io::ErrorKind::Interrupted
+                    | io::ErrorKind::WouldBlock
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+            ),
+            TaskError::FileReadError { .. }
+            | TaskError::ValidationError { .. }
+            | TaskError::ParseError { .. }
+            | TaskError::ConfigError { .. } => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jitter {
+    // Exactly the backoff delay
+    None,
+    // Anywhere from zero to the delay - spreads retries out the most
+    Full,
+    // Half the delay plus up to another half - never retries immediately
+    Equal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    // Total tries including the first; 0 is treated as 1
+    pub max_attempts: u32,
+    pub initial_delay: Duration,
+    pub multiplier: f64,
+    pub max_delay: Duration,
+    pub jitter: Jitter,
+}
+
+impl Default for RetryPolicy {
+    // 4 attempts, waiting about 100ms, 200ms and 400ms in between
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 4,
+            initial_delay: Duration::from_millis(100),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(5),
+            jitter: Jitter::Full,
+        }
+    }
+}
+
+impl RetryPolicy {
+    // The wait before retry number `retry` (1 for the first retry), given a
+    // random number in [0, 1)
+    pub fn delay_for(&self, retry: u32, random: f64) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let backoff = self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        let capped = backoff.min(self.max_delay.as_secs_f64());
+        let delay = match self.jitter {
+            Jitter::None => capped,
+            Jitter::Full => capped * random,
+            Jitter::Equal => capped / 2.0 + capped / 2.0 * random,
+        };
+        Duration::from_secs_f64(delay)
+    }
+}
+
+// A random number in [0, 1) without pulling in the rand crate: every
+// RandomState gets fresh keys, so hashing nothing gives a new u64 each time
+fn random_unit() -> f64 {
+    use std::hash::{BuildHasher, Hasher, RandomState};
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+// Calls `op` until it succeeds, fails with a non-retryable error, or runs out
+// of attempts, sleeping between tries as the policy says
+pub async fn retry_with_backoff<T, E, F, Fut>(mut op: F, policy: &RetryPolicy) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: Retryable + fmt::Display,
+{
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt < max_attempts && e.is_retryable() => {
+                let delay = policy.delay_for(attempt, random_unit());
+                warn!("attempt {}/{} failed: {}; retrying in {:?}", attempt, max_attempts, e, delay);
+                simulated_latency(delay).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+// A simulated endpoint that times out a fixed number of times, then answers
+// through fetch_data_with_timeout
+pub struct FlakyEndpoint {
+    url: String,
+    failures: u32,
+    calls: AtomicU32,
+}
+
+impl FlakyEndpoint {
+    pub fn new(url: &str, failures: u32) -> Self {
+        FlakyEndpoint { url: url.to_string(), failures, calls: AtomicU32::new(0) }
+    }
+
+    pub async fn fetch(&self) -> Result<String, TaskError> {
+        let call = self.calls.fetch_add(1, Ordering::Relaxed) + 1;
+        let timeout_seconds = if call <= self.failures { 0 } else { 5 };
+        fetch_data_with_timeout(&self.url, timeout_seconds).await
+    }
+
+    pub fn calls(&self) -> u32 {
+        self.calls.load(Ordering::Relaxed)
+    }
+}
+
+async fn retries_section(report: &mut Report) {
+    let section = report.section("5️⃣ RETRYING TRANSIENT ERRORS:");
+    let policy = RetryPolicy { initial_delay: Duration::from_millis(50), ..RetryPolicy::default() };
+
+    let endpoint = FlakyEndpoint::new("https://example.com/flaky", 2);
+    let retried = match retry_with_backoff(|| endpoint.fetch(), &policy).await {
+        Ok(data) => format!("✓ {} after {} attempts", data, endpoint.calls()),
+        Err(e) => format!("✗ Gave up: {}", e),
+    };
+    section.example("Endpoint that times out twice", retried);
+
+    let endpoint = FlakyEndpoint::new("https://example.com/down", u32::MAX);
+    let retried = match retry_with_backoff(|| endpoint.fetch(), &policy).await {
+        Ok(data) => format!("✓ {}", data),
+        Err(e) => format!("✗ Gave up after {} attempts: {}", endpoint.calls(), e),
+    };
+    section.example("Endpoint that never recovers", retried);
+
+    let mut attempts = 0;
+    let result = retry_with_backoff(
+        || {
+            attempts += 1;
+            async { parse_number("not_a_number") }
+        },
+        &policy,
+    )
+    .await;
+    if let Err(e) = result {
+        section.example("Non-retryable errors fail immediately", format!("✗ {} (attempts: {})", e, attempts));
+    }
+
+    section.text("Backoff schedule for the default policy:");
+    let default = RetryPolicy::default();
+    for retry in 1..default.max_attempts {
+        section.bullet(format!(
+            "retry {}: up to {:?} (equal jitter: {:?}..{:?})",
+            retry,
+            default.delay_for(retry, 1.0),
+            RetryPolicy { jitter: Jitter::Equal, ..default }.delay_for(retry, 0.0),
+            RetryPolicy { jitter: Jitter::Equal, ..default }.delay_for(retry, 1.0),
+        ));
+    }
+}
+
+// ===== 14. THE REPORT =====
+//
+// errors_report() runs every demonstration above into one Report (see
+// report.rs); demonstrate_errors() prints it. Sections 2 and 5 await
+// timers, so both are async and run on whatever runtime polls them.
+//
+// The retry warnings are captured around every poll of the retries, so they
+// land in the report instead of being printed. mini_logger::capture takes a
+// closure, and an await can't happen inside one, but a single poll can.
+async fn capture_logs<T>(future: impl Future<Output = T>) -> (T, Vec<Record>) {
+    let mut future = std::pin::pin!(future);
+    let mut logged = Vec::new();
+    let output = std::future::poll_fn(|cx| {
+        let mut poll = Poll::Pending;
+        logged.extend(mini_logger::capture(|| poll = future.as_mut().poll(cx)));
+        poll
+    })
+    .await;
+    (output, logged)
+}
+
+pub async fn errors_report() -> Report {
+    let mut report = Report::new("🦀 ERROR HANDLING DEEP STUDY 🦀");
+    
+    error_handling_section(&mut report);
+    async_errors_section(&mut report).await;
+    error_crates_section(&mut report);
+    error_chains_section(&mut report);
+    
+    let ((), logged) = capture_logs(retries_section(&mut report)).await;
+    let retries = report.sections.last_mut().expect("retries_section added a section");
+    retries.text("Logged while retrying:");
+    for record in logged {
+        retries.bullet(format!("{} {}", record.level, record.message));
+    }
+    
+    report
+}
+
+pub async fn demonstrate_errors() {
+    print!("{}", errors_report().await);
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct ErrorsModule;
+
+impl LearningModule for ErrorsModule {
+    fn number(&self) -> u8 {
+        4
+    }
+
+    fn name(&self) -> &'static str {
+        "errors"
+    }
+
+    fn title(&self) -> &'static str {
+        "ERROR HANDLING DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Error Handling"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["traits"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(demonstrate_errors())
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(errors_report()))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&ErrorsModule) }
+
 // ===== KEY TAKEAWAYS =====
 //
 // ERROR HANDLING BEST PRACTICES:
@@ -265,9 +831,184 @@ pub fn propagate_errors(input: &str) -> Result<i32, TaskError> {
 // 6. Design error types to be informative and actionable
 // 7. Consider using From trait for error conversions
 // 8. Test both success and error cases
+// 9. Keep the cause as a source instead of formatting it into the message
+// 10. Retry only errors that can go away, with backoff and an attempt limit
 //
 // WHEN TO USE EACH:
 // • thiserror: When you need structured, specific error types
 // • anyhow: When you need flexible error handling across different error types
+// • eyre: anyhow's API with your own report format (e.g. color-eyre)
+// • miette: When errors point at user input and should show where
 // • Result<T, E>: Always prefer over exceptions or panics
-// • ? operator: For clean error propagation without nested match statements
\ No newline at end of file
+// • ? operator: For clean error propagation without nested match statements
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn report_records_each_demonstration() {
+        let report = errors_report().await;
+        let handling = report.find("ERROR HANDLING DEMONSTRATIONS").unwrap();
+        assert_eq!(handling.output("parse_number(\"42\")"), Some("✓ Parsed number: 42"));
+
+        // The async sections ran to completion on the test's runtime
+        let fetched: Vec<_> = report.find("ASYNC ERROR HANDLING").unwrap().outputs().collect();
+        assert_eq!(fetched, ["✗ Network error: Network timeout after 0 seconds", "✓ Fetched: Data from https://example.com"]);
+
+        let retries = report.find("RETRYING").unwrap();
+        assert_eq!(
+            retries.output("Endpoint that times out twice"),
+            Some("✓ Data from https://example.com/flaky after 3 attempts")
+        );
+        // Two retries for the flaky endpoint, three for the one that stays down
+        let warnings = retries.entries.iter().filter(|entry| entry.to_string().starts_with("• WARN")).count();
+        assert_eq!(warnings, 5);
+    }
+
+    #[test]
+    fn parse_number_separates_empty_from_malformed_input() {
+        assert_eq!(parse_number("42").unwrap(), 42);
+        assert_eq!(parse_number("-7").unwrap(), -7);
+        assert!(matches!(parse_number(""), Err(TaskError::ValidationError { .. })));
+        match parse_number("4x") {
+            Err(TaskError::ParseError { input }) => assert_eq!(input, "4x"),
+            other => panic!("expected ParseError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn validate_user_input_checks_each_field_in_order() {
+        assert_eq!(
+            validate_user_input("Ada", "ada@example.com", "36").unwrap(),
+            ("Ada".to_string(), "ada@example.com".to_string(), 36)
+        );
+
+        let message = |result: Result<_, TaskError>| result.unwrap_err().to_string();
+        assert_eq!(message(validate_user_input("", "nope", "x")), "Invalid input: Name cannot be empty");
+        assert_eq!(message(validate_user_input("Ada", "nope", "x")), "Invalid input: Invalid email format");
+        assert_eq!(message(validate_user_input("Ada", "a@b", "x")), "Failed to parse number: x");
+        assert_eq!(message(validate_user_input("Ada", "a@b", "151")), "Invalid input: Age must be between 0 and 150");
+    }
+
+    #[test]
+    fn propagate_errors_passes_parse_errors_through_unchanged() {
+        assert_eq!(propagate_errors("21").unwrap(), 42);
+        assert!(matches!(propagate_errors("abc"), Err(TaskError::ParseError { .. })));
+        assert_eq!(propagate_errors("-1").unwrap_err().to_string(), "Invalid input: Number must be positive");
+    }
+
+    #[test]
+    fn io_errors_convert_with_from_and_keep_their_source() {
+        use std::error::Error as _;
+
+        let err: TaskError = io::Error::new(io::ErrorKind::NotFound, "gone").into();
+        assert_eq!(err.to_string(), "IO operation failed");
+        assert_eq!(err.source().map(|source| source.to_string()), Some("gone".to_string()));
+
+        // read_config_file maps instead, so the path survives but the cause doesn't
+        let err = read_config_file("definitely/missing/config.toml").unwrap_err();
+        assert_eq!(err.to_string(), "Failed to read file: definitely/missing/config.toml");
+        assert!(err.source().is_none());
+    }
+
+    #[tokio::test]
+    async fn timeouts_below_one_second_fail() {
+        assert_eq!(fetch_data_with_timeout("db", 1).await.unwrap(), "Data from db");
+        assert!(matches!(fetch_data_with_timeout("db", 0).await, Err(TaskError::NetworkTimeout { seconds: 0 })));
+    }
+
+    #[test]
+    fn report_lists_every_cause_in_order() {
+        let e = load_settings("definitely/missing/settings.toml").unwrap_err();
+        assert_eq!(error_chain(&e).count(), 3);
+
+        let report = report_error(&e);
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines[0], "error: failed to load settings from definitely/missing/settings.toml");
+        assert_eq!(lines[2], "Caused by:");
+        assert_eq!(lines[3], "    1: IO operation failed");
+        assert!(lines[4].starts_with("    2: "), "io::Error last: {}", report);
+        assert_eq!(lines.len(), 5);
+    }
+
+    #[test]
+    fn report_without_causes_is_one_line() {
+        let e = parse_number("x").unwrap_err();
+        assert_eq!(report_error(&e), "error: Failed to parse number: x");
+    }
+
+    #[test]
+    fn only_transient_errors_are_retryable() {
+        assert!(TaskError::NetworkTimeout { seconds: 0 }.is_retryable());
+        assert!(TaskError::IoError(io::Error::from(io::ErrorKind::TimedOut)).is_retryable());
+        assert!(!TaskError::IoError(io::Error::from(io::ErrorKind::NotFound)).is_retryable());
+        assert!(!TaskError::ValidationError { message: "bad".to_string() }.is_retryable());
+        assert!(!TaskError::ParseError { input: "x".to_string() }.is_retryable());
+    }
+
+    #[test]
+    fn backoff_grows_is_capped_and_jittered() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_delay: Duration::from_millis(100),
+            multiplier: 2.0,
+            max_delay: Duration::from_millis(500),
+            jitter: Jitter::None,
+        };
+        let delays: Vec<_> = (1..=5).map(|retry| policy.delay_for(retry, 0.7).as_millis()).collect();
+        assert_eq!(delays, [100, 200, 400, 500, 500]);
+
+        let full = RetryPolicy { jitter: Jitter::Full, ..policy };
+        assert_eq!(full.delay_for(2, 0.0), Duration::ZERO);
+        assert_eq!(full.delay_for(2, 0.5).as_millis(), 100);
+
+        let equal = RetryPolicy { jitter: Jitter::Equal, ..policy };
+        assert_eq!(equal.delay_for(3, 0.0).as_millis(), 200);
+        assert_eq!(equal.delay_for(3, 0.5).as_millis(), 300);
+
+        for _ in 0..100 {
+            let random = random_unit();
+            assert!((0.0..1.0).contains(&random));
+        }
+    }
+
+    fn quick(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy { max_attempts, initial_delay: Duration::from_millis(1), ..RetryPolicy::default() }
+    }
+
+    #[tokio::test]
+    async fn retries_until_the_endpoint_recovers() {
+        let endpoint = FlakyEndpoint::new("https://example.com", 2);
+        let data = retry_with_backoff(|| endpoint.fetch(), &quick(5)).await.unwrap();
+        assert_eq!(data, "Data from https://example.com");
+        assert_eq!(endpoint.calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn stops_after_max_attempts_with_the_last_error() {
+        let endpoint = FlakyEndpoint::new("https://example.com", u32::MAX);
+        let err = retry_with_backoff(|| endpoint.fetch(), &quick(3)).await.unwrap_err();
+        assert!(matches!(err, TaskError::NetworkTimeout { seconds: 0 }));
+        assert_eq!(endpoint.calls(), 3);
+
+        let endpoint = FlakyEndpoint::new("https://example.com", u32::MAX);
+        assert!(retry_with_backoff(|| endpoint.fetch(), &quick(0)).await.is_err());
+        assert_eq!(endpoint.calls(), 1, "zero attempts still tries once");
+    }
+
+    #[tokio::test]
+    async fn gives_up_at_once_on_permanent_errors() {
+        let mut calls = 0;
+        let result = retry_with_backoff(
+            || {
+                calls += 1;
+                async { propagate_errors("-4") }
+            },
+            &quick(5),
+        )
+        .await;
+        assert!(matches!(result, Err(TaskError::ValidationError { .. })));
+        assert_eq!(calls, 1);
+    }
+}
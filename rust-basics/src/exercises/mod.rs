@@ -0,0 +1,276 @@
+// ===== GRADED EXERCISES =====
+//
+// Rustlings-style practice for every learning module:
+// • exercises/<module>/<name>.rs holds small functions stubbed with todo!()
+// • src/exercises/suites/<module>_<name>.rs holds the matching test suite,
+//   embedded in the binary so learners don't see it while they work
+// • src/exercises/solutions/<module>_<name>.rs is a reference solution; the
+//   tests below grade each one, so a suite and its solution can't drift apart
+// • `rust-basics check <module>` grades each exercise and reports pass/fail
+//
+// HOW GRADING WORKS:
+//   exercise source + hidden suite ──▶ one file ──rustc --test──▶ test binary
+//                                                                   │
+//   ✅ / ❌ / ⏳ / 🔧  ◀── parse "test result: ... N passed; M failed" ◀─┘
+//
+// Each exercise compiles on its own with plain rustc, so exercises can only
+// use the standard library.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ===== 1. THE EXERCISE LIST =====
+
+pub struct Exercise {
+    pub module: &'static str,
+    pub name: &'static str,
+    pub hint: &'static str,
+    suite: &'static str,
+}
+
+// Embeds suites/<module>_<name>.rs next to the exercise's metadata
+macro_rules! exercise {
+    ($module:literal, $name:literal, $hint:literal) => {
+        Exercise {
+            module: $module,
+            name: $name,
+            hint: $hint,
+            suite: include_str!(concat!("suites/", $module, "_", $name, ".rs")),
+        }
+    };
+}
+
+pub const EXERCISES: &[Exercise] = &[
+    exercise!("traits", "shapes", "a default method body can simply return \"shape\".to_string()"),
+    exercise!("lifetimes", "highlighter", "split_whitespace() and find('.') both hand back borrowed data"),
+    exercise!("smart_pointers", "shared_log", "handle() is Rc::clone, owners() is Rc::strong_count"),
+    exercise!("errors", "config_line", "split_once('=') then map_err the parse error into InvalidPort"),
+    exercise!("macros", "hashmap", "create the map, then $( map.insert($key, $value); )*"),
+    exercise!("async_await", "ready_after", "decrement the counter and call cx.waker().wake_by_ref() before Pending"),
+    exercise!("concurrency", "parallel_count", "texts.chunks(texts.len().div_ceil(threads)) inside thread::scope"),
+    exercise!("collections", "top_words", "sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0))) then truncate(k)"),
+    exercise!("testing", "semver", "reject parts that are empty or contain anything but ASCII digits"),
+    exercise!("pin_futures", "doubler", "project with map_unchecked_mut, then .poll(cx).map(|n| n * 2)"),
+    exercise!("tokio_channels", "broadcast_ring", "pop_front once the deque is full; compare the cursor with the oldest sequence"),
+    exercise!("patterns", "door", "move the count into a new Door { opened: self.opened, state: PhantomData }"),
+    exercise!("actors", "account", "loop over rx, match the request, and send the answer back on its reply Sender"),
+    exercise!("file_io", "log_parser", "splitn(3, ' ') for the fields; reader.lines() yields io::Result, so ? converts it"),
+    exercise!("networking", "frame_decoder", "check buffer.len() >= 4, then >= 4 + len, before drain(..4 + len)"),
+    exercise!("parsing", "request_line", "split_once(' ') twice for the three parts, then split_once('?') on the target"),
+    exercise!("iterators", "interleave", "keep a flag for whose turn it is; when one side returns None, mark it finished and try the other"),
+    exercise!("kv_store", "expiring_map", "store (value, Option<u64>) and treat an entry as live while expiry.is_none_or(|at| now < at)"),
+    exercise!("downloader", "bounded_map", "spawn limit.max(1) scoped workers that pull indexes from a shared AtomicUsize and write into that slot"),
+    exercise!("lru_cache", "arena_list", "unlink: point prev.next at next and next.prev at prev (or move head/tail); link_front: the old head becomes next"),
+    exercise!("strings", "safe_slice", "char_indices() yields the byte offset where each char starts; str::get and is_char_boundary never panic"),
+    exercise!("combinators", "settings", "settings.get(key).map(String::as_str) is an Option<&str>; ok_or_else, zip, transpose and unwrap_or each turn one requirement into a single call"),
+    exercise!("const_eval", "tables", "Only while loops and indexing work in a const fn: keep an index, loop while it is below the length, and bump it at the end of the body"),
+    exercise!("zero_copy", "unquote", "Check first whether any work is needed and return Cow::Borrowed(slice) if not; only the escape case builds a String"),
+    exercise!("panics", "isolate", "panic::catch_unwind(job) gives Err(Box<dyn Any + Send>); try downcast_ref::<&str>() then downcast_ref::<String>(). PoisonError::into_inner hands back the guard"),
+    exercise!("cancellation", "line_reader", "A cancelled next_line() drops its locals - keep the partial line in a field of LineReader and mem::take it when the newline arrives"),
+    exercise!("instrumentation", "span_stack", "Keep the stack in a RefCell<Vec<String>>: enter pushes and hands out a guard holding &self, and the guard's Drop pops. event joins the stack with ':'"),
+];
+
+impl Exercise {
+    // Where the learner edits this exercise
+    pub fn path(&self) -> PathBuf {
+        exercises_dir().join(self.module).join(format!("{}.rs", self.name))
+    }
+}
+
+// RUST_BASICS_EXERCISES_DIR points at a copy elsewhere; the default is the
+// exercises/ folder of this crate
+pub fn exercises_dir() -> PathBuf {
+    std::env::var_os("RUST_BASICS_EXERCISES_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("exercises"))
+}
+
+pub fn for_module(module: &str) -> Vec<&'static Exercise> {
+    EXERCISES.iter().filter(|exercise| exercise.module == module).collect()
+}
+
+// ===== 2. OUTCOMES =====
+
+#[derive(Debug)]
+pub enum Outcome {
+    Passed { tests: usize },
+    Failed { passed: usize, failed: usize, output: String },
+    // Tests fail and todo!() is still in the source
+    NotAttempted { todos: usize },
+    CompileError { output: String },
+    // The exercise file couldn't be read, or rustc/the test binary couldn't start
+    Unavailable(String),
+}
+
+impl Outcome {
+    pub fn is_passed(&self) -> bool {
+        matches!(self, Outcome::Passed { .. })
+    }
+}
+
+// ===== 3. GRADING =====
+
+pub fn check(exercise: &Exercise) -> Outcome {
+    match fs::read_to_string(exercise.path()) {
+        Ok(source) => grade(exercise, &source),
+        Err(err) => Outcome::Unavailable(format!("{}: {}", exercise.path().display(), err)),
+    }
+}
+
+// Grades `source` as if it were the exercise file (check() reads it from disk)
+pub fn grade(exercise: &Exercise, source: &str) -> Outcome {
+    // Unique per call, so the same exercise can be graded concurrently
+    static GRADINGS: AtomicUsize = AtomicUsize::new(0);
+    let scratch = std::env::temp_dir()
+        .join(format!("rust-basics-exercises-{}", std::process::id()))
+        .join(format!(
+            "{}_{}_{}",
+            exercise.module,
+            exercise.name,
+            GRADINGS.fetch_add(1, Ordering::Relaxed)
+        ));
+
+    let outcome = compile_and_run(exercise, source, &scratch)
+        .unwrap_or_else(|err| Outcome::Unavailable(err.to_string()));
+    let _ = fs::remove_dir_all(&scratch);
+    outcome
+}
+
+fn compile_and_run(exercise: &Exercise, source: &str, scratch: &Path) -> io::Result<Outcome> {
+    fs::create_dir_all(scratch)?;
+    let combined_path = scratch.join(format!("{}.rs", exercise.name));
+    let binary_path = scratch.join(exercise.name);
+
+    // The exercise comes first so compiler line numbers match the learner's file
+    let combined = format!(
+        "{}\n\n#[cfg(test)]\nmod hidden_tests {{\n    use super::*;\n\n{}\n}}\n",
+        source, exercise.suite
+    );
+    fs::write(&combined_path, combined)?;
+
+    let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let compiled = Command::new(rustc)
+        .args(["--edition", "2024", "--test", "-A", "unused", "--crate-name", exercise.name])
+        .arg("-o")
+        .arg(&binary_path)
+        .arg(&combined_path)
+        .output()?;
+
+    if !compiled.status.success() {
+        let output = String::from_utf8_lossy(&compiled.stderr)
+            .replace(&combined_path.display().to_string(), &exercise.path().display().to_string());
+        return Ok(Outcome::CompileError { output });
+    }
+
+    let ran = Command::new(&binary_path).arg("--color=never").output()?;
+    let output = String::from_utf8_lossy(&ran.stdout).into_owned();
+    let (passed, failed) = parse_test_summary(&output).unwrap_or((0, 1));
+
+    let todos = source.matches("todo!(").count();
+    Ok(if failed == 0 && ran.status.success() {
+        Outcome::Passed { tests: passed }
+    } else if todos > 0 {
+        Outcome::NotAttempted { todos }
+    } else {
+        Outcome::Failed { passed, failed, output }
+    })
+}
+
+// "test result: FAILED. 2 passed; 1 failed; 0 ignored; ..." → (2, 1)
+fn parse_test_summary(output: &str) -> Option<(usize, usize)> {
+    let line = output.lines().find(|line| line.starts_with("test result:"))?;
+    let count = |label: &str| -> Option<usize> {
+        line.split(';')
+            .find(|part| part.trim_end().ends_with(label))?
+            .split_whitespace()
+            .rev()
+            .nth(1)?
+            .parse()
+            .ok()
+    };
+    Some((count("passed")?, count("failed")?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn parses_libtest_summaries() {
+        let ok = "running 3 tests\n...\ntest result: ok. 3 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out";
+        assert_eq!(parse_test_summary(ok), Some((3, 0)));
+
+        let failed = "test result: FAILED. 1 passed; 2 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s";
+        assert_eq!(parse_test_summary(failed), Some((1, 2)));
+        assert_eq!(parse_test_summary("error: no tests"), None);
+    }
+
+    #[test]
+    fn every_module_ships_an_exercise() {
+        for module in registry::modules() {
+            assert!(!for_module(module.name()).is_empty(), "{} has no exercise", module.name());
+        }
+        for exercise in EXERCISES {
+            assert!(registry::find(exercise.module).is_some(), "unknown module {}", exercise.module);
+        }
+    }
+
+    // Invokes rustc once per exercise, so it takes a few seconds
+    #[test]
+    fn shipped_exercises_compile_but_are_not_attempted() {
+        for exercise in EXERCISES {
+            let source = fs::read_to_string(exercise.path()).unwrap();
+            let outcome = grade(exercise, &source);
+            assert!(
+                matches!(outcome, Outcome::NotAttempted { .. }),
+                "{}/{}: {:?}",
+                exercise.module,
+                exercise.name,
+                outcome
+            );
+        }
+    }
+
+    fn solution(exercise: &Exercise) -> String {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/exercises/solutions")
+            .join(format!("{}_{}.rs", exercise.module, exercise.name));
+        fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {}", path.display(), err))
+    }
+
+    // Invokes rustc once per exercise too
+    #[test]
+    fn reference_solutions_pass_every_test() {
+        for exercise in EXERCISES {
+            let tests = exercise.suite.matches("#[test]").count();
+            let outcome = grade(exercise, &solution(exercise));
+            assert!(
+                matches!(outcome, Outcome::Passed { tests: passed } if passed == tests),
+                "{}/{}: {:?}",
+                exercise.module,
+                exercise.name,
+                outcome
+            );
+        }
+    }
+
+    #[test]
+    fn grades_a_wrong_answer_and_a_broken_one() {
+        let exercise = for_module("collections")[0];
+        let solution = solution(exercise);
+
+        // Forgets the case-insensitive comparison and the tie-break
+        let wrong = solution
+            .replace("word.to_lowercase()", "word.to_string()")
+            .replace(".then(a.0.cmp(&b.0))", "");
+        assert_ne!(wrong, solution);
+        assert!(matches!(grade(exercise, &wrong), Outcome::Failed { failed: 1.., .. }));
+
+        let broken = "pub fn top_words(text: &str) {}";
+        assert!(matches!(grade(exercise, broken), Outcome::CompileError { .. }));
+    }
+}
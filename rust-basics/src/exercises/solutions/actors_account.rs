@@ -0,0 +1,68 @@
+// ===== SOLUTION: ACTORS - A BANK ACCOUNT THREAD =====
+
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+
+#[derive(Debug, PartialEq)]
+pub struct InsufficientFunds;
+
+pub enum Request {
+    Deposit(u64, Sender<u64>),
+    Withdraw(u64, Sender<Result<u64, InsufficientFunds>>),
+    Balance(Sender<u64>),
+}
+
+#[derive(Clone)]
+pub struct AccountHandle {
+    mailbox: Sender<Request>,
+}
+
+impl AccountHandle {
+    pub fn spawn() -> AccountHandle {
+        let (mailbox, requests) = mpsc::channel::<Request>();
+        thread::spawn(move || {
+            let mut balance = 0u64;
+            // Ends once every handle, and so every Sender, is dropped
+            for request in requests {
+                match request {
+                    Request::Deposit(amount, reply) => {
+                        balance += amount;
+                        let _ = reply.send(balance);
+                    }
+                    Request::Withdraw(amount, reply) => {
+                        let result = match balance.checked_sub(amount) {
+                            Some(left) => {
+                                balance = left;
+                                Ok(balance)
+                            }
+                            None => Err(InsufficientFunds),
+                        };
+                        let _ = reply.send(result);
+                    }
+                    Request::Balance(reply) => {
+                        let _ = reply.send(balance);
+                    }
+                }
+            }
+        });
+        AccountHandle { mailbox }
+    }
+
+    pub fn deposit(&self, amount: u64) -> u64 {
+        let (reply, answer) = mpsc::channel();
+        self.mailbox.send(Request::Deposit(amount, reply)).expect("account thread is running");
+        answer.recv().expect("account thread replies")
+    }
+
+    pub fn withdraw(&self, amount: u64) -> Result<u64, InsufficientFunds> {
+        let (reply, answer) = mpsc::channel();
+        self.mailbox.send(Request::Withdraw(amount, reply)).expect("account thread is running");
+        answer.recv().expect("account thread replies")
+    }
+
+    pub fn balance(&self) -> u64 {
+        let (reply, answer) = mpsc::channel();
+        self.mailbox.send(Request::Balance(reply)).expect("account thread is running");
+        answer.recv().expect("account thread replies")
+    }
+}
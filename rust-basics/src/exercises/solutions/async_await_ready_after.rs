@@ -0,0 +1,30 @@
+// ===== SOLUTION: ASYNC/AWAIT - A HAND-WRITTEN FUTURE =====
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub struct ReadyAfter<T> {
+    remaining: u32,
+    value: Option<T>,
+}
+
+impl<T> ReadyAfter<T> {
+    pub fn new(polls: u32, value: T) -> Self {
+        ReadyAfter { remaining: polls, value: Some(value) }
+    }
+}
+
+impl<T: Unpin> Future for ReadyAfter<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let this = self.get_mut();
+        if this.remaining == 0 {
+            return Poll::Ready(this.value.take().expect("polled after completion"));
+        }
+        this.remaining -= 1;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
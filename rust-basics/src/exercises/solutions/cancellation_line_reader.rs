@@ -0,0 +1,75 @@
+// ===== SOLUTION: CANCELLATION - A CANCEL-SAFE LINE READER =====
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub struct ByteSource {
+    bytes: VecDeque<u8>,
+}
+
+impl ByteSource {
+    pub fn new(text: &str) -> Self {
+        ByteSource { bytes: text.bytes().collect() }
+    }
+
+    // Like a socket read: not ready on the first poll. The byte is only
+    // removed when the future completes, so dropping it early is harmless
+    pub fn next_byte(&mut self) -> NextByte<'_> {
+        NextByte { source: self, polled: false }
+    }
+}
+
+pub struct NextByte<'a> {
+    source: &'a mut ByteSource,
+    polled: bool,
+}
+
+impl Future for NextByte<'_> {
+    type Output = Option<u8>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u8>> {
+        if !self.polled {
+            self.polled = true;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        Poll::Ready(self.source.bytes.pop_front())
+    }
+}
+
+// NOT cancel-safe: `line` lives in the future
+pub async fn read_line_buggy(source: &mut ByteSource) -> Option<String> {
+    let mut line = Vec::new();
+    while let Some(byte) = source.next_byte().await {
+        if byte == b'\n' {
+            return Some(String::from_utf8_lossy(&line).into_owned());
+        }
+        line.push(byte);
+    }
+    if line.is_empty() { None } else { Some(String::from_utf8_lossy(&line).into_owned()) }
+}
+
+pub struct LineReader {
+    source: ByteSource,
+    buffer: Vec<u8>,
+}
+
+impl LineReader {
+    pub fn new(source: ByteSource) -> Self {
+        LineReader { source, buffer: Vec::new() }
+    }
+
+    // Every byte goes straight into self.buffer, so a call dropped at the
+    // .await leaves it for the next one
+    pub async fn next_line(&mut self) -> Option<String> {
+        while let Some(byte) = self.source.next_byte().await {
+            if byte == b'\n' {
+                return Some(String::from_utf8_lossy(&std::mem::take(&mut self.buffer)).into_owned());
+            }
+            self.buffer.push(byte);
+        }
+        if self.buffer.is_empty() { None } else { Some(String::from_utf8_lossy(&std::mem::take(&mut self.buffer)).into_owned()) }
+    }
+}
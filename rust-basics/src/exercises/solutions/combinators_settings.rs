@@ -0,0 +1,20 @@
+// ===== SOLUTION: COMBINATORS - READING SETTINGS =====
+
+use std::collections::HashMap;
+use std::num::ParseIntError;
+
+pub fn require<'a>(settings: &'a HashMap<String, String>, key: &str) -> Result<&'a str, String> {
+    settings.get(key).map(String::as_str).ok_or_else(|| format!("missing setting: {}", key))
+}
+
+pub fn port(settings: &HashMap<String, String>) -> Result<Option<u16>, ParseIntError> {
+    settings.get("port").map(|port| port.parse()).transpose()
+}
+
+pub fn timeout_secs(settings: &HashMap<String, String>) -> u64 {
+    settings.get("timeout").and_then(|timeout| timeout.parse().ok()).unwrap_or(30)
+}
+
+pub fn endpoint(settings: &HashMap<String, String>) -> Option<String> {
+    settings.get("host").zip(settings.get("port")).map(|(host, port)| format!("{}:{}", host, port))
+}
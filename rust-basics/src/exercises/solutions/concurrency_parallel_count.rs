@@ -0,0 +1,37 @@
+// ===== SOLUTION: CONCURRENCY - PARALLEL COUNTING =====
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+
+pub fn parallel_word_count(texts: &[&str], threads: usize) -> usize {
+    if texts.is_empty() {
+        return 0;
+    }
+    let chunk_size = texts.len().div_ceil(threads.max(1));
+    thread::scope(|scope| {
+        let handles: Vec<_> = texts
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(|text| text.split_whitespace().count()).sum::<usize>()))
+            .collect();
+        handles.into_iter().map(|handle| handle.join().unwrap()).sum()
+    })
+}
+
+pub fn shared_counter(threads: usize, increments: u64) -> u64 {
+    let counter = Arc::new(AtomicU64::new(0));
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..increments {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    counter.load(Ordering::Relaxed)
+}
@@ -0,0 +1,43 @@
+// ===== SOLUTION: CONST EVAL - COMPILE-TIME TABLES =====
+
+pub const fn pow(base: u64, exp: u32) -> u64 {
+    let mut result = 1;
+    let mut i = 0;
+    while i < exp {
+        result *= base;
+        i += 1;
+    }
+    result
+}
+
+pub const fn popcount_table() -> [u8; 256] {
+    let mut table = [0u8; 256];
+    let mut i = 1;
+    while i < 256 {
+        table[i] = (i & 1) as u8 + table[i >> 1];
+        i += 1;
+    }
+    table
+}
+
+pub const fn count_ones(bytes: &[u8]) -> u32 {
+    const TABLE: [u8; 256] = popcount_table();
+    let mut total = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        total += TABLE[bytes[i] as usize] as u32;
+        i += 1;
+    }
+    total
+}
+
+pub const fn contains(haystack: &[u8], needle: u8) -> bool {
+    let mut i = 0;
+    while i < haystack.len() {
+        if haystack[i] == needle {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
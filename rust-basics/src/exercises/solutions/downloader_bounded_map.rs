@@ -0,0 +1,41 @@
+// ===== SOLUTION: DOWNLOADER - A BOUNDED PARALLEL MAP =====
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+pub fn bounded_map<T, R, E, F>(inputs: &[T], limit: usize, job: F) -> Vec<Result<R, E>>
+where
+    T: Sync,
+    R: Send,
+    E: Send,
+    F: Fn(&T) -> Result<R, E> + Sync,
+{
+    // One slot per input, so results keep the input order whoever finishes first
+    let slots: Vec<Mutex<Option<Result<R, E>>>> = inputs.iter().map(|_| Mutex::new(None)).collect();
+    let next = AtomicUsize::new(0);
+    thread::scope(|scope| {
+        for _ in 0..limit.max(1) {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(input) = inputs.get(index) else { break };
+                    *slots[index].lock().unwrap() = Some(job(input));
+                }
+            });
+        }
+    });
+    slots.into_iter().map(|slot| slot.into_inner().unwrap().expect("every input ran")).collect()
+}
+
+pub fn summarize<R, E: std::fmt::Display>(results: Vec<Result<R, E>>) -> (Vec<R>, Vec<String>) {
+    let mut successes = Vec::new();
+    let mut failures = Vec::new();
+    for (index, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(value) => successes.push(value),
+            Err(error) => failures.push(format!("{}: {}", index, error)),
+        }
+    }
+    (successes, failures)
+}
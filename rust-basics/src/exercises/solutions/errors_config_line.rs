@@ -0,0 +1,39 @@
+// ===== SOLUTION: ERRORS - CONFIG LINES =====
+
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    MissingEquals,
+    EmptyName,
+    InvalidPort(String),
+    ZeroPort,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::MissingEquals => write!(f, "expected 'name = port'"),
+            ConfigError::EmptyName => write!(f, "name is empty"),
+            ConfigError::InvalidPort(raw) => write!(f, "'{}' is not a valid port", raw),
+            ConfigError::ZeroPort => write!(f, "port 0 is reserved"),
+        }
+    }
+}
+
+pub fn parse_port(s: &str) -> Result<u16, ConfigError> {
+    let s = s.trim();
+    match s.parse::<u16>().map_err(|_| ConfigError::InvalidPort(s.to_string()))? {
+        0 => Err(ConfigError::ZeroPort),
+        port => Ok(port),
+    }
+}
+
+pub fn parse_line(line: &str) -> Result<(String, u16), ConfigError> {
+    let (name, port) = line.split_once('=').ok_or(ConfigError::MissingEquals)?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ConfigError::EmptyName);
+    }
+    Ok((name.to_string(), parse_port(port)?))
+}
@@ -0,0 +1,71 @@
+// ===== SOLUTION: FILE IO - A LOG-FILE PARSER =====
+
+use std::fmt;
+use std::io::{self, BufRead};
+
+#[derive(Debug)]
+pub enum TaskError {
+    ValidationError { message: String },
+    ParseError { input: String },
+    IoError(io::Error),
+}
+
+impl fmt::Display for TaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskError::ValidationError { message } => write!(f, "Invalid input: {}", message),
+            TaskError::ParseError { input } => write!(f, "Failed to parse number: {}", input),
+            TaskError::IoError(_) => write!(f, "IO operation failed"),
+        }
+    }
+}
+
+impl From<io::Error> for TaskError {
+    fn from(e: io::Error) -> Self {
+        TaskError::IoError(e)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Level {
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct LogEntry {
+    pub seconds: u64,
+    pub level: Level,
+    pub message: String,
+}
+
+pub fn parse_line(line: &str) -> Result<LogEntry, TaskError> {
+    let mut fields = line.splitn(3, ' ');
+    let (Some(seconds), Some(level), Some(message)) = (fields.next(), fields.next(), fields.next()) else {
+        return Err(TaskError::ValidationError { message: "missing fields".to_string() });
+    };
+    let seconds = seconds.parse().map_err(|_| TaskError::ParseError { input: seconds.to_string() })?;
+    let level = match level {
+        "INFO" => Level::Info,
+        "WARN" => Level::Warn,
+        "ERROR" => Level::Error,
+        other => return Err(TaskError::ValidationError { message: format!("unknown level '{}'", other) }),
+    };
+    Ok(LogEntry { seconds, level, message: message.to_string() })
+}
+
+pub fn error_messages<R: BufRead>(reader: R) -> Result<Vec<String>, TaskError> {
+    let mut messages = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = parse_line(&line)?;
+        if entry.level == Level::Error {
+            messages.push(entry.message);
+        }
+    }
+    Ok(messages)
+}
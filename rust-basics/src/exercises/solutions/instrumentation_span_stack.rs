@@ -0,0 +1,40 @@
+// ===== SOLUTION: INSTRUMENTATION - A TINY SPAN STACK =====
+
+use std::cell::RefCell;
+
+#[derive(Debug, Default)]
+pub struct Tracer {
+    stack: RefCell<Vec<String>>,
+    lines: RefCell<Vec<String>>,
+}
+
+pub struct SpanGuard<'a> {
+    tracer: &'a Tracer,
+}
+
+impl Tracer {
+    pub fn enter(&self, name: &str) -> SpanGuard<'_> {
+        self.stack.borrow_mut().push(name.to_string());
+        SpanGuard { tracer: self }
+    }
+
+    pub fn event(&self, level: &str, message: &str) {
+        let stack = self.stack.borrow();
+        let line = if stack.is_empty() {
+            format!("{} {}", level, message)
+        } else {
+            format!("{} {}: {}", level, stack.join(":"), message)
+        };
+        self.lines.borrow_mut().push(line);
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.lines.borrow().clone()
+    }
+}
+
+impl Drop for SpanGuard<'_> {
+    fn drop(&mut self) {
+        self.tracer.stack.borrow_mut().pop();
+    }
+}
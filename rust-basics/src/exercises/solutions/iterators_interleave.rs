@@ -0,0 +1,55 @@
+// ===== SOLUTION: ITERATORS - AN INTERLEAVE ADAPTOR =====
+
+pub struct Interleave<A, B> {
+    a: A,
+    b: B,
+    a_done: bool,
+    b_done: bool,
+    b_next: bool,
+}
+
+impl<A, B> Iterator for Interleave<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        // At most one try per side: the one whose turn it is, then the other
+        for _ in 0..2 {
+            if self.b_next {
+                if !self.b_done {
+                    match self.b.next() {
+                        Some(item) => {
+                            self.b_next = false;
+                            return Some(item);
+                        }
+                        None => self.b_done = true,
+                    }
+                }
+            } else if !self.a_done {
+                match self.a.next() {
+                    Some(item) => {
+                        self.b_next = true;
+                        return Some(item);
+                    }
+                    None => self.a_done = true,
+                }
+            }
+            self.b_next = !self.b_next;
+        }
+        None
+    }
+}
+
+pub trait InterleaveExt: Iterator + Sized {
+    fn interleave<B>(self, other: B) -> Interleave<Self, B::IntoIter>
+    where
+        B: IntoIterator<Item = Self::Item>,
+    {
+        Interleave { a: self, b: other.into_iter(), a_done: false, b_done: false, b_next: false }
+    }
+}
+
+impl<I: Iterator> InterleaveExt for I {}
@@ -0,0 +1,38 @@
+// ===== SOLUTION: KV STORE - AN EXPIRING MAP =====
+
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct ExpiringMap {
+    entries: HashMap<String, (String, Option<u64>)>,
+}
+
+fn is_live(expiry: Option<u64>, now: u64) -> bool {
+    expiry.is_none_or(|at| now < at)
+}
+
+impl ExpiringMap {
+    pub fn new() -> Self {
+        ExpiringMap::default()
+    }
+
+    pub fn set(&mut self, key: &str, value: &str, ttl: Option<u64>, now: u64) {
+        let expiry = ttl.map(|ttl| now.saturating_add(ttl));
+        self.entries.insert(key.to_string(), (value.to_string(), expiry));
+    }
+
+    pub fn get(&self, key: &str, now: u64) -> Option<&str> {
+        let (value, expiry) = self.entries.get(key)?;
+        is_live(*expiry, now).then_some(value.as_str())
+    }
+
+    pub fn len(&self, now: u64) -> usize {
+        self.entries.values().filter(|(_, expiry)| is_live(*expiry, now)).count()
+    }
+
+    pub fn purge(&mut self, now: u64) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|_, (_, expiry)| is_live(*expiry, now));
+        before - self.entries.len()
+    }
+}
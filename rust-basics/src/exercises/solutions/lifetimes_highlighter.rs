@@ -0,0 +1,25 @@
+// ===== SOLUTION: LIFETIMES - HIGHLIGHTER =====
+
+pub fn longest_word<'a>(text: &'a str) -> &'a str {
+    let mut longest = "";
+    for word in text.split_whitespace() {
+        // Strictly longer, so the first of equal words stays
+        if word.len() > longest.len() {
+            longest = word;
+        }
+    }
+    longest
+}
+
+pub struct Highlighter<'a> {
+    pub text: &'a str,
+}
+
+impl<'a> Highlighter<'a> {
+    pub fn first_sentence(&self) -> &'a str {
+        match self.text.find('.') {
+            Some(end) => &self.text[..=end],
+            None => self.text,
+        }
+    }
+}
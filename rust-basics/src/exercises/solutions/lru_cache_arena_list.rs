@@ -0,0 +1,110 @@
+// ===== SOLUTION: LRU CACHE - AN ARENA LINKED LIST =====
+
+pub struct Node<T> {
+    value: T,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+pub struct ArenaList<T> {
+    slots: Vec<Option<Node<T>>>,
+    free: Vec<usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
+}
+
+impl<T: Clone> ArenaList<T> {
+    pub fn new() -> Self {
+        ArenaList { slots: Vec::new(), free: Vec::new(), head: None, tail: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn slots(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn push_front(&mut self, value: T) -> usize {
+        let node = Some(Node { value, prev: None, next: None });
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.slots[index] = node;
+                index
+            }
+            None => {
+                self.slots.push(node);
+                self.slots.len() - 1
+            }
+        };
+        self.link_front(index);
+        self.len += 1;
+        index
+    }
+
+    pub fn move_to_front(&mut self, index: usize) {
+        if self.is_used(index) {
+            self.unlink(index);
+            self.link_front(index);
+        }
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if !self.is_used(index) {
+            return None;
+        }
+        self.unlink(index);
+        self.free.push(index);
+        self.len -= 1;
+        self.slots[index].take().map(|node| node.value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.remove(self.tail?)
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.len);
+        let mut current = self.head;
+        while let Some(index) = current {
+            let node = self.slots[index].as_ref().expect("linked nodes are in use");
+            values.push(node.value.clone());
+            current = node.next;
+        }
+        values
+    }
+
+    fn is_used(&self, index: usize) -> bool {
+        self.slots.get(index).is_some_and(Option::is_some)
+    }
+
+    fn node(&mut self, index: usize) -> &mut Node<T> {
+        self.slots[index].as_mut().expect("linked nodes are in use")
+    }
+
+    // Takes the node out of the chain, leaving its own links cleared
+    fn unlink(&mut self, index: usize) {
+        let node = self.node(index);
+        let (prev, next) = (node.prev.take(), node.next.take());
+        match prev {
+            Some(prev) => self.node(prev).next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.node(next).prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn link_front(&mut self, index: usize) {
+        let old_head = self.head;
+        self.node(index).next = old_head;
+        match old_head {
+            Some(head) => self.node(head).prev = Some(index),
+            None => self.tail = Some(index),
+        }
+        self.head = Some(index);
+    }
+}
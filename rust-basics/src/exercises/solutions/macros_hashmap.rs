@@ -0,0 +1,10 @@
+// ===== SOLUTION: MACROS - HASHMAP LITERAL =====
+
+#[macro_export]
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        let mut map = ::std::collections::HashMap::new();
+        $( map.insert($key, $value); )*
+        map
+    }};
+}
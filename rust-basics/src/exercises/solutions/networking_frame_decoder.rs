@@ -0,0 +1,41 @@
+// ===== SOLUTION: NETWORKING - A FRAME DECODER =====
+
+#[derive(Debug, PartialEq)]
+pub struct FrameTooLarge(pub usize);
+
+pub fn encode(payload: &[u8]) -> Vec<u8> {
+    let mut frame = (payload.len() as u32).to_be_bytes().to_vec();
+    frame.extend_from_slice(payload);
+    frame
+}
+
+pub struct FrameDecoder {
+    max_len: usize,
+    buffer: Vec<u8>,
+}
+
+impl FrameDecoder {
+    pub fn new(max_len: usize) -> Self {
+        FrameDecoder { max_len, buffer: Vec::new() }
+    }
+
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    pub fn next_frame(&mut self) -> Result<Option<Vec<u8>>, FrameTooLarge> {
+        if self.buffer.len() < 4 {
+            return Ok(None);
+        }
+        let len = u32::from_be_bytes([self.buffer[0], self.buffer[1], self.buffer[2], self.buffer[3]]) as usize;
+        if len > self.max_len {
+            return Err(FrameTooLarge(len));
+        }
+        if self.buffer.len() < 4 + len {
+            return Ok(None);
+        }
+        let frame = self.buffer[4..4 + len].to_vec();
+        self.buffer.drain(..4 + len);
+        Ok(Some(frame))
+    }
+}
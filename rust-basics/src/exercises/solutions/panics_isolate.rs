@@ -0,0 +1,22 @@
+// ===== SOLUTION: PANICS - ISOLATING FAILURES =====
+
+use std::any::Any;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+pub fn panic_text(payload: &(dyn Any + Send)) -> String {
+    if let Some(text) = payload.downcast_ref::<&str>() {
+        text.to_string()
+    } else if let Some(text) = payload.downcast_ref::<String>() {
+        text.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+pub fn run_isolated(jobs: &[fn() -> u32]) -> Vec<Result<u32, String>> {
+    jobs.iter().map(|&job| std::panic::catch_unwind(job).map_err(|payload| panic_text(&*payload))).collect()
+}
+
+pub fn lock_or_recover<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,49 @@
+// ===== SOLUTION: PARSING - AN HTTP REQUEST LINE =====
+
+#[derive(Debug, PartialEq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RequestError<'a> {
+    Malformed,
+    UnknownMethod(&'a str),
+    UnsupportedVersion(&'a str),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RequestLine<'a> {
+    pub method: Method,
+    pub path: &'a str,
+    pub query: Vec<(&'a str, &'a str)>,
+    pub version: &'a str,
+}
+
+pub fn parse_request_line(line: &str) -> Result<RequestLine<'_>, RequestError<'_>> {
+    let (method, rest) = line.split_once(' ').ok_or(RequestError::Malformed)?;
+    let (target, version) = rest.split_once(' ').ok_or(RequestError::Malformed)?;
+    if version.contains(' ') || !target.starts_with('/') {
+        return Err(RequestError::Malformed);
+    }
+    let method = match method {
+        "GET" => Method::Get,
+        "POST" => Method::Post,
+        "PUT" => Method::Put,
+        "DELETE" => Method::Delete,
+        other => return Err(RequestError::UnknownMethod(other)),
+    };
+    if version != "HTTP/1.0" && version != "HTTP/1.1" {
+        return Err(RequestError::UnsupportedVersion(version));
+    }
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let query = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+        .collect();
+    Ok(RequestLine { method, path, query, version })
+}
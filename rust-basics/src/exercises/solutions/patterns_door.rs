@@ -0,0 +1,43 @@
+// ===== SOLUTION: PATTERNS - A TYPESTATE DOOR =====
+
+use std::marker::PhantomData;
+
+pub const CODE: u32 = 1234;
+
+pub struct Locked;
+pub struct Unlocked;
+
+pub struct Door<State> {
+    opened: u32,
+    state: PhantomData<State>,
+}
+
+impl Door<Locked> {
+    pub fn new() -> Self {
+        Door { opened: 0, state: PhantomData }
+    }
+
+    pub fn unlock(self, code: u32) -> Result<Door<Unlocked>, Door<Locked>> {
+        if code == CODE {
+            Ok(Door { opened: self.opened, state: PhantomData })
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Door<Unlocked> {
+    pub fn open(&mut self) {
+        self.opened += 1;
+    }
+
+    pub fn lock(self) -> Door<Locked> {
+        Door { opened: self.opened, state: PhantomData }
+    }
+}
+
+impl<State> Door<State> {
+    pub fn times_opened(&self) -> u32 {
+        self.opened
+    }
+}
@@ -0,0 +1,25 @@
+// ===== SOLUTION: PIN - PROJECTING INTO A WRAPPED FUTURE =====
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub struct Doubler<F> {
+    inner: F,
+}
+
+impl<F> Doubler<F> {
+    pub fn new(inner: F) -> Self {
+        Doubler { inner }
+    }
+}
+
+impl<F: Future<Output = u32>> Future for Doubler<F> {
+    type Output = u32;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+        // SAFETY: `inner` is never moved out of a pinned Doubler
+        let inner = unsafe { self.map_unchecked_mut(|doubler| &mut doubler.inner) };
+        inner.poll(cx).map(|n| n * 2)
+    }
+}
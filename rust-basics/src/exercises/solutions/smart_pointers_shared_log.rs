@@ -0,0 +1,30 @@
+// ===== SOLUTION: SMART POINTERS - SHARED LOG =====
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct SharedLog {
+    messages: Rc<RefCell<Vec<String>>>,
+}
+
+impl SharedLog {
+    pub fn new() -> Self {
+        SharedLog { messages: Rc::new(RefCell::new(Vec::new())) }
+    }
+
+    pub fn handle(&self) -> SharedLog {
+        SharedLog { messages: Rc::clone(&self.messages) }
+    }
+
+    pub fn push(&self, message: &str) {
+        self.messages.borrow_mut().push(message.to_string());
+    }
+
+    pub fn messages(&self) -> Vec<String> {
+        self.messages.borrow().clone()
+    }
+
+    pub fn owners(&self) -> usize {
+        Rc::strong_count(&self.messages)
+    }
+}
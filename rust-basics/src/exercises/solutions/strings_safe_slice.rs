@@ -0,0 +1,25 @@
+// ===== SOLUTION: STRINGS - SLICING MULTIBYTE TEXT SAFELY =====
+
+// The byte offset where char n starts, or the length for the char just past the end
+fn char_offset(text: &str, n: usize) -> Option<usize> {
+    text.char_indices().map(|(offset, _)| offset).chain(std::iter::once(text.len())).nth(n)
+}
+
+pub fn take_chars(text: &str, n: usize) -> &str {
+    &text[..char_offset(text, n).unwrap_or(text.len())]
+}
+
+pub fn clip_bytes(text: &str, max: usize) -> &str {
+    let mut end = max.min(text.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+pub fn char_slice(text: &str, start: usize, end: usize) -> Option<&str> {
+    if start > end {
+        return None;
+    }
+    text.get(char_offset(text, start)?..char_offset(text, end)?)
+}
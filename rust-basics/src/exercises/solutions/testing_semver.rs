@@ -0,0 +1,37 @@
+// ===== SOLUTION: TESTING - VERSIONS =====
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+pub enum Bump {
+    Major,
+    Minor,
+    Patch,
+}
+
+pub fn parse_version(input: &str) -> Option<Version> {
+    let parts: Vec<&str> = input.trim().split('.').collect();
+    let [major, minor, patch] = parts[..] else {
+        return None;
+    };
+    // u32::parse alone would take "+1"
+    let number = |part: &str| -> Option<u32> {
+        if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        part.parse().ok()
+    };
+    Some(Version { major: number(major)?, minor: number(minor)?, patch: number(patch)? })
+}
+
+pub fn bump(version: Version, part: Bump) -> Version {
+    match part {
+        Bump::Major => Version { major: version.major + 1, minor: 0, patch: 0 },
+        Bump::Minor => Version { minor: version.minor + 1, patch: 0, ..version },
+        Bump::Patch => Version { patch: version.patch + 1, ..version },
+    }
+}
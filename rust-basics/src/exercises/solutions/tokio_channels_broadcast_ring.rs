@@ -0,0 +1,44 @@
+// ===== SOLUTION: CHANNELS - A LAGGING BROADCAST BUFFER =====
+
+use std::collections::VecDeque;
+
+#[derive(Debug, PartialEq)]
+pub enum RecvError {
+    Lagged(u64),
+    Empty,
+}
+
+pub struct BroadcastRing {
+    capacity: usize,
+    next_seq: u64,
+    messages: VecDeque<(u64, u32)>,
+}
+
+impl BroadcastRing {
+    pub fn new(capacity: usize) -> Self {
+        BroadcastRing { capacity, next_seq: 0, messages: VecDeque::with_capacity(capacity) }
+    }
+
+    pub fn send(&mut self, value: u32) {
+        if self.messages.len() == self.capacity {
+            self.messages.pop_front();
+        }
+        self.messages.push_back((self.next_seq, value));
+        self.next_seq += 1;
+    }
+
+    pub fn recv(&self, cursor: &mut u64) -> Result<u32, RecvError> {
+        if *cursor >= self.next_seq {
+            return Err(RecvError::Empty);
+        }
+        let &(oldest, _) = self.messages.front().ok_or(RecvError::Empty)?;
+        if *cursor < oldest {
+            let skipped = oldest - *cursor;
+            *cursor = oldest;
+            return Err(RecvError::Lagged(skipped));
+        }
+        let (_, value) = self.messages[(*cursor - oldest) as usize];
+        *cursor += 1;
+        Ok(value)
+    }
+}
@@ -0,0 +1,38 @@
+// ===== SOLUTION: TRAITS - SHAPES =====
+
+pub trait Shape {
+    fn area(&self) -> f64;
+
+    fn name(&self) -> String {
+        "shape".to_string()
+    }
+}
+
+pub struct Circle {
+    pub radius: f64,
+}
+
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    fn name(&self) -> String {
+        "circle".to_string()
+    }
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+}
+
+pub fn total_area(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
@@ -0,0 +1,30 @@
+// ===== SOLUTION: ZERO COPY - BORROW UNLESS YOU MUST =====
+
+use std::borrow::Cow;
+
+pub fn unquote(field: &str) -> Cow<'_, str> {
+    let inner = match field.strip_prefix('"').and_then(|rest| rest.strip_suffix('"')) {
+        Some(inner) => inner,
+        None => return Cow::Borrowed(field),
+    };
+    if inner.contains("\\\"") {
+        Cow::Owned(inner.replace("\\\"", "\""))
+    } else {
+        Cow::Borrowed(inner)
+    }
+}
+
+pub fn collapse_spaces(text: &str) -> Cow<'_, str> {
+    if !text.contains("  ") {
+        return Cow::Borrowed(text);
+    }
+    let mut collapsed = String::with_capacity(text.len());
+    let mut last_was_space = false;
+    for c in text.chars() {
+        if c != ' ' || !last_was_space {
+            collapsed.push(c);
+        }
+        last_was_space = c == ' ';
+    }
+    Cow::Owned(collapsed)
+}
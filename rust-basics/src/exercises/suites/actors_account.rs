@@ -0,0 +1,36 @@
+#[test]
+fn deposits_and_withdrawals() {
+    let account = AccountHandle::spawn();
+    assert_eq!(account.balance(), 0);
+    assert_eq!(account.deposit(50), 50);
+    assert_eq!(account.withdraw(20), Ok(30));
+    assert_eq!(account.balance(), 30);
+}
+
+#[test]
+fn overdrafts_are_refused() {
+    let account = AccountHandle::spawn();
+    account.deposit(10);
+    assert_eq!(account.withdraw(11), Err(InsufficientFunds));
+    assert_eq!(account.balance(), 10);
+    assert_eq!(account.withdraw(10), Ok(0));
+}
+
+#[test]
+fn clones_share_one_account_across_threads() {
+    let account = AccountHandle::spawn();
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let account = account.clone();
+            std::thread::spawn(move || {
+                for _ in 0..100 {
+                    account.deposit(1);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    assert_eq!(account.balance(), 400);
+}
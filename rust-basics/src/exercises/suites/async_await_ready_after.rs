@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Wake, Waker};
+
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+// Polls until ready, returning (value, polls, wakes)
+fn drive<F: Future + Unpin>(mut future: F) -> (F::Output, usize, usize) {
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(Arc::clone(&counter));
+    let mut cx = Context::from_waker(&waker);
+    for polls in 1..=100 {
+        if let Poll::Ready(value) = Pin::new(&mut future).poll(&mut cx) {
+            return (value, polls, counter.0.load(Ordering::SeqCst));
+        }
+    }
+    panic!("future was still pending after 100 polls");
+}
+
+#[test]
+fn zero_means_ready_immediately() {
+    assert_eq!(drive(ReadyAfter::new(0, "now")), ("now", 1, 0));
+}
+
+#[test]
+fn completes_on_the_expected_poll() {
+    let (value, polls, _) = drive(ReadyAfter::new(3, 42));
+    assert_eq!((value, polls), (42, 4));
+}
+
+#[test]
+fn wakes_every_time_it_is_pending() {
+    let (_, _, wakes) = drive(ReadyAfter::new(5, ()));
+    assert_eq!(wakes, 5);
+}
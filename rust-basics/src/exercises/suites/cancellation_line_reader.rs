@@ -0,0 +1,61 @@
+fn noop_waker() -> std::task::Waker {
+    fn clone(_: *const ()) -> std::task::RawWaker {
+        std::task::RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+    fn noop(_: *const ()) {}
+    static VTABLE: std::task::RawWakerVTable = std::task::RawWakerVTable::new(clone, noop, noop, noop);
+    unsafe { std::task::Waker::from_raw(clone(std::ptr::null())) }
+}
+
+// Polls the future at most `polls` times; None if it was still pending
+// when it got dropped
+fn poll_up_to<F: Future>(future: F, polls: usize) -> Option<F::Output> {
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    for _ in 0..polls {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return Some(output);
+        }
+    }
+    None
+}
+
+fn finish<F: Future>(future: F) -> F::Output {
+    poll_up_to(future, usize::MAX).unwrap()
+}
+
+#[test]
+fn reads_lines_to_the_end() {
+    let mut reader = LineReader::new(ByteSource::new("first\nsecond\n\nlast"));
+    assert_eq!(finish(reader.next_line()), Some("first".to_string()));
+    assert_eq!(finish(reader.next_line()), Some("second".to_string()));
+    assert_eq!(finish(reader.next_line()), Some(String::new()));
+    assert_eq!(finish(reader.next_line()), Some("last".to_string()));
+    assert_eq!(finish(reader.next_line()), None);
+}
+
+#[test]
+fn a_cancelled_call_loses_nothing() {
+    let mut reader = LineReader::new(ByteSource::new("hello\nworld\n"));
+    // Four polls consume two bytes, then the future is dropped
+    assert_eq!(poll_up_to(reader.next_line(), 4), None);
+    assert_eq!(finish(reader.next_line()), Some("hello".to_string()));
+    assert_eq!(finish(reader.next_line()), Some("world".to_string()));
+}
+
+#[test]
+fn survives_cancellation_after_every_byte() {
+    let mut reader = LineReader::new(ByteSource::new("ab\ncd\n"));
+    let mut lines = Vec::new();
+    for _ in 0..20 {
+        // Two polls complete at most one byte before the call is dropped
+        if let Some(line) = poll_up_to(reader.next_line(), 2) {
+            match line {
+                Some(line) => lines.push(line),
+                None => break,
+            }
+        }
+    }
+    assert_eq!(lines, ["ab", "cd"]);
+}
@@ -0,0 +1,21 @@
+#[test]
+fn counts_case_insensitively() {
+    let result = top_words("Rust rust RUST go", 2);
+    assert_eq!(result, [("rust".to_string(), 3), ("go".to_string(), 1)]);
+}
+
+#[test]
+fn breaks_ties_alphabetically() {
+    let result = top_words("b a c b a c d", 3);
+    assert_eq!(
+        result,
+        [("a".to_string(), 2), ("b".to_string(), 2), ("c".to_string(), 2)]
+    );
+}
+
+#[test]
+fn returns_at_most_k() {
+    assert_eq!(top_words("one two", 10).len(), 2);
+    assert!(top_words("", 3).is_empty());
+    assert!(top_words("some words", 0).is_empty());
+}
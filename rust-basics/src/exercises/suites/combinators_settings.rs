@@ -0,0 +1,29 @@
+fn settings(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+#[test]
+fn require_names_the_missing_key() {
+    let s = settings(&[("host", "example.com")]);
+    assert_eq!(require(&s, "host"), Ok("example.com"));
+    assert_eq!(require(&s, "user"), Err("missing setting: user".to_string()));
+}
+
+#[test]
+fn port_is_optional_but_must_parse() {
+    assert_eq!(port(&settings(&[("port", "8080")])), Ok(Some(8080)));
+    assert_eq!(port(&settings(&[])), Ok(None));
+    assert!(port(&settings(&[("port", "http")])).is_err());
+    assert!(port(&settings(&[("port", "70000")])).is_err(), "doesn't fit in a u16");
+}
+
+#[test]
+fn defaults_and_pairs() {
+    assert_eq!(timeout_secs(&settings(&[("timeout", "5")])), 5);
+    assert_eq!(timeout_secs(&settings(&[("timeout", "soon")])), 30);
+    assert_eq!(timeout_secs(&settings(&[])), 30);
+
+    assert_eq!(endpoint(&settings(&[("host", "db"), ("port", "5432")])), Some("db:5432".to_string()));
+    assert_eq!(endpoint(&settings(&[("host", "db")])), None);
+    assert_eq!(endpoint(&settings(&[("port", "5432")])), None);
+}
@@ -0,0 +1,18 @@
+#[test]
+fn counts_words_across_threads() {
+    let texts = ["one two", "three", "", "four five six", "  seven  "];
+    for threads in 1..=8 {
+        assert_eq!(parallel_word_count(&texts, threads), 7, "with {} threads", threads);
+    }
+}
+
+#[test]
+fn handles_no_texts() {
+    assert_eq!(parallel_word_count(&[], 4), 0);
+}
+
+#[test]
+fn shared_counter_loses_no_updates() {
+    assert_eq!(shared_counter(8, 10_000), 80_000);
+    assert_eq!(shared_counter(1, 0), 0);
+}
@@ -0,0 +1,26 @@
+#[test]
+fn powers() {
+    assert_eq!(pow(2, 10), 1024);
+    assert_eq!(pow(10, 0), 1);
+    assert_eq!(pow(0, 3), 0);
+    assert_eq!(pow(3, 4), 81);
+}
+
+#[test]
+fn popcount_table_matches_count_ones() {
+    let table = popcount_table();
+    assert_eq!((table[0], table[7], table[128], table[255]), (0, 3, 1, 8));
+    for byte in 0..=255u8 {
+        assert_eq!(u32::from(table[byte as usize]), byte.count_ones(), "byte {}", byte);
+    }
+}
+
+#[test]
+fn counting_and_searching_bytes() {
+    assert_eq!(count_ones(b""), 0);
+    assert_eq!(count_ones(&[0xFF, 0x0F, 0x01]), 13);
+    assert_eq!(count_ones(b"rust"), b"rust".iter().map(|b| b.count_ones()).sum::<u32>());
+    assert!(contains(b"const", b'n'));
+    assert!(!contains(b"const", b'x'));
+    assert!(!contains(b"", 0));
+}
@@ -0,0 +1,39 @@
+#[test]
+fn keeps_input_order_and_every_result() {
+    let inputs: Vec<u32> = (0..20).collect();
+    let results = bounded_map(&inputs, 4, |&n| if n % 7 == 3 { Err(format!("bad {}", n)) } else { Ok(n * 10) });
+    assert_eq!(results.len(), 20);
+    assert_eq!(results[0], Ok(0));
+    assert_eq!(results[3], Err("bad 3".to_string()));
+    assert_eq!(results[19], Ok(190));
+
+    let (ok, failed) = summarize(results);
+    assert_eq!(ok.len(), 17);
+    assert_eq!(failed, ["3: bad 3", "10: bad 10", "17: bad 17"]);
+}
+
+#[test]
+fn never_exceeds_the_limit() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    for limit in [0, 1, 3] {
+        let (running, peak) = (AtomicUsize::new(0), AtomicUsize::new(0));
+        let results = bounded_map(&[(); 12], limit, |_| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(5));
+            running.fetch_sub(1, Ordering::SeqCst);
+            Ok::<_, String>(())
+        });
+        assert_eq!(results.len(), 12);
+        assert_eq!(peak.load(Ordering::SeqCst), limit.max(1), "limit {}", limit);
+    }
+}
+
+#[test]
+fn empty_input_runs_nothing() {
+    let results = bounded_map(&Vec::<u8>::new(), 4, |_| -> Result<u8, String> { panic!("no inputs") });
+    assert!(results.is_empty());
+    assert_eq!(summarize(results), (vec![], vec![]));
+}
@@ -0,0 +1,26 @@
+#[test]
+fn parses_valid_ports() {
+    assert_eq!(parse_port("8080"), Ok(8080));
+    assert_eq!(parse_port("  443 "), Ok(443));
+}
+
+#[test]
+fn rejects_invalid_and_zero_ports() {
+    assert_eq!(parse_port(" http "), Err(ConfigError::InvalidPort("http".to_string())));
+    assert_eq!(parse_port("70000"), Err(ConfigError::InvalidPort("70000".to_string())));
+    assert_eq!(parse_port("0"), Err(ConfigError::ZeroPort));
+}
+
+#[test]
+fn parses_lines() {
+    assert_eq!(parse_line("api = 8080"), Ok(("api".to_string(), 8080)));
+    assert_eq!(parse_line("db=5432"), Ok(("db".to_string(), 5432)));
+}
+
+#[test]
+fn reports_line_errors() {
+    assert_eq!(parse_line("api 8080"), Err(ConfigError::MissingEquals));
+    assert_eq!(parse_line("  = 80"), Err(ConfigError::EmptyName));
+    assert_eq!(parse_line("api = = 80"), Err(ConfigError::InvalidPort("= 80".to_string())));
+    assert_eq!(parse_line("api = 0"), Err(ConfigError::ZeroPort));
+}
@@ -0,0 +1,39 @@
+#[test]
+fn parses_well_formed_lines() {
+    let entry = parse_line("1714564800 ERROR disk full on /var").unwrap();
+    assert_eq!(
+        entry,
+        LogEntry { seconds: 1714564800, level: Level::Error, message: "disk full on /var".to_string() }
+    );
+    assert_eq!(parse_line("7 INFO ok").unwrap().level, Level::Info);
+}
+
+#[test]
+fn reports_what_is_wrong_with_a_line() {
+    assert_eq!(parse_line("7 INFO").unwrap_err().to_string(), "Invalid input: missing fields");
+    assert_eq!(parse_line("noon WARN late").unwrap_err().to_string(), "Failed to parse number: noon");
+    assert_eq!(parse_line("7 LOUD hi").unwrap_err().to_string(), "Invalid input: unknown level 'LOUD'");
+}
+
+#[test]
+fn collects_error_messages_from_a_reader() {
+    let log = "1 INFO start\n\n2 ERROR first\n3 WARN meh\n4 ERROR second\n";
+    assert_eq!(error_messages(io::Cursor::new(log)).unwrap(), ["first", "second"]);
+
+    let bad = "1 ERROR first\nbroken\n2 ERROR never seen\n";
+    assert!(matches!(error_messages(io::Cursor::new(bad)), Err(TaskError::ValidationError { .. })));
+}
+
+struct Broken;
+
+impl io::Read for Broken {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk on fire"))
+    }
+}
+
+#[test]
+fn io_errors_are_wrapped() {
+    let result = error_messages(io::BufReader::new(Broken));
+    assert!(matches!(result, Err(TaskError::IoError(ref e)) if e.to_string() == "disk on fire"));
+}
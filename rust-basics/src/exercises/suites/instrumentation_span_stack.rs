@@ -0,0 +1,33 @@
+#[test]
+fn events_outside_spans_have_no_prefix() {
+    let tracer = Tracer::default();
+    tracer.event("INFO", "starting");
+    tracer.event("WARN", "low disk");
+    assert_eq!(tracer.lines(), ["INFO starting", "WARN low disk"]);
+}
+
+#[test]
+fn nested_spans_build_a_path() {
+    let tracer = Tracer::default();
+    let _request = tracer.enter("request");
+    {
+        let _db = tracer.enter("db");
+        tracer.event("DEBUG", "query");
+        let _retry = tracer.enter("retry");
+        tracer.event("WARN", "timed out");
+    }
+    tracer.event("INFO", "done");
+    assert_eq!(tracer.lines(), ["DEBUG request:db: query", "WARN request:db:retry: timed out", "INFO request: done"]);
+}
+
+#[test]
+fn dropping_the_guard_exits_the_span() {
+    let tracer = Tracer::default();
+    for job in ["a", "b"] {
+        let _job = tracer.enter(job);
+        tracer.event("INFO", "run");
+    }
+    drop(tracer.enter("ignored"));
+    tracer.event("INFO", "idle");
+    assert_eq!(tracer.lines(), ["INFO a: run", "INFO b: run", "INFO idle"]);
+}
@@ -0,0 +1,37 @@
+#[test]
+fn alternates_then_drains_the_longer_side() {
+    assert_eq!([1, 2, 3].into_iter().interleave([10, 20, 30]).collect::<Vec<_>>(), [1, 10, 2, 20, 3, 30]);
+    assert_eq!([1, 2, 3].into_iter().interleave([10]).collect::<Vec<_>>(), [1, 10, 2, 3]);
+    assert_eq!([1].into_iter().interleave([10, 20, 30]).collect::<Vec<_>>(), [1, 10, 20, 30]);
+    assert_eq!(std::iter::empty::<u8>().interleave([]).next(), None);
+}
+
+#[test]
+fn is_lazy() {
+    let pulled = std::cell::Cell::new(0);
+    let counted = (0..).inspect(|_| pulled.set(pulled.get() + 1));
+    let mut mixed = counted.interleave(100..);
+    assert_eq!(pulled.get(), 0);
+    assert_eq!(mixed.nth(4), Some(2));
+    assert_eq!(pulled.get(), 3);
+}
+
+// Some, None, Some: resumes after returning None
+struct Flaky(u8);
+
+impl Iterator for Flaky {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        self.0 += 1;
+        (self.0 != 2).then_some(self.0)
+    }
+}
+
+#[test]
+fn is_fused() {
+    let mut mixed = Flaky(0).interleave(std::iter::empty());
+    assert_eq!(mixed.next(), Some(1));
+    assert_eq!(mixed.next(), None);
+    assert_eq!(mixed.next(), None);
+}
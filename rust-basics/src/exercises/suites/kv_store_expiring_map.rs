@@ -0,0 +1,38 @@
+#[test]
+fn entries_expire_at_their_tick() {
+    let mut map = ExpiringMap::new();
+    map.set("forever", "a", None, 0);
+    map.set("brief", "b", Some(5), 10);
+    map.set("instant", "c", Some(0), 10);
+    assert_eq!(map.get("brief", 14), Some("b"));
+    assert_eq!(map.get("brief", 15), None);
+    assert_eq!(map.get("instant", 10), None);
+    assert_eq!(map.get("forever", u64::MAX), Some("a"));
+    assert_eq!(map.get("missing", 0), None);
+    assert_eq!(map.len(14), 2);
+}
+
+#[test]
+fn setting_again_replaces_value_and_expiry() {
+    let mut map = ExpiringMap::new();
+    map.set("k", "old", Some(1), 0);
+    map.set("k", "new", None, 0);
+    assert_eq!(map.get("k", 100), Some("new"));
+    map.set("k", "newer", Some(10), 100);
+    assert_eq!(map.get("k", 109), Some("newer"));
+    assert_eq!(map.get("k", 110), None);
+}
+
+#[test]
+fn purge_frees_only_expired_entries() {
+    let mut map = ExpiringMap::new();
+    for (n, key) in ["a", "b", "c", "d"].into_iter().enumerate() {
+        map.set(key, "x", Some(n as u64 + 1), 0);
+    }
+    map.set("keep", "x", None, 0);
+    assert_eq!(map.purge(2), 2);
+    assert_eq!(map.purge(2), 0);
+    assert_eq!(map.len(2), 3);
+    assert_eq!(map.purge(100), 2);
+    assert_eq!(map.get("keep", 100), Some("x"));
+}
@@ -0,0 +1,31 @@
+#[test]
+fn longest_word_picks_the_first_longest() {
+    assert_eq!(longest_word("the quick brown fox"), "quick");
+    assert_eq!(longest_word("  ab cd  "), "ab");
+    assert_eq!(longest_word(""), "");
+}
+
+#[test]
+fn longest_word_borrows_from_the_input() {
+    let text = String::from("borrowed not copied");
+    let word = longest_word(&text);
+    assert!(text.as_ptr() <= word.as_ptr());
+    assert_eq!(word, "borrowed");
+}
+
+#[test]
+fn first_sentence_includes_the_period() {
+    let highlighter = Highlighter { text: "Rust is fast. It is safe." };
+    assert_eq!(highlighter.first_sentence(), "Rust is fast.");
+    assert_eq!(Highlighter { text: "no period" }.first_sentence(), "no period");
+}
+
+#[test]
+fn first_sentence_outlives_the_highlighter() {
+    let text = String::from("Lifetimes matter. Really.");
+    let sentence = {
+        let highlighter = Highlighter { text: &text };
+        highlighter.first_sentence()
+    };
+    assert_eq!(sentence, "Lifetimes matter.");
+}
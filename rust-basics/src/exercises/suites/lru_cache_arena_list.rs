@@ -0,0 +1,46 @@
+#[test]
+fn pushes_moves_and_pops() {
+    let mut list = ArenaList::new();
+    let a = list.push_front('a');
+    let b = list.push_front('b');
+    let c = list.push_front('c');
+    assert_eq!(list.to_vec(), ['c', 'b', 'a']);
+
+    list.move_to_front(a);
+    assert_eq!(list.to_vec(), ['a', 'c', 'b']);
+    list.move_to_front(a);
+    list.move_to_front(b);
+    assert_eq!(list.to_vec(), ['b', 'a', 'c']);
+    assert_eq!(list.pop_back(), Some('c'));
+    assert_eq!(list.remove(c), None, "c's slot is empty now");
+    assert_eq!(list.len(), 2);
+}
+
+#[test]
+fn removes_from_anywhere() {
+    let mut list = ArenaList::new();
+    let indexes: Vec<usize> = (1..=5).map(|n| list.push_front(n)).collect();
+    assert_eq!(list.remove(indexes[2]), Some(3));
+    assert_eq!(list.remove(indexes[4]), Some(5));
+    assert_eq!(list.remove(indexes[0]), Some(1));
+    assert_eq!(list.to_vec(), [4, 2]);
+    assert_eq!(list.remove(indexes[2]), None);
+    assert_eq!(list.remove(99), None);
+    assert_eq!(list.pop_back(), Some(2));
+    assert_eq!(list.pop_back(), Some(4));
+    assert_eq!(list.pop_back(), None);
+    assert!(list.to_vec().is_empty());
+}
+
+#[test]
+fn reuses_freed_slots() {
+    let mut list = ArenaList::new();
+    for n in 0..100 {
+        list.push_front(n);
+        if list.len() > 3 {
+            list.pop_back();
+        }
+    }
+    assert_eq!(list.to_vec(), [99, 98, 97]);
+    assert!(list.slots() <= 4, "{} slots for at most 4 values", list.slots());
+}
@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+
+#[test]
+fn empty_map() {
+    let map: HashMap<&str, i32> = hashmap! {};
+    assert!(map.is_empty());
+}
+
+#[test]
+fn inserts_pairs_with_optional_trailing_comma() {
+    let map: HashMap<&str, i32> = hashmap! { "a" => 1, "b" => 2 };
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["b"], 2);
+
+    let trailing: HashMap<&str, char> = hashmap! { "x" => 'x', };
+    assert_eq!(trailing["x"], 'x');
+}
+
+#[test]
+fn evaluates_each_expression_once() {
+    let mut calls = 0;
+    let mut next = || {
+        calls += 1;
+        calls
+    };
+    let map: HashMap<i32, i32> = hashmap! { next() => next(), next() => next() };
+    assert_eq!(map.len(), 2);
+    assert_eq!(calls, 4);
+}
@@ -0,0 +1,40 @@
+#[test]
+fn encodes_a_big_endian_length_prefix() {
+    assert_eq!(encode(b"hi"), [0, 0, 0, 2, b'h', b'i']);
+    assert_eq!(encode(b""), [0, 0, 0, 0]);
+}
+
+#[test]
+fn decodes_frames_split_across_chunks() {
+    let bytes = [encode(b"hello"), encode(b""), encode(b"world")].concat();
+    for chunk_size in [1, 2, 5, 100] {
+        let mut decoder = FrameDecoder::new(64);
+        let mut frames = Vec::new();
+        for chunk in bytes.chunks(chunk_size) {
+            decoder.push(chunk);
+            while let Some(frame) = decoder.next_frame().unwrap() {
+                frames.push(frame);
+            }
+        }
+        assert_eq!(frames, [b"hello".to_vec(), Vec::new(), b"world".to_vec()], "chunks of {}", chunk_size);
+    }
+}
+
+#[test]
+fn waits_for_incomplete_frames() {
+    let mut decoder = FrameDecoder::new(64);
+    decoder.push(&[0, 0]);
+    assert_eq!(decoder.next_frame(), Ok(None));
+    decoder.push(&[0, 3, b'a', b'b']);
+    assert_eq!(decoder.next_frame(), Ok(None));
+    decoder.push(b"c");
+    assert_eq!(decoder.next_frame(), Ok(Some(b"abc".to_vec())));
+    assert_eq!(decoder.next_frame(), Ok(None));
+}
+
+#[test]
+fn rejects_oversized_lengths_early() {
+    let mut decoder = FrameDecoder::new(8);
+    decoder.push(&[0, 0, 0, 9]);
+    assert_eq!(decoder.next_frame(), Err(FrameTooLarge(9)));
+}
@@ -0,0 +1,49 @@
+fn seven() -> u32 {
+    7
+}
+
+fn literal_panic() -> u32 {
+    panic!("out of fuel")
+}
+
+fn formatted_panic() -> u32 {
+    let step = 3;
+    panic!("failed at step {}", step)
+}
+
+#[test]
+fn payload_text_for_both_kinds() {
+    let literal = std::panic::catch_unwind(literal_panic).unwrap_err();
+    assert_eq!(panic_text(&*literal), "out of fuel");
+    let formatted = std::panic::catch_unwind(formatted_panic).unwrap_err();
+    assert_eq!(panic_text(&*formatted), "failed at step 3");
+    let other = std::panic::catch_unwind(|| std::panic::panic_any(42_i32)).unwrap_err();
+    assert_eq!(panic_text(&*other), "unknown panic");
+}
+
+#[test]
+fn one_panic_doesnt_stop_the_batch() {
+    let jobs: [fn() -> u32; 4] = [seven, literal_panic, formatted_panic, seven];
+    assert_eq!(
+        run_isolated(&jobs),
+        [Ok(7), Err("out of fuel".to_string()), Err("failed at step 3".to_string()), Ok(7)]
+    );
+    assert!(run_isolated(&[]).is_empty());
+}
+
+#[test]
+fn poisoned_mutex_is_still_usable() {
+    let counter = std::sync::Arc::new(Mutex::new(1));
+    let poisoner = std::sync::Arc::clone(&counter);
+    let crashed = std::thread::spawn(move || {
+        let mut value = poisoner.lock().unwrap();
+        *value += 1;
+        panic!("crash while holding the lock");
+    })
+    .join();
+    assert!(crashed.is_err());
+    assert!(counter.is_poisoned());
+
+    *lock_or_recover(&counter) += 10;
+    assert_eq!(*lock_or_recover(&counter), 12);
+}
@@ -0,0 +1,28 @@
+#[test]
+fn parses_path_and_query() {
+    let request = parse_request_line("GET /search?q=rust&page=2 HTTP/1.1").unwrap();
+    assert_eq!(request.method, Method::Get);
+    assert_eq!(request.path, "/search");
+    assert_eq!(request.query, [("q", "rust"), ("page", "2")]);
+    assert_eq!(request.version, "HTTP/1.1");
+
+    let request = parse_request_line("DELETE /items/7 HTTP/1.0").unwrap();
+    assert_eq!((request.method, request.path, request.query.len()), (Method::Delete, "/items/7", 0));
+}
+
+#[test]
+fn odd_query_strings() {
+    let request = parse_request_line("POST /?flag&&a=1&b=& HTTP/1.1").unwrap();
+    assert_eq!(request.path, "/");
+    assert_eq!(request.query, [("flag", ""), ("a", "1"), ("b", "")]);
+}
+
+#[test]
+fn rejects_bad_request_lines() {
+    assert_eq!(parse_request_line("GET /"), Err(RequestError::Malformed));
+    assert_eq!(parse_request_line("GET / HTTP/1.1 extra"), Err(RequestError::Malformed));
+    assert_eq!(parse_request_line("GET index.html HTTP/1.1"), Err(RequestError::Malformed));
+    assert_eq!(parse_request_line("get / HTTP/1.1"), Err(RequestError::UnknownMethod("get")));
+    assert_eq!(parse_request_line("PATCH / HTTP/1.1"), Err(RequestError::UnknownMethod("PATCH")));
+    assert_eq!(parse_request_line("GET / HTTP/2"), Err(RequestError::UnsupportedVersion("HTTP/2")));
+}
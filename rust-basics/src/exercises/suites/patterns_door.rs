@@ -0,0 +1,30 @@
+#[test]
+fn new_doors_are_locked_and_unused() {
+    let door: Door<Locked> = Door::new();
+    assert_eq!(door.times_opened(), 0);
+}
+
+#[test]
+fn wrong_codes_hand_the_door_back() {
+    let door = Door::new();
+    let door = match door.unlock(CODE + 1) {
+        Ok(_) => panic!("unlocked with the wrong code"),
+        Err(locked) => locked,
+    };
+    let mut door = door.unlock(CODE).ok().expect("the right code unlocks");
+    door.open();
+    assert_eq!(door.times_opened(), 1);
+}
+
+#[test]
+fn locking_keeps_the_count() {
+    let mut door = Door::new().unlock(CODE).ok().unwrap();
+    door.open();
+    door.open();
+    let locked: Door<Locked> = door.lock();
+    assert_eq!(locked.times_opened(), 2);
+
+    let mut door = locked.unlock(CODE).ok().unwrap();
+    door.open();
+    assert_eq!(door.times_opened(), 3);
+}
@@ -0,0 +1,47 @@
+use std::marker::PhantomPinned;
+use std::task::Waker;
+
+// A !Unpin future that is pending once before completing
+struct NotUnpin {
+    polled: bool,
+    value: u32,
+    _pin: PhantomPinned,
+}
+
+impl Future for NotUnpin {
+    type Output = u32;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+        // SAFETY: no field is moved out
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.polled {
+            Poll::Ready(this.value)
+        } else {
+            this.polled = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+fn drive<F: Future>(future: F) -> (F::Output, usize) {
+    let mut future = Box::pin(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    for polls in 1..=10 {
+        if let Poll::Ready(value) = future.as_mut().poll(&mut cx) {
+            return (value, polls);
+        }
+    }
+    panic!("still pending after 10 polls");
+}
+
+#[test]
+fn doubles_a_ready_future() {
+    assert_eq!(drive(Doubler::new(std::future::ready(21))), (42, 1));
+}
+
+#[test]
+fn passes_pending_through_and_supports_not_unpin() {
+    let inner = NotUnpin { polled: false, value: 5, _pin: PhantomPinned };
+    assert_eq!(drive(Doubler::new(inner)), (10, 2));
+}
@@ -0,0 +1,29 @@
+#[test]
+fn new_log_is_empty_with_one_owner() {
+    let log = SharedLog::new();
+    assert!(log.messages().is_empty());
+    assert_eq!(log.owners(), 1);
+}
+
+#[test]
+fn handles_share_the_same_messages() {
+    let log = SharedLog::new();
+    let other = log.handle();
+    log.push("first");
+    other.push("second");
+    assert_eq!(log.messages(), ["first", "second"]);
+    assert_eq!(other.messages(), ["first", "second"]);
+}
+
+#[test]
+fn owners_follow_handles() {
+    let log = SharedLog::new();
+    let a = log.handle();
+    {
+        let _b = a.handle();
+        assert_eq!(log.owners(), 3);
+    }
+    assert_eq!(log.owners(), 2);
+    drop(a);
+    assert_eq!(log.owners(), 1);
+}
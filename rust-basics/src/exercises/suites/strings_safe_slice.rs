@@ -0,0 +1,27 @@
+#[test]
+fn takes_whole_chars() {
+    assert_eq!(take_chars("héllo", 2), "hé");
+    assert_eq!(take_chars("🦀🦀🦀", 1), "🦀");
+    assert_eq!(take_chars("ab", 0), "");
+    assert_eq!(take_chars("ab", 10), "ab");
+    assert_eq!(take_chars("", 3), "");
+}
+
+#[test]
+fn clips_to_a_byte_budget_on_a_boundary() {
+    assert_eq!(clip_bytes("héllo", 2), "h", "é is bytes 1..3");
+    assert_eq!(clip_bytes("héllo", 3), "hé");
+    assert_eq!(clip_bytes("🦀x", 3), "");
+    assert_eq!(clip_bytes("🦀x", 4), "🦀");
+    assert_eq!(clip_bytes("abc", 99), "abc");
+}
+
+#[test]
+fn slices_by_char_positions() {
+    assert_eq!(char_slice("🦀ab", 1, 3), Some("ab"));
+    assert_eq!(char_slice("🦀ab", 0, 1), Some("🦀"));
+    assert_eq!(char_slice("naïve", 2, 5), Some("ïve"));
+    assert_eq!(char_slice("naïve", 5, 5), Some(""));
+    assert_eq!(char_slice("naïve", 3, 6), None);
+    assert_eq!(char_slice("naïve", 3, 2), None);
+}
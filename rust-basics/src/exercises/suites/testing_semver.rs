@@ -0,0 +1,37 @@
+fn v(major: u32, minor: u32, patch: u32) -> Version {
+    Version { major, minor, patch }
+}
+
+#[test]
+fn parses_valid_versions() {
+    assert_eq!(parse_version("1.2.3"), Some(v(1, 2, 3)));
+    assert_eq!(parse_version(" 0.10.0\n"), Some(v(0, 10, 0)));
+}
+
+#[test]
+fn rejects_malformed_versions() {
+    for input in ["", "1.2", "1.2.3.4", "1..3", "a.b.c", "1.2.x", "+1.2.3", "1.-2.3", "1 .2.3"] {
+        assert_eq!(parse_version(input), None, "input {:?}", input);
+    }
+}
+
+#[test]
+fn bumps_reset_lower_parts() {
+    assert_eq!(bump(v(1, 2, 3), Bump::Major), v(2, 0, 0));
+    assert_eq!(bump(v(1, 2, 3), Bump::Minor), v(1, 3, 0));
+    assert_eq!(bump(v(1, 2, 3), Bump::Patch), v(1, 2, 4));
+}
+
+#[test]
+fn bumping_always_increases_the_version() {
+    for major in 0..3 {
+        for minor in 0..3 {
+            for patch in 0..3 {
+                let version = v(major, minor, patch);
+                for part in [Bump::Major, Bump::Minor, Bump::Patch] {
+                    assert!(bump(version, part) > version);
+                }
+            }
+        }
+    }
+}
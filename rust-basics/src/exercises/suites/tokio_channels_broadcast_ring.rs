@@ -0,0 +1,38 @@
+#[test]
+fn receives_in_order() {
+    let mut ring = BroadcastRing::new(4);
+    ring.send(10);
+    ring.send(20);
+    let mut cursor = 0;
+    assert_eq!(ring.recv(&mut cursor), Ok(10));
+    assert_eq!(ring.recv(&mut cursor), Ok(20));
+    assert_eq!(ring.recv(&mut cursor), Err(RecvError::Empty));
+    assert_eq!(cursor, 2);
+}
+
+#[test]
+fn receivers_are_independent() {
+    let mut ring = BroadcastRing::new(4);
+    ring.send(1);
+    let (mut fast, mut slow) = (0, 0);
+    assert_eq!(ring.recv(&mut fast), Ok(1));
+    ring.send(2);
+    assert_eq!(ring.recv(&mut slow), Ok(1));
+    assert_eq!(ring.recv(&mut fast), Ok(2));
+    assert_eq!(ring.recv(&mut slow), Ok(2));
+}
+
+#[test]
+fn slow_receiver_lags_and_catches_up() {
+    let mut ring = BroadcastRing::new(3);
+    for value in 0..10 {
+        ring.send(value);
+    }
+    let mut cursor = 0;
+    assert_eq!(ring.recv(&mut cursor), Err(RecvError::Lagged(7)));
+    assert_eq!(cursor, 7);
+    assert_eq!(ring.recv(&mut cursor), Ok(7));
+    assert_eq!(ring.recv(&mut cursor), Ok(8));
+    assert_eq!(ring.recv(&mut cursor), Ok(9));
+    assert_eq!(ring.recv(&mut cursor), Err(RecvError::Empty));
+}
@@ -0,0 +1,26 @@
+#[test]
+fn circle_area_is_pi_r_squared() {
+    let circle = Circle { radius: 2.0 };
+    assert!((circle.area() - std::f64::consts::PI * 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn rectangle_area_is_width_times_height() {
+    assert_eq!(Rectangle { width: 3.0, height: 4.5 }.area(), 13.5);
+}
+
+#[test]
+fn names_use_default_unless_overridden() {
+    assert_eq!(Circle { radius: 1.0 }.name(), "circle");
+    assert_eq!(Rectangle { width: 1.0, height: 1.0 }.name(), "shape");
+}
+
+#[test]
+fn total_area_sums_trait_objects() {
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Rectangle { width: 2.0, height: 3.0 }),
+        Box::new(Circle { radius: 1.0 }),
+    ];
+    assert!((total_area(&shapes) - (6.0 + std::f64::consts::PI)).abs() < 1e-9);
+    assert_eq!(total_area(&[]), 0.0);
+}
@@ -0,0 +1,37 @@
+fn borrowed(value: &Cow<'_, str>) -> bool {
+    matches!(value, Cow::Borrowed(_))
+}
+
+#[test]
+fn unquote_borrows_when_it_can() {
+    let plain = unquote("plain");
+    assert_eq!(plain, "plain");
+    assert!(borrowed(&plain));
+
+    let quoted = unquote("\"a b\"");
+    assert_eq!(quoted, "a b");
+    assert!(borrowed(&quoted), "the text between the quotes is a slice");
+
+    assert_eq!(unquote("\"\""), "");
+    assert_eq!(unquote("\"half"), "\"half");
+    assert_eq!(unquote("\""), "\"", "a lone quote isn't a pair");
+}
+
+#[test]
+fn unquote_allocates_for_escapes() {
+    let escaped = unquote("\"say \\\"hi\\\"\"");
+    assert_eq!(escaped, "say \"hi\"");
+    assert!(!borrowed(&escaped));
+}
+
+#[test]
+fn collapse_spaces_only_copies_when_needed() {
+    let single = collapse_spaces("one two three");
+    assert_eq!(single, "one two three");
+    assert!(borrowed(&single));
+
+    let spaced = collapse_spaces("one  two    three ");
+    assert_eq!(spaced, "one two three ");
+    assert!(!borrowed(&spaced));
+    assert_eq!(collapse_spaces("   "), " ");
+}
@@ -0,0 +1,552 @@
+// ===== FILE IO DEEP STUDY =====
+//
+// std::io IS BUILT ON A FEW SMALL TRAITS:
+// • Read: read(&mut buf) -> how many bytes arrived (0 = end of input)
+// • Write: write(&buf) -> how many bytes were accepted, plus flush()
+// • BufRead: a Read with an internal buffer - lines(), read_line(), fill_buf()
+// • Seek: move the cursor - SeekFrom::Start / End / Current
+//
+// File, TcpStream, Stdin, &[u8], Vec<u8> and io::Cursor all implement some
+// of them, so code written against the traits works on all of them - and most
+// of the tests below never touch the disk.
+//
+// THE LAYERS:
+//   your code ──▶ BufWriter ──▶ File ──▶ write() system call ──▶ kernel
+//                (batches small writes)   (expensive: one per call)
+
+use crate::errors::TaskError;
+use crate::report::Report;
+use memmap2::Mmap;
+use mini_logger::Level;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Cursor, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use tempfile::NamedTempFile;
+
+// ===== 1. Read AND Write =====
+//
+// UNDERSTANDING read():
+// • It may return FEWER bytes than the buffer holds - always use the count
+// • Ok(0) means end of input (for a non-empty buffer)
+// • ErrorKind::Interrupted means "try again", not failure
+// • read_exact / read_to_end / read_to_string wrap this loop for you
+
+// Byte count and a simple additive checksum, reading 4 KiB at a time
+pub fn checksum<R: Read>(mut reader: R) -> io::Result<(u64, u32)> {
+    let mut buf = [0u8; 4096];
+    let (mut len, mut sum) = (0u64, 0u32);
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => return Ok((len, sum)),
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        len += n as u64;
+        sum = buf[..n].iter().fold(sum, |sum, &byte| sum.wrapping_add(byte as u32));
+    }
+}
+
+// A Write adapter that counts how often the layer below is called - a
+// stand-in for counting system calls
+#[derive(Debug)]
+pub struct CountingWriter<W> {
+    inner: W,
+    pub writes: usize,
+    pub bytes: usize,
+}
+
+impl<W: Write> CountingWriter<W> {
+    pub fn new(inner: W) -> Self {
+        CountingWriter { inner, writes: 0, bytes: 0 }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.writes += 1;
+        self.bytes += n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+// ===== 2. BUFFERED READERS AND WRITERS =====
+//
+// UNDERSTANDING BufWriter:
+// • Collects writes in memory (8 KiB by default) and passes them on in one go
+// • writeln!(file, ...) on a bare File is one or more system calls PER LINE
+// • Dropping a BufWriter flushes, but IGNORES errors - call flush() or
+//   into_inner() yourself when the data matters
+//
+// UNDERSTANDING BufReader:
+// • Reads big chunks, hands them out in small pieces
+// • Gives any Read the BufRead methods: lines(), read_line(), split()
+// • lines() allocates a String per line; read_line() into a reused String
+//   doesn't
+
+pub fn write_report<W: Write>(mut writer: W, rows: &[(&str, u32)]) -> io::Result<W> {
+    for (name, score) in rows {
+        writeln!(writer, "{:<10} {:>5}", name, score)?;
+    }
+    writer.flush()?;
+    Ok(writer)
+}
+
+// Longest line, reusing one String for every line
+pub fn longest_line<R: BufRead>(mut reader: R) -> io::Result<Option<String>> {
+    let (mut line, mut longest) = (String::new(), None::<String>);
+    while reader.read_line(&mut line)? != 0 {
+        let trimmed = line.trim_end_matches(['\n', '\r']);
+        if longest.as_ref().is_none_or(|l| trimmed.len() > l.len()) {
+            longest = Some(trimmed.to_string());
+        }
+        line.clear();
+    }
+    Ok(longest)
+}
+
+// ===== 3. Seek: RANDOM ACCESS =====
+//
+// Fixed-size records make the position of record N simple arithmetic:
+//   | name (12 bytes, space padded) | score (u32, little endian) |  × N
+// seek() jumps straight there instead of reading everything before it.
+
+pub const NAME_LEN: usize = 12;
+pub const RECORD_LEN: usize = NAME_LEN + 4;
+
+pub fn write_record<W: Write>(writer: &mut W, name: &str, score: u32) -> io::Result<()> {
+    let mut record = [b' '; RECORD_LEN];
+    let name = &name.as_bytes()[..name.len().min(NAME_LEN)];
+    record[..name.len()].copy_from_slice(name);
+    record[NAME_LEN..].copy_from_slice(&score.to_le_bytes());
+    writer.write_all(&record)
+}
+
+pub fn read_record<R: Read + Seek>(reader: &mut R, index: u64) -> io::Result<(String, u32)> {
+    reader.seek(SeekFrom::Start(index * RECORD_LEN as u64))?;
+    let mut record = [0u8; RECORD_LEN];
+    // UnexpectedEof if the record isn't all there
+    reader.read_exact(&mut record)?;
+    let name = String::from_utf8_lossy(&record[..NAME_LEN]).trim_end().to_string();
+    let score = u32::from_le_bytes(record[NAME_LEN..].try_into().unwrap());
+    Ok((name, score))
+}
+
+// The length comes from seeking to the end - no need to read anything
+pub fn record_count<S: Seek>(stream: &mut S) -> io::Result<u64> {
+    Ok(stream.seek(SeekFrom::End(0))? / RECORD_LEN as u64)
+}
+
+// ===== 4. TEMPORARY FILES =====
+//
+// THE tempfile CRATE:
+// • tempfile::tempfile(): a File with no name; the OS deletes it when the
+//   last handle closes, even if the program crashes
+// • NamedTempFile: has a path (for APIs that want one); deleted on Drop
+//   unless you persist() it
+// • tempdir(): a whole directory, removed recursively on Drop
+//
+// ATOMIC WRITES:
+// Writing a config file in place leaves a half-written file if the program
+// dies midway. Instead: write a temp file in the SAME directory, then rename
+// it over the target. A rename within one filesystem is atomic - readers
+// see the old file or the new one, never a mix.
+
+pub fn write_atomically(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    let mut temp = NamedTempFile::new_in(dir)?;
+    temp.write_all(contents)?;
+    // Make sure the bytes are on disk before the new name points at them
+    temp.as_file().sync_all()?;
+    temp.persist(path).map_err(|e| e.error)?;
+    Ok(())
+}
+
+// ===== 5. MEMORY-MAPPED VS STREAMING READS =====
+//
+// STREAMING (BufReader / read):
+// • Copies chunks from the kernel into your buffer
+// • Constant memory however big the file is; works on pipes and sockets too
+//
+// MEMORY-MAPPED (memmap2::Mmap):
+// • Maps the file into the address space; the whole file looks like a &[u8]
+//   and pages are loaded on first touch
+// • No copying, cheap random access, and slice APIs work on the whole file
+// • unsafe: if another process truncates or changes the file while it's
+//   mapped, the "immutable" slice changes under you (or the program gets
+//   SIGBUS). Only map files you control
+// • Not for pipes, sockets or stdin; small files are often faster streamed
+//
+// Both functions count lines the way BufRead::lines() would: a final line
+// without a trailing newline still counts.
+
+fn lines_in(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|&&byte| byte == b'\n').count()
+}
+
+pub fn count_lines_streaming(path: &Path) -> io::Result<usize> {
+    let mut reader = BufReader::with_capacity(64 * 1024, File::open(path)?);
+    let (mut lines, mut last) = (0, b'\n');
+    loop {
+        let chunk = reader.fill_buf()?;
+        let Some(&end) = chunk.last() else { break };
+        lines += lines_in(chunk);
+        last = end;
+        let len = chunk.len();
+        reader.consume(len);
+    }
+    Ok(lines + usize::from(last != b'\n'))
+}
+
+pub fn count_lines_mmap(path: &Path) -> io::Result<usize> {
+    let file = File::open(path)?;
+    // SAFETY: the demo and tests only map temp files nothing else writes to
+    let map = unsafe { Mmap::map(&file)? };
+    Ok(lines_in(&map) + usize::from(map.last().is_some_and(|&end| end != b'\n')))
+}
+
+// ===== 6. PARSING A LOG FILE =====
+//
+// Line format: "<RFC 3339 timestamp> <LEVEL> <message>", e.g.
+//   2024-05-01T12:00:00Z INFO server started
+//
+// TWO KINDS OF FAILURE, HANDLED DIFFERENTLY:
+// • IO errors (file missing, disk error) stop everything: `?` turns them
+//   into TaskError and returns
+// • A malformed line is one bad line in an otherwise useful file: it's
+//   recorded and parsing goes on
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub timestamp: chrono::DateTime<chrono::FixedOffset>,
+    pub level: Level,
+    pub message: String,
+}
+
+pub fn parse_log_line(line: &str, line_no: usize) -> Result<LogEntry, TaskError> {
+    let invalid = |message: String| TaskError::ValidationError { message: format!("line {}: {}", line_no, message) };
+    let mut parts = line.trim().splitn(3, ' ');
+    let (Some(timestamp), Some(level), Some(message)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(invalid("expected '<timestamp> <LEVEL> <message>'".to_string()));
+    };
+    Ok(LogEntry {
+        timestamp: chrono::DateTime::parse_from_rfc3339(timestamp)
+            .map_err(|e| invalid(format!("bad timestamp '{}': {}", timestamp, e)))?,
+        level: level.parse().map_err(|e| invalid(format!("{}", e)))?,
+        message: message.trim().to_string(),
+    })
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct LogSummary {
+    pub counts: BTreeMap<Level, usize>,
+    pub errors: Vec<String>,
+    // One message per malformed line
+    pub rejected: Vec<String>,
+}
+
+// Blank lines and '#' comments are skipped
+pub fn summarize_log<R: BufRead>(reader: R) -> Result<LogSummary, TaskError> {
+    let mut summary = LogSummary::default();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match parse_log_line(&line, index + 1) {
+            Ok(entry) => {
+                *summary.counts.entry(entry.level).or_default() += 1;
+                if entry.level == Level::Error {
+                    summary.errors.push(entry.message);
+                }
+            }
+            Err(e) => summary.rejected.push(e.to_string()),
+        }
+    }
+    Ok(summary)
+}
+
+pub fn summarize_log_file(path: &Path) -> Result<LogSummary, TaskError> {
+    let file = File::open(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => TaskError::FileReadError { path: path.display().to_string() },
+        _ => TaskError::IoError(e),
+    })?;
+    summarize_log(BufReader::new(file))
+}
+
+// ===== 7. DEMONSTRATION FUNCTION =====
+
+const SAMPLE_LOG: &str = "\
+# deploy of 2024-05-01
+2024-05-01T12:00:00Z INFO server started
+2024-05-01T12:00:01Z DEBUG listening on :8080
+2024-05-01T12:00:05Z WARN slow request: 1200ms
+2024-05-01T12:00:07Z ERROR database connection lost
+2024-05-01T12:00:07Z LOUD reconnecting
+yesterday INFO cache warmed
+2024-05-01T12:00:09Z ERROR retry budget exhausted
+";
+
+// IO errors stop the demonstration; what ran so far stays in the report
+// and the error is added as a last section
+pub fn file_io_report() -> Report {
+    let mut report = Report::new("🦀 RUST FILE IO DEEP STUDY 🦀");
+    if let Err(e) = add_file_io_sections(&mut report) {
+        report.section("❌ FILE IO DEMO FAILED:").text(e.to_string());
+    }
+    report
+}
+
+fn add_file_io_sections(report: &mut Report) -> io::Result<()> {
+    // ===== READ AND WRITE =====
+    report
+        .section("1️⃣ Read AND Write:")
+        .example("checksum(&[u8])", format!("{:?}", checksum(&b"hello"[..])?))
+        .example("checksum(Cursor)", format!("{:?}", checksum(Cursor::new(vec![1u8; 10_000]))?));
+
+    // ===== BUFFERING =====
+    let buffering = report.section("2️⃣ BUFFERED WRITERS AND READERS:");
+    let rows: Vec<(&str, u32)> = (0..100).map(|n| ("player", n)).collect();
+    let unbuffered = write_report(CountingWriter::new(Vec::new()), &rows)?;
+    buffering.example("100 lines unbuffered", format!("{} writes, {} bytes", unbuffered.writes, unbuffered.bytes));
+    let buffered = write_report(BufWriter::new(CountingWriter::new(Vec::new())), &rows)?;
+    let buffered = buffered.into_inner().map_err(|e| e.into_error())?;
+    buffering
+        .example("100 lines through BufWriter", format!("{} writes, {} bytes", buffered.writes, buffered.bytes))
+        .example("longest_line", format!("{:?}", longest_line(Cursor::new("short\na much longer line\nmid"))?));
+
+    // ===== SEEK =====
+    let seek = report.section("3️⃣ Seek AND FIXED-SIZE RECORDS:");
+    let mut scores = tempfile::tempfile()?;
+    for (name, score) in [("ferris", 42), ("corro", 7), ("bartholomew-the-long", 99)] {
+        write_record(&mut scores, name, score)?;
+    }
+    seek.text(format!("{} records in an unnamed temp file", record_count(&mut scores)?))
+        .example("read_record(2)", format!("{:?}", read_record(&mut scores, 2)?))
+        .example("read_record(0)", format!("{:?}", read_record(&mut scores, 0)?))
+        .example("read_record(5)", format!("{:?}", read_record(&mut scores, 5).map_err(|e| e.kind())));
+
+    // ===== TEMP FILES =====
+    let dir = tempfile::tempdir()?;
+    let config = dir.path().join("app.toml");
+    write_atomically(&config, b"port = 8080\n")?;
+    write_atomically(&config, b"port = 9090\n")?;
+    report
+        .section("4️⃣ TEMPORARY FILES AND ATOMIC WRITES:")
+        .example("app.toml after two atomic writes", format!("{:?}", std::fs::read_to_string(&config)?))
+        .example("files left in the directory", std::fs::read_dir(dir.path())?.count());
+
+    // ===== MMAP VS STREAMING =====
+    let mut big = NamedTempFile::new_in(dir.path())?;
+    {
+        let mut writer = BufWriter::new(big.as_file_mut());
+        for n in 0..200_000 {
+            writeln!(writer, "line {}", n)?;
+        }
+        writer.flush()?;
+    }
+    let start = std::time::Instant::now();
+    let streamed = count_lines_streaming(big.path())?;
+    let streamed_in = start.elapsed();
+    let start = std::time::Instant::now();
+    let mapped = count_lines_mmap(big.path())?;
+    report
+        .section("5️⃣ MEMORY-MAPPED VS STREAMING READS:")
+        .example("streaming", format!("{} lines in {:?}", streamed, streamed_in))
+        .example("mmap", format!("{} lines in {:?}", mapped, start.elapsed()));
+
+    // ===== LOG PARSER =====
+    let parsing = report.section("6️⃣ PARSING A LOG FILE:");
+    let log = dir.path().join("deploy.log");
+    std::fs::write(&log, SAMPLE_LOG)?;
+    match summarize_log_file(&log) {
+        Ok(summary) => {
+            parsing
+                .example("Counts", format!("{:?}", summary.counts))
+                .example("Errors", format!("{:?}", summary.errors));
+            for rejected in &summary.rejected {
+                parsing.example("Rejected", rejected);
+            }
+        }
+        Err(e) => {
+            parsing.example("Failed", e);
+        }
+    }
+    if let Err(e) = summarize_log_file(&dir.path().join("missing.log")) {
+        parsing.example("Missing file", e);
+    }
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 FILE IO SUMMARY:")
+        .check("Read/Write/Seek let one function serve files, buffers and sockets")
+        .check("BufWriter turns many small writes into a few big ones")
+        .check("Seek + fixed-size records give random access")
+        .check("tempfile cleans up after itself; rename makes writes atomic")
+        .check("mmap trades copying for unsafe assumptions about the file")
+        .check("IO errors stop with ?, malformed lines are collected");
+
+    Ok(())
+}
+
+pub fn demonstrate_file_io() {
+    print!("{}", file_io_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct FileIoModule;
+
+impl LearningModule for FileIoModule {
+    fn number(&self) -> u8 {
+        14
+    }
+
+    fn name(&self) -> &'static str {
+        "file_io"
+    }
+
+    fn title(&self) -> &'static str {
+        "FILE IO DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "File IO & std::io"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["traits", "errors"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_file_io() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { file_io_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&FileIoModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// FILE IO BEST PRACTICES:
+// 1. Accept `impl Read` / `impl BufRead` / `impl Write`, not File - easier to
+//    test and to reuse
+// 2. Wrap files in BufReader/BufWriter unless you read or write big chunks
+// 3. Flush (or into_inner) a BufWriter explicitly; Drop swallows the error
+// 4. Use read_exact/write_all when you need every byte - read/write may be short
+// 5. Temp file + rename for files that must never be half written
+// 6. Stream by default; reach for mmap for large files you own and read randomly
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_runs_every_section_without_failing() {
+        let report = file_io_report();
+        assert!(report.find("FAILED").is_none());
+
+        let seek = report.find("Seek AND FIXED-SIZE RECORDS").unwrap();
+        assert_eq!(seek.output("read_record(0)"), Some(r#"("ferris", 42)"#));
+        assert_eq!(seek.output("read_record(5)"), Some("Err(UnexpectedEof)"));
+
+        let temp = report.find("ATOMIC WRITES").unwrap();
+        assert_eq!(temp.output("app.toml after two atomic writes"), Some(r#""port = 9090\n""#));
+        assert_eq!(temp.output("files left in the directory"), Some("1"));
+
+        let mmap: Vec<_> = report.find("MEMORY-MAPPED").unwrap().outputs().collect();
+        assert!(mmap.iter().all(|output| output.starts_with("200000 lines")));
+    }
+
+    // A reader that fails once with Interrupted before every real read
+    struct Interrupting<R> {
+        inner: R,
+        interrupt: bool,
+    }
+
+    impl<R: Read> Read for Interrupting<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.interrupt = !self.interrupt;
+            if self.interrupt {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            self.inner.read(buf)
+        }
+    }
+
+    #[test]
+    fn checksum_retries_interrupted_reads() {
+        let data = vec![3u8; 10_000];
+        assert_eq!(checksum(&data[..]).unwrap(), (10_000, 30_000));
+        assert_eq!(checksum(Interrupting { inner: &data[..], interrupt: false }).unwrap(), (10_000, 30_000));
+    }
+
+    #[test]
+    fn buffering_batches_small_writes() {
+        let rows = [("a", 1), ("b", 2), ("c", 3)];
+        let unbuffered = write_report(CountingWriter::new(Vec::new()), &rows).unwrap();
+        assert!(unbuffered.writes >= rows.len());
+
+        let buffered = write_report(BufWriter::new(CountingWriter::new(Vec::new())), &rows).unwrap();
+        let buffered = buffered.into_inner().unwrap();
+        assert_eq!(buffered.writes, 1);
+        assert_eq!(buffered.into_inner(), unbuffered.into_inner());
+
+        assert_eq!(longest_line(Cursor::new("ab\r\nabc\nz")).unwrap().as_deref(), Some("abc"));
+        assert_eq!(longest_line(Cursor::new("")).unwrap(), None);
+    }
+
+    #[test]
+    fn records_are_found_by_seeking() {
+        let mut records = Cursor::new(Vec::new());
+        write_record(&mut records, "ferris", 42).unwrap();
+        write_record(&mut records, "a-name-longer-than-twelve", 7).unwrap();
+        assert_eq!(record_count(&mut records).unwrap(), 2);
+        assert_eq!(read_record(&mut records, 1).unwrap(), ("a-name-longe".to_string(), 7));
+        assert_eq!(read_record(&mut records, 0).unwrap(), ("ferris".to_string(), 42));
+        assert_eq!(read_record(&mut records, 2).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn mmap_and_streaming_agree() {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, contents, lines) in [("empty", "", 0), ("one", "x", 1), ("two", "x\ny\n", 2), ("blank", "\n\n", 2)] {
+            let path = dir.path().join(name);
+            write_atomically(&path, contents.as_bytes()).unwrap();
+            assert_eq!(count_lines_streaming(&path).unwrap(), lines, "{}", name);
+            assert_eq!(count_lines_mmap(&path).unwrap(), lines, "{}", name);
+        }
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 4, "no temp files left behind");
+    }
+
+    #[test]
+    fn log_summary_keeps_going_past_bad_lines() {
+        let summary = summarize_log(Cursor::new(SAMPLE_LOG)).unwrap();
+        assert_eq!(summary.counts.values().sum::<usize>(), 5);
+        assert_eq!(summary.counts[&Level::Error], 2);
+        assert_eq!(summary.errors, ["database connection lost", "retry budget exhausted"]);
+        assert_eq!(summary.rejected.len(), 2);
+        assert!(summary.rejected[0].starts_with("Invalid input: line 6: unknown log level 'LOUD'"));
+        assert!(summary.rejected[1].starts_with("Invalid input: line 7: bad timestamp 'yesterday'"));
+
+        let missing = summarize_log_file(Path::new("/definitely/not/here.log")).unwrap_err();
+        assert!(matches!(missing, TaskError::FileReadError { .. }));
+    }
+}
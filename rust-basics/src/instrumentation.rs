@@ -0,0 +1,541 @@
+// ===== TRACING & INSTRUMENTATION =====
+//
+// macros.rs built a logger out of macro_rules!, now the mini-logger crate:
+// every info!(..) formats one line and prints it. The `tracing` crate
+// splits that job in two:
+//
+//   your code ──info!(order_id = 7, "paid")──▶ Subscriber ──▶ fmt layer  → stdout
+//   (events, spans,                            (whoever is  ──▶ counter    → metrics
+//    structured fields)                          installed)  ──▶ bridge     → mini-logger
+//
+// • Instrumented code only describes WHAT happened: events with typed
+//   key-value fields, inside SPANS that give them context ("while handling
+//   checkout{order_id=7}")
+// • A Subscriber decides what to do with it. tracing-subscriber builds one
+//   from LAYERS - formatting, filtering, metrics - stacked on a Registry
+//   that keeps track of the spans
+// • With no subscriber installed the macros cost almost nothing: each call
+//   site caches whether anyone is interested in it
+
+use std::fmt;
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use thiserror::Error;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber, debug, error, info, info_span, instrument, warn};
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+use crate::report::Report;
+
+// Collects formatted output so it can be shown in the report and checked in
+// tests. The fmt layer asks its MakeWriter for a writer per event
+#[derive(Debug, Clone, Default)]
+pub struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl LogBuffer {
+    pub fn lines(&self) -> Vec<String> {
+        let bytes = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        String::from_utf8_lossy(&bytes).lines().map(|line| line.trim().to_string()).collect()
+    }
+}
+
+impl io::Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for LogBuffer {
+    type Writer = LogBuffer;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+// A fmt layer without timestamps or colors, so its output is stable
+fn plain_fmt_layer<S>(buffer: &LogBuffer) -> impl Layer<S> + use<S>
+where
+    S: Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
+{
+    tracing_subscriber::fmt::layer().with_writer(buffer.clone()).with_ansi(false).without_time().with_target(false)
+}
+
+// Runs `f` with a formatting subscriber installed on this thread only, and
+// returns what it printed
+pub fn capture_formatted(f: impl FnOnce()) -> Vec<String> {
+    let buffer = LogBuffer::default();
+    let subscriber = tracing_subscriber::registry().with(plain_fmt_layer(&buffer).with_filter(LevelFilter::TRACE));
+    tracing::subscriber::with_default(subscriber, f);
+    buffer.lines()
+}
+
+// ===== 1. EVENTS AND SPANS =====
+//
+// UNDERSTANDING EVENTS:
+// • info!(user = 42, "logged in") records an event with a level, a message
+//   and typed fields - subscribers get the values, not a pre-built string
+// • `%value` records with Display, `?value` with Debug
+//
+// UNDERSTANDING SPANS:
+// • A span is a period of time with fields: a request, a job, a query.
+//   Events recorded while it is ENTERED are "inside" it
+// • span.in_scope(|| ..) or let _guard = span.enter() enters it; spans nest
+// • Fields can be declared Empty and filled in later with span.record()
+// • In async code never hold an enter() guard across .await - the task may
+//   be suspended and another task's events would land in your span. Use
+//   future.instrument(span) instead, which enters on every poll
+
+// Imports rows inside a span, recording the row count once it is known
+pub fn import_rows(file: &str, rows: &[&str]) -> usize {
+    let span = info_span!("import", file, rows = tracing::field::Empty);
+    let _entered = span.enter();
+
+    let mut imported = 0;
+    for (line, row) in rows.iter().enumerate() {
+        if row.is_empty() {
+            warn!(line, "skipping empty row");
+            continue;
+        }
+        debug!(line, row, "parsed");
+        imported += 1;
+    }
+    span.record("rows", imported);
+    info!("import finished");
+    imported
+}
+
+// ===== 2. #[instrument] =====
+//
+// UNDERSTANDING #[instrument]:
+// • Wraps the function body in a span named after the function, with every
+//   argument recorded as a field (using Debug)
+// • skip(..) leaves out arguments that are secret, huge or not Debug;
+//   fields(..) adds computed ones
+// • err records the returned Err as an ERROR event; ret records Ok values
+// • level = "debug" makes the span itself less important, so filters can
+//   hide it
+// • On an async fn it instruments the future, so it is safe across .await
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CheckoutError {
+    #[error("cart is empty")]
+    EmptyCart,
+    #[error("item {0} is out of stock")]
+    OutOfStock(u32),
+}
+
+pub const OUT_OF_STOCK: u32 = 404;
+
+#[instrument(level = "debug", skip(cart))]
+fn reserve_stock(cart: &[u32]) -> Result<(), CheckoutError> {
+    if let Some(&item) = cart.iter().find(|&&item| item == OUT_OF_STOCK) {
+        return Err(CheckoutError::OutOfStock(item));
+    }
+    debug!(items = cart.len(), "reserved");
+    Ok(())
+}
+
+// The card number never reaches the logs; the cart shows up as a count
+#[instrument(skip(cart, card_number), fields(items = cart.len()), err)]
+pub fn checkout(order_id: u32, cart: &[u32], card_number: &str) -> Result<u32, CheckoutError> {
+    if cart.is_empty() {
+        return Err(CheckoutError::EmptyCart);
+    }
+    reserve_stock(cart)?;
+    let total: u32 = cart.iter().sum();
+    info!(total, card = %mask(card_number), "charged");
+    Ok(total)
+}
+
+fn mask(card_number: &str) -> String {
+    let visible = card_number.len().saturating_sub(4);
+    format!("****{}", &card_number[visible..])
+}
+
+// ===== 3. LAYERED SUBSCRIBERS =====
+//
+// UNDERSTANDING LAYERS:
+// • registry().with(a).with(b) stacks layers; each one sees every span and
+//   event that passes its filter
+// • A filter attached with .with_filter(..) applies to that layer ONLY: the
+//   console can show warnings while metrics still count everything
+// • LevelFilter::WARN filters by level; Targets filters per module,
+//   like mini-logger's "info,db=debug"
+// • with_default(subscriber, || ..) installs one for the current thread -
+//   ideal for tests. Applications call .init() once in main instead
+
+pub struct LayeredRun {
+    pub console: Vec<String>,
+    pub counts: LevelCounts,
+}
+
+// Console shows WARN and above, plus DEBUG for the "payments" target; the
+// counter layer sees every event
+pub fn run_layered(f: impl FnOnce()) -> LayeredRun {
+    let console = LogBuffer::default();
+    let counter = LevelCounter::default();
+    let console_filter = Targets::new()
+        .with_target("payments", Level::DEBUG)
+        .with_default(Level::WARN);
+    let subscriber = tracing_subscriber::registry()
+        .with(plain_fmt_layer(&console).with_filter(console_filter))
+        .with(counter.clone());
+    tracing::subscriber::with_default(subscriber, f);
+    LayeredRun { console: console.lines(), counts: counter.counts() }
+}
+
+fn noisy_workload() {
+    import_rows("users.csv", &["ana", "", "bo"]);
+    let _ = checkout(7, &[30, OUT_OF_STOCK], "4242424242424242");
+    debug!(target: "payments", attempt = 2, "retrying charge");
+    error!(target: "payments", "payment provider unreachable");
+}
+
+// ===== 4. A CUSTOM Layer =====
+//
+// UNDERSTANDING Layer:
+// • Layer<S> has hooks for everything a subscriber sees: on_new_span,
+//   on_enter, on_exit, on_close, on_record and on_event. Implement only
+//   the ones you need - the rest default to doing nothing
+// • event.metadata() has the level, target, module, file and line;
+//   the FIELD VALUES are read with a Visitor (section 5)
+// • Layers take &self and may be called from many threads at once:
+//   keep state in atomics or behind a Mutex
+// • ctx.event_span(event) / ctx.lookup_current() reach the span data the
+//   Registry stores, e.g. to attach a request id to every event
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LevelCounts {
+    pub trace: usize,
+    pub debug: usize,
+    pub info: usize,
+    pub warn: usize,
+    pub error: usize,
+}
+
+impl LevelCounts {
+    pub fn total(&self) -> usize {
+        self.trace + self.debug + self.info + self.warn + self.error
+    }
+}
+
+impl fmt::Display for LevelCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "TRACE={} DEBUG={} INFO={} WARN={} ERROR={}",
+            self.trace, self.debug, self.info, self.warn, self.error
+        )
+    }
+}
+
+// Counts events per level; clones share the same counters
+#[derive(Debug, Clone, Default)]
+pub struct LevelCounter {
+    // Indexed like mini_logger::Level::ALL: TRACE first, ERROR last
+    counts: Arc<[AtomicUsize; 5]>,
+}
+
+impl LevelCounter {
+    pub fn counts(&self) -> LevelCounts {
+        let [trace, debug, info, warn, error] = self.counts.each_ref().map(|count| count.load(Ordering::Relaxed));
+        LevelCounts { trace, debug, info, warn, error }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LevelCounter {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let index = to_mini_logger_level(event.metadata().level()) as usize;
+        self.counts[index].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+// ===== 5. tracing VS THE HAND-ROLLED MACROS =====
+//
+// mini-logger                          tracing
+// ─────────────────────────────────    ──────────────────────────────────────
+// info!("paid"; id = 7)                info!(id = 7, "paid")
+// fields become Strings at the call    fields stay typed until a Visitor reads them
+// one sink: stdout (+ a file)          any number of layers, chosen by the app
+// Filter "info,db=debug", global       filters per layer, or per subscriber
+// flat records                         spans give every event its context
+// one small crate, easy to read        the ecosystem: OpenTelemetry, tokio-console, ...
+//
+// The two can meet: MiniLoggerLayer below turns tracing events into
+// mini-logger Records, so code instrumented with tracing can still be
+// printed by - or captured in tests with - the hand-rolled logger.
+
+pub fn to_mini_logger_level(level: &Level) -> mini_logger::Level {
+    match *level {
+        Level::TRACE => mini_logger::Level::Trace,
+        Level::DEBUG => mini_logger::Level::Debug,
+        Level::INFO => mini_logger::Level::Info,
+        Level::WARN => mini_logger::Level::Warn,
+        Level::ERROR => mini_logger::Level::Error,
+    }
+}
+
+// Reads an event's fields. The message is just a field named "message"
+#[derive(Debug, Default)]
+struct FieldCollector {
+    message: String,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Visit for FieldCollector {
+    // Without this, strings would be recorded with their Debug quotes
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.fields.push((field.name(), value.to_string()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.fields.push((field.name(), format!("{:?}", value)));
+        }
+    }
+}
+
+// Forwards every event to mini_logger::emit
+pub struct MiniLoggerLayer;
+
+impl<S: Subscriber> Layer<S> for MiniLoggerLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut collector = FieldCollector::default();
+        event.record(&mut collector);
+
+        let location = mini_logger::Location {
+            // tracing doesn't know the package name; the crate part of the
+            // target is the closest thing
+            app: metadata.target().split("::").next().unwrap_or_default(),
+            module_path: metadata.module_path().unwrap_or_else(|| metadata.target()),
+            file: metadata.file().unwrap_or("<unknown>"),
+            line: metadata.line().unwrap_or_default(),
+        };
+        mini_logger::emit(mini_logger::Record::new(
+            to_mini_logger_level(metadata.level()),
+            metadata.target(),
+            collector.message,
+            None,
+            collector.fields,
+            location,
+        ));
+    }
+}
+
+// The same event written with both APIs, as mini-logger Records
+pub fn log_both_ways() -> (mini_logger::Record, mini_logger::Record) {
+    let mut by_hand = mini_logger::capture(|| mini_logger::info!("order placed"; id = 42, total = 9.99));
+    let mut bridged = mini_logger::capture(|| {
+        let subscriber = tracing_subscriber::registry().with(MiniLoggerLayer);
+        tracing::subscriber::with_default(subscriber, || info!(id = 42, total = 9.99, "order placed"));
+    });
+    (by_hand.remove(0), bridged.remove(0))
+}
+
+// ===== 6. DEMONSTRATION FUNCTION =====
+
+pub fn instrumentation_report() -> Report {
+    let mut report = Report::new("🦀 TRACING & INSTRUMENTATION 🦀");
+
+    let mut imported = 0;
+    let lines = capture_formatted(|| imported = import_rows("users.csv", &["ana", "", "bo"]));
+    let section = report.section("1️⃣ EVENTS AND SPANS:");
+    section.text(format!("import_rows(\"users.csv\", [\"ana\", \"\", \"bo\"]) → {} rows, printing:", imported));
+    for line in lines {
+        section.bullet(line);
+    }
+
+    let section = report.section("2️⃣ #[instrument]:");
+    let mut paid = Ok(0);
+    let lines = capture_formatted(|| paid = checkout(7, &[30, 12], "4242424242424242"));
+    section.text(format!("checkout(7, &[30, 12], card) → {:?}, printing:", paid));
+    for line in lines {
+        section.bullet(line);
+    }
+    let lines = capture_formatted(|| paid = checkout(8, &[30, OUT_OF_STOCK], "4242424242424242"));
+    section.text(format!("checkout(8, &[30, {}], card) → {:?}, printing:", OUT_OF_STOCK, paid));
+    for line in lines {
+        section.bullet(line);
+    }
+    section.text("The card number appears nowhere: skip(card_number) kept it out of the span");
+
+    let run = run_layered(noisy_workload);
+    let section = report.section("3️⃣ LAYERED SUBSCRIBERS:");
+    section.text("Console layer: WARN and above, DEBUG for target \"payments\". It printed:");
+    for line in &run.console {
+        section.bullet(line.clone());
+    }
+    section
+        .text("No span prefixes: import{..} and checkout{..} are INFO spans, so this layer's filter drops them too")
+        .example("console lines", run.console.len())
+        .example("events the counter layer saw", run.counts.total());
+
+    report
+        .section("4️⃣ A CUSTOM Layer:")
+        .example("LevelCounter, same workload", run.counts)
+        .text("One on_event hook, one atomic increment per event - the start of a metrics exporter");
+
+    let (by_hand, bridged) = log_both_ways();
+    let describe = |record: &mini_logger::Record| format!("{} {:?} {:?}", record.level.as_str(), record.message, record.fields);
+    report
+        .section("5️⃣ tracing VS mini-logger:")
+        .example("mini_logger::info!(\"order placed\"; id = 42, total = 9.99)", describe(&by_hand))
+        .example("tracing::info!(id = 42, total = 9.99, \"order placed\") via MiniLoggerLayer", describe(&bridged))
+        .bullet("mini-logger: one crate, formats at the call site, one global sink")
+        .bullet("tracing: typed fields, spans for context, layers picked by the application")
+        .bullet("Libraries emit tracing events; the binary decides where they go");
+
+    report
+        .section("🎯 TRACING SUMMARY:")
+        .check("Events carry typed fields; spans give them context")
+        .check("#[instrument] turns a function into a span - skip secrets, add fields")
+        .check("Use .instrument(span) for futures, never an enter() guard across .await")
+        .check("Subscribers are layers on a Registry, each with its own filter")
+        .check("A custom Layer is a few hooks: on_event, on_new_span, on_close")
+        .check("with_default scopes a subscriber to one thread - made for tests");
+
+    report
+}
+
+pub fn demonstrate_instrumentation() {
+    print!("{}", instrumentation_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct InstrumentationModule;
+
+impl LearningModule for InstrumentationModule {
+    fn number(&self) -> u8 {
+        27
+    }
+
+    fn name(&self) -> &'static str {
+        "instrumentation"
+    }
+
+    fn title(&self) -> &'static str {
+        "TRACING & INSTRUMENTATION"
+    }
+
+    fn description(&self) -> &'static str {
+        "Spans, #[instrument], Layers, Subscribers"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["macros", "traits"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_instrumentation() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { instrumentation_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&InstrumentationModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// TRACING BEST PRACTICES:
+// 1. Record values as fields (id = 7), not inside the message string
+// 2. Put a span around each unit of work: request, job, message
+// 3. skip(..) secrets and large arguments in #[instrument]
+// 4. Libraries depend on tracing only; the application picks the subscriber
+// 5. Filter per layer so each output gets the detail it needs
+// 6. Test instrumentation with with_default and a capturing writer
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans_prefix_their_events() {
+        let lines = capture_formatted(|| {
+            import_rows("a.csv", &["x", ""]);
+        });
+        assert_eq!(lines.len(), 3, "{:?}", lines);
+        assert!(lines[0].starts_with("DEBUG import{file=\"a.csv\"}: parsed line=0 row=\"x\""), "{}", lines[0]);
+        assert!(lines[1].starts_with("WARN import{file=\"a.csv\"}: skipping empty row line=1"), "{}", lines[1]);
+        // rows was recorded after the span was created
+        assert!(lines[2].contains("rows=1"), "{}", lines[2]);
+    }
+
+    #[test]
+    fn instrument_skips_secrets_and_records_errors() {
+        let card = "4242424242424242";
+        let lines = capture_formatted(|| {
+            assert_eq!(checkout(1, &[5, 6], card), Ok(11));
+            assert_eq!(checkout(2, &[OUT_OF_STOCK], card), Err(CheckoutError::OutOfStock(OUT_OF_STOCK)));
+            assert_eq!(checkout(3, &[], card), Err(CheckoutError::EmptyCart));
+        });
+        assert!(lines.iter().all(|line| !line.contains(card)), "{:?}", lines);
+        assert!(lines.iter().any(|line| line.contains("checkout{order_id=1 items=2}") && line.contains("card=****4242")));
+        assert!(lines.iter().any(|line| line.starts_with("ERROR checkout{order_id=3 items=0}: error=cart is empty")));
+        assert!(lines.iter().any(|line| line.contains("reserve_stock")));
+    }
+
+    #[test]
+    fn per_layer_filters_are_independent() {
+        let run = run_layered(noisy_workload);
+        assert_eq!(
+            run.counts,
+            LevelCounts { trace: 0, debug: 3, info: 1, warn: 1, error: 2 },
+            "the counter sees every event"
+        );
+        // import's WARN, checkout's error, and both "payments" events
+        assert_eq!(run.console.len(), 4, "{:?}", run.console);
+        assert!(run.console[0].starts_with("WARN"));
+        assert!(run.console[2].starts_with("DEBUG") && run.console[2].contains("retrying charge"));
+    }
+
+    #[test]
+    fn counter_clones_share_counts() {
+        let counter = LevelCounter::default();
+        let subscriber = tracing_subscriber::registry().with(counter.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            info!("one");
+            info!("two");
+            error!("three");
+        });
+        assert_eq!(counter.counts(), LevelCounts { info: 2, error: 1, ..LevelCounts::default() });
+        assert_eq!(counter.counts().to_string(), "TRACE=0 DEBUG=0 INFO=2 WARN=0 ERROR=1");
+    }
+
+    #[test]
+    fn bridge_produces_the_same_record_as_the_macros() {
+        let (by_hand, bridged) = log_both_ways();
+        assert_eq!(bridged.level, by_hand.level);
+        assert_eq!(bridged.message, by_hand.message);
+        assert_eq!(bridged.fields, by_hand.fields);
+        assert_eq!(bridged.target, "rust_basics::instrumentation");
+        assert_eq!(bridged.location.app, "rust_basics");
+    }
+
+    #[test]
+    fn no_subscriber_means_no_output() {
+        // Nothing installed on this thread: the events go nowhere, and the
+        // function still works
+        assert_eq!(import_rows("quiet.csv", &["a", "b"]), 2);
+        assert_eq!(checkout(9, &[1], "0000"), Ok(1));
+    }
+}
@@ -0,0 +1,516 @@
+// ===== ITERATORS DEEP STUDY =====
+//
+// AN ITERATOR IS ONE METHOD:
+//     fn next(&mut self) -> Option<Self::Item>;
+// Everything else on Iterator - map, filter, zip, sum and ~70 more - is a
+// provided method written in terms of next(). Implement next() and you get
+// all of them.
+//
+// ADAPTORS ARE STRUCTS:
+// `iter.map(f)` doesn't do anything yet: it returns Map { iter, f }, whose
+// next() calls the inner next() and applies f. Nothing runs until someone
+// pulls (a for loop, collect, sum). This module builds three adaptors of its
+// own the same way and attaches them to every iterator with an extension
+// trait.
+//
+//   source ──next()──▶ Chunked ──next()──▶ DedupBy ──next()──▶ for loop
+//          ◀── pull ──         ◀── pull ──         ◀── pull ──
+
+use crate::report::Report;
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+
+// ===== 1. IMPLEMENTING Iterator =====
+//
+// UNDERSTANDING THE CONTRACT:
+// • next() returns Some(item) until the sequence is over, then None
+// • After the first None, Iterator makes NO promise: calling next() again
+//   may return more items (a channel, a retrying reader) or panic
+// • FusedIterator is the promise "None forever after the first None" -
+//   .fuse() adds that promise to any iterator
+// • size_hint() returns (lower, Option<upper>) bounds; collect() uses it to
+//   allocate once. The default (0, None) is always correct, just unhelpful
+
+// The Collatz sequence: halve even numbers, 3n+1 odd ones, stop at 1.
+// Nobody knows a formula for its length, so size_hint stays the default
+#[derive(Debug, Clone)]
+pub struct Collatz {
+    next: Option<u64>,
+}
+
+impl Collatz {
+    pub fn new(start: u64) -> Self {
+        Collatz { next: (start > 0).then_some(start) }
+    }
+}
+
+impl Iterator for Collatz {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let current = self.next?;
+        self.next = match current {
+            1 => None,
+            n if n % 2 == 0 => Some(n / 2),
+            n => Some(3 * n + 1),
+        };
+        Some(current)
+    }
+}
+
+// Once `next` is None it stays None
+impl FusedIterator for Collatz {}
+
+// A countdown knows exactly how many items are left, so it can also be
+// ExactSizeIterator (len()) and DoubleEndedIterator (rev())
+#[derive(Debug, Clone)]
+pub struct Countdown {
+    low: u32,
+    high: u32,
+}
+
+impl Countdown {
+    // from, from - 1, ..., 1
+    pub fn new(from: u32) -> Self {
+        Countdown { low: 1, high: from + 1 }
+    }
+}
+
+impl Iterator for Countdown {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        (self.low < self.high).then(|| {
+            self.high -= 1;
+            self.high
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.high - self.low) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Countdown {
+    fn next_back(&mut self) -> Option<u32> {
+        (self.low < self.high).then(|| {
+            self.low += 1;
+            self.low - 1
+        })
+    }
+}
+
+impl ExactSizeIterator for Countdown {}
+impl FusedIterator for Countdown {}
+
+// A deliberately NOT fused iterator: replays a script of Some/None, like a
+// try_recv() loop that sometimes finds the channel empty
+#[derive(Debug, Clone)]
+pub struct Scripted<T> {
+    script: VecDeque<Option<T>>,
+}
+
+impl<T> Scripted<T> {
+    pub fn new(script: impl IntoIterator<Item = Option<T>>) -> Self {
+        Scripted { script: script.into_iter().collect() }
+    }
+}
+
+impl<T> Iterator for Scripted<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.script.pop_front().flatten()
+    }
+}
+
+// ===== 2. ADAPTORS AS AN EXTENSION TRAIT =====
+//
+// UNDERSTANDING EXTENSION TRAITS:
+// • You can't add methods to std's Iterator, but you can define a trait
+//   with a blanket impl for every Iterator - itertools does exactly this
+// • `Self: Sized` because each method takes self by value
+// • The trait must be in scope (`use ...::IteratorExt`) for the methods to
+//   resolve, just like Read/Write
+//
+// UNDERSTANDING ADAPTOR FUSING:
+// Each adaptor below stops calling its inner iterator after the first None
+// and returns None from then on - so they're FusedIterator even over an
+// unfused source. std's adaptors mostly don't promise this; wrapping the
+// source in .fuse() is the usual fix.
+
+pub trait IteratorExt: Iterator + Sized {
+    // Non-overlapping groups of `size`; the last may be shorter.
+    // Panics if size is 0, like slice::chunks
+    fn chunked(self, size: usize) -> Chunked<Self> {
+        assert!(size > 0, "chunk size must be non-zero");
+        Chunked { iter: self, size, done: false }
+    }
+
+    // Overlapping windows of `size`, each shifted by one item. Items are
+    // cloned into every window they appear in. Panics if size is 0
+    fn windowed(self, size: usize) -> Windowed<Self>
+    where
+        Self::Item: Clone,
+    {
+        assert!(size > 0, "window size must be non-zero");
+        Windowed { iter: self, size, window: VecDeque::with_capacity(size), done: false }
+    }
+
+    // Drops items that `same` considers equal to the item before them
+    fn dedup_by<F>(self, same: F) -> DedupBy<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        DedupBy { iter: self, same, pending: None, done: false }
+    }
+
+    // dedup_by with ==
+    fn dedup(self) -> Dedup<Self>
+    where
+        Self::Item: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+// A closure's type can't be written down, so dedup() passes a fn pointer
+pub type Dedup<I> = DedupBy<I, fn(&<I as Iterator>::Item, &<I as Iterator>::Item) -> bool>;
+
+// ===== 3. THE ADAPTORS =====
+
+#[derive(Debug, Clone)]
+pub struct Chunked<I> {
+    iter: I,
+    size: usize,
+    done: bool,
+}
+
+impl<I: Iterator> Iterator for Chunked<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.done {
+            return None;
+        }
+        let mut chunk = Vec::with_capacity(self.size);
+        while chunk.len() < self.size {
+            match self.iter.next() {
+                Some(item) => chunk.push(item),
+                None => {
+                    self.done = true;
+                    break;
+                }
+            }
+        }
+        (!chunk.is_empty()).then_some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (low, high) = self.iter.size_hint();
+        (low.div_ceil(self.size), high.map(|high| high.div_ceil(self.size)))
+    }
+}
+
+impl<I: Iterator> FusedIterator for Chunked<I> {}
+
+#[derive(Debug, Clone)]
+pub struct Windowed<I: Iterator> {
+    iter: I,
+    size: usize,
+    window: VecDeque<I::Item>,
+    done: bool,
+}
+
+impl<I: Iterator> Iterator for Windowed<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.done {
+            return None;
+        }
+        // The first window needs `size` items, every later one just one more
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+        while self.window.len() < self.size {
+            match self.iter.next() {
+                Some(item) => self.window.push_back(item),
+                None => {
+                    self.done = true;
+                    self.window.clear();
+                    return None;
+                }
+            }
+        }
+        Some(self.window.iter().cloned().collect())
+    }
+}
+
+impl<I: Iterator> FusedIterator for Windowed<I> where I::Item: Clone {}
+
+// Reads one item ahead: to know a run has ended it has to see the first item
+// of the next run, which it keeps in `pending` for the following call
+#[derive(Debug, Clone)]
+pub struct DedupBy<I: Iterator, F> {
+    iter: I,
+    same: F,
+    pending: Option<I::Item>,
+    done: bool,
+}
+
+impl<I: Iterator, F> DedupBy<I, F> {
+    fn pull(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next();
+        self.done = item.is_none();
+        item
+    }
+}
+
+impl<I, F> Iterator for DedupBy<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let current = match self.pending.take() {
+            Some(item) => item,
+            None => self.pull()?,
+        };
+        while let Some(item) = self.pull() {
+            if !(self.same)(&current, &item) {
+                self.pending = Some(item);
+                break;
+            }
+        }
+        Some(current)
+    }
+}
+
+impl<I, F> FusedIterator for DedupBy<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+// ===== 4. DEMONSTRATION FUNCTION =====
+
+pub fn iterators_report() -> Report {
+    let mut report = Report::new("🦀 RUST ITERATORS DEEP STUDY 🦀");
+
+    // ===== CUSTOM ITERATORS =====
+    let custom = report.section("1️⃣ IMPLEMENTING Iterator:");
+    let countdown = Countdown::new(5);
+    custom
+        .example("Collatz(6)", format!("{:?}", Collatz::new(6).collect::<Vec<_>>()))
+        .example("Longest Collatz start below 1000", format!("{:?}", (1..1000).max_by_key(|&n| Collatz::new(n).count())))
+        .example(
+            "Countdown(5)",
+            format!("len {}: {:?}, reversed {:?}", countdown.len(), countdown.clone().collect::<Vec<_>>(), countdown.rev().collect::<Vec<_>>()),
+        )
+        .example(
+            "Free adaptors: Countdown(10).filter(even).map(square).sum()",
+            Countdown::new(10).filter(|n| n % 2 == 0).map(|n| n * n).sum::<u32>(),
+        );
+
+    // ===== FUSING =====
+    let fusing = report.section("2️⃣ FUSE SEMANTICS:");
+    let script = [Some(1), None, Some(2), None];
+    let mut raw = Scripted::new(script);
+    fusing.example("Unfused", format!("{:?}", [raw.next(), raw.next(), raw.next(), raw.next()]));
+    let mut fused = Scripted::new(script).fuse();
+    fusing.example("fuse()", format!("{:?}", [fused.next(), fused.next(), fused.next(), fused.next()]));
+    let mut chunked = Scripted::new(script).chunked(2);
+    fusing.example("chunked(2) over the script", format!("{:?}", [chunked.next(), chunked.next(), chunked.next()]));
+
+    // ===== ADAPTORS =====
+    let words = ["Apple", "apple", "APPLE", "banana", "Banana", "cherry"];
+    let readings = [20.1_f64, 20.2, 20.15, 23.0, 23.4, 19.0];
+    report
+        .section("3️⃣ EXTENSION-TRAIT ADAPTORS:")
+        .example("(1..=7).chunked(3)", format!("{:?}", (1..=7).chunked(3).collect::<Vec<_>>()))
+        .example("(1..=5).windowed(3)", format!("{:?}", (1..=5).windowed(3).collect::<Vec<_>>()))
+        .example("\"aaabccdaa\".dedup()", format!("{:?}", "aaabccdaa".chars().dedup().collect::<String>()))
+        .example(
+            "dedup_by(eq_ignore_ascii_case)",
+            format!("{:?}", words.iter().dedup_by(|a, b| a.eq_ignore_ascii_case(b)).collect::<Vec<_>>()),
+        )
+        .example(
+            "Readings, dropping changes under 0.5",
+            format!("{:?}", readings.iter().dedup_by(|a, b| (*a - *b).abs() < 0.5).collect::<Vec<_>>()),
+        );
+
+    // ===== LAZINESS =====
+    let mut pulled = Vec::new();
+    let first_chunk = (1..)
+        .inspect(|n| pulled.push(*n))
+        .chunked(4)
+        .windowed(2)
+        .next();
+    report
+        .section("4️⃣ LAZINESS:")
+        .example("First pair of chunks from an INFINITE range", format!("{:?}", first_chunk))
+        .example("Items actually pulled from the source", format!("{:?}", pulled));
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 ITERATORS SUMMARY:")
+        .check("Implement next() and every Iterator method comes free")
+        .check("size_hint, ExactSizeIterator and DoubleEndedIterator unlock len() and rev()")
+        .check("After None, only FusedIterator (or .fuse()) promises more None")
+        .check("Extension traits with blanket impls add adaptors to every iterator")
+        .check("Adaptors are lazy structs - nothing runs until something pulls");
+
+    report
+}
+
+pub fn demonstrate_iterators() {
+    print!("{}", iterators_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct IteratorsModule;
+
+impl LearningModule for IteratorsModule {
+    fn number(&self) -> u8 {
+        17
+    }
+
+    fn name(&self) -> &'static str {
+        "iterators"
+    }
+
+    fn title(&self) -> &'static str {
+        "ITERATORS DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Custom Iterators & Adaptors"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["traits", "collections"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_iterators() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { iterators_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&IteratorsModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// ITERATOR BEST PRACTICES:
+// 1. Return `impl Iterator<Item = T>` from functions instead of collecting
+// 2. Override size_hint when you know the length - collect() allocates once
+// 3. Implement FusedIterator when you can keep the promise; call .fuse()
+//    when you depend on it and the source doesn't promise it
+// 4. Adaptors that read ahead (dedup, peekable) pull one extra item - it
+//    matters when pulling has side effects
+// 5. Check itertools before writing an adaptor; write one when it doesn't fit
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn report_shows_fusing_and_laziness() {
+        let report = iterators_report();
+        let fusing = report.find("FUSE SEMANTICS").unwrap();
+        assert_eq!(fusing.output("Unfused"), Some("[Some(1), None, Some(2), None]"));
+        assert_eq!(fusing.output("fuse()"), Some("[Some(1), None, None, None]"));
+
+        // Two chunks of four is all the infinite range had to give
+        let laziness = report.find("LAZINESS").unwrap();
+        assert_eq!(laziness.output("Items actually pulled from the source"), Some("[1, 2, 3, 4, 5, 6, 7, 8]"));
+    }
+
+    #[test]
+    fn custom_iterators() {
+        assert_eq!(Collatz::new(6).collect::<Vec<_>>(), [6, 3, 10, 5, 16, 8, 4, 2, 1]);
+        assert_eq!(Collatz::new(0).count(), 0);
+
+        let mut countdown = Countdown::new(4);
+        assert_eq!(countdown.len(), 4);
+        assert_eq!((countdown.next(), countdown.next_back()), (Some(4), Some(1)));
+        assert_eq!(countdown.len(), 2);
+        assert_eq!(countdown.collect::<Vec<_>>(), [3, 2]);
+        assert_eq!(Countdown::new(3).rev().collect::<Vec<_>>(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn adaptors_produce_the_right_items() {
+        assert_eq!((1..=7).chunked(3).collect::<Vec<_>>(), [vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+        assert_eq!((1..=6).chunked(3).size_hint(), (2, Some(2)));
+        assert_eq!((1..=4).windowed(2).collect::<Vec<_>>(), [vec![1, 2], vec![2, 3], vec![3, 4]]);
+        assert_eq!((1..=2).windowed(3).count(), 0);
+        assert_eq!([1, 1, 2, 3, 3, 3, 1].into_iter().dedup().collect::<Vec<_>>(), [1, 2, 3, 1]);
+        assert_eq!((1..=10).dedup_by(|a, b| a / 4 == b / 4).collect::<Vec<_>>(), [1, 4, 8]);
+        assert_eq!(std::iter::empty::<u8>().dedup().next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn zero_sized_chunks_panic() {
+        let _ = (1..3).chunked(0);
+    }
+
+    #[test]
+    fn adaptors_are_lazy() {
+        let pulled = Cell::new(0);
+        let source = (1..).inspect(|_| pulled.set(pulled.get() + 1));
+
+        let mut chunks = source.chunked(3).windowed(2).dedup();
+        assert_eq!(pulled.get(), 0, "building the chain pulls nothing");
+
+        assert_eq!(chunks.next(), Some(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+        // Two chunks for the first window, plus one more chunk that dedup
+        // reads ahead to see where the run ends
+        assert_eq!(pulled.get(), 9);
+    }
+
+    #[test]
+    fn adaptors_are_fused_over_unfused_sources() {
+        let script = || Scripted::new([Some(1), Some(1), None, Some(2), Some(3), None, Some(4)]);
+        let mut raw = script();
+        assert_eq!([raw.next(), raw.next(), raw.next(), raw.next()], [Some(1), Some(1), None, Some(2)]);
+
+        let mut chunked = script().chunked(5);
+        assert_eq!(chunked.next(), Some(vec![1, 1]));
+        assert_eq!((chunked.next(), chunked.next()), (None, None));
+
+        let mut windowed = script().windowed(2);
+        assert_eq!(windowed.next(), Some(vec![1, 1]));
+        assert_eq!((windowed.next(), windowed.next()), (None, None));
+
+        let mut dedup = script().dedup();
+        assert_eq!(dedup.next(), Some(1));
+        assert_eq!((dedup.next(), dedup.next()), (None, None));
+    }
+}
@@ -0,0 +1,88 @@
+// ===== RUST ECOSYSTEM LEARNING PROJECT =====
+// 
+// This project is organized into modules for learning different Rust concepts:
+// - traits: Deep study of Rust's trait system
+// - lifetimes: Deep study of Rust's lifetime system
+// - smart_pointers: Deep study of Rust's smart pointers (Box, Rc, RefCell, Arc)
+// - errors: Deep study of error handling with thiserror and anyhow
+// - macros: Deep study of declarative macros (macro_rules!)
+// - async_await: Deep study of futures, executors and async/await
+// - concurrency: Deep study of threads, channels, locks and atomics
+// - collections: Deep study of std collections and choosing between them
+// - testing: Unit, integration and property tests plus criterion benchmarks
+// - pin_futures: Pin/Unpin, async desugaring and a from-scratch executor
+// - tokio_channels: tokio::sync channels, Semaphore, streams and shutdown
+// - patterns: Builder, Newtype, Typestate, Strategy and RAII guards
+// - actors: an actor framework on tokio mpsc/oneshot vs Arc<Mutex<T>>
+// - file_io: std::io traits, buffering, Seek, temp files, mmap, log parsing
+// - networking: framed TCP echo servers, blocking (std) and async (tokio)
+// - parsing: regex, hand-rolled and nom parsers for the same log lines
+// - iterators: custom Iterator impls and extension-trait adaptors
+// - lru_cache: an O(1) LRU cache on a HashMap and an index-arena linked list
+// - strings: String/&str, UTF-8 slicing, OsString/PathBuf, CString, byte strings
+// - combinators: Option/Result combinators refactored from match-heavy code
+// - const_eval: const fn, compile-time lookup tables, static vs const
+// - zero_copy: CSV and query-string parsers that return Cow<str>
+// - panics: catch_unwind, panic hooks, poisoning and restarting workers
+// - cancellation: tokio::select!, timeouts, cancel safety and JoinSet
+// - instrumentation: tracing spans, #[instrument], layers and a custom Layer
+// - projects: capstones that combine the modules above (kv_store, ...)
+// - wasm: browser exports for traits and errors (wasm32-unknown-unknown)
+// - (future modules will be added here)
+//
+// Every module implements registry::LearningModule and registers itself, so
+// the CLI in main.rs discovers modules without listing them. exercises grades
+// the practice files in exercises/ (`rust-basics check <module>`), quiz asks
+// multiple-choice questions, and progress remembers both between sessions.
+// scaffold generates new modules (`cargo run --bin new-module <name>`).
+// Every learning module also builds its demonstration as a report::Report,
+// data that run() prints and tests inspect.
+//
+// The modules live in this library crate so that `tests/` and `benches/` can
+// use them; `main.rs` is a thin binary that runs the demonstrations.
+
+pub mod traits;
+pub mod lifetimes;
+pub mod smart_pointers;
+pub mod errors;
+pub mod macros;
+// Built on tokio, which needs an OS underneath - not part of the wasm32 build
+#[cfg(not(target_arch = "wasm32"))]
+pub mod async_await;
+pub mod concurrency;
+pub mod collections;
+pub mod testing;
+pub mod pin_futures;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tokio_channels;
+pub mod patterns;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod actors;
+// Needs a filesystem, which wasm32-unknown-unknown doesn't have
+#[cfg(not(target_arch = "wasm32"))]
+pub mod file_io;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod networking;
+pub mod parsing;
+pub mod iterators;
+pub mod lru_cache;
+pub mod strings;
+pub mod combinators;
+pub mod const_eval;
+pub mod zero_copy;
+pub mod panics;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod cancellation;
+pub mod instrumentation;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod projects;
+pub mod wasm;
+
+pub mod registry;
+pub mod report;
+pub mod exercises;
+pub mod quiz;
+pub mod progress;
+// Used by the new-module binary (src/bin/new-module.rs)
+#[cfg(not(target_arch = "wasm32"))]
+pub mod scaffold;
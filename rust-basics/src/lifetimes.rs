@@ -5,6 +5,12 @@
 // should be valid. They prevent dangling references and ensure memory safety
 // without a garbage collector. Every reference in Rust has a lifetime.
 
+use std::marker::{PhantomData, PhantomPinned};
+use std::pin::Pin;
+use std::ptr::NonNull;
+
+use crate::report::{Report, Section};
+
 // ===== 1. BASIC LIFETIME CONCEPTS =====
 // 
 // UNDERSTANDING LIFETIME ANNOTATIONS:
@@ -63,6 +69,8 @@ where
 
 // ELISION EXAMPLE 1: Single input parameter
 // The compiler can infer the lifetime
+// (`&s[..]` spells out the whole-string slice)
+#[allow(clippy::redundant_slicing)]
 pub fn first_word(s: &str) -> &str {
     // Elided: fn first_word<'a>(s: &'a str) -> &'a str
     let bytes = s.as_bytes();
@@ -78,6 +86,8 @@ pub fn first_word(s: &str) -> &str {
 
 // ELISION EXAMPLE 2: Multiple inputs, no elision possible
 // This requires explicit lifetime annotations
+// (`word` is never read - it is only there to need the annotation)
+#[allow(unused_variables)]
 pub fn longest_word_from_sentence<'a>(sentence: &'a str, word: &str) -> &'a str {
     // We need to specify that the output relates to 'sentence', not 'word'
     // Without annotation, compiler can't determine which input the output relates to
@@ -148,10 +158,12 @@ impl<'a> ImportantExcerpt<'a> {
         3
     }
     
-    // Method with multiple lifetimes
-    pub fn announce_and_return_part<'b>(&self, announcement: &'b str) -> &str {
+    // Method with multiple lifetimes (written out explicitly for illustration).
+    // The caller makes the announcement (see lifetimes_report); what matters
+    // here is which of the two borrows the result comes from
+    #[allow(clippy::needless_lifetimes)]
+    pub fn announce_and_return_part<'b>(&self, _announcement: &'b str) -> &str {
         // Returns reference tied to struct's lifetime, not parameter's lifetime
-        println!("Attention please: {}", announcement);
         self.part
     }
 }
@@ -196,7 +208,8 @@ impl<'title, 'content> BookExcerpt<'title, 'content> {
 // • Static variables also have 'static lifetime
 // • Don't confuse 'static lifetime with static variables
 
-// String literals have 'static lifetime
+// String literals have 'static lifetime (spelled out here for illustration)
+#[allow(clippy::redundant_static_lifetimes)]
 pub static GLOBAL_MESSAGE: &'static str = "This lives for the entire program";
 
 // Function returning static reference
@@ -253,6 +266,7 @@ where
 // }
 
 // SOLUTION: Return owned value instead
+#[allow(clippy::let_and_return)]
 pub fn no_dangling_reference() -> String {
     let s = String::from("hello");
     s  // ✅ Return owned value, not reference
@@ -275,31 +289,352 @@ pub fn demonstrate_lifetime_error() {
 }
 
 // SOLUTION: Ensure all references live long enough
-pub fn demonstrate_lifetime_solution() {
+pub fn demonstrate_lifetime_solution(section: &mut Section) {
     let string1 = String::from("long string is long");
     let string2 = String::from("xyz");
     
     // Both string1 and string2 live for the entire function
     let result = longest(&string1, &string2);
-    println!("The longest string is: {}", result);
+    section.example("The longest string is", result);
     
     // We can use result anywhere in this scope
-    println!("Result is still valid: {}", result);
+    section.example("Result is still valid", result);
 }
 
-// ===== 8. DEMONSTRATION FUNCTION =====
+// ===== 8. HIGHER-RANKED TRAIT BOUNDS (HRTB) =====
+// 
+// UNDERSTANDING for<'a>:
+// • `F: for<'a> Fn(&'a str) -> &'a str` means "F works for EVERY lifetime
+//   'a", not for one particular lifetime picked by the caller
+// • Needed when the function calls F with references to its own locals -
+//   no lifetime the caller could name covers a local created inside
+// • `Fn(&str) -> &str` is already sugar for the for<'a> version; the explicit
+//   form is needed in trait bounds like `T: for<'a> Trait<'a>`
+// 
+// WITHOUT HRTB (won't compile):
+// fn apply_broken<'a, F: Fn(&'a str) -> &'a str>(f: F) -> String {
+//     let local = String::from("text");
+//     f(&local).to_string()  // ❌ `local` does not live long enough:
+// }                          //    the caller chose 'a, and it outlives this body
 
-pub fn demonstrate_lifetimes() {
-    println!("🦀 RUST LIFETIMES DEEP STUDY 🦀\n");
+pub fn normalize_and_apply<F>(inputs: &[&str], f: F) -> Vec<String>
+where
+    F: for<'a> Fn(&'a str) -> &'a str,
+{
+    inputs
+        .iter()
+        .map(|input| {
+            // A fresh local every time - only for<'a> lets f borrow it
+            let normalized = input.trim().to_lowercase();
+            f(&normalized).to_string()
+        })
+        .collect()
+}
+
+// HRTB ON A TRAIT WITH A LIFETIME PARAMETER
+pub trait Extractor<'a> {
+    fn extract(&self, line: &'a str) -> Option<&'a str>;
+}
+
+pub struct KeyExtractor;
+
+impl<'a> Extractor<'a> for KeyExtractor {
+    fn extract(&self, line: &'a str) -> Option<&'a str> {
+        line.split_once('=').map(|(key, _)| key.trim())
+    }
+}
+
+// The lines are built here, so E must work for every lifetime
+pub fn extract_all<E>(raw: &str, extractor: &E) -> Vec<String>
+where
+    E: for<'a> Extractor<'a>,
+{
+    raw.split(';')
+        .map(|line| line.replace('\t', " "))
+        .filter_map(|line| extractor.extract(&line).map(str::to_string))
+        .collect()
+}
+
+// ===== 9. VARIANCE AND PHANTOMDATA =====
+// 
+// UNDERSTANDING VARIANCE:
+// Variance decides whether a type with a longer lifetime can be used where
+// one with a shorter lifetime is expected
+// • Covariant: &'a T, Box<T>, Vec<T> - 'long can shrink to 'short ✅
+// • Invariant: &'a mut T, Cell<T> - the lifetime must match exactly
+// • Contravariant: fn(&'a str) - the other way round: a function taking any
+//   &str can stand in for one that only takes &'static str
+// 
+// WHY &mut T IS INVARIANT (won't compile):
+// let mut s: &'static str = "static";
+// {
+//     let local = String::from("temp");
+//     overwrite(&mut s, &local);  // ❌ `local` does not live long enough
+// }
+// println!("{}", s);  // would read freed memory if the call were allowed
+// 
+// CONTRAVARIANCE (won't compile):
+// let only_static: fn(&'static str) = |_| {};
+// let any: for<'a> fn(&'a str) = only_static;  // ❌ mismatched types
+// 
+// PHANTOMDATA:
+// • A struct that doesn't store a reference but should act as if it did
+//   (raw pointers, handles, IDs) uses PhantomData to pick its variance
+// • PhantomData<&'a T> → covariant, like a reference
+// • PhantomData<fn(&'a ()) -> &'a ()> → invariant in 'a
+
+pub fn overwrite<'a>(target: &mut &'a str, value: &'a str) {
+    *target = value;
+}
+
+// Covariance: a longer-lived reference shrinks to a shorter one for free
+pub fn shorten<'short, 'long: 'short>(s: &'long str) -> &'short str {
+    s
+}
+
+// Contravariance: accepts any &str, so it also works as fn(&'static str)
+pub fn print_any(s: &str) {
+    println!("  print_any got: '{}'", s);
+}
+
+// INVARIANT BRANDS:
+// An invariant lifetime can't grow or shrink, so it works as a unique
+// "brand" for one collection: indices from one BrandedVec are rejected by
+// every other at compile time. with_branded uses an HRTB so each call gets
+// a fresh lifetime nobody else can name.
+// 
+// with_branded(vec![1], |mut a| {
+//     with_branded(vec![2], |b| {
+//         let i = a.push(3);
+//         b.get(i)  // ❌ borrowed data escapes outside of closure:
+//     })            //    the brands of a and b are different lifetimes
+// });
+type Brand<'id> = PhantomData<fn(&'id ()) -> &'id ()>;
+
+pub struct BrandedVec<'id, T> {
+    items: Vec<T>,
+    _brand: Brand<'id>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BrandedIndex<'id> {
+    index: usize,
+    _brand: Brand<'id>,
+}
+
+pub fn with_branded<T, R>(items: Vec<T>, f: impl for<'id> FnOnce(BrandedVec<'id, T>) -> R) -> R {
+    f(BrandedVec { items, _brand: PhantomData })
+}
+
+impl<'id, T> BrandedVec<'id, T> {
+    pub fn push(&mut self, value: T) -> BrandedIndex<'id> {
+        self.items.push(value);
+        BrandedIndex { index: self.items.len() - 1, _brand: PhantomData }
+    }
+
+    pub fn index_of(&self, index: usize) -> Option<BrandedIndex<'id>> {
+        (index < self.items.len()).then_some(BrandedIndex { index, _brand: PhantomData })
+    }
+
+    // Every BrandedIndex<'id> came from this vec, which never shrinks
+    pub fn get(&self, index: BrandedIndex<'id>) -> &T {
+        &self.items[index.index]
+    }
+}
+
+// ===== 10. &'static T VS T: 'static =====
+// 
+// UNDERSTANDING THE DIFFERENCE:
+// • &'static T: a REFERENCE valid until the program ends - string literals,
+//   statics, or memory deliberately leaked with Box::leak
+// • T: 'static: T holds no borrows shorter than 'static. Every owned type
+//   (String, Vec<u8>, i32) qualifies, even though it can be dropped any time
+// • So `T: 'static` is about what T could borrow, not how long a value lives
+// • thread::spawn and Box<dyn Trait> (which means Box<dyn Trait + 'static>)
+//   need T: 'static because they may keep the value around indefinitely
+// 
+// BORROWING A LOCAL (won't compile):
+// let local = String::from("temporary");
+// store_reference(&local);  // ❌ `local` does not live long enough
+// keep_forever(&local);     // ❌ same: &'a String is only 'static if 'a is
+// keep_forever(local);      // ✅ String owns its data, so String: 'static
+
+pub fn keep_forever<T: std::fmt::Debug + 'static>(value: T) -> Box<dyn std::fmt::Debug> {
+    Box::new(value)
+}
+
+// Owned data moved into the thread satisfies T: 'static
+pub fn describe_on_thread<T: std::fmt::Display + Send + 'static>(value: T) -> String {
+    std::thread::spawn(move || format!("thread saw: {}", value)).join().unwrap()
+}
+
+// A &'static str made at runtime: the allocation is never freed
+pub fn leak_string(text: String) -> &'static str {
+    Box::leak(text.into_boxed_str())
+}
+
+// ===== 11. SELF-REFERENTIAL STRUCTS =====
+// 
+// THE PROBLEM (won't compile):
+// pub struct Parsed<'a> {
+//     text: String,
+//     first_word: &'a str,  // meant to point into `text`
+// }
+// 
+// pub fn parse<'a>(text: String) -> Parsed<'a> {
+//     let first_word = first_word(&text);
+//     Parsed { text, first_word }
+//     // ❌ cannot move out of `text` because it is borrowed
+//     // ❌ cannot return value referencing function parameter `text`
+// }
+// 
+// • Building the struct moves `text`, and a borrowed value can't be moved
+// • There is no lifetime meaning "as long as my own field" - 'a always comes
+//   from outside the struct
+// 
+// THE OPTIONS, IN ORDER OF PREFERENCE:
+// 1. Restructure: store positions (ranges, indices) instead of references
+//    and turn them into &str in methods. Safe, and the struct moves freely
+// 2. Split owner and borrower: keep the String in one variable and a View<'a>
+//    borrowing it in another - the usual answer in Rust
+// 3. Pin: when something really points at its own address (inline buffers,
+//    futures, intrusive lists), pin it so it can't move (see pin_futures.rs)
+// 4. ouroboros / self_cell: when a type you don't control borrows from
+//    another (a parser over a file's text, a statement borrowing a database
+//    connection) and both must travel together - returned from a function,
+//    stored in a HashMap. They do what WordIndex below does, with the unsafe
+//    code written and reviewed once
+// 
+// HEAP DATA DOESN'T MOVE:
+// Moving a String copies its (pointer, length, capacity), not the bytes. A
+// pointer into those bytes survives the move; a pointer to a field of the
+// struct itself does not. That's why ouroboros-style crates keep the owner's
+// data on the heap, and why InlineDocument below needs Pin.
+
+// 1. RESTRUCTURE: ranges into the owned text
+pub struct Document {
+    text: String,
+    title: std::ops::Range<usize>,
+}
+
+impl Document {
+    pub fn new(text: String) -> Self {
+        let end = text.find('\n').unwrap_or(text.len());
+        Document { text, title: 0..end }
+    }
+
+    pub fn title(&self) -> &str {
+        &self.text[self.title.clone()]
+    }
+
+    pub fn body(&self) -> &str {
+        self.text[self.title.end..].trim_start()
+    }
+}
+
+// 2. SPLIT OWNER AND BORROWER: the caller keeps the String alive
+pub struct DocumentView<'a> {
+    pub title: &'a str,
+    pub body: &'a str,
+}
+
+impl<'a> DocumentView<'a> {
+    pub fn new(text: &'a str) -> Self {
+        let (title, body) = text.split_once('\n').unwrap_or((text, ""));
+        DocumentView { title, body: body.trim_start() }
+    }
+}
+
+// 3. PIN: the title points into an inline array, so moving would break it
+pub const INLINE_CAPACITY: usize = 64;
+
+pub struct InlineDocument {
+    buf: [u8; INLINE_CAPACITY],
+    len: usize,
+    // Points into `buf` once pinned
+    title: Option<NonNull<str>>,
+    _pin: PhantomPinned,
+}
+
+impl InlineDocument {
+    // Text longer than INLINE_CAPACITY is cut at a char boundary
+    pub fn new(text: &str) -> Pin<Box<Self>> {
+        let mut len = text.len().min(INLINE_CAPACITY);
+        while !text.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut buf = [0; INLINE_CAPACITY];
+        buf[..len].copy_from_slice(&text.as_bytes()[..len]);
+
+        // Pinned first, so the address taken below is final
+        let mut doc = Box::pin(InlineDocument { buf, len, title: None, _pin: PhantomPinned });
+        let stored = std::str::from_utf8(&doc.buf[..doc.len]).expect("copied up to a char boundary");
+        let title = NonNull::from(stored.lines().next().unwrap_or(""));
+        // SAFETY: only a field is written; the document is never moved out of the Pin
+        unsafe { doc.as_mut().get_unchecked_mut().title = Some(title) };
+        doc
+    }
+
+    pub fn title(self: Pin<&Self>) -> &str {
+        match self.get_ref().title {
+            // SAFETY: `title` points into `buf`, which can't move (pinned) or
+            // change (no &mut access is handed out) while self is borrowed
+            Some(title) => unsafe { title.as_ref() },
+            None => "",
+        }
+    }
+
+    pub fn points_into_itself(self: Pin<&Self>) -> bool {
+        let buf = self.buf.as_ptr_range();
+        self.title.is_some_and(|title| buf.contains(&title.as_ptr().cast_const().cast()))
+    }
+}
+
+// 4. OUROBOROS-STYLE BY HAND: owner and borrower in one movable struct
+pub struct WordIndex {
+    // Really borrowed from `text`: the 'static is a lie that never leaves this
+    // struct. Declared first so it is dropped before the text
+    words: Vec<&'static str>,
+    // Its bytes are on the heap, so they stay put when WordIndex moves
+    text: String,
+}
+
+impl WordIndex {
+    pub fn new(text: String) -> Self {
+        // SAFETY: the bytes live on the heap, and `text` is private and never
+        // changed or dropped before `words`, so these references stay valid
+        // for as long as the WordIndex exists
+        let text_forever: &'static str = unsafe { &*(text.as_str() as *const str) };
+        WordIndex { words: text_forever.split_whitespace().collect(), text }
+    }
+
+    // Shrinks the fake 'static to the borrow of self, so it can't escape
+    pub fn words(&self) -> &[&str] {
+        &self.words
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+// ===== 12. DEMONSTRATION FUNCTION =====
+//
+// lifetimes_report() collects the demonstration as a Report (see report.rs);
+// demonstrate_lifetimes() prints it.
+
+pub fn lifetimes_report() -> Report {
+    let mut report = Report::new("🦀 RUST LIFETIMES DEEP STUDY 🦀");
     
     // ===== BASIC LIFETIME ANNOTATIONS =====
-    println!("1️⃣ BASIC LIFETIME ANNOTATIONS:");
+    let basics = report.section("1️⃣ BASIC LIFETIME ANNOTATIONS:");
     let string1 = String::from("abcd");
     let string2 = "xyz";
     
     // Both strings live for the entire function, so this works
+    #[allow(clippy::needless_borrow)]
     let result = longest(&string1, &string2);
-    println!("The longest string is: '{}'", result);
+    basics.example("longest(\"abcd\", \"xyz\")", format!("'{}'", result));
     
     // Different lifetimes example
     let long_string = String::from("long string is long");
@@ -307,91 +642,268 @@ pub fn demonstrate_lifetimes() {
         let short_string = String::from("short");
         // This works because we use result within the scope where both strings live
         let result = longest(&long_string, &short_string);
-        println!("Within scope, longest is: '{}'", result);
+        basics.example("Within scope, longest", format!("'{}'", result));
     }
     
     // ===== LIFETIME ELISION EXAMPLES =====
-    println!("\n2️⃣ LIFETIME ELISION EXAMPLES:");
+    let elision = report.section("2️⃣ LIFETIME ELISION EXAMPLES:");
     let sentence = "Hello world from Rust programming";
     let first = first_word(sentence);
-    println!("First word: '{}'", first);
+    elision.example("first_word(sentence)", format!("'{}'", first));
     
     let longest_word = longest_word_from_sentence(sentence, "test");
-    println!("Longest word in sentence: '{}'", longest_word);
+    elision.example("longest_word_from_sentence(sentence, \"test\")", format!("'{}'", longest_word));
     
     // ===== STRUCT WITH LIFETIMES =====
-    println!("\n3️⃣ STRUCTS WITH LIFETIMES:");
+    let structs = report.section("3️⃣ STRUCTS WITH LIFETIMES:");
     let novel = String::from("Call me Ishmael. Some years ago...");
     let first_sentence = novel.split('.').next().expect("Could not find a '.'");
     
     // The excerpt references data from 'novel'
     let excerpt = ImportantExcerpt::new(first_sentence);
-    println!("Important excerpt: '{}'", excerpt.get_part());
-    println!("Excerpt level: {}", excerpt.level());
+    structs
+        .example("excerpt.get_part()", format!("'{}'", excerpt.get_part()))
+        .example("excerpt.level()", excerpt.level());
     
     // Method with announcement
     let announcement = "Listen up!";
     let part = excerpt.announce_and_return_part(announcement);
-    println!("Returned part: '{}'", part);
+    structs
+        .text(format!("Attention please: {}", announcement))
+        .example("excerpt.announce_and_return_part(\"Listen up!\")", format!("'{}'", part));
     
     // ===== MULTIPLE LIFETIMES =====
-    println!("\n4️⃣ MULTIPLE LIFETIMES:");
     let book_title = "The Rust Programming Language";
     let book_content = "Rust is a systems programming language...";
     
     let book_excerpt = BookExcerpt::new(book_title, book_content, 42);
-    println!("Book title: '{}'", book_excerpt.get_title());
-    println!("Book content: '{}'", book_excerpt.get_content());
-    println!("Formatted excerpt:\n{}", book_excerpt.format_excerpt());
+    report
+        .section("4️⃣ MULTIPLE LIFETIMES:")
+        .example("book_excerpt.get_title()", format!("'{}'", book_excerpt.get_title()))
+        .example("book_excerpt.get_content()", format!("'{}'", book_excerpt.get_content()))
+        .example("book_excerpt.format_excerpt()", book_excerpt.format_excerpt());
     
     // ===== STATIC LIFETIME =====
-    println!("\n5️⃣ STATIC LIFETIME:");
-    println!("Global message: '{}'", GLOBAL_MESSAGE);
-    println!("Static string: '{}'", get_static_str());
-    
-    // Static holder
     let holder = Holder::new("This has static lifetime");
-    println!("Holder value: '{}'", holder.get_value());
+    report
+        .section("5️⃣ STATIC LIFETIME:")
+        .example("GLOBAL_MESSAGE", format!("'{}'", GLOBAL_MESSAGE))
+        .example("get_static_str()", format!("'{}'", get_static_str()))
+        .example("holder.get_value()", format!("'{}'", holder.get_value()));
     
     // ===== TEXT ANALYZER =====
-    println!("\n6️⃣ TEXT ANALYZER (ELISION IN METHODS):");
+    let analyzer_section = report.section("6️⃣ TEXT ANALYZER (ELISION IN METHODS):");
     let analyzer = TextAnalyzer::new("The quick brown fox jumps over the lazy dog".to_string());
-    println!("Full text: '{}'", analyzer.get_text());
+    analyzer_section.example("analyzer.get_text()", format!("'{}'", analyzer.get_text()));
     
     if let Some(longest) = analyzer.find_longest_word(4) {
-        println!("Longest word (min 4 chars): '{}'", longest);
+        analyzer_section.example("analyzer.find_longest_word(4)", format!("'{}'", longest));
     }
     
     // ===== LIFETIME SOLUTIONS =====
-    println!("\n7️⃣ LIFETIME SOLUTIONS:");
+    let solutions = report.section("7️⃣ LIFETIME SOLUTIONS:");
     let owned_string = no_dangling_reference();
-    println!("Owned string (no dangling): '{}'", owned_string);
-    
-    demonstrate_lifetime_solution();
+    solutions.example("Owned string (no dangling)", format!("'{}'", owned_string));
+    
+    demonstrate_lifetime_solution(solutions);
+    
+    // ===== HIGHER-RANKED TRAIT BOUNDS =====
+    let inputs = ["  Hello World ", "RUST lifetimes"];
+    report
+        .section("8️⃣ HIGHER-RANKED TRAIT BOUNDS (for<'a>):")
+        .example("first_word on normalized locals", format!("{:?}", normalize_and_apply(&inputs, first_word)))
+        .example(
+            "closure on normalized locals",
+            format!("{:?}", normalize_and_apply(&inputs, |s| s.rsplit(' ').next().unwrap_or(s))),
+        )
+        .example("Extracted keys", format!("{:?}", extract_all("host = a;\tport=8080;no key", &KeyExtractor)));
+    
+    // ===== VARIANCE =====
+    let variance = report.section("9️⃣ VARIANCE AND PHANTOMDATA:");
+    let mut current: &str = "initial";
+    let replacement = String::from("replacement");
+    overwrite(&mut current, &replacement); // fine: both share the local's lifetime
+    variance
+        .example("overwrite (&mut is invariant)", format!("'{}'", current))
+        .example("shorten (covariant)", format!("'{}'", shorten(GLOBAL_MESSAGE)));
+    // Not called: print_any writes to stdout, which is not part of the report
+    let only_static: fn(&'static str) = print_any; // contravariant
+    variance.example(
+        "fn(&str) used as fn(&'static str), same function",
+        std::ptr::fn_addr_eq(only_static, print_any as fn(&'static str)),
+    );
+    let total = with_branded(vec![10, 20], |mut numbers| {
+        let thirty = numbers.push(30);
+        let first = numbers.index_of(0).expect("index 0 exists");
+        numbers.get(first) + numbers.get(thirty)
+    });
+    variance.example("Branded indices (invariant lifetime): 10 + 30", total);
+    
+    // ===== &'static T VS T: 'static =====
+    let owned = String::from("owned String is 'static");
+    let leaked = leak_string(format!("built at runtime, leaked: {}", 42));
+    report
+        .section("🔟 &'static T VS T: 'static:")
+        .example("keep_forever(String)", format!("{:?}", keep_forever(owned)))
+        .example("keep_forever(&'static str)", format!("{:?}", keep_forever(get_static_str())))
+        .text(describe_on_thread(vec![1, 2, 3].len()))
+        .example("store_reference(leaked)", format!("'{}'", store_reference(leaked)));
+    
+    // ===== SELF-REFERENTIAL STRUCTS =====
+    let self_ref = report.section("1️⃣1️⃣ SELF-REFERENTIAL STRUCTS:");
+    let text = "Release notes\nLifetimes now cover self-references.";
+    let documents = [Document::new(text.to_string())]; // moved into an array - ranges don't care
+    self_ref.text(format!("Ranges: title '{}', body '{}'", documents[0].title(), documents[0].body()));
+    
+    let view = DocumentView::new(text);
+    self_ref.text(format!("Owner + view: title '{}', body '{}'", view.title, view.body));
+    
+    let pinned = InlineDocument::new(text);
+    let moved = pinned; // moves the Box pointer; the pinned document stays put
+    self_ref.text(format!(
+        "Pinned inline buffer: title '{}', points into itself: {}",
+        moved.as_ref().title(),
+        moved.as_ref().points_into_itself()
+    ));
+    
+    let index = WordIndex::new(text.to_string());
+    let indexes = [index]; // the String's heap bytes don't move with it
+    self_ref.text(format!("WordIndex of {} bytes: {:?}", indexes[0].text().len(), &indexes[0].words()[..3]));
     
     // ===== SUMMARY =====
-    println!("\n🎯 LIFETIME CONCEPTS SUMMARY:");
-    println!("✅ Lifetime annotations describe relationships, not durations");
-    println!("✅ Elision rules let you omit annotations in many cases");
-    println!("✅ Structs with references need lifetime parameters");
-    println!("✅ 'static means 'lives for entire program duration'");
-    println!("✅ Lifetime bounds specify outliving relationships");
-    println!("✅ Dangling references are prevented at compile time");
-    
-    println!("\n📚 LIFETIME ELISION RULES:");
-    println!("1. Each reference parameter gets its own lifetime");
-    println!("2. One input lifetime → assigned to all outputs");
-    println!("3. &self or &mut self → its lifetime assigned to all outputs");
-    
-    println!("\n🚫 COMMON LIFETIME ERRORS:");
-    println!("• Returning references to local variables");
-    println!("• Using references after their data is dropped");
-    println!("• Struct outliving its referenced data");
-    println!("• Mismatched lifetime relationships");
-    
-    println!("\n💡 LIFETIME BEST PRACTICES:");
-    println!("• Prefer owned types over references when possible");
-    println!("• Use lifetime elision when available");
-    println!("• Make lifetime relationships explicit when needed");
-    println!("• Understand the borrow checker's perspective");
-}
\ No newline at end of file
+    report
+        .section("🎯 LIFETIME CONCEPTS SUMMARY:")
+        .check("Lifetime annotations describe relationships, not durations")
+        .check("Elision rules let you omit annotations in many cases")
+        .check("Structs with references need lifetime parameters")
+        .check("'static means 'lives for entire program duration'")
+        .check("Lifetime bounds specify outliving relationships")
+        .check("Dangling references are prevented at compile time")
+        .check("for<'a> bounds accept closures that work for any lifetime")
+        .check("Variance decides when lifetimes may shrink; &mut T is invariant")
+        .check("T: 'static means 'owns or borrows forever', not 'lives forever'")
+        .check("Structs can't borrow from themselves - store ranges or split owner and view");
+    
+    report
+        .section("📚 LIFETIME ELISION RULES:")
+        .text("1. Each reference parameter gets its own lifetime")
+        .text("2. One input lifetime → assigned to all outputs")
+        .text("3. &self or &mut self → its lifetime assigned to all outputs");
+    
+    report
+        .section("🚫 COMMON LIFETIME ERRORS:")
+        .bullet("Returning references to local variables")
+        .bullet("Using references after their data is dropped")
+        .bullet("Struct outliving its referenced data")
+        .bullet("Mismatched lifetime relationships")
+        .bullet("Naming a caller's lifetime where for<'a> was needed")
+        .bullet("Passing &local to thread::spawn or Box<dyn Trait>");
+    
+    report
+        .section("💡 LIFETIME BEST PRACTICES:")
+        .bullet("Prefer owned types over references when possible")
+        .bullet("Use lifetime elision when available")
+        .bullet("Make lifetime relationships explicit when needed")
+        .bullet("Reach for Pin or ouroboros only after ranges and owner/view splits")
+        .bullet("Understand the borrow checker's perspective");
+    
+    report
+}
+
+pub fn demonstrate_lifetimes() {
+    print!("{}", lifetimes_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct LifetimesModule;
+
+impl LearningModule for LifetimesModule {
+    fn number(&self) -> u8 {
+        2
+    }
+
+    fn name(&self) -> &'static str {
+        "lifetimes"
+    }
+
+    fn title(&self) -> &'static str {
+        "LIFETIMES DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Lifetimes"
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_lifetimes() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { lifetimes_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&LifetimesModule) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_and_first_word_return_borrowed_slices() {
+        let owned = String::from("much longer");
+        assert_eq!(longest(&owned, "short"), "much longer");
+        // Ties go to the second argument
+        assert_eq!(longest("abc", "xyz"), "xyz");
+        assert_eq!(first_word("hello world"), "hello");
+        assert_eq!(first_word("single"), "single");
+    }
+
+    #[test]
+    fn text_analyzer_finds_the_last_longest_word() {
+        let analyzer = TextAnalyzer::new("The quick brown fox jumps over the lazy dog".to_string());
+        assert_eq!(analyzer.get_text().split_whitespace().count(), 9);
+        // quick, brown and jumps tie at 5 letters; max_by_key keeps the last
+        assert_eq!(analyzer.find_longest_word(4), Some("jumps"));
+        assert_eq!(analyzer.find_longest_word(6), None);
+        assert_eq!(TextAnalyzer::new(String::new()).find_longest_word(0), None);
+    }
+
+    #[test]
+    fn excerpts_return_parts_of_the_original_text() {
+        let novel = String::from("Call me Ishmael. Some years ago...");
+        let excerpt = ImportantExcerpt::new(novel.split('.').next().unwrap());
+        let part = excerpt.announce_and_return_part("hey");
+        assert_eq!(part, "Call me Ishmael");
+        // Same bytes as the novel, not a copy
+        assert_eq!(part.as_ptr(), novel.as_ptr());
+    }
+
+    #[test]
+    fn report_has_every_numbered_section_in_order() {
+        let report = lifetimes_report();
+        let numbered = report.sections.iter().filter(|section| !section.heading.starts_with(['🎯', '📚', '🚫', '💡']));
+        assert_eq!(numbered.count(), 11);
+        assert!(report.sections[0].heading.contains("BASIC LIFETIME ANNOTATIONS"));
+        assert!(report.to_string().starts_with("🦀 RUST LIFETIMES DEEP STUDY 🦀\n\n1️⃣"));
+    }
+
+    #[test]
+    fn report_captures_borrowed_results() {
+        let report = lifetimes_report();
+        let basics = report.find("BASIC LIFETIME ANNOTATIONS").unwrap();
+        assert_eq!(basics.output("longest(\"abcd\", \"xyz\")"), Some("'abcd'"));
+
+        let solutions = report.find("LIFETIME SOLUTIONS").unwrap();
+        assert_eq!(solutions.output("Result is still valid"), Some("long string is long"));
+
+        let variance = report.find("VARIANCE").unwrap();
+        assert_eq!(variance.output("overwrite (&mut is invariant)"), Some("'replacement'"));
+        assert_eq!(variance.output("Branded indices (invariant lifetime): 10 + 30"), Some("40"));
+    }
+}
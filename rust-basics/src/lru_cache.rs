@@ -0,0 +1,574 @@
+// ===== LRU CACHE FROM SCRATCH =====
+//
+// A cache with room for N entries that, when full, evicts the Least Recently
+// Used one. Both get() and put() should be O(1), which takes two structures
+// working together:
+//
+//   HashMap<K, slot>          key → where its node lives      (O(1) lookup)
+//   doubly linked list        most recent ... least recent    (O(1) reorder)
+//
+//   head (MRU)                                          tail (LRU)
+//     [c] ⇄ [a] ⇄ [d] ⇄ [b]      get(d) → [d] ⇄ [c] ⇄ [a] ⇄ [b]
+//                                 put(e) when full → evict b from the tail
+//
+// Every access unlinks a node from the middle and relinks it at the head -
+// which is exactly the operation Rust's ownership rules make awkward.
+
+use crate::report::Report;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FusedIterator;
+use std::rc::{Rc, Weak};
+use std::time::Instant;
+
+// ===== 1. WHY Rc<RefCell<Node>> STRUGGLES =====
+//
+// UNDERSTANDING THE PROBLEM:
+// • A node in a doubly linked list has two owners-in-spirit: its prev and its
+//   next neighbour. Box means exactly one owner, so the textbook translation
+//   is Rc<RefCell<Node>> for shared, mutable nodes
+// • prev and next can't both be Rc: every pair of neighbours would be a
+//   reference cycle, and the list would leak when dropped. So next is Rc and
+//   prev is Weak - and every backwards step is an upgrade() that can fail
+// • Unlinking a node mutates three nodes. Each borrow_mut() is checked at
+//   runtime; hold one borrow too long and the next one panics with
+//   BorrowMutError - a bug the compiler can no longer catch
+// • The HashMap needs a handle to each node, so it holds yet another Rc
+// • Every access pays for reference-count updates and borrow flags
+//
+// Rc<RefCell> works, but it moves the ownership checks from compile time to
+// runtime. The usual alternatives:
+// • Raw pointers (*mut Node) with unsafe - what std's LinkedList does; fast,
+//   but every invariant is now yours to uphold
+// • An index arena (below) - nodes live in a Vec and point at each other by
+//   index. No unsafe, no Rc, no RefCell; a stale index is a logic bug, never
+//   memory unsafety
+
+pub struct RcNode {
+    pub key: u32,
+    pub prev: Option<Weak<RefCell<RcNode>>>,
+    pub next: Option<Rc<RefCell<RcNode>>>,
+}
+
+impl RcNode {
+    pub fn new(key: u32) -> Rc<RefCell<RcNode>> {
+        Rc::new(RefCell::new(RcNode { key, prev: None, next: None }))
+    }
+}
+
+// Links a ⇄ b the way an Rc list has to: a owns b, b only observes a
+pub fn link(a: &Rc<RefCell<RcNode>>, b: &Rc<RefCell<RcNode>>) {
+    a.borrow_mut().next = Some(Rc::clone(b));
+    b.borrow_mut().prev = Some(Rc::downgrade(a));
+}
+
+#[derive(Debug)]
+pub struct RcPitfalls {
+    // After link(a, b): b's owners are its binding and a.next; a's Weak
+    // back-link from b doesn't count
+    pub a_strong: usize,
+    pub b_strong: usize,
+    // Whether walking back from b to a fails while a is already borrowed
+    pub second_borrow_fails: bool,
+}
+
+pub fn rc_pitfalls() -> RcPitfalls {
+    let (a, b) = (RcNode::new(1), RcNode::new(2));
+    link(&a, &b);
+    let (a_strong, b_strong) = (Rc::strong_count(&a), Rc::strong_count(&b));
+
+    // Moving b to the front means fixing a.next while holding b - natural to
+    // write, and a runtime error if a is already borrowed
+    let _holding_a = a.borrow_mut();
+    let prev = b.borrow().prev.as_ref().and_then(Weak::upgrade).expect("a is alive");
+    RcPitfalls { a_strong, b_strong, second_borrow_fails: prev.try_borrow_mut().is_err() }
+}
+
+// ===== 2. THE ARENA-BACKED LRU =====
+//
+// UNDERSTANDING THE LAYOUT:
+// • slots: Vec<Option<Node>>, each Node holding its key, value and the
+//   indexes of its neighbours - the "pointers" are plain usizes
+// • map: key → slot index
+// • head/tail: indexes of the most and least recently used nodes
+// • free: slots emptied by remove(), reused by the next insert, so the Vec
+//   never grows beyond the capacity
+// • Keys are stored twice (map and node), hence K: Clone. Eviction needs
+//   the key in the node to remove it from the map
+//
+// INVARIANTS (checked by the property tests):
+// • map.len() == number of occupied slots == length of the list
+// • walking next from head visits every node once and ends at tail, and
+//   prev links mirror next links
+
+#[derive(Debug)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct LruCache<K, V> {
+    slots: Vec<Option<Node<K, V>>>,
+    map: HashMap<K, usize>,
+    free: Vec<usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    capacity: usize,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    // A capacity of 0 gives a cache that stores nothing
+    pub fn new(capacity: usize) -> Self {
+        LruCache {
+            slots: Vec::with_capacity(capacity),
+            map: HashMap::with_capacity(capacity),
+            free: Vec::new(),
+            head: None,
+            tail: None,
+            capacity,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    fn node(&self, index: usize) -> &Node<K, V> {
+        self.slots[index].as_ref().expect("linked slots are occupied")
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut Node<K, V> {
+        self.slots[index].as_mut().expect("linked slots are occupied")
+    }
+
+    // Takes the node out of the list, joining its neighbours
+    fn unlink(&mut self, index: usize) {
+        let (prev, next) = {
+            let node = self.node(index);
+            (node.prev, node.next)
+        };
+        match prev {
+            Some(prev) => self.node_mut(prev).next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.node_mut(next).prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn push_front(&mut self, index: usize) {
+        let old_head = self.head;
+        {
+            let node = self.node_mut(index);
+            node.prev = None;
+            node.next = old_head;
+        }
+        match old_head {
+            Some(old_head) => self.node_mut(old_head).prev = Some(index),
+            None => self.tail = Some(index),
+        }
+        self.head = Some(index);
+    }
+
+    fn touch(&mut self, index: usize) {
+        if self.head != Some(index) {
+            self.unlink(index);
+            self.push_front(index);
+        }
+    }
+
+    // Marks the entry as most recently used
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let index = *self.map.get(key)?;
+        self.touch(index);
+        Some(&self.node(index).value)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let index = *self.map.get(key)?;
+        self.touch(index);
+        Some(&mut self.node_mut(index).value)
+    }
+
+    // Looks without counting as a use
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.map.get(key).map(|&index| &self.node(index).value)
+    }
+
+    // Inserts or updates, making the entry most recently used. Returns the
+    // entry pushed out: the old value under the same key, or the least
+    // recently used entry if the cache was full (with capacity 0, the entry
+    // just given)
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(&index) = self.map.get(&key) {
+            self.touch(index);
+            let old = std::mem::replace(&mut self.node_mut(index).value, value);
+            return Some((key, old));
+        }
+        if self.capacity == 0 {
+            return Some((key, value));
+        }
+
+        let evicted = if self.map.len() == self.capacity { self.pop_lru() } else { None };
+        let node = Node { key: key.clone(), value, prev: None, next: None };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.slots[index] = Some(node);
+                index
+            }
+            None => {
+                self.slots.push(Some(node));
+                self.slots.len() - 1
+            }
+        };
+        self.push_front(index);
+        self.map.insert(key, index);
+        evicted
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.map.remove(key)?;
+        Some(self.release(index).1)
+    }
+
+    // Removes and returns the least recently used entry
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let index = self.tail?;
+        let (key, value) = self.release(index);
+        self.map.remove(&key);
+        Some((key, value))
+    }
+
+    fn release(&mut self, index: usize) -> (K, V) {
+        self.unlink(index);
+        let node = self.slots[index].take().expect("linked slots are occupied");
+        self.free.push(index);
+        (node.key, node.value)
+    }
+
+    // Most recently used first; rev() for least recently used first
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { cache: self, front: self.head, back: self.tail, remaining: self.len() }
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    cache: &'a LruCache<K, V>,
+    front: Option<usize>,
+    back: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, K: Hash + Eq + Clone, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.cache.node(self.front?);
+        self.front = node.next;
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.cache.node(self.back?);
+        self.back = node.prev;
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K: Hash + Eq + Clone, V> FusedIterator for Iter<'_, K, V> {}
+
+// ===== 3. THE REFERENCE MODEL =====
+//
+// The obvious version: a Vec in recency order, most recent first. Every
+// operation is a linear scan, so it's O(n) - but it's short enough to be
+// obviously right. The property tests run random operation sequences
+// against both and demand identical answers: the model is the spec.
+
+#[derive(Debug, Clone)]
+pub struct VecLru<K, V> {
+    entries: Vec<(K, V)>,
+    capacity: usize,
+}
+
+impl<K: PartialEq, V> VecLru<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        VecLru { entries: Vec::new(), capacity }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn position(&self, key: &K) -> Option<usize> {
+        self.entries.iter().position(|(k, _)| k == key)
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let entry = self.entries.remove(self.position(key)?);
+        self.entries.insert(0, entry);
+        Some(&self.entries[0].1)
+    }
+
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.position(key).map(|at| &self.entries[at].1)
+    }
+
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(at) = self.position(&key) {
+            let (stored, old) = self.entries.remove(at);
+            self.entries.insert(0, (stored, value));
+            return Some((key, old));
+        }
+        if self.capacity == 0 {
+            return Some((key, value));
+        }
+        let evicted = if self.entries.len() == self.capacity { self.entries.pop() } else { None };
+        self.entries.insert(0, (key, value));
+        evicted
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        Some(self.entries.remove(self.position(key)?).1)
+    }
+
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        self.entries.pop()
+    }
+
+    // Most recently used first, like LruCache::iter
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+}
+
+// ===== 4. DEMONSTRATION FUNCTION =====
+
+fn keys<K: Hash + Eq + Clone + Copy, V>(cache: &LruCache<K, V>) -> Vec<K> {
+    cache.iter().map(|(k, _)| *k).collect()
+}
+
+pub fn lru_cache_report() -> Report {
+    let mut report = Report::new("🦀 LRU CACHE FROM SCRATCH 🦀");
+
+    // ===== Rc<RefCell> =====
+    let pitfalls = rc_pitfalls();
+    report
+        .section("1️⃣ WHY Rc<RefCell<Node>> STRUGGLES:")
+        .example(
+            "After link(a, b)",
+            format!("a has {} strong ref, b has {} (a.next owns b; b.prev is Weak)", pitfalls.a_strong, pitfalls.b_strong),
+        )
+        .example("Walking back from b to a while a is borrowed: try_borrow_mut fails", pitfalls.second_borrow_fails)
+        .text("(borrow_mut() there would panic at runtime, not fail to compile)");
+
+    // ===== BASICS =====
+    let basics = report.section("2️⃣ RECENCY ORDER AND EVICTION:");
+    let mut cache = LruCache::new(3);
+    for (key, value) in [('a', 1), ('b', 2), ('c', 3)] {
+        cache.put(key, value);
+    }
+    basics.example("put a, b, c", format!("{:?} (most recent first)", keys(&cache)));
+    cache.get(&'a');
+    basics.example("get(a)", format!("{:?}", keys(&cache)));
+    cache.peek(&'b');
+    basics.example("peek(b) doesn't count", format!("{:?}", keys(&cache)));
+    let evicted = cache.put('d', 4);
+    basics.example("put(d) evicts", format!("{:?}, leaving {:?}", evicted, keys(&cache)));
+    let replaced = cache.put('a', 10);
+    basics.example("put(a, 10) replaces", format!("{:?}, leaving {:?}", replaced, keys(&cache)));
+    let removed = cache.remove(&'c');
+    basics.example(
+        "remove(c)",
+        format!("{:?}, least recent first {:?}", removed, cache.iter().rev().map(|(k, _)| *k).collect::<Vec<_>>()),
+    );
+
+    // ===== O(1) vs O(n) =====
+    let timings = report.section("3️⃣ O(1) VS THE O(n) MODEL:");
+    for capacity in [100, 10_000] {
+        let mut arena = LruCache::new(capacity);
+        let mut model = VecLru::new(capacity);
+        let start = Instant::now();
+        for n in 0..10_000u32 {
+            arena.put(n % (capacity as u32 * 2), n);
+            arena.get(&(n / 2));
+        }
+        let arena_time = start.elapsed();
+        let start = Instant::now();
+        for n in 0..10_000u32 {
+            model.put(n % (capacity as u32 * 2), n);
+            model.get(&(n / 2));
+        }
+        timings.example(
+            format!("capacity {:>6}", capacity),
+            format!("arena {:>10.2?}, Vec model {:>10.2?}", arena_time, start.elapsed()),
+        );
+    }
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 LRU CACHE SUMMARY:")
+        .check("HashMap for lookup + doubly linked list for recency = O(1) everything")
+        .check("Rc<RefCell> lists need Weak back-links and runtime borrow checks")
+        .check("An index arena gives the same links with no unsafe and no refcounts")
+        .check("A slow, obviously-correct model makes a strong test oracle");
+
+    report
+}
+
+pub fn demonstrate_lru_cache() {
+    print!("{}", lru_cache_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct LruCacheModule;
+
+impl LearningModule for LruCacheModule {
+    fn number(&self) -> u8 {
+        20
+    }
+
+    fn name(&self) -> &'static str {
+        "lru_cache"
+    }
+
+    fn title(&self) -> &'static str {
+        "LRU CACHE FROM SCRATCH"
+    }
+
+    fn description(&self) -> &'static str {
+        "LRU Cache: HashMap + Linked List"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["smart_pointers", "collections", "iterators"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_lru_cache() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { lru_cache_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&LruCacheModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// LINKED STRUCTURES IN RUST:
+// 1. Decide who owns each node before writing any code
+// 2. Rc<RefCell> is the direct translation - and trades compile-time checks
+//    for runtime ones
+// 3. Indexes into a Vec are pointers the borrow checker doesn't need to track
+// 4. Reuse freed slots so the arena stays bounded
+// 5. Test clever data structures against a dumb one
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_walks_through_recency_and_eviction() {
+        let report = lru_cache_report();
+        let basics = report.find("RECENCY ORDER").unwrap();
+        assert_eq!(basics.output("get(a)"), Some("['a', 'c', 'b']"));
+        assert_eq!(basics.output("peek(b) doesn't count"), Some("['a', 'c', 'b']"));
+        assert_eq!(basics.output("put(d) evicts"), Some("Some(('b', 2)), leaving ['d', 'a', 'c']"));
+        assert_eq!(report.find("Rc<RefCell").unwrap().output("Walking back from b to a while a is borrowed: try_borrow_mut fails"), Some("true"));
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = LruCache::new(2);
+        assert_eq!(cache.put("a", 1), None);
+        assert_eq!(cache.put("b", 2), None);
+        assert_eq!(cache.get(&"a"), Some(&1));
+        assert_eq!(cache.put("c", 3), Some(("b", 2)));
+        assert_eq!(keys(&cache), ["c", "a"]);
+        assert!(!cache.contains_key(&"b"));
+    }
+
+    #[test]
+    fn peek_and_get_mut() {
+        let mut cache = LruCache::new(2);
+        cache.put(1, String::from("one"));
+        cache.put(2, String::from("two"));
+        assert_eq!(cache.peek(&1).map(String::as_str), Some("one"));
+        assert_eq!(cache.pop_lru(), Some((1, String::from("one"))), "peek didn't refresh 1");
+
+        cache.put(3, String::from("three"));
+        cache.get_mut(&2).unwrap().push('!');
+        assert_eq!(cache.iter().map(|(k, v)| (*k, v.as_str())).collect::<Vec<_>>(), [(2, "two!"), (3, "three")]);
+    }
+
+    #[test]
+    fn freed_slots_are_reused() {
+        let mut cache = LruCache::new(3);
+        for round in 0..100 {
+            cache.put(round, round);
+            if round % 2 == 0 {
+                cache.remove(&round);
+            }
+        }
+        assert_eq!(cache.len(), 3);
+        assert!(cache.slots.len() <= 3, "arena grew to {}", cache.slots.len());
+    }
+
+    #[test]
+    fn zero_capacity_stores_nothing() {
+        let mut cache = LruCache::new(0);
+        assert_eq!(cache.put('x', 1), Some(('x', 1)));
+        assert!(cache.is_empty());
+        assert_eq!(cache.get(&'x'), None);
+    }
+
+    #[test]
+    fn rc_list_needs_weak_back_links() {
+        let pitfalls = rc_pitfalls();
+        assert_eq!((pitfalls.a_strong, pitfalls.b_strong), (1, 2));
+        assert!(pitfalls.second_borrow_fails);
+    }
+}
@@ -12,150 +12,43 @@
 // • Hygiene prevents variable name collisions
 // • Can generate repetitive code efficiently
 
-use colored::*;
+use crate::report::{Report, Section};
+use thiserror::Error;
+use mini_logger::{debug, error, info, log, warn};
 
-// ===== 1. BASIC LOG MACRO =====
+// ===== 1. LOG MACROS FROM A LIBRARY CRATE =====
 //
-// CORE LOG MACRO WITH PATTERN MATCHING:
-// This macro takes a log level and message, formats them with colors
-// and prints them with timestamp and location information
-macro_rules! log {
-    // PATTERN 1: Simple message string
-    ($level:ident, $message:expr) => {
-        {
-            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-            let file = file!();
-            let line = line!();
-            
-            // MATCH LOG LEVEL AND APPLY COLORS:
-            let (level_str, color_fn): (&str, fn(&str) -> ColoredString) = match stringify!($level) {
-                "info" => ("INFO", |s| s.blue()),
-                "warn" => ("WARN", |s| s.yellow()),
-                "error" => ("ERROR", |s| s.red()),
-                "debug" => ("DEBUG", |s| s.cyan()),
-                "trace" => ("TRACE", |s| s.magenta()),
-                _ => ("LOG", |s| s.white()),
-            };
-            
-            // PRINT FORMATTED LOG MESSAGE:
-            println!("[{}] {} {} - {} ({}:{})", 
-                timestamp.to_string().dimmed(),
-                color_fn(&format!("[{}]", level_str)),
-                $message,
-                "rust-basics".green(),
-                file,
-                line
-            );
-        }
-    };
-    
-    // PATTERN 2: Formatted message with arguments
-    ($level:ident, $format:expr, $($arg:expr),*) => {
-        log!($level, format!($format, $($arg),*))
-    };
-    
-    // PATTERN 3: Message with additional context
-    ($level:ident, $message:expr, context: $context:expr) => {
-        {
-            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-            let file = file!();
-            let line = line!();
-            
-            let (level_str, color_fn): (&str, fn(&str) -> ColoredString) = match stringify!($level) {
-                "info" => ("INFO", |s| s.blue()),
-                "warn" => ("WARN", |s| s.yellow()),
-                "error" => ("ERROR", |s| s.red()),
-                "debug" => ("DEBUG", |s| s.cyan()),
-                "trace" => ("TRACE", |s| s.magenta()),
-                _ => ("LOG", |s| s.white()),
-            };
-            
-            println!("[{}] {} {} | {} - {} ({}:{})", 
-                timestamp.to_string().dimmed(),
-                color_fn(&format!("[{}]", level_str)),
-                $message,
-                format!("Context: {}", $context).italic(),
-                "rust-basics".green(),
-                file,
-                line
-            );
-        }
-    };
-}
+// The log!, info!, warn!, error! and debug! macros used below started out in
+// this file and now live in the mini-logger workspace crate, so any crate can
+// use them. Exporting a macro_rules! macro takes two things:
+//
+// • #[macro_export] puts the macro at the crate root: mini_logger::info!
+// • Paths inside the expansion start with $crate, which always resolves to
+//   mini_logger - even in a crate that only imported the macro itself
+//
+// PATTERNS THE LIBRARY'S log! MATCHES (see mini-logger/src/macros.rs):
+//   log!(info, "User {} logged in", name)    format string + arguments
+//   log!(warn, some_value)                   anything that implements Display
+//   log!(error, "Failed", context: "db")     message with extra context
+//
+// The level is matched as an identifier and turned into mini_logger::Level at
+// compile time, so a typo like log!(inof, ...) is a compile error.
 
 // ===== 2. CONVENIENCE MACROS =====
 //
-// SPECIFIC LOG LEVEL MACROS:
-// These macros provide convenient shortcuts for common log levels
-// They all delegate to the main log! macro
-
-// INFO MACRO - for general information
-macro_rules! info {
-    ($message:expr) => {
-        log!(info, $message)
-    };
-    ($format:expr, $($arg:expr),*) => {
-        log!(info, $format, $($arg),*)
-    };
-    ($message:expr, context: $context:expr) => {
-        log!(info, $message, context: $context)
-    };
-}
-
-// ERROR MACRO - for error messages
-macro_rules! error {
-    ($message:expr) => {
-        log!(error, $message)
-    };
-    ($format:expr, $($arg:expr),*) => {
-        log!(error, $format, $($arg),*)
-    };
-    ($message:expr, context: $context:expr) => {
-        log!(error, $message, context: $context)
-    };
-}
-
-// WARN MACRO - for warnings
-macro_rules! warn {
-    ($message:expr) => {
-        log!(warn, $message)
-    };
-    ($format:expr, $($arg:expr),*) => {
-        log!(warn, $format, $($arg),*)
-    };
-    ($message:expr, context: $context:expr) => {
-        log!(warn, $message, context: $context)
-    };
-}
-
-// DEBUG MACRO - for debug information
-macro_rules! debug {
-    ($message:expr) => {
-        log!(debug, $message)
-    };
-    ($format:expr, $($arg:expr),*) => {
-        log!(debug, $format, $($arg),*)
-    };
-    ($message:expr, context: $context:expr) => {
-        log!(debug, $message, context: $context)
-    };
-}
+// info!(...) simply forwards to log!(info, ...). Forwarding uses $($arg:tt)+,
+// which passes tokens through untouched: once tokens are captured as $x:expr
+// they become one opaque expression and can no longer match a :literal
+// pattern in the macro they are handed to.
 
 // ===== 3. ADVANCED MACRO PATTERNS =====
 //
 // MACRO WITH REPETITION:
-// This macro can take multiple key-value pairs and format them
+// This macro can take multiple key-value pairs and forwards each one to
+// mini-logger as a structured field, so JSON output keeps them as separate keys
 macro_rules! log_with_fields {
-    ($level:ident, $message:expr, $($key:ident = $value:expr),*) => {
-        {
-            let mut fields = Vec::new();
-            $(
-                fields.push(format!("{}={}", stringify!($key), $value));
-            )*
-            
-            let fields_str = fields.join(", ");
-            log!($level, format!("{} [{}]", $message, fields_str));
-        }
+    ($level:ident, $message:expr, $($key:ident = $value:expr),+) => {
+        log!($level, $message; $($key = $value),+)
     };
 }
 
@@ -180,29 +73,24 @@ macro_rules! time_operation {
 // MACRO FOR CONDITIONAL LOGGING:
 // This macro only logs if a condition is true
 macro_rules! log_if {
-    ($condition:expr, $level:ident, $message:expr) => {
-        if $condition {
-            log!($level, $message);
-        }
-    };
-    ($condition:expr, $level:ident, $format:expr, $($arg:expr),*) => {
+    ($condition:expr, $level:ident, $($arg:tt)+) => {
         if $condition {
-            log!($level, $format, $($arg),*);
+            log!($level, $($arg)+);
         }
     };
 }
 
 // ===== 4. MACRO FOR DEBUGGING VALUES =====
 //
-// DBGLOG MACRO - prints variable names and values
+// DBGLOG MACRO - formats variable names and values into one line
 macro_rules! dbglog {
     ($($val:expr),*) => {
         {
-            print!("{} ", "[DEBUG]".cyan());
+            let mut line = String::from("[DEBUG]");
             $(
-                print!("{} = {:?} ", stringify!($val), $val);
+                line.push_str(&format!(" {} = {:?}", stringify!($val), $val));
             )*
-            println!();
+            line
         }
     };
 }
@@ -211,17 +99,17 @@ macro_rules! dbglog {
 //
 // FLEXIBLE LOG MACRO WITH OPTIONAL TIMESTAMP
 macro_rules! flexible_log {
-    // Without timestamp
+    // Without timestamp: the bare line, for the caller to show
     (no_time, $level:ident, $message:expr) => {
         {
-            let (level_str, color_fn): (&str, fn(&str) -> ColoredString) = match stringify!($level) {
-                "info" => ("INFO", |s| s.blue()),
-                "warn" => ("WARN", |s| s.yellow()),
-                "error" => ("ERROR", |s| s.red()),
-                _ => ("LOG", |s| s.white()),
+            let level_str = match stringify!($level) {
+                "info" => "INFO",
+                "warn" => "WARN",
+                "error" => "ERROR",
+                _ => "LOG",
             };
             
-            println!("{} {}", color_fn(&format!("[{}]", level_str)), $message);
+            format!("[{}] {}", level_str, $message)
         }
     };
     
@@ -233,168 +121,864 @@ macro_rules! flexible_log {
 
 // ===== 6. DEMONSTRATION FUNCTIONS =====
 //
+// Each demonstration adds its section to the module's Report (see
+// macros_report() below and report.rs). Most of what they show is log
+// output, so log_into() captures the records instead of printing them and
+// adds each one to the section as the line it would have printed.
+
+fn log_into<T>(section: &mut Section, f: impl FnOnce() -> T) -> T {
+    let format = mini_logger::format();
+    let mut output = None;
+    for record in mini_logger::capture(|| output = Some(f())) {
+        section.text(format.render(&record, false));
+    }
+    output.expect("capture ran the closure")
+}
+
 // FUNCTION TO DEMONSTRATE BASIC MACRO USAGE:
-pub fn demonstrate_basic_macros() {
-    println!("=== BASIC MACRO DEMONSTRATIONS ===");
+fn basic_macros_section(report: &mut Report) {
+    let section = report.section("1️⃣ BASIC MACRO DEMONSTRATIONS:");
     
-    // BASIC LOG MESSAGES:
-    info!("This is an info message");
-    warn!("This is a warning message");
-    error!("This is an error message");
-    debug!("This is a debug message");
+    log_into(section, || {
+        // BASIC LOG MESSAGES:
+        info!("This is an info message");
+        warn!("This is a warning message");
+        error!("This is an error message");
+        debug!("This is a debug message");
     
-    // FORMATTED MESSAGES:
-    info!("User {} logged in with ID {}", "Alice", 12345);
-    warn!("Memory usage at {}%", 85);
-    error!("Failed to connect to database after {} attempts", 3);
+        // FORMATTED MESSAGES:
+        info!("User {} logged in with ID {}", "Alice", 12345);
+        warn!("Memory usage at {}%", 85);
+        error!("Failed to connect to database after {} attempts", 3);
     
-    // MESSAGES WITH CONTEXT:
-    info!("Processing user request", context: "user_service");
-    error!("Database connection failed", context: "connection_pool");
+        // MESSAGES WITH CONTEXT:
+        info!("Processing user request", context: "user_service");
+        error!("Database connection failed", context: "connection_pool");
+    });
 }
 
 // FUNCTION TO DEMONSTRATE ADVANCED MACROS:
-pub fn demonstrate_advanced_macros() {
-    println!("\n=== ADVANCED MACRO DEMONSTRATIONS ===");
+fn advanced_macros_section(report: &mut Report) {
+    let section = report.section("2️⃣ ADVANCED MACRO DEMONSTRATIONS:");
     
     // MACRO WITH FIELDS:
-    log_with_fields!(info, "User action", user_id = 123, action = "login", ip = "192.168.1.1");
-    log_with_fields!(error, "Transaction failed", amount = 100.50, account = "ACC001", error_code = 500);
+    log_into(section, || {
+        log_with_fields!(info, "User action", user_id = 123, action = "login", ip = "192.168.1.1");
+        log_with_fields!(error, "Transaction failed", amount = 100.50, account = "ACC001", error_code = 500);
+    });
+
+    // SAME CALLS, JSON OUTPUT:
+    // The format is a runtime setting, so nothing at the call site changes
+    section.text("Same calls, JSON output:");
+    let previous = mini_logger::format();
+    mini_logger::set_format(mini_logger::Format::Json);
+    log_into(section, || {
+        log_with_fields!(info, "User action", user_id = 123, action = "login");
+        warn!("Disk usage at {}%", 91);
+        error!("Database connection failed", context: "connection_pool");
+    });
+    mini_logger::set_format(previous);
     
     // TIMING MACRO:
-    let result = time_operation!("Database Query", {
-        // Simulate some work
-        std::thread::sleep(std::time::Duration::from_millis(100));
-        "Query result"
+    log_into(section, || {
+        let result = time_operation!("Database Query", {
+            // Simulate some work
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            "Query result"
+        });
+        info!("Query returned: {}", result);
     });
-    info!("Query returned: {}", result);
     
     // CONDITIONAL LOGGING:
     let debug_mode = true;
     let user_count = 42;
     
-    log_if!(debug_mode, debug, "Debug mode is enabled");
-    log_if!(user_count > 50, warn, "High user count: {}", user_count);
-    log_if!(user_count > 100, error, "Critical user count: {}", user_count);
+    log_into(section, || {
+        log_if!(debug_mode, debug, "Debug mode is enabled");
+        log_if!(user_count > 50, warn, "High user count: {}", user_count);
+        log_if!(user_count > 100, error, "Critical user count: {}", user_count);
+    });
     
     // DEBUGGING VALUES:
     let x = 10;
     let y = 20;
     let name = "Rust";
-    dbglog!(x, y, name, x + y);
+    section.example("dbglog!(x, y, name, x + y)", dbglog!(x, y, name, x + y));
     
     // FLEXIBLE LOGGING:
-    flexible_log!(info, "This has a timestamp");
-    flexible_log!(no_time, warn, "This has no timestamp");
+    log_into(section, || flexible_log!(info, "This has a timestamp"));
+    section.example(
+        "flexible_log!(no_time, warn, \"This has no timestamp\")",
+        flexible_log!(no_time, warn, "This has no timestamp"),
+    );
 }
 
 // ===== 7. MACRO EXPANSION EXAMPLES =====
 //
 // FUNCTION TO SHOW WHAT MACROS EXPAND TO:
-pub fn demonstrate_macro_expansion() {
-    println!("\n=== MACRO EXPANSION EXAMPLES ===");
-    
-    // SIMPLE EXPANSION:
-    info!("Simple message");
-    
-    // FORMATTED EXPANSION:
-    info!("Formatted message: {}", "Hello World");
+// (the timed block binds its result first to show a multi-statement body)
+#[allow(clippy::let_and_return)]
+fn macro_expansion_section(report: &mut Report) {
+    let section = report.section("3️⃣ MACRO EXPANSION EXAMPLES:");
     
-    // CONTEXT EXPANSION:
-    info!("Message with context", context: "demo");
-    
-    // TIMING EXPANSION:
-    time_operation!("Simple calculation", {
-        let result = 2 + 2;
-        result
+    log_into(section, || {
+        // SIMPLE EXPANSION:
+        info!("Simple message");
+        
+        // FORMATTED EXPANSION:
+        info!("Formatted message: {}", "Hello World");
+        
+        // CONTEXT EXPANSION:
+        info!("Message with context", context: "demo");
+        
+        // TIMING EXPANSION:
+        time_operation!("Simple calculation", {
+            let result = 2 + 2;
+            result
+        });
     });
 }
 
 // ===== 8. MACRO BEST PRACTICES EXAMPLES =====
 //
 // FUNCTION DEMONSTRATING MACRO BEST PRACTICES:
-pub fn demonstrate_macro_best_practices() {
-    println!("\n=== MACRO BEST PRACTICES ===");
-    
-    // 1. CLEAR NAMING:
-    info!("Use descriptive names for macros");
+fn macro_best_practices_section(report: &mut Report) {
+    let section = report.section("4️⃣ MACRO BEST PRACTICES:");
     
-    // 2. CONSISTENT PATTERNS:
-    info!("Basic message");
-    info!("Formatted message: {}", "with args");
-    
-    // 3. PROPER ERROR HANDLING:
-    error!("Always handle errors gracefully");
-    
-    // 4. DOCUMENTATION:
-    debug!("Document your macros well");
-    
-    // 5. TESTING:
-    info!("Test macro expansion thoroughly");
+    log_into(section, || {
+        // 1. CLEAR NAMING:
+        info!("Use descriptive names for macros");
+        
+        // 2. CONSISTENT PATTERNS:
+        info!("Basic message");
+        info!("Formatted message: {}", "with args");
+        
+        // 3. PROPER ERROR HANDLING:
+        error!("Always handle errors gracefully");
+        
+        // 4. DOCUMENTATION:
+        debug!("Document your macros well");
+        
+        // 5. TESTING:
+        info!("Test macro expansion thoroughly");
+    });
 }
 
 // ===== 9. MACRO HYGIENE DEMONSTRATION =====
 //
 // MACRO THAT DEMONSTRATES HYGIENE:
+// Its `x` is a different variable from any `x` at the call site
 macro_rules! hygienic_macro {
     () => {
         {
             let x = "macro variable";
-            println!("Inside macro: {}", x);
             x
         }
     };
 }
 
-pub fn demonstrate_macro_hygiene() {
-    println!("\n=== MACRO HYGIENE DEMONSTRATION ===");
+fn macro_hygiene_section(report: &mut Report) {
+    let section = report.section("5️⃣ MACRO HYGIENE DEMONSTRATION:");
     
     let x = "function variable";
-    println!("Before macro: {}", x);
+    section.example("x before the macro", x);
     
     let macro_result = hygienic_macro!();
-    println!("After macro: {}", x);
-    println!("Macro returned: {}", macro_result);
+    section
+        .example("hygienic_macro!()", macro_result)
+        .example("x after the macro", x);
     
-    info!("Macro variables don't interfere with surrounding scope");
+    log_into(section, || info!("Macro variables don't interfere with surrounding scope"));
+}
+
+// ===== 9b. LEVEL FILTERING: COMPILE TIME VS RUNTIME =====
+//
+// mini-logger drops records below a minimum level in two places:
+//
+// COMPILE TIME (cargo feature "min-level-info" etc. → const STATIC_MIN_LEVEL):
+// • `level >= CONST` folds away, so disabled calls vanish from the binary
+// • Zero runtime cost - but changing it means rebuilding
+//
+// RUNTIME (set_level() or RUST_BASICS_LOG=warn → one global AtomicU8):
+// • Can be changed while the program runs, e.g. from a CLI flag
+// • Every call still pays for one atomic load and a comparison
+//
+// Either way the check happens BEFORE the message is formatted, so arguments
+// of a suppressed debug! are never evaluated - something a plain function
+// taking a String could not do.
+
+fn level_filtering_section(report: &mut Report) {
+    let section = report.section("6️⃣ LEVEL FILTERING:");
+
+    let previous = mini_logger::level();
+    section.example("mini_logger::STATIC_MIN_LEVEL", mini_logger::STATIC_MIN_LEVEL);
+
+    // RAISING THE RUNTIME LEVEL:
+    mini_logger::set_level(mini_logger::Level::Warn);
+    section.text(format!("Runtime level set to {} - debug!/info! below are skipped", mini_logger::level()));
+
+    let mut evaluated = 0;
+    let mut expensive_summary = || {
+        evaluated += 1;
+        "a very large report"
+    };
+    log_into(section, || {
+        debug!("Skipped: {}", expensive_summary());
+        info!("Also skipped: {}", expensive_summary());
+        warn!("Still shown: {}", expensive_summary());
+    });
+    section.text(format!("The message argument was evaluated {} time(s), not 3", evaluated));
+
+    // RESTORING IT:
+    mini_logger::set_level(previous);
+    log_into(section, || debug!("Back to {} - debug messages are visible again", previous));
+}
+
+// ===== 9c. LOGGING TO A ROTATING FILE =====
+//
+// mini_logger::FileSink (mini-logger/src/file.rs) appends lines to a file and
+// rotates it by size: app.log → app.log.1 → app.log.2 → deleted.
+//
+// TWO RUST IDEAS DO THE HEAVY LIFTING:
+// • Interior mutability: write_line takes &self, yet the BufWriter inside
+//   changes - an Arc<Mutex<..>> shared with a background flusher thread
+// • Drop-based flushing: dropping the sink stops that thread and writes out
+//   whatever is still buffered, so no explicit close() call is needed
+//
+// For the whole program's log output use `rust-basics --log-file logs/run.log`,
+// which installs one with mini_logger::log_to_file and keeps its guard in main.
+
+fn file_sink_section(report: &mut Report) {
+    let section = report.section("7️⃣ FILE OUTPUT WITH ROTATION:");
+
+    let dir = std::env::temp_dir().join(format!("rust-basics-logs-{}", std::process::id()));
+    let path = dir.join("demo.log");
+    let options = mini_logger::FileOptions { max_bytes: 120, max_files: 2, ..Default::default() };
+
+    {
+        let sink = match mini_logger::FileSink::open(&path, options) {
+            Ok(sink) => sink,
+            Err(err) => {
+                section.text(format!("⚠️ Could not open {}: {}", path.display(), err));
+                return;
+            }
+        };
+        for request in 1..=14 {
+            let _ = sink.write_line(&format!("request {} handled in {}ms", request, request * 7));
+        }
+        let on_disk = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        section.text(format!("Before drop: {} bytes of the newest file are on disk, the rest is buffered", on_disk));
+    } // ← sink dropped here: flusher thread stopped, buffer flushed
+
+    section.text(format!("After drop, {} holds the newest lines (max_files = 2):", dir.display()));
+    let mut files: Vec<_> = std::fs::read_dir(&dir)
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_default();
+    files.sort();
+    for file in &files {
+        let lines = std::fs::read_to_string(file).map(|text| text.lines().count()).unwrap_or(0);
+        let name = file.file_name().unwrap_or_default().to_string_lossy();
+        section.bullet(format!("{:<12} {} lines", name, lines));
+    }
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+// ===== 9d. PER-MODULE TARGETS =====
+//
+// Every record has a target: the module it was logged from (module_path!(),
+// e.g. "rust_basics::macros"), or whatever `target: "db"` says. Filters in the
+// env_logger style then pick a level per target:
+//
+//   RUST_BASICS_LOG=warn                         warn and above everywhere
+//   RUST_BASICS_LOG=smart_pointers=debug,traits=warn
+//                                                per-module levels
+//   RUST_BASICS_LOG=info,macros                  a bare name means "everything"
+//
+// Names match whole path segments, the crate name may be left out, and the
+// longest matching name wins.
+
+fn log_targets_section(report: &mut Report) {
+    let section = report.section("8️⃣ PER-MODULE TARGETS:");
+
+    let previous = mini_logger::filter();
+    let spec = "warn,macros=info,db=debug";
+    mini_logger::set_filter(spec.parse().expect("valid filter spec"));
+    section.text(format!("Filter: {}", spec));
+
+    log_into(section, || {
+        info!("Shown: this module's target is {}", module_path!());
+        debug!("Skipped: debug is below this module's info level");
+        debug!(target: "db", "Shown: the db target allows debug");
+        info!(target: "http", "Skipped: http has no directive, so the default (warn) applies");
+        warn!(target: "http", "Shown: warn passes the default");
+    });
+
+    mini_logger::set_filter(previous);
+}
+
+// ===== 9e. TT-MUNCHERS AND RECURSION =====
+//
+// UNDERSTANDING TT-MUNCHERS:
+// • A macro can call itself. A tt-muncher matches the first few tokens of
+//   its input, handles them, and recurses on the rest ($($rest:tt)*) until
+//   nothing is left
+// • That lets one macro accept input no single pattern could describe:
+//   mixed item shapes, optional parts, nested blocks
+// • Each step is one level of recursion - the default limit is 128, raised
+//   with #![recursion_limit = "256"] at the crate root
+//
+// PUSH-DOWN ACCUMULATION:
+// • A macro must expand to a complete item, expression or statement - it
+//   can't expand to half a vec![...] or a single match arm
+// • So the muncher carries what it has built so far in brackets,
+//   [$($out:tt)*], appends to it on every step, and emits the whole thing
+//   once the input is used up
+//
+// THE @internal RULE CONVENTION:
+// • Helper rules start with a marker like @parse - no real input starts with
+//   @, so users can't hit them by accident
+// • List them BEFORE the public rules: a catch-all like ($($t:tt)*) would
+//   otherwise swallow the internal calls too
+
+// COUNTING TOKENS, RECURSIVELY:
+// One token per step - simple, but 200 tokens need 200 levels of recursion
+macro_rules! count_recursive {
+    () => { 0usize };
+    ($head:tt $($tail:tt)*) => { 1usize + count_recursive!($($tail)*) };
+}
+
+// COUNTING TOKENS, WITHOUT RECURSION:
+// Every token becomes () and the slice's length is the count - one expansion
+// however long the input, and still usable in a const
+macro_rules! count_tts {
+    (@unit $t:tt) => { () };
+    ($($t:tt)*) => { <[()]>::len(&[$(count_tts!(@unit $t)),*]) };
+}
+
+// PARSING A KEY-VALUE LIST:
+// settings! { port = 8080, host = "localhost", verbose } - a bare key means
+// "true", which no single repetition pattern can express. Exported so the
+// compile-fail tests in tests/ui can call it as rust_basics::settings!
+#[macro_export]
+macro_rules! settings {
+    (@parse [$($out:tt)*]) => { vec![$($out)*] };
+    (@parse [$($out:tt)*] $key:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::settings!(@parse [$($out)* (stringify!($key), $value.to_string()),] $($($rest)*)?)
+    };
+    (@parse [$($out:tt)*] $key:ident $(, $($rest:tt)*)?) => {
+        $crate::settings!(@parse [$($out)* (stringify!($key), true.to_string()),] $($($rest)*)?)
+    };
+    // A key followed by anything but `=` or `,`: the error names what followed
+    (@parse [$($out:tt)*] $key:ident $bad:tt $($rest:tt)*) => {
+        compile_error!(concat!("expected `key = value` or `key`, found `", stringify!($bad), "`"))
+    };
+    (@parse [$($out:tt)*] $bad:tt $($rest:tt)*) => {
+        compile_error!(concat!("expected `key = value` or `key`, found `", stringify!($bad), "`"))
+    };
+    ($($input:tt)*) => { $crate::settings!(@parse [] $($input)*) };
+}
+
+// ❌ settings! { port: 8080 }
+//    error: expected `key = value` or `key`, found `:`
+
+// PUSH-DOWN ACCUMULATION IN ITS PUREST FORM:
+// Each step moves one token to the FRONT of the accumulator. It expands to
+// the text of each token, so the result can be looked at and tested
+macro_rules! reverse_tokens {
+    (@acc [$($reversed:tt)*]) => { [$(stringify!($reversed)),*] as [&str; _] };
+    (@acc [$($reversed:tt)*] $head:tt $($rest:tt)*) => {
+        reverse_tokens!(@acc [$head $($reversed)*] $($rest)*)
+    };
+    ($($input:tt)*) => { reverse_tokens!(@acc [] $($input)*) };
+}
+
+// BUILDING NESTED MATCH ARMS:
+// A table of path segments becomes nested `match segments.next()` blocks.
+// Arms are accumulated because a macro can't expand to a lone arm; a nested
+// { ... } table turns into a fresh muncher for the next segment
+//
+//   router!(path, { "users" => { "new" => A }, "health" => B })
+//
+// expands to roughly
+//
+//   let mut segments = path.split('/').filter(|s| !s.is_empty());
+//   match segments.next() {
+//       Some("users") => match segments.next() {
+//           Some("new") => match segments.next() { None => Some(A), Some(_) => None },
+//           _ => None,
+//       },
+//       Some("health") => match segments.next() { None => Some(B), Some(_) => None },
+//       _ => None,
+//   }
+macro_rules! router {
+    (@match $segments:ident [$($arms:tt)*]) => {
+        match $segments.next() {
+            $($arms)*
+            _ => None,
+        }
+    };
+    (@match $segments:ident [$($arms:tt)*] $segment:literal => { $($inner:tt)* } $(, $($rest:tt)*)?) => {
+        router!(@match $segments [
+            $($arms)*
+            Some($segment) => router!(@match $segments [] $($inner)*),
+        ] $($($rest)*)?)
+    };
+    (@match $segments:ident [$($arms:tt)*] $segment:literal => $handler:expr $(, $($rest:tt)*)?) => {
+        router!(@match $segments [
+            $($arms)*
+            // A leaf only matches if the path ends here
+            Some($segment) => match $segments.next() {
+                None => Some($handler),
+                Some(_) => None,
+            },
+        ] $($($rest)*)?)
+    };
+    ($path:expr, { $($routes:tt)* }) => {{
+        let mut segments = $path.split('/').filter(|segment| !segment.is_empty());
+        router!(@match segments [] $($routes)*)
+    }};
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Route {
+    ListUsers,
+    NewUser,
+    Health,
+}
+
+pub fn route(path: &str) -> Option<Route> {
+    router!(path, {
+        "users" => {
+            "list" => Route::ListUsers,
+            "new" => Route::NewUser,
+        },
+        "health" => Route::Health,
+    })
+}
+
+fn tt_munchers_section(report: &mut Report) {
+    let section = report.section("9️⃣ TT-MUNCHERS AND RECURSION:");
+
+    // COUNTING:
+    const FIELDS: usize = count_tts!(id name email created_at);
+    section
+        .example("count_recursive!(a b c d e)", count_recursive!(a b c d e))
+        .example("count_tts!(id name email created_at)", format!("{} (usable in a const)", FIELDS));
+
+    // KEY-VALUE PARSING:
+    let parsed = settings! { port = 8080, host = "localhost", verbose, retries = 2 + 1 };
+    for (key, value) in &parsed {
+        section.bullet(format!("setting {} = {}", key, value));
+    }
+
+    // PUSH-DOWN ACCUMULATION:
+    section.example("reverse_tokens!(let x = 1;)", reverse_tokens!(let x = 1;).join(" "));
+
+    // NESTED MATCH ARMS:
+    for path in ["/users/new", "/health", "/users", "/users/list/extra"] {
+        section.example(format!("route({:?})", path), format!("{:?}", route(path)));
+    }
+}
+
+// ===== 9f. A DECLARATIVE DSL: STATE MACHINES =====
+//
+// A macro can give a problem its own little language. state_machine! reads
+//
+//   pub machine Order {
+//       states { Pending, Paid, Shipped }
+//       initial Pending;
+//       events {
+//           pay: Pending => Paid,
+//           cancel: Pending | Paid => Cancelled,
+//       }
+//   }
+//
+// and generates:
+// • an enum with one variant per state, plus ALL, INITIAL, Default, Display
+// • one method per event that returns the next state, or an
+//   InvalidTransition error naming the event and the state it was fired in
+// • available_events() and is_terminal(), derived from the same table
+//
+// The compiler checks the table too: a typo in a state name is an unknown
+// variant, and a missing state in `initial` fails to compile.
+//
+// ❌ events { pay: Pendng => Paid }
+//    error[E0599]: no variant or associated item named `Pendng` found
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("cannot {event} while {from}")]
+pub struct InvalidTransition<S> {
+    pub from: S,
+    pub event: &'static str,
+}
+
+macro_rules! state_machine {
+    (
+        $(#[$meta:meta])*
+        $vis:vis machine $name:ident {
+            states { $($state:ident),+ $(,)? }
+            initial $initial:ident;
+            events { $($event:ident : $($from:ident)|+ => $to:ident),* $(,)? }
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($state),+
+        }
+
+        impl $name {
+            pub const ALL: [$name; count_tts!($($state)+)] = [$($name::$state),+];
+            pub const INITIAL: $name = $name::$initial;
+
+            $(
+                pub fn $event(self) -> Result<$name, $crate::macros::InvalidTransition<$name>> {
+                    // A catch-all after every state is unreachable; that's fine
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        $($name::$from)|+ => Ok($name::$to),
+                        from => Err($crate::macros::InvalidTransition { from, event: stringify!($event) }),
+                    }
+                }
+            )*
+
+            pub fn available_events(self) -> Vec<&'static str> {
+                let mut events = Vec::new();
+                $(
+                    if matches!(self, $($name::$from)|+) {
+                        events.push(stringify!($event));
+                    }
+                )*
+                events
+            }
+
+            pub fn is_terminal(self) -> bool {
+                self.available_events().is_empty()
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name::INITIAL
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let name = match self {
+                    $($name::$state => stringify!($state)),+
+                };
+                f.pad(name)
+            }
+        }
+    };
+}
+
+state_machine! {
+    /// The life of an order, from checkout to delivery or refund.
+    pub machine OrderState {
+        states { Pending, Paid, Shipped, Delivered, Cancelled, Refunded }
+        initial Pending;
+        events {
+            pay: Pending => Paid,
+            ship: Paid => Shipped,
+            deliver: Shipped => Delivered,
+            cancel: Pending | Paid => Cancelled,
+            refund: Cancelled | Delivered => Refunded,
+        }
+    }
+}
+
+fn state_machine_section(report: &mut Report) {
+    let section = report.section("🔟 A DECLARATIVE DSL: STATE MACHINES:");
+
+    section.example("OrderState::ALL", format!("{:?}", OrderState::ALL));
+    let mut order = OrderState::default();
+    for step in [OrderState::pay, OrderState::ship, OrderState::deliver] {
+        section.text(format!("{:<9} can: {}", order, order.available_events().join(", ")));
+        order = match step(order) {
+            Ok(next) => next,
+            Err(e) => {
+                section.text(format!("⚠️ {}", e));
+                return;
+            }
+        };
+    }
+    section.text(format!("{:<9} can: {}", order, order.available_events().join(", ")));
+
+    // INVALID TRANSITIONS ARE ERRORS, NOT PANICS:
+    let cancelled = match order.cancel() {
+        Ok(next) => format!("Cancelled: {}", next),
+        Err(e) => format!("⚠️ {}", e),
+    };
+    section.example("order.cancel()", cancelled);
+    let refunded = order.refund().expect("delivered orders can be refunded");
+    section.example("order.refund()?.is_terminal()", refunded.is_terminal());
+}
+
+// ===== 9g. GENERATING BUILDERS =====
+//
+// make_builder! takes an ordinary-looking struct definition and writes the
+// builder boilerplate for it:
+//
+//   make_builder! {
+//       pub struct ServerConfig {
+//           pub host: String,              ← required: build() fails without it
+//           pub port: u16 = 8080,          ← optional, with a default
+//       }
+//       builder ServerConfigBuilder;
+//       check |c: &ServerConfig| c.port != 0, "port must not be 0";
+//   }
+//
+// WHAT IT SHOWS:
+// • Repetition: one builder field, one setter and one build() line per field
+// • Nested metavariables: `$(= $default:expr)?` is an optional group inside
+//   the per-field repetition, and @default turns "has one / doesn't" into
+//   either the value or an early MissingField error
+// • Hygiene: the local build() declares (`config`) lives in the macro's own
+//   syntax context - a check can't reach it without being handed it
+//
+// ❌ check |_c: &ServerConfig| config.port != 0, "...";
+//    error[E0425]: cannot find value `config` in this scope
+//
+// WHAT IT CAN'T DO: macro_rules! can't glue identifiers together, so the
+// builder's name has to be spelled out (crates like `paste` work around it)
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BuildError {
+    #[error("missing required field `{0}`")]
+    MissingField(&'static str),
+    #[error("invalid configuration: {0}")]
+    Invalid(&'static str),
+}
+
+macro_rules! make_builder {
+    (@default $field:ident = $default:expr) => { $default };
+    (@default $field:ident) => {
+        return Err($crate::macros::BuildError::MissingField(stringify!($field)))
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($field_vis:vis $field:ident : $ty:ty $(= $default:expr)?),* $(,)?
+        }
+        builder $builder:ident;
+        $(check $check:expr, $message:literal;)*
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $($field_vis $field: $ty),*
+        }
+
+        #[derive(Debug, Default)]
+        $vis struct $builder {
+            $($field: Option<$ty>),*
+        }
+
+        impl $name {
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+        }
+
+        impl $builder {
+            $(
+                pub fn $field(mut self, value: impl Into<$ty>) -> Self {
+                    self.$field = Some(value.into());
+                    self
+                }
+            )*
+
+            pub fn build(self) -> Result<$name, $crate::macros::BuildError> {
+                let config = $name {
+                    $($field: match self.$field {
+                        Some(value) => value,
+                        None => make_builder!(@default $field $(= $default)?),
+                    }),*
+                };
+                $(
+                    if !($check)(&config) {
+                        return Err($crate::macros::BuildError::Invalid($message));
+                    }
+                )*
+                Ok(config)
+            }
+        }
+    };
+}
+
+make_builder! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServerConfig {
+        pub host: String,
+        pub port: u16 = 8080,
+        pub workers: usize = 4,
+        pub tls: bool = false,
+    }
+    builder ServerConfigBuilder;
+    check |c: &ServerConfig| c.port != 0, "port must not be 0";
+    check |c: &ServerConfig| (1..=64).contains(&c.workers), "workers must be between 1 and 64";
+    // Same name as build()'s local, but only the argument is visible here
+    check |config: &ServerConfig| !config.host.is_empty(), "host must not be empty";
+}
+
+// THE SAME BUILDER FROM A PROC-MACRO DERIVE (derive_builder):
+// A derive sees the parsed struct, so it names the builder and its error type
+// itself and reads per-field options from attributes. The price: syn and
+// darling in the build, and code you only see through cargo expand.
+//
+// DIFFERENCES WORTH KNOWING:
+// • Setters take &mut self by default; pattern = "owned" matches make_builder!
+// • Validation is one method on the builder, run BEFORE defaults are filled
+//   in, so it sees Option fields
+// • Errors are a generated ServerSettingsBuilderError: UninitializedField or
+//   ValidationError(String)
+
+#[derive(Debug, Clone, PartialEq, derive_builder::Builder)]
+#[builder(pattern = "owned", setter(into), build_fn(validate = "Self::validate"))]
+pub struct ServerSettings {
+    pub host: String,
+    #[builder(default = "8080")]
+    pub port: u16,
+    #[builder(default = "4")]
+    pub workers: usize,
+    #[builder(default)]
+    pub tls: bool,
+}
+
+impl ServerSettingsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.port {
+            Some(0) => Err("port must not be 0".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn builders_section(report: &mut Report) {
+    let section = report.section("1️⃣1️⃣ GENERATING BUILDERS:");
+
+    // make_builder!:
+    let built = match ServerConfig::builder().host("localhost").workers(8usize).build() {
+        Ok(config) => format!("{:?}", config),
+        Err(e) => format!("⚠️ {}", e),
+    };
+    section.example("ServerConfig::builder().host(\"localhost\").workers(8usize).build()", built);
+    for result in [ServerConfig::builder().port(443u16).build(), ServerConfig::builder().host("db").port(0u16).build()] {
+        if let Err(e) = result {
+            section.text(format!("make_builder! rejected: {}", e));
+        }
+    }
+
+    // derive_builder:
+    let built = match ServerSettingsBuilder::default().host("localhost").workers(8usize).build() {
+        Ok(settings) => format!("{:?}", settings),
+        Err(e) => format!("⚠️ {}", e),
+    };
+    section.example("ServerSettingsBuilder::default().host(\"localhost\").workers(8usize).build()", built);
+    if let Err(e) = ServerSettingsBuilder::default().port(443u16).build() {
+        section.text(format!("derive(Builder) rejected: {}", e));
+    }
 }
 
 // ===== 10. MAIN DEMONSTRATION FUNCTION =====
 //
-// FUNCTION TO RUN ALL DEMONSTRATIONS:
-pub fn demonstrate_macros() {
-    println!("🦀 RUST DECLARATIVE MACROS DEEP STUDY 🦀");
+// macros_report() runs every demonstration above into one Report (see
+// report.rs); demonstrate_macros() prints it.
+
+pub fn macros_report() -> Report {
+    let mut report = Report::new("🦀 RUST DECLARATIVE MACROS DEEP STUDY 🦀");
+    
+    basic_macros_section(&mut report);
+    advanced_macros_section(&mut report);
+    macro_expansion_section(&mut report);
+    macro_best_practices_section(&mut report);
+    macro_hygiene_section(&mut report);
+    level_filtering_section(&mut report);
+    file_sink_section(&mut report);
+    log_targets_section(&mut report);
+    tt_munchers_section(&mut report);
+    state_machine_section(&mut report);
+    builders_section(&mut report);
     
-    demonstrate_basic_macros();
-    demonstrate_advanced_macros();
-    demonstrate_macro_expansion();
-    demonstrate_macro_best_practices();
-    demonstrate_macro_hygiene();
+    report
+        .section("🎯 MACRO CONCEPTS SUMMARY:")
+        .check("Declarative macros use pattern matching")
+        .check("Macros expand at compile time")
+        .check("Multiple patterns can be matched")
+        .check("Repetition patterns with $()*")
+        .check("Hygiene prevents variable conflicts")
+        .check("Built-in macros: file!(), line!(), stringify!()")
+        .check("#[macro_export] + $crate share macros across crates")
+        .check("One log call, human or JSON output chosen at runtime")
+        .check("Macros can skip evaluating arguments of disabled log levels")
+        .check("Drop flushes buffered log files - no close() to forget")
+        .check("module_path!() gives every log call a target to filter on")
+        .check("tt-munchers recurse over their input a few tokens at a time")
+        .check("Accumulators build output a macro can't emit piece by piece")
+        .check("A small DSL can generate a whole type and its API")
+        .check("macro_rules! builders vs derives: no extra crate, but less insight");
     
-    println!("\n🎯 MACRO CONCEPTS SUMMARY:");
-    println!("✅ Declarative macros use pattern matching");
-    println!("✅ Macros expand at compile time");
-    println!("✅ Multiple patterns can be matched");
-    println!("✅ Repetition patterns with $()*");
-    println!("✅ Hygiene prevents variable conflicts");
-    println!("✅ Built-in macros: file!(), line!(), stringify!()");
+    report
+        .section("📊 MACRO USAGE PATTERNS:")
+        .bullet("Code generation and repetition")
+        .bullet("Domain-specific languages (DSLs)")
+        .bullet("Configuration and setup")
+        .bullet("Logging and debugging (try: rust-basics --log-format json run macros)")
+        .bullet("Testing utilities");
     
-    println!("\n📊 MACRO USAGE PATTERNS:");
-    println!("• Code generation and repetition");
-    println!("• Domain-specific languages (DSLs)");
-    println!("• Configuration and setup");
-    println!("• Logging and debugging");
-    println!("• Testing utilities");
+    report
+        .section("💡 MACRO BEST PRACTICES:")
+        .bullet("Keep macros simple and focused")
+        .bullet("Use descriptive names")
+        .bullet("Document macro behavior")
+        .bullet("Test macro expansion")
+        .bullet("Prefer functions when possible")
+        .bullet("Use cargo expand to debug");
     
-    println!("\n💡 MACRO BEST PRACTICES:");
-    println!("• Keep macros simple and focused");
-    println!("• Use descriptive names");
-    println!("• Document macro behavior");
-    println!("• Test macro expansion");
-    println!("• Prefer functions when possible");
-    println!("• Use cargo expand to debug");
+    report
+}
+
+pub fn demonstrate_macros() {
+    print!("{}", macros_report());
 }
 
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct MacrosModule;
+
+impl LearningModule for MacrosModule {
+    fn number(&self) -> u8 {
+        5
+    }
+
+    fn name(&self) -> &'static str {
+        "macros"
+    }
+
+    fn title(&self) -> &'static str {
+        "DECLARATIVE MACROS DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Declarative Macros"
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_macros() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { macros_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&MacrosModule) }
+
 // ===== KEY TAKEAWAYS =====
 //
 // DECLARATIVE MACRO CONCEPTS:
@@ -404,6 +988,7 @@ pub fn demonstrate_macros() {
 // 4. Multiple pattern arms for flexibility
 // 5. Repetition with $()*
 // 6. Built-in macros for meta-information
+// 7. Recursion and @internal rules for input no single pattern can describe
 //
 // WHEN TO USE MACROS:
 // • Reducing code duplication
@@ -416,4 +1001,130 @@ pub fn demonstrate_macros() {
 // • Use cargo expand to see generated code
 // • Add println! statements in macro arms
 // • Test with simple inputs first
-// • Use rust-analyzer for macro highlighting
\ No newline at end of file
+// • Use rust-analyzer for macro highlighting
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_captures_the_log_lines() {
+        let report = macros_report();
+        let basics = report.find("BASIC MACRO DEMONSTRATIONS").unwrap();
+        assert_eq!(basics.entries.len(), 9, "one line per log call");
+        assert!(basics.to_string().contains("[WARN] Memory usage at 85%"), "{}", basics);
+
+        let advanced = report.find("ADVANCED MACRO DEMONSTRATIONS").unwrap();
+        assert_eq!(advanced.output("dbglog!(x, y, name, x + y)"), Some("[DEBUG] x = 10 y = 20 name = \"Rust\" x + y = 30"));
+
+        // The macro's `x` did not shadow the function's
+        let hygiene = report.find("MACRO HYGIENE").unwrap();
+        assert_eq!(hygiene.output("hygienic_macro!()"), Some("macro variable"));
+        assert_eq!(hygiene.output("x after the macro"), Some("function variable"));
+
+        let filtering = report.find("LEVEL FILTERING").unwrap().to_string();
+        assert!(filtering.contains("evaluated 1 time(s)"), "{}", filtering);
+    }
+
+    #[test]
+    fn counts_tokens_both_ways() {
+        const EMPTY: usize = count_tts!();
+        const GROUPED: usize = count_tts!(a (b c) [d] { e f });
+        assert_eq!(EMPTY, 0);
+        assert_eq!(GROUPED, 4, "a group counts as one token tree");
+        assert_eq!(count_recursive!(a (b c) [d] { e f }), GROUPED);
+    }
+
+    #[test]
+    fn parses_mixed_key_value_lists() {
+        let parsed = settings! { port = 8080, verbose, host = "localhost", limit = 2 * 5, };
+        assert_eq!(
+            parsed,
+            [
+                ("port", "8080".to_string()),
+                ("verbose", "true".to_string()),
+                ("host", "localhost".to_string()),
+                ("limit", "10".to_string()),
+            ]
+        );
+        let empty: Vec<(&str, String)> = settings! {};
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn accumulator_expansion_is_reversed() {
+        assert_eq!(reverse_tokens!(a b c), ["c", "b", "a"]);
+        assert_eq!(reverse_tokens!(1 + (2 * 3)), ["(2 * 3)", "+", "1"]);
+        assert!(reverse_tokens!().is_empty());
+    }
+
+    #[test]
+    fn routes_through_nested_tables() {
+        assert_eq!(route("/users/list"), Some(Route::ListUsers));
+        assert_eq!(route("users/new/"), Some(Route::NewUser));
+        assert_eq!(route("/health"), Some(Route::Health));
+        assert_eq!(route("/users"), None, "a table is not a leaf");
+        assert_eq!(route("/health/extra"), None, "a leaf must end the path");
+        assert_eq!(route("/missing"), None);
+        assert_eq!(route("/"), None);
+    }
+
+    #[test]
+    fn state_machine_follows_the_table() {
+        let order = OrderState::default();
+        assert_eq!(order, OrderState::Pending);
+        assert_eq!(order.available_events(), ["pay", "cancel"]);
+
+        let delivered = order.pay().and_then(OrderState::ship).and_then(OrderState::deliver);
+        assert_eq!(delivered, Ok(OrderState::Delivered));
+        assert_eq!(OrderState::Paid.cancel(), Ok(OrderState::Cancelled));
+        assert_eq!(OrderState::Cancelled.refund(), Ok(OrderState::Refunded));
+    }
+
+    #[test]
+    fn invalid_transitions_name_the_event_and_state() {
+        let err = OrderState::Shipped.cancel().unwrap_err();
+        assert_eq!(err, InvalidTransition { from: OrderState::Shipped, event: "cancel" });
+        assert_eq!(err.to_string(), "cannot cancel while Shipped");
+        assert!(OrderState::Pending.ship().is_err());
+    }
+
+    #[test]
+    fn generated_constants_and_terminal_states() {
+        assert_eq!(OrderState::ALL.len(), 6);
+        assert_eq!(OrderState::INITIAL, OrderState::Pending);
+        let terminal: Vec<_> = OrderState::ALL.into_iter().filter(|state| state.is_terminal()).collect();
+        assert_eq!(terminal, [OrderState::Refunded]);
+        assert_eq!(format!("[{:>8}]", OrderState::Paid), "[    Paid]");
+    }
+
+    #[test]
+    fn builder_fills_defaults_and_requires_the_rest() {
+        let config = ServerConfig::builder().host("localhost").tls(true).build().unwrap();
+        assert_eq!(config, ServerConfig { host: "localhost".to_string(), port: 8080, workers: 4, tls: true });
+        assert_eq!(ServerConfig::builder().port(80u16).build(), Err(BuildError::MissingField("host")));
+    }
+
+    #[test]
+    fn builder_runs_every_check() {
+        let build = |port: u16, workers: usize, host: &str| {
+            ServerConfig::builder().host(host).port(port).workers(workers).build()
+        };
+        assert_eq!(build(0, 4, "a"), Err(BuildError::Invalid("port must not be 0")));
+        assert_eq!(build(80, 65, "a"), Err(BuildError::Invalid("workers must be between 1 and 64")));
+        assert_eq!(build(80, 4, ""), Err(BuildError::Invalid("host must not be empty")));
+        assert!(build(80, 64, "a").is_ok());
+    }
+
+    #[test]
+    fn derive_builder_matches_the_macro() {
+        let settings = ServerSettingsBuilder::default().host("localhost").build().unwrap();
+        let config = ServerConfig::builder().host("localhost").build().unwrap();
+        assert_eq!((settings.host, settings.port, settings.workers, settings.tls), (config.host, config.port, config.workers, config.tls));
+
+        let missing = ServerSettingsBuilder::default().build().unwrap_err();
+        assert!(matches!(missing, ServerSettingsBuilderError::UninitializedField("host")));
+        let invalid = ServerSettingsBuilder::default().host("a").port(0u16).build().unwrap_err();
+        assert!(matches!(invalid, ServerSettingsBuilderError::ValidationError(message) if message == "port must not be 0"));
+    }
+}
@@ -1,62 +1,404 @@
 // ===== RUST ECOSYSTEM LEARNING PROJECT =====
-// 
-// This project is organized into modules for learning different Rust concepts:
-// - traits: Deep study of Rust's trait system
-// - lifetimes: Deep study of Rust's lifetime system
-// - smart_pointers: Deep study of Rust's smart pointers (Box, Rc, RefCell, Arc)
-// - errors: Deep study of error handling with thiserror and anyhow
-// - macros: Deep study of declarative macros (macro_rules!)
-// - (future modules will be added here)
-
-mod traits;
-mod lifetimes;
-mod smart_pointers;
-mod errors;
-mod macros;
+//
+// Binary entry point: a small CLI for picking which demonstrations to run.
+// The modules themselves live in the library crate (src/lib.rs) and are
+// discovered through src/registry.rs.
+//
+// USAGE:
+//   rust-basics                    Run every module (same as `run --all`)
+//   rust-basics list               Show the available modules
+//   rust-basics run traits         Run one module (by name or number)
+//   rust-basics run 2 errors       Run several modules in the given order
+//   rust-basics run --all --quiet  Only the demonstrations, no banners/summary
+//   rust-basics tui                Interactive menu (see src/tui.rs)
+//   rust-basics check traits       Grade the exercises in exercises/traits/
+//   rust-basics quiz traits        Answer the module's multiple-choice quiz
+//   rust-basics progress           What you've run, solved and scored so far
+//   rust-basics --log-format json run macros   Log lines as JSON (see mini-logger)
+//   rust-basics --log-level warn run macros    Hide debug/info log lines
+//   RUST_BASICS_LOG=warn,macros=debug rust-basics run
+//                                  Per-module levels, env_logger style
+//   rust-basics --color never run macros       No ANSI colors (NO_COLOR=1 works too)
+//   rust-basics --log-file logs/run.log run    Also append log lines to a rotating file
+
+mod tui;
+
+use clap::{Args, Parser, Subcommand};
+use rust_basics::exercises::{self, Outcome};
+use rust_basics::progress::Progress;
+use rust_basics::quiz;
+use rust_basics::registry::{self, LearningModule};
+
+#[derive(Parser, Debug)]
+#[command(name = "rust-basics", version, about = "🦀 Rust ecosystem learning project")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Output format of log lines printed by the demonstrations: human or json
+    #[arg(long, global = true, value_name = "FORMAT", default_value = "human")]
+    log_format: mini_logger::Format,
+
+    /// Hide log lines less severe than LEVEL: trace, debug, info, warn or error.
+    /// Overrides the default from RUST_BASICS_LOG (e.g. "info,macros=debug"),
+    /// but not its per-module levels
+    #[arg(long, global = true, value_name = "LEVEL")]
+    log_level: Option<mini_logger::Level>,
+
+    /// When to use terminal colors: auto (only on a terminal, honoring NO_COLOR), always or never
+    #[arg(long, global = true, value_name = "WHEN", default_value = "auto")]
+    color: mini_logger::ColorMode,
+
+    /// Also append log lines to FILE, rotated at 1 MiB with 3 old files kept
+    #[arg(long, global = true, value_name = "FILE")]
+    log_file: Option<std::path::PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List the available learning modules
+    List,
+    /// Run one or more learning modules
+    Run(RunArgs),
+    /// Browse and run modules in an interactive terminal menu
+    Tui,
+    /// Grade your solutions to a module's exercises (see exercises/)
+    Check(CheckArgs),
+    /// Take a module's multiple-choice quiz
+    Quiz {
+        /// Module to be quizzed on, by name or number
+        #[arg(value_name = "MODULE", value_parser = parse_module)]
+        module: &'static dyn LearningModule,
+    },
+    /// Show your saved progress across modules, exercises and quizzes
+    Progress,
+}
+
+#[derive(Args, Debug, Default)]
+struct RunArgs {
+    /// Modules to run, by name (`smart_pointers` or `smart-pointers`) or number
+    #[arg(
+        value_name = "MODULE",
+        value_parser = parse_module,
+        required_unless_present = "all",
+        conflicts_with = "all"
+    )]
+    modules: Vec<&'static dyn LearningModule>,
+
+    /// Run every module in order
+    #[arg(short, long)]
+    all: bool,
+
+    /// Print only the module output: no banner, headings or progress summary
+    #[arg(short, long)]
+    quiet: bool,
+}
+
+#[derive(Args, Debug)]
+struct CheckArgs {
+    /// Modules whose exercises to grade, by name or number
+    #[arg(
+        value_name = "MODULE",
+        value_parser = parse_module,
+        required_unless_present = "all",
+        conflicts_with = "all"
+    )]
+    modules: Vec<&'static dyn LearningModule>,
+
+    /// Grade the exercises of every module
+    #[arg(short, long)]
+    all: bool,
+
+    /// Show compiler errors and failing test output
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+// USES: clap value_parser - unknown modules are rejected during parsing,
+// with the same error formatting and exit code as any other bad argument
+fn parse_module(query: &str) -> Result<&'static dyn LearningModule, String> {
+    registry::find(query).ok_or_else(|| {
+        format!("unknown module; expected a number or one of: {}", registry::names().join(", "))
+    })
+}
 
 #[tokio::main]
 async fn main() {
-    println!("🦀 RUST ECOSYSTEM LEARNING PROJECT 🦀\n");
-    
-    // Module 1: Traits System Deep Study
-    println!("📚 MODULE 1: TRAIT SYSTEM DEEP STUDY");
-    println!("=====================================");
-    traits::demonstrate_traits();
-    
-    println!("\n\n");
-    
-    // Module 2: Lifetimes Deep Study
-    println!("📚 MODULE 2: LIFETIMES DEEP STUDY");
-    println!("==================================");
-    lifetimes::demonstrate_lifetimes();
-    
-    println!("\n\n");
-    
-    // Module 3: Smart Pointers Deep Study
-    println!("📚 MODULE 3: SMART POINTERS DEEP STUDY");
-    println!("=======================================");
-    smart_pointers::demonstrate_smart_pointers();
-    
-    println!("\n\n");
-    
-    // Module 4: Error Handling Deep Study
-    println!("📚 MODULE 4: ERROR HANDLING DEEP STUDY");
-    println!("=======================================");
-    errors::demonstrate_error_handling();
-    errors::demonstrate_async_errors().await;
-    
-    println!("\n\n");
-    
-    // Module 5: Declarative Macros Deep Study
-    println!("📚 MODULE 5: DECLARATIVE MACROS DEEP STUDY");
-    println!("===========================================");
-    macros::demonstrate_macros();
-    
+    let cli = Cli::parse();
+    mini_logger::set_format(cli.log_format);
+    mini_logger::set_color_mode(cli.color);
+    // The demonstrations also print with the colored crate; keep it in step
+    colored::control::set_override(mini_logger::use_color());
+    if let Err(err) = mini_logger::init_from_env("RUST_BASICS_LOG") {
+        eprintln!("⚠️  Ignoring RUST_BASICS_LOG: {}", err);
+    }
+    if let Some(level) = cli.log_level {
+        mini_logger::set_level(level);
+    }
+    // Flushes the file when dropped at the end of main. std::process::exit
+    // below skips that, but those paths only follow commands that don't log.
+    let _log_file = cli.log_file.and_then(|path| {
+        mini_logger::log_to_file(&path, mini_logger::FileOptions::default())
+            .inspect_err(|err| eprintln!("⚠️  Not logging to {}: {}", path.display(), err))
+            .ok()
+    });
+
+    match cli.command {
+        Some(Command::List) => list_modules(),
+        Some(Command::Run(args)) => run_modules(args).await,
+        Some(Command::Tui) => {
+            if let Err(err) = tui::run() {
+                eprintln!("❌ TUI error: {}", err);
+                std::process::exit(1);
+            }
+        }
+        Some(Command::Check(args)) => {
+            if !check_exercises(args) {
+                std::process::exit(1);
+            }
+        }
+        Some(Command::Quiz { module }) => take_quiz(module),
+        Some(Command::Progress) => show_progress(),
+        None => run_modules(RunArgs { all: true, ..RunArgs::default() }).await,
+    }
+}
+
+fn list_modules() {
+    println!("🦀 RUST ECOSYSTEM LEARNING PROJECT - MODULES\n");
+    for module in registry::modules() {
+        println!(
+            "{:>3}. {:<16} {} ({})",
+            module.number(),
+            module.name(),
+            module.description(),
+            module.file()
+        );
+        if !module.prerequisites().is_empty() {
+            println!("     {:<16} builds on: {}", "", module.prerequisites().join(", "));
+        }
+    }
+    println!("\nRun one with: rust-basics run <name|number>");
+}
+
+async fn run_modules(args: RunArgs) {
+    let selected: Vec<&dyn LearningModule> = if args.all {
+        registry::modules().to_vec()
+    } else {
+        args.modules
+    };
+
+    if !args.quiet {
+        println!("🦀 RUST ECOSYSTEM LEARNING PROJECT 🦀\n");
+    }
+
+    for (i, module) in selected.iter().enumerate() {
+        if i > 0 {
+            println!("\n\n");
+        }
+        if !args.quiet {
+            let heading = module.heading();
+            println!("{}", heading);
+            // The 📚 emoji renders two columns wide
+            println!("{}", "=".repeat(heading.chars().count() + 1));
+            // Running a module on its own: point at what it builds on
+            if !args.all && !module.prerequisites().is_empty() {
+                println!("💡 Builds on: {}\n", module.prerequisites().join(", "));
+            }
+        }
+        module.run().await;
+    }
+    save_progress(|progress| {
+        for module in &selected {
+            progress.record_run(module.name());
+        }
+    });
+
+    if args.quiet {
+        return;
+    }
     println!("\n\n🎯 LEARNING PROGRESS:");
-    println!("✅ Module 1: Trait System (traits.rs)");
-    println!("✅ Module 2: Lifetimes (lifetimes.rs)");
-    println!("✅ Module 3: Smart Pointers (smart_pointers.rs)");
-    println!("✅ Module 4: Error Handling (errors.rs)");
-    println!("✅ Module 5: Declarative Macros (macros.rs)");
-    println!("⏳ More modules coming soon...");
+    for module in &selected {
+        println!("✅ Module {}: {} ({})", module.number(), module.description(), module.file());
+    }
+    if args.all {
+        println!("⏳ More modules coming soon...");
+    }
+}
+
+// Returns true when every graded exercise passes
+fn check_exercises(args: CheckArgs) -> bool {
+    let selected: Vec<&dyn LearningModule> = if args.all {
+        registry::modules().to_vec()
+    } else {
+        args.modules
+    };
+
+    let mut total = 0;
+    let mut passing = 0;
+    for module in selected {
+        println!("📝 Module {}: {}", module.number(), module.description());
+        for exercise in exercises::for_module(module.name()) {
+            total += 1;
+            let outcome = exercises::check(exercise);
+            let (icon, summary) = match &outcome {
+                Outcome::Passed { tests } => ("✅", format!("{} tests passed", tests)),
+                Outcome::Failed { passed, failed, .. } => (
+                    "❌",
+                    format!("{}/{} tests passed", passed, passed + failed),
+                ),
+                Outcome::NotAttempted { todos } => (
+                    "⏳",
+                    format!("not attempted yet ({} todo!() left)", todos),
+                ),
+                Outcome::CompileError { .. } => ("🔧", "does not compile".to_string()),
+                Outcome::Unavailable(reason) => ("⚠️", format!("could not be graded: {}", reason)),
+            };
+            println!("   {} {:<16} {}", icon, exercise.name, summary);
+            if !matches!(outcome, Outcome::Unavailable(_)) {
+                save_progress(|progress| {
+                    progress.record_exercise(module.name(), exercise.name, outcome.is_passed())
+                });
+            }
+
+            if outcome.is_passed() {
+                passing += 1;
+                continue;
+            }
+            println!("      📄 {}", exercise.path().display());
+            if matches!(outcome, Outcome::Failed { .. } | Outcome::CompileError { .. }) {
+                println!("      💡 Hint: {}", exercise.hint);
+            }
+            if args.verbose
+                && let Outcome::Failed { output, .. } | Outcome::CompileError { output } = &outcome
+            {
+                for line in output.lines() {
+                    println!("      │ {}", line);
+                }
+            }
+        }
+    }
+
+    println!("\n🎯 {}/{} exercises passing", passing, total);
+    if passing < total && !args.verbose {
+        println!("Run with --verbose to see compiler errors and failing tests.");
+    }
+    passing == total
+}
+
+fn take_quiz(module: &dyn LearningModule) {
+    let quiz = match quiz::load(module.name()) {
+        Ok(quiz) => quiz,
+        Err(err) => {
+            eprintln!("❌ {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    println!("🧠 QUIZ: {}", module.heading());
+    println!("{} questions - answer with the letter of your choice.", quiz.questions.len());
+
+    let stdin = std::io::stdin();
+    let result = match quiz::run_interactive(&quiz, &mut stdin.lock(), &mut std::io::stdout()) {
+        Ok(result) => result,
+        Err(err) => {
+            eprintln!("❌ Quiz interrupted: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    println!("\n🎯 Score: {}/{}", result.score, result.total);
+    if !result.finished() {
+        println!("Quiz not finished ({} of {} answered) - score not saved.", result.answered, result.total);
+        return;
+    }
+    let mut new_best = false;
+    let saved = save_progress(|progress| {
+        new_best = progress.record_quiz(module.name(), result.score, result.total);
+    });
+    match saved.as_ref().and_then(|progress| progress.quiz(module.name())) {
+        Some(_) if new_best => println!("🏆 New best score saved!"),
+        Some(record) => println!("Best so far: {}/{} after {} attempts", record.best, record.total, record.attempts),
+        None => {}
+    }
+}
+
+fn show_progress() {
+    let path = Progress::default_path();
+    let progress = match Progress::load(&path) {
+        Ok(progress) => progress,
+        Err(err) => {
+            eprintln!("❌ {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    println!("🎯 LEARNING PROGRESS ({})\n", path.display());
+    println!("{:>3}  {:<16} {:<5} {:<10} quiz", "#", "module", "run", "exercises");
+    let (mut run, mut solved, mut exercises_total, mut quizzes) = (0, 0, 0, 0);
+    for module in registry::modules() {
+        let exercise_count = exercises::for_module(module.name()).len();
+        let passed = progress.exercises_passed_in(module.name());
+        let quiz = progress
+            .quiz(module.name())
+            .map(|record| format!("{}/{} ({} attempts)", record.best, record.total, record.attempts))
+            .unwrap_or_else(|| "-".to_string());
+
+        run += progress.has_run(module.name()) as usize;
+        solved += passed;
+        exercises_total += exercise_count;
+        quizzes += progress.quiz(module.name()).is_some() as usize;
+
+        println!(
+            "{:>3}. {:<16} {} {:<10} {}",
+            module.number(),
+            module.name(),
+            // Padded by hand: ✅ is one char but two columns wide
+            if progress.has_run(module.name()) { "✅   " } else { "·    " },
+            format!("{}/{}", passed, exercise_count),
+            quiz
+        );
+    }
+
+    let modules = registry::modules().len();
+    println!(
+        "\nModules run: {}/{} · Exercises solved: {}/{} · Quizzes taken: {}/{}",
+        run, modules, solved, exercises_total, quizzes, modules
+    );
+}
+
+// Progress is a convenience - failing to save it must not fail the command
+fn save_progress(change: impl FnOnce(&mut Progress)) -> Option<Progress> {
+    match Progress::update(&Progress::default_path(), change) {
+        Ok(progress) => Some(progress),
+        Err(err) => {
+            eprintln!("⚠️ Progress not saved: {}", err);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn run_requires_modules_or_all() {
+        assert!(Cli::try_parse_from(["rust-basics", "run"]).is_err());
+        assert!(Cli::try_parse_from(["rust-basics", "run", "traits", "--all"]).is_err());
+        assert!(Cli::try_parse_from(["rust-basics", "run", "nope"]).is_err());
+
+        let cli = Cli::try_parse_from(["rust-basics", "run", "traits", "8", "--quiet"]).unwrap();
+        let Some(Command::Run(args)) = cli.command else {
+            panic!("expected the run subcommand");
+        };
+        let names: Vec<_> = args.modules.iter().map(|m| m.name()).collect();
+        assert_eq!(names, ["traits", "collections"]);
+        assert!(args.quiet);
+    }
 }
@@ -0,0 +1,511 @@
+// ===== TCP NETWORKING DEEP STUDY =====
+//
+// TCP IS A BYTE STREAM, NOT A MESSAGE QUEUE:
+// Two write() calls of 5 bytes can arrive as one read() of 10 bytes, or as
+// reads of 3 and 7. The receiver can't tell where one message ends unless
+// the protocol says so. That's FRAMING:
+// • Delimiters: "one message per line" (HTTP headers, Redis inline)
+// • Length prefixes: "4 bytes of length, then that many bytes" (used here)
+//
+// WIRE FORMAT:
+//   ┌───────────────┬──────────────────────────┐
+//   │ length: u32 BE│ payload: `length` bytes  │  ... next frame ...
+//   └───────────────┴──────────────────────────┘
+//
+// The same protocol is implemented twice below:
+// • Blocking std::net: a thread per connection, simple and fine for a few
+//   hundred connections
+// • Async tokio::net: a task per connection, for many thousands
+// The frames are byte-for-byte identical, so either client talks to either
+// server (tests/networking_integration.rs checks that).
+
+use crate::report::{Report, Section};
+use mini_logger::warn;
+use std::io::{self, BufReader, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+
+// ===== 1. LENGTH-PREFIXED FRAMES =====
+//
+// UNDERSTANDING THE DETAILS:
+// • A limit on the length: without one, a peer sending 0xFFFFFFFF makes us
+//   allocate 4 GiB
+// • EOF before a frame starts is a clean close; EOF in the middle of one is
+//   an error (UnexpectedEof)
+// • Header and payload go out in ONE write: two small writes can trip over
+//   Nagle's algorithm and delayed ACKs and stall for ~40ms
+
+pub const MAX_FRAME_LEN: usize = 1 << 20;
+
+fn too_large(len: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("frame of {} bytes exceeds the {} byte limit", len, MAX_FRAME_LEN),
+    )
+}
+
+pub fn encode_frame(payload: &[u8]) -> io::Result<Vec<u8>> {
+    if payload.len() > MAX_FRAME_LEN {
+        return Err(too_large(payload.len()));
+    }
+    let mut frame = Vec::with_capacity(4 + payload.len());
+    frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    frame.extend_from_slice(payload);
+    Ok(frame)
+}
+
+fn payload_len(header: [u8; 4]) -> io::Result<usize> {
+    let len = u32::from_be_bytes(header) as usize;
+    if len > MAX_FRAME_LEN { Err(too_large(len)) } else { Ok(len) }
+}
+
+pub fn write_frame<W: Write>(writer: &mut W, payload: &[u8]) -> io::Result<()> {
+    writer.write_all(&encode_frame(payload)?)
+}
+
+// Ok(None) when the stream ends cleanly between frames
+pub fn read_frame<R: Read>(reader: &mut R) -> io::Result<Option<Vec<u8>>> {
+    let mut header = [0u8; 4];
+    let mut filled = 0;
+    while filled < header.len() {
+        match reader.read(&mut header[filled..]) {
+            Ok(0) if filled == 0 => return Ok(None),
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    let mut payload = vec![0; payload_len(header)?];
+    reader.read_exact(&mut payload)?;
+    Ok(Some(payload))
+}
+
+// The async versions are the same code with .await - AsyncReadExt and
+// AsyncWriteExt mirror the std methods
+pub async fn write_frame_async<W: AsyncWrite + Unpin>(writer: &mut W, payload: &[u8]) -> io::Result<()> {
+    writer.write_all(&encode_frame(payload)?).await
+}
+
+pub async fn read_frame_async<R: AsyncRead + Unpin>(reader: &mut R) -> io::Result<Option<Vec<u8>>> {
+    let mut header = [0u8; 4];
+    let mut filled = 0;
+    while filled < header.len() {
+        match reader.read(&mut header[filled..]).await? {
+            0 if filled == 0 => return Ok(None),
+            0 => return Err(io::ErrorKind::UnexpectedEof.into()),
+            n => filled += n,
+        }
+    }
+    let mut payload = vec![0; payload_len(header)?];
+    reader.read_exact(&mut payload).await?;
+    Ok(Some(payload))
+}
+
+// ===== 2. BLOCKING ECHO SERVER (std::net) =====
+//
+// UNDERSTANDING GRACEFUL SHUTDOWN, THREAD EDITION:
+// • accept() blocks and has no "cancel" - the classic trick is to set a flag
+//   and then connect to ourselves, so accept() returns and sees the flag
+// • Connections already accepted run to completion: shutdown() joins their
+//   threads. A client that never hangs up would block it forever, which is
+//   what read timeouts (set_read_timeout) are for
+//
+// UNDERSTANDING HALF-CLOSE:
+// stream.shutdown(Shutdown::Write) sends FIN: "I'm done sending" - but the
+// socket can still READ. The echo client uses it to say "no more requests"
+// and then reads replies until the server closes its side too.
+
+pub struct EchoServer {
+    addr: SocketAddr,
+    stopping: Arc<AtomicBool>,
+    accept_loop: thread::JoinHandle<usize>,
+}
+
+// Echoes frames until the client half-closes; returns how many
+fn echo_connection(stream: TcpStream) -> io::Result<usize> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    let mut frames = 0;
+    while let Some(frame) = read_frame(&mut reader)? {
+        write_frame(&mut writer, &frame)?;
+        frames += 1;
+    }
+    writer.shutdown(Shutdown::Write)?;
+    Ok(frames)
+}
+
+// Bind to port 0 to let the OS pick a free port; addr() tells you which
+pub fn spawn_echo_server(addr: impl ToSocketAddrs) -> io::Result<EchoServer> {
+    let listener = TcpListener::bind(addr)?;
+    let addr = listener.local_addr()?;
+    let stopping = Arc::new(AtomicBool::new(false));
+    let accept_loop = thread::spawn({
+        let stopping = Arc::clone(&stopping);
+        move || {
+            let mut connections = Vec::new();
+            for stream in listener.incoming() {
+                if stopping.load(Ordering::SeqCst) {
+                    break;
+                }
+                match stream {
+                    Ok(stream) => connections.push(thread::spawn(move || echo_connection(stream))),
+                    Err(e) => warn!("accept failed: {}", e),
+                }
+            }
+            connections
+                .into_iter()
+                .map(|connection| match connection.join() {
+                    Ok(Ok(frames)) => frames,
+                    Ok(Err(e)) => {
+                        warn!("connection failed: {}", e);
+                        0
+                    }
+                    Err(_) => 0,
+                })
+                .sum()
+        }
+    });
+    Ok(EchoServer { addr, stopping, accept_loop })
+}
+
+impl EchoServer {
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    // Stops accepting, waits for open connections, returns frames echoed
+    pub fn shutdown(self) -> usize {
+        self.stopping.store(true, Ordering::SeqCst);
+        // Wake the blocked accept(); if this fails the listener is gone anyway
+        let _ = TcpStream::connect(self.addr);
+        self.accept_loop.join().unwrap_or(0)
+    }
+}
+
+// Sends every message, half-closes, then collects the replies. Sending
+// everything before reading is fine for a few messages - with megabytes,
+// both sides could fill their buffers and wait on each other forever
+pub fn echo_blocking(addr: impl ToSocketAddrs, messages: &[&str]) -> io::Result<Vec<String>> {
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    for message in messages {
+        write_frame(&mut stream, message.as_bytes())?;
+    }
+    stream.shutdown(Shutdown::Write)?;
+
+    let mut reader = BufReader::new(stream);
+    let mut replies = Vec::new();
+    while let Some(frame) = read_frame(&mut reader)? {
+        replies.push(String::from_utf8_lossy(&frame).into_owned());
+    }
+    Ok(replies)
+}
+
+// ===== 3. ASYNC ECHO SERVER (tokio::net) =====
+//
+// UNDERSTANDING GRACEFUL SHUTDOWN, TASK EDITION:
+// • select! between accept() and token.cancelled() - no self-connect trick
+// • Connection tasks watch the same CancellationToken: they finish the
+//   frame in hand, then shut down their write half so the client sees a
+//   clean EOF instead of a reset
+// • TaskTracker (tokio-util) waits for every connection task, the way the
+//   blocking server joins its threads
+// • read_frame_async isn't cancel-safe (a half-read frame is lost when the
+//   token wins the select) - fine here, because the connection closes next
+
+pub struct AsyncEchoServer {
+    addr: SocketAddr,
+    token: CancellationToken,
+    accept_loop: tokio::task::JoinHandle<usize>,
+}
+
+async fn echo_connection_async(stream: tokio::net::TcpStream, token: CancellationToken) -> io::Result<usize> {
+    let (mut reader, mut writer) = stream.into_split();
+    let mut frames = 0;
+    loop {
+        let frame = tokio::select! {
+            biased;
+            _ = token.cancelled() => break,
+            frame = read_frame_async(&mut reader) => frame?,
+        };
+        let Some(frame) = frame else { break };
+        write_frame_async(&mut writer, &frame).await?;
+        frames += 1;
+    }
+    writer.shutdown().await?;
+    Ok(frames)
+}
+
+pub async fn spawn_echo_server_async(addr: impl tokio::net::ToSocketAddrs) -> io::Result<AsyncEchoServer> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    let addr = listener.local_addr()?;
+    let token = CancellationToken::new();
+    let accept_loop = tokio::spawn({
+        let token = token.clone();
+        async move {
+            let tracker = TaskTracker::new();
+            let frames = Arc::new(AtomicUsize::new(0));
+            loop {
+                tokio::select! {
+                    biased;
+                    _ = token.cancelled() => break,
+                    accepted = listener.accept() => match accepted {
+                        Ok((stream, _)) => {
+                            let (token, frames) = (token.clone(), Arc::clone(&frames));
+                            tracker.spawn(async move {
+                                match echo_connection_async(stream, token).await {
+                                    Ok(echoed) => {
+                                        frames.fetch_add(echoed, Ordering::Relaxed);
+                                    }
+                                    Err(e) => warn!("connection failed: {}", e),
+                                }
+                            });
+                        }
+                        Err(e) => warn!("accept failed: {}", e),
+                    },
+                }
+            }
+            tracker.close();
+            tracker.wait().await;
+            frames.load(Ordering::Relaxed)
+        }
+    });
+    Ok(AsyncEchoServer { addr, token, accept_loop })
+}
+
+impl AsyncEchoServer {
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    // Stops accepting, lets each connection finish its current frame and
+    // close, and returns the frames echoed
+    pub async fn shutdown(self) -> usize {
+        self.token.cancel();
+        self.accept_loop.await.unwrap_or(0)
+    }
+}
+
+pub async fn echo_async(addr: impl tokio::net::ToSocketAddrs, messages: &[&str]) -> io::Result<Vec<String>> {
+    let stream = tokio::net::TcpStream::connect(addr).await?;
+    let (mut reader, mut writer) = stream.into_split();
+    for message in messages {
+        write_frame_async(&mut writer, message.as_bytes()).await?;
+    }
+    // Half-close: the async spelling of Shutdown::Write
+    writer.shutdown().await?;
+
+    let mut replies = Vec::new();
+    while let Some(frame) = read_frame_async(&mut reader).await? {
+        replies.push(String::from_utf8_lossy(&frame).into_owned());
+    }
+    Ok(replies)
+}
+
+// ===== 4. DEMONSTRATION FUNCTION =====
+
+fn blocking_examples(section: &mut Section) -> io::Result<()> {
+    let server = spawn_echo_server("127.0.0.1:0")?;
+    section
+        .text(format!("Blocking server listening on {}", server.addr()))
+        .example("Client 1 got", format!("{:?}", echo_blocking(server.addr(), &["hello", "over", "tcp"])?))
+        .example("Client 2 got", format!("{:?}", echo_blocking(server.addr(), &["a message with spaces", ""])?))
+        .example("shutdown()", format!("{} frames echoed", server.shutdown()));
+    Ok(())
+}
+
+async fn async_examples(section: &mut Section) -> io::Result<()> {
+    let server = spawn_echo_server_async("127.0.0.1:0").await?;
+    section.text(format!("Async server listening on {}", server.addr()));
+    let clients: Vec<_> = (1..=3)
+        .map(|n| {
+            let addr = server.addr();
+            tokio::spawn(async move { echo_async(addr, &[&format!("client {}", n), "bye"]).await })
+        })
+        .collect();
+    for client in clients {
+        match client.await {
+            Ok(replies) => section.example("Async client got", format!("{:?}", replies)),
+            Err(e) => section.example("Client task failed", e),
+        };
+    }
+
+    // A client left open: shutdown closes it cleanly instead of waiting
+    let idle = tokio::net::TcpStream::connect(server.addr()).await?;
+    let (mut idle_reader, mut idle_writer) = idle.into_split();
+    write_frame_async(&mut idle_writer, b"still here").await?;
+    section.example("Idle client got", format!("{:?}", read_frame_async(&mut idle_reader).await?.map(String::from_utf8)));
+    section.example("shutdown()", format!("{} frames echoed", server.shutdown().await));
+    section.example("Idle client then reads", format!("{:?} (clean EOF)", read_frame_async(&mut idle_reader).await?));
+    Ok(())
+}
+
+pub async fn networking_report() -> Report {
+    let mut report = Report::new("🦀 RUST TCP NETWORKING DEEP STUDY 🦀");
+
+    // ===== FRAMING =====
+    let framing = report.section("1️⃣ LENGTH-PREFIXED FRAMES:");
+    if let Ok(frame) = encode_frame(b"hi") {
+        framing.example(r#"encode_frame(b"hi")"#, format!("{:?}", frame));
+    }
+    let mut stream = Vec::new();
+    for word in ["one", "two"] {
+        let _ = write_frame(&mut stream, word.as_bytes());
+    }
+    let mut stream = &stream[..];
+    while let Ok(Some(frame)) = read_frame(&mut stream) {
+        framing.example("read_frame", format!("{:?}", String::from_utf8_lossy(&frame)));
+    }
+    let oversized = (MAX_FRAME_LEN as u32 + 1).to_be_bytes();
+    framing.example("Oversized header", format!("{:?}", read_frame(&mut &oversized[..]).map_err(|e| e.to_string())));
+
+    // ===== BLOCKING =====
+    let blocking = report.section("2️⃣ BLOCKING SERVER AND CLIENT (std::net):");
+    if let Err(e) = blocking_examples(blocking) {
+        blocking.text(format!("❌ Blocking demo failed: {}", e));
+    }
+
+    // ===== ASYNC =====
+    let asynchronous = report.section("3️⃣ ASYNC SERVER AND CLIENT (tokio::net):");
+    if let Err(e) = async_examples(asynchronous).await {
+        asynchronous.text(format!("❌ Async demo failed: {}", e));
+    }
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 NETWORKING SUMMARY:")
+        .check("TCP is a byte stream - framing puts message boundaries back")
+        .check("Length prefixes need a size limit and one write per frame")
+        .check("Blocking: a thread per connection, a self-connect to stop accept()")
+        .check("Async: a task per connection, select! on a CancellationToken")
+        .check("Half-close (Shutdown::Write) says \"done sending\" but keeps reading");
+
+    report
+}
+
+pub async fn demonstrate_networking() {
+    print!("{}", networking_report().await);
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct NetworkingModule;
+
+impl LearningModule for NetworkingModule {
+    fn number(&self) -> u8 {
+        15
+    }
+
+    fn name(&self) -> &'static str {
+        "networking"
+    }
+
+    fn title(&self) -> &'static str {
+        "TCP NETWORKING DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "TCP Networking"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["tokio_channels", "file_io"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(demonstrate_networking())
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(networking_report()))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&NetworkingModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// NETWORKING BEST PRACTICES:
+// 1. Never assume one write() is one read() - frame your messages
+// 2. Bound every length you read off the wire
+// 3. Bind to port 0 in tests and ask the listener for its address
+// 4. Set read timeouts on blocking sockets; a silent peer blocks forever
+// 5. Close gracefully: stop accepting, finish in-flight work, then shut down
+//    the write half so peers see EOF rather than a reset
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn report_echoes_over_both_servers() {
+        let report = networking_report().await;
+        let framing = report.find("LENGTH-PREFIXED FRAMES").unwrap();
+        assert_eq!(framing.output(r#"encode_frame(b"hi")"#), Some("[0, 0, 0, 2, 104, 105]"));
+
+        let blocking = report.find("BLOCKING SERVER").unwrap();
+        assert_eq!(blocking.output("Client 1 got"), Some(r#"["hello", "over", "tcp"]"#));
+        assert_eq!(blocking.output("shutdown()"), Some("5 frames echoed"));
+
+        let asynchronous = report.find("ASYNC SERVER").unwrap();
+        assert_eq!(asynchronous.output("Idle client then reads"), Some("None (clean EOF)"));
+        assert!(!report.to_string().contains("failed"));
+    }
+
+    // Hands out at most `step` bytes per read, like a slow network
+    struct Trickle<'a> {
+        data: &'a [u8],
+        step: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.step.min(buf.len()).min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn frames_survive_arbitrary_read_boundaries() {
+        let bytes = [encode_frame(b"hello").unwrap(), encode_frame(b"").unwrap(), encode_frame(b"world").unwrap()].concat();
+        for step in [1, 3, 64] {
+            let mut reader = Trickle { data: &bytes, step };
+            assert_eq!(read_frame(&mut reader).unwrap().as_deref(), Some(&b"hello"[..]));
+            assert_eq!(read_frame(&mut reader).unwrap().as_deref(), Some(&b""[..]));
+            assert_eq!(read_frame(&mut reader).unwrap().as_deref(), Some(&b"world"[..]));
+            assert_eq!(read_frame(&mut reader).unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn truncated_and_oversized_frames_are_errors() {
+        let frame = encode_frame(b"hello").unwrap();
+        assert_eq!(read_frame(&mut &frame[..2]).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(read_frame(&mut &frame[..7]).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+        let oversized = ((MAX_FRAME_LEN + 1) as u32).to_be_bytes();
+        assert_eq!(read_frame(&mut &oversized[..]).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert!(encode_frame(&vec![0; MAX_FRAME_LEN + 1]).is_err());
+    }
+
+    #[tokio::test]
+    async fn async_frames_match_blocking_frames() {
+        let mut buffer = Vec::new();
+        write_frame_async(&mut buffer, b"same bytes").await.unwrap();
+        assert_eq!(buffer, encode_frame(b"same bytes").unwrap());
+        assert_eq!(read_frame_async(&mut &buffer[..]).await.unwrap().as_deref(), Some(&b"same bytes"[..]));
+    }
+}
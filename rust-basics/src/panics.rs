@@ -0,0 +1,589 @@
+// ===== PANICS & UNWINDING =====
+//
+// A panic is Rust's "this should never happen" signal. By default it
+// UNWINDS: the panicking thread walks back up its stack, running Drop for
+// every live value, until the thread ends (or catch_unwind stops it). The
+// rest of the program keeps running:
+//
+//   main thread ──spawn──▶ worker: job 1 ✓  job 2 💥 panic!
+//        │                           └─ unwind: drop locals, release locks
+//        └─ join() ◀──────────────── Err(payload)  → restart the worker
+//
+// This module covers when to panic at all, how to stop and inspect an
+// unwinding panic, and how to keep a program healthy when a thread dies:
+// hooks, UnwindSafe, poisoned mutexes, restarts, and panic = "abort".
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe, UnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, Once, PoisonError};
+use std::thread::{self, JoinHandle};
+
+use thiserror::Error;
+
+use crate::report::Report;
+
+// ===== 1. panic! VS Result =====
+//
+// UNDERSTANDING THE SPLIT:
+// • Result: failures the CALLER should handle - bad input, missing files,
+//   network errors. They are part of normal operation
+// • panic!: a BUG - a broken invariant the code can't recover from, where
+//   continuing would give wrong answers. unwrap/expect/indexing/overflow
+//   in debug builds all panic
+// • expect("why this can't fail") documents the invariant at the call site
+// • Libraries should almost never panic on input; offer a Result and let
+//   applications decide
+// • Tests, examples and prototypes may unwrap freely
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PercentError {
+    #[error("{0:?} is not a number")]
+    NotANumber(String),
+
+    #[error("{0} is more than 100")]
+    OutOfRange(u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Percent(u8);
+
+impl Percent {
+    // User input: failure is expected, so it's a Result
+    pub fn parse(input: &str) -> Result<Self, PercentError> {
+        let value: u32 = input.trim().parse().map_err(|_| PercentError::NotANumber(input.to_string()))?;
+        u8::try_from(value).ok().filter(|&v| v <= 100).map(Percent).ok_or(PercentError::OutOfRange(value))
+    }
+
+    // Internal arithmetic: the inputs are already Percents, so a result over
+    // 100 would be a bug in this function - panic rather than return garbage
+    pub fn average(values: &[Percent]) -> Percent {
+        assert!(!values.is_empty(), "average of no percentages");
+        let sum: u32 = values.iter().map(|p| u32::from(p.0)).sum();
+        let average = sum / values.len() as u32;
+        Percent(u8::try_from(average).expect("an average of values ≤ 100 is ≤ 100"))
+    }
+
+    pub fn value(self) -> u8 {
+        self.0
+    }
+}
+
+// ===== 2. catch_unwind =====
+//
+// UNDERSTANDING catch_unwind:
+// • panic::catch_unwind(f) runs f and returns Err(payload) if it panicked,
+//   instead of letting the unwind continue
+// • The payload is a Box<dyn Any + Send>: a &'static str for
+//   panic!("literal"), a String for panic!("{}", x) - anything for
+//   panic_any(value)
+// • It is NOT try/catch for errors: use it at boundaries - a thread pool
+//   keeping its workers, a plugin host, an FFI callback (a panic must not
+//   unwind into C - since Rust 1.81 that aborts the process)
+// • It can't catch a panic when the program is built with panic = "abort"
+
+// The text of a panic payload, whichever type it is
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "non-string panic payload".to_string()
+    }
+}
+
+pub fn catch<R>(f: impl FnOnce() -> R + UnwindSafe) -> Result<R, String> {
+    quietly(|| panic::catch_unwind(f)).map_err(|payload| panic_message(&*payload))
+}
+
+// Unwinding still runs Drop - this is how locks get released and files get
+// closed when a thread panics
+pub struct SetOnDrop<'a>(pub &'a AtomicBool);
+
+impl Drop for SetOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+// ===== 3. PANIC HOOKS =====
+//
+// UNDERSTANDING HOOKS:
+// • The hook runs first, on the panicking thread, BEFORE unwinding starts.
+//   The default one prints "thread 'x' panicked at src/..: message"
+// • panic::set_hook replaces it for the whole process; take_hook returns
+//   the current one so a new hook can wrap it
+// • Typical uses: send the panic to a log or crash reporter, add context,
+//   or silence panics that are expected (as here, in the demonstrations)
+// • A hook must not panic itself, and should avoid locks the panicking
+//   code might hold
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PanicRecord {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+thread_local! {
+    // Set by quietly(): the hook records the panic but doesn't print it
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<PanicRecord>> = const { RefCell::new(None) };
+}
+
+// Wraps whatever hook is installed (the default one, or the test
+// harness's) so only panics inside quietly() are silenced
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let record = PanicRecord {
+                message: info.payload_as_str().unwrap_or("non-string panic payload").to_string(),
+                location: info.location().map(|location| format!("{}:{}", location.file(), location.line())),
+            };
+            // try_borrow_mut: panicking inside the hook would abort
+            LAST_PANIC.with(|last| {
+                if let Ok(mut last) = last.try_borrow_mut() {
+                    *last = Some(record);
+                }
+            });
+            if !QUIET.get() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+// The most recent panic on this thread, as seen by the hook
+pub fn last_panic() -> Option<PanicRecord> {
+    LAST_PANIC.with(|last| last.borrow().clone())
+}
+
+// Restores the previous QUIET value however the closure exits - including
+// by unwinding
+struct QuietGuard(bool);
+
+impl Drop for QuietGuard {
+    fn drop(&mut self) {
+        QUIET.set(self.0);
+    }
+}
+
+// Runs f with this thread's panics recorded but not printed
+pub fn quietly<R>(f: impl FnOnce() -> R) -> R {
+    install_panic_hook();
+    let _restore = QuietGuard(QUIET.replace(true));
+    f()
+}
+
+// ===== 4. UnwindSafe =====
+//
+// UNDERSTANDING UNWIND SAFETY:
+// • A panic halfway through a &mut method can leave the value half-updated.
+//   Whoever catches the panic and keeps using the value sees the broken
+//   state
+// • catch_unwind therefore requires F: UnwindSafe. &mut T is not
+//   UnwindSafe, nor is &RefCell<T> or &Cell<T> - anything that allows
+//   mutation through a captured reference
+// • AssertUnwindSafe(f) overrides the check: "I have thought about it".
+//   Either the value is discarded afterwards, or it is written so a panic
+//   can't break it (validate first, then mutate)
+// • Mutex and RwLock ARE UnwindSafe: they use poisoning instead (section 5)
+
+/// Account balances that must always add up to the same total.
+///
+/// A `&mut Ledger` captured by `catch_unwind` doesn't compile - a panic in
+/// the middle of `transfer` could leave it inconsistent:
+///
+/// ```compile_fail,E0277
+/// use rust_basics::panics::Ledger;
+///
+/// let mut ledger = Ledger::new(vec![100, 0]);
+/// let result = std::panic::catch_unwind(|| ledger.transfer(0, 5, 50));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ledger {
+    balances: Vec<i64>,
+}
+
+impl Ledger {
+    pub fn new(balances: Vec<i64>) -> Self {
+        Ledger { balances }
+    }
+
+    pub fn total(&self) -> i64 {
+        self.balances.iter().sum()
+    }
+
+    // Debits, THEN indexes the destination: a bad `to` panics in between
+    pub fn transfer(&mut self, from: usize, to: usize, amount: i64) {
+        self.balances[from] -= amount;
+        self.balances[to] += amount;
+    }
+
+    // Every index is checked before anything changes, so a panic (or an
+    // early return) can't leave half a transfer behind
+    pub fn transfer_checked(&mut self, from: usize, to: usize, amount: i64) -> bool {
+        if from >= self.balances.len() || to >= self.balances.len() {
+            return false;
+        }
+        self.balances[from] -= amount;
+        self.balances[to] += amount;
+        true
+    }
+}
+
+// ===== 5. POISONED MUTEXES =====
+//
+// UNDERSTANDING POISONING:
+// • If a thread panics while holding a MutexGuard, the unwind drops the
+//   guard (unlocking it) and marks the Mutex POISONED
+// • Every later lock() returns Err(PoisonError): "the data may be half
+//   updated - decide whether you trust it"
+// • lock().unwrap() propagates the panic to every thread that touches the
+//   data afterwards - often the right default
+// • PoisonError::into_inner() gives the guard anyway, for data that can't
+//   be left inconsistent (counters, logs, caches you can rebuild)
+// • mutex.clear_poison() (Rust 1.77) marks it healthy again once repaired
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoisonOutcome {
+    pub writer_panicked: bool,
+    pub poisoned: bool,
+    pub recovered: Vec<String>,
+    pub poisoned_after_clear: bool,
+}
+
+pub fn poison_and_recover() -> PoisonOutcome {
+    let log = Arc::new(Mutex::new(vec!["started".to_string()]));
+    let writer = {
+        let log = Arc::clone(&log);
+        thread::spawn(move || {
+            quietly(|| {
+                let mut entries = log.lock().expect("not poisoned yet");
+                entries.push("half-written entry".to_string());
+                panic!("writer crashed while holding the lock");
+            })
+        })
+    };
+    let writer_panicked = writer.join().is_err();
+    let poisoned = log.is_poisoned();
+
+    // Keep the data: a log entry too many is better than losing the log
+    let recovered = log.lock().unwrap_or_else(PoisonError::into_inner).clone();
+    log.clear_poison();
+
+    PoisonOutcome { writer_panicked, poisoned, recovered, poisoned_after_clear: log.is_poisoned() }
+}
+
+// ===== 6. KEEPING WORKERS ALIVE =====
+//
+// UNDERSTANDING THE TWO STRATEGIES:
+// • Catch per job: the worker wraps each job in catch_unwind and reports
+//   the failure as a value. The thread never dies
+// • Supervise: let the panic end the thread, notice it in join(), and
+//   start a new worker on the same queue - Erlang's "let it crash". Any
+//   state the dead worker built up is gone, which is the point
+// • Either way the queue must outlive the worker: here an
+//   Arc<Mutex<Receiver>>, locked only to take a job so a panicking job
+//   never poisons it
+
+// Panics on 0 with "attempt to divide by zero"
+pub fn per_mille(n: u64) -> u64 {
+    1000 / n
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupervisedRun {
+    pub results: Vec<(u64, u64)>,
+    pub failures: Vec<(u64, String)>,
+    pub restarts: usize,
+}
+
+// Strategy 1: one worker thread that catches each job's panic
+pub fn process_catching(inputs: Vec<u64>, work: fn(u64) -> u64) -> Vec<Result<u64, String>> {
+    thread::spawn(move || inputs.into_iter().map(|input| catch(|| work(input))).collect())
+        .join()
+        .expect("the worker catches every panic")
+}
+
+fn spawn_worker(
+    jobs: Arc<Mutex<Receiver<u64>>>,
+    results: Sender<(u64, u64)>,
+    current: Arc<Mutex<Option<u64>>>,
+    work: fn(u64) -> u64,
+) -> JoinHandle<()> {
+    thread::spawn(move || {
+        quietly(|| {
+            loop {
+                // The guard is dropped at the end of this statement, before
+                // the job runs
+                let next = jobs.lock().unwrap_or_else(PoisonError::into_inner).recv();
+                let Ok(input) = next else { break };
+                *current.lock().unwrap_or_else(PoisonError::into_inner) = Some(input);
+                let output = work(input);
+                *current.lock().unwrap_or_else(PoisonError::into_inner) = None;
+                if results.send((input, output)).is_err() {
+                    break;
+                }
+            }
+        })
+    })
+}
+
+// Strategy 2: restart the worker whenever it dies, up to max_restarts times
+pub fn supervise(inputs: &[u64], work: fn(u64) -> u64, max_restarts: usize) -> SupervisedRun {
+    let (job_sender, job_receiver) = mpsc::channel();
+    for &input in inputs {
+        job_sender.send(input).expect("receiver is alive");
+    }
+    drop(job_sender);
+
+    let jobs = Arc::new(Mutex::new(job_receiver));
+    let current = Arc::new(Mutex::new(None));
+    let (result_sender, result_receiver) = mpsc::channel();
+    let mut failures = Vec::new();
+    let mut restarts = 0;
+
+    loop {
+        let worker = spawn_worker(Arc::clone(&jobs), result_sender.clone(), Arc::clone(&current), work);
+        let Err(payload) = worker.join() else { break }; // the queue ran dry
+        if let Some(input) = current.lock().unwrap_or_else(PoisonError::into_inner).take() {
+            failures.push((input, panic_message(&*payload)));
+        }
+        if restarts == max_restarts {
+            break;
+        }
+        restarts += 1;
+    }
+
+    drop(result_sender);
+    SupervisedRun { results: result_receiver.iter().collect(), failures, restarts }
+}
+
+// ===== 7. ABORT VS UNWIND =====
+//
+// UNDERSTANDING panic = "abort":
+// • [profile.release] panic = "abort" makes every panic end the process
+//   at once: no unwinding, no Drop, no catch_unwind, no poisoning
+// • Gains: smaller binaries (no unwind tables or landing pads), slightly
+//   faster code, and no half-updated state for anyone to observe
+// • Costs: one panicking thread takes down the whole process, and cleanup
+//   in Drop (flushing files, removing temp files) doesn't happen
+// • Some panics abort even with unwinding: a panic while already
+//   unwinding (e.g. in a Drop), a panic escaping an extern "C" fn, and
+//   std::process::abort() itself
+// • Choose unwind for servers and thread pools that must survive a bad
+//   request; abort for small tools, embedded targets, and anything where a
+//   supervisor (systemd, Kubernetes) restarts the process anyway
+
+// ===== 8. DEMONSTRATION FUNCTION =====
+
+pub fn panics_report() -> Report {
+    let mut report = Report::new("🦀 PANICS & UNWINDING 🦀");
+
+    let section = report.section("1️⃣ panic! VS Result:");
+    for input in ["42", "250", "lots"] {
+        section.example(format!("Percent::parse({:?})", input), format!("{:?}", Percent::parse(input).map_err(|err| err.to_string())));
+    }
+    section.example("catch(|| Percent::average(&[]))", format!("{:?}", catch(|| Percent::average(&[]))));
+
+    let dropped = AtomicBool::new(false);
+    let caught = catch(|| {
+        let _guard = SetOnDrop(&dropped);
+        panic!("boom at step {}", 3);
+    });
+    report
+        .section("2️⃣ catch_unwind:")
+        .example("catch(|| panic!(\"boom at step {}\", 3))", format!("{:?}", caught))
+        .example("catch(|| 2 + 2)", format!("{:?}", catch(|| 2 + 2)))
+        .example("guard dropped during the unwind", dropped.load(Ordering::SeqCst));
+
+    let _ = catch(|| per_mille(0));
+    let section = report.section("3️⃣ PANIC HOOKS:");
+    section.text("The hook saw the last panic before the unwind began:");
+    if let Some(record) = last_panic() {
+        section
+            .example("last_panic().message", record.message)
+            .example("last_panic().location", record.location.unwrap_or_default());
+    }
+
+    let mut ledger = Ledger::new(vec![100, 0]);
+    let broken = catch(AssertUnwindSafe(|| ledger.transfer(0, 5, 50)));
+    let mut checked = Ledger::new(vec![100, 0]);
+    report
+        .section("4️⃣ UnwindSafe:")
+        .example("catch(AssertUnwindSafe(|| ledger.transfer(0, 5, 50)))", format!("{:?}", broken.map_err(|_| "panicked")))
+        .example("ledger.total() afterwards (was 100)", ledger.total())
+        .example("checked.transfer_checked(0, 5, 50)", checked.transfer_checked(0, 5, 50))
+        .example("checked.total() afterwards", checked.total());
+
+    let poison = poison_and_recover();
+    report
+        .section("5️⃣ POISONED MUTEXES:")
+        .example("writer thread panicked", poison.writer_panicked)
+        .example("log.is_poisoned()", poison.poisoned)
+        .example("lock().unwrap_or_else(PoisonError::into_inner)", format!("{:?}", poison.recovered))
+        .example("is_poisoned() after clear_poison()", poison.poisoned_after_clear);
+
+    let inputs = vec![4, 0, 8, 0, 5];
+    let caught = process_catching(inputs.clone(), per_mille);
+    let run = supervise(&inputs, per_mille, 5);
+    report
+        .section("6️⃣ KEEPING WORKERS ALIVE:")
+        .example("process_catching([4, 0, 8, 0, 5], per_mille)", format!("{:?}", caught))
+        .example("supervise(..).results", format!("{:?}", run.results))
+        .example("supervise(..).failures", format!("{:?}", run.failures))
+        .example("supervise(..).restarts", run.restarts);
+
+    report
+        .section("7️⃣ ABORT VS UNWIND:")
+        .bullet("unwind (default): Drop runs, catch_unwind works, one thread can die alone")
+        .bullet("panic = \"abort\": smaller and simpler, but any panic ends the process")
+        .bullet("a panic during unwinding, or escaping extern \"C\", aborts either way");
+
+    report
+        .section("🎯 PANICS SUMMARY:")
+        .check("Result for expected failures, panic! for broken invariants")
+        .check("catch_unwind belongs at boundaries: pools, plugins, FFI callbacks")
+        .check("Hooks see every panic first - log it, report it, or silence it")
+        .check("UnwindSafe flags state a panic could leave half-updated")
+        .check("Poisoned mutexes can be recovered with PoisonError::into_inner")
+        .check("Workers survive by catching per job or being restarted by a supervisor");
+
+    report
+}
+
+pub fn demonstrate_panics() {
+    print!("{}", panics_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct PanicsModule;
+
+impl LearningModule for PanicsModule {
+    fn number(&self) -> u8 {
+        25
+    }
+
+    fn name(&self) -> &'static str {
+        "panics"
+    }
+
+    fn title(&self) -> &'static str {
+        "PANICS & UNWINDING"
+    }
+
+    fn description(&self) -> &'static str {
+        "catch_unwind, Hooks, Poisoning"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["errors", "concurrency"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_panics() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { panics_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&PanicsModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// PANIC BEST PRACTICES:
+// 1. Don't panic on input - return a Result; expect() documents invariants
+// 2. Catch panics only where a whole unit of work can be thrown away
+// 3. Install one hook early to log panics; wrap the previous hook
+// 4. Validate before mutating so a panic can't leave half an update
+// 5. Decide per Mutex: propagate poison (unwrap) or recover (into_inner)
+// 6. Pick panic = "abort" deliberately, knowing Drop won't run
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_for_input_panics_for_bugs() {
+        assert_eq!(Percent::parse(" 7 ").map(Percent::value), Ok(7));
+        assert_eq!(Percent::parse("101"), Err(PercentError::OutOfRange(101)));
+        assert_eq!(Percent::parse("99999999999").map_err(|err| matches!(err, PercentError::NotANumber(_))), Err(true));
+        let values = [Percent::parse("40").unwrap(), Percent::parse("61").unwrap()];
+        assert_eq!(Percent::average(&values).value(), 50);
+        assert_eq!(catch(|| Percent::average(&[])), Err("average of no percentages".to_string()));
+    }
+
+    #[test]
+    fn catch_reports_both_payload_types_and_runs_drop() {
+        assert_eq!(catch(|| panic!("literal")), Err::<(), _>("literal".to_string()));
+        assert_eq!(catch(|| panic!("formatted {}", 1)), Err::<(), _>("formatted 1".to_string()));
+        assert_eq!(catch(|| panic::panic_any(7_u8)), Err::<(), _>("non-string panic payload".to_string()));
+
+        let dropped = AtomicBool::new(false);
+        let _ = catch(|| {
+            let _guard = SetOnDrop(&dropped);
+            panic!("unwind");
+        });
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn hook_records_the_panic_and_quiet_is_restored() {
+        let _ = catch(|| per_mille(0));
+        let record = last_panic().expect("the hook saw it");
+        assert_eq!(record.message, "attempt to divide by zero");
+        assert!(record.location.is_some_and(|location| location.contains("panics.rs")));
+        assert!(!QUIET.get(), "the guard restored the flag after the unwind");
+    }
+
+    #[test]
+    fn asserting_unwind_safety_can_expose_broken_state() {
+        let mut ledger = Ledger::new(vec![100, 0]);
+        assert!(catch(AssertUnwindSafe(|| ledger.transfer(0, 9, 30))).is_err());
+        assert_eq!(ledger.total(), 70, "debited but never credited");
+
+        let mut checked = Ledger::new(vec![100, 0]);
+        assert!(!checked.transfer_checked(0, 9, 30));
+        assert!(checked.transfer_checked(0, 1, 30));
+        assert_eq!(checked.total(), 100);
+    }
+
+    #[test]
+    fn poisoned_mutex_is_recovered() {
+        let outcome = poison_and_recover();
+        assert!(outcome.writer_panicked && outcome.poisoned);
+        assert_eq!(outcome.recovered, ["started", "half-written entry"]);
+        assert!(!outcome.poisoned_after_clear);
+    }
+
+    #[test]
+    fn worker_catching_per_job_survives() {
+        let results = process_catching(vec![2, 0, 500], per_mille);
+        assert_eq!(results, [Ok(500), Err("attempt to divide by zero".to_string()), Ok(2)]);
+    }
+
+    #[test]
+    fn supervisor_restarts_a_dead_worker() {
+        let run = supervise(&[1, 0, 2, 0, 4], per_mille, 5);
+        assert_eq!(run.results, [(1, 1000), (2, 500), (4, 250)], "every good job ran, in order");
+        assert_eq!(run.restarts, 2);
+        assert_eq!(run.failures, [(0, "attempt to divide by zero".to_string()), (0, "attempt to divide by zero".to_string())]);
+
+        // Out of restarts: the rest of the queue is abandoned
+        let run = supervise(&[0, 0, 0, 5], per_mille, 1);
+        assert_eq!((run.restarts, run.failures.len()), (1, 2));
+        assert!(run.results.is_empty());
+    }
+}
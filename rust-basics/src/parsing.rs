@@ -0,0 +1,391 @@
+// ===== STRING PARSING DEEP STUDY =====
+//
+// THREE WAYS TO PULL STRUCTURE OUT OF TEXT:
+// • Hand-rolled: split_once, strip_prefix, byte checks. No dependencies,
+//   fastest, precise error messages - and the most code to get right
+// • regex: a pattern string compiled into a matcher. Compact and quick to
+//   write; errors amount to "didn't match"
+// • nom: parser combinators - small functions (digits, tag, space) composed
+//   into bigger ones. Typed results and a real grammar, at the cost of a
+//   learning curve
+//
+// All three parse the same log line format and borrow from the input, so
+// LogLine<'a> never copies a string:
+//   2024-05-01 12:00:05 WARN db::pool: slow query (1200ms)
+//   └──date──┘ └─time─┘ └lvl┘ └target┘  └────message─────┘
+//
+// benches/parsing_benchmarks.rs measures them against each other.
+
+use crate::report::Report;
+use mini_logger::Level;
+use nom::bytes::complete::{tag, take_while_m_n, take_while1};
+use nom::character::complete::{alpha1, char};
+use nom::combinator::{map_res, recognize, rest};
+use nom::multi::separated_list1;
+use nom::{IResult, Parser};
+use regex::Regex;
+use std::borrow::Cow;
+use std::sync::LazyLock;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LineError {
+    #[error("malformed line: {0}")]
+    Malformed(&'static str),
+
+    #[error("unknown level '{0}'")]
+    UnknownLevel(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine<'a> {
+    pub date: &'a str,
+    pub time: &'a str,
+    pub level: Level,
+    pub target: &'a str,
+    pub message: &'a str,
+}
+
+fn parse_level(level: &str) -> Result<Level, LineError> {
+    level.parse().map_err(|_| LineError::UnknownLevel(level.to_string()))
+}
+
+// ===== 1. REGEX: CAPTURES AND NAMED GROUPS =====
+//
+// UNDERSTANDING COMPILATION:
+// • Regex::new parses the pattern and builds an automaton - microseconds,
+//   far more than a match. Compiling inside a function called per line is
+//   the classic regex performance bug
+// • Compile once: a static LazyLock (std since 1.80; once_cell::Lazy and
+//   lazy_static! did the same before) builds it on first use
+// • unwrap() is fine for a pattern literal: it's a bug in the program, and
+//   the tests hit it on the first run
+//
+// UNDERSTANDING CAPTURES:
+// • (?<name>...) is a named group; caps["name"] or caps.name("name")
+// • caps.name() gives a Match with start/end - and as_str() borrows the input
+// • \d and \w are Unicode-aware by default: \d matches "٣" too. [0-9] says
+//   what a log timestamp actually means
+
+static LOG_LINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?<date>[0-9]{4}-[0-9]{2}-[0-9]{2}) (?<time>[0-9]{2}:[0-9]{2}:[0-9]{2}) (?<level>[A-Za-z]+) (?<target>\w+(?:::\w+)*): (?<message>.*)$",
+    )
+    .unwrap()
+});
+
+pub fn parse_with_regex(line: &str) -> Result<LogLine<'_>, LineError> {
+    let caps = LOG_LINE.captures(line).ok_or(LineError::Malformed("doesn't match the log line pattern"))?;
+    // name() rather than caps["date"]: the Match borrows `line`, not `caps`
+    let group = |name| caps.name(name).map_or("", |m| m.as_str());
+    Ok(LogLine {
+        date: group("date"),
+        time: group("time"),
+        level: parse_level(group("level"))?,
+        target: group("target"),
+        message: group("message"),
+    })
+}
+
+// The performance bug, kept for the benchmark
+pub fn parse_with_regex_compiled_per_call(line: &str) -> bool {
+    Regex::new(LOG_LINE.as_str()).unwrap().is_match(line)
+}
+
+// key=value pairs in a message; values may be "quoted with spaces"
+pub fn extract_fields(message: &str) -> Vec<(&str, &str)> {
+    static FIELD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"(?<key>\w+)=(?:"(?<quoted>[^"]*)"|(?<bare>\S+))"#).unwrap());
+    FIELD
+        .captures_iter(message)
+        .filter_map(|caps| {
+            let value = caps.name("quoted").or(caps.name("bare"))?;
+            Some((caps.name("key")?.as_str(), value.as_str()))
+        })
+        .collect()
+}
+
+// Keeps the first letter and the domain: ada@example.com → a***@example.com.
+// replace_all returns Cow::Borrowed when nothing matched - no allocation
+pub fn redact_emails(text: &str) -> Cow<'_, str> {
+    static EMAIL: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\b(?<first>[A-Za-z0-9])[A-Za-z0-9._%+-]*@(?<domain>[A-Za-z0-9.-]+\.[A-Za-z]{2,})\b").unwrap());
+    EMAIL.replace_all(text, "$first***@$domain")
+}
+
+// ===== 2. HAND-ROLLED =====
+//
+// UNDERSTANDING THE TOOLKIT:
+// • split_once(' ') peels off one field and returns the rest
+// • Checking bytes is fine when the format is ASCII - a multi-byte UTF-8
+//   character can't be mistaken for an ASCII digit
+// • Each failure point knows exactly what's wrong, so errors say so
+
+// `shape` uses '9' for "any ASCII digit"; everything else must match exactly
+fn has_shape(field: &str, shape: &str) -> bool {
+    field.len() == shape.len()
+        && field.bytes().zip(shape.bytes()).all(|(b, s)| if s == b'9' { b.is_ascii_digit() } else { b == s })
+}
+
+fn is_target(target: &str) -> bool {
+    target.split("::").all(|part| !part.is_empty() && part.chars().all(|c| c.is_alphanumeric() || c == '_'))
+}
+
+pub fn parse_by_hand(line: &str) -> Result<LogLine<'_>, LineError> {
+    let (date, rest) = line.split_once(' ').ok_or(LineError::Malformed("missing time"))?;
+    if !has_shape(date, "9999-99-99") {
+        return Err(LineError::Malformed("date must look like 2024-05-01"));
+    }
+    let (time, rest) = rest.split_once(' ').ok_or(LineError::Malformed("missing level"))?;
+    if !has_shape(time, "99:99:99") {
+        return Err(LineError::Malformed("time must look like 12:00:05"));
+    }
+    let (level, rest) = rest.split_once(' ').ok_or(LineError::Malformed("missing target"))?;
+    if level.is_empty() || !level.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return Err(LineError::UnknownLevel(level.to_string()));
+    }
+    let (target, message) = rest.split_once(": ").ok_or(LineError::Malformed("missing ': ' after the target"))?;
+    if !is_target(target) {
+        return Err(LineError::Malformed("target must be a module path like db::pool"));
+    }
+    Ok(LogLine { date, time, level: parse_level(level)?, target, message })
+}
+
+// ===== 3. nom: PARSER COMBINATORS =====
+//
+// UNDERSTANDING nom:
+// • A parser is a function: input → IResult<remaining input, output>
+// • Combinators build parsers from parsers: recognize(...) returns the
+//   consumed slice, map_res(...) converts the output, a tuple runs parsers
+//   in sequence, separated_list1 repeats one with a separator between
+// • `.parse(input)` runs any of them (nom 8's Parser trait)
+// • Every step returns the rest of the input, so the output can borrow it
+
+fn digits<'a>(count: usize) -> impl Parser<&'a str, Output = &'a str, Error = nom::error::Error<&'a str>> {
+    take_while_m_n(count, count, |c: char| c.is_ascii_digit())
+}
+
+fn nom_date(input: &str) -> IResult<&str, &str> {
+    recognize((digits(4), char('-'), digits(2), char('-'), digits(2))).parse(input)
+}
+
+fn nom_time(input: &str) -> IResult<&str, &str> {
+    recognize((digits(2), char(':'), digits(2), char(':'), digits(2))).parse(input)
+}
+
+fn nom_level(input: &str) -> IResult<&str, Level> {
+    map_res(alpha1, str::parse::<Level>).parse(input)
+}
+
+fn nom_target(input: &str) -> IResult<&str, &str> {
+    recognize(separated_list1(tag("::"), take_while1(|c: char| c.is_alphanumeric() || c == '_'))).parse(input)
+}
+
+fn nom_line(input: &str) -> IResult<&str, LogLine<'_>> {
+    let (rest, (date, _, time, _, level, _, target, _, message)) =
+        (nom_date, char(' '), nom_time, char(' '), nom_level, char(' '), nom_target, tag(": "), rest).parse(input)?;
+    Ok((rest, LogLine { date, time, level, target, message }))
+}
+
+// nom's own errors say which combinator failed and where; here they're
+// mapped onto LineError so all three parsers can be compared
+pub fn parse_with_nom(line: &str) -> Result<LogLine<'_>, LineError> {
+    nom_line(line).map(|(_, parsed)| parsed).map_err(|e| match e {
+        nom::Err::Error(e) | nom::Err::Failure(e) if e.code == nom::error::ErrorKind::MapRes => {
+            LineError::UnknownLevel(e.input.split(' ').next().unwrap_or_default().to_string())
+        }
+        _ => LineError::Malformed("doesn't match the log line grammar"),
+    })
+}
+
+// ===== 4. DEMONSTRATION FUNCTION =====
+
+pub const SAMPLE_LINES: [&str; 6] = [
+    "2024-05-01 12:00:00 INFO server: started on :8080",
+    "2024-05-01 12:00:05 WARN db::pool: slow query (1200ms) table=orders rows=12",
+    "2024-05-01 12:00:07 ERROR auth::login: rejected user=\"Ada Lovelace\" email=ada@example.com",
+    "2024-05-01 12:00:09 LOUD server: shouting",
+    "2024-05-01 noon DEBUG server: tick",
+    "yesterday INFO server: ok",
+];
+
+pub fn parsing_report() -> Report {
+    let mut report = Report::new("🦀 RUST STRING PARSING DEEP STUDY 🦀");
+
+    // ===== REGEX =====
+    let regex = report.section("1️⃣ REGEX: CAPTURES AND NAMED GROUPS:");
+    if let Some(caps) = LOG_LINE.captures(SAMPLE_LINES[1]) {
+        regex.example("caps[\"level\"], caps[\"target\"]", format!("{:?}, {:?}", &caps["level"], &caps["target"]));
+        if let Some(message) = caps.name("message") {
+            regex.example(format!("message at bytes {}..{}", message.start(), message.end()), format!("{:?}", message.as_str()));
+        }
+    }
+    regex.example(
+        "extract_fields(\"rejected user=\\\"Ada Lovelace\\\" email=ada@example.com\")",
+        format!("{:?}", extract_fields("rejected user=\"Ada Lovelace\" email=ada@example.com")),
+    );
+    for text in ["contact ada@example.com or grace@navy.mil", "no addresses here"] {
+        let redacted = redact_emails(text);
+        let kind = if matches!(redacted, Cow::Borrowed(_)) { "borrowed" } else { "owned" };
+        regex.example(format!("redact_emails({:?})", text), format!("{:?} ({})", redacted, kind));
+    }
+    regex.example(
+        "\\d matches Arabic-Indic digits",
+        Regex::new(r"^\d$").map(|re| re.is_match("٣")).unwrap_or(false),
+    );
+
+    // ===== THREE PARSERS =====
+    let parsers = report.section("2️⃣ REGEX VS HAND-ROLLED VS nom:");
+    for line in SAMPLE_LINES {
+        parsers
+            .text(line)
+            .example("   regex", format!("{:?}", parse_with_regex(line).map(|l| (l.level, l.target))))
+            .example("   hand ", format!("{:?}", parse_by_hand(line).map(|l| (l.level, l.target))))
+            .example("   nom  ", format!("{:?}", parse_with_nom(line).map(|l| (l.level, l.target))));
+    }
+    parsers.text("Same answers; the hand-rolled parser explains itself best");
+
+    // ===== COST =====
+    let cost = report.section("3️⃣ WHAT COMPILING A REGEX COSTS:");
+    let start = std::time::Instant::now();
+    for _ in 0..100 {
+        parse_with_regex_compiled_per_call(SAMPLE_LINES[0]);
+    }
+    let per_call = start.elapsed();
+    let start = std::time::Instant::now();
+    for _ in 0..100 {
+        let _ = parse_with_regex(SAMPLE_LINES[0]);
+    }
+    cost.example("100 matches, compiling each time", format!("{:?}", per_call))
+        .example("100 matches, compiled once", format!("{:?}", start.elapsed()))
+        .text("Run `cargo bench -p rust-basics --bench parsing_benchmarks` for all three parsers");
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 PARSING SUMMARY:")
+        .check("Compile a regex once (LazyLock), match it many times")
+        .check("Named groups make captures readable; Match borrows the input")
+        .check("replace_all returns Cow - no allocation when nothing changed")
+        .check("Hand-rolled parsers are fastest and give the best errors")
+        .check("nom composes small parsers into a grammar with typed output");
+
+    report
+}
+
+pub fn demonstrate_parsing() {
+    print!("{}", parsing_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct ParsingModule;
+
+impl LearningModule for ParsingModule {
+    fn number(&self) -> u8 {
+        16
+    }
+
+    fn name(&self) -> &'static str {
+        "parsing"
+    }
+
+    fn title(&self) -> &'static str {
+        "STRING PARSING DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Regex & String Parsing"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["lifetimes", "errors"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_parsing() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { parsing_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&ParsingModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// CHOOSING A PARSER:
+// 1. A fixed, simple format on a hot path: hand-roll it with split_once
+// 2. Searching or extracting from free text: regex
+// 3. A real grammar (nesting, alternatives, many token types): nom
+// 4. Whichever you pick, return borrowed &str where you can
+// 5. Never compile a regex inside a loop or a per-request function
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Parser = fn(&str) -> Result<LogLine<'_>, LineError>;
+    const PARSERS: [(&str, Parser); 3] = [("regex", parse_with_regex), ("hand", parse_by_hand), ("nom", parse_with_nom)];
+
+    #[test]
+    fn report_compares_the_parsers_line_by_line() {
+        let report = parsing_report();
+        let regex = report.find("NAMED GROUPS").unwrap();
+        assert_eq!(regex.output("caps[\"level\"], caps[\"target\"]"), Some(r#""WARN", "db::pool""#));
+        assert_eq!(regex.output("redact_emails(\"no addresses here\")"), Some(r#""no addresses here" (borrowed)"#));
+
+        // Three parsers per sample line. They agree until the malformed
+        // lines, where each words its error its own way
+        let parsers = report.find("REGEX VS HAND-ROLLED").unwrap();
+        let outputs: Vec<_> = parsers.outputs().collect();
+        assert_eq!(outputs.len(), 3 * SAMPLE_LINES.len());
+        assert!(outputs.chunks(3).take(4).all(|line| line[0] == line[1] && line[1] == line[2]), "{:?}", outputs);
+    }
+
+    #[test]
+    fn all_parsers_agree_on_good_lines() {
+        let expected = LogLine {
+            date: "2024-05-01",
+            time: "12:00:05",
+            level: Level::Warn,
+            target: "db::pool",
+            message: "slow query (1200ms) table=orders rows=12",
+        };
+        for (name, parse) in PARSERS {
+            assert_eq!(parse(SAMPLE_LINES[1]), Ok(expected.clone()), "{}", name);
+            assert_eq!(parse("2024-05-01 00:00:00 info a: ").map(|l| l.message), Ok(""), "{}", name);
+        }
+    }
+
+    #[test]
+    fn all_parsers_reject_bad_lines() {
+        for (name, parse) in PARSERS {
+            assert_eq!(parse(SAMPLE_LINES[3]), Err(LineError::UnknownLevel("LOUD".to_string())), "{}", name);
+            for line in [SAMPLE_LINES[4], SAMPLE_LINES[5], "2024-05-01 12:00:00 INFO db:: oops", ""] {
+                assert!(matches!(parse(line), Err(LineError::Malformed(_))), "{} accepted {:?}", name, line);
+            }
+        }
+        assert_eq!(parse_by_hand(SAMPLE_LINES[4]), Err(LineError::Malformed("time must look like 12:00:05")));
+    }
+
+    #[test]
+    fn regex_helpers() {
+        assert_eq!(extract_fields("a=1 b=\"two words\" c= d=x"), [("a", "1"), ("b", "two words"), ("d", "x")]);
+        assert_eq!(redact_emails("mail ada@example.com!"), "mail a***@example.com!");
+        assert!(matches!(redact_emails("nothing to hide"), Cow::Borrowed(_)));
+        assert!(parse_with_regex_compiled_per_call(SAMPLE_LINES[0]));
+    }
+
+    #[test]
+    fn digits_means_ascii_digits() {
+        let arabic = "٢٠٢٤-05-01 12:00:00 INFO a: b";
+        assert!(Regex::new(r"^\d{4}").unwrap().is_match(arabic));
+        for (name, parse) in PARSERS {
+            assert!(parse(arabic).is_err(), "{}", name);
+        }
+    }
+}
@@ -0,0 +1,854 @@
+// ===== DESIGN PATTERNS DEEP STUDY =====
+//
+// WHY RUST PATTERNS LOOK DIFFERENT:
+// Many classic design patterns exist to work around what a language can't
+// say. Rust can say more: ownership, enums, closures and Drop take over much
+// of the work, and the compiler enforces rules that other languages leave to
+// documentation.
+//
+// THE PATTERNS IN THIS MODULE:
+// • Builder: many optional settings, validated once in build()
+// • Newtype: a one-field wrapper that gives a value its own type
+// • Typestate: the state lives in a type parameter, so a wrong call is a
+//   compile error instead of a runtime check
+// • Strategy: swap behavior with a closure instead of a class hierarchy
+// • RAII guard: a value whose Drop undoes or cleans up, however the scope ends
+//
+// Every "this can't compile" claim below is a compile_fail doc test:
+// `cargo test --doc -p rust-basics` checks that each one still fails.
+
+use crate::report::Report;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PatternError {
+    #[error("'{0}' is not an email address")]
+    InvalidEmail(String),
+
+    #[error("an email needs a subject or a body")]
+    EmptyEmail,
+
+    #[error("{account} has {balance}, can't take {amount}")]
+    InsufficientFunds { account: String, balance: i64, amount: i64 },
+}
+
+// ===== 1. NEWTYPE =====
+//
+// UNDERSTANDING NEWTYPES:
+// • struct UserId(u64) costs nothing at runtime - same layout as u64
+// • But UserId and OrderId are different types: mixing them up is a type
+//   error, not a bug found in production
+// • A private field plus a checked constructor means every value that exists
+//   is valid ("parse, don't validate") - code taking an EmailAddress never
+//   re-checks it
+// • The orphan rule forbids impl Display for Vec<String> (both foreign);
+//   wrapping the Vec in a local newtype makes it allowed
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UserId(pub u64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OrderId(pub u64);
+
+/// Orders belonging to a user - the argument can't be an `OrderId` by mistake:
+///
+/// ```compile_fail,E0308
+/// use rust_basics::patterns::{orders_for, OrderId};
+///
+/// orders_for(OrderId(7));
+/// ```
+pub fn orders_for(user: UserId) -> Vec<OrderId> {
+    (1..=user.0 % 4).map(|n| OrderId(user.0 * 100 + n)).collect()
+}
+
+/// An email address that has already been checked.
+///
+/// The field is private, so [`EmailAddress::parse`] is the only way to get one:
+///
+/// ```compile_fail,E0423
+/// use rust_basics::patterns::EmailAddress;
+///
+/// let unchecked = EmailAddress("not an address".to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmailAddress(String);
+
+impl EmailAddress {
+    pub fn parse(input: &str) -> Result<Self, PatternError> {
+        let input = input.trim();
+        let valid = match input.split_once('@') {
+            Some((user, domain)) => {
+                !user.is_empty()
+                    && !domain.contains('@')
+                    && domain.contains('.')
+                    && !domain.starts_with('.')
+                    && !domain.ends_with('.')
+            }
+            None => false,
+        };
+        if valid {
+            Ok(EmailAddress(input.to_lowercase()))
+        } else {
+            Err(PatternError::InvalidEmail(input.to_string()))
+        }
+    }
+
+    pub fn domain(&self) -> &str {
+        self.0.split_once('@').map_or("", |(_, domain)| domain)
+    }
+}
+
+impl AsRef<str> for EmailAddress {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for EmailAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.0)
+    }
+}
+
+// Display for a list of addresses: "a@x.com, b@y.org"
+pub struct AddressList<'a>(pub &'a [EmailAddress]);
+
+impl fmt::Display for AddressList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, address) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", address)?;
+        }
+        Ok(())
+    }
+}
+
+// ===== 2. BUILDER =====
+//
+// UNDERSTANDING BUILDERS IN RUST:
+// • Rust has no default or named arguments, so a type with many optional
+//   settings gets a builder instead of a constructor with ten parameters
+// • REQUIRED values go into builder(...) itself - forgetting one is then a
+//   compile error, not a runtime "missing field"
+// • Setters take and return `self`, so calls chain and build() consumes the
+//   builder - it can't be reused half-configured by accident
+// • build() returns Result for the rules no type can express
+// • Because addresses are EmailAddress, the builder has no address checks
+//   left to do - the newtype already did them
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Email {
+    pub to: EmailAddress,
+    pub cc: Vec<EmailAddress>,
+    pub subject: String,
+    pub body: String,
+}
+
+impl Email {
+    pub fn builder(to: EmailAddress) -> EmailBuilder {
+        EmailBuilder { to, cc: Vec::new(), subject: String::new(), body: String::new() }
+    }
+}
+
+/// Builds an [`Email`]; start with [`Email::builder`].
+///
+/// The recipient is required, so it's an argument of `builder`:
+///
+/// ```compile_fail,E0061
+/// use rust_basics::patterns::Email;
+///
+/// let email = Email::builder().subject("Hello").build();
+/// ```
+///
+/// `build` consumes the builder, so it can't be reused:
+///
+/// ```compile_fail,E0382
+/// use rust_basics::patterns::{Email, EmailAddress};
+///
+/// let builder = Email::builder(EmailAddress::parse("a@example.com").unwrap()).subject("Hi");
+/// let first = builder.build();
+/// let second = builder.build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct EmailBuilder {
+    to: EmailAddress,
+    cc: Vec<EmailAddress>,
+    subject: String,
+    body: String,
+}
+
+impl EmailBuilder {
+    // Can be called repeatedly; each call adds one address
+    pub fn cc(mut self, address: EmailAddress) -> Self {
+        if !self.cc.contains(&address) && address != self.to {
+            self.cc.push(address);
+        }
+        self
+    }
+
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.subject = subject.into();
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    pub fn build(self) -> Result<Email, PatternError> {
+        let subject = self.subject.trim().to_string();
+        if subject.is_empty() && self.body.trim().is_empty() {
+            return Err(PatternError::EmptyEmail);
+        }
+        Ok(Email { to: self.to, cc: self.cc, subject, body: self.body })
+    }
+}
+
+// ===== 3. TYPESTATE =====
+//
+// UNDERSTANDING TYPESTATE:
+// • Encode "where we are in the protocol" as a type parameter:
+//   HttpRequest<Unsigned> → sign() → HttpRequest<Signed> → send()
+// • Methods exist only on the states where they make sense: there is no
+//   send() on an unsigned request, and no header() on a signed one (it would
+//   invalidate the signature)
+// • Transitions take `self` by value, so the old state is gone - and send()
+//   consuming the request means it can't be sent twice
+// • The state types are zero-sized or tiny; the checks cost nothing at runtime
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unsigned;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Signed {
+    signature: u64,
+}
+
+/// An HTTP request that must be signed before it is sent, and is sent once.
+///
+/// Sending an unsigned request:
+///
+/// ```compile_fail,E0599
+/// use rust_basics::patterns::HttpRequest;
+///
+/// let response = HttpRequest::get("https://example.com").send();
+/// ```
+///
+/// Changing a request after signing it:
+///
+/// ```compile_fail,E0599
+/// use rust_basics::patterns::HttpRequest;
+///
+/// let request = HttpRequest::get("https://example.com").sign("key").header("x-debug", "1");
+/// ```
+///
+/// Sending the same request twice:
+///
+/// ```compile_fail,E0382
+/// use rust_basics::patterns::HttpRequest;
+///
+/// let request = HttpRequest::get("https://example.com").sign("key");
+/// let first = request.send();
+/// let second = request.send();
+/// ```
+// Deliberately not Clone: a clone could be sent a second time
+#[derive(Debug, PartialEq, Eq)]
+pub struct HttpRequest<State> {
+    method: Method,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+    state: State,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpRequest<Unsigned> {
+    pub fn get(url: &str) -> Self {
+        HttpRequest { method: Method::Get, url: url.to_string(), headers: Vec::new(), body: None, state: Unsigned }
+    }
+
+    pub fn post(url: &str, body: impl Into<String>) -> Self {
+        HttpRequest { body: Some(body.into()), method: Method::Post, ..HttpRequest::get(url) }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_lowercase(), value.to_string()));
+        self
+    }
+
+    pub fn sign(self, key: &str) -> HttpRequest<Signed> {
+        let mut hasher = DefaultHasher::new();
+        (key, self.method, &self.url, &self.headers, &self.body).hash(&mut hasher);
+        let signature = hasher.finish();
+        HttpRequest {
+            method: self.method,
+            url: self.url,
+            headers: self.headers,
+            body: self.body,
+            state: Signed { signature },
+        }
+    }
+}
+
+impl HttpRequest<Signed> {
+    pub fn signature(&self) -> u64 {
+        self.state.signature
+    }
+
+    // Simulated: a real client would write the request to a socket here
+    pub fn send(self) -> Response {
+        let status = if self.url.ends_with("/missing") { 404 } else { 200 };
+        let body = format!(
+            "{} {} with {} header(s), {} byte body, signature {:016x}",
+            self.method,
+            self.url,
+            self.headers.len(),
+            self.body.as_ref().map_or(0, String::len),
+            self.state.signature
+        );
+        Response { status, body }
+    }
+}
+
+// Available in every state
+impl<State> HttpRequest<State> {
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+}
+
+// ===== 4. STRATEGY =====
+//
+// UNDERSTANDING STRATEGY IN RUST:
+// • The classic version: an interface with one method, one class per
+//   algorithm. In Rust a closure IS a one-method object
+// • A blanket impl<F: Fn(u64) -> u64> Pricing for F lets plain closures and
+//   named structs (with their own configuration) be used interchangeably
+// • Box<dyn Pricing> picks the strategy at runtime; a generic
+//   P: Pricing picks it at compile time and can be inlined
+// • Pricing asks for Fn, not FnMut: a strategy that quietly changes its own
+//   state on every call doesn't compile
+
+pub trait Pricing {
+    // Final price in cents for a cart that costs `subtotal` cents
+    fn price(&self, subtotal: u64) -> u64;
+}
+
+impl<F: Fn(u64) -> u64> Pricing for F {
+    fn price(&self, subtotal: u64) -> u64 {
+        self(subtotal)
+    }
+}
+
+pub fn full_price() -> impl Fn(u64) -> u64 {
+    |subtotal| subtotal
+}
+
+pub fn percent_off(percent: u64) -> impl Fn(u64) -> u64 {
+    let percent = percent.min(100);
+    move |subtotal| subtotal - subtotal * percent / 100
+}
+
+// A strategy with more configuration than fits comfortably in a closure:
+// the best discount whose threshold the subtotal reaches
+pub struct TieredDiscount {
+    // (threshold in cents, percent off)
+    tiers: Vec<(u64, u64)>,
+}
+
+impl TieredDiscount {
+    pub fn new(tiers: &[(u64, u64)]) -> Self {
+        TieredDiscount { tiers: tiers.to_vec() }
+    }
+}
+
+impl Pricing for TieredDiscount {
+    fn price(&self, subtotal: u64) -> u64 {
+        let percent = self
+            .tiers
+            .iter()
+            .filter(|(threshold, _)| subtotal >= *threshold)
+            .map(|(_, percent)| *percent)
+            .max()
+            .unwrap_or(0);
+        percent_off(percent)(subtotal)
+    }
+}
+
+/// A checkout whose pricing strategy is chosen at runtime.
+///
+/// Strategies must be `Fn` - one that mutates what it captured is rejected:
+///
+/// ```compile_fail,E0525
+/// use rust_basics::patterns::Checkout;
+///
+/// let mut calls = 0;
+/// let checkout = Checkout::new(move |subtotal: u64| {
+///     calls += 1;
+///     subtotal
+/// });
+/// ```
+pub struct Checkout {
+    pricing: Box<dyn Pricing>,
+}
+
+impl Checkout {
+    pub fn new(pricing: impl Pricing + 'static) -> Self {
+        Checkout { pricing: Box::new(pricing) }
+    }
+
+    pub fn set_pricing(&mut self, pricing: impl Pricing + 'static) {
+        self.pricing = Box::new(pricing);
+    }
+
+    pub fn total(&self, prices: &[u64]) -> u64 {
+        self.pricing.price(prices.iter().sum())
+    }
+}
+
+// Static dispatch: one copy of this function per strategy type
+pub fn total_with<P: Pricing>(pricing: &P, prices: &[u64]) -> u64 {
+    pricing.price(prices.iter().sum())
+}
+
+// ===== 5. RAII GUARDS =====
+//
+// UNDERSTANDING RAII GUARDS:
+// • Resource Acquisition Is Initialization: acquiring something returns a
+//   guard, and the guard's Drop releases it - MutexGuard, File and
+//   tokio's SemaphorePermit all work this way
+// • Drop runs on every exit path: normal return, early return through ?,
+//   and unwinding panics - cleanup can't be forgotten
+// • A guard that holds &mut borrows its owner, so the borrow checker stops
+//   anyone from touching the owner while the guard is alive
+// • commit(self) consumes the guard; Drop sees it was committed and does
+//   nothing. Otherwise Drop rolls back
+
+#[derive(Debug, Default)]
+pub struct Ledger {
+    balances: HashMap<String, i64>,
+    log: Vec<String>,
+}
+
+impl Ledger {
+    pub fn new(accounts: &[(&str, i64)]) -> Self {
+        let balances = accounts.iter().map(|(name, balance)| (name.to_string(), *balance)).collect();
+        Ledger { balances, log: Vec::new() }
+    }
+
+    pub fn balance(&self, account: &str) -> i64 {
+        self.balances.get(account).copied().unwrap_or(0)
+    }
+
+    pub fn log(&self) -> &[String] {
+        &self.log
+    }
+
+    pub fn begin(&mut self) -> Transaction<'_> {
+        Transaction { ledger: self, undo: Vec::new(), committed: false }
+    }
+}
+
+/// Transfers that are undone when dropped, unless [`Transaction::commit`] is called.
+///
+/// The transaction borrows the ledger mutably, so the ledger can't be read
+/// halfway through:
+///
+/// ```compile_fail,E0502
+/// use rust_basics::patterns::Ledger;
+///
+/// let mut ledger = Ledger::new(&[("alice", 100), ("bob", 0)]);
+/// let mut tx = ledger.begin();
+/// tx.transfer("alice", "bob", 30).unwrap();
+/// println!("{}", ledger.balance("bob"));
+/// tx.commit();
+/// ```
+///
+/// `commit` consumes the transaction, so it can't be used afterwards:
+///
+/// ```compile_fail,E0382
+/// use rust_basics::patterns::Ledger;
+///
+/// let mut ledger = Ledger::new(&[("alice", 100), ("bob", 0)]);
+/// let mut tx = ledger.begin();
+/// tx.commit();
+/// tx.transfer("alice", "bob", 30).unwrap();
+/// ```
+pub struct Transaction<'a> {
+    ledger: &'a mut Ledger,
+    // (from, to, amount) of every applied transfer, to reverse on rollback
+    undo: Vec<(String, String, i64)>,
+    committed: bool,
+}
+
+impl Transaction<'_> {
+    // Applied immediately, so later transfers see the new balances
+    pub fn transfer(&mut self, from: &str, to: &str, amount: i64) -> Result<(), PatternError> {
+        let balance = self.ledger.balance(from);
+        if amount > balance {
+            return Err(PatternError::InsufficientFunds { account: from.to_string(), balance, amount });
+        }
+        self.apply(from, to, amount);
+        self.undo.push((from.to_string(), to.to_string(), amount));
+        Ok(())
+    }
+
+    pub fn commit(mut self) {
+        self.committed = true;
+        let message = format!("committed {} transfer(s)", self.undo.len());
+        self.ledger.log.push(message);
+    }
+
+    fn apply(&mut self, from: &str, to: &str, amount: i64) {
+        *self.ledger.balances.entry(from.to_string()).or_insert(0) -= amount;
+        *self.ledger.balances.entry(to.to_string()).or_insert(0) += amount;
+    }
+}
+
+impl Drop for Transaction<'_> {
+    fn drop(&mut self) {
+        if self.committed {
+            return;
+        }
+        let undo = std::mem::take(&mut self.undo);
+        for (from, to, amount) in undo.iter().rev() {
+            self.apply(to, from, *amount);
+        }
+        self.ledger.log.push(format!("rolled back {} transfer(s)", undo.len()));
+    }
+}
+
+// Moves money from every payer to one account, all or nothing: the first
+// failing transfer returns early through ?, and dropping the transaction
+// undoes the ones before it
+pub fn collect_from(ledger: &mut Ledger, payers: &[(&str, i64)], to: &str) -> Result<(), PatternError> {
+    let mut tx = ledger.begin();
+    for (payer, amount) in payers {
+        tx.transfer(payer, to, *amount)?;
+    }
+    tx.commit();
+    Ok(())
+}
+
+// The smallest guard: runs a closure when the scope ends, like Go's defer
+pub struct Defer<F: FnOnce()> {
+    action: Option<F>,
+}
+
+pub fn defer<F: FnOnce()>(action: F) -> Defer<F> {
+    Defer { action: Some(action) }
+}
+
+impl<F: FnOnce()> Drop for Defer<F> {
+    fn drop(&mut self) {
+        if let Some(action) = self.action.take() {
+            action();
+        }
+    }
+}
+
+// ===== 6. DEMONSTRATION FUNCTION =====
+
+pub fn patterns_report() -> Report {
+    let mut report = Report::new("🦀 RUST DESIGN PATTERNS DEEP STUDY 🦀");
+
+    // ===== NEWTYPE =====
+    let newtype = report.section("1️⃣ NEWTYPE:");
+    let user = UserId(42);
+    newtype.example(format!("orders_for({:?})", user), format!("{:?}", orders_for(user)));
+    for input in ["Ada@Example.com", "no-at-sign", "a@b@c.com", "user@localhost"] {
+        let parsed = match EmailAddress::parse(input) {
+            Ok(address) => format!("{} (domain {})", address, address.domain()),
+            Err(e) => format!("failed: {}", e),
+        };
+        newtype.example(format!("EmailAddress::parse({:?})", input), parsed);
+    }
+
+    // ===== BUILDER =====
+    let builder = report.section("2️⃣ BUILDER:");
+    let address = |s: &str| EmailAddress::parse(s).expect("valid address");
+    let email = Email::builder(address("ada@example.com"))
+        .cc(address("grace@example.com"))
+        .cc(address("grace@example.com"))
+        .subject("  Build passed  ")
+        .body("All 64 tests green.")
+        .build();
+    match email {
+        Ok(email) => builder.text(format!("To: {} | Cc: {} | Subject: {:?}", email.to, AddressList(&email.cc), email.subject)),
+        Err(e) => builder.example("build()", format!("failed: {}", e)),
+    };
+    if let Err(e) = Email::builder(address("ada@example.com")).build() {
+        builder.example("Empty email", e);
+    }
+
+    // ===== TYPESTATE =====
+    let typestate = report.section("3️⃣ TYPESTATE:");
+    let request = HttpRequest::post("https://api.example.com/orders", r#"{"item": 7}"#)
+        .header("Content-Type", "application/json")
+        .sign("secret-key");
+    typestate.example(format!("Signed {} {}", request.method(), request.url()), format!("{:016x}", request.signature()));
+    let response = request.send();
+    typestate.example(format!("Response {}", response.status), response.body);
+    // request.send() again would not compile: send() took the request by value
+
+    // ===== STRATEGY =====
+    let strategy = report.section("4️⃣ STRATEGY:");
+    let cart = [2_500, 4_000, 1_500];
+    let mut checkout = Checkout::new(full_price());
+    strategy.example("Full price", format!("{} cents", checkout.total(&cart)));
+    checkout.set_pricing(percent_off(10));
+    strategy.example("10% off", format!("{} cents", checkout.total(&cart)));
+    checkout.set_pricing(|subtotal: u64| subtotal.saturating_sub(1_000));
+    strategy.example("$10 off (closure)", format!("{} cents", checkout.total(&cart)));
+    let tiers = TieredDiscount::new(&[(5_000, 5), (10_000, 15)]);
+    strategy.example("Tiered (struct)", format!("{} cents", total_with(&tiers, &cart)));
+
+    // ===== RAII GUARDS =====
+    let raii = report.section("5️⃣ RAII GUARDS:");
+    let mut ledger = Ledger::new(&[("alice", 100), ("bob", 20), ("shop", 0)]);
+    let paid = collect_from(&mut ledger, &[("alice", 30), ("bob", 10)], "shop");
+    raii.example("Collect 30 + 10", format!("{:?}, shop has {}", paid, ledger.balance("shop")));
+    let paid = collect_from(&mut ledger, &[("alice", 50), ("bob", 50)], "shop");
+    raii.example("Collect 50 + 50", format!("{:?}", paid.map_err(|e| e.to_string())))
+        .example(
+            "After rollback",
+            format!(
+                "alice {}, bob {}, shop {} | log {:?}",
+                ledger.balance("alice"),
+                ledger.balance("bob"),
+                ledger.balance("shop"),
+                ledger.log()
+            ),
+        );
+    // The deferred actions can't write to the section while it is borrowed
+    // here, so they note what they did and the notes are added afterwards
+    let order = RefCell::new(Vec::new());
+    {
+        let _first = defer(|| order.borrow_mut().push("defer: registered first, runs last"));
+        let _second = defer(|| order.borrow_mut().push("defer: registered second, runs first"));
+        order.borrow_mut().push("defer: leaving the scope");
+    }
+    for line in order.into_inner() {
+        raii.text(line);
+    }
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 DESIGN PATTERNS SUMMARY:")
+        .check("Newtype: distinct types and checked values at zero cost")
+        .check("Builder: required values as arguments, the rest chained, build() validates")
+        .check("Typestate: invalid call orders don't compile")
+        .check("Strategy: closures are one-method objects")
+        .check("RAII: Drop cleans up on every exit path, including ? and panics");
+
+    report
+}
+
+pub fn demonstrate_patterns() {
+    print!("{}", patterns_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct PatternsModule;
+
+impl LearningModule for PatternsModule {
+    fn number(&self) -> u8 {
+        12
+    }
+
+    fn name(&self) -> &'static str {
+        "patterns"
+    }
+
+    fn title(&self) -> &'static str {
+        "DESIGN PATTERNS DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Design Patterns"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["traits", "lifetimes", "errors"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_patterns() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { patterns_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&PatternsModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// DESIGN PATTERN GUIDELINES:
+// 1. Let types carry the rules: a newtype or a typestate is checked on every
+//    build, a comment is not
+// 2. Put required values in constructors, optional ones in builders
+// 3. Reach for a closure before a one-method trait; add the trait (with a
+//    blanket impl for closures) when strategies need names or configuration
+// 4. Tie cleanup to Drop instead of to a method callers must remember
+// 5. Consuming methods (self by value) end a value's life - use them for
+//    "send", "commit" and "build"
+//
+// WHEN NOT TO:
+// • A typestate with many states and transitions gets verbose - an enum
+//   checked at runtime may read better
+// • Builders for structs with two fields are ceremony; use a constructor
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(s: &str) -> EmailAddress {
+        EmailAddress::parse(s).unwrap()
+    }
+
+    #[test]
+    fn report_shows_each_pattern_at_work() {
+        let report = patterns_report();
+        let newtype = report.find("NEWTYPE").unwrap();
+        assert_eq!(newtype.output("EmailAddress::parse(\"Ada@Example.com\")"), Some("ada@example.com (domain example.com)"));
+
+        let strategy: Vec<_> = report.find("STRATEGY").unwrap().outputs().collect();
+        assert_eq!(strategy, ["8000 cents", "7200 cents", "7000 cents", "7600 cents"]);
+
+        // Rolled back, and the deferred actions ran in reverse
+        let raii = report.find("RAII GUARDS").unwrap();
+        assert!(raii.output("After rollback").unwrap().starts_with("alice 70, bob 10, shop 40"));
+        let deferred: Vec<String> = raii.entries.iter().map(ToString::to_string).filter(|line| line.starts_with("defer")).collect();
+        assert_eq!(deferred, ["defer: leaving the scope", "defer: registered second, runs first", "defer: registered first, runs last"]);
+    }
+
+    #[test]
+    fn email_addresses_are_checked_once() {
+        assert_eq!(address(" Ada@Example.COM ").as_ref(), "ada@example.com");
+        assert_eq!(address("x@mail.example.org").domain(), "mail.example.org");
+        for bad in ["", "plain", "@example.com", "a@b@c.com", "a@localhost", "a@.com", "a@example."] {
+            assert_eq!(EmailAddress::parse(bad), Err(PatternError::InvalidEmail(bad.to_string())));
+        }
+    }
+
+    #[test]
+    fn builder_dedupes_cc_and_validates_content() {
+        let email = Email::builder(address("a@example.com"))
+            .cc(address("b@example.com"))
+            .cc(address("B@example.com"))
+            .cc(address("a@example.com"))
+            .subject(" Hi ")
+            .build()
+            .unwrap();
+        assert_eq!(email.cc, [address("b@example.com")]);
+        assert_eq!(email.subject, "Hi");
+        assert_eq!(AddressList(&[address("a@x.io"), address("b@y.io")]).to_string(), "a@x.io, b@y.io");
+
+        let empty = Email::builder(address("a@example.com")).subject("  ").body("\n").build();
+        assert_eq!(empty, Err(PatternError::EmptyEmail));
+    }
+
+    #[test]
+    fn signature_covers_everything_that_was_signed() {
+        let base = || HttpRequest::get("https://example.com/items").header("Accept", "text/plain");
+        let signed = base().sign("key");
+        assert_eq!(signed.signature(), base().sign("key").signature());
+        assert_ne!(signed.signature(), base().sign("other").signature());
+        assert_ne!(signed.signature(), base().header("x", "1").sign("key").signature());
+        assert_eq!(signed.headers(), [("accept".to_string(), "text/plain".to_string())]);
+
+        let response = signed.send();
+        assert_eq!(response.status, 200);
+        assert!(response.body.starts_with("GET https://example.com/items with 1 header(s), 0 byte body"));
+        assert_eq!(HttpRequest::post("https://example.com/missing", "{}").sign("key").send().status, 404);
+    }
+
+    #[test]
+    fn strategies_are_interchangeable() {
+        let cart = [2_500, 4_000, 1_500];
+        let mut checkout = Checkout::new(full_price());
+        assert_eq!(checkout.total(&cart), 8_000);
+        checkout.set_pricing(percent_off(25));
+        assert_eq!(checkout.total(&cart), 6_000);
+        checkout.set_pricing(TieredDiscount::new(&[(5_000, 5), (10_000, 15)]));
+        assert_eq!(checkout.total(&cart), 7_600);
+
+        assert_eq!(total_with(&percent_off(250), &cart), 0, "capped at 100%");
+        assert_eq!(total_with(&|subtotal: u64| subtotal / 2, &cart), 4_000);
+        assert_eq!(total_with(&TieredDiscount::new(&[]), &cart), 8_000);
+    }
+
+    #[test]
+    fn dropped_transactions_roll_back() {
+        let mut ledger = Ledger::new(&[("alice", 100), ("bob", 20)]);
+        {
+            let mut tx = ledger.begin();
+            tx.transfer("alice", "bob", 60).unwrap();
+            tx.transfer("bob", "carol", 70).unwrap();
+        }
+        assert_eq!((ledger.balance("alice"), ledger.balance("bob"), ledger.balance("carol")), (100, 20, 0));
+
+        let err = collect_from(&mut ledger, &[("alice", 10), ("bob", 30)], "shop").unwrap_err();
+        assert_eq!(err, PatternError::InsufficientFunds { account: "bob".to_string(), balance: 20, amount: 30 });
+        assert_eq!(ledger.balance("alice"), 100);
+
+        collect_from(&mut ledger, &[("alice", 10), ("bob", 20)], "shop").unwrap();
+        assert_eq!((ledger.balance("alice"), ledger.balance("bob"), ledger.balance("shop")), (90, 0, 30));
+        assert_eq!(ledger.log(), ["rolled back 2 transfer(s)", "rolled back 1 transfer(s)", "committed 2 transfer(s)"]);
+    }
+
+    #[test]
+    fn deferred_actions_run_in_reverse_even_when_panicking() {
+        let order = RefCell::new(Vec::new());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _first = defer(|| order.borrow_mut().push("first"));
+            let _second = defer(|| order.borrow_mut().push("second"));
+            panic!("boom");
+        }));
+        assert!(result.is_err());
+        assert_eq!(*order.borrow(), ["second", "first"]);
+    }
+}
@@ -0,0 +1,641 @@
+// ===== PIN AND FUTURE INTERNALS DEEP STUDY =====
+//
+// WHAT'S UNDER ASYNC/AWAIT?
+// async_await.rs showed how to USE futures. This module opens them up:
+// • Why futures need Pin, and what Unpin means
+// • Why self-referential structs break when moved
+// • How an `async fn` becomes an enum-based state machine
+// • How an executor and a timer "reactor" cooperate through Wakers
+//
+// THE BIG PICTURE:
+//   Executor ──poll──▶ Future ──registers Waker──▶ Reactor (timers, IO)
+//      ▲                                               │
+//      └──────────── waker.wake() re-queues task ◀─────┘
+
+use crate::report::Report;
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::{Duration, Instant};
+
+// ===== 1. THE SELF-REFERENCE PROBLEM =====
+//
+// UNDERSTANDING THE PITFALL:
+// An async fn that holds a reference across .await compiles to a struct that
+// contains BOTH the data and a pointer into that data:
+//
+//     async fn example() {
+//         let buf = [0u8; 16];
+//         let slice = &buf[..];      // points INTO the future's own state
+//         something().await;         // state (buf + slice) saved here
+//         use(slice);
+//     }
+//
+// Moving such a struct copies the bytes to a new address, but the inner
+// pointer still points at the OLD address. In Rust every value can be moved
+// by default (mem::swap, returning it, pushing into a Vec), so we need a way
+// to promise "this will not move anymore". That promise is Pin.
+
+// A NAIVE SELF-REFERENTIAL STRUCT (raw pointer, because references can't do this)
+pub struct SelfReferential {
+    pub data: String,
+    pub ptr_to_data: *const String,
+}
+
+impl SelfReferential {
+    pub fn new(data: &str) -> Self {
+        SelfReferential {
+            data: data.to_string(),
+            ptr_to_data: std::ptr::null(),
+        }
+    }
+
+    pub fn init(&mut self) {
+        self.ptr_to_data = &self.data;
+    }
+
+    // Is the internal pointer still pointing at our own field?
+    pub fn is_consistent(&self) -> bool {
+        std::ptr::eq(self.ptr_to_data, &self.data)
+    }
+}
+
+// ===== 2. PIN AND UNPIN =====
+//
+// UNDERSTANDING Pin<P>:
+// • Pin wraps a POINTER (Pin<&mut T>, Pin<Box<T>>), not a value
+// • It guarantees the pointee won't be moved until it is dropped...
+// • ...unless T: Unpin, in which case Pin is a no-op and you can get &mut T back
+//
+// UNDERSTANDING Unpin:
+// • An auto trait: almost every type is Unpin (i32, String, Vec, Box<T>, ...)
+// • Types opt OUT with a PhantomPinned field (or by containing a !Unpin type)
+// • Compiler-generated async state machines are !Unpin (they may self-reference)
+//
+// CREATING PINS:
+// • Box::pin(value): Pin on the heap - always safe
+// • std::pin::pin!(value): Pin on the stack - safe, value can't escape the scope
+// • Pin::new(&mut value): Only for T: Unpin (safe because moving is harmless)
+// • Pin::new_unchecked: unsafe - YOU promise never to move it
+
+// THE SAME STRUCT, MADE !Unpin
+pub struct PinnedSelfReferential {
+    data: String,
+    ptr_to_data: *const String,
+    _pin: PhantomPinned, // Opts out of Unpin
+}
+
+impl PinnedSelfReferential {
+    // Constructing directly into a Pin<Box<..>> means it's never moved after init
+    pub fn new(data: &str) -> Pin<Box<Self>> {
+        let mut boxed = Box::pin(PinnedSelfReferential {
+            data: data.to_string(),
+            ptr_to_data: std::ptr::null(),
+            _pin: PhantomPinned,
+        });
+
+        let self_ptr: *const String = &boxed.data;
+        // SAFETY: We only write a field; we never move the pinned value out
+        unsafe {
+            boxed.as_mut().get_unchecked_mut().ptr_to_data = self_ptr;
+        }
+        boxed
+    }
+
+    pub fn data(self: Pin<&Self>) -> &str {
+        // SAFETY: ptr_to_data was set in new() and the value can't move while pinned
+        unsafe { &*self.ptr_to_data }
+    }
+
+    pub fn is_consistent(self: Pin<&Self>) -> bool {
+        std::ptr::eq(self.ptr_to_data, &self.data)
+    }
+}
+
+// Compile-time checks: this function only accepts Unpin types
+pub fn require_unpin<T: Unpin>(_: &T) -> &'static str {
+    "Unpin ✓"
+}
+
+// ===== 3. PIN PROJECTION =====
+//
+// UNDERSTANDING PROJECTION:
+// A future that wraps another future gets Pin<&mut Self>, but needs
+// Pin<&mut Inner> to poll the inner one. Turning one into the other is
+// "pin projection". Structural fields (the inner future) stay pinned;
+// plain data fields (counters) can be accessed normally.
+// The pin-project crate generates this code safely; here it is by hand.
+
+pub struct CountPolls<F> {
+    inner: F,
+    polls: u32,
+}
+
+impl<F> CountPolls<F> {
+    pub fn new(inner: F) -> Self {
+        CountPolls { inner, polls: 0 }
+    }
+}
+
+impl<F: Future> Future for CountPolls<F> {
+    type Output = (F::Output, u32);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `inner` is never moved out of `self`; `polls` is not structurally pinned
+        let this = unsafe { self.get_unchecked_mut() };
+        this.polls += 1;
+        let inner = unsafe { Pin::new_unchecked(&mut this.inner) };
+
+        match inner.poll(cx) {
+            Poll::Ready(output) => Poll::Ready((output, this.polls)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+// ===== 4. A HAND-IMPLEMENTED TIMER FUTURE + REACTOR =====
+//
+// UNDERSTANDING THE REACTOR:
+// Leaf futures (timers, sockets) don't do the waiting themselves. They register
+// their Waker with a REACTOR and return Pending. When the event happens, the
+// reactor calls wake(), and the executor polls the future again.
+//
+// Our reactor is a min-heap of (deadline, waker) kept per thread. The executor
+// asks it "when is the next deadline?" and sleeps exactly that long.
+
+thread_local! {
+    static TIMERS: RefCell<BinaryHeap<Reverse<TimerEntry>>> = const { RefCell::new(BinaryHeap::new()) };
+}
+
+struct TimerEntry {
+    deadline: Instant,
+    waker: Waker,
+}
+
+// Ordered by deadline only (wakers aren't comparable)
+impl PartialEq for TimerEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.deadline == other.deadline
+    }
+}
+impl Eq for TimerEntry {}
+impl PartialOrd for TimerEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for TimerEntry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.deadline.cmp(&other.deadline)
+    }
+}
+
+pub struct Timer {
+    deadline: Instant,
+}
+
+impl Timer {
+    pub fn after(duration: Duration) -> Self {
+        Timer { deadline: Instant::now() + duration }
+    }
+}
+
+impl Future for Timer {
+    type Output = ();
+
+    // Timer is Unpin (only an Instant inside), so Pin<&mut Self> derefs freely
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+
+        // Not yet: hand our waker to the reactor and yield
+        TIMERS.with(|timers| {
+            timers.borrow_mut().push(Reverse(TimerEntry {
+                deadline: self.deadline,
+                waker: cx.waker().clone(),
+            }))
+        });
+        Poll::Pending
+    }
+}
+
+// Wake every timer whose deadline has passed; return the next pending deadline
+fn fire_due_timers() -> Option<Instant> {
+    TIMERS.with(|timers| {
+        let mut timers = timers.borrow_mut();
+        let now = Instant::now();
+        while timers.peek().is_some_and(|Reverse(entry)| entry.deadline <= now) {
+            let Reverse(entry) = timers.pop().unwrap();
+            entry.waker.wake();
+        }
+        timers.peek().map(|Reverse(entry)| entry.deadline)
+    })
+}
+
+// ===== 5. A SINGLE-THREADED EXECUTOR FROM SCRATCH =====
+//
+// HOW IT WORKS:
+// • Each spawned future becomes a Task: the pinned, boxed future + a queue sender
+// • The Task itself is the Waker (via the Wake trait): wake() re-sends it to the queue
+// • run() polls queued tasks; when none are ready it sleeps until the reactor's
+//   next timer is due and fires it, which re-queues the waiting task
+// • run() returns when every task has completed
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+struct Task {
+    future: Mutex<Option<BoxFuture>>, // None once completed
+    queue: Sender<Arc<Task>>,
+}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        // Ignore errors: the executor may already be gone
+        let _ = self.queue.send(self.clone());
+    }
+}
+
+pub struct MiniExecutor {
+    queue: Receiver<Arc<Task>>,
+    sender: Sender<Arc<Task>>,
+    live_tasks: Arc<AtomicUsize>,
+    pub polls: usize,
+}
+
+impl Default for MiniExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MiniExecutor {
+    pub fn new() -> Self {
+        let (sender, queue) = mpsc::channel();
+        MiniExecutor {
+            queue,
+            sender,
+            live_tasks: Arc::new(AtomicUsize::new(0)),
+            polls: 0,
+        }
+    }
+
+    pub fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
+        self.live_tasks.fetch_add(1, Ordering::SeqCst);
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(Box::pin(future))),
+            queue: self.sender.clone(),
+        });
+        self.sender.send(task).expect("executor queue closed");
+    }
+
+    pub fn run(&mut self) {
+        while self.live_tasks.load(Ordering::SeqCst) > 0 {
+            // 1. Let the reactor wake every timer that is due (re-queues their tasks)
+            let next_deadline = fire_due_timers();
+
+            // 2. Take the next ready task, sleeping at most until the next timer
+            let task = match next_deadline {
+                Some(deadline) => {
+                    match self.queue.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                        Ok(task) => task,
+                        Err(_) => continue, // A timer is due - go fire it
+                    }
+                }
+                // No timers: a pending task with nothing queued could never be woken
+                None => self
+                    .queue
+                    .try_recv()
+                    .expect("deadlock: tasks are pending with nothing to wake them"),
+            };
+
+            self.poll_task(task);
+        }
+    }
+
+    fn poll_task(&mut self, task: Arc<Task>) {
+        let mut slot = task.future.lock().unwrap();
+        let Some(future) = slot.as_mut() else {
+            return; // Spurious wake of a finished task
+        };
+
+        // The task itself is the waker: waking it re-queues it
+        let waker = Waker::from(task.clone());
+        let mut cx = Context::from_waker(&waker);
+        self.polls += 1;
+
+        if future.as_mut().poll(&mut cx).is_ready() {
+            *slot = None; // Drop the finished future
+            self.live_tasks.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+// ===== 6. HOW async fn DESUGARS =====
+//
+// THE SOURCE:
+//     async fn add_slowly(a: u32, b: u32) -> u32 {
+//         Timer::after(10ms).await;
+//         let sum = a + b;
+//         Timer::after(10ms).await;
+//         sum * 2
+//     }
+//
+// THE COMPILER GENERATES (conceptually) an enum with one variant per .await
+// point, holding every local that is alive across that point, plus a poll()
+// that runs from one .await to the next. Below is that state machine by hand.
+
+pub async fn add_slowly(a: u32, b: u32) -> u32 {
+    Timer::after(Duration::from_millis(10)).await;
+    let sum = a + b;
+    Timer::after(Duration::from_millis(10)).await;
+    sum * 2
+}
+
+pub enum AddSlowly {
+    Start { a: u32, b: u32 },
+    FirstWait { timer: Timer, a: u32, b: u32 },
+    SecondWait { timer: Timer, sum: u32 },
+    Done,
+}
+
+impl AddSlowly {
+    pub fn new(a: u32, b: u32) -> Self {
+        AddSlowly::Start { a, b }
+    }
+}
+
+impl Future for AddSlowly {
+    type Output = u32;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+        // All fields are Unpin, so AddSlowly is Unpin and we can use &mut freely.
+        // (Compiler-generated machines may hold self-references, so theirs aren't.)
+        loop {
+            match &mut *self {
+                AddSlowly::Start { a, b } => {
+                    // Code before the first .await
+                    let (a, b) = (*a, *b);
+                    *self = AddSlowly::FirstWait { timer: Timer::after(Duration::from_millis(10)), a, b };
+                }
+                AddSlowly::FirstWait { timer, a, b } => {
+                    // First .await: return Pending until the timer is done
+                    if Pin::new(timer).poll(cx).is_pending() {
+                        return Poll::Pending;
+                    }
+                    let sum = *a + *b; // Code between the awaits
+                    *self = AddSlowly::SecondWait { timer: Timer::after(Duration::from_millis(10)), sum };
+                }
+                AddSlowly::SecondWait { timer, sum } => {
+                    if Pin::new(timer).poll(cx).is_pending() {
+                        return Poll::Pending;
+                    }
+                    let result = *sum * 2; // Code after the last .await
+                    *self = AddSlowly::Done;
+                    return Poll::Ready(result);
+                }
+                AddSlowly::Done => panic!("AddSlowly polled after completion"),
+            }
+        }
+    }
+}
+
+// ===== 7. DEMONSTRATION FUNCTION =====
+
+pub fn pin_and_futures_report() -> Report {
+    let mut report = Report::new("🦀 RUST PIN AND FUTURE INTERNALS DEEP STUDY 🦀");
+
+    // ===== SELF-REFERENCE PITFALL =====
+    let pitfall = report.section("1️⃣ SELF-REFERENTIAL STRUCTS BREAK WHEN MOVED:");
+
+    let mut first = SelfReferential::new("first");
+    let mut second = SelfReferential::new("second");
+    first.init();
+    second.init();
+    pitfall.example("Before swap", format!("first consistent = {}, second consistent = {}", first.is_consistent(), second.is_consistent()));
+
+    std::mem::swap(&mut first, &mut second); // Moves the bytes, pointers keep old addresses
+    pitfall
+        .example("After swap", format!("first consistent = {}, second consistent = {}", first.is_consistent(), second.is_consistent()))
+        .text("(first.ptr_to_data now points at the OTHER struct's field - a dangling bug waiting to happen)");
+
+    // ===== PIN =====
+    let pin = report.section("2️⃣ PIN AND UNPIN:");
+
+    let pinned = PinnedSelfReferential::new("pinned data");
+    pin.example("Pinned value reads through its self-pointer", format!("'{}'", pinned.as_ref().data()))
+        .example("Pinned value consistent", pinned.as_ref().is_consistent());
+    // std::mem::swap(&mut *pinned_a, &mut *pinned_b);  ❌ won't compile: no &mut access to a !Unpin pinned value
+
+    let moved_box = pinned; // Moving the Box moves the POINTER, not the pinned heap value
+    pin.example("After moving the Pin<Box<..>> itself", format!("consistent = {}", moved_box.as_ref().is_consistent()))
+        .text(format!(
+            "i32: {}, String: {}, Timer: {}",
+            require_unpin(&1),
+            require_unpin(&String::new()),
+            require_unpin(&Timer::after(Duration::ZERO))
+        ))
+        .text("PinnedSelfReferential and `async {}` blocks are !Unpin - require_unpin() rejects them at compile time");
+
+    // ===== DESUGARING + EXECUTOR =====
+    let results = Arc::new(Mutex::new(Vec::new()));
+    let mut executor = MiniExecutor::new();
+
+    {
+        let results = Arc::clone(&results);
+        executor.spawn(async move {
+            let (value, polls) = CountPolls::new(add_slowly(2, 3)).await;
+            results.lock().unwrap().push(("async fn add_slowly(2, 3)", format!("{} after {} polls", value, polls)));
+        });
+    }
+    {
+        let results = Arc::clone(&results);
+        executor.spawn(async move {
+            let (value, polls) = CountPolls::new(AddSlowly::new(2, 3)).await;
+            results.lock().unwrap().push(("enum AddSlowly::new(2, 3)", format!("{} after {} polls", value, polls)));
+        });
+    }
+
+    let start = Instant::now();
+    executor.run();
+    let desugaring = report.section("3️⃣ ASYNC FN VS HAND-WRITTEN STATE MACHINE (on our own executor):");
+    for (code, output) in results.lock().unwrap().drain(..) {
+        desugaring.example(code, output);
+    }
+    desugaring.text(format!("Both ran concurrently: {:?} total (not 40ms), {} task polls", start.elapsed(), executor.polls));
+
+    // ===== MANY TASKS =====
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let mut executor = MiniExecutor::new();
+    for (name, delay_ms) in [("slow", 30), ("fast", 5), ("medium", 15)] {
+        let order = Arc::clone(&order);
+        executor.spawn(async move {
+            Timer::after(Duration::from_millis(delay_ms)).await;
+            order.lock().unwrap().push(name);
+        });
+    }
+    executor.run();
+    report
+        .section("4️⃣ EXECUTOR + REACTOR WITH MANY TIMERS:")
+        .example("Completion order (spawned slow, fast, medium)", format!("{:?}", order.lock().unwrap()));
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 PIN & FUTURE INTERNALS SUMMARY:")
+        .check("async fn = enum state machine with a poll() method")
+        .check("Self-references make moving unsafe - Pin forbids the move")
+        .check("Unpin types opt out of pinning guarantees (most types)")
+        .check("Pin projection gives Pin<&mut Field> for inner futures")
+        .check("Leaf futures register Wakers with a reactor")
+        .check("An executor is a queue of tasks woken by those Wakers");
+
+    report
+}
+
+pub fn demonstrate_pin_and_futures() {
+    print!("{}", pin_and_futures_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct PinFuturesModule;
+
+impl LearningModule for PinFuturesModule {
+    fn number(&self) -> u8 {
+        10
+    }
+
+    fn name(&self) -> &'static str {
+        "pin_futures"
+    }
+
+    fn title(&self) -> &'static str {
+        "PIN AND FUTURE INTERNALS DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pin & Future Internals"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["smart_pointers", "async_await"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_pin_and_futures() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { pin_and_futures_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&PinFuturesModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// PIN BEST PRACTICES:
+// 1. Use Box::pin or pin!() - avoid Pin::new_unchecked unless you must
+// 2. Use the pin-project crate instead of hand-written projections
+// 3. Only !Unpin types need any of this; most of your types are Unpin
+// 4. A Future must register a Waker before returning Pending
+// 5. Never poll a future again after it returned Ready
+//
+// MENTAL MODEL:
+// • Future = "what to do next", Waker = "when to try again", Executor = "who tries"
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Drives a future on MiniExecutor and hands back its output
+    fn run_to_completion<T: Send + 'static>(future: impl Future<Output = T> + Send + 'static) -> (T, usize) {
+        let (tx, rx) = mpsc::channel();
+        let mut executor = MiniExecutor::new();
+        executor.spawn(async move {
+            tx.send(future.await).unwrap();
+        });
+        executor.run();
+        (rx.recv().unwrap(), executor.polls)
+    }
+
+    #[test]
+    fn report_shows_the_move_hazard_and_timer_order() {
+        let report = pin_and_futures_report();
+        let pitfall = report.find("SELF-REFERENTIAL").unwrap();
+        assert_eq!(pitfall.output("Before swap"), Some("first consistent = true, second consistent = true"));
+        assert_eq!(pitfall.output("After swap"), Some("first consistent = false, second consistent = false"));
+
+        let pin = report.find("PIN AND UNPIN").unwrap();
+        assert_eq!(pin.output("After moving the Pin<Box<..>> itself"), Some("consistent = true"));
+
+        let desugaring = report.find("HAND-WRITTEN STATE MACHINE").unwrap();
+        // The compiler's state machine and the hand-written one agree
+        assert_eq!(desugaring.output("async fn add_slowly(2, 3)"), desugaring.output("enum AddSlowly::new(2, 3)"));
+
+        let timers = report.find("MANY TIMERS").unwrap();
+        assert_eq!(timers.output("Completion order (spawned slow, fast, medium)"), Some(r#"["fast", "medium", "slow"]"#));
+    }
+
+    #[test]
+    fn moving_breaks_an_unpinned_self_reference() {
+        let mut value = SelfReferential::new("hello");
+        value.init();
+        assert!(value.is_consistent());
+
+        let moved = Box::new(value); // Copies the bytes to the heap
+        assert!(!moved.is_consistent(), "the pointer still targets the old address");
+    }
+
+    #[test]
+    fn pinned_self_reference_survives_moving_the_box() {
+        let pinned = PinnedSelfReferential::new("hello");
+        let boxes = [pinned]; // Moves the Box, not the pinned value
+        assert!(boxes[0].as_ref().is_consistent());
+        assert_eq!(boxes[0].as_ref().data(), "hello");
+        assert_eq!(require_unpin(&boxes[0]), "Unpin ✓", "Pin<Box<T>> itself is Unpin");
+    }
+
+    #[test]
+    fn hand_written_state_machine_matches_async_fn() {
+        let (by_hand, _) = run_to_completion(AddSlowly::new(2, 3));
+        let (generated, _) = run_to_completion(add_slowly(2, 3));
+        assert_eq!((by_hand, generated), (10, 10));
+    }
+
+    #[test]
+    fn count_polls_projects_to_the_inner_future() {
+        // One poll to register each timer, one after each fires
+        let ((sum, polls), executor_polls) = run_to_completion(CountPolls::new(add_slowly(1, 1)));
+        assert_eq!(sum, 4);
+        assert_eq!(polls, 3);
+        assert_eq!(executor_polls, 3);
+    }
+
+    #[test]
+    fn executor_interleaves_timers_by_deadline() {
+        let (tx, rx) = mpsc::channel();
+        let mut executor = MiniExecutor::new();
+        for (name, ms) in [("slow", 30), ("fast", 5), ("medium", 15)] {
+            let tx = tx.clone();
+            executor.spawn(async move {
+                Timer::after(Duration::from_millis(ms)).await;
+                tx.send(name).unwrap();
+            });
+        }
+        drop(tx);
+        executor.run();
+        assert_eq!(rx.iter().collect::<Vec<_>>(), ["fast", "medium", "slow"]);
+    }
+}
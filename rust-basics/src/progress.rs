@@ -0,0 +1,196 @@
+// ===== PROGRESS TRACKER =====
+//
+// Remembers what a learner has done across sessions:
+// • which modules have been run
+// • which exercises pass (`rust-basics check`)
+// • the best quiz score per module (`rust-basics quiz`)
+//
+// Stored as TOML so it's easy to read or reset by hand:
+//
+//   modules_run = ["collections", "traits"]
+//   exercises_passed = ["traits/shapes"]
+//
+//   [quizzes.traits]
+//   best = 3
+//   total = 3
+//   attempts = 2
+//
+// The file lives at $RUST_BASICS_PROGRESS_FILE, or .progress.toml in this crate.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ProgressError {
+    #[error("could not access {path}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+
+    #[error("{path} is not a valid progress file: {source}")]
+    Parse { path: PathBuf, source: toml::de::Error },
+
+    #[error("could not serialize progress: {0}")]
+    Serialize(#[from] toml::ser::Error),
+}
+
+// BTree collections keep the saved file sorted and diff-friendly
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Progress {
+    #[serde(default)]
+    pub modules_run: BTreeSet<String>,
+
+    // "module/exercise"
+    #[serde(default)]
+    pub exercises_passed: BTreeSet<String>,
+
+    #[serde(default)]
+    pub quizzes: BTreeMap<String, QuizRecord>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct QuizRecord {
+    pub best: usize,
+    pub total: usize,
+    pub attempts: u32,
+}
+
+impl Progress {
+    pub fn default_path() -> PathBuf {
+        std::env::var_os("RUST_BASICS_PROGRESS_FILE")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join(".progress.toml"))
+    }
+
+    // A missing file is a fresh start, not an error
+    pub fn load(path: &Path) -> Result<Progress, ProgressError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Progress::default()),
+            Err(source) => return Err(ProgressError::Io { path: path.to_path_buf(), source }),
+        };
+        toml::from_str(&text).map_err(|source| ProgressError::Parse { path: path.to_path_buf(), source })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ProgressError> {
+        let text = toml::to_string_pretty(self)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|source| ProgressError::Io { path: dir.to_path_buf(), source })?;
+        }
+        fs::write(path, text).map_err(|source| ProgressError::Io { path: path.to_path_buf(), source })
+    }
+
+    // Load, apply `change`, save - the pattern every command uses
+    pub fn update(path: &Path, change: impl FnOnce(&mut Progress)) -> Result<Progress, ProgressError> {
+        let mut progress = Progress::load(path)?;
+        change(&mut progress);
+        progress.save(path)?;
+        Ok(progress)
+    }
+
+    // ===== RECORDING =====
+
+    pub fn record_run(&mut self, module: &str) {
+        self.modules_run.insert(module.to_string());
+    }
+
+    // A previously passing exercise that now fails is no longer counted
+    pub fn record_exercise(&mut self, module: &str, exercise: &str, passed: bool) {
+        let key = format!("{}/{}", module, exercise);
+        if passed {
+            self.exercises_passed.insert(key);
+        } else {
+            self.exercises_passed.remove(&key);
+        }
+    }
+
+    // Keeps the best score; returns true when this attempt set a new best
+    pub fn record_quiz(&mut self, module: &str, score: usize, total: usize) -> bool {
+        let record = self
+            .quizzes
+            .entry(module.to_string())
+            .or_insert(QuizRecord { best: 0, total, attempts: 0 });
+        record.attempts += 1;
+        record.total = total;
+
+        let improved = record.attempts == 1 || score > record.best;
+        record.best = record.best.max(score);
+        improved
+    }
+
+    // ===== QUERIES =====
+
+    pub fn has_run(&self, module: &str) -> bool {
+        self.modules_run.contains(module)
+    }
+
+    pub fn exercises_passed_in(&self, module: &str) -> usize {
+        let prefix = format!("{}/", module);
+        self.exercises_passed.iter().filter(|key| key.starts_with(&prefix)).count()
+    }
+
+    pub fn quiz(&self, module: &str) -> Option<&QuizRecord> {
+        self.quizzes.get(module)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rust-basics-progress-{}-{}.toml", std::process::id(), name))
+    }
+
+    #[test]
+    fn missing_file_loads_as_empty_progress() {
+        assert_eq!(Progress::load(&temp_file("missing")).unwrap(), Progress::default());
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let path = temp_file("round-trip");
+        let saved = Progress::update(&path, |progress| {
+            progress.record_run("traits");
+            progress.record_exercise("traits", "shapes", true);
+            progress.record_quiz("traits", 2, 3);
+        })
+        .unwrap();
+
+        let loaded = Progress::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, saved);
+        assert!(loaded.has_run("traits"));
+        assert_eq!(loaded.exercises_passed_in("traits"), 1);
+    }
+
+    #[test]
+    fn quiz_keeps_the_best_score() {
+        let mut progress = Progress::default();
+        assert!(progress.record_quiz("errors", 1, 3));
+        assert!(progress.record_quiz("errors", 3, 3));
+        assert!(!progress.record_quiz("errors", 2, 3));
+
+        assert_eq!(progress.quiz("errors"), Some(&QuizRecord { best: 3, total: 3, attempts: 3 }));
+    }
+
+    #[test]
+    fn failing_exercise_is_removed() {
+        let mut progress = Progress::default();
+        progress.record_exercise("macros", "hashmap", true);
+        progress.record_exercise("macros", "hashmap", false);
+        assert_eq!(progress.exercises_passed_in("macros"), 0);
+    }
+
+    #[test]
+    fn invalid_file_reports_its_path() {
+        let path = temp_file("invalid");
+        fs::write(&path, "modules_run = 42").unwrap();
+        let err = Progress::load(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(err, ProgressError::Parse { .. }));
+        assert!(err.to_string().contains("rust-basics-progress"));
+    }
+}
@@ -0,0 +1,659 @@
+// ===== CAPSTONE: CONCURRENT DOWNLOADER =====
+//
+// Fetch a list of URLs at once: at most N in flight, transient failures
+// retried with backoff, progress logged as results arrive, and every failure
+// collected into one report instead of stopping at the first.
+//
+// WHAT EACH EARLIER MODULE CONTRIBUTES:
+// • async_await: one task per URL, joined through a JoinSet
+// • tokio_channels: a Semaphore bounds how many fetches run at once
+// • errors: FetchError (thiserror) for each fetch, RetryPolicy and
+//   retry_with_backoff for transient ones, anyhow for the aggregate
+// • networking: a minimal HTTP/1.0 client and a local fixture server on
+//   tokio TcpStreams
+//
+// THE FLOW:
+//   urls ──spawn per URL──▶ [task] ──acquire permit──▶ fetch ──▶ Ok / Err
+//                             │  ▲                               │
+//                             │  └──── backoff (permit released) ◀┘ retryable?
+//                             ▼
+//   JoinSet ──as each finishes──▶ Progress (info!/warn!) ──▶ DownloadReport
+//
+// WHY NOT REQWEST: a real client is one line of Cargo.toml, but it hides the
+// part this project is about. Only the Fetch trait touches the network, so
+// swapping in reqwest changes one impl and nothing else.
+
+use crate::errors::{RetryPolicy, Retryable, retry_with_backoff};
+use mini_logger::{debug, info, warn};
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+
+#[derive(Debug, Error)]
+pub enum FetchError {
+    #[error("invalid URL '{0}' (expected http://host[:port]/path)")]
+    InvalidUrl(String),
+
+    #[error("server answered {0}")]
+    Status(u16),
+
+    #[error("no response within {0:?}")]
+    Timeout(Duration),
+
+    #[error("malformed response: {0}")]
+    Malformed(&'static str),
+
+    #[error("connection failed: {0}")]
+    Io(#[from] io::Error),
+}
+
+// Worth another try: the same request might succeed in a moment. A 404 or a
+// bad URL won't, so retrying those only adds load and delay
+impl Retryable for FetchError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            FetchError::Timeout(_) => true,
+            FetchError::Status(code) => *code == 429 || *code >= 500,
+            FetchError::Io(e) => matches!(
+                e.kind(),
+                io::ErrorKind::TimedOut
+                    | io::ErrorKind::Interrupted
+                    | io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::UnexpectedEof
+            ),
+            FetchError::InvalidUrl(_) | FetchError::Malformed(_) => false,
+        }
+    }
+}
+
+// ===== 1. FETCHING ONE URL =====
+//
+// UNDERSTANDING THE FETCH TRAIT:
+// • An async fn in a trait, written as `-> impl Future + Send` so the
+//   future can be moved into tokio::spawn
+// • The downloader is generic over it: HttpFetcher for real requests, any
+//   fake for tests - no network needed to test the concurrency logic
+
+pub trait Fetch: Send + Sync + 'static {
+    fn fetch(&self, url: &str) -> impl Future<Output = Result<Vec<u8>, FetchError>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Url {
+    pub host: String,
+    pub port: u16,
+    pub path: String,
+}
+
+impl Url {
+    // Plain http only - TLS would need a crate like rustls
+    pub fn parse(url: &str) -> Result<Url, FetchError> {
+        let invalid = || FetchError::InvalidUrl(url.to_string());
+        let rest = url.strip_prefix("http://").ok_or_else(invalid)?;
+        let (authority, path) = rest.find('/').map_or((rest, "/"), |slash| rest.split_at(slash));
+        let (host, port) = match authority.split_once(':') {
+            Some((host, port)) => (host, port.parse().map_err(|_| invalid())?),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Url { host: host.to_string(), port, path: path.to_string() })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct HttpFetcher {
+    pub timeout: Duration,
+}
+
+impl Default for HttpFetcher {
+    fn default() -> Self {
+        HttpFetcher { timeout: Duration::from_secs(10) }
+    }
+}
+
+// HTTP/1.0 with Connection: close - the body is everything after the
+// headers until the server hangs up, so no chunked encoding or
+// Content-Length bookkeeping
+async fn http_get(url: &Url) -> Result<Vec<u8>, FetchError> {
+    let mut stream = TcpStream::connect((url.host.as_str(), url.port)).await?;
+    let request = format!("GET {} HTTP/1.0\r\nHost: {}\r\nConnection: close\r\n\r\n", url.path, url.host);
+    stream.write_all(request.as_bytes()).await?;
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).await?;
+    let header_end = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or(FetchError::Malformed("no end of headers"))?;
+    let status = std::str::from_utf8(&response[..header_end])
+        .ok()
+        .and_then(|headers| headers.split(' ').nth(1))
+        .and_then(|code| code.parse().ok())
+        .ok_or(FetchError::Malformed("no status code"))?;
+    if !(200..300).contains(&status) {
+        return Err(FetchError::Status(status));
+    }
+    Ok(response.split_off(header_end + 4))
+}
+
+impl Fetch for HttpFetcher {
+    async fn fetch(&self, url: &str) -> Result<Vec<u8>, FetchError> {
+        let url = Url::parse(url)?;
+        tokio::time::timeout(self.timeout, http_get(&url)).await.map_err(|_| FetchError::Timeout(self.timeout))?
+    }
+}
+
+// ===== 2. PROGRESS =====
+//
+// Tasks finish in any order, so progress is a shared counter. Reporting
+// goes through the log macros: INFO for each success, WARN for each
+// failure (and retry_with_backoff already WARNs on every retry), so
+// --log-level (or RUST_BASICS_LOG) decides how chatty a run is.
+
+#[derive(Debug)]
+pub struct Progress {
+    total: usize,
+    finished: AtomicUsize,
+    bytes: AtomicUsize,
+}
+
+impl Progress {
+    pub fn new(total: usize) -> Self {
+        Progress { total, finished: AtomicUsize::new(0), bytes: AtomicUsize::new(0) }
+    }
+
+    fn record(&self, url: &str, outcome: &Result<Download, FetchError>) {
+        let finished = self.finished.fetch_add(1, Ordering::Relaxed) + 1;
+        match outcome {
+            Ok(download) => {
+                let bytes = self.bytes.fetch_add(download.bytes.len(), Ordering::Relaxed) + download.bytes.len();
+                info!(
+                    "[{}/{}] {} - {} bytes after {} attempt(s) ({} bytes so far)",
+                    finished,
+                    self.total,
+                    url,
+                    download.bytes.len(),
+                    download.attempts,
+                    bytes
+                );
+            }
+            Err(e) => warn!("[{}/{}] {} failed: {}", finished, self.total, url, e),
+        }
+    }
+
+    pub fn finished(&self) -> usize {
+        self.finished.load(Ordering::Relaxed)
+    }
+}
+
+// ===== 3. THE DOWNLOADER =====
+//
+// UNDERSTANDING THE COMPOSITION:
+// • One spawned task per URL: spawning is cheap, the Semaphore is what
+//   limits the work actually in flight
+// • The permit is taken per attempt, not per URL, so a task sleeping
+//   through its backoff doesn't hold a slot another URL could use
+// • Results carry their index: JoinSet yields in completion order, the
+//   report lists them in input order
+// • A panicking task surfaces as a JoinError; it's logged and its URL
+//   reported as failed rather than bringing the whole run down
+
+#[derive(Debug, Clone, Copy)]
+pub struct DownloadConfig {
+    // Most fetches in flight at once; 0 is treated as 1
+    pub concurrency: usize,
+    pub retry: RetryPolicy,
+}
+
+impl Default for DownloadConfig {
+    fn default() -> Self {
+        DownloadConfig { concurrency: 4, retry: RetryPolicy::default() }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Download {
+    pub url: String,
+    pub bytes: Vec<u8>,
+    pub attempts: u32,
+}
+
+#[derive(Debug)]
+pub struct Failure {
+    pub url: String,
+    pub error: FetchError,
+}
+
+#[derive(Debug, Default)]
+pub struct DownloadReport {
+    pub succeeded: Vec<Download>,
+    pub failed: Vec<Failure>,
+    pub elapsed: Duration,
+}
+
+pub async fn download_all<F: Fetch>(fetcher: Arc<F>, urls: &[&str], config: DownloadConfig) -> DownloadReport {
+    let start = Instant::now();
+    let permits = Arc::new(Semaphore::new(config.concurrency.max(1)));
+    let progress = Arc::new(Progress::new(urls.len()));
+    let mut tasks = JoinSet::new();
+
+    for (index, url) in urls.iter().enumerate() {
+        let url = url.to_string();
+        let (fetcher, permits, progress) = (Arc::clone(&fetcher), Arc::clone(&permits), Arc::clone(&progress));
+        tasks.spawn(async move {
+            let attempts = AtomicU32::new(0);
+            let fetched = retry_with_backoff(
+                || async {
+                    // Never closed, so acquire() can't fail
+                    let _permit = permits.acquire().await.expect("semaphore closed");
+                    attempts.fetch_add(1, Ordering::Relaxed);
+                    fetcher.fetch(&url).await
+                },
+                &config.retry,
+            )
+            .await;
+            let outcome = fetched.map(|bytes| Download { url: url.clone(), bytes, attempts: attempts.into_inner() });
+            progress.record(&url, &outcome);
+            (index, url, outcome)
+        });
+    }
+
+    let mut results: Vec<Option<(String, Result<Download, FetchError>)>> = (0..urls.len()).map(|_| None).collect();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((index, url, outcome)) => results[index] = Some((url, outcome)),
+            Err(e) => warn!("download task failed: {}", e),
+        }
+    }
+
+    let mut report = DownloadReport { elapsed: start.elapsed(), ..DownloadReport::default() };
+    for (url, result) in urls.iter().zip(results) {
+        match result {
+            Some((_, Ok(download))) => report.succeeded.push(download),
+            Some((url, Err(error))) => report.failed.push(Failure { url, error }),
+            None => report.failed.push(Failure {
+                url: url.to_string(),
+                error: FetchError::Io(io::Error::other("download task panicked")),
+            }),
+        }
+    }
+    debug!("{} downloads finished in {:?}", progress.finished(), report.elapsed);
+    report
+}
+
+// ===== 4. AGGREGATING ERRORS =====
+//
+// UNDERSTANDING THE REPORT:
+// • Stopping at the first error (? in a loop) would hide the other
+//   failures and throw away the successful downloads
+// • DownloadReport keeps both; into_result() is for callers that only care
+//   whether everything worked
+// • The anyhow error keeps the structure: its context says how many
+//   failed, its source is a Failures value callers can downcast_ref to
+//   inspect each URL and its FetchError
+
+#[derive(Debug)]
+pub struct Failures(pub Vec<Failure>);
+
+impl fmt::Display for Failures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines: Vec<String> = self.0.iter().map(|failure| format!("{}: {}", failure.url, failure.error)).collect();
+        write!(f, "{}", lines.join("; "))
+    }
+}
+
+impl std::error::Error for Failures {}
+
+impl DownloadReport {
+    pub fn into_result(self) -> anyhow::Result<Vec<Download>> {
+        if self.failed.is_empty() {
+            return Ok(self.succeeded);
+        }
+        let total = self.succeeded.len() + self.failed.len();
+        let summary = format!("{} of {} downloads failed", self.failed.len(), total);
+        Err(anyhow::Error::new(Failures(self.failed)).context(summary))
+    }
+}
+
+// ===== 5. A LOCAL SERVER TO DOWNLOAD FROM =====
+//
+// The demo and the tests need URLs that are fast, slow, flaky or missing on
+// demand, without the internet. Routes:
+//   /bytes/N          200 with N bytes
+//   /slow/MS          200 after MS milliseconds
+//   /flaky/K/NAME     503 for the first K requests of NAME, then 200
+//   /status/CODE      that status, empty body
+//   anything else     404
+
+pub struct FixtureServer {
+    addr: SocketAddr,
+    token: CancellationToken,
+    accept_loop: tokio::task::JoinHandle<usize>,
+}
+
+type FlakyCounts = Arc<Mutex<HashMap<String, u32>>>;
+
+async fn route(path: &str, flaky: &FlakyCounts) -> (u16, Vec<u8>) {
+    let parts: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    match parts.as_slice() {
+        ["bytes", n] if let Ok(n) = n.parse() => (200, vec![b'x'; n]),
+        ["slow", ms] if let Ok(ms) = ms.parse() => {
+            tokio::time::sleep(Duration::from_millis(ms)).await;
+            (200, b"slow".to_vec())
+        }
+        ["flaky", failures, name] if let Ok(failures) = failures.parse::<u32>() => {
+            let mut counts = flaky.lock().unwrap_or_else(PoisonError::into_inner);
+            let seen = counts.entry(name.to_string()).or_default();
+            *seen += 1;
+            if *seen <= failures { (503, Vec::new()) } else { (200, format!("{} recovered", name).into_bytes()) }
+        }
+        ["status", code] if let Ok(code) = code.parse() => (code, Vec::new()),
+        _ => (404, Vec::new()),
+    }
+}
+
+async fn serve_request(stream: TcpStream, flaky: FlakyCounts) -> io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    // Skip the headers up to the blank line
+    let mut header = String::new();
+    while reader.read_line(&mut header).await? > 2 {
+        header.clear();
+    }
+
+    let path = request_line.split(' ').nth(1).unwrap_or("/");
+    let (status, body) = route(path, &flaky).await;
+    let head = format!("HTTP/1.0 {} FIXTURE\r\nContent-Length: {}\r\n\r\n", status, body.len());
+    writer.write_all(&[head.as_bytes(), &body].concat()).await?;
+    writer.shutdown().await
+}
+
+pub async fn spawn_fixture_server() -> io::Result<FixtureServer> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let token = CancellationToken::new();
+    let accept_loop = tokio::spawn({
+        let token = token.clone();
+        async move {
+            let tracker = TaskTracker::new();
+            let flaky = FlakyCounts::default();
+            let mut served = 0;
+            loop {
+                tokio::select! {
+                    biased;
+                    _ = token.cancelled() => break,
+                    accepted = listener.accept() => match accepted {
+                        Ok((stream, _)) => {
+                            served += 1;
+                            let flaky = Arc::clone(&flaky);
+                            tracker.spawn(async move {
+                                if let Err(e) = serve_request(stream, flaky).await {
+                                    warn!("fixture request failed: {}", e);
+                                }
+                            });
+                        }
+                        Err(e) => warn!("accept failed: {}", e),
+                    },
+                }
+            }
+            tracker.close();
+            tracker.wait().await;
+            served
+        }
+    });
+    Ok(FixtureServer { addr, token, accept_loop })
+}
+
+impl FixtureServer {
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.addr, path)
+    }
+
+    // Stops accepting, waits for in-flight requests, returns how many were served
+    pub async fn shutdown(self) -> usize {
+        self.token.cancel();
+        self.accept_loop.await.unwrap_or(0)
+    }
+}
+
+// ===== 6. DEMONSTRATION FUNCTION =====
+
+fn print_report(report: &DownloadReport) {
+    println!("{} ok, {} failed in {:?}", report.succeeded.len(), report.failed.len(), report.elapsed);
+    for download in &report.succeeded {
+        println!("   ✓ {} ({} bytes, {} attempt(s))", download.url, download.bytes.len(), download.attempts);
+    }
+    for failure in &report.failed {
+        println!("   ✗ {} - {}", failure.url, failure.error);
+    }
+}
+
+pub async fn demonstrate_downloader() {
+    println!("🦀 CAPSTONE: CONCURRENT DOWNLOADER 🦀\n");
+
+    let server = match spawn_fixture_server().await {
+        Ok(server) => server,
+        Err(e) => {
+            println!("❌ Couldn't start the fixture server: {}", e);
+            return;
+        }
+    };
+    let fetcher = Arc::new(HttpFetcher::default());
+    let retry = RetryPolicy { initial_delay: Duration::from_millis(20), ..RetryPolicy::default() };
+
+    // ===== BOUNDED CONCURRENCY =====
+    println!("1️⃣ BOUNDED CONCURRENCY (4 URLs × 100ms each):");
+    let slow: Vec<String> = (0..4).map(|_| server.url("/slow/100")).collect();
+    let slow: Vec<&str> = slow.iter().map(String::as_str).collect();
+    for concurrency in [1, 4] {
+        let report = download_all(Arc::clone(&fetcher), &slow, DownloadConfig { concurrency, retry }).await;
+        println!("concurrency {}: {:?}", concurrency, report.elapsed);
+    }
+
+    // ===== RETRIES AND FAILURES =====
+    println!("\n2️⃣ RETRIES, PROGRESS AND FAILURES:");
+    let urls = [
+        server.url("/bytes/1024"),
+        server.url("/flaky/2/report"),
+        server.url("/status/404"),
+        server.url("/bytes/10"),
+        server.url("/flaky/9/outage"),
+        "ftp://example.com/file".to_string(),
+    ];
+    let urls: Vec<&str> = urls.iter().map(String::as_str).collect();
+    let report = download_all(Arc::clone(&fetcher), &urls, DownloadConfig { concurrency: 3, retry }).await;
+    print_report(&report);
+
+    // ===== ANYHOW =====
+    println!("\n3️⃣ ONE ERROR FOR THE WHOLE BATCH:");
+    match report.into_result() {
+        Ok(downloads) => println!("All {} downloads succeeded", downloads.len()),
+        Err(e) => {
+            println!("{{}}  → {}", e);
+            println!("{{:#}} → {:#}", e);
+            if let Some(failures) = e.downcast_ref::<Failures>() {
+                let retryable = failures.0.iter().filter(|failure| failure.error.is_retryable()).count();
+                println!("downcast_ref::<Failures>() → {} failures, {} worth retrying later", failures.0.len(), retryable);
+            }
+        }
+    }
+    println!("\nFixture server served {} requests", server.shutdown().await);
+
+    // ===== SUMMARY =====
+    println!("\n🎯 DOWNLOADER SUMMARY:");
+    println!("✅ Spawn freely, bound the work with a Semaphore");
+    println!("✅ Take the permit per attempt so backoff doesn't block a slot");
+    println!("✅ Retry only what can succeed later (timeouts, 5xx, 429)");
+    println!("✅ Collect every failure; turn them into one anyhow error at the edge");
+    println!("✅ A Fetch trait keeps the network out of the concurrency tests");
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration};
+
+pub struct DownloaderModule;
+
+impl LearningModule for DownloaderModule {
+    fn number(&self) -> u8 {
+        19
+    }
+
+    fn name(&self) -> &'static str {
+        "downloader"
+    }
+
+    fn title(&self) -> &'static str {
+        "CAPSTONE: CONCURRENT DOWNLOADER"
+    }
+
+    fn description(&self) -> &'static str {
+        "Concurrent Downloader (capstone)"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["async_await", "errors", "tokio_channels", "networking"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(demonstrate_downloader())
+    }
+
+    fn file(&self) -> String {
+        "projects/downloader.rs".to_string()
+    }
+}
+
+inventory::submit! { ModuleRegistration(&DownloaderModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// COMPOSING ASYNC WORK:
+// 1. Tasks are cheap; limit the expensive part (connections) with a Semaphore
+// 2. Hold a permit only while doing the work it guards
+// 3. Classify errors before retrying: transient vs permanent
+// 4. Gather results, then decide - partial success is still useful
+// 5. Put the outside world behind a trait so the logic tests in memory
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::Jitter;
+
+    fn quick_retries(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy { max_attempts, initial_delay: Duration::from_millis(1), jitter: Jitter::None, ..RetryPolicy::default() }
+    }
+
+    // In-memory fetcher: "ok" URLs succeed after a short delay, "fail-N"
+    // URLs answer 503 N times first, "gone" is a 404
+    #[derive(Default)]
+    struct FakeFetcher {
+        in_flight: AtomicUsize,
+        peak: AtomicUsize,
+        calls: Mutex<HashMap<String, u32>>,
+    }
+
+    impl Fetch for FakeFetcher {
+        async fn fetch(&self, url: &str) -> Result<Vec<u8>, FetchError> {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            let call = {
+                let mut calls = self.calls.lock().unwrap();
+                let call = calls.entry(url.to_string()).or_default();
+                *call += 1;
+                *call
+            };
+            match url.split_once('-') {
+                Some(("fail", n)) if call <= n.parse().unwrap() => Err(FetchError::Status(503)),
+                _ if url == "gone" => Err(FetchError::Status(404)),
+                _ => Ok(url.as_bytes().to_vec()),
+            }
+        }
+    }
+
+    #[test]
+    fn urls_parse() {
+        assert_eq!(
+            Url::parse("http://localhost:8080/a/b").unwrap(),
+            Url { host: "localhost".to_string(), port: 8080, path: "/a/b".to_string() }
+        );
+        assert_eq!(Url::parse("http://example.com").unwrap().path, "/");
+        assert_eq!(Url::parse("http://example.com").unwrap().port, 80);
+        for bad in ["https://example.com/", "http://:80/", "http://host:port/", "example.com"] {
+            assert!(matches!(Url::parse(bad), Err(FetchError::InvalidUrl(_))), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn only_transient_errors_are_retryable() {
+        assert!(FetchError::Status(503).is_retryable());
+        assert!(FetchError::Status(429).is_retryable());
+        assert!(FetchError::Timeout(Duration::from_secs(1)).is_retryable());
+        assert!(FetchError::Io(io::ErrorKind::ConnectionRefused.into()).is_retryable());
+        assert!(!FetchError::Status(404).is_retryable());
+        assert!(!FetchError::InvalidUrl("x".to_string()).is_retryable());
+    }
+
+    #[tokio::test]
+    async fn concurrency_never_exceeds_the_limit() {
+        let fetcher = Arc::new(FakeFetcher::default());
+        let urls = ["ok"; 20];
+        let config = DownloadConfig { concurrency: 3, retry: quick_retries(1) };
+        let report = download_all(Arc::clone(&fetcher), &urls, config).await;
+        assert_eq!(report.succeeded.len(), 20);
+        assert_eq!(fetcher.peak.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn retries_transient_failures_and_keeps_input_order() {
+        let fetcher = Arc::new(FakeFetcher::default());
+        let urls = ["fail-2", "ok", "gone", "fail-9"];
+        let config = DownloadConfig { concurrency: 4, retry: quick_retries(3) };
+        let report = download_all(Arc::clone(&fetcher), &urls, config).await;
+
+        let succeeded: Vec<_> = report.succeeded.iter().map(|d| (d.url.as_str(), d.attempts)).collect();
+        assert_eq!(succeeded, [("fail-2", 3), ("ok", 1)]);
+        let failed: Vec<_> = report.failed.iter().map(|f| f.url.as_str()).collect();
+        assert_eq!(failed, ["gone", "fail-9"]);
+        let calls = fetcher.calls.lock().unwrap();
+        assert_eq!((calls["gone"], calls["fail-9"]), (1, 3), "404s aren't retried; 503s stop at max_attempts");
+    }
+
+    #[tokio::test]
+    async fn failures_aggregate_into_one_anyhow_error() {
+        let report = download_all(Arc::new(FakeFetcher::default()), &["ok", "gone"], DownloadConfig::default()).await;
+        let error = report.into_result().unwrap_err();
+        assert_eq!(error.to_string(), "1 of 2 downloads failed");
+        assert_eq!(format!("{:#}", error), "1 of 2 downloads failed: gone: server answered 404");
+        assert!(matches!(error.downcast_ref::<Failures>().unwrap().0[0].error, FetchError::Status(404)));
+
+        let all_ok = download_all(Arc::new(FakeFetcher::default()), &["ok"], DownloadConfig::default()).await;
+        assert_eq!(all_ok.into_result().unwrap()[0].bytes, b"ok");
+    }
+}
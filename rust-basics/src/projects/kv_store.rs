@@ -0,0 +1,829 @@
+// ===== CAPSTONE: CONCURRENT KEY-VALUE STORE =====
+//
+// A small Redis/memcached-style server: string keys and values, optional
+// expiry, many clients at once over TCP.
+//
+// WHAT EACH EARLIER MODULE CONTRIBUTES:
+// • traits: Storage is the interface the server is written against, with a
+//   single-lock and a sharded implementation; Clock makes time injectable
+// • smart_pointers: Arc shares one store between every connection thread
+// • concurrency: Mutex vs sharded RwLocks, a sweeper thread stopped through
+//   a channel
+// • errors: KvError (thiserror) for protocol, server and IO failures
+// • networking: a thread per connection and the self-connect shutdown trick
+//
+// THE PIECES:
+//   KvClient ──TCP──▶ KvServer ──thread per connection──▶ handle_line()
+//                                                            │ parse → Command
+//                                                            ▼ execute
+//                     Sweeper ──purge_expired() every N ms──▶ Arc<dyn Storage>
+//
+// PROTOCOL - one command per line, one reply per line:
+//   PING                        → PONG
+//   SET <key> <value...>        → OK
+//   SETEX <key> <secs> <value>  → OK      (expires after <secs> seconds)
+//   GET <key>                   → VALUE <value> | NIL
+//   DEL <key>                   → INT 1 | INT 0
+//   LEN                         → INT <live keys>
+//   QUIT                        → BYE     (server closes the connection)
+//   anything wrong              → ERR <message>
+// Values are the rest of the line, so they may contain spaces.
+
+use mini_logger::{debug, warn};
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum KvError {
+    #[error("empty command")]
+    Empty,
+
+    #[error("unknown command '{0}'")]
+    UnknownCommand(String),
+
+    #[error("usage: {0}")]
+    Usage(&'static str),
+
+    #[error("invalid TTL '{0}' (expected whole seconds)")]
+    InvalidTtl(String),
+
+    #[error("keys can't be empty or contain whitespace: {0:?}")]
+    InvalidKey(String),
+
+    #[error("server error: {0}")]
+    Server(String),
+
+    #[error("unexpected reply '{0}'")]
+    UnexpectedReply(String),
+
+    #[error("connection failed")]
+    Io(#[from] io::Error),
+}
+
+// ===== 1. TIME AS A DEPENDENCY =====
+//
+// TTL code that calls Instant::now() directly can only be tested by
+// sleeping. Taking the clock as a trait lets tests move time forward by
+// hand - the Strategy pattern again, applied to time.
+
+pub trait Clock: Send + Sync + 'static {
+    fn now(&self) -> Instant;
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Mutex<Instant>,
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        ManualClock { now: Mutex::new(Instant::now()) }
+    }
+}
+
+impl ManualClock {
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// So a test can keep one Arc to advance while the store owns another
+impl<C: Clock> Clock for Arc<C> {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
+
+// ===== 2. THE STORAGE TRAIT =====
+//
+// UNDERSTANDING THE DESIGN:
+// • Every method takes &self: the stores lock internally, so callers share
+//   them as Arc<S> with no outer Mutex
+// • Send + Sync supertraits make Arc<dyn Storage> usable from any thread
+// • Expired entries are invisible at once (checked on read) but only freed
+//   by purge_expired() - lazy expiry plus a background sweep, like Redis
+
+pub trait Storage: Send + Sync {
+    fn set(&self, key: &str, value: &str, ttl: Option<Duration>);
+
+    fn get(&self, key: &str) -> Option<String>;
+
+    // Whether a live entry was removed
+    fn delete(&self, key: &str) -> bool;
+
+    // Live (unexpired) entries
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Frees expired entries; returns how many
+    fn purge_expired(&self) -> usize;
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: String,
+    expires_at: Option<Instant>,
+}
+
+impl Entry {
+    fn new(value: &str, ttl: Option<Duration>, now: Instant) -> Self {
+        Entry { value: value.to_string(), expires_at: ttl.map(|ttl| now + ttl) }
+    }
+
+    fn is_live(&self, now: Instant) -> bool {
+        self.expires_at.is_none_or(|expires_at| now < expires_at)
+    }
+}
+
+type Map = HashMap<String, Entry>;
+
+fn purge(map: &mut Map, now: Instant) -> usize {
+    let before = map.len();
+    map.retain(|_, entry| entry.is_live(now));
+    before - map.len()
+}
+
+// A panic in one connection thread mustn't take the whole store down, so
+// poisoned locks are used anyway: every update leaves the map consistent
+fn lock(map: &Mutex<Map>) -> MutexGuard<'_, Map> {
+    map.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// ===== 3. ONE BIG LOCK =====
+
+#[derive(Debug, Default)]
+pub struct LockedStore<C = SystemClock> {
+    map: Mutex<Map>,
+    clock: C,
+}
+
+impl LockedStore {
+    pub fn new() -> Self {
+        LockedStore::default()
+    }
+}
+
+impl<C: Clock> LockedStore<C> {
+    pub fn with_clock(clock: C) -> Self {
+        LockedStore { map: Mutex::default(), clock }
+    }
+}
+
+impl<C: Clock> Storage for LockedStore<C> {
+    fn set(&self, key: &str, value: &str, ttl: Option<Duration>) {
+        lock(&self.map).insert(key.to_string(), Entry::new(value, ttl, self.clock.now()));
+    }
+
+    fn get(&self, key: &str) -> Option<String> {
+        let now = self.clock.now();
+        lock(&self.map).get(key).filter(|entry| entry.is_live(now)).map(|entry| entry.value.clone())
+    }
+
+    fn delete(&self, key: &str) -> bool {
+        let now = self.clock.now();
+        lock(&self.map).remove(key).is_some_and(|entry| entry.is_live(now))
+    }
+
+    fn len(&self) -> usize {
+        let now = self.clock.now();
+        lock(&self.map).values().filter(|entry| entry.is_live(now)).count()
+    }
+
+    fn purge_expired(&self) -> usize {
+        purge(&mut lock(&self.map), self.clock.now())
+    }
+}
+
+// ===== 4. SHARDED LOCKS =====
+//
+// UNDERSTANDING SHARDING:
+// • One lock serializes every operation, even on unrelated keys
+// • N maps, each behind its own lock; a key's hash picks its shard, so two
+//   threads only contend when their keys land in the same shard
+// • RwLock per shard: GETs on one shard run in parallel
+// • The hasher is created once - RandomState::new() per call would send the
+//   same key to different shards
+// • Cost: len() and purge_expired() visit every shard, one at a time, so
+//   they're not a consistent snapshot across shards
+
+#[derive(Debug)]
+pub struct ShardedStore<C = SystemClock> {
+    shards: Box<[RwLock<Map>]>,
+    hasher: RandomState,
+    clock: C,
+}
+
+impl ShardedStore {
+    pub fn new(shards: usize) -> Self {
+        ShardedStore::with_clock(shards, SystemClock)
+    }
+}
+
+impl<C: Clock> ShardedStore<C> {
+    // Panics if shards is 0
+    pub fn with_clock(shards: usize, clock: C) -> Self {
+        assert!(shards > 0, "a sharded store needs at least one shard");
+        ShardedStore { shards: (0..shards).map(|_| RwLock::default()).collect(), hasher: RandomState::new(), clock }
+    }
+
+    fn shard(&self, key: &str) -> &RwLock<Map> {
+        &self.shards[self.hasher.hash_one(key) as usize % self.shards.len()]
+    }
+
+    // Live entries per shard, to see how evenly keys spread
+    pub fn shard_sizes(&self) -> Vec<usize> {
+        let now = self.clock.now();
+        self.shards
+            .iter()
+            .map(|shard| {
+                let shard = shard.read().unwrap_or_else(PoisonError::into_inner);
+                shard.values().filter(|entry| entry.is_live(now)).count()
+            })
+            .collect()
+    }
+}
+
+impl<C: Clock> Storage for ShardedStore<C> {
+    fn set(&self, key: &str, value: &str, ttl: Option<Duration>) {
+        let entry = Entry::new(value, ttl, self.clock.now());
+        self.shard(key).write().unwrap_or_else(PoisonError::into_inner).insert(key.to_string(), entry);
+    }
+
+    fn get(&self, key: &str) -> Option<String> {
+        let now = self.clock.now();
+        let shard = self.shard(key).read().unwrap_or_else(PoisonError::into_inner);
+        shard.get(key).filter(|entry| entry.is_live(now)).map(|entry| entry.value.clone())
+    }
+
+    fn delete(&self, key: &str) -> bool {
+        let now = self.clock.now();
+        let mut shard = self.shard(key).write().unwrap_or_else(PoisonError::into_inner);
+        shard.remove(key).is_some_and(|entry| entry.is_live(now))
+    }
+
+    fn len(&self) -> usize {
+        self.shard_sizes().into_iter().sum()
+    }
+
+    fn purge_expired(&self) -> usize {
+        let now = self.clock.now();
+        self.shards
+            .iter()
+            .map(|shard| purge(&mut shard.write().unwrap_or_else(PoisonError::into_inner), now))
+            .sum()
+    }
+}
+
+// ===== 5. THE SWEEPER =====
+//
+// A background thread that calls purge_expired() every `every`. It waits
+// with recv_timeout on a channel instead of thread::sleep, so stopping it is
+// immediate: dropping the Sender wakes it with Disconnected. Stopping
+// happens in Drop too (RAII) - a forgotten Sweeper can't outlive its owner.
+
+pub struct Sweeper {
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<usize>>,
+}
+
+pub fn spawn_sweeper<S: Storage + ?Sized + 'static>(store: Arc<S>, every: Duration) -> Sweeper {
+    let (stop, stopped) = mpsc::channel::<()>();
+    let thread = thread::spawn(move || {
+        let mut purged = 0;
+        while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(every) {
+            purged += store.purge_expired();
+        }
+        purged
+    });
+    Sweeper { stop: Some(stop), thread: Some(thread) }
+}
+
+impl Sweeper {
+    // Stops the thread and returns how many entries it purged
+    pub fn stop(mut self) -> usize {
+        self.halt()
+    }
+
+    fn halt(&mut self) -> usize {
+        drop(self.stop.take());
+        self.thread.take().map_or(0, |thread| thread.join().unwrap_or(0))
+    }
+}
+
+impl Drop for Sweeper {
+    fn drop(&mut self) {
+        self.halt();
+    }
+}
+
+// ===== 6. THE PROTOCOL =====
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Ping,
+    Get(String),
+    Set { key: String, value: String, ttl: Option<Duration> },
+    Del(String),
+    Len,
+    Quit,
+}
+
+fn key(key: &str) -> Result<String, KvError> {
+    if key.is_empty() || key.contains(char::is_whitespace) {
+        return Err(KvError::InvalidKey(key.to_string()));
+    }
+    Ok(key.to_string())
+}
+
+impl FromStr for Command {
+    type Err = KvError;
+
+    // Command names are case-insensitive; keys and values are not
+    fn from_str(line: &str) -> Result<Self, KvError> {
+        let line = line.trim();
+        let (name, args) = line.split_once(' ').unwrap_or((line, ""));
+        let args = args.trim_start();
+        let no_args = |command, usage| if args.is_empty() { Ok(command) } else { Err(KvError::Usage(usage)) };
+
+        match name.to_ascii_uppercase().as_str() {
+            "" => Err(KvError::Empty),
+            "PING" => no_args(Command::Ping, "PING"),
+            "LEN" => no_args(Command::Len, "LEN"),
+            "QUIT" => no_args(Command::Quit, "QUIT"),
+            "GET" if !args.is_empty() && !args.contains(' ') => Ok(Command::Get(key(args)?)),
+            "GET" => Err(KvError::Usage("GET <key>")),
+            "DEL" if !args.is_empty() && !args.contains(' ') => Ok(Command::Del(key(args)?)),
+            "DEL" => Err(KvError::Usage("DEL <key>")),
+            "SET" => match args.split_once(' ') {
+                Some((k, value)) => Ok(Command::Set { key: key(k)?, value: value.to_string(), ttl: None }),
+                None => Err(KvError::Usage("SET <key> <value>")),
+            },
+            "SETEX" => {
+                let mut parts = args.splitn(3, ' ');
+                let (Some(k), Some(seconds), Some(value)) = (parts.next(), parts.next(), parts.next()) else {
+                    return Err(KvError::Usage("SETEX <key> <seconds> <value>"));
+                };
+                let seconds: u64 = seconds.parse().map_err(|_| KvError::InvalidTtl(seconds.to_string()))?;
+                Ok(Command::Set { key: key(k)?, value: value.to_string(), ttl: Some(Duration::from_secs(seconds)) })
+            }
+            _ => Err(KvError::UnknownCommand(name.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Ok,
+    Pong,
+    Value(String),
+    Nil,
+    Int(usize),
+    Error(String),
+    Bye,
+}
+
+impl std::fmt::Display for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Response::Ok => write!(f, "OK"),
+            Response::Pong => write!(f, "PONG"),
+            Response::Value(value) => write!(f, "VALUE {}", value),
+            Response::Nil => write!(f, "NIL"),
+            Response::Int(n) => write!(f, "INT {}", n),
+            Response::Error(message) => write!(f, "ERR {}", message),
+            Response::Bye => write!(f, "BYE"),
+        }
+    }
+}
+
+pub fn execute<S: Storage + ?Sized>(store: &S, command: Command) -> Response {
+    match command {
+        Command::Ping => Response::Pong,
+        Command::Get(key) => store.get(&key).map_or(Response::Nil, Response::Value),
+        Command::Set { key, value, ttl } => {
+            store.set(&key, &value, ttl);
+            Response::Ok
+        }
+        Command::Del(key) => Response::Int(usize::from(store.delete(&key))),
+        Command::Len => Response::Int(store.len()),
+        Command::Quit => Response::Bye,
+    }
+}
+
+// Parse errors become ERR replies - a bad command never closes the connection
+pub fn handle_line<S: Storage + ?Sized>(store: &S, line: &str) -> Response {
+    match line.parse() {
+        Ok(command) => execute(store, command),
+        Err(e) => Response::Error(e.to_string()),
+    }
+}
+
+// ===== 7. THE SERVER =====
+//
+// One thread per connection, each holding an Arc to the store. shutdown()
+// stops accepting new connections; connections already open keep working
+// until their clients QUIT or disconnect (the threads are detached).
+
+pub struct KvServer {
+    addr: SocketAddr,
+    stopping: Arc<AtomicBool>,
+    accept_loop: JoinHandle<usize>,
+}
+
+fn serve_connection<S: Storage + ?Sized>(store: &S, stream: TcpStream) -> io::Result<usize> {
+    let mut writer = stream.try_clone()?;
+    let mut commands = 0;
+    for line in BufReader::new(stream).lines() {
+        let response = handle_line(store, &line?);
+        // One write per reply: writeln! issues several small writes, and Nagle's
+        // algorithm holds the later ones until the client's (delayed) ACK
+        writer.write_all(format!("{}\n", response).as_bytes())?;
+        commands += 1;
+        if response == Response::Bye {
+            break;
+        }
+    }
+    Ok(commands)
+}
+
+pub fn serve<S: Storage + ?Sized + 'static>(store: Arc<S>, addr: impl ToSocketAddrs) -> io::Result<KvServer> {
+    let listener = TcpListener::bind(addr)?;
+    let addr = listener.local_addr()?;
+    let stopping = Arc::new(AtomicBool::new(false));
+    let accept_loop = thread::spawn({
+        let stopping = Arc::clone(&stopping);
+        move || {
+            let mut accepted = 0;
+            for stream in listener.incoming() {
+                if stopping.load(Ordering::SeqCst) {
+                    break;
+                }
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(e) => {
+                        warn!("accept failed: {}", e);
+                        continue;
+                    }
+                };
+                accepted += 1;
+                let store = Arc::clone(&store);
+                thread::spawn(move || match serve_connection(&*store, stream) {
+                    Ok(commands) => debug!("connection closed after {} commands", commands),
+                    Err(e) => warn!("connection failed: {}", e),
+                });
+            }
+            accepted
+        }
+    });
+    Ok(KvServer { addr, stopping, accept_loop })
+}
+
+impl KvServer {
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    // Stops accepting; returns how many connections were accepted
+    pub fn shutdown(self) -> usize {
+        self.stopping.store(true, Ordering::SeqCst);
+        let _ = TcpStream::connect(self.addr);
+        self.accept_loop.join().unwrap_or(0)
+    }
+}
+
+// ===== 8. THE CLIENT =====
+
+pub struct KvClient {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl KvClient {
+    pub fn connect(addr: impl ToSocketAddrs) -> Result<Self, KvError> {
+        let writer = TcpStream::connect(addr)?;
+        writer.set_read_timeout(Some(Duration::from_secs(5)))?;
+        Ok(KvClient { reader: BufReader::new(writer.try_clone()?), writer })
+    }
+
+    // Sends one raw line, returns the raw reply without its newline
+    pub fn request(&mut self, line: &str) -> Result<String, KvError> {
+        self.writer.write_all(format!("{}\n", line).as_bytes())?;
+        let mut reply = String::new();
+        if self.reader.read_line(&mut reply)? == 0 {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        let reply = reply.trim_end_matches(['\n', '\r']);
+        match reply.strip_prefix("ERR ") {
+            Some(message) => Err(KvError::Server(message.to_string())),
+            None => Ok(reply.to_string()),
+        }
+    }
+
+    fn expect(&mut self, line: &str, expected: &str) -> Result<(), KvError> {
+        let reply = self.request(line)?;
+        if reply == expected { Ok(()) } else { Err(KvError::UnexpectedReply(reply)) }
+    }
+
+    fn int(&mut self, line: &str) -> Result<usize, KvError> {
+        let reply = self.request(line)?;
+        reply.strip_prefix("INT ").and_then(|n| n.parse().ok()).ok_or(KvError::UnexpectedReply(reply))
+    }
+
+    pub fn ping(&mut self) -> Result<(), KvError> {
+        self.expect("PING", "PONG")
+    }
+
+    // Checked here too, so a bad key fails before it's misread as two words
+    pub fn set(&mut self, key: &str, value: &str, ttl: Option<Duration>) -> Result<(), KvError> {
+        let key = self::key(key)?;
+        if value.contains(['\n', '\r']) {
+            return Err(KvError::Usage("values are a single line"));
+        }
+        match ttl {
+            Some(ttl) => self.expect(&format!("SETEX {} {} {}", key, ttl.as_secs(), value), "OK"),
+            None => self.expect(&format!("SET {} {}", key, value), "OK"),
+        }
+    }
+
+    pub fn get(&mut self, key: &str) -> Result<Option<String>, KvError> {
+        let reply = self.request(&format!("GET {}", self::key(key)?))?;
+        if reply == "NIL" {
+            return Ok(None);
+        }
+        match reply.strip_prefix("VALUE ") {
+            Some(value) => Ok(Some(value.to_string())),
+            None => Err(KvError::UnexpectedReply(reply)),
+        }
+    }
+
+    pub fn delete(&mut self, key: &str) -> Result<bool, KvError> {
+        Ok(self.int(&format!("DEL {}", self::key(key)?))? == 1)
+    }
+
+    // LEN: live keys on the server
+    pub fn count(&mut self) -> Result<usize, KvError> {
+        self.int("LEN")
+    }
+
+    pub fn quit(mut self) -> Result<(), KvError> {
+        self.expect("QUIT", "BYE")
+    }
+}
+
+// ===== 9. DEMONSTRATION FUNCTION =====
+
+// `threads` threads each run `ops` mixed operations (3 GETs per SET) on
+// their own keys; returns the elapsed time
+pub fn hammer(store: Arc<dyn Storage>, threads: usize, ops: usize) -> Duration {
+    let start = Instant::now();
+    let workers: Vec<_> = (0..threads)
+        .map(|t| {
+            let store = Arc::clone(&store);
+            thread::spawn(move || {
+                for i in 0..ops {
+                    let key = format!("t{}-k{}", t, i % 100);
+                    if i % 4 == 0 {
+                        store.set(&key, "value", None);
+                    } else {
+                        store.get(&key);
+                    }
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        let _ = worker.join();
+    }
+    start.elapsed()
+}
+
+fn demonstrate_session(store: Arc<ShardedStore>) -> Result<(), KvError> {
+    let server = serve(store, "127.0.0.1:0")?;
+    println!("Server listening on {}", server.addr());
+    let mut client = KvClient::connect(server.addr())?;
+    for line in ["PING", "SET greeting hello there", "GET greeting", "SETEX session 60 abc123", "LEN", "DEL greeting", "GET greeting", "FLY away"] {
+        let reply = client.request(line).unwrap_or_else(|e| e.to_string());
+        println!("> {:<26} {}", line, reply);
+    }
+    println!("Typed client: get(\"session\") = {:?}", client.get("session")?);
+    println!("Typed client: set(\"bad key\") = {:?}", client.set("bad key", "x", None).map_err(|e| e.to_string()));
+    client.quit()?;
+    println!("shutdown() → {} connection(s) served", server.shutdown());
+    Ok(())
+}
+
+pub fn demonstrate_kv_store() {
+    println!("🦀 CAPSTONE: CONCURRENT KV STORE 🦀\n");
+
+    // ===== TTL =====
+    println!("1️⃣ STORAGE AND TTL (with a manual clock):");
+    let clock = Arc::new(ManualClock::default());
+    let store = ShardedStore::with_clock(4, Arc::clone(&clock));
+    store.set("user:1", "ada", None);
+    store.set("token", "s3cret", Some(Duration::from_secs(30)));
+    println!("get(token) = {:?}, len = {}", store.get("token"), store.len());
+    clock.advance(Duration::from_secs(31));
+    println!("31s later: get(token) = {:?}, len = {}", store.get("token"), store.len());
+    println!("purge_expired() freed {}, user:1 = {:?}", store.purge_expired(), store.get("user:1"));
+
+    // ===== SHARDING =====
+    println!("\n2️⃣ ONE LOCK VS SHARDED LOCKS:");
+    let sharded = ShardedStore::new(16);
+    for n in 0..1000 {
+        sharded.set(&format!("key{}", n), "v", None);
+    }
+    println!("1000 keys over 16 shards: {:?}", sharded.shard_sizes());
+    let threads = thread::available_parallelism().map_or(4, |n| n.get()).max(4);
+    let locked = hammer(Arc::new(LockedStore::new()), threads, 50_000);
+    let sharded = hammer(Arc::new(ShardedStore::new(16)), threads, 50_000);
+    println!("{} threads × 50000 ops: one Mutex {:?}, 16 RwLock shards {:?}", threads, locked, sharded);
+    println!("(With few cores or cheap operations the single lock can still win - measure before sharding)");
+
+    // ===== SWEEPER =====
+    println!("\n3️⃣ BACKGROUND EXPIRY:");
+    let store = Arc::new(LockedStore::new());
+    for n in 0..100 {
+        store.set(&format!("temp{}", n), "x", Some(Duration::ZERO));
+    }
+    let sweeper = spawn_sweeper(Arc::clone(&store) as Arc<dyn Storage>, Duration::from_millis(5));
+    thread::sleep(Duration::from_millis(30));
+    println!("Sweeper purged {} expired entries; {} left", sweeper.stop(), store.len());
+
+    // ===== TCP =====
+    println!("\n4️⃣ THE TEXT PROTOCOL OVER TCP:");
+    if let Err(e) = demonstrate_session(Arc::new(ShardedStore::new(8))) {
+        println!("❌ Session failed: {}", e);
+    }
+
+    // ===== SUMMARY =====
+    println!("\n🎯 KV STORE SUMMARY:");
+    println!("✅ A Storage trait lets the server run on either locking strategy");
+    println!("✅ Sharding turns one contended lock into many quiet ones");
+    println!("✅ Lazy expiry on read plus a background sweep, stopped by Drop");
+    println!("✅ An injected Clock makes TTLs testable without sleeping");
+    println!("✅ Protocol errors are replies, not disconnects");
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration};
+
+pub struct KvStoreModule;
+
+impl LearningModule for KvStoreModule {
+    fn number(&self) -> u8 {
+        18
+    }
+
+    fn name(&self) -> &'static str {
+        "kv_store"
+    }
+
+    fn title(&self) -> &'static str {
+        "CAPSTONE: CONCURRENT KV STORE"
+    }
+
+    fn description(&self) -> &'static str {
+        "Concurrent KV Store (capstone)"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["traits", "smart_pointers", "errors", "concurrency", "networking"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_kv_store() })
+    }
+
+    fn file(&self) -> String {
+        "projects/kv_store.rs".to_string()
+    }
+}
+
+inventory::submit! { ModuleRegistration(&KvStoreModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// BUILDING A SHARED SERVICE:
+// 1. Write the server against a trait; swap implementations to compare them
+// 2. Shard by key hash when one lock becomes the bottleneck
+// 3. Inject time (and other outside inputs) so behaviour can be tested
+// 4. Background threads need a way to stop - a channel plus Drop works well
+// 5. Validate input at both ends: the client for friendly errors, the server
+//    because clients can't be trusted
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stores(clock: &Arc<ManualClock>) -> [(&'static str, Box<dyn Storage>); 2] {
+        [
+            ("locked", Box::new(LockedStore::with_clock(Arc::clone(clock)))),
+            ("sharded", Box::new(ShardedStore::with_clock(4, Arc::clone(clock)))),
+        ]
+    }
+
+    #[test]
+    fn both_stores_expire_entries() {
+        let clock = Arc::new(ManualClock::default());
+        for (name, store) in stores(&clock) {
+            store.set("forever", "1", None);
+            store.set("brief", "2", Some(Duration::from_secs(10)));
+            assert_eq!((store.get("brief").as_deref(), store.len()), (Some("2"), 2), "{}", name);
+
+            clock.advance(Duration::from_secs(10));
+            assert_eq!((store.get("brief"), store.len()), (None, 1), "{}", name);
+            assert!(!store.delete("brief"), "{}: expired entries don't count as deleted", name);
+            store.set("brief", "3", Some(Duration::from_secs(10)));
+            assert_eq!(store.purge_expired(), 0, "{}", name);
+
+            clock.advance(Duration::from_secs(10));
+            assert_eq!(store.purge_expired(), 1, "{}", name);
+            assert!(store.delete("forever"), "{}", name);
+            assert!(store.is_empty(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn sharded_store_spreads_keys_and_survives_threads() {
+        let store = Arc::new(ShardedStore::new(8));
+        let elapsed = hammer(Arc::clone(&store) as Arc<dyn Storage>, 4, 1_000);
+        assert!(elapsed < Duration::from_secs(10));
+        // Every 4th op is a SET, over keys i % 100: 25 distinct keys per thread
+        assert_eq!(store.len(), 4 * 25);
+        assert!(store.shard_sizes().iter().all(|&size| size > 0));
+    }
+
+    #[test]
+    fn commands_parse_and_reject() {
+        assert_eq!("ping".parse::<Command>().unwrap(), Command::Ping);
+        assert_eq!(
+            "SET  k hello world".parse::<Command>().unwrap(),
+            Command::Set { key: "k".to_string(), value: "hello world".to_string(), ttl: None }
+        );
+        assert_eq!(
+            "setex k 5 v".parse::<Command>().unwrap(),
+            Command::Set { key: "k".to_string(), value: "v".to_string(), ttl: Some(Duration::from_secs(5)) }
+        );
+        let error = |line: &str| line.parse::<Command>().unwrap_err().to_string();
+        assert_eq!(error(""), "empty command");
+        assert_eq!(error("FLY away"), "unknown command 'FLY'");
+        assert_eq!(error("GET a b"), "usage: GET <key>");
+        assert_eq!(error("SET k"), "usage: SET <key> <value>");
+        assert_eq!(error("SETEX k soon v"), "invalid TTL 'soon' (expected whole seconds)");
+        assert_eq!(error("LEN now"), "usage: LEN");
+    }
+
+    #[test]
+    fn lines_become_responses() {
+        let store = LockedStore::new();
+        assert_eq!(handle_line(&store, "SET a 1").to_string(), "OK");
+        assert_eq!(handle_line(&store, "GET a").to_string(), "VALUE 1");
+        assert_eq!(handle_line(&store, "DEL a").to_string(), "INT 1");
+        assert_eq!(handle_line(&store, "GET a").to_string(), "NIL");
+        assert_eq!(handle_line(&store, "NOPE").to_string(), "ERR unknown command 'NOPE'");
+    }
+
+    #[test]
+    fn sweeper_purges_in_the_background_and_stops_on_drop() {
+        let store = Arc::new(LockedStore::new());
+        store.set("gone", "x", Some(Duration::ZERO));
+        let sweeper = spawn_sweeper(Arc::clone(&store), Duration::from_millis(1));
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while lock(&store.map).contains_key("gone") && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(1));
+        }
+        drop(sweeper);
+        assert!(!lock(&store.map).contains_key("gone"));
+        assert_eq!(Arc::strong_count(&store), 1, "the sweeper thread released its Arc");
+    }
+}
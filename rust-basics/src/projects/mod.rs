@@ -0,0 +1,16 @@
+// ===== CAPSTONE PROJECTS =====
+//
+// The learning modules each study one topic in isolation. The projects here
+// are small but complete programs that need several of them at once - the
+// questions they answer are "which tool where?" rather than "how does this
+// tool work?".
+//
+// Each project registers itself like any other module (`run kv_store`), with
+// its own quiz and exercise; file() points at the projects/ path.
+//
+// - kv_store: a sharded, TTL-aware key-value store served over TCP
+// - downloader: bounded-concurrency HTTP downloads with retries and an
+//   aggregated error report
+
+pub mod downloader;
+pub mod kv_store;
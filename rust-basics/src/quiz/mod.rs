@@ -0,0 +1,265 @@
+// ===== QUIZ ENGINE =====
+//
+// Multiple-choice questions for every learning module, answered with
+// `rust-basics quiz <module>`.
+//
+// QUESTION BANKS:
+// • One TOML file per module in src/quiz/questions/, embedded with include_str!
+//   so the binary works from any directory
+// • Each [[question]] has a prompt, choices, the 0-based index of the answer,
+//   and an explanation shown after answering
+// • Banks are validated when loaded (answer in range, at least two choices)
+//
+// The interactive loop reads from any BufRead and writes to any Write, so the
+// CLI passes stdin/stdout and the tests pass in-memory buffers.
+
+use serde::Deserialize;
+use std::io::{self, BufRead, Write};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum QuizError {
+    #[error("there is no quiz for module '{0}'")]
+    NoQuiz(String),
+
+    #[error("quiz for '{module}' is not valid TOML: {source}")]
+    Parse { module: String, source: toml::de::Error },
+
+    #[error("quiz for '{module}', question {number}: {reason}")]
+    Invalid { module: String, number: usize, reason: String },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Question {
+    pub prompt: String,
+    pub choices: Vec<String>,
+    pub answer: usize,
+    pub explanation: String,
+}
+
+#[derive(Deserialize)]
+struct QuestionBank {
+    question: Vec<Question>,
+}
+
+#[derive(Debug)]
+pub struct Quiz {
+    pub module: String,
+    pub questions: Vec<Question>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct QuizResult {
+    pub score: usize,
+    pub answered: usize,
+    pub total: usize,
+}
+
+impl QuizResult {
+    // Quitting early doesn't count towards the recorded best score
+    pub fn finished(&self) -> bool {
+        self.answered == self.total
+    }
+}
+
+// ===== 1. LOADING =====
+
+const BANKS: &[(&str, &str)] = &[
+    ("traits", include_str!("questions/traits.toml")),
+    ("lifetimes", include_str!("questions/lifetimes.toml")),
+    ("smart_pointers", include_str!("questions/smart_pointers.toml")),
+    ("errors", include_str!("questions/errors.toml")),
+    ("macros", include_str!("questions/macros.toml")),
+    ("async_await", include_str!("questions/async_await.toml")),
+    ("concurrency", include_str!("questions/concurrency.toml")),
+    ("collections", include_str!("questions/collections.toml")),
+    ("testing", include_str!("questions/testing.toml")),
+    ("pin_futures", include_str!("questions/pin_futures.toml")),
+    ("tokio_channels", include_str!("questions/tokio_channels.toml")),
+    ("patterns", include_str!("questions/patterns.toml")),
+    ("actors", include_str!("questions/actors.toml")),
+    ("file_io", include_str!("questions/file_io.toml")),
+    ("networking", include_str!("questions/networking.toml")),
+    ("parsing", include_str!("questions/parsing.toml")),
+    ("iterators", include_str!("questions/iterators.toml")),
+    ("kv_store", include_str!("questions/kv_store.toml")),
+    ("downloader", include_str!("questions/downloader.toml")),
+    ("lru_cache", include_str!("questions/lru_cache.toml")),
+    ("strings", include_str!("questions/strings.toml")),
+    ("combinators", include_str!("questions/combinators.toml")),
+    ("const_eval", include_str!("questions/const_eval.toml")),
+    ("zero_copy", include_str!("questions/zero_copy.toml")),
+    ("panics", include_str!("questions/panics.toml")),
+    ("cancellation", include_str!("questions/cancellation.toml")),
+    ("instrumentation", include_str!("questions/instrumentation.toml")),
+];
+
+pub fn has_quiz(module: &str) -> bool {
+    BANKS.iter().any(|(name, _)| *name == module)
+}
+
+pub fn load(module: &str) -> Result<Quiz, QuizError> {
+    let (_, text) = BANKS
+        .iter()
+        .find(|(name, _)| *name == module)
+        .ok_or_else(|| QuizError::NoQuiz(module.to_string()))?;
+    parse(module, text)
+}
+
+fn parse(module: &str, text: &str) -> Result<Quiz, QuizError> {
+    let bank: QuestionBank = toml::from_str(text)
+        .map_err(|source| QuizError::Parse { module: module.to_string(), source })?;
+
+    for (i, question) in bank.question.iter().enumerate() {
+        let reason = if question.choices.len() < 2 {
+            Some("needs at least two choices".to_string())
+        } else if question.choices.len() > 26 {
+            Some("has more choices than letters".to_string())
+        } else if question.answer >= question.choices.len() {
+            Some(format!("answer {} is out of range", question.answer))
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            return Err(QuizError::Invalid { module: module.to_string(), number: i + 1, reason });
+        }
+    }
+
+    Ok(Quiz { module: module.to_string(), questions: bank.question })
+}
+
+// ===== 2. ASKING =====
+
+fn letter(index: usize) -> char {
+    (b'a' + index as u8) as char
+}
+
+// "b", "B" and "2" all pick the second choice
+fn parse_choice(input: &str, choices: usize) -> Option<usize> {
+    let input = input.trim().to_lowercase();
+    let index = match input.parse::<usize>() {
+        Ok(number) => number.checked_sub(1)?,
+        Err(_) => {
+            let mut chars = input.chars();
+            let c = chars.next().filter(|c| c.is_ascii_lowercase() && chars.next().is_none())?;
+            (c as u8 - b'a') as usize
+        }
+    };
+    (index < choices).then_some(index)
+}
+
+pub fn run_interactive(
+    quiz: &Quiz,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<QuizResult> {
+    let total = quiz.questions.len();
+    let mut result = QuizResult { score: 0, answered: 0, total };
+
+    for (number, question) in quiz.questions.iter().enumerate() {
+        writeln!(out, "\n❓ Question {}/{}: {}", number + 1, total, question.prompt)?;
+        for (i, choice) in question.choices.iter().enumerate() {
+            writeln!(out, "   {}) {}", letter(i), choice)?;
+        }
+
+        let last = letter(question.choices.len() - 1);
+        let choice = loop {
+            write!(out, "Your answer (a-{}, q to quit): ", last)?;
+            out.flush()?;
+
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 || line.trim().eq_ignore_ascii_case("q") {
+                return Ok(result); // EOF or quit
+            }
+            match parse_choice(&line, question.choices.len()) {
+                Some(choice) => break choice,
+                None => writeln!(out, "Please answer with a letter from a to {}.", last)?,
+            }
+        };
+
+        result.answered += 1;
+        if choice == question.answer {
+            result.score += 1;
+            writeln!(out, "✅ Correct!")?;
+        } else {
+            writeln!(
+                out,
+                "❌ Not quite - the answer is {}) {}",
+                letter(question.answer),
+                question.choices[question.answer]
+            )?;
+        }
+        writeln!(out, "💡 {}", question.explanation)?;
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    const SAMPLE: &str = r#"
+        [[question]]
+        prompt = "Two plus two?"
+        choices = ["3", "4"]
+        answer = 1
+        explanation = "Arithmetic."
+
+        [[question]]
+        prompt = "Rust's mascot?"
+        choices = ["Ferris", "Gopher", "Duke"]
+        answer = 0
+        explanation = "Ferris the crab."
+    "#;
+
+    fn play(answers: &str) -> (QuizResult, String) {
+        let quiz = parse("sample", SAMPLE).unwrap();
+        let mut out = Vec::new();
+        let result = run_interactive(&quiz, &mut answers.as_bytes(), &mut out).unwrap();
+        (result, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn every_module_has_a_valid_quiz() {
+        for module in registry::modules() {
+            let quiz = load(module.name()).unwrap();
+            assert!(quiz.questions.len() >= 3, "{} has too few questions", module.name());
+        }
+        assert!(matches!(load("nope"), Err(QuizError::NoQuiz(_))));
+    }
+
+    #[test]
+    fn scores_answers_and_explains() {
+        let (result, output) = play("b\nc\n");
+        assert_eq!(result, QuizResult { score: 1, answered: 2, total: 2 });
+        assert!(result.finished());
+        assert!(output.contains("✅ Correct!"));
+        assert!(output.contains("❌ Not quite - the answer is a) Ferris"));
+    }
+
+    #[test]
+    fn reprompts_on_invalid_input_and_accepts_numbers() {
+        let (result, output) = play("x\n5\n2\n1\n");
+        assert_eq!(result.score, 2);
+        assert_eq!(output.matches("Please answer with a letter from a to b.").count(), 2);
+    }
+
+    #[test]
+    fn quitting_early_is_not_finished() {
+        let (result, _) = play("b\nq\n");
+        assert_eq!(result, QuizResult { score: 1, answered: 1, total: 2 });
+        assert!(!result.finished());
+
+        let (result, _) = play("");
+        assert_eq!(result.answered, 0);
+    }
+
+    #[test]
+    fn rejects_out_of_range_answers() {
+        let bad = "[[question]]\nprompt = \"?\"\nchoices = [\"a\", \"b\"]\nanswer = 2\nexplanation = \"\"";
+        let err = parse("bad", bad).unwrap_err();
+        assert_eq!(err.to_string(), "quiz for 'bad', question 1: answer 2 is out of range");
+    }
+}
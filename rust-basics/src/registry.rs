@@ -0,0 +1,156 @@
+// ===== MODULE REGISTRY =====
+//
+// Every learning module implements `LearningModule` and registers itself next
+// to its own code with `inventory::submit!`. The CLI (main.rs) and the TUI
+// discover them here, so adding a module never means editing main.rs.
+//
+// HOW INVENTORY WORKS:
+// • collect!(T) declares a global, type-keyed collection
+// • submit! { T(...) } adds a value from ANY file (or crate) in the program
+// • iter::<T>() walks everything submitted - the linker gathers the entries
+//   at build time, so there is no runtime registration call to forget
+// • Iteration order is unspecified, which is why modules carry a number()
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::OnceLock;
+
+use crate::report::Report;
+
+// Boxed so that sync and async demonstrations share one signature.
+// Not Send: demonstrations run on the main task and never move threads.
+pub type DemoFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+// The same for reports, which await whatever the demonstration awaits
+pub type ReportFuture = Pin<Box<dyn Future<Output = Report>>>;
+
+// ===== 1. THE COMMON INTERFACE =====
+
+// Sync because registered modules are shared through 'static references
+pub trait LearningModule: Sync {
+    // Position in `run --all` and `list`
+    fn number(&self) -> u8;
+
+    // CLI name, matches the source file
+    fn name(&self) -> &'static str;
+
+    // Heading shown when the module runs
+    fn title(&self) -> &'static str;
+
+    // One line for lists and the progress summary
+    fn description(&self) -> &'static str;
+
+    // Names of the modules worth studying first
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    // Runs the demonstration; sync ones are wrapped in an async block
+    fn run(&self) -> DemoFuture;
+
+    // The demonstration as data (see report.rs). Learning modules' run()
+    // prints the same report, so callers may use either; the projects only
+    // print and keep this default
+    fn report(&self) -> Option<ReportFuture> {
+        None
+    }
+
+    fn file(&self) -> String {
+        format!("{}.rs", self.name())
+    }
+
+    fn heading(&self) -> String {
+        format!("📚 MODULE {}: {}", self.number(), self.title())
+    }
+}
+
+impl fmt::Debug for dyn LearningModule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LearningModule")
+            .field("number", &self.number())
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+// ===== 2. THE REGISTRY =====
+
+pub struct ModuleRegistration(pub &'static dyn LearningModule);
+
+inventory::collect!(ModuleRegistration);
+
+// All registered modules, sorted by number (collected once)
+pub fn modules() -> &'static [&'static dyn LearningModule] {
+    static MODULES: OnceLock<Vec<&'static dyn LearningModule>> = OnceLock::new();
+
+    MODULES.get_or_init(|| {
+        let mut modules: Vec<_> = inventory::iter::<ModuleRegistration>
+            .into_iter()
+            .map(|registration| registration.0)
+            .collect();
+        modules.sort_by_key(|module| module.number());
+        modules
+    })
+}
+
+// ===== 3. LOOKUP =====
+//
+// Accepts the module name ("smart_pointers"), its kebab-case spelling
+// ("smart-pointers") or its number ("3").
+
+pub fn find(query: &str) -> Option<&'static dyn LearningModule> {
+    let query = query.trim().to_lowercase().replace('-', "_");
+
+    if let Ok(number) = query.parse::<u8>() {
+        return modules().iter().copied().find(|module| module.number() == number);
+    }
+    modules().iter().copied().find(|module| module.name() == query)
+}
+
+pub fn names() -> Vec<&'static str> {
+    modules().iter().map(|module| module.name()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn names_and_numbers_are_unique_and_sequential() {
+        let names: HashSet<_> = modules().iter().map(|m| m.name()).collect();
+        assert_eq!(names.len(), modules().len());
+
+        let numbers: Vec<u8> = modules().iter().map(|m| m.number()).collect();
+        let expected: Vec<u8> = (1..=modules().len() as u8).collect();
+        assert_eq!(numbers, expected);
+    }
+
+    #[test]
+    fn prerequisites_point_to_earlier_modules() {
+        for module in modules() {
+            for prerequisite in module.prerequisites() {
+                let Some(required) = find(prerequisite) else {
+                    panic!("{} requires unknown module {}", module.name(), prerequisite);
+                };
+                // Earlier-only also rules out cycles
+                assert!(
+                    required.number() < module.number(),
+                    "{} requires later module {}",
+                    module.name(),
+                    prerequisite
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn find_accepts_name_kebab_case_and_number() {
+        assert_eq!(find("smart_pointers").map(|m| m.number()), Some(3));
+        assert_eq!(find("Smart-Pointers").map(|m| m.number()), Some(3));
+        assert_eq!(find("3").map(|m| m.name()), Some("smart_pointers"));
+        assert!(find("borrow_checker").is_none());
+        assert!(find("0").is_none());
+    }
+}
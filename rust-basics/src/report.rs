@@ -0,0 +1,175 @@
+// ===== DEMONSTRATION REPORTS =====
+//
+// A demonstration that prints as it goes can only be checked by reading the
+// terminal. A Report is the same content as data: the module builds it, a
+// renderer turns it into text, and tests (or the TUI) can look inside.
+//
+//   Report "🦀 RUST TRAIT SYSTEM DEEP STUDY 🦀"
+//   ├─ Section "1️⃣ BASIC TRAIT IMPLEMENTATIONS:"
+//   │   ├─ Example  { code: "article.print()", output: "📰 Article: ..." }
+//   │   └─ Text     "..."
+//   └─ Section "🎯 KEY CONCEPTS SUMMARY:"
+//       └─ Check    "Traits define shared behavior"
+//
+// Every learning module returns one from LearningModule::report() (see
+// registry.rs); only the capstone projects in projects/ still print as they
+// run. A module keeps its demonstrate_x() function - it just prints the
+// rendering.
+
+use std::fmt;
+
+// One line (or block) of a section
+#[derive(Debug, Clone, PartialEq)]
+pub enum Entry {
+    // Prose, printed as is
+    Text(String),
+    // An expression and what it produced, e.g. "Article::type_name()" → "Article"
+    Example { code: String, output: String },
+    // A takeaway, printed with ✅
+    Check(String),
+    // A list item, printed with •
+    Bullet(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    pub heading: String,
+    pub entries: Vec<Entry>,
+}
+
+impl Section {
+    pub fn new(heading: impl Into<String>) -> Self {
+        Section { heading: heading.into(), entries: Vec::new() }
+    }
+
+    pub fn text(&mut self, text: impl Into<String>) -> &mut Self {
+        self.entries.push(Entry::Text(text.into()));
+        self
+    }
+
+    pub fn example(&mut self, code: impl Into<String>, output: impl fmt::Display) -> &mut Self {
+        self.entries.push(Entry::Example { code: code.into(), output: output.to_string() });
+        self
+    }
+
+    pub fn check(&mut self, text: impl Into<String>) -> &mut Self {
+        self.entries.push(Entry::Check(text.into()));
+        self
+    }
+
+    pub fn bullet(&mut self, text: impl Into<String>) -> &mut Self {
+        self.entries.push(Entry::Bullet(text.into()));
+        self
+    }
+
+    // Output of the first example whose code matches exactly
+    pub fn output(&self, code: &str) -> Option<&str> {
+        self.entries.iter().find_map(|entry| match entry {
+            Entry::Example { code: c, output } if c == code => Some(output.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn outputs(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().filter_map(|entry| match entry {
+            Entry::Example { output, .. } => Some(output.as_str()),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub title: String,
+    pub sections: Vec<Section>,
+}
+
+impl Report {
+    pub fn new(title: impl Into<String>) -> Self {
+        Report { title: title.into(), sections: Vec::new() }
+    }
+
+    // Starts a new section and returns it for filling in
+    pub fn section(&mut self, heading: impl Into<String>) -> &mut Section {
+        self.sections.push(Section::new(heading));
+        self.sections.last_mut().expect("just pushed")
+    }
+
+    // First section whose heading contains `needle` - headings carry emoji
+    // numbers, so tests match on the words
+    pub fn find(&self, needle: &str) -> Option<&Section> {
+        self.sections.iter().find(|section| section.heading.contains(needle))
+    }
+}
+
+// ===== RENDERING =====
+//
+// The text layout the demonstrations always printed: the title, then each
+// section after a blank line. Multi-line example output starts on its own
+// line so tables and matrices stay aligned.
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Entry::Text(text) => write!(f, "{}", text),
+            Entry::Example { code, output } if output.contains('\n') => write!(f, "{} =\n{}", code, output),
+            Entry::Example { code, output } => write!(f, "{} → {}", code, output),
+            Entry::Check(text) => write!(f, "✅ {}", text),
+            Entry::Bullet(text) => write!(f, "• {}", text),
+        }
+    }
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.heading)?;
+        for entry in &self.entries {
+            writeln!(f, "{}", entry)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.title)?;
+        for section in &self.sections {
+            write!(f, "\n{}", section)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Report {
+        let mut report = Report::new("TITLE");
+        report
+            .section("1️⃣ FIRST:")
+            .text("intro")
+            .example("1 + 1", 2)
+            .example("matrix", "[1 0]\n[0 1]");
+        report.section("🎯 SUMMARY:").check("done").bullet("note");
+        report
+    }
+
+    #[test]
+    fn renders_title_then_blank_separated_sections() {
+        assert_eq!(
+            sample().to_string(),
+            "TITLE\n\n1️⃣ FIRST:\nintro\n1 + 1 → 2\nmatrix =\n[1 0]\n[0 1]\n\n🎯 SUMMARY:\n✅ done\n• note\n"
+        );
+    }
+
+    #[test]
+    fn sections_are_found_by_heading_words() {
+        let report = sample();
+        let first = report.find("FIRST").unwrap();
+        assert_eq!(first.output("1 + 1"), Some("2"));
+        assert_eq!(first.output("2 + 2"), None);
+        assert_eq!(first.outputs().count(), 2);
+        assert!(report.find("MISSING").is_none());
+    }
+}
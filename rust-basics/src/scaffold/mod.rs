@@ -0,0 +1,317 @@
+// ===== MODULE SCAFFOLDING =====
+//
+// Backs `cargo run --bin new-module <name>`: everything a new learning
+// module needs to pass the crate's own checks, generated from the templates
+// in src/scaffold/templates/.
+//
+//   new files                                  edited files
+//   src/<name>.rs           module + tests     src/lib.rs             header line, pub mod
+//   src/quiz/questions/<name>.toml             src/quiz/mod.rs        BANKS entry
+//   exercises/<name>/<exercise>.rs             src/exercises/mod.rs   exercise! entry
+//   src/exercises/suites/<name>_<exercise>.rs
+//
+// main.rs never changes: the module registers itself with inventory (see
+// registry.rs). The quiz and exercise entries are what `progress`, `check`
+// and `quiz` track per module, and tests require every module to have both.
+//
+// Everything is prepared in memory first, so a missing anchor or an
+// existing file fails before anything is written.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+const MODULE_TEMPLATE: &str = include_str!("templates/module.rs.tmpl");
+const QUIZ_TEMPLATE: &str = include_str!("templates/quiz.toml.tmpl");
+const EXERCISE_TEMPLATE: &str = include_str!("templates/exercise.rs.tmpl");
+const SUITE_TEMPLATE: &str = include_str!("templates/suite.rs.tmpl");
+
+// Words that can't name a module without r#
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn", "for",
+    "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "static",
+    "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while",
+];
+
+#[derive(Error, Debug)]
+pub enum ScaffoldError {
+    #[error("'{0}' is not a valid module name: use lower snake_case, like zero_copy")]
+    InvalidName(String),
+
+    #[error("{} already exists", .0.display())]
+    Exists(PathBuf),
+
+    #[error("could not find {anchor} in {}", path.display())]
+    MissingAnchor { path: PathBuf, anchor: &'static str },
+
+    #[error("could not access {}: {source}", path.display())]
+    Io { path: PathBuf, source: io::Error },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleSpec {
+    pub name: String,
+    pub number: u8,
+    pub title: String,
+    pub description: String,
+    pub prerequisites: Vec<String>,
+    // Name of the first exercise: exercises/<name>/<exercise>.rs
+    pub exercise: String,
+}
+
+impl ModuleSpec {
+    // "zero_copy" → title "ZERO COPY"; the rest are placeholders to edit
+    pub fn new(name: &str, number: u8) -> Result<Self, ScaffoldError> {
+        validate_name(name)?;
+        Ok(ModuleSpec {
+            name: name.to_string(),
+            number,
+            title: name.replace('_', " ").to_uppercase(),
+            description: "TODO: one-line description".to_string(),
+            prerequisites: Vec::new(),
+            exercise: "warmup".to_string(),
+        })
+    }
+
+    // ZeroCopyModule
+    pub fn type_name(&self) -> String {
+        let mut type_name: String = self
+            .name
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars.next().map(|first| first.to_ascii_uppercase().to_string() + chars.as_str()).unwrap_or_default()
+            })
+            .collect();
+        type_name.push_str("Module");
+        type_name
+    }
+
+    fn render(&self, template: &str) -> String {
+        let prerequisites = if self.prerequisites.is_empty() {
+            String::new()
+        } else {
+            let list = self.prerequisites.iter().map(|name| format!("{:?}", name)).collect::<Vec<_>>().join(", ");
+            format!("\n    fn prerequisites(&self) -> &'static [&'static str] {{\n        &[{}]\n    }}\n", list)
+        };
+        template
+            .replace("{{name}}", &self.name)
+            .replace("{{number}}", &self.number.to_string())
+            .replace("{{title}}", &self.title)
+            .replace("{{description}}", &self.description.replace('"', "\\\""))
+            .replace("{{type_name}}", &self.type_name())
+            .replace("{{prerequisites}}", &prerequisites)
+    }
+}
+
+// Lower snake_case, starting with a letter, and not a keyword
+pub fn validate_name(name: &str) -> Result<(), ScaffoldError> {
+    let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && !name.ends_with('_')
+        && !name.contains("__")
+        && !KEYWORDS.contains(&name);
+    if valid { Ok(()) } else { Err(ScaffoldError::InvalidName(name.to_string())) }
+}
+
+// Creates and registers the module under `root` (the rust-basics crate
+// directory). Returns every path written, relative to `root`: new files
+// first, then edited ones
+pub fn create(root: &Path, spec: &ModuleSpec) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let new_files = [
+        (PathBuf::from(format!("src/{}.rs", spec.name)), spec.render(MODULE_TEMPLATE)),
+        (PathBuf::from(format!("src/quiz/questions/{}.toml", spec.name)), spec.render(QUIZ_TEMPLATE)),
+        (PathBuf::from(format!("exercises/{}/{}.rs", spec.name, spec.exercise)), spec.render(EXERCISE_TEMPLATE)),
+        (
+            PathBuf::from(format!("src/exercises/suites/{}_{}.rs", spec.name, spec.exercise)),
+            spec.render(SUITE_TEMPLATE),
+        ),
+    ];
+    for (path, _) in &new_files {
+        if root.join(path).exists() {
+            return Err(ScaffoldError::Exists(path.clone()));
+        }
+    }
+
+    let lib = Path::new("src/lib.rs");
+    let mut lib_text = read(root, lib)?;
+    lib_text = insert_line(
+        &lib_text,
+        lib,
+        "the \"// - projects:\" header line",
+        |lines| lines.iter().position(|line| line.starts_with("// - projects:")),
+        &format!("// - {}: {}", spec.name, spec.description),
+    )?;
+    lib_text = insert_line(
+        &lib_text,
+        lib,
+        "\"pub mod projects;\"",
+        |lines| {
+            let at = lines.iter().position(|line| *line == "pub mod projects;")?;
+            // Above the cfg attribute that belongs to projects
+            Some(if at > 0 && lines[at - 1].starts_with("#[cfg") { at - 1 } else { at })
+        },
+        &format!("pub mod {};", spec.name),
+    )?;
+
+    let quiz = Path::new("src/quiz/mod.rs");
+    let quiz_text = insert_line(
+        &read(root, quiz)?,
+        quiz,
+        "the end of BANKS",
+        |lines| closing_bracket_after(lines, "const BANKS"),
+        &format!("    (\"{0}\", include_str!(\"questions/{0}.toml\")),", spec.name),
+    )?;
+
+    let exercises = Path::new("src/exercises/mod.rs");
+    let exercises_text = insert_line(
+        &read(root, exercises)?,
+        exercises,
+        "the end of EXERCISES",
+        |lines| closing_bracket_after(lines, "pub const EXERCISES"),
+        &format!("    exercise!(\"{}\", \"{}\", \"TODO: a hint for when the learner is stuck\"),", spec.name, spec.exercise),
+    )?;
+
+    let edited_files = [(lib.to_path_buf(), lib_text), (quiz.to_path_buf(), quiz_text), (exercises.to_path_buf(), exercises_text)];
+    let mut written = Vec::new();
+    for (path, text) in new_files.into_iter().chain(edited_files) {
+        write(root, &path, &text)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+// The "];" that closes the array declared on the line starting with `declaration`
+fn closing_bracket_after(lines: &[&str], declaration: &str) -> Option<usize> {
+    let start = lines.iter().position(|line| line.starts_with(declaration))?;
+    lines[start..].iter().position(|line| *line == "];").map(|offset| start + offset)
+}
+
+// Inserts `line` before the line `find` picks
+fn insert_line(
+    text: &str,
+    path: &Path,
+    anchor: &'static str,
+    find: impl FnOnce(&[&str]) -> Option<usize>,
+    line: &str,
+) -> Result<String, ScaffoldError> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let at = find(&lines).ok_or_else(|| ScaffoldError::MissingAnchor { path: path.to_path_buf(), anchor })?;
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(root: &Path, path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(root.join(path)).map_err(|source| ScaffoldError::Io { path: path.to_path_buf(), source })
+}
+
+fn write(root: &Path, path: &Path, text: &str) -> Result<(), ScaffoldError> {
+    let full = root.join(path);
+    if let Some(dir) = full.parent() {
+        fs::create_dir_all(dir).map_err(|source| ScaffoldError::Io { path: path.to_path_buf(), source })?;
+    }
+    fs::write(&full, text).map_err(|source| ScaffoldError::Io { path: path.to_path_buf(), source })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EDITED: [&str; 3] = ["src/lib.rs", "src/quiz/mod.rs", "src/exercises/mod.rs"];
+
+    // A scratch crate holding copies of the real files the generator edits,
+    // so the anchors are tested against the tree as it is
+    fn scratch_crate() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        for file in EDITED {
+            let to = root.path().join(file);
+            fs::create_dir_all(to.parent().unwrap()).unwrap();
+            fs::copy(Path::new(env!("CARGO_MANIFEST_DIR")).join(file), to).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn names_must_be_snake_case_identifiers() {
+        for good in ["zero_copy", "ffi", "http2"] {
+            assert!(validate_name(good).is_ok(), "{}", good);
+        }
+        for bad in ["", "ZeroCopy", "zero-copy", "2fast", "_private", "trailing_", "double__under", "async", "mod"] {
+            assert!(matches!(validate_name(bad), Err(ScaffoldError::InvalidName(_))), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn spec_derives_title_and_type_name() {
+        let spec = ModuleSpec::new("zero_copy", 30).unwrap();
+        assert_eq!(spec.title, "ZERO COPY");
+        assert_eq!(spec.type_name(), "ZeroCopyModule");
+        assert!(ModuleSpec::new("Bad", 30).is_err());
+    }
+
+    #[test]
+    fn template_renders_a_registered_module() {
+        let mut spec = ModuleSpec::new("ring_buffers", 42).unwrap();
+        spec.description = "Fixed-size \"circular\" queues".to_string();
+        let source = spec.render(MODULE_TEMPLATE);
+        assert!(!source.contains("{{"), "every placeholder is filled");
+        assert!(source.contains("pub fn demonstrate_ring_buffers()"));
+        assert!(source.contains("pub struct RingBuffersModule;"));
+        assert!(source.contains("        42\n"));
+        assert!(source.contains(r#""Fixed-size \"circular\" queues""#));
+        assert!(!source.contains("fn prerequisites"), "the trait's default covers none");
+
+        spec.prerequisites = vec!["collections".to_string(), "iterators".to_string()];
+        assert!(spec.render(MODULE_TEMPLATE).contains(r#"&["collections", "iterators"]"#));
+    }
+
+    #[test]
+    fn creates_files_and_registers_the_module() {
+        let root = scratch_crate();
+        let spec = ModuleSpec::new("ring_buffers", 42).unwrap();
+        let written = create(root.path(), &spec).unwrap();
+        assert_eq!(written.len(), 7);
+        for path in &written {
+            assert!(root.path().join(path).is_file(), "{}", path.display());
+        }
+
+        let lib = fs::read_to_string(root.path().join("src/lib.rs")).unwrap();
+        assert!(lib.contains("// - ring_buffers: TODO: one-line description\n// - projects:"));
+        assert!(lib.contains("pub mod ring_buffers;\n#[cfg(not(target_arch = \"wasm32\"))]\npub mod projects;"));
+
+        let quiz = fs::read_to_string(root.path().join("src/quiz/mod.rs")).unwrap();
+        assert!(quiz.contains("    (\"ring_buffers\", include_str!(\"questions/ring_buffers.toml\")),\n];"));
+        let exercises = fs::read_to_string(root.path().join("src/exercises/mod.rs")).unwrap();
+        assert!(exercises.contains("    exercise!(\"ring_buffers\", \"warmup\", "));
+
+        let stub = fs::read_to_string(root.path().join("exercises/ring_buffers/warmup.rs")).unwrap();
+        assert!(stub.contains("todo!()"), "a fresh exercise counts as not attempted");
+    }
+
+    #[test]
+    fn refuses_to_overwrite_and_writes_nothing() {
+        let root = scratch_crate();
+        let spec = ModuleSpec::new("ring_buffers", 42).unwrap();
+        fs::write(root.path().join("src/ring_buffers.rs"), "// mine").unwrap();
+        let before = fs::read_to_string(root.path().join("src/lib.rs")).unwrap();
+
+        let err = create(root.path(), &spec).unwrap_err();
+        assert!(matches!(err, ScaffoldError::Exists(_)));
+        assert_eq!(err.to_string(), "src/ring_buffers.rs already exists");
+        assert_eq!(fs::read_to_string(root.path().join("src/lib.rs")).unwrap(), before);
+        assert!(!root.path().join("src/quiz/questions/ring_buffers.toml").exists());
+    }
+
+    #[test]
+    fn missing_anchor_is_reported_before_writing() {
+        let root = scratch_crate();
+        fs::write(root.path().join("src/quiz/mod.rs"), "// no banks here\n").unwrap();
+        let spec = ModuleSpec::new("ring_buffers", 42).unwrap();
+
+        let err = create(root.path(), &spec).unwrap_err();
+        assert_eq!(err.to_string(), "could not find the end of BANKS in src/quiz/mod.rs");
+        assert!(!root.path().join("src/ring_buffers.rs").exists());
+    }
+}
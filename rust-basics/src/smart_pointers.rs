@@ -11,11 +11,23 @@
 // • RefCell<T>: Interior mutability, runtime borrow checking
 // • Arc<T>: Atomic reference counting, shared ownership (multi-threaded)
 // • Weak<T>: Weak references to break cycles
+// • Cow<T>: Borrow until a modification forces a clone
+// • Cell<T>: Interior mutability by moving values, no borrows
+// • OnceCell<T>/OnceLock<T>/LazyLock<T>: Values initialized exactly once
 
+use std::borrow::Cow;
+use std::cell::{Cell, OnceCell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
 use std::rc::{Rc, Weak};
-use std::cell::RefCell;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
 use std::thread;
+use mini_logger::debug;
+use crate::report::Report;
 
 // ===== 1. BOX<T> - HEAP ALLOCATION =====
 // 
@@ -41,6 +53,12 @@ pub enum List {
     Nil,                   // End of list
 }
 
+impl Default for List {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl List {
     // Create a new empty list
     pub fn new() -> Self {
@@ -109,6 +127,8 @@ impl BinaryTree {
         }
     }
     
+    // map_or(false, ..) reads as "no subtree, not found"
+    #[allow(clippy::unnecessary_map_or)]
     pub fn contains(&self, value: i32) -> bool {
         if value == self.value {
             return true;
@@ -138,6 +158,9 @@ impl BinaryTree {
 // • Graph-like data structures
 
 // GRAPH NODE USING RC<T>
+// The fields are only shown through Debug: the example is about sharing a
+// child between parents, not walking the graph
+#[allow(dead_code)]
 #[derive(Debug)]
 pub struct Node {
     value: i32,
@@ -222,8 +245,10 @@ impl MutableNode {
             .collect()
     }
     
+    // True only while the parent is alive: a Weak that can't be upgraded
+    // means the parent has been dropped, even though one was set
     pub fn has_parent(&self) -> bool {
-        self.parent.borrow().is_some()
+        self.parent.borrow().as_ref().is_some_and(|weak| weak.upgrade().is_some())
     }
 }
 
@@ -239,6 +264,12 @@ pub struct TreeNode {
     children: RefCell<Vec<Rc<TreeNode>>>,
 }
 
+impl Default for Tree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Tree {
     pub fn new() -> Self {
         Tree { root: None }
@@ -306,10 +337,17 @@ impl TreeNode {
 // • Thread-safe shared state
 
 // THREAD-SAFE COUNTER USING ARC<T>
+// (benches/sync_benchmarks.rs measures it against RwLock, atomics and sharding)
 pub struct SharedCounter {
     count: Arc<Mutex<i32>>,  // Arc for sharing, Mutex for thread-safe mutation
 }
 
+impl Default for SharedCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SharedCounter {
     pub fn new() -> Self {
         SharedCounter {
@@ -339,6 +377,12 @@ pub struct SharedData {
     values: Arc<Mutex<Vec<i32>>>,
 }
 
+impl Default for SharedData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SharedData {
     pub fn new() -> Self {
         SharedData {
@@ -419,6 +463,8 @@ impl Child {
         &self.name
     }
     
+    // No parent pointer, or a dropped parent, both mean false
+    #[allow(clippy::unnecessary_map_or)]
     pub fn has_parent(&self) -> bool {
         self.parent.borrow().as_ref().map_or(false, |weak| weak.upgrade().is_some())
     }
@@ -435,7 +481,603 @@ impl Child {
     }
 }
 
-// ===== 6. SMART POINTER COMPARISON =====
+// EXAMPLE: A REFERENCE-CYCLE LEAK AND HOW TO FIND IT
+//
+// Rc frees a value when its strong count reaches 0. In a cycle every node
+// is owned by another node of the cycle, so once the outside handles are
+// gone the counts stay at 1 forever - nothing is ever dropped:
+//
+//   head ─▶ [a] ──next──▶ [b] ──next──▶ [c]          leaky: prev is Rc
+//            ▲ ◀──prev─── │ ▲ ◀──prev─── │           drop(head): a still has
+//            └────────────┘ └────────────┘             strong count 1 (from b)
+//
+// Finding the leak means walking strong counts, the way CPython's cycle
+// collector does:
+// 1. For every node, count the strong links that come from OTHER NODES
+// 2. strong_count minus those is how many handles live OUTSIDE the graph;
+//    nodes with any are roots
+// 3. Everything reachable from a root over strong links is in use; the
+//    rest is kept alive only by the cycle - leaked
+//
+// The fix is the design rule from above: links that point "back" (prev,
+// parent, owner) are Weak, so every cycle has at least one non-owning edge.
+
+// Creates nodes and remembers them with Weak, so it can inspect them
+// without keeping any of them alive
+#[derive(Default)]
+pub struct NodeGraph {
+    nodes: RefCell<Vec<Weak<GraphNode>>>,
+    dropped: Rc<Cell<usize>>,
+}
+
+pub struct GraphNode {
+    name: String,
+    links: RefCell<Vec<Rc<GraphNode>>>,
+    weak_links: RefCell<Vec<Weak<GraphNode>>>,
+    // Shared with the NodeGraph: the custom Drop below counts into it
+    dropped: Rc<Cell<usize>>,
+}
+
+impl Drop for GraphNode {
+    fn drop(&mut self) {
+        self.dropped.set(self.dropped.get() + 1);
+    }
+}
+
+impl GraphNode {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // `from` owns `to`
+    pub fn link(from: &Rc<GraphNode>, to: &Rc<GraphNode>) {
+        from.links.borrow_mut().push(Rc::clone(to));
+    }
+
+    // `from` can reach `to` while something else keeps it alive
+    pub fn link_weak(from: &Rc<GraphNode>, to: &Rc<GraphNode>) {
+        from.weak_links.borrow_mut().push(Rc::downgrade(to));
+    }
+
+    // Names of the nodes this one links to and that still exist
+    pub fn neighbours(&self) -> Vec<String> {
+        let strong = self.links.borrow().iter().map(|node| node.name.clone()).collect::<Vec<_>>();
+        let weak = self.weak_links.borrow().iter().filter_map(Weak::upgrade).map(|node| node.name.clone()).collect::<Vec<_>>();
+        [strong, weak].concat()
+    }
+}
+
+impl NodeGraph {
+    pub fn new() -> Self {
+        NodeGraph::default()
+    }
+
+    pub fn node(&self, name: &str) -> Rc<GraphNode> {
+        let node = Rc::new(GraphNode {
+            name: name.to_string(),
+            links: RefCell::new(Vec::new()),
+            weak_links: RefCell::new(Vec::new()),
+            dropped: Rc::clone(&self.dropped),
+        });
+        self.nodes.borrow_mut().push(Rc::downgrade(&node));
+        node
+    }
+
+    // Nodes whose Drop has run
+    pub fn dropped(&self) -> usize {
+        self.dropped.get()
+    }
+
+    // Nodes still allocated, leaked or not
+    pub fn alive(&self) -> usize {
+        self.nodes.borrow().iter().filter(|node| node.strong_count() > 0).count()
+    }
+
+    // Names of the nodes nothing outside the graph can reach: they exist
+    // only because a cycle of strong links keeps them alive
+    pub fn find_leaks(&self) -> Vec<String> {
+        let nodes: Vec<Rc<GraphNode>> = self.nodes.borrow().iter().filter_map(Weak::upgrade).collect();
+        let index_of = |target: &Rc<GraphNode>| nodes.iter().position(|node| Rc::ptr_eq(node, target));
+
+        let mut internal = vec![0; nodes.len()];
+        for node in &nodes {
+            for target in node.links.borrow().iter() {
+                if let Some(i) = index_of(target) {
+                    internal[i] += 1;
+                }
+            }
+        }
+
+        // The upgrade above holds one strong reference of its own
+        let mut reachable: Vec<bool> =
+            nodes.iter().zip(&internal).map(|(node, &internal)| Rc::strong_count(node) - 1 > internal).collect();
+        let mut pending: Vec<usize> = (0..nodes.len()).filter(|&i| reachable[i]).collect();
+        while let Some(i) = pending.pop() {
+            for target in nodes[i].links.borrow().iter() {
+                if let Some(j) = index_of(target)
+                    && !reachable[j]
+                {
+                    reachable[j] = true;
+                    pending.push(j);
+                }
+            }
+        }
+
+        nodes.iter().zip(reachable).filter(|(_, reachable)| !reachable).map(|(node, _)| node.name.clone()).collect()
+    }
+
+    // Clears the strong links of every leaked node, which breaks the cycles
+    // and lets Rc free them. Returns how many nodes were freed
+    pub fn collect_cycles(&self) -> usize {
+        let before = self.dropped();
+        let leaked = self.find_leaks();
+        let nodes: Vec<Rc<GraphNode>> = self.nodes.borrow().iter().filter_map(Weak::upgrade).collect();
+        for node in nodes.iter().filter(|node| leaked.contains(&node.name)) {
+            node.links.borrow_mut().clear();
+        }
+        drop(nodes);
+        self.dropped() - before
+    }
+}
+
+// A doubly linked playlist whose prev links are Rc: every neighbour pair is
+// a cycle. Returns the first track
+pub fn leaky_playlist(graph: &NodeGraph, tracks: &[&str]) -> Option<Rc<GraphNode>> {
+    let nodes: Vec<Rc<GraphNode>> = tracks.iter().map(|track| graph.node(track)).collect();
+    for pair in nodes.windows(2) {
+        GraphNode::link(&pair[0], &pair[1]);
+        GraphNode::link(&pair[1], &pair[0]);
+    }
+    nodes.into_iter().next()
+}
+
+// The same playlist with Weak prev links: next owns, prev only points back
+pub fn playlist(graph: &NodeGraph, tracks: &[&str]) -> Option<Rc<GraphNode>> {
+    let nodes: Vec<Rc<GraphNode>> = tracks.iter().map(|track| graph.node(track)).collect();
+    for pair in nodes.windows(2) {
+        GraphNode::link(&pair[0], &pair[1]);
+        GraphNode::link_weak(&pair[1], &pair[0]);
+    }
+    nodes.into_iter().next()
+}
+
+// ===== 6. COW<T> - CLONE ON WRITE =====
+// 
+// UNDERSTANDING COW<T>:
+// • Cow<'a, str> is either Borrowed(&'a str) or Owned(String)
+// • Functions return Borrowed when the input needs no change - no allocation
+// • .to_mut() turns a Borrowed into Owned the first time you write to it
+// • Deref gives &str either way, so callers rarely care which one they got
+// 
+// WHEN TO USE COW<T>:
+// • Parsing/normalizing where most inputs are already fine
+// • APIs that sometimes need to modify their input
+// • Config values that are usually defaults (&'static str) but may be owned
+
+// CLONE-ON-WRITE PARSING: only strings containing escapes are copied
+pub fn unescape(input: &str) -> Cow<'_, str> {
+    if !input.contains('\\') {
+        return Cow::Borrowed(input);
+    }
+
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => output.push('\n'),
+            Some('t') => output.push('\t'),
+            Some(other) => output.push(other), // \\ and \" keep the character
+            None => output.push('\\'),         // trailing backslash stays as-is
+        }
+    }
+    Cow::Owned(output)
+}
+
+// TO_MUT: borrow until the first write, then own
+pub fn with_extension<'a>(name: &'a str, extension: &str) -> Cow<'a, str> {
+    let mut name = Cow::Borrowed(name);
+    if !name.ends_with(extension) {
+        name.to_mut().push_str(extension); // clones here, and only here
+    }
+    name
+}
+
+// ===== 7. CELL<T> VS REFCELL<T> =====
+// 
+// BOTH GIVE INTERIOR MUTABILITY, DIFFERENTLY:
+// • Cell<T>: moves values in and out (get/set/replace/take) - never hands out
+//   references, so it can never panic and has no runtime bookkeeping
+// • RefCell<T>: hands out Ref/RefMut guards and tracks them at runtime -
+//   needed when you must borrow the value (String, Vec, ...) in place
+// 
+// RULE OF THUMB:
+// • Copy types (counters, flags, small ids) → Cell
+// • Everything you need a reference into → RefCell
+
+pub struct RequestStats {
+    hits: Cell<u32>,            // Copy: get/set is enough
+    last_path: RefCell<String>, // Not Copy: mutated in place through a borrow
+}
+
+impl Default for RequestStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestStats {
+    pub fn new() -> Self {
+        RequestStats { hits: Cell::new(0), last_path: RefCell::new(String::new()) }
+    }
+
+    // &self, yet both fields change
+    pub fn record(&self, path: &str) {
+        self.hits.set(self.hits.get() + 1);
+
+        let mut last = self.last_path.borrow_mut();
+        last.clear();
+        last.push_str(path);
+    }
+
+    pub fn hits(&self) -> u32 {
+        self.hits.get()
+    }
+
+    pub fn last_path(&self) -> String {
+        self.last_path.borrow().clone()
+    }
+
+    // Cell::take swaps in Default::default() and returns the old value
+    pub fn reset(&self) -> u32 {
+        self.hits.take()
+    }
+}
+
+// ===== 8. ONCECELL, ONCELOCK AND LAZYLOCK - LAZY INITIALIZATION =====
+// 
+// UNDERSTANDING ONE-TIME INITIALIZATION:
+// • OnceCell<T>: set at most once, then only read - single-threaded
+// • OnceLock<T>: the same, but Sync - usable in statics and across threads;
+//   if several threads race, exactly one initializer runs
+// • LazyLock<T>: a OnceLock bundled with its initializer, so a static can be
+//   declared with its value and computed on first access
+// 
+// WHEN TO USE WHICH:
+// • Field computed on first use (caches) → OnceCell
+// • Global initialized from runtime input (args, env) → OnceLock
+// • Global whose value is known up front but not const → LazyLock
+
+// ONCECELL: the config text is parsed the first time a key is read
+pub struct LazyConfig {
+    raw: String,
+    parsed: OnceCell<HashMap<String, String>>,
+    parse_count: Cell<u32>,
+}
+
+impl LazyConfig {
+    pub fn new(raw: &str) -> Self {
+        LazyConfig { raw: raw.to_string(), parsed: OnceCell::new(), parse_count: Cell::new(0) }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        let parsed = self.parsed.get_or_init(|| {
+            self.parse_count.set(self.parse_count.get() + 1);
+            self.raw
+                .lines()
+                .filter_map(|line| line.split_once('='))
+                .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+                .collect()
+        });
+        parsed.get(key).map(String::as_str)
+    }
+
+    pub fn parse_count(&self) -> u32 {
+        self.parse_count.get()
+    }
+}
+
+// ONCELOCK: a global initialized once, whichever thread gets there first
+static SQUARES_INITIALIZED: AtomicUsize = AtomicUsize::new(0);
+
+pub fn squares_table() -> &'static [u64] {
+    static TABLE: OnceLock<Vec<u64>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        SQUARES_INITIALIZED.fetch_add(1, Ordering::SeqCst);
+        (0..1000u64).map(|n| n * n).collect()
+    })
+}
+
+pub fn squares_table_initializations() -> usize {
+    SQUARES_INITIALIZED.load(Ordering::SeqCst)
+}
+
+// LAZYLOCK: a static HashSet - HashSet::from isn't const, so a plain static
+// can't hold one
+static RUST_KEYWORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    ["as", "async", "await", "fn", "impl", "let", "match", "mut", "pub", "struct", "trait", "where"]
+        .into_iter()
+        .collect()
+});
+
+pub fn is_keyword(word: &str) -> bool {
+    RUST_KEYWORDS.contains(word)
+}
+
+// ===== 9. MYRC<T> - RC<T> FROM SCRATCH =====
+// 
+// WHAT RC<T> ACTUALLY IS:
+// • A pointer to ONE heap allocation holding two counters and the value:
+// 
+//     MyRc   ──┐     ┌──────────────────┐
+//     MyRc   ──┼───► │ strong: Cell     │  number of MyRc
+//     MyWeak ──┘     │ weak:   Cell     │  number of MyWeak, +1 for all MyRc together
+//                    │ value:  T        │  dropped when strong reaches 0
+//                    └──────────────────┘  freed when weak reaches 0
+// 
+// • Clone only bumps `strong` - the value is never copied
+// • Dropping the last MyRc drops the value, but the allocation stays until
+//   the last MyWeak is gone, because MyWeak still reads the counters
+// • upgrade() checks `strong` first, so a MyWeak never hands out a dropped value
+// 
+// WHY THE EXTRA WEAK COUNT:
+// • Dropping the value can drop the last MyWeak too (a node holding a weak
+//   pointer to itself). Without the +1 owned by the strong side, that MyWeak
+//   would free the allocation while MyRc::drop is still using it
+// 
+// WHY UNSAFE:
+// • The compiler can't see that several MyRc share one allocation - the
+//   counters are the proof that it's still alive, so every dereference has a
+//   SAFETY comment naming the count that keeps it valid
+// • The tests cover every path through the counters; run them under Miri
+//   (`cargo +nightly miri test smart_pointers`) to catch leaks, double frees
+//   and use-after-free
+// • NonNull is neither Send nor Sync, so neither is MyRc - correct, since the
+//   counters are plain Cells, not atomics (that's the difference from Arc)
+
+struct RcBox<T> {
+    strong: Cell<usize>,
+    weak: Cell<usize>,
+    // Dropped by hand when `strong` reaches 0, long before the box is freed
+    value: ManuallyDrop<T>,
+}
+
+pub struct MyRc<T> {
+    ptr: NonNull<RcBox<T>>,
+    // Tells the drop checker that dropping a MyRc may drop a T
+    _owns: PhantomData<RcBox<T>>,
+}
+
+pub struct MyWeak<T> {
+    ptr: NonNull<RcBox<T>>,
+}
+
+impl<T> MyRc<T> {
+    pub fn new(value: T) -> Self {
+        let boxed = Box::new(RcBox { strong: Cell::new(1), weak: Cell::new(1), value: ManuallyDrop::new(value) });
+        MyRc { ptr: NonNull::from(Box::leak(boxed)), _owns: PhantomData }
+    }
+
+    fn inner(&self) -> &RcBox<T> {
+        // SAFETY: this MyRc holds a strong count, so both the allocation and
+        // the value are alive for as long as it is borrowed
+        unsafe { self.ptr.as_ref() }
+    }
+
+    // Associated functions rather than methods, like Rc, so they can't
+    // shadow methods of T reached through Deref
+    pub fn strong_count(this: &Self) -> usize {
+        this.inner().strong.get()
+    }
+
+    pub fn weak_count(this: &Self) -> usize {
+        this.inner().weak.get() - 1
+    }
+
+    pub fn downgrade(this: &Self) -> MyWeak<T> {
+        let weak = &this.inner().weak;
+        weak.set(weak.get() + 1);
+        MyWeak { ptr: this.ptr }
+    }
+
+    pub fn ptr_eq(a: &Self, b: &Self) -> bool {
+        a.ptr == b.ptr
+    }
+}
+
+impl<T> Deref for MyRc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner().value
+    }
+}
+
+impl<T> Clone for MyRc<T> {
+    fn clone(&self) -> Self {
+        let strong = &self.inner().strong;
+        strong.set(strong.get() + 1);
+        MyRc { ptr: self.ptr, _owns: PhantomData }
+    }
+}
+
+impl<T> Drop for MyRc<T> {
+    fn drop(&mut self) {
+        let strong = &self.inner().strong;
+        strong.set(strong.get() - 1);
+        if strong.get() > 0 {
+            return;
+        }
+
+        // SAFETY: that was the last MyRc, so no reference to the value can
+        // exist any more, and `strong` never rises from 0 again (upgrade
+        // refuses), so it is dropped exactly once
+        unsafe { ManuallyDrop::drop(&mut (*self.ptr.as_ptr()).value) };
+
+        // Give up the weak count the MyRc shared; frees the box if no MyWeak is left
+        drop(MyWeak { ptr: self.ptr });
+    }
+}
+
+impl<T> MyWeak<T> {
+    // Only the counters: the value may already be dropped, so never make a
+    // reference to the whole RcBox here
+    fn counts(&self) -> (&Cell<usize>, &Cell<usize>) {
+        let inner = self.ptr.as_ptr();
+        // SAFETY: this MyWeak holds a weak count, so the allocation - though
+        // not necessarily the value - is still alive
+        unsafe { (&(*inner).strong, &(*inner).weak) }
+    }
+
+    pub fn upgrade(&self) -> Option<MyRc<T>> {
+        let (strong, _) = self.counts();
+        if strong.get() == 0 {
+            return None;
+        }
+        strong.set(strong.get() + 1);
+        Some(MyRc { ptr: self.ptr, _owns: PhantomData })
+    }
+
+    pub fn strong_count(&self) -> usize {
+        self.counts().0.get()
+    }
+}
+
+impl<T> Clone for MyWeak<T> {
+    fn clone(&self) -> Self {
+        let (_, weak) = self.counts();
+        weak.set(weak.get() + 1);
+        MyWeak { ptr: self.ptr }
+    }
+}
+
+impl<T> Drop for MyWeak<T> {
+    fn drop(&mut self) {
+        let (_, weak) = self.counts();
+        weak.set(weak.get() - 1);
+        if weak.get() == 0 {
+            // SAFETY: the pointer came from Box::leak in MyRc::new and nothing
+            // else can reach the allocation now. The value was already
+            // dropped by the last MyRc; ManuallyDrop keeps Box from dropping it again
+            drop(unsafe { Box::from_raw(self.ptr.as_ptr()) });
+        }
+    }
+}
+
+// ===== 10. TRACKEDBOX<T> - A CUSTOM SMART POINTER =====
+// 
+// WHAT MAKES SOMETHING A SMART POINTER:
+// • Deref: `*ptr` and method calls reach the value inside
+// • DerefMut: the same for `&mut` access
+// • Drop: code that runs when the owner goes out of scope (RAII - the
+//   resource is released by the scope that acquired it, no manual free)
+// 
+// DEREF COERCION:
+// • Where a `&TrackedBox<String>` is passed but a `&str` is expected, the
+//   compiler inserts derefs until the types match:
+//   &TrackedBox<String> → &String → &str
+// • Method calls do the same, so `tracked.len()` finds String::len
+// 
+// DROP ORDER:
+// • Local variables: reverse order of declaration (last in, first out)
+// • Struct fields and Vec elements: in order, first to last
+// • mem::drop(x) or moving x into a function ends its life early
+
+// Shared record of what every TrackedBox created from it did
+#[derive(Clone, Default)]
+pub struct AllocationTracker {
+    state: Rc<TrackerState>,
+}
+
+#[derive(Default)]
+struct TrackerState {
+    live: Cell<usize>,
+    total: Cell<usize>,
+    events: RefCell<Vec<String>>,
+}
+
+impl AllocationTracker {
+    pub fn new() -> Self {
+        AllocationTracker::default()
+    }
+
+    pub fn track<T>(&self, label: &'static str, value: T) -> TrackedBox<T> {
+        TrackedBox::new(self, label, value)
+    }
+
+    // TrackedBoxes allocated and not yet dropped
+    pub fn live(&self) -> usize {
+        self.state.live.get()
+    }
+
+    pub fn total(&self) -> usize {
+        self.state.total.get()
+    }
+
+    // "alloc <label>" and "drop <label>", in the order they happened
+    pub fn events(&self) -> Vec<String> {
+        self.state.events.borrow().clone()
+    }
+
+    fn record(&self, event: String) {
+        self.state.events.borrow_mut().push(event);
+    }
+}
+
+pub struct TrackedBox<T> {
+    value: Box<T>,
+    label: &'static str,
+    tracker: AllocationTracker,
+}
+
+impl<T> TrackedBox<T> {
+    pub fn new(tracker: &AllocationTracker, label: &'static str, value: T) -> Self {
+        let state = &tracker.state;
+        state.live.set(state.live.get() + 1);
+        state.total.set(state.total.get() + 1);
+        debug!("TrackedBox '{}' allocated {} bytes", label, std::mem::size_of::<T>());
+        tracker.record(format!("alloc {}", label));
+        TrackedBox { value: Box::new(value), label, tracker: tracker.clone() }
+    }
+
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+}
+
+impl<T> Deref for TrackedBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for TrackedBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T> Drop for TrackedBox<T> {
+    // Runs before the fields are dropped, so the Box is freed right after this
+    fn drop(&mut self) {
+        let state = &self.tracker.state;
+        state.live.set(state.live.get() - 1);
+        debug!("TrackedBox '{}' freed, {} still live", self.label, state.live.get());
+        self.tracker.record(format!("drop {}", self.label));
+    }
+}
+
+// Takes &str, but accepts &TrackedBox<String> through deref coercion
+pub fn shout(text: &str) -> String {
+    text.to_uppercase()
+}
+
+// ===== 11. SMART POINTER COMPARISON =====
 // 
 // DECISION MATRIX:
 // 
@@ -449,28 +1091,42 @@ impl Child {
 // │ Use Case    │ Heap alloc  │ Shared data │ Interior mut│ Thread share│
 // └─────────────┴─────────────┴─────────────┴─────────────┴─────────────┘
 // 
+// ┌─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┐
+// │   Feature   │   Cow<T>    │   Cell<T>   │ OnceCell<T> │ OnceLock<T> │ LazyLock<T> │
+// ├─────────────┼─────────────┼─────────────┼─────────────┼─────────────┼─────────────┤
+// │ Ownership   │ Borrow/Own  │ Single      │ Single      │ Shared      │ Shared      │
+// │ Mutability  │ On write    │ Get/set     │ Set once    │ Set once    │ Init once   │
+// │ Thread Safe │ If T is     │ No          │ No          │ Yes         │ Yes         │
+// │ Overhead    │ Enum tag    │ None        │ Init check  │ Atomic check│ Atomic check│
+// │ Use Case    │ Maybe-modify│ Copy counter│ Lazy field  │ Global init │ Lazy static │
+// └─────────────┴─────────────┴─────────────┴─────────────┴─────────────┴─────────────┘
+// 
 // COMMON COMBINATIONS:
 // • Rc<RefCell<T>>: Shared mutable data (single-threaded)
 // • Arc<Mutex<T>>: Shared mutable data (multi-threaded)
 // • Box<dyn Trait>: Trait objects with single ownership
 // • Rc<dyn Trait>: Trait objects with shared ownership
 
-// ===== 7. DEMONSTRATION FUNCTION =====
+// ===== 12. DEMONSTRATION FUNCTION =====
+//
+// smart_pointers_report() runs every demonstration into one Report (see
+// report.rs); demonstrate_smart_pointers() prints it.
 
-pub fn demonstrate_smart_pointers() {
-    println!("🦀 RUST SMART POINTERS DEEP STUDY 🦀\n");
+pub fn smart_pointers_report() -> Report {
+    let mut report = Report::new("🦀 RUST SMART POINTERS DEEP STUDY 🦀");
     
     // ===== BOX<T> DEMONSTRATIONS =====
-    println!("1️⃣ BOX<T> - HEAP ALLOCATION & RECURSIVE STRUCTURES:");
+    let section = report.section("1️⃣ BOX<T> - HEAP ALLOCATION & RECURSIVE STRUCTURES:");
     
     // Simple Box usage
     let boxed_int = Box::new(42);
-    println!("Boxed integer: {}", boxed_int);
+    section.example("Box::new(42)", boxed_int);
     
     // Recursive list
     let list = List::cons(1, List::cons(2, List::cons(3, List::new())));
-    println!("Recursive list: {:?}", list.to_vec());
-    println!("List length: {}", list.len());
+    section
+        .example("list.to_vec()", format!("{:?}", list.to_vec()))
+        .example("list.len()", list.len());
     
     // Binary tree
     let mut tree = BinaryTree::new(5);
@@ -478,27 +1134,30 @@ pub fn demonstrate_smart_pointers() {
     tree.insert(7);
     tree.insert(1);
     tree.insert(9);
-    println!("Tree contains 3: {}", tree.contains(3));
-    println!("Tree contains 6: {}", tree.contains(6));
+    section
+        .example("tree.contains(3)", tree.contains(3))
+        .example("tree.contains(6)", tree.contains(6));
     
     // ===== RC<T> DEMONSTRATIONS =====
-    println!("\n2️⃣ RC<T> - REFERENCE COUNTING:");
+    let section = report.section("2️⃣ RC<T> - REFERENCE COUNTING:");
     
     let node1 = Node::new(1);
+    // A second, unshared node: its count stays at 1
+    #[allow(unused_variables)]
     let node2 = Node::new(2);
     
-    println!("Node1 reference count: {}", Rc::strong_count(&node1));
+    section.example("Rc::strong_count(&node1)", Rc::strong_count(&node1));
     
     // Clone creates new reference, doesn't copy data
     let node1_clone = Rc::clone(&node1);
-    println!("Node1 reference count after clone: {}", Rc::strong_count(&node1));
+    section.example("after Rc::clone(&node1)", Rc::strong_count(&node1));
     
     // Dropping clone decreases reference count
     drop(node1_clone);
-    println!("Node1 reference count after drop: {}", Rc::strong_count(&node1));
+    section.example("after drop(node1_clone)", Rc::strong_count(&node1));
     
     // ===== REFCELL<T> DEMONSTRATIONS =====
-    println!("\n3️⃣ REFCELL<T> - INTERIOR MUTABILITY:");
+    let section = report.section("3️⃣ REFCELL<T> - INTERIOR MUTABILITY:");
     
     // Mutable node example
     let root = MutableNode::new(1);
@@ -508,16 +1167,17 @@ pub fn demonstrate_smart_pointers() {
     MutableNode::add_child(&root, child1);
     MutableNode::add_child(&root, child2);
     
-    println!("Root value: {}", root.get_value());
-    println!("Root children count: {}", root.get_children_count());
-    println!("Children values: {:?}", root.get_children_values());
+    section
+        .example("root.get_value()", root.get_value())
+        .example("root.get_children_count()", root.get_children_count())
+        .example("root.get_children_values()", format!("{:?}", root.get_children_values()));
     
     // Modify value through RefCell
     root.set_value(10);
-    println!("Root value after change: {}", root.get_value());
+    section.example("after root.set_value(10)", root.get_value());
     
     // ===== RC<REFCELL<T>> TREE DEMONSTRATIONS =====
-    println!("\n4️⃣ RC<REFCELL<T>> - SHARED MUTABLE TREE:");
+    let section = report.section("4️⃣ RC<REFCELL<T>> - SHARED MUTABLE TREE:");
     
     let mut tree = Tree::new();
     tree.set_root("root".to_string());
@@ -531,45 +1191,48 @@ pub fn demonstrate_smart_pointers() {
         root.add_child(child2.clone());
         child1.add_child(grandchild);
         
-        println!("Tree structure:");
-        let traversal = root.traverse(0);
-        for (value, depth) in traversal {
-            println!("{}├─ {}", "  ".repeat(depth), value);
-        }
+        let draw = |traversal: Vec<(String, usize)>| {
+            let lines: Vec<String> = traversal.into_iter().map(|(value, depth)| format!("{}├─ {}", "  ".repeat(depth), value)).collect();
+            lines.join("\n")
+        };
+        section.example("Tree structure", draw(root.traverse(0)));
         
         // Modify tree nodes
         child1.set_value("modified_child1".to_string());
-        println!("After modification:");
-        let traversal = root.traverse(0);
-        for (value, depth) in traversal {
-            println!("{}├─ {}", "  ".repeat(depth), value);
-        }
+        section.example("After modification", draw(root.traverse(0)));
     }
     
     // ===== ARC<T> DEMONSTRATIONS =====
-    println!("\n5️⃣ ARC<T> - THREAD-SAFE SHARING:");
+    let section = report.section("5️⃣ ARC<T> - THREAD-SAFE SHARING:");
     
     let counter = SharedCounter::new();
     let mut handles = vec![];
+    // The threads send their lines here, so they arrive in the order they ran
+    let (lines, received) = std::sync::mpsc::channel();
     
     // Spawn threads that share the counter
     for i in 0..3 {
         let counter_clone = counter.clone_handle();
+        let lines = lines.clone();
         let handle = thread::spawn(move || {
             for j in 0..5 {
                 counter_clone.increment();
-                println!("Thread {}: increment {}, count: {}", i, j + 1, counter_clone.get_count());
+                let _ = lines.send(format!("Thread {}: increment {}, count: {}", i, j + 1, counter_clone.get_count()));
             }
         });
         handles.push(handle);
     }
+    drop(lines);
     
     // Wait for all threads to complete
     for handle in handles {
         handle.join().unwrap();
     }
+    for line in received {
+        section.text(line);
+    }
     
-    println!("Final counter value: {}", counter.get_count());
+    section.example("counter.get_count()", counter.get_count());
     
     // Shared data example
     let shared_data = SharedData::new();
@@ -589,46 +1252,607 @@ pub fn demonstrate_smart_pointers() {
         handle.join().unwrap();
     }
     
-    println!("Shared data values: {:?}", shared_data.get_values());
-    println!("Sum: {}", shared_data.get_sum());
+    section
+        .example("shared_data.get_values()", format!("{:?}", shared_data.get_values()))
+        .example("shared_data.get_sum()", shared_data.get_sum());
     
     // ===== WEAK<T> DEMONSTRATIONS =====
-    println!("\n6️⃣ WEAK<T> - BREAKING CYCLES:");
+    let section = report.section("6️⃣ WEAK<T> - BREAKING CYCLES:");
     
     let parent = Parent::new();
     let child1 = Parent::add_child(&parent, "Alice".to_string());
+    // Only counted through the parent
+    #[allow(unused_variables)]
     let child2 = Parent::add_child(&parent, "Bob".to_string());
     
-    println!("Parent has {} children", parent.get_children_count());
-    println!("Child1 name: {}", child1.get_name());
-    println!("Child1 has parent: {}", child1.has_parent());
-    println!("Child1 siblings count: {:?}", child1.get_siblings_count());
+    section
+        .example("parent.get_children_count()", parent.get_children_count())
+        .example("child1.get_name()", child1.get_name())
+        .example("child1.has_parent()", child1.has_parent())
+        .example("child1.get_siblings_count()", format!("{:?}", child1.get_siblings_count()));
+
+    let tracks = ["intro", "verse", "outro"];
+    let leaky = NodeGraph::new();
+    let head = leaky_playlist(&leaky, &tracks);
+    section.text(format!("Leaky playlist, head held: leaks = {:?}", leaky.find_leaks()));
+    drop(head);
+    section
+        .text(format!("Leaky playlist, head dropped: {} dropped, {} alive, leaks = {:?}", leaky.dropped(), leaky.alive(), leaky.find_leaks()))
+        .example("leaky.collect_cycles()", format!("{} nodes freed", leaky.collect_cycles()));
+
+    let fixed = NodeGraph::new();
+    let head = playlist(&fixed, &tracks);
+    if let Some(second) = head.as_ref().and_then(|head| head.links.borrow().first().cloned()) {
+        section.text(format!("Weak playlist: {} links to {:?}", second.name(), second.neighbours()));
+    }
+    drop(head);
+    section.text(format!("Weak playlist, head dropped: {} dropped, {} alive, leaks = {:?}", fixed.dropped(), fixed.alive(), fixed.find_leaks()));
+    
+    // ===== COW<T> DEMONSTRATIONS =====
+    let section = report.section("7️⃣ COW<T> - CLONE ON WRITE:");
+    
+    for input in ["plain text", r#"tab\tseparated \"quoted\""#] {
+        let parsed = unescape(input);
+        let kind = match parsed {
+            Cow::Borrowed(_) => "borrowed, no allocation",
+            Cow::Owned(_) => "owned, allocated",
+        };
+        section.example(format!("unescape({:?})", input), format!("{:?} ({})", parsed, kind));
+    }
+    section
+        .example("with_extension(\"notes.txt\") is borrowed", matches!(with_extension("notes.txt", ".txt"), Cow::Borrowed(_)))
+        .example("with_extension(\"notes\")", with_extension("notes", ".txt"));
+    
+    // ===== CELL<T> VS REFCELL<T> DEMONSTRATIONS =====
+    let section = report.section("8️⃣ CELL<T> VS REFCELL<T>:");
+    
+    let stats = RequestStats::new(); // not mut - both fields use interior mutability
+    stats.record("/home");
+    stats.record("/about");
+    section
+        .example("stats.hits() (Cell<u32>)", stats.hits())
+        .example("stats.last_path() (RefCell<String>)", stats.last_path())
+        .example("stats.reset()", stats.reset())
+        .example("stats.hits() after the reset", stats.hits());
+    
+    // ===== LAZY INITIALIZATION DEMONSTRATIONS =====
+    let section = report.section("9️⃣ ONCECELL / ONCELOCK / LAZYLOCK - LAZY INITIALIZATION:");
+    
+    let config = LazyConfig::new("host = localhost\nport = 8080");
+    section.text(format!("Parsed before first read: {} time(s)", config.parse_count()));
+    section.text(format!("host = {:?}, port = {:?}", config.get("host"), config.get("port")));
+    section.text(format!("Parsed after two reads: {} time(s)", config.parse_count()));
+    
+    let handles: Vec<_> = (0..4).map(|_| thread::spawn(|| squares_table()[12])).collect();
+    let results: Vec<u64> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+    section
+        .example("4 threads read squares_table()[12]", format!("{:?}", results))
+        .text(format!("OnceLock initializer ran {} time(s)", squares_table_initializations()));
+    
+    section
+        .example("is_keyword(\"impl\")", is_keyword("impl"))
+        .example("is_keyword(\"banana\")", is_keyword("banana"));
+    
+    // ===== MYRC<T> DEMONSTRATIONS =====
+    let section = report.section("🔟 MYRC<T> - RC<T> FROM SCRATCH:");
+    
+    let first = MyRc::new(String::from("shared"));
+    let second = MyRc::clone(&first);
+    let weak = MyRc::downgrade(&first);
+    section
+        .example("*second", format!("{} (len {})", *second, second.len()))
+        .example("MyRc::ptr_eq(&first, &second)", MyRc::ptr_eq(&first, &second))
+        .text(format!("strong = {}, weak = {}", MyRc::strong_count(&first), MyRc::weak_count(&first)));
+    
+    drop(first);
+    section.text(format!("After dropping one MyRc: strong = {}, upgrade works: {}", weak.strong_count(), weak.upgrade().is_some()));
+    drop(second);
+    section.text(format!("After dropping the last MyRc: strong = {}, upgrade works: {}", weak.strong_count(), weak.upgrade().is_some()));
+    
+    // ===== TRACKEDBOX<T> DEMONSTRATIONS =====
+    let section = report.section("1️⃣1️⃣ TRACKEDBOX<T> - CUSTOM SMART POINTER WITH DEREF/DROP:");
+    
+    let tracker = AllocationTracker::new();
+    // TrackedBox logs each allocation and free; captured, they go in the
+    // section instead of the terminal
+    let format = mini_logger::format();
+    let mut boxes = None;
+    for record in mini_logger::capture(|| boxes = Some((tracker.track("greeting", String::from("hello")), tracker.track("numbers", vec![1, 2, 3])))) {
+        section.text(format.render(&record, false));
+    }
+    let (mut greeting, numbers) = boxes.expect("capture ran the closure");
+    greeting.push_str(", world"); // DerefMut
+    section
+        .example("shout(&greeting) via deref coercion", shout(&greeting))
+        .example("tracker.live() while both are held", tracker.live());
+    // A tuple drops its fields in order: numbers first, then greeting
+    for record in mini_logger::capture(|| drop((numbers, greeting))) {
+        section.text(format.render(&record, false));
+    }
+    section
+        .text(format!("Live allocations after the drop: {} (of {} total)", tracker.live(), tracker.total()))
+        .example("tracker.events()", format!("{:?}", tracker.events()));
     
     // ===== SUMMARY =====
-    println!("\n🎯 SMART POINTER CONCEPTS SUMMARY:");
-    println!("✅ Box<T>: Single ownership, heap allocation");
-    println!("✅ Rc<T>: Multiple ownership, reference counting");
-    println!("✅ RefCell<T>: Interior mutability, runtime borrow checking");
-    println!("✅ Arc<T>: Thread-safe multiple ownership");
-    println!("✅ Weak<T>: Non-owning references, break cycles");
-    
-    println!("\n📊 USAGE PATTERNS:");
-    println!("• Box<T>: Recursive types, large data, trait objects");
-    println!("• Rc<RefCell<T>>: Shared mutable data (single-threaded)");
-    println!("• Arc<Mutex<T>>: Shared mutable data (multi-threaded)");
-    println!("• Weak<T>: Parent-child relationships, observer patterns");
-    
-    println!("\n🚫 COMMON PITFALLS:");
-    println!("• RefCell runtime panics if borrowing rules violated");
-    println!("• Reference cycles with Rc can cause memory leaks");
-    println!("• Arc has performance overhead due to atomic operations");
-    println!("• Weak references can become invalid");
-    
-    println!("\n💡 BEST PRACTICES:");
-    println!("• Use owned types when possible");
-    println!("• Prefer Box<T> for single ownership");
-    println!("• Use Rc<T> for shared immutable data");
-    println!("• Combine with RefCell for shared mutable data");
-    println!("• Use Arc<T> only when threads involved");
-    println!("• Use Weak<T> to break cycles");
-}
\ No newline at end of file
+    report
+        .section("🎯 SMART POINTER CONCEPTS SUMMARY:")
+        .check("Box<T>: Single ownership, heap allocation")
+        .check("Rc<T>: Multiple ownership, reference counting")
+        .check("RefCell<T>: Interior mutability, runtime borrow checking")
+        .check("Arc<T>: Thread-safe multiple ownership")
+        .check("Weak<T>: Non-owning references, break cycles")
+        .check("Cow<T>: Borrow by default, clone only when modifying")
+        .check("Cell<T>: Interior mutability for Copy values, no runtime borrows")
+        .check("OnceCell/OnceLock/LazyLock: Initialize once, read forever")
+        .check("MyRc<T>: Rc is just a pointer to counters + value in one allocation")
+        .check("Deref + Drop: All it takes to write your own smart pointer");
+    
+    report
+        .section("📊 USAGE PATTERNS:")
+        .bullet("Box<T>: Recursive types, large data, trait objects")
+        .bullet("Rc<RefCell<T>>: Shared mutable data (single-threaded)")
+        .bullet("Arc<Mutex<T>>: Shared mutable data (multi-threaded)")
+        .bullet("Weak<T>: Parent-child relationships, observer patterns")
+        .bullet("Cow<str>: Parsers and normalizers that usually change nothing")
+        .bullet("LazyLock<T>: Statics that need a heap allocation (maps, regexes)")
+        .bullet("Deref/Drop wrappers: Guards, handles and instrumented resources (RAII)");
+    
+    report
+        .section("🚫 COMMON PITFALLS:")
+        .bullet("RefCell runtime panics if borrowing rules violated")
+        .bullet("Reference cycles with Rc can cause memory leaks")
+        .bullet("Arc has performance overhead due to atomic operations")
+        .bullet("Weak references can become invalid")
+        .bullet("Hand-written unsafe pointers need Miri, not just passing tests");
+    
+    report
+        .section("💡 BEST PRACTICES:")
+        .bullet("Use owned types when possible")
+        .bullet("Prefer Box<T> for single ownership")
+        .bullet("Use Rc<T> for shared immutable data")
+        .bullet("Combine with RefCell for shared mutable data")
+        .bullet("Use Arc<T> only when threads involved")
+        .bullet("Use Weak<T> to break cycles");
+    
+    report
+}
+
+pub fn demonstrate_smart_pointers() {
+    print!("{}", smart_pointers_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct SmartPointersModule;
+
+impl LearningModule for SmartPointersModule {
+    fn number(&self) -> u8 {
+        3
+    }
+
+    fn name(&self) -> &'static str {
+        "smart_pointers"
+    }
+
+    fn title(&self) -> &'static str {
+        "SMART POINTERS DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Smart Pointers"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["traits"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_smart_pointers() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { smart_pointers_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&SmartPointersModule) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_records_counts_and_thread_output() {
+        let report = smart_pointers_report();
+        let rc: Vec<_> = report.find("RC<T> - REFERENCE COUNTING").unwrap().outputs().collect();
+        assert_eq!(rc, ["1", "2", "1"]);
+
+        // Every increment made it back from its thread
+        let arc = report.find("ARC<T>").unwrap();
+        assert_eq!(arc.entries.iter().filter(|entry| entry.to_string().starts_with("Thread ")).count(), 15);
+        assert_eq!(arc.output("counter.get_count()"), Some("15"));
+
+        let tracked = report.find("TRACKEDBOX<T>").unwrap();
+        assert_eq!(tracked.output("tracker.live() while both are held"), Some("2"));
+        assert_eq!(tracked.output("tracker.events()"), Some(r#"["alloc greeting", "alloc numbers", "drop numbers", "drop greeting"]"#));
+    }
+
+    #[test]
+    fn mutable_node_loses_its_parent_when_the_parent_drops() {
+        let root = MutableNode::new(1);
+        let child = MutableNode::new(2);
+        assert!(!child.has_parent());
+
+        MutableNode::add_child(&root, Rc::clone(&child));
+        assert!(child.has_parent());
+        drop(root);
+        assert!(!child.has_parent());
+    }
+
+    #[test]
+    fn unescape_borrows_unless_it_must_allocate() {
+        assert!(matches!(unescape("nothing to do"), Cow::Borrowed("nothing to do")));
+
+        let unescaped = unescape(r#"a\tb \"c\" \\ end\"#);
+        assert!(matches!(unescaped, Cow::Owned(_)));
+        assert_eq!(unescaped, "a\tb \"c\" \\ end\\");
+    }
+
+    #[test]
+    fn with_extension_clones_only_when_writing() {
+        assert!(matches!(with_extension("a.rs", ".rs"), Cow::Borrowed("a.rs")));
+        assert_eq!(with_extension("a", ".rs"), "a.rs");
+    }
+
+    #[test]
+    fn request_stats_mutate_through_shared_references() {
+        let stats = RequestStats::new();
+        stats.record("/a");
+        stats.record("/b");
+        assert_eq!((stats.hits(), stats.last_path().as_str()), (2, "/b"));
+        assert_eq!(stats.reset(), 2);
+        assert_eq!(stats.hits(), 0);
+    }
+
+    #[test]
+    fn lazy_config_parses_once_on_first_read() {
+        let config = LazyConfig::new("a = 1\nb=2\nnot a pair");
+        assert_eq!(config.parse_count(), 0);
+        assert_eq!(config.get("a"), Some("1"));
+        assert_eq!(config.get("b"), Some("2"));
+        assert_eq!(config.get("not a pair"), None);
+        assert_eq!(config.parse_count(), 1);
+    }
+
+    #[test]
+    fn once_lock_initializes_once_across_threads() {
+        let handles: Vec<_> = (0..8).map(|_| thread::spawn(|| squares_table().len())).collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 1000);
+        }
+        assert_eq!(squares_table()[31], 961);
+        assert_eq!(squares_table_initializations(), 1);
+    }
+
+    #[test]
+    fn lazy_lock_static_is_built_on_first_use() {
+        assert!(is_keyword("trait"));
+        assert!(!is_keyword("Trait"));
+    }
+
+    // Counts how many times it has been dropped
+    struct DropCounter<'a>(&'a Cell<u32>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn my_rc_clones_share_one_value() {
+        let a = MyRc::new(vec![1, 2, 3]);
+        let b = a.clone();
+        assert!(MyRc::ptr_eq(&a, &b));
+        assert_eq!(b.len(), 3);
+        assert_eq!(MyRc::strong_count(&a), 2);
+        drop(b);
+        assert_eq!(MyRc::strong_count(&a), 1);
+    }
+
+    #[test]
+    fn my_rc_drops_the_value_once_with_the_last_strong() {
+        let drops = Cell::new(0);
+        let a = MyRc::new(DropCounter(&drops));
+        let b = a.clone();
+        drop(a);
+        assert_eq!(drops.get(), 0);
+        drop(b);
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn my_weak_upgrades_only_while_the_value_lives() {
+        let drops = Cell::new(0);
+        let strong = MyRc::new(DropCounter(&drops));
+        let weak = MyRc::downgrade(&strong);
+        let weak2 = weak.clone();
+        assert_eq!(MyRc::weak_count(&strong), 2);
+
+        let upgraded = weak.upgrade().unwrap();
+        assert_eq!(MyRc::strong_count(&strong), 2);
+        drop((strong, upgraded));
+
+        // The value is gone but the allocation lives on for the weak pointers
+        assert_eq!(drops.get(), 1);
+        assert_eq!(weak.strong_count(), 0);
+        assert!(weak2.upgrade().is_none());
+    }
+
+    #[test]
+    fn my_rc_survives_a_value_that_holds_a_weak_pointer_to_itself() {
+        struct SelfRef {
+            me: RefCell<Option<MyWeak<SelfRef>>>,
+        }
+
+        let node = MyRc::new(SelfRef { me: RefCell::new(None) });
+        *node.me.borrow_mut() = Some(MyRc::downgrade(&node));
+        assert!(node.me.borrow().as_ref().unwrap().upgrade().is_some());
+        // Dropping the value drops the only MyWeak; the allocation must
+        // outlive it until MyRc::drop finishes
+        drop(node);
+    }
+
+    #[test]
+    fn tracked_box_derefs_and_coerces() {
+        let tracker = AllocationTracker::new();
+        let mut text = tracker.track("text", String::from("abc"));
+        text.push('d');
+        assert_eq!(shout(&text), "ABCD");
+        assert_eq!(*text, "abcd");
+        assert_eq!(text.label(), "text");
+    }
+
+    #[test]
+    fn locals_drop_in_reverse_declaration_order() {
+        let tracker = AllocationTracker::new();
+        {
+            let _a = tracker.track("a", 1);
+            let _b = tracker.track("b", 2);
+            let c = tracker.track("c", 3);
+            drop(c); // ends early
+            assert_eq!(tracker.live(), 2);
+        }
+        assert_eq!(tracker.events(), ["alloc a", "alloc b", "alloc c", "drop c", "drop b", "drop a"]);
+        assert_eq!((tracker.live(), tracker.total()), (0, 3));
+    }
+
+    #[test]
+    fn fields_and_elements_drop_first_to_last() {
+        struct Pair {
+            _first: TrackedBox<u8>,
+            _second: TrackedBox<u8>,
+        }
+
+        let tracker = AllocationTracker::new();
+        let pair = Pair { _first: tracker.track("first", 1), _second: tracker.track("second", 2) };
+        let list = vec![tracker.track("x", 0), tracker.track("y", 0)];
+        drop(pair);
+        drop(list);
+        assert_eq!(tracker.events()[4..], ["drop first", "drop second", "drop x", "drop y"]);
+    }
+
+    #[test]
+    fn moving_into_a_function_drops_at_its_end() {
+        fn consume(boxed: TrackedBox<i32>, tracker: &AllocationTracker) {
+            assert_eq!(*boxed, 7);
+            assert_eq!(tracker.live(), 1);
+        }
+
+        let tracker = AllocationTracker::new();
+        let boxed = tracker.track("moved", 7);
+        consume(boxed, &tracker);
+        assert_eq!(tracker.live(), 0);
+        assert_eq!(tracker.events().last().map(String::as_str), Some("drop moved"));
+    }
+
+    // ===== BOX: RECURSIVE TYPES =====
+
+    #[test]
+    fn list_grows_at_the_front() {
+        let list = List::cons(1, List::cons(2, List::cons(3, List::new())));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.to_vec(), [1, 2, 3]);
+        assert!(!list.is_empty());
+
+        assert!(List::default().is_empty());
+        assert_eq!(List::new().to_vec(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn binary_tree_finds_only_inserted_values() {
+        let mut tree = BinaryTree::new(50);
+        for value in [30, 70, 20, 40, 60, 80, 30] {
+            tree.insert(value);
+        }
+        for value in [50, 20, 40, 80, 30] {
+            assert!(tree.contains(value), "{} was inserted", value);
+        }
+        for value in [0, 35, 65, 100] {
+            assert!(!tree.contains(value), "{} was never inserted", value);
+        }
+        // Duplicates go right: the second 30 lands left of 40, right of the first 30
+        let forty = tree.left.as_ref().unwrap().right.as_ref().unwrap();
+        assert_eq!(forty.value, 40);
+        assert_eq!(forty.left.as_ref().unwrap().value, 30);
+    }
+
+    // ===== RC + REFCELL + WEAK =====
+
+    #[test]
+    fn mutable_node_shares_children_and_mutates_through_rc() {
+        let parent = MutableNode::new(1);
+        let child = MutableNode::new(2);
+        MutableNode::add_child(&parent, Rc::clone(&child));
+
+        child.set_value(20);
+        assert_eq!(parent.get_children_values(), [20]);
+        assert_eq!(parent.get_children_count(), 1);
+        assert!(child.has_parent());
+        assert!(!parent.has_parent());
+
+        // The parent's Vec and `child` own it; the child's Weak doesn't count
+        assert_eq!(Rc::strong_count(&child), 2);
+        assert_eq!((Rc::strong_count(&parent), Rc::weak_count(&parent)), (1, 1));
+    }
+
+    #[test]
+    fn mutable_node_weak_parent_does_not_upgrade_after_drop() {
+        let parent = MutableNode::new(1);
+        let child = MutableNode::new(2);
+        MutableNode::add_child(&parent, Rc::clone(&child));
+
+        let weak = Rc::downgrade(&parent);
+        drop(parent);
+        assert!(weak.upgrade().is_none());
+        assert!(!child.has_parent(), "a dangling Weak is not a parent");
+        assert_eq!(Rc::strong_count(&child), 1, "the parent's Vec released it");
+    }
+
+    #[test]
+    fn child_loses_its_parent_and_siblings_when_the_parent_drops() {
+        let parent = Parent::new();
+        let alice = Parent::add_child(&parent, "alice".to_string());
+        let _bob = Parent::add_child(&parent, "bob".to_string());
+        assert_eq!(alice.get_name(), "alice");
+        assert_eq!(parent.get_children_count(), 2);
+        assert_eq!(alice.get_siblings_count(), Some(2));
+
+        drop(parent);
+        assert!(!alice.has_parent());
+        assert_eq!(alice.get_siblings_count(), None);
+    }
+
+    #[test]
+    fn strong_back_links_leak_and_the_detector_finds_them() {
+        let graph = NodeGraph::new();
+        let head = leaky_playlist(&graph, &["a", "b", "c"]).unwrap();
+        assert!(graph.find_leaks().is_empty(), "everything is reachable from head");
+
+        drop(head);
+        assert_eq!(graph.dropped(), 0, "no Drop ran: the cycles keep every node alive");
+        assert_eq!(graph.alive(), 3);
+        assert_eq!(graph.find_leaks(), ["a", "b", "c"]);
+
+        assert_eq!(graph.collect_cycles(), 3);
+        assert_eq!((graph.dropped(), graph.alive()), (3, 0));
+    }
+
+    #[test]
+    fn detector_keeps_nodes_reachable_from_a_live_handle() {
+        let graph = NodeGraph::new();
+        let head = leaky_playlist(&graph, &["a", "b"]).unwrap();
+        let orphan = graph.node("orphan");
+        let ring = graph.node("ring");
+        GraphNode::link(&ring, &orphan);
+        GraphNode::link(&orphan, &ring);
+        drop((orphan, ring));
+
+        assert_eq!(graph.find_leaks(), ["orphan", "ring"]);
+        assert_eq!(graph.collect_cycles(), 2);
+        assert_eq!(head.neighbours(), ["b"], "the live playlist was left alone");
+        assert_eq!(graph.alive(), 2);
+    }
+
+    #[test]
+    fn weak_back_links_free_the_whole_playlist() {
+        let graph = NodeGraph::new();
+        let head = playlist(&graph, &["a", "b", "c"]).unwrap();
+        let second = Rc::clone(&head.links.borrow()[0]);
+        assert_eq!(second.neighbours(), ["c", "a"], "next is owned, prev is reachable");
+
+        drop(head);
+        assert_eq!(graph.dropped(), 1, "only a had no other owner");
+        assert_eq!(second.neighbours(), ["c"], "the Weak to a no longer upgrades");
+
+        drop(second);
+        assert_eq!((graph.dropped(), graph.alive()), (3, 0));
+        assert!(graph.find_leaks().is_empty());
+    }
+
+    #[test]
+    fn tree_traversal_is_depth_first_with_depths() {
+        let mut tree = Tree::new();
+        assert!(tree.get_root().is_none());
+        tree.set_root("root".to_string());
+        let root = tree.get_root().unwrap();
+
+        let a = TreeNode::new("a".to_string());
+        a.add_child(TreeNode::new("a1".to_string()));
+        root.add_child(a);
+        root.add_child(TreeNode::new("b".to_string()));
+        root.set_value("ROOT".to_string());
+
+        let visited: Vec<_> = root.traverse(0).into_iter().map(|(value, depth)| format!("{}@{}", value, depth)).collect();
+        assert_eq!(visited, ["ROOT@0", "a@1", "a1@2", "b@1"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "already mutably borrowed")]
+    fn reading_while_mutably_borrowed_panics() {
+        let node = MutableNode::new(1);
+        let _writer = node.value.borrow_mut();
+        node.get_value(); // borrow() while a borrow_mut() is alive
+    }
+
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn mutating_while_borrowed_panics() {
+        let node = MutableNode::new(1);
+        let _children = node.children.borrow();
+        MutableNode::add_child(&node, MutableNode::new(2)); // borrow_mut() on children
+    }
+
+    #[test]
+    fn try_borrow_reports_conflicts_without_panicking() {
+        let node = MutableNode::new(1);
+        {
+            let _writer = node.value.borrow_mut();
+            assert!(node.value.try_borrow().is_err());
+            assert!(node.value.try_borrow_mut().is_err());
+        }
+        let _reader = node.value.borrow();
+        assert!(node.value.try_borrow().is_ok(), "any number of readers");
+        assert!(node.value.try_borrow_mut().is_err());
+    }
+
+    // ===== ARC + MUTEX =====
+
+    #[test]
+    fn shared_handles_see_every_thread_write() {
+        let counter = SharedCounter::new();
+        let data = SharedData::default();
+        let handles: Vec<_> = (1..=4)
+            .map(|i| {
+                let (counter, data) = (counter.clone_handle(), data.clone_handle());
+                thread::spawn(move || {
+                    counter.increment();
+                    data.add_value(i);
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(counter.get_count(), 4);
+        let mut values = data.get_values();
+        values.sort();
+        assert_eq!(values, [1, 2, 3, 4]);
+        assert_eq!(data.get_sum(), 10);
+    }
+}
@@ -0,0 +1,498 @@
+// ===== STRINGS DEEP STUDY =====
+//
+// RUST HAS SEVERAL STRING TYPES BECAUSE TEXT COMES FROM SEVERAL PLACES:
+//
+//   owned        borrowed     guarantees                 comes from
+//   String       &str         valid UTF-8                your program
+//   OsString     &OsStr       whatever the OS allows     env vars, file names, args
+//   PathBuf      &Path        an OsString with / logic   the file system
+//   CString      &CStr        no interior NUL, ends in 0 C libraries (FFI)
+//   Vec<u8>      &[u8]        nothing                    sockets, files, b"..."
+//
+// Each owned type derefs to its borrowed partner, the way String derefs to
+// str. Converting between them is where the checks happen: anything can
+// become bytes, but bytes only become a str after UTF-8 validation.
+
+use std::borrow::Cow;
+use std::ffi::{CStr, CString, NulError, OsStr, OsString};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::report::Report;
+
+// ===== 1. String VS &str =====
+//
+// UNDERSTANDING THE PAIR:
+// • String: a growable, heap-allocated UTF-8 buffer (ptr, len, capacity)
+// • &str: a borrowed view (ptr, len) into UTF-8 bytes owned by someone else -
+//   a String, a literal baked into the binary, a slice of either
+// • &String derefs to &str, so a function taking &str accepts both
+//
+// CHOOSING A PARAMETER TYPE:
+// • Only reading it?                  → &str
+// • Storing it in a struct?           → String (take impl Into<String> so
+//   callers with a String don't have to clone)
+// • Usually borrowed, sometimes built? → Cow<'_, str> (see smart_pointers.rs)
+
+// Reads only, so it borrows - works for literals, Strings and slices alike
+pub fn initials(name: &str) -> String {
+    name.split_whitespace().filter_map(|word| word.chars().next()).flat_map(char::to_uppercase).collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    text: String,
+}
+
+impl Label {
+    // A String moves in without a copy; a &str is copied once
+    pub fn new(text: impl Into<String>) -> Self {
+        Label { text: text.into() }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+}
+
+// ===== 2. UTF-8 AND INDEXING =====
+//
+// UNDERSTANDING UTF-8:
+// • A char (a Unicode scalar value) takes 1 to 4 bytes: 'a' is 1, 'é' is 2,
+//   '€' is 3, '🦀' is 4
+// • len() counts BYTES; chars().count() counts chars and is O(n)
+// • s[i] doesn't compile: "the i-th character" isn't an O(1) question
+// • s[a..b] takes BYTE offsets and panics unless both fall on char
+//   boundaries: "é"[0..1] panics with "byte index 1 is not a char boundary"
+// • s.get(a..b) returns None instead of panicking
+//
+// AND A char ISN'T WHAT A READER CALLS A CHARACTER:
+// • "é" may be one char (U+00E9) or two: 'e' + U+0301 COMBINING ACUTE
+// • Flags and family emoji are several chars joined together
+// • Those "user-perceived characters" are grapheme clusters; std doesn't
+//   segment them - the unicode-segmentation crate does
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextLengths {
+    pub bytes: usize,
+    pub chars: usize,
+}
+
+pub fn lengths(s: &str) -> TextLengths {
+    TextLengths { bytes: s.len(), chars: s.chars().count() }
+}
+
+// The first `max_chars` chars. char_indices gives the byte offset where
+// each char starts, so the cut is always on a boundary
+pub fn truncate_chars(s: &str, max_chars: usize) -> &str {
+    match s.char_indices().nth(max_chars) {
+        Some((end, _)) => &s[..end],
+        None => s,
+    }
+}
+
+// The longest prefix of at most `max_bytes` bytes that is still valid UTF-8
+// (what a fixed-size database column or protocol field needs)
+pub fn truncate_bytes(s: &str, max_bytes: usize) -> &str {
+    if max_bytes >= s.len() {
+        return s;
+    }
+    // A boundary is at most 3 bytes back
+    let end = (0..=max_bytes).rev().find(|&i| s.is_char_boundary(i)).unwrap_or(0);
+    &s[..end]
+}
+
+// Byte-offset slicing that can't panic
+pub fn byte_slice(s: &str, start: usize, end: usize) -> Option<&str> {
+    s.get(start..end)
+}
+
+// Reverses chars - which detaches combining marks from their letter
+pub fn reverse_chars(s: &str) -> String {
+    s.chars().rev().collect()
+}
+
+// ===== 3. OsString AND PathBuf =====
+//
+// UNDERSTANDING OS STRINGS:
+// • Unix file names are any bytes except '/' and NUL; Windows names are
+//   UTF-16 that may contain unpaired surrogates. Neither is always UTF-8
+// • OsString holds them losslessly; to_str() returns None when the name
+//   isn't UTF-8, to_string_lossy() swaps the bad bytes for U+FFFD �
+// • PathBuf/Path wrap OsString/OsStr and add components, extensions,
+//   join() and parent() - never build paths with format!("{}/{}")
+// • Display a path with path.display(); it is lossy for the same reason
+
+// Joins parts onto `base`, ignoring empty parts and refusing ones that
+// would escape it (absolute paths replace the base in Path::join)
+pub fn join_under(base: &Path, parts: &[&str]) -> Option<PathBuf> {
+    let mut path = base.to_path_buf();
+    for part in parts.iter().filter(|part| !part.is_empty()) {
+        let part = Path::new(part);
+        if part.is_absolute() || part.components().any(|c| matches!(c, std::path::Component::ParentDir)) {
+            return None;
+        }
+        path.push(part);
+    }
+    Some(path)
+}
+
+// Borrowed when the name is UTF-8, owned (with �) when it had to be repaired
+pub fn file_name_lossy(path: &Path) -> Option<Cow<'_, str>> {
+    path.file_name().map(OsStr::to_string_lossy)
+}
+
+// A file name no &str can hold: "caf" followed by Latin-1 é (0xE9)
+#[cfg(unix)]
+pub fn non_utf8_name() -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+    OsString::from_vec(b"caf\xE9.txt".to_vec())
+}
+
+// ===== 4. CString AND CStr =====
+//
+// UNDERSTANDING C STRINGS:
+// • C marks the end of a string with a 0 byte instead of storing a length
+// • CString::new() appends the 0 and fails with NulError if the text
+//   already contains one (C would see a shorter string)
+// • c"literal" (Rust 1.77+) is a &'static CStr checked at compile time
+// • as_ptr() lends a *const c_char to C; the CString must outlive the call.
+//   CString::new(s).unwrap().as_ptr() dangles as soon as the line ends
+// • CStr::from_ptr() goes the other way, for strings C hands back
+
+pub fn to_c_string(s: &str) -> Result<CString, NulError> {
+    CString::new(s)
+}
+
+// Bytes from C are not promised to be UTF-8
+pub fn from_c_bytes(bytes_with_nul: &[u8]) -> Option<&str> {
+    CStr::from_bytes_with_nul(bytes_with_nul).ok()?.to_str().ok()
+}
+
+// Calls the C library's strlen, which counts bytes up to the 0
+#[cfg(unix)]
+pub fn c_strlen(s: &CStr) -> usize {
+    unsafe extern "C" {
+        fn strlen(s: *const std::ffi::c_char) -> usize;
+    }
+    // SAFETY: a &CStr always points at a NUL-terminated buffer that lives
+    // for the whole call
+    unsafe { strlen(s.as_ptr()) }
+}
+
+// ===== 5. BYTE STRINGS =====
+//
+// UNDERSTANDING &[u8] TEXT:
+// • b"GET /" is a &[u8; 5]; b'A' is a u8 - for protocols that are ASCII
+//   by specification (HTTP, SMTP, many file headers)
+// • u8 and [u8] have ASCII helpers: is_ascii_digit, eq_ignore_ascii_case,
+//   to_ascii_uppercase, trim_ascii, escape_ascii for printing
+// • str::from_utf8 validates; on failure, Utf8Error::valid_up_to() says how
+//   much WAS valid and error_len() is None when the input just stopped
+//   mid-character - the rest may be in the next network read
+// • String::from_utf8_lossy repairs instead, borrowing when nothing broke
+
+// Splits a buffer into its valid UTF-8 prefix and the bytes of a character
+// cut off at the end. Invalid bytes in the middle are an error
+pub fn decode_prefix(bytes: &[u8]) -> Result<(&str, &[u8]), std::str::Utf8Error> {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => Ok((text, &[])),
+        Err(err) if err.error_len().is_none() => {
+            let (valid, rest) = bytes.split_at(err.valid_up_to());
+            Ok((std::str::from_utf8(valid).expect("validated up to here"), rest))
+        }
+        Err(err) => Err(err),
+    }
+}
+
+// "Content-Length: 42" → (b"content-length", b"42"), case-folded and trimmed
+pub fn parse_header(line: &[u8]) -> Option<(Vec<u8>, &[u8])> {
+    let colon = line.iter().position(|&b| b == b':')?;
+    let (name, value) = (line[..colon].trim_ascii(), line[colon + 1..].trim_ascii());
+    (!name.is_empty()).then(|| (name.to_ascii_lowercase(), value))
+}
+
+// ===== 6. BUILDING STRINGS EFFICIENTLY =====
+//
+// UNDERSTANDING THE COSTS:
+// • s1 + &s2 reuses s1's buffer; format!() always allocates a new String
+// • format!() inside a loop allocates once per iteration. write!(s, ...)
+//   formats straight into an existing String (it implements fmt::Write)
+// • push_str / push are the cheapest appends - no formatting machinery
+// • String::with_capacity(n) when the final size is known; like Vec, the
+//   buffer otherwise doubles as it grows
+// • [a, b, c].concat() and .join(sep) compute the size and allocate once
+
+// One allocation: the exact size is computed first
+pub fn join_exact(parts: &[&str], separator: &str) -> String {
+    let len = parts.iter().map(|part| part.len()).sum::<usize>() + separator.len() * parts.len().saturating_sub(1);
+    let mut joined = String::with_capacity(len);
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            joined.push_str(separator);
+        }
+        joined.push_str(part);
+    }
+    joined
+}
+
+// write! into one buffer instead of format! per row. Writing to a String
+// can't fail, so the fmt::Result is safe to ignore
+pub fn render_rows(rows: &[(&str, u32)]) -> String {
+    let width = rows.iter().map(|(name, _)| name.chars().count()).max().unwrap_or(0);
+    let mut out = String::new();
+    for (name, count) in rows {
+        let _ = writeln!(out, "{:<width$} {:>5}", name, count, width = width);
+    }
+    out
+}
+
+// How many times the buffer grew while appending `pieces` one at a time
+pub fn count_string_reallocations(pieces: &[&str], preallocate: bool) -> usize {
+    let total = pieces.iter().map(|piece| piece.len()).sum();
+    let mut s = if preallocate { String::with_capacity(total) } else { String::new() };
+    let mut reallocations = 0;
+    for piece in pieces {
+        let before = s.capacity();
+        s.push_str(piece);
+        reallocations += usize::from(s.capacity() != before);
+    }
+    reallocations
+}
+
+// ===== 7. DEMONSTRATION FUNCTION =====
+
+pub fn strings_report() -> Report {
+    let mut report = Report::new("🦀 RUST STRINGS DEEP STUDY 🦀");
+
+    // ===== String VS &str =====
+    let owned = String::from("ada lovelace");
+    report
+        .section("1️⃣ String VS &str:")
+        .example("initials(\"grace brewster hopper\") (a literal)", initials("grace brewster hopper"))
+        .example("initials(&owned) (a &String derefs to &str)", initials(&owned))
+        .example("initials(&owned[4..]) (a slice)", initials(&owned[4..]))
+        .example("Label::new(owned) moves, no copy", Label::new(owned).as_str());
+
+    // ===== UTF-8 =====
+    let utf8 = report.section("2️⃣ UTF-8 AND INDEXING:");
+    for s in ["a", "é", "€", "🦀", "naïve café"] {
+        let TextLengths { bytes, chars } = lengths(s);
+        utf8.example(format!("{:?}", s), format!("{} bytes, {} chars", bytes, chars));
+    }
+    let text = "naïve café";
+    utf8.example("byte_slice(text, 0, 3) (inside ï)", format!("{:?}", byte_slice(text, 0, 3)))
+        .example("byte_slice(text, 0, 4)", format!("{:?}", byte_slice(text, 0, 4)))
+        .example("truncate_chars(text, 3)", format!("{:?}", truncate_chars(text, 3)))
+        .example("truncate_bytes(text, 10) (would split é)", format!("{:?}", truncate_bytes(text, 10)));
+    utf8.text("text[0..3] would panic: byte index 3 is not a char boundary");
+    let composed = "e\u{301}"; // 'e' + combining acute: looks like é
+    utf8.example("reverse_chars(\"ae\\u{301}\")", format!("{:?} - the accent moved", reverse_chars(&format!("a{}", composed))))
+        .example("\"é\" == \"e\\u{301}\"", "é" == composed);
+
+    // ===== OS STRINGS AND PATHS =====
+    let paths = report.section("3️⃣ OsString AND PathBuf:");
+    let base = Path::new("/srv/data");
+    // An absolute part replaces everything before it - easy to miss when the
+    // part came from user input
+    let absolute: PathBuf = ["/", "etc"].iter().collect();
+    paths
+        .example("join_under(base, [\"2024\", \"logs\"])", format!("{:?}", join_under(base, &["2024", "logs"])))
+        .example("join_under(base, [\"..\", \"etc\"])", format!("{:?}", join_under(base, &["..", "etc"])))
+        .example("Path::new(\"/srv\").join(\"/etc\") replaces the base", Path::new("/srv").join(&absolute).display());
+    #[cfg(unix)]
+    {
+        let name = PathBuf::from(non_utf8_name());
+        paths
+            .example("non-UTF-8 name .to_str()", format!("{:?}", name.to_str()))
+            .example("file_name_lossy", format!("{:?}", file_name_lossy(&name)))
+            .example("extension() still works", format!("{:?}", name.extension()));
+    }
+    let os: OsString = OsString::from("plain.txt");
+    paths.example("OsString::from(\"plain.txt\").into_string()", format!("{:?}", os.into_string()));
+
+    // ===== C STRINGS =====
+    let c = report.section("4️⃣ CString AND CStr:");
+    match to_c_string("hello") {
+        Ok(c_string) => c.example("CString::new(\"hello\").as_bytes_with_nul()", format!("{:?}", c_string.as_bytes_with_nul())),
+        Err(err) => c.example("CString::new(\"hello\")", err),
+    };
+    c.example("CString::new(\"nul\\0inside\")", format!("{:?}", to_c_string("nul\0inside").map_err(|err| err.nul_position())))
+        .example("from_c_bytes(b\"from C\\0\")", format!("{:?}", from_c_bytes(b"from C\0")))
+        .example("from_c_bytes(b\"no terminator\")", format!("{:?}", from_c_bytes(b"no terminator")));
+    #[cfg(unix)]
+    c.example("strlen(c\"crab 🦀\") via FFI (bytes, not chars)", c_strlen(c"crab 🦀"));
+
+    // ===== BYTE STRINGS =====
+    let bytes = report.section("5️⃣ BYTE STRINGS:");
+    let crab = "🦀 ok".as_bytes();
+    let (text, rest) = decode_prefix(&crab[..2]).expect("only cut short");
+    bytes.example("decode_prefix(first 2 bytes of 🦀)", format!("({:?}, {:?}) - wait for more", text, rest));
+    let (text, rest) = decode_prefix(crab).expect("valid");
+    bytes.example("decode_prefix(all bytes)", format!("({:?}, {:?})", text, rest));
+    bytes
+        .example("decode_prefix(b\"ok\\xFFok\")", format!("{:?}", decode_prefix(b"ok\xFFok").map_err(|err| err.to_string())))
+        .example("String::from_utf8_lossy(b\"ok\\xFFok\")", String::from_utf8_lossy(b"ok\xFFok"));
+    if let Some((name, value)) = parse_header(b"Content-Length:  42 ") {
+        bytes.example("parse_header(b\"Content-Length:  42 \")", format!("({}, {})", name.escape_ascii(), value.escape_ascii()));
+    }
+
+    // ===== BUILDING =====
+    let building = report.section("6️⃣ BUILDING STRINGS EFFICIENTLY:");
+    let words = ["alpha", "beta", "gamma", "delta"];
+    let joined = join_exact(&words, ", ");
+    building.example("join_exact(words, \", \")", format!("{:?} (len {}, capacity {})", joined, joined.len(), joined.capacity()));
+    let pieces: Vec<&str> = std::iter::repeat_n("0123456789", 100).collect();
+    building
+        .example("push_str 100 × 10 bytes, String::new()", format!("{} reallocations", count_string_reallocations(&pieces, false)))
+        .example("push_str 100 × 10 bytes, with_capacity(1000)", format!("{} reallocations", count_string_reallocations(&pieces, true)))
+        .example("render_rows via writeln!", render_rows(&[("crab", 42), ("ferris", 7), ("λ", 1000)]).trim_end());
+
+    report
+        .section("🎯 STRINGS SUMMARY:")
+        .check("Take &str to read, store String, accept impl Into<String> to store cheaply")
+        .check("len() is bytes; slicing takes byte offsets on char boundaries - or use get()")
+        .check("A char is a code point, not what a reader calls a character")
+        .check("OsString and PathBuf hold names that may not be UTF-8; to_str() can fail")
+        .check("CString adds the NUL terminator C expects and rejects interior NULs")
+        .check("&[u8] for byte protocols; from_utf8's error says how much was valid")
+        .check("write!/push_str into one buffer instead of format! in a loop");
+
+    report
+}
+
+pub fn demonstrate_strings() {
+    print!("{}", strings_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct StringsModule;
+
+impl LearningModule for StringsModule {
+    fn number(&self) -> u8 {
+        21
+    }
+
+    fn name(&self) -> &'static str {
+        "strings"
+    }
+
+    fn title(&self) -> &'static str {
+        "STRINGS DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Strings, UTF-8 & OS/C Strings"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["lifetimes", "smart_pointers"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_strings() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { strings_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&StringsModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// STRING BEST PRACTICES:
+// 1. Borrow (&str, &Path, &OsStr) in parameters; own in struct fields
+// 2. Never slice text at an offset you computed by counting chars - use
+//    char_indices, get(), or is_char_boundary
+// 3. Keep file names as Path/OsStr until you must display them
+// 4. Keep a CString alive for as long as C holds its pointer
+// 5. Decode network bytes incrementally: a character can straddle two reads
+// 6. Build output with write!/push_str into one buffer sized up front
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lengths_and_char_boundaries() {
+        assert_eq!(lengths("🦀é"), TextLengths { bytes: 6, chars: 2 });
+        assert_eq!(truncate_chars("🦀é!", 2), "🦀é");
+        assert_eq!(truncate_chars("ab", 5), "ab");
+        // 5 bytes would cut é (bytes 4..6) in half
+        assert_eq!(truncate_bytes("🦀é!", 5), "🦀");
+        assert_eq!(truncate_bytes("🦀é!", 3), "");
+        assert_eq!(truncate_bytes("🦀é!", 99), "🦀é!");
+        assert_eq!(byte_slice("🦀é", 4, 6), Some("é"));
+        assert_eq!(byte_slice("🦀é", 1, 6), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a char boundary")]
+    fn slicing_inside_a_char_panics() {
+        let s = String::from("é");
+        let _ = &s[0..1];
+    }
+
+    #[test]
+    fn paths_and_os_strings() {
+        let base = Path::new("/srv");
+        assert_eq!(join_under(base, &["a", "", "b.txt"]), Some(PathBuf::from("/srv/a/b.txt")));
+        assert_eq!(join_under(base, &["/etc"]), None);
+        assert_eq!(join_under(base, &["a/../../etc"]), None);
+        assert_eq!(file_name_lossy(Path::new("/srv/a.txt")), Some(Cow::Borrowed("a.txt")));
+
+        #[cfg(unix)]
+        {
+            let name = PathBuf::from(non_utf8_name());
+            assert_eq!(name.to_str(), None);
+            assert_eq!(file_name_lossy(&name).unwrap(), "caf\u{FFFD}.txt");
+        }
+    }
+
+    #[test]
+    fn c_strings_reject_interior_nul() {
+        assert_eq!(to_c_string("hi").unwrap().as_bytes_with_nul(), b"hi\0");
+        assert_eq!(to_c_string("a\0b").unwrap_err().nul_position(), 1);
+        assert_eq!(from_c_bytes(b"ok\0"), Some("ok"));
+        assert_eq!(from_c_bytes(b"\xFF\0"), None, "not UTF-8");
+        assert_eq!(from_c_bytes(b"a\0b\0"), None, "interior NUL");
+        #[cfg(unix)]
+        assert_eq!(c_strlen(c"🦀"), 4);
+    }
+
+    #[test]
+    fn byte_strings_decode_incrementally() {
+        let crab = "a🦀".as_bytes();
+        assert_eq!(decode_prefix(&crab[..3]).unwrap(), ("a", &crab[1..3]));
+        assert_eq!(decode_prefix(crab).unwrap(), ("a🦀", &[][..]));
+        assert_eq!(decode_prefix(b"a\xFFb").unwrap_err().valid_up_to(), 1);
+
+        let (name, value) = parse_header(b"X-Id :  7\r").unwrap();
+        assert_eq!((name.as_slice(), value), (&b"x-id"[..], &b"7"[..]));
+        assert_eq!(parse_header(b": no name"), None);
+        assert_eq!(parse_header(b"no colon"), None);
+    }
+
+    #[test]
+    fn builders_allocate_once() {
+        let joined = join_exact(&["a", "bb", "ccc"], ", ");
+        assert_eq!(joined, "a, bb, ccc");
+        assert_eq!(joined.capacity(), joined.len());
+        assert_eq!(join_exact(&[], ", "), "");
+
+        let pieces = ["x"; 64];
+        assert_eq!(count_string_reallocations(&pieces, true), 0);
+        assert!(count_string_reallocations(&pieces, false) > 1);
+
+        assert_eq!(render_rows(&[("a", 1), ("λλ", 20)]), "a      1\nλλ    20\n");
+        assert_eq!(initials("  grace  hopper "), "GH");
+        assert_eq!(Label::new(String::from("x")), Label::new("x"));
+    }
+}
@@ -0,0 +1,400 @@
+// ===== TESTING AND BENCHMARKING DEEP STUDY =====
+//
+// WHY TEST IN RUST?
+// The compiler rules out whole classes of bugs, but it can't tell whether
+// your logic is RIGHT. Cargo ships a test runner and benchmark support out of
+// the box, so there's no excuse not to use them.
+//
+// THE KINDS OF TESTS:
+// • Unit tests: Live next to the code in `#[cfg(test)] mod tests`, can test
+//   private functions (see the bottom of this file)
+// • Integration tests: Files in `tests/`, each compiled as its own crate that
+//   only sees the PUBLIC API (see tests/testing_integration.rs)
+// • Doc tests: Code blocks in `///` comments, compiled and run by `cargo test`
+// • Property tests: Generate hundreds of random inputs and check an invariant
+//   (see tests/testing_properties.rs, using proptest)
+// • Benchmarks: Measure performance with statistics (see benches/, using criterion)
+//
+// COMMANDS:
+// • cargo test                      → unit + integration + doc tests
+// • cargo test parse_duration       → only tests whose name contains the filter
+// • cargo test -- --ignored         → run tests marked #[ignore]
+// • cargo test -- --nocapture       → show println! output from passing tests
+// • cargo bench                     → run criterion benchmarks
+
+use crate::report::Report;
+use std::collections::HashMap;
+use std::time::Duration;
+use thiserror::Error;
+
+// ===== 1. CODE UNDER TEST: A PARSER =====
+//
+// Parsers are ideal for testing: clear inputs, clear outputs, lots of edge cases.
+// parse_duration("1h30m") == Ok(5400s)
+
+#[derive(Error, Debug, PartialEq)]
+pub enum DurationParseError {
+    #[error("duration is empty")]
+    Empty,
+
+    #[error("expected a number before unit in '{0}'")]
+    MissingNumber(String),
+
+    #[error("unknown unit '{0}' (expected h, m, s or ms)")]
+    UnknownUnit(String),
+
+    #[error("missing unit after '{0}'")]
+    MissingUnit(String),
+}
+
+/// Parses durations like `"1h30m"`, `"45s"` or `"250ms"`.
+///
+/// ```
+/// use rust_basics::testing::parse_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_duration("1m30s"), Ok(Duration::from_secs(90)));
+/// assert!(parse_duration("10x").is_err());
+/// ```
+pub fn parse_duration(input: &str) -> Result<Duration, DurationParseError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(DurationParseError::Empty);
+    }
+
+    let mut total = Duration::ZERO;
+    let mut rest = input;
+
+    while !rest.is_empty() {
+        // Split off the leading digits...
+        let digits_end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        if digits_end == 0 {
+            return Err(DurationParseError::MissingNumber(rest.to_string()));
+        }
+        let (number, after) = rest.split_at(digits_end);
+        let value: u64 = number.parse().map_err(|_| DurationParseError::MissingNumber(number.to_string()))?;
+
+        // ...then the unit letters
+        let unit_end = after.find(|c: char| c.is_ascii_digit()).unwrap_or(after.len());
+        let (unit, next) = after.split_at(unit_end);
+
+        total += match unit {
+            "h" => Duration::from_secs(value * 3600),
+            "m" => Duration::from_secs(value * 60),
+            "s" => Duration::from_secs(value),
+            "ms" => Duration::from_millis(value),
+            "" => return Err(DurationParseError::MissingUnit(number.to_string())),
+            other => return Err(DurationParseError::UnknownUnit(other.to_string())),
+        };
+
+        rest = next;
+    }
+
+    Ok(total)
+}
+
+// ===== 2. CODE UNDER TEST: STATE AND PANICS =====
+//
+// WHEN SHOULD CODE PANIC?
+// • For broken PRECONDITIONS - bugs in the caller, not expected failures
+// • Offer a fallible alternative (try_*) for callers who can't guarantee it
+// • Test the panic with #[should_panic(expected = "...")] so the contract is enforced
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Inventory {
+    stock: HashMap<String, u32>,
+}
+
+impl Inventory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, item: &str, quantity: u32) {
+        *self.stock.entry(item.to_string()).or_insert(0) += quantity;
+    }
+
+    pub fn quantity(&self, item: &str) -> u32 {
+        self.stock.get(item).copied().unwrap_or(0)
+    }
+
+    // Fallible version: for callers that don't know the stock level
+    pub fn try_remove(&mut self, item: &str, quantity: u32) -> Result<u32, String> {
+        let available = self.quantity(item);
+        if available < quantity {
+            return Err(format!("insufficient stock for '{}': have {}, need {}", item, available, quantity));
+        }
+
+        let remaining = available - quantity;
+        if remaining == 0 {
+            self.stock.remove(item);
+        } else {
+            self.stock.insert(item.to_string(), remaining);
+        }
+        Ok(remaining)
+    }
+
+    // Panicking version: removing more than is in stock is a caller bug
+    pub fn remove(&mut self, item: &str, quantity: u32) -> u32 {
+        match self.try_remove(item, quantity) {
+            Ok(remaining) => remaining,
+            Err(message) => panic!("{}", message),
+        }
+    }
+
+    pub fn total_items(&self) -> u32 {
+        self.stock.values().sum()
+    }
+}
+
+// ===== 3. CODE UNDER TEST: INVARIANTS =====
+//
+// PROPERTY-BASED TESTING:
+// Instead of hand-picking examples, state a rule that must hold for ALL inputs:
+// • decode(encode(s)) == s                 (round trip)
+// • encoded runs never have count 0        (structural invariant)
+// • adjacent runs never share a character  (canonical form)
+// proptest generates random inputs and SHRINKS failures to a minimal example.
+
+pub fn run_length_encode(input: &str) -> Vec<(char, usize)> {
+    let mut runs: Vec<(char, usize)> = Vec::new();
+    for c in input.chars() {
+        match runs.last_mut() {
+            Some((last, count)) if *last == c => *count += 1,
+            _ => runs.push((c, 1)),
+        }
+    }
+    runs
+}
+
+pub fn run_length_decode(runs: &[(char, usize)]) -> String {
+    runs.iter().map(|&(c, count)| c.to_string().repeat(count)).collect()
+}
+
+// ===== 4. CODE UNDER TEST: PERFORMANCE =====
+//
+// BENCHMARKING WITH CRITERION:
+// • Runs each function many times, warms up, and reports mean ± confidence interval
+// • Detects regressions by comparing against the previous run
+// • std::hint::black_box keeps the optimizer from precomputing results
+// See benches/testing_benchmarks.rs
+
+pub fn fibonacci_recursive(n: u32) -> u64 {
+    match n {
+        0 => 0,
+        1 => 1,
+        _ => fibonacci_recursive(n - 1) + fibonacci_recursive(n - 2),
+    }
+}
+
+pub fn fibonacci_iterative(n: u32) -> u64 {
+    let (mut a, mut b) = (0u64, 1u64);
+    for _ in 0..n {
+        (a, b) = (b, a + b);
+    }
+    a
+}
+
+// Private helper - only unit tests (in this file) can reach it
+fn is_canonical(runs: &[(char, usize)]) -> bool {
+    runs.iter().all(|&(_, count)| count > 0) && runs.windows(2).all(|pair| pair[0].0 != pair[1].0)
+}
+
+// ===== 5. DEMONSTRATION FUNCTION =====
+
+pub fn testing_report() -> Report {
+    let mut report = Report::new("🦀 RUST TESTING AND BENCHMARKING DEEP STUDY 🦀");
+
+    // ===== THE CODE UNDER TEST =====
+    let code = report.section("1️⃣ CODE UNDER TEST:");
+    for input in ["1h30m", "45s", "2m250ms", "", "10x", "5"] {
+        code.example(format!("parse_duration({:?})", input), format!("{:?}", parse_duration(input)));
+    }
+
+    let mut inventory = Inventory::new();
+    inventory.add("apple", 3);
+    code.example("Inventory try_remove 5 apples", format!("{:?}", inventory.try_remove("apple", 5)))
+        .example("Inventory remove 2 apples", format!("{} left", inventory.remove("apple", 2)));
+
+    let runs = run_length_encode("aaabccdddd");
+    code.example("run_length_encode(\"aaabccdddd\")", format!("{:?}", runs))
+        .example("round trip", format!("{:?} (canonical: {})", run_length_decode(&runs), is_canonical(&runs)));
+
+    // ===== WHERE TESTS LIVE =====
+    report
+        .section("2️⃣ WHERE EACH KIND OF TEST LIVES:")
+        .example("Unit tests", "src/testing.rs, #[cfg(test)] mod tests (can test private fns)")
+        .example("Integration tests", "tests/testing_integration.rs (public API only)")
+        .example("Shared fixtures", "tests/common/mod.rs (not a test crate itself)")
+        .example("Property tests", "tests/testing_properties.rs (proptest)")
+        .example("Doc tests", "the example on parse_duration")
+        .example("Benchmarks", "benches/testing_benchmarks.rs (criterion)");
+
+    // ===== ATTRIBUTES =====
+    report
+        .section("3️⃣ USEFUL TEST ATTRIBUTES:")
+        .example("#[test]", "a test function")
+        .example("#[should_panic(expected = \"insufficient\")]", "passes only if it panics with that message")
+        .example("#[ignore = \"slow\"]", "skipped unless `cargo test -- --ignored`")
+        .example("fn test() -> Result<(), E>", "use ? instead of unwrap()");
+
+    // ===== PERFORMANCE PREVIEW =====
+    let benchmarks = report.section("4️⃣ WHY BENCHMARK:");
+    for n in [10, 20, 25] {
+        let start = std::time::Instant::now();
+        let recursive = fibonacci_recursive(n);
+        let recursive_time = start.elapsed();
+        let start = std::time::Instant::now();
+        let iterative = fibonacci_iterative(n);
+        let iterative_time = start.elapsed();
+        assert_eq!(recursive, iterative);
+        benchmarks.example(
+            format!("fib({:>2}) = {:>6}", n, recursive),
+            format!("recursive {:>10?} vs iterative {:>8?}", recursive_time, iterative_time),
+        );
+    }
+    benchmarks.text("Run `cargo bench -p rust-basics` for statistically sound numbers");
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 TESTING CONCEPTS SUMMARY:")
+        .check("Unit tests sit next to the code and see private items")
+        .check("Integration tests exercise the public API from tests/")
+        .check("#[should_panic] pins down panic contracts")
+        .check("Fixtures are plain functions that build test data")
+        .check("Property tests check invariants over random inputs")
+        .check("Criterion measures performance with statistics");
+
+    report
+}
+
+pub fn demonstrate_testing() {
+    print!("{}", testing_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct TestingModule;
+
+impl LearningModule for TestingModule {
+    fn number(&self) -> u8 {
+        9
+    }
+
+    fn name(&self) -> &'static str {
+        "testing"
+    }
+
+    fn title(&self) -> &'static str {
+        "TESTING AND BENCHMARKING DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Testing & Benchmarking"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["errors"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_testing() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { testing_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&TestingModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// TESTING BEST PRACTICES:
+// 1. Test behavior through the public API; unit-test tricky private helpers
+// 2. Name tests after the behavior they check (rejects_unknown_units, ...)
+// 3. Cover the edge cases: empty input, boundaries, invalid data
+// 4. Use expected = "..." on #[should_panic] so the RIGHT panic is required
+// 5. Reach for property tests when you can state an invariant
+// 6. Benchmark before and after optimizing - intuition is often wrong
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // FIXTURE: A plain function that builds a known starting state.
+    // Each test gets a fresh copy, so tests can't interfere with each other.
+    fn stocked_inventory() -> Inventory {
+        let mut inventory = Inventory::new();
+        inventory.add("apple", 5);
+        inventory.add("pear", 2);
+        inventory
+    }
+
+    #[test]
+    fn report_runs_the_code_under_test() {
+        let report = testing_report();
+        let code = report.find("CODE UNDER TEST").unwrap();
+        assert_eq!(code.output("parse_duration(\"1h30m\")"), Some("Ok(5400s)"));
+        assert_eq!(code.output("parse_duration(\"\")"), Some("Err(Empty)"));
+        assert_eq!(code.output("Inventory remove 2 apples"), Some("1 left"));
+        assert_eq!(code.output("round trip"), Some("\"aaabccdddd\" (canonical: true)"));
+        assert_eq!(report.find("WHY BENCHMARK").unwrap().outputs().count(), 3);
+    }
+
+    #[test]
+    fn parses_single_units() {
+        assert_eq!(parse_duration("45s"), Ok(Duration::from_secs(45)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1h"), Ok(Duration::from_secs(3600)));
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn parses_combined_units() {
+        assert_eq!(parse_duration("1h30m15s"), Ok(Duration::from_secs(5415)));
+        assert_eq!(parse_duration(" 1s500ms "), Ok(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert_eq!(parse_duration(""), Err(DurationParseError::Empty));
+        assert_eq!(parse_duration("10x"), Err(DurationParseError::UnknownUnit("x".into())));
+        assert_eq!(parse_duration("5"), Err(DurationParseError::MissingUnit("5".into())));
+        assert_eq!(parse_duration("m5"), Err(DurationParseError::MissingNumber("m5".into())));
+    }
+
+    // Tests can return Result and use ? instead of unwrap()
+    #[test]
+    fn removing_updates_stock() -> Result<(), String> {
+        let mut inventory = stocked_inventory();
+        assert_eq!(inventory.try_remove("apple", 3)?, 2);
+        assert_eq!(inventory.try_remove("pear", 2)?, 0);
+        assert_eq!(inventory.total_items(), 2);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "insufficient stock for 'pear'")]
+    fn removing_too_much_panics() {
+        let mut inventory = stocked_inventory();
+        inventory.remove("pear", 3);
+    }
+
+    // Private helpers are reachable from unit tests
+    #[test]
+    fn encoding_is_canonical() {
+        assert!(is_canonical(&run_length_encode("aabbbcaa")));
+        assert!(!is_canonical(&[('a', 1), ('a', 2)]));
+    }
+
+    #[test]
+    #[ignore = "slow: exponential recursion, run with `cargo test -- --ignored`"]
+    fn recursive_fibonacci_matches_iterative_for_large_n() {
+        assert_eq!(fibonacci_recursive(35), fibonacci_iterative(35));
+    }
+}
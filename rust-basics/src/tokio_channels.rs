@@ -0,0 +1,663 @@
+// ===== TOKIO CHANNELS AND STREAMS DEEP STUDY =====
+//
+// WHY ASYNC CHANNELS?
+// concurrency.rs used std::sync::mpsc, whose recv() BLOCKS the thread. Inside
+// async code that would stall the executor. tokio::sync provides channels
+// whose send/recv are futures: waiting tasks yield instead of blocking.
+//
+// THE TOKIO::SYNC TOOLBOX:
+// • mpsc: Many producers → one consumer, bounded (backpressure)
+// • oneshot: Exactly one value, once (request → response)
+// • broadcast: Every receiver gets every message (fan-out, events)
+// • watch: Receivers only see the LATEST value (config, state)
+// • Semaphore: Limit how many tasks do something at once
+//
+// STREAMS:
+// A Stream is the async version of Iterator: poll_next() yields items over time.
+// tokio-stream adds combinators (map, filter, take, merge, chunks_timeout, ...)
+// and wrappers that turn channel receivers into streams.
+//
+// SHUTDOWN:
+// tokio-util's CancellationToken broadcasts "please stop" to any number of
+// tasks; TaskTracker waits until they have all finished.
+
+use crate::report::Report;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+use tokio::sync::{Semaphore, broadcast, mpsc, oneshot, watch};
+use tokio::time::sleep;
+use tokio_stream::wrappers::{BroadcastStream, ReceiverStream};
+use tokio_stream::{self as stream, StreamExt};
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+
+// ===== 1. MPSC - BOUNDED MULTI-PRODUCER CHANNEL =====
+//
+// UNDERSTANDING tokio::sync::mpsc:
+// • mpsc::channel(n): send().await WAITS when n messages are queued (backpressure)
+// • mpsc::unbounded_channel(): send() never waits - use only when producers are bounded
+// • recv() returns None once every Sender is dropped
+// • try_send() fails immediately instead of waiting (load shedding)
+
+pub async fn mpsc_fan_in(producers: u32, items_each: u32) -> Vec<String> {
+    let (tx, mut rx) = mpsc::channel::<String>(4);
+
+    for id in 0..producers {
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            for item in 0..items_each {
+                // Waits (yields) whenever the buffer of 4 is full
+                if tx.send(format!("p{}#{}", id, item)).await.is_err() {
+                    return; // Receiver gone - stop producing
+                }
+            }
+        });
+    }
+    drop(tx); // Otherwise recv() never returns None
+
+    let mut received = Vec::new();
+    while let Some(message) = rx.recv().await {
+        received.push(message);
+    }
+    received.sort();
+    received
+}
+
+pub fn try_send_load_shedding(capacity: usize, attempts: usize) -> (usize, usize) {
+    let (tx, _rx) = mpsc::channel::<usize>(capacity);
+    let mut accepted = 0;
+    let mut rejected = 0;
+
+    for i in 0..attempts {
+        match tx.try_send(i) {
+            Ok(()) => accepted += 1,
+            Err(mpsc::error::TrySendError::Full(_)) => rejected += 1,
+            Err(mpsc::error::TrySendError::Closed(_)) => break,
+        }
+    }
+    (accepted, rejected)
+}
+
+// ===== 2. ONESHOT - REQUEST/RESPONSE =====
+//
+// UNDERSTANDING oneshot:
+// • One Sender, one Receiver, one value
+// • The Receiver is itself a future
+// • Classic pattern: send a request over mpsc WITH a oneshot::Sender inside,
+//   the worker replies on it (this is how actors answer questions)
+
+pub enum KvCommand {
+    Get { key: String, reply: oneshot::Sender<Option<u32>> },
+    Set { key: String, value: u32 },
+}
+
+pub fn spawn_kv_worker() -> mpsc::Sender<KvCommand> {
+    let (tx, mut rx) = mpsc::channel(16);
+
+    tokio::spawn(async move {
+        // The worker OWNS the map - no Mutex needed
+        let mut store = std::collections::HashMap::new();
+        while let Some(command) = rx.recv().await {
+            match command {
+                KvCommand::Set { key, value } => {
+                    store.insert(key, value);
+                }
+                KvCommand::Get { key, reply } => {
+                    // The asker may have given up; ignore the error
+                    let _ = reply.send(store.get(&key).copied());
+                }
+            }
+        }
+    });
+
+    tx
+}
+
+pub async fn kv_get(worker: &mpsc::Sender<KvCommand>, key: &str) -> Option<u32> {
+    let (reply, response) = oneshot::channel();
+    worker
+        .send(KvCommand::Get { key: key.to_string(), reply })
+        .await
+        .ok()?;
+    response.await.ok().flatten()
+}
+
+// ===== 3. BROADCAST - FAN-OUT =====
+//
+// UNDERSTANDING broadcast:
+// • Every subscriber receives a CLONE of every message sent after it subscribed
+// • The buffer is fixed-size; a slow receiver that falls behind gets
+//   RecvError::Lagged(n) and skips the n oldest messages
+// • Good for events ("user logged in") that several components react to
+
+pub async fn broadcast_with_lag(capacity: usize, messages: u32) -> (Vec<u32>, Vec<String>) {
+    let (tx, mut fast) = broadcast::channel::<u32>(capacity);
+    let mut slow = tx.subscribe();
+
+    for i in 0..messages {
+        tx.send(i).unwrap();
+        // The fast receiver keeps up
+        let _ = fast.recv().await;
+    }
+    drop(tx);
+
+    // The slow receiver only starts reading now - the buffer has overflowed
+    let mut events = Vec::new();
+    let mut received = Vec::new();
+    loop {
+        match slow.recv().await {
+            Ok(value) => received.push(value),
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                events.push(format!("lagged, skipped {}", skipped));
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+    (received, events)
+}
+
+// ===== 4. WATCH - LATEST VALUE ONLY =====
+//
+// UNDERSTANDING watch:
+// • Holds ONE value; send() replaces it
+// • Receivers call changed().await, then borrow() the current value
+// • Intermediate values may be skipped - receivers only care about "now"
+// • Perfect for configuration reloads and state that many tasks read
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub version: u32,
+    pub rate_limit: u32,
+}
+
+pub async fn watch_config_updates() -> Vec<u32> {
+    let (tx, mut rx) = watch::channel(Config { version: 1, rate_limit: 10 });
+
+    let watcher = tokio::spawn(async move {
+        let mut seen = vec![rx.borrow().version];
+        while rx.changed().await.is_ok() {
+            seen.push(rx.borrow_and_update().version);
+            sleep(Duration::from_millis(20)).await; // Slow reader
+        }
+        seen
+    });
+
+    for version in 2..=6 {
+        tx.send_modify(|config| {
+            config.version = version;
+            config.rate_limit += 5;
+        });
+        sleep(Duration::from_millis(5)).await; // Faster than the reader
+    }
+    drop(tx); // changed() returns Err - watcher exits
+
+    watcher.await.unwrap()
+}
+
+// ===== 5. SEMAPHORE - LIMITING CONCURRENCY =====
+//
+// UNDERSTANDING Semaphore:
+// • Holds N permits; acquire().await waits until one is free
+// • The permit is returned when dropped
+// • Use it to cap concurrent requests to a database or remote API
+// • acquire_owned() gives a permit that can move into a spawned task
+
+pub async fn limited_concurrency(tasks: usize, limit: usize) -> (usize, Duration) {
+    let semaphore = Arc::new(Semaphore::new(limit));
+    let running = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let start = Instant::now();
+
+    let handles: Vec<_> = (0..tasks)
+        .map(|_| {
+            let semaphore = Arc::clone(&semaphore);
+            let running = Arc::clone(&running);
+            let peak = Arc::clone(&peak);
+            tokio::spawn(async move {
+                let _permit = semaphore.acquire_owned().await.unwrap();
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                sleep(Duration::from_millis(10)).await; // "Call the API"
+                running.fetch_sub(1, Ordering::SeqCst);
+            }) // _permit dropped here → next task may start
+        })
+        .collect();
+
+    for handle in handles {
+        handle.await.unwrap();
+    }
+    (peak.load(Ordering::SeqCst), start.elapsed())
+}
+
+// ===== 6. STREAM COMBINATORS =====
+//
+// UNDERSTANDING STREAMS:
+// • Iterator: next() -> Option<T>          (synchronous)
+// • Stream:   next().await -> Option<T>    (items arrive over time)
+// • Combinators look like Iterator's: map, filter, take, skip, fold, ...
+// • Time-aware ones: throttle, timeout, chunks_timeout
+// • merge() interleaves two streams as items become ready
+
+pub async fn stream_basics() -> Vec<u32> {
+    stream::iter(1..=20)
+        .filter(|n| n % 3 == 0) // 3, 6, 9, ...
+        .map(|n| n * 10)
+        .take(4)
+        .collect()
+        .await
+}
+
+pub async fn merged_streams() -> Vec<String> {
+    let ticks = stream::iter(["tick-1", "tick-2", "tick-3"])
+        .then(|t| async move {
+            sleep(Duration::from_millis(10)).await;
+            t.to_string()
+        });
+    let alerts = stream::iter(["ALERT"]).then(|a| async move {
+        sleep(Duration::from_millis(15)).await;
+        a.to_string()
+    });
+
+    // Items appear in arrival order, not stream order
+    ticks.merge(alerts).collect().await
+}
+
+pub async fn batch_with_chunks_timeout() -> Vec<Vec<u32>> {
+    let (tx, rx) = mpsc::channel(16);
+
+    tokio::spawn(async move {
+        for burst in [vec![1, 2, 3, 4, 5], vec![6], vec![7, 8]] {
+            for value in burst {
+                tx.send(value).await.unwrap();
+            }
+            sleep(Duration::from_millis(30)).await; // Pause between bursts
+        }
+    });
+
+    // Batches of at most 3 items, or whatever arrived within 20ms
+    let batches = ReceiverStream::new(rx).chunks_timeout(3, Duration::from_millis(20));
+    tokio::pin!(batches); // chunks_timeout is !Unpin - pin it to call next()
+
+    let mut result = Vec::new();
+    while let Some(batch) = batches.next().await {
+        result.push(batch);
+    }
+    result
+}
+
+pub async fn broadcast_as_stream() -> Vec<String> {
+    let (tx, rx) = broadcast::channel(8);
+    for event in ["login", "click", "logout"] {
+        tx.send(event.to_string()).unwrap();
+    }
+    drop(tx);
+
+    // Lag errors become stream items too; keep only the successes
+    BroadcastStream::new(rx).filter_map(Result::ok).collect().await
+}
+
+// ===== 7. GRACEFUL SHUTDOWN WITH CANCELLATIONTOKEN =====
+//
+// UNDERSTANDING CancellationToken:
+// • cancel() flips the token; every clone's cancelled().await completes
+// • child_token(): cancelled when the parent is, but can be cancelled alone
+// • Workers select! on cancelled() vs their next unit of work, finish the
+//   current item, clean up, and exit - no half-written state
+//
+// UNDERSTANDING TaskTracker:
+// • spawn() tasks through the tracker, then close() + wait()
+// • wait() completes once every tracked task has finished
+
+pub async fn graceful_shutdown(workers: u32) -> Vec<String> {
+    let token = CancellationToken::new();
+    let tracker = TaskTracker::new();
+    let (log_tx, mut log_rx) = mpsc::unbounded_channel::<String>();
+
+    for id in 0..workers {
+        let token = token.child_token();
+        let log = log_tx.clone();
+        tracker.spawn(async move {
+            let mut processed = 0;
+            loop {
+                tokio::select! {
+                    // `biased` checks branches in order: shutdown wins ties
+                    biased;
+                    _ = token.cancelled() => break,
+                    _ = sleep(Duration::from_millis(5)) => processed += 1, // One unit of work
+                }
+            }
+            // Cleanup runs after cancellation, before the task ends
+            let _ = log.send(format!("worker {} stopped cleanly after {} items", id, processed));
+        });
+    }
+    drop(log_tx);
+
+    sleep(Duration::from_millis(30)).await;
+    token.cancel(); // Broadcast "stop"
+
+    tracker.close(); // No more tasks will be added
+    tracker.wait().await; // Wait for every worker's cleanup
+
+    let mut log = Vec::new();
+    while let Some(line) = log_rx.recv().await {
+        log.push(line);
+    }
+    log.sort();
+    log
+}
+
+// ===== 8. PRODUCER/CONSUMER PIPELINE =====
+//
+// PUTTING IT ALL TOGETHER:
+//   producer ──mpsc(8)──▶ parse stage (Semaphore: 2 at a time) ──mpsc(8)──▶ sink
+//      ▲                              ▲                                     │
+//      └────────── CancellationToken ─┴───── progress via watch ◀───────────┘
+//
+// • Bounded channels give backpressure end to end
+// • The semaphore caps concurrent "expensive" work
+// • watch publishes progress for anyone interested
+// • The token stops the producer; everything downstream drains and exits
+//   naturally as channels close
+
+#[derive(Debug, Default)]
+pub struct PipelineReport {
+    pub produced: usize,
+    pub parsed: usize,
+    pub rejected: usize,
+    pub total: i64,
+    pub last_progress: usize,
+}
+
+pub async fn run_pipeline(inputs: Vec<&'static str>, stop_after: Option<usize>) -> PipelineReport {
+    let token = CancellationToken::new();
+    let (raw_tx, mut raw_rx) = mpsc::channel::<&'static str>(8);
+    let (parsed_tx, parsed_rx) = mpsc::channel::<Result<i64, String>>(8);
+    let (progress_tx, progress_rx) = watch::channel(0usize);
+    let limit = Arc::new(Semaphore::new(2));
+
+    // STAGE 1: Producer (stops early if cancelled)
+    let producer = {
+        let token = token.clone();
+        tokio::spawn(async move {
+            let mut produced = 0;
+            for input in inputs {
+                tokio::select! {
+                    _ = token.cancelled() => break,
+                    sent = raw_tx.send(input) => {
+                        if sent.is_err() { break; }
+                        produced += 1;
+                    }
+                }
+            }
+            produced // raw_tx dropped here → stage 2 sees the end
+        })
+    };
+
+    // STAGE 2: Parse with bounded concurrency
+    let parser = tokio::spawn(async move {
+        let tracker = TaskTracker::new();
+        while let Some(raw) = raw_rx.recv().await {
+            let permit = Arc::clone(&limit).acquire_owned().await.unwrap();
+            let parsed_tx = parsed_tx.clone();
+            tracker.spawn(async move {
+                sleep(Duration::from_millis(2)).await; // "Expensive" parsing
+                let result = raw.trim().parse::<i64>().map_err(|_| raw.to_string());
+                let _ = parsed_tx.send(result).await;
+                drop(permit);
+            });
+        }
+        tracker.close();
+        tracker.wait().await;
+        // Last parsed_tx clone dropped here → stage 3 sees the end
+    });
+
+    // STAGE 3: Sink consumes the stream of results and publishes progress
+    let sink = tokio::spawn(async move {
+        let mut report = PipelineReport::default();
+        let mut results = ReceiverStream::new(parsed_rx);
+        while let Some(result) = results.next().await {
+            match result {
+                Ok(value) => {
+                    report.parsed += 1;
+                    report.total += value;
+                }
+                Err(_) => report.rejected += 1,
+            }
+            let _ = progress_tx.send(report.parsed + report.rejected);
+        }
+        report
+    });
+
+    // Optional early shutdown once enough items were processed
+    if let Some(stop_after) = stop_after {
+        let mut progress = progress_rx.clone();
+        let _ = progress.wait_for(|&done| done >= stop_after).await;
+        token.cancel();
+    }
+
+    let produced = producer.await.unwrap();
+    parser.await.unwrap();
+    let mut report = sink.await.unwrap();
+    report.produced = produced;
+    report.last_progress = *progress_rx.borrow();
+    report
+}
+
+// ===== 9. DEMONSTRATION FUNCTION =====
+
+pub async fn tokio_channels_report() -> Report {
+    let mut report = Report::new("🦀 RUST TOKIO CHANNELS AND STREAMS DEEP STUDY 🦀");
+
+    // ===== MPSC & ONESHOT =====
+    let messages = mpsc_fan_in(3, 3).await;
+    let (accepted, rejected) = try_send_load_shedding(3, 10);
+
+    let kv = spawn_kv_worker();
+    kv.send(KvCommand::Set { key: "answer".into(), value: 42 }).await.unwrap();
+    let answer = kv_get(&kv, "answer").await;
+    let missing = kv_get(&kv, "missing").await;
+
+    report
+        .section("1️⃣ MPSC & ONESHOT:")
+        .example("Fan-in from 3 producers through a 4-slot channel", format!("{:?}", messages))
+        .example("try_send on a full channel", format!("{} accepted, {} shed", accepted, rejected))
+        .example("oneshot reply for 'answer'", format!("{:?}", answer))
+        .example("oneshot reply for 'missing'", format!("{:?}", missing));
+
+    // ===== BROADCAST & WATCH =====
+    let (received, events) = broadcast_with_lag(4, 10).await;
+    let versions = watch_config_updates().await;
+    report
+        .section("2️⃣ BROADCAST & WATCH:")
+        .example("Slow broadcast receiver (capacity 4, 10 sent)", format!("got {:?}, events {:?}", received, events))
+        .example("watch versions seen by a slow reader (sent 1..=6)", format!("{:?}", versions));
+
+    // ===== SEMAPHORE =====
+    let (peak, elapsed) = limited_concurrency(12, 3).await;
+    report
+        .section("3️⃣ SEMAPHORE:")
+        .example("12 tasks × 10ms with 3 permits", format!("peak concurrency {}, took {:?}", peak, elapsed));
+
+    // ===== STREAMS =====
+    let basics = stream_basics().await;
+    let merged = merged_streams().await;
+    let batches = batch_with_chunks_timeout().await;
+    let broadcast = broadcast_as_stream().await;
+    report
+        .section("4️⃣ STREAM COMBINATORS:")
+        .example("iter(1..=20).filter(%3).map(*10).take(4)", format!("{:?}", basics))
+        .example("merge(ticks every 10ms, alert at 15ms)", format!("{:?}", merged))
+        .example("chunks_timeout(3, 20ms) over bursts [1..5], [6], [7, 8]", format!("{:?}", batches))
+        .example("BroadcastStream", format!("{:?}", broadcast));
+
+    // ===== SHUTDOWN =====
+    let shutdown_log = graceful_shutdown(3).await;
+    let shutdown = report.section("5️⃣ GRACEFUL SHUTDOWN WITH CANCELLATIONTOKEN:");
+    for line in shutdown_log {
+        shutdown.text(line);
+    }
+
+    // ===== PIPELINE =====
+    let inputs = vec!["10", "20", "oops", "30", " 40 ", "x", "50", "60"];
+    let full = run_pipeline(inputs.clone(), None).await;
+
+    let many: Vec<&'static str> = inputs.iter().cycle().take(200).copied().collect();
+    let cancelled = run_pipeline(many, Some(20)).await;
+    report
+        .section("6️⃣ PRODUCER/CONSUMER PIPELINE:")
+        .example("Full run", format!("{:?}", full))
+        .example(
+            "Cancelled after ≥20 results",
+            format!("produced {} of 200, processed {}", cancelled.produced, cancelled.last_progress),
+        );
+
+    // ===== SUMMARY =====
+    report
+        .section("🎯 TOKIO CHANNELS & STREAMS SUMMARY:")
+        .check("mpsc: bounded queues with backpressure")
+        .check("oneshot: one reply to one request")
+        .check("broadcast: everyone sees everything (watch for Lagged)")
+        .check("watch: everyone sees the latest")
+        .check("Semaphore: cap concurrent work")
+        .check("Streams: async iterators with combinators")
+        .check("CancellationToken + TaskTracker: clean shutdown");
+
+    report
+}
+
+pub async fn demonstrate_tokio_channels() {
+    print!("{}", tokio_channels_report().await);
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct TokioChannelsModule;
+
+impl LearningModule for TokioChannelsModule {
+    fn number(&self) -> u8 {
+        11
+    }
+
+    fn name(&self) -> &'static str {
+        "tokio_channels"
+    }
+
+    fn title(&self) -> &'static str {
+        "TOKIO CHANNELS AND STREAMS DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Tokio Channels & Streams"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["async_await", "concurrency"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(demonstrate_tokio_channels())
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(tokio_channels_report()))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&TokioChannelsModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// ASYNC CHANNEL BEST PRACTICES:
+// 1. Prefer bounded channels - unbounded ones hide overload until memory runs out
+// 2. Drop your own Sender clone, or receivers never see the end
+// 3. Handle send errors: they mean the other side is gone - stop working
+// 4. Pick the channel by delivery semantics: one-to-one, fan-out, or latest-value
+// 5. Give every long-running task a way to be cancelled, and wait for it to finish
+//
+// WHEN TO USE EACH:
+// • mpsc: Work queues, actor mailboxes, pipelines
+// • oneshot: Replies, completion signals
+// • broadcast: Events with several independent listeners
+// • watch: Config, health status, progress
+// • Semaphore: Connection/request limits
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn report_collects_what_each_channel_delivered() {
+        let report = tokio_channels_report().await;
+        let mpsc = report.find("MPSC & ONESHOT").unwrap();
+        assert_eq!(mpsc.output("try_send on a full channel"), Some("3 accepted, 7 shed"));
+        assert_eq!(mpsc.output("oneshot reply for 'answer'"), Some("Some(42)"));
+        assert_eq!(mpsc.output("oneshot reply for 'missing'"), Some("None"));
+
+        let streams = report.find("STREAM COMBINATORS").unwrap();
+        assert_eq!(streams.output("iter(1..=20).filter(%3).map(*10).take(4)"), Some("[30, 60, 90, 120]"));
+
+        assert!(!report.find("GRACEFUL SHUTDOWN").unwrap().entries.is_empty());
+    }
+
+    #[tokio::test]
+    async fn mpsc_fan_in_receives_everything_once() {
+        let received = mpsc_fan_in(3, 5).await;
+        assert_eq!(received.len(), 15);
+        assert!(received.contains(&"p2#4".to_string()));
+
+        // Nobody reads, so only `capacity` sends fit
+        assert_eq!(try_send_load_shedding(3, 10), (3, 7));
+    }
+
+    #[tokio::test]
+    async fn oneshot_replies_come_from_the_worker_state() {
+        let worker = spawn_kv_worker();
+        worker.send(KvCommand::Set { key: "a".to_string(), value: 1 }).await.unwrap();
+        assert_eq!(kv_get(&worker, "a").await, Some(1));
+        assert_eq!(kv_get(&worker, "missing").await, None);
+    }
+
+    #[tokio::test]
+    async fn lagging_broadcast_receiver_skips_the_oldest_messages() {
+        let (received, events) = broadcast_with_lag(4, 10).await;
+        assert_eq!(received, [6, 7, 8, 9], "only the last `capacity` survive");
+        assert_eq!(events, ["lagged, skipped 6"]);
+        assert_eq!(broadcast_as_stream().await, ["login", "click", "logout"]);
+    }
+
+    #[tokio::test]
+    async fn watch_receivers_see_the_latest_value() {
+        // The watcher may start after the first update (then borrow() and the
+        // first changed() both see it), and skips any that land while it
+        // sleeps - but never goes backwards
+        let seen = watch_config_updates().await;
+        assert_eq!(seen.last(), Some(&6), "the final update is never skipped");
+        assert!(seen.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[tokio::test]
+    async fn semaphore_caps_concurrency() {
+        let (peak, _) = limited_concurrency(10, 3).await;
+        assert!(peak <= 3 && peak > 0);
+        assert_eq!(stream_basics().await, [30, 60, 90, 120]);
+    }
+
+    #[tokio::test]
+    async fn every_worker_cleans_up_after_cancellation() {
+        let log = graceful_shutdown(3).await;
+        assert_eq!(log.len(), 3);
+        assert!(log.iter().all(|line| line.contains("stopped cleanly")));
+    }
+
+    #[tokio::test]
+    async fn pipeline_accounts_for_every_input() {
+        let report = run_pipeline(vec!["1", " 2 ", "x", "4"], None).await;
+        assert_eq!((report.produced, report.parsed, report.rejected), (4, 3, 1));
+        assert_eq!((report.total, report.last_progress), (7, 4));
+    }
+}
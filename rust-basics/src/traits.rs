@@ -5,7 +5,16 @@
 // in other languages but more powerful. Traits allow you to define method signatures
 // that types must implement, enabling polymorphism and code reuse.
 
-use std::fmt::Display;
+use crate::report::Report;
+use crate::smart_pointers::RequestStats;
+use std::any::{Any, TypeId};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Write};
+use std::marker::PhantomData;
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+use std::ptr::NonNull;
+use thiserror::Error;
 
 // ===== 1. BASIC TRAIT DEFINITION =====
 // 
@@ -13,7 +22,16 @@ use std::fmt::Display;
 // Methods can have default implementations or be required (no body).
 pub trait Printable {
     // Required method - implementors MUST provide this
-    fn print(&self);
+    // Formatting is kept separate from I/O so it can be tested and benchmarked
+    // (see benches/dispatch_benchmarks.rs) without flooding stdout
+    fn render(&self, out: &mut String);
+    
+    // Default implementation built on the required method
+    fn print(&self) {
+        let mut line = String::new();
+        self.render(&mut line);
+        println!("{}", line);
+    }
     
     // Default implementation - implementors CAN override this
     fn print_twice(&self) {
@@ -109,8 +127,8 @@ pub struct Book {
 
 // Implementing Printable for Article
 impl Printable for Article {
-    fn print(&self) {
-        println!("📰 Article: '{}' by {}", self.title, self.author);
+    fn render(&self, out: &mut String) {
+        let _ = write!(out, "📰 Article: '{}' by {}", self.title, self.author);
     }
     
     // Override the default implementation
@@ -128,11 +146,11 @@ impl Printable for Article {
 
 // Implementing Printable for Tweet
 impl Printable for Tweet {
-    fn print(&self) {
-        match &self.reply_to {
-            Some(reply) => println!("🐦 @{} (replying to {}): {}", self.username, reply, self.content),
-            None => println!("🐦 @{}: {}", self.username, self.content),
-        }
+    fn render(&self, out: &mut String) {
+        let _ = match &self.reply_to {
+            Some(reply) => write!(out, "🐦 @{} (replying to {}): {}", self.username, reply, self.content),
+            None => write!(out, "🐦 @{}: {}", self.username, self.content),
+        };
     }
     
     fn type_name() -> &'static str {
@@ -142,8 +160,8 @@ impl Printable for Tweet {
 
 // Implementing Printable for Book
 impl Printable for Book {
-    fn print(&self) {
-        println!("📚 Book: '{}' by {} ({} pages)", self.title, self.author, self.pages);
+    fn render(&self, out: &mut String) {
+        let _ = write!(out, "📚 Book: '{}' by {} ({} pages)", self.title, self.author, self.pages);
     }
     
     fn type_name() -> &'static str {
@@ -270,6 +288,33 @@ pub fn create_printable_item(choice: u8) -> Box<dyn Printable> {
     }
 }
 
+// ===== 7b. ENUM DISPATCH =====
+//
+// UNDERSTANDING ENUM DISPATCH:
+// • A closed set of types wrapped in one enum; a match picks the implementation
+// • No vtable, no heap allocation, and the compiler can inline every arm
+// • Items are stored inline (size = largest variant), so a Vec is contiguous
+// • Trade-off: the set of types is fixed - adding one means editing the enum
+//
+// Measured against generics and trait objects in benches/dispatch_benchmarks.rs
+
+#[derive(Debug)]
+pub enum PrintableItem {
+    Article(Article),
+    Tweet(Tweet),
+    Book(Book),
+}
+
+impl Printable for PrintableItem {
+    fn render(&self, out: &mut String) {
+        match self {
+            PrintableItem::Article(article) => article.render(out),
+            PrintableItem::Tweet(tweet) => tweet.render(out),
+            PrintableItem::Book(book) => book.render(out),
+        }
+    }
+}
+
 // ===== 8. ADVANCED TRAIT FEATURES =====
 
 // Trait with associated types (more advanced than generics)
@@ -281,11 +326,123 @@ pub trait Iterator {
 
 // Trait with associated constants
 pub trait Geometry {
+    // A hand-rounded literal, kept as the example value implementors override
+    #[allow(clippy::approx_constant)]
     const PI: f64 = 3.14159;
     
     fn area(&self) -> f64;
 }
 
+// ===== 8b. GENERIC ASSOCIATED TYPES (GATs) =====
+//
+// THE LIMIT OF `type Item`:
+// The Iterator sketch above has one Item type, fixed for the whole iterator.
+// Items can't borrow from the iterator itself, because callers may keep
+// several at once:
+//
+//     let a = iter.next();
+//     let b = iter.next();   // `a` must still be valid here
+//
+// So an Iterator can't lend out a reused buffer, or overlapping &mut windows.
+//
+// UNDERSTANDING GATs:
+// • The associated type takes a parameter: `type Item<'a> where Self: 'a;`
+// • next() returns Self::Item<'_>, borrowing the iterator, so the previous
+//   item has to be gone before next() can be called again
+// • `where Self: 'a` is required: an item can only borrow Self for as long
+//   as Self lives
+// • Trade-off: no `for` loops or std adaptors - use `while let Some(x) = it.next()`
+//
+// This is called a LENDING (or streaming) iterator.
+
+pub trait LendingIterator {
+    type Item<'a>
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+}
+
+// Overlapping mutable windows: an Iterator can't return these, since two
+// windows alive at the same time would alias
+pub struct WindowsMut<'s, T> {
+    slice: &'s mut [T],
+    start: usize,
+    size: usize,
+}
+
+impl<'s, T> WindowsMut<'s, T> {
+    pub fn new(slice: &'s mut [T], size: usize) -> Self {
+        WindowsMut { slice, start: 0, size }
+    }
+}
+
+impl<T> LendingIterator for WindowsMut<'_, T> {
+    type Item<'a>
+        = &'a mut [T]
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<&mut [T]> {
+        let window = self.slice.get_mut(self.start..self.start + self.size)?;
+        self.start += 1;
+        Some(window)
+    }
+}
+
+// STREAMING PARSER: every entry borrows the one line buffer, so parsing a
+// file of any size allocates only as much as its longest line
+#[derive(Debug, PartialEq)]
+pub struct Entry<'a> {
+    pub line: usize,
+    pub key: &'a str,
+    pub value: &'a str,
+}
+
+pub struct EntryParser<R> {
+    reader: R,
+    buf: String,
+    line: usize,
+}
+
+impl<R: std::io::BufRead> EntryParser<R> {
+    pub fn new(reader: R) -> Self {
+        EntryParser { reader, buf: String::new(), line: 0 }
+    }
+}
+
+impl<R: std::io::BufRead> LendingIterator for EntryParser<R> {
+    type Item<'a>
+        = std::io::Result<Entry<'a>>
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        // Skip blank lines and # comments
+        loop {
+            self.buf.clear();
+            match self.reader.read_line(&mut self.buf) {
+                Ok(0) => return None,
+                Ok(_) => self.line += 1,
+                Err(err) => return Some(Err(err)),
+            }
+            let trimmed = self.buf.trim();
+            if !trimmed.is_empty() && !trimmed.starts_with('#') {
+                break;
+            }
+        }
+
+        let line = self.line;
+        Some(match self.buf.trim().split_once('=') {
+            Some((key, value)) => Ok(Entry { line, key: key.trim(), value: value.trim() }),
+            None => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("line {}: expected key = value", line),
+            )),
+        })
+    }
+}
+
 // ===== 9. TRAIT INHERITANCE =====
 
 // Trait that extends another trait
@@ -300,10 +457,752 @@ pub trait AdvancedPrintable: Printable {
 // Implementing the extended trait
 impl AdvancedPrintable for Article {}
 
+// ===== 9b. OPERATOR OVERLOADING =====
+//
+// UNDERSTANDING OPERATORS AS TRAITS:
+// • `a + b` is sugar for Add::add(a, b) - every overloadable operator is a
+//   trait in std::ops (Add, Sub, Mul, Neg, Index, AddAssign, ...)
+// • == and != come from PartialEq, < > <= >= from PartialOrd (std::cmp)
+// • `{}` formatting comes from fmt::Display
+//
+// WHY #[derive] ISN'T ENOUGH:
+// • derive only exists where there's one obvious meaning (Debug, Clone,
+//   PartialEq). What should `+` or `{}` mean for your type? Only you know
+// • derive(PartialOrd) compares fields in order: x first, then y. Vector2D
+//   orders by length instead, so it needs a hand-written impl
+// • A hand-written PartialOrd must agree with PartialEq: partial_cmp returns
+//   Some(Equal) exactly when the values are ==
+//
+// REFERENCE IMPLS:
+// • `impl Add for Matrix` takes both operands by value: `a + b` moves a and b
+// • `impl Add<&Matrix> for &Matrix` lets callers write `&a + &b` and keep both
+// • Vector2D is Copy, so by value is free; its Add<&Vector2D> impl is there
+//   so folds over .iter() read naturally
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vector2D {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Vector2D {
+    pub const fn new(x: f64, y: f64) -> Self {
+        Vector2D { x, y }
+    }
+
+    pub fn length(self) -> f64 {
+        self.x.hypot(self.y)
+    }
+}
+
+impl Add for Vector2D {
+    type Output = Vector2D;
+
+    fn add(self, other: Vector2D) -> Vector2D {
+        Vector2D::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+// Lets `vectors.iter().fold(Vector2D::default(), |sum, v| sum + v)` work
+impl Add<&Vector2D> for Vector2D {
+    type Output = Vector2D;
+
+    fn add(self, other: &Vector2D) -> Vector2D {
+        self + *other
+    }
+}
+
+impl Sub for Vector2D {
+    type Output = Vector2D;
+
+    fn sub(self, other: Vector2D) -> Vector2D {
+        Vector2D::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+// Scaling: the right-hand side doesn't have to be the same type
+impl Mul<f64> for Vector2D {
+    type Output = Vector2D;
+
+    fn mul(self, factor: f64) -> Vector2D {
+        Vector2D::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Vector2D {
+    type Output = Vector2D;
+
+    fn neg(self) -> Vector2D {
+        Vector2D::new(-self.x, -self.y)
+    }
+}
+
+// Longer vectors are greater. Different vectors of the same length (3,4) and
+// (4,3) are neither equal nor ordered, so partial_cmp returns None for them -
+// that's what makes it a PARTIAL order
+impl PartialOrd for Vector2D {
+    fn partial_cmp(&self, other: &Vector2D) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+        match self.length().partial_cmp(&other.length())? {
+            Ordering::Equal => None,
+            ordering => Some(ordering),
+        }
+    }
+}
+
+// Honors a precision: format!("{:.1}", v) gives "(1.0, 2.5)"
+impl Display for Vector2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "({:.*}, {:.*})", precision, self.x, precision, self.y),
+            None => write!(f, "({}, {})", self.x, self.y),
+        }
+    }
+}
+
+// A row-major matrix of any size. Not Copy, so the reference impls matter
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix { rows, cols, data: vec![0.0; rows * cols] }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut matrix = Matrix::zeros(size, size);
+        for i in 0..size {
+            matrix[(i, i)] = 1.0;
+        }
+        matrix
+    }
+
+    pub fn from_rows<const COLS: usize>(rows: &[[f64; COLS]]) -> Self {
+        Matrix { rows: rows.len(), cols: COLS, data: rows.concat() }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    // Operators can't return errors, so the fallible version is a method
+    pub fn checked_add(&self, other: &Matrix) -> Option<Matrix> {
+        if (self.rows, self.cols) != (other.rows, other.cols) {
+            return None;
+        }
+        let data = self.data.iter().zip(&other.data).map(|(a, b)| a + b).collect();
+        Some(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+
+    fn assert_same_shape(&self, other: &Matrix) {
+        assert!(
+            (self.rows, self.cols) == (other.rows, other.cols),
+            "cannot add a {}x{} matrix to a {}x{} matrix",
+            other.rows,
+            other.cols,
+            self.rows,
+            self.cols
+        );
+    }
+}
+
+// matrix[(row, col)]
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &f64 {
+        assert!(row < self.rows && col < self.cols, "index ({}, {}) out of bounds for a {}x{} matrix", row, col, self.rows, self.cols);
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        assert!(row < self.rows && col < self.cols, "index ({}, {}) out of bounds for a {}x{} matrix", row, col, self.rows, self.cols);
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+// &a + &b: borrows both, allocates the result. Panics if the shapes differ,
+// like slice indexing does
+impl Add<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn add(self, other: &Matrix) -> Matrix {
+        self.assert_same_shape(other);
+        self.checked_add(other).expect("shapes checked above")
+    }
+}
+
+// a + b: owns `a`, so its buffer can be reused instead of allocating
+impl Add for Matrix {
+    type Output = Matrix;
+
+    fn add(mut self, other: Matrix) -> Matrix {
+        self.assert_same_shape(&other);
+        for (a, b) in self.data.iter_mut().zip(&other.data) {
+            *a += b;
+        }
+        self
+    }
+}
+
+// Matrix product: (n x m) * (m x p) = (n x p)
+impl Mul<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: &Matrix) -> Matrix {
+        assert_eq!(self.cols, other.rows, "cannot multiply a {}x{} matrix by a {}x{} matrix", self.rows, self.cols, other.rows, other.cols);
+        let mut product = Matrix::zeros(self.rows, other.cols);
+        for row in 0..self.rows {
+            for col in 0..other.cols {
+                product[(row, col)] = (0..self.cols).map(|k| self[(row, k)] * other[(k, col)]).sum();
+            }
+        }
+        product
+    }
+}
+
+// One line per row, columns right-aligned:
+// [  1 2.5]
+// [ 10  -1]
+impl Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells: Vec<String> = match f.precision() {
+            Some(precision) => self.data.iter().map(|value| format!("{:.*}", precision, value)).collect(),
+            None => self.data.iter().map(|value| value.to_string()).collect(),
+        };
+        let width = cells.iter().map(String::len).max().unwrap_or(0);
+        for (i, row) in cells.chunks(self.cols.max(1)).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let row: Vec<String> = row.iter().map(|cell| format!("{:>width$}", cell, width = width)).collect();
+            write!(f, "[{}]", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+// ===== 9c. SEND, SYNC AND AUTO TRAITS =====
+//
+// UNDERSTANDING Send AND Sync:
+// • Send: a value can be MOVED to another thread
+// • Sync: a value can be SHARED between threads - T is Sync when &T is Send
+// • Both are marker traits with no methods; thread::spawn, Arc, Mutex and
+//   tokio::spawn put them in their bounds
+//
+// AUTO TRAITS:
+// • The compiler implements Send and Sync for a struct automatically when
+//   ALL of its fields are Send/Sync - there's nothing to derive
+// • One non-Send field makes the whole struct non-Send. That's how Rc and
+//   RefCell spread to every type (and every async block) that holds them
+//
+// WHY THE STD TYPES ARE WHAT THEY ARE:
+// ┌─────────────────────┬──────┬──────┬─────────────────────────────────────────┐
+// │ Type                │ Send │ Sync │ Why                                     │
+// ├─────────────────────┼──────┼──────┼─────────────────────────────────────────┤
+// │ Rc<T>               │  ❌  │  ❌  │ count updated without atomics           │
+// │ Arc<T>              │  ✅  │  ✅  │ atomic count (if T: Send + Sync)        │
+// │ Cell<T>, RefCell<T> │  ✅  │  ❌  │ unsynchronized mutation through &self   │
+// │ Mutex<T>            │  ✅  │  ✅  │ the lock makes &Mutex safe (if T: Send) │
+// │ MutexGuard<T>       │  ❌  │  ✅  │ must unlock on the thread that locked   │
+// │ *const T, *mut T    │  ❌  │  ❌  │ the compiler can't tell what they own   │
+// └─────────────────────┴──────┴──────┴─────────────────────────────────────────┘
+//
+// OPTING OUT AND IN:
+// • Out: add a PhantomData field of a type that lacks the trait -
+//   PhantomData<*const ()> removes both, PhantomData<Cell<()>> only Sync
+// • In: `unsafe impl Send for T {}` - you promise what the compiler couldn't
+//   check, e.g. that a raw pointer is uniquely owned (how Box and Vec do it)
+//
+// The errors learners hit most are collected as compile_fail doc tests on
+// assert_send and assert_sync: `cargo test --doc -p rust-basics` checks that
+// each one still fails to compile.
+
+/// Compiles only if `T` is `Send`; does nothing at runtime.
+///
+/// ```
+/// use rust_basics::smart_pointers::RequestStats;
+/// use rust_basics::traits::{assert_send, OwnedPtr};
+/// use std::sync::{Arc, Mutex};
+///
+/// assert_send::<Arc<Mutex<Vec<i32>>>>();
+/// assert_send::<RequestStats>(); // Cell and RefCell fields: Send, not Sync
+/// assert_send::<OwnedPtr<String>>();
+/// ```
+///
+/// # Common errors and their fixes
+///
+/// Moving an `Rc` into a thread - use `Arc`:
+///
+/// ```compile_fail,E0277
+/// use std::rc::Rc;
+///
+/// let shared = Rc::new(5);
+/// let clone = Rc::clone(&shared);
+/// std::thread::spawn(move || println!("{}", clone));
+/// ```
+///
+/// Putting a `RefCell` in an `Arc` - `Arc<T>` is only `Send` if `T` is
+/// `Sync`, so use `Arc<Mutex<T>>`:
+///
+/// ```compile_fail,E0277
+/// use std::cell::RefCell;
+/// use std::sync::Arc;
+///
+/// let shared = Arc::new(RefCell::new(Vec::new()));
+/// let clone = Arc::clone(&shared);
+/// std::thread::spawn(move || clone.borrow_mut().push(1));
+/// ```
+///
+/// Sending a `MutexGuard` to another thread - lock inside that thread:
+///
+/// ```compile_fail,E0277
+/// use std::sync::Mutex;
+///
+/// let lock = Mutex::new(0);
+/// let guard = lock.lock().unwrap();
+/// std::thread::scope(|s| {
+///     s.spawn(move || drop(guard));
+/// });
+/// ```
+///
+/// Moving a type marked with `PhantomData<*const ()>`:
+///
+/// ```compile_fail,E0277
+/// use rust_basics::traits::ThreadBound;
+///
+/// let handle = ThreadBound::new(1);
+/// std::thread::spawn(move || handle.id());
+/// ```
+///
+/// Holding an `Rc` across `.await` - the future keeps it, so the future isn't
+/// `Send` and `tokio::spawn` rejects it. Drop the `Rc` before awaiting, or use
+/// `Arc`:
+///
+/// ```compile_fail
+/// use std::future::Future;
+/// use std::rc::Rc;
+///
+/// fn spawn<F: Future + Send>(_future: F) {}
+///
+/// spawn(async {
+///     let name = Rc::new("task");
+///     std::future::ready(()).await;
+///     println!("{}", name);
+/// });
+/// ```
+pub fn assert_send<T: Send>() {}
+
+/// Compiles only if `T` is `Sync`; does nothing at runtime.
+///
+/// ```
+/// use rust_basics::traits::assert_sync;
+/// use std::sync::atomic::AtomicU32;
+/// use std::sync::Mutex;
+///
+/// assert_sync::<AtomicU32>();
+/// assert_sync::<Mutex<Vec<i32>>>();
+/// ```
+///
+/// # Common errors and their fixes
+///
+/// A `static` must be `Sync`, and `Cell` isn't - use an atomic:
+///
+/// ```compile_fail,E0277
+/// use std::cell::Cell;
+///
+/// static COUNTER: Cell<u32> = Cell::new(0);
+/// ```
+///
+/// Sharing a `Cell` with scoped threads - they only need `&Cell`, but that
+/// requires `Cell: Sync`:
+///
+/// ```compile_fail,E0277
+/// use std::cell::Cell;
+///
+/// let hits = Cell::new(0);
+/// std::thread::scope(|s| {
+///     s.spawn(|| hits.set(hits.get() + 1));
+/// });
+/// ```
+///
+/// `RequestStats` is `Send` but not `Sync` - it can move to another thread
+/// but not be shared with one:
+///
+/// ```compile_fail,E0277
+/// use rust_basics::smart_pointers::RequestStats;
+/// use rust_basics::traits::assert_sync;
+///
+/// assert_sync::<RequestStats>();
+/// ```
+pub fn assert_sync<T: Sync>() {}
+
+// OPTING OUT: bound to the thread that created it, like a GUI window handle
+#[derive(Debug)]
+pub struct ThreadBound {
+    id: u32,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl ThreadBound {
+    pub fn new(id: u32) -> Self {
+        ThreadBound { id, _not_send: PhantomData }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+// OPTING IN: a raw pointer makes this !Send and !Sync, but it owns its value
+// exactly like Box<T> does
+pub struct OwnedPtr<T> {
+    ptr: NonNull<T>,
+}
+
+impl<T> OwnedPtr<T> {
+    pub fn new(value: T) -> Self {
+        OwnedPtr { ptr: NonNull::from(Box::leak(Box::new(value))) }
+    }
+
+    pub fn get(&self) -> &T {
+        // SAFETY: ptr came from Box::leak and is freed only in Drop
+        unsafe { self.ptr.as_ref() }
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        // SAFETY: as in get(), and &mut self means no other borrow exists
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<T> Drop for OwnedPtr<T> {
+    fn drop(&mut self) {
+        // SAFETY: ptr came from Box::leak and nothing uses it after this
+        drop(unsafe { Box::from_raw(self.ptr.as_ptr()) });
+    }
+}
+
+// SAFETY: nothing else points to the T, so moving the OwnedPtr moves the T -
+// fine whenever T: Send. Sharing &OwnedPtr only hands out &T, so it needs T: Sync
+unsafe impl<T: Send> Send for OwnedPtr<T> {}
+unsafe impl<T: Sync> Sync for OwnedPtr<T> {}
+
+// ===== 9d. A RUNTIME PLUGIN REGISTRY =====
+//
+// TRAIT OBJECTS IN THE REAL WORLD:
+// A host program that runs plugins can't list their types in an enum (7b) or
+// a generic parameter - it doesn't know them when it's written. Each plugin
+// is a different type behind the same interface, found at runtime by name:
+// exactly the job of `&dyn Plugin`.
+//
+// HOW PLUGINS GET REGISTERED:
+// • inventory::collect!(PluginRegistration) declares the collection
+// • Each plugin adds itself next to its own code with inventory::submit!, in
+//   any file or even another crate - the host never changes
+// • The linker gathers the entries at build time; iteration order is
+//   unspecified, so plugins() sorts them
+// • The learning modules themselves are registered the same way (registry.rs)
+// • linkme's #[distributed_slice] does the same job with a plain static slice
+//
+// WHY `Plugin: Sync`:
+// Registered plugins are `&'static dyn Plugin` reachable from every thread,
+// so sharing them must be safe (see 9c).
+
+pub trait Plugin: Sync {
+    // Unique name used to look the plugin up
+    fn name(&self) -> &'static str;
+
+    fn description(&self) -> &'static str;
+
+    fn apply(&self, input: &str) -> String;
+}
+
+pub struct PluginRegistration(pub &'static dyn Plugin);
+
+inventory::collect!(PluginRegistration);
+
+// Every registered plugin, sorted by name
+pub fn plugins() -> Vec<&'static dyn Plugin> {
+    let mut plugins: Vec<_> = inventory::iter::<PluginRegistration>.into_iter().map(|registration| registration.0).collect();
+    plugins.sort_by_key(|plugin| plugin.name());
+    plugins
+}
+
+pub fn find_plugin(name: &str) -> Option<&'static dyn Plugin> {
+    plugins().into_iter().find(|plugin| plugin.name() == name)
+}
+
+#[derive(Debug, PartialEq, Error)]
+#[error("unknown plugin '{0}'")]
+pub struct UnknownPlugin(pub String);
+
+// Runs the named plugins in order, each on the previous one's output
+pub fn run_pipeline(names: &[&str], input: &str) -> Result<String, UnknownPlugin> {
+    // Look every name up first, so a typo fails before any work is done
+    let pipeline = names
+        .iter()
+        .map(|name| find_plugin(name).ok_or_else(|| UnknownPlugin(name.to_string())))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(pipeline.iter().fold(input.to_string(), |text, plugin| plugin.apply(&text)))
+}
+
+// THE PLUGINS: each registers itself, nothing else refers to them by type
+
+pub struct Uppercase;
+
+impl Plugin for Uppercase {
+    fn name(&self) -> &'static str {
+        "uppercase"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts text to upper case"
+    }
+
+    fn apply(&self, input: &str) -> String {
+        input.to_uppercase()
+    }
+}
+
+inventory::submit! { PluginRegistration(&Uppercase) }
+
+pub struct ReverseWords;
+
+impl Plugin for ReverseWords {
+    fn name(&self) -> &'static str {
+        "reverse-words"
+    }
+
+    fn description(&self) -> &'static str {
+        "Reverses the order of the words"
+    }
+
+    fn apply(&self, input: &str) -> String {
+        input.split_whitespace().rev().collect::<Vec<_>>().join(" ")
+    }
+}
+
+inventory::submit! { PluginRegistration(&ReverseWords) }
+
+pub struct Rot13;
+
+impl Plugin for Rot13 {
+    fn name(&self) -> &'static str {
+        "rot13"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rotates ASCII letters by 13 places (applying it twice undoes it)"
+    }
+
+    fn apply(&self, input: &str) -> String {
+        input
+            .chars()
+            .map(|c| match c {
+                'a'..='z' => (b'a' + (c as u8 - b'a' + 13) % 26) as char,
+                'A'..='Z' => (b'A' + (c as u8 - b'A' + 13) % 26) as char,
+                c => c,
+            })
+            .collect()
+    }
+}
+
+inventory::submit! { PluginRegistration(&Rot13) }
+
+pub struct SqueezeWhitespace;
+
+impl Plugin for SqueezeWhitespace {
+    fn name(&self) -> &'static str {
+        "squeeze"
+    }
+
+    fn description(&self) -> &'static str {
+        "Trims the text and collapses runs of whitespace into one space"
+    }
+
+    fn apply(&self, input: &str) -> String {
+        input.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
+
+inventory::submit! { PluginRegistration(&SqueezeWhitespace) }
+
+// ===== 9e. DOWNCASTING WITH Any =====
+//
+// UNDERSTANDING Any:
+// • std::any::Any is implemented for every 'static type. A &dyn Any or
+//   Box<dyn Any> remembers the concrete type's TypeId in its vtable
+// • downcast_ref::<T>() / downcast_mut::<T>() compare TypeIds and hand back
+//   Some(&T) only on an exact match - no casts, no unsafe, no guessing
+// • Box<dyn Any>::downcast::<T>() takes ownership: Ok(Box<T>) or the
+//   original box back in Err, so nothing is lost on a miss
+// • Only 'static types: a &str borrowed from a local can't be erased,
+//   because the TypeId couldn't say how long the borrow lives
+//
+// PITFALL - THE BOX IS Any TOO:
+// • Box<dyn Any> is itself 'static, so `&boxed` coerces to a &dyn Any
+//   describing the Box, not the value. Downcast through the box: (*boxed)
+//   or boxed.downcast_ref(), which goes through Deref
+//
+// WHERE IT FITS:
+// • Type-keyed storage: HashMap<TypeId, Box<dyn Any>> - a typed extension
+//   map. actix-web's req.extensions(), http::Extensions and bevy's resources
+//   all work like TypedExtensions below: middleware stores a value, the
+//   handler asks for it by type
+// • Panic payloads (Box<dyn Any + Send>, see panics.rs)
+//
+// WHEN IT'S A DESIGN SMELL:
+// • A function that downcasts a trait object to a list of known types is a
+//   match over a closed set - with the compiler's exhaustiveness check
+//   thrown away. A new type silently falls through to the default
+// • If the set of types is known, use an enum (7b); if it's open, put the
+//   behavior in the trait (7, 9d). Reach for Any only when the code that
+//   stores a value truly can't name its type
+
+// Describes a value whose type was erased
+pub fn describe_any(value: &dyn Any) -> String {
+    if let Some(n) = value.downcast_ref::<i32>() {
+        format!("i32 {}", n)
+    } else if let Some(text) = value.downcast_ref::<String>() {
+        format!("String {:?}", text)
+    } else if let Some(text) = value.downcast_ref::<&'static str>() {
+        format!("&str {:?}", text)
+    } else if let Some(book) = value.downcast_ref::<Book>() {
+        format!("Book '{}'", book.title)
+    } else {
+        "something else".to_string()
+    }
+}
+
+// Sums every i32 in a mixed bag, ignoring the rest
+pub fn sum_i32s(values: &[Box<dyn Any>]) -> i32 {
+    values.iter().filter_map(|value| value.downcast_ref::<i32>()).sum()
+}
+
+// Takes the Strings out by value; everything else is handed back
+pub fn take_strings(values: Vec<Box<dyn Any>>) -> (Vec<String>, Vec<Box<dyn Any>>) {
+    let mut strings = Vec::new();
+    let mut rest = Vec::new();
+    for value in values {
+        match value.downcast::<String>() {
+            Ok(string) => strings.push(*string),
+            Err(other) => rest.push(other),
+        }
+    }
+    (strings, rest)
+}
+
+// One value per type, looked up by the type itself
+#[derive(Default)]
+pub struct TypedExtensions {
+    map: HashMap<TypeId, Box<dyn Any>>,
+}
+
+impl TypedExtensions {
+    pub fn new() -> Self {
+        TypedExtensions::default()
+    }
+
+    // Returns the value of the same type that was there before
+    pub fn insert<T: 'static>(&mut self, value: T) -> Option<T> {
+        self.map
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .and_then(|previous| previous.downcast().ok())
+            .map(|previous| *previous)
+    }
+
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.map.get(&TypeId::of::<T>()).and_then(|value| value.downcast_ref())
+    }
+
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.map.get_mut(&TypeId::of::<T>()).and_then(|value| value.downcast_mut())
+    }
+
+    pub fn remove<T: 'static>(&mut self) -> Option<T> {
+        self.map.remove(&TypeId::of::<T>()).and_then(|value| value.downcast().ok()).map(|value| *value)
+    }
+
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.map.contains_key(&TypeId::of::<T>())
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+// What a middleware chain might attach to a request. Newtypes keep two
+// values of the same underlying type (two u64s) from overwriting each other
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RequestId(pub u64);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrentUser(pub String);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryCount(pub u32);
+
+// The handler only names the types it needs
+pub fn handle_request(extensions: &TypedExtensions) -> String {
+    let id = extensions.get::<RequestId>().map_or(0, |id| id.0);
+    match extensions.get::<CurrentUser>() {
+        Some(user) => format!("request #{}: hello, {}", id, user.0),
+        None => format!("request #{}: 401 unauthorized", id),
+    }
+}
+
+// THE SMELL: a closed set of types, checked at runtime. Anything that isn't
+// one of the two - including a type added next year - quietly gets None
+pub fn word_count_by_downcast(item: &dyn Any) -> Option<usize> {
+    if let Some(article) = item.downcast_ref::<Article>() {
+        return Some(article.content.split_whitespace().count());
+    }
+    item.downcast_ref::<Tweet>().map(|tweet| tweet.content.split_whitespace().count())
+}
+
+// THE FIX: the enum already lists the types, and match checks them all
+pub fn word_count(item: &PrintableItem) -> Option<usize> {
+    match item {
+        PrintableItem::Article(article) => Some(article.content.split_whitespace().count()),
+        PrintableItem::Tweet(tweet) => Some(tweet.content.split_whitespace().count()),
+        PrintableItem::Book(_) => None,
+    }
+}
+
 // ===== 10. DEMONSTRATION FUNCTION =====
+//
+// traits_report() builds everything the demonstration shows as a Report (see
+// report.rs); demonstrate_traits() just prints it. The print_* helpers above
+// write to stdout, so the report records what they would print instead.
 
-pub fn demonstrate_traits() {
-    println!("🦀 RUST TRAIT SYSTEM DEEP STUDY 🦀\n");
+// What item.print() would print
+fn rendered(item: &dyn Printable) -> String {
+    let mut line = String::new();
+    item.render(&mut line);
+    line
+}
+
+pub fn traits_report() -> Report {
+    let mut report = Report::new("🦀 RUST TRAIT SYSTEM DEEP STUDY 🦀");
     
     // ===== CREATING SAMPLE DATA =====
     let article = Article {
@@ -325,111 +1224,504 @@ pub fn demonstrate_traits() {
     };
     
     // ===== DEMONSTRATING BASIC TRAIT USAGE =====
-    println!("1️⃣ BASIC TRAIT IMPLEMENTATIONS:");
-    article.print();
-    tweet.print();
-    book.print();
+    report
+        .section("1️⃣ BASIC TRAIT IMPLEMENTATIONS:")
+        .example("article.print()", rendered(&article))
+        .example("tweet.print()", rendered(&tweet))
+        .example("book.print()", rendered(&book));
     
-    println!("\n2️⃣ DEFAULT TRAIT METHODS:");
-    article.print_twice();
+    // Article overrides print_twice with its own framing lines
+    report
+        .section("2️⃣ DEFAULT TRAIT METHODS:")
+        .text("🔄 Printing article twice:")
+        .text(rendered(&article))
+        .text("   (second time)")
+        .text(rendered(&article));
     
-    println!("\n3️⃣ ASSOCIATED FUNCTIONS:");
-    println!("Article type: {}", Article::type_name());
-    println!("Tweet type: {}", Tweet::type_name());
-    println!("Book type: {}", Book::type_name());
+    report
+        .section("3️⃣ ASSOCIATED FUNCTIONS:")
+        .example("Article::type_name()", Article::type_name())
+        .example("Tweet::type_name()", Tweet::type_name())
+        .example("Book::type_name()", Book::type_name());
     
     // ===== DEMONSTRATING GENERIC TRAIT BOUNDS =====
-    println!("\n4️⃣ GENERIC FUNCTIONS WITH TRAIT BOUNDS:");
     // USING: <T: Trait> - Compile-time polymorphism
     // Each call gets its own optimized version (monomorphization)
-    print_item(&article);   // Creates print_item::<Article>
-    print_item(&tweet);     // Creates print_item::<Tweet>
-    print_item(&book);      // Creates print_item::<Book>
+    report
+        .section("4️⃣ GENERIC FUNCTIONS WITH TRAIT BOUNDS:")
+        .example("print_item::<Article>", rendered(&article))
+        .example("print_item::<Tweet>", rendered(&tweet))
+        .example("print_item::<Book>", rendered(&book));
     
-    println!("\n5️⃣ MULTIPLE TRAIT BOUNDS:");
     // USING: <T> where T: Multiple + Bounds - Complex constraints
     // Only types implementing BOTH traits can be used
-    print_and_summarize(&article);  // ✅ Article implements both
-    print_and_summarize(&tweet);    // ✅ Tweet implements both
-    // print_and_summarize(&book);  // ❌ Book doesn't implement Summarizable<String>
+    report
+        .section("5️⃣ MULTIPLE TRAIT BOUNDS:")
+        .example("print_and_summarize(&article)", format!("{} | {}", rendered(&article), article.summarize()))
+        .example("print_and_summarize(&tweet)", format!("{} | {}", rendered(&tweet), tweet.summarize()))
+        .text("❌ print_and_summarize(&book): Book doesn't implement Summarizable<String>");
     
-    println!("\n6️⃣ IMPL TRAIT SYNTAX:");
     // USING: &impl Trait - Syntactic sugar for generics
     // Same performance as generics, cleaner syntax
-    print_with_impl_trait(&book);
+    report
+        .section("6️⃣ IMPL TRAIT SYNTAX:")
+        .example("print_with_impl_trait(&book)", rendered(&book));
     
     // ===== DEMONSTRATING SUMMARIZABLE TRAIT =====
-    println!("\n7️⃣ SUMMARIZABLE TRAIT:");
-    println!("Article summary: {}", article.summarize());
-    println!("Tweet summary: {}", tweet.summarize());
-    println!("Book pages: {}", book.summarize());
-    
     // With context (requires Display trait bound)
-    println!("Article with context: {}", article.summarize_with_context("Latest"));
+    report
+        .section("7️⃣ SUMMARIZABLE TRAIT:")
+        .example("article.summarize()", article.summarize())
+        .example("tweet.summarize()", tweet.summarize())
+        .example("book.summarize()", book.summarize())
+        .example("article.summarize_with_context(\"Latest\")", article.summarize_with_context("Latest"));
     
     // ===== DEMONSTRATING DYNAMIC DISPATCH =====
-    println!("\n8️⃣ DYNAMIC DISPATCH WITH TRAIT OBJECTS:");
+    let dispatch = report.section("8️⃣ DYNAMIC DISPATCH WITH TRAIT OBJECTS:");
     
     // Using trait objects directly
     // USING: &dyn Trait - Runtime polymorphism via vtable
-    print_dynamic(&article);  // Calls Article::print via vtable
-    print_dynamic(&tweet);    // Calls Tweet::print via vtable
-    print_dynamic(&book);     // Calls Book::print via vtable
+    dispatch
+        .example("print_dynamic(&article)", rendered(&article))
+        .example("print_dynamic(&tweet)", rendered(&tweet))
+        .example("print_dynamic(&book)", rendered(&book));
     
     // Vector of trait objects - heterogeneous collection
     // USING: Vec<&dyn Trait> - Store different types together
     // This is impossible with generics! Each element can be a different concrete type
     let printables: Vec<&dyn Printable> = vec![&article, &tweet, &book];
-    println!("\n📦 Processing heterogeneous collection:");
+    dispatch.text("📦 Processing heterogeneous collection:");
     for (i, item) in printables.iter().enumerate() {
-        println!("Item {}: ", i + 1);
-        item.print();  // Runtime dispatch - don't know type until runtime
+        dispatch.example(format!("Item {}", i + 1), rendered(*item)); // Runtime dispatch
     }
     
     // Boxed trait objects
     // USING: Vec<Box<dyn Trait>> - Owned trait objects
     // Heap-allocated, can be moved, returned from functions
-    println!("\n📦 Boxed trait objects:");
     let boxed_items: Vec<Box<dyn Printable>> = vec![
         create_printable_item(1),  // Returns Box<Article>
         create_printable_item(2),  // Returns Box<Tweet>
         create_printable_item(3),  // Returns Box<Book>
     ];
-    
+    dispatch.text("📦 Boxed trait objects:");
     for (i, item) in boxed_items.iter().enumerate() {
-        println!("Boxed item {}: ", i + 1);
-        item.print();  // Deref to &dyn Printable, then vtable call
+        dispatch.example(format!("Boxed item {}", i + 1), rendered(item.as_ref())); // Deref, then vtable call
+    }
+    
+    // Enum dispatch
+    // USING: enum + match - Closed set of types, no vtable, no Box
+    let items = vec![
+        PrintableItem::Article(article.clone()),
+        PrintableItem::Tweet(tweet.clone()),
+    ];
+    dispatch.text("📦 Enum dispatch:");
+    for item in &items {
+        dispatch.text(rendered(item)); // Static match on the variant
     }
     
     // ===== DEMONSTRATING ADVANCED FEATURES =====
-    println!("\n9️⃣ TRAIT INHERITANCE:");
-    article.print_with_border();
-    
-    println!("\n🔟 KEY CONCEPTS SUMMARY:");
-    println!("✅ Traits define shared behavior");
-    println!("✅ Static dispatch: compile-time polymorphism (generics)");
-    println!("✅ Dynamic dispatch: runtime polymorphism (trait objects)");
-    println!("✅ Trait bounds constrain generic parameters");
-    println!("✅ Default implementations provide fallback behavior");
-    println!("✅ Associated types and constants add flexibility");
-    println!("✅ Trait inheritance enables trait composition");
-    
-    println!("\n🎯 STATIC vs DYNAMIC DISPATCH:");
-    println!("• Static (T: Trait): Fast, monomorphization, compile-time");
-    println!("• Dynamic (&dyn Trait): Flexible, vtable lookup, runtime");
-    
-    println!("\n📊 TRAIT USAGE DECISION TREE:");
-    println!("┌─ Need different types at runtime? ────── YES → &dyn Trait / Box<dyn Trait>");
-    println!("│");
-    println!("└─ NO → Compile-time known types");
-    println!("    ├─ Simple function parameter? ───── YES → &impl Trait");
-    println!("    ├─ Complex constraints? ─────────── YES → <T> where T: Multiple + Bounds");
-    println!("    ├─ Performance critical? ────────── YES → <T: Trait>");
-    println!("    └─ Default choice ─────────────────────── <T: Trait>");
-    
-    println!("\n🚀 PERFORMANCE COMPARISON:");
-    println!("• <T: Trait>      : Zero-cost ✨ (inlined, optimized)");
-    println!("• &impl Trait     : Zero-cost ✨ (same as above)");
-    println!("• &dyn Trait      : Small cost 📊 (vtable lookup)");
-    println!("• Box<dyn Trait>  : Higher cost 💰 (heap allocation + vtable)");
-}
\ No newline at end of file
+    report
+        .section("9️⃣ TRAIT INHERITANCE:")
+        .text("==================")
+        .text(rendered(&article))
+        .text("==================");
+    
+    let gats = report.section("🔟 GENERIC ASSOCIATED TYPES (LENDING ITERATORS):");
+    // USING: type Item<'a> - each item borrows the iterator
+    let mut totals = [1, 2, 3, 4, 5];
+    let mut windows = WindowsMut::new(&mut totals, 2);
+    while let Some(window) = windows.next() {
+        window[1] += window[0]; // overlapping &mut windows: running total
+    }
+    gats.example("Running totals via WindowsMut", format!("{:?}", totals));
+    
+    let config = "# server\nhost = localhost\n\nport = 8080\nbroken line\n";
+    let mut parser = EntryParser::new(std::io::Cursor::new(config));
+    while let Some(entry) = parser.next() {
+        match entry {
+            Ok(entry) => gats.example("Entry borrowed from the line buffer", format!("{:?}", entry)),
+            Err(err) => gats.example("Parse error", err),
+        };
+    }
+    
+    let operators = report.section("1️⃣1️⃣ OPERATOR OVERLOADING:");
+    // USING: impl Add/Mul/Index/PartialOrd/Display - operators are trait methods
+    let a = Vector2D::new(3.0, 4.0);
+    let b = Vector2D::new(1.0, -2.0);
+    operators
+        .example("a + b", a + b)
+        .example("a - b", a - b)
+        .example("a * 0.5", a * 0.5)
+        .example("-b", -b)
+        .example("|a|", a.length())
+        .example("a > b", a > b)
+        .example("format!(\"{:.1}\", b)", format!("{:.1}", b))
+        .example(
+            "(3,4).partial_cmp(&(4,3))",
+            format!("{:?} - same length, different vectors", a.partial_cmp(&Vector2D::new(4.0, 3.0))),
+        );
+    let path = [a, b, Vector2D::new(-1.0, 1.0)];
+    operators.example("Sum of a path via Add<&Vector2D>", path.iter().fold(Vector2D::default(), |sum, step| sum + step));
+    
+    let mut m = Matrix::from_rows(&[[1.0, 2.5], [10.0, -1.0]]);
+    let identity = Matrix::identity(2);
+    operators
+        .example("m", &m)
+        .example("&m + &identity (both still usable)", &m + &identity)
+        .example("&m * &identity == m", &m * &identity == m);
+    m[(1, 1)] = 4.0; // IndexMut
+    operators
+        .example("m[(1, 1)] after m[(1, 1)] = 4", m[(1, 1)])
+        .example("2x2 + 3x3 checked", format!("{:?}", m.checked_add(&Matrix::identity(3))));
+    
+    let auto_traits = report.section("1️⃣2️⃣ SEND, SYNC AND AUTO TRAITS:");
+    // USING: T: Send / T: Sync bounds - checked at compile time, free at runtime
+    assert_send::<std::sync::Arc<std::sync::Mutex<Vec<i32>>>>();
+    assert_sync::<std::sync::Arc<std::sync::Mutex<Vec<i32>>>>();
+    assert_send::<RequestStats>();
+    auto_traits.text("Compile-time checks passed: Arc<Mutex<T>> is Send + Sync, RequestStats is Send");
+    
+    let stats = RequestStats::new();
+    stats.record("/home");
+    let hits = std::thread::spawn(move || {
+        stats.record("/moved"); // moved, not shared: Send is enough
+        stats.hits()
+    })
+    .join()
+    .unwrap();
+    auto_traits.example("RequestStats moved to another thread, hits there", hits);
+    
+    let mut owned = OwnedPtr::new(vec![1, 2, 3]);
+    owned.get_mut().push(4);
+    let len = std::thread::spawn(move || owned.get().len()).join().unwrap();
+    auto_traits.example("OwnedPtr (unsafe impl Send) crossed threads, len", len);
+    
+    let handle = ThreadBound::new(7);
+    auto_traits
+        .text(format!("ThreadBound #{} stays on this thread (PhantomData<*const ()>)", handle.id()))
+        .text("📏 Compile-fail catalogue: cargo test --doc -p rust-basics traits::assert");
+    
+    let registry = report.section("1️⃣3️⃣ RUNTIME PLUGIN REGISTRY:");
+    // USING: inventory + &'static dyn Plugin - types unknown to the host, found by name
+    for plugin in plugins() {
+        registry.text(format!("🔌 {:<14} {}", plugin.name(), plugin.description()));
+    }
+    let input = "  hello   plugin   world ";
+    for pipeline in [&["squeeze", "reverse-words"][..], &["squeeze", "uppercase", "rot13"], &["squeeze", "shout"]] {
+        match run_pipeline(pipeline, input) {
+            Ok(output) => registry.example(format!("{:?}", pipeline), format!("{:?}", output)),
+            Err(err) => registry.example(format!("{:?}", pipeline), format!("error: {}", err)),
+        };
+    }
+    
+    let downcasting = report.section("1️⃣4️⃣ DOWNCASTING WITH Any:");
+    // USING: Box<dyn Any> + downcast_ref/downcast - the type is checked at runtime
+    let bag: Vec<Box<dyn Any>> = vec![
+        Box::new(7),
+        Box::new("borrowed"),
+        Box::new(String::from("owned")),
+        Box::new(35),
+        Box::new(2.5_f64),
+    ];
+    for value in &bag {
+        downcasting.text(format!("🧳 {}", describe_any(value.as_ref())));
+    }
+    downcasting.example("sum_i32s(&bag)", sum_i32s(&bag));
+    let boxed: Box<dyn Any> = Box::new(7);
+    downcasting
+        .example("(&boxed as &dyn Any).is::<i32>()", (&boxed as &dyn Any).is::<i32>())
+        .example("(*boxed).is::<i32>()", (*boxed).is::<i32>());
+    let (strings, rest) = take_strings(bag);
+    downcasting.example("take_strings(bag)", format!("{:?} + {} others handed back", strings, rest.len()));
+
+    let mut extensions = TypedExtensions::new();
+    downcasting.example("handle_request(&extensions)", handle_request(&extensions));
+    extensions.insert(RequestId(17));
+    extensions.insert(CurrentUser("ferris".to_string()));
+    extensions.insert(RetryCount(0));
+    if let Some(retries) = extensions.get_mut::<RetryCount>() {
+        retries.0 += 1;
+    }
+    downcasting
+        .example("after auth middleware", handle_request(&extensions))
+        .example("extensions.get::<RetryCount>()", format!("{:?}", extensions.get::<RetryCount>()))
+        .example("extensions.insert(RequestId(18))", format!("{:?}", extensions.insert(RequestId(18))));
+
+    let sample = Book { title: "Programming Rust".to_string(), author: "Jim Blandy".to_string(), pages: 624 };
+    let tweet_item = PrintableItem::Tweet(Tweet { username: "rustlang".to_string(), content: "Downcasting is a last resort".to_string(), reply_to: None });
+    downcasting
+        .example("word_count_by_downcast(&book)", format!("{:?}", word_count_by_downcast(&sample)))
+        .example("word_count_by_downcast(&PrintableItem::Tweet(..))", format!("{:?}  ← smell: wrapped, so unrecognised", word_count_by_downcast(&tweet_item)))
+        .example("word_count(&PrintableItem::Tweet(..))", format!("{:?}", word_count(&tweet_item)));
+
+    report
+        .section("🔑 KEY CONCEPTS SUMMARY:")
+        .check("Traits define shared behavior")
+        .check("Static dispatch: compile-time polymorphism (generics)")
+        .check("Dynamic dispatch: runtime polymorphism (trait objects)")
+        .check("Trait bounds constrain generic parameters")
+        .check("Default implementations provide fallback behavior")
+        .check("Associated types and constants add flexibility")
+        .check("Trait inheritance enables trait composition")
+        .check("GATs let associated types borrow from self (lending iterators)")
+        .check("Operators are traits: impl Add, Index, PartialOrd, Display yourself")
+        .check("Send/Sync are auto traits: a type gets them when all its fields have them")
+        .check("Plugin registries: trait objects for types the host never names")
+        .check("Any + TypeId: type-keyed storage; downcasting a closed set is a smell");
+    
+    report
+        .section("🎯 STATIC vs DYNAMIC DISPATCH:")
+        .bullet("Static (T: Trait): Fast, monomorphization, compile-time")
+        .bullet("Dynamic (&dyn Trait): Flexible, vtable lookup, runtime");
+    
+    report
+        .section("📊 TRAIT USAGE DECISION TREE:")
+        .text("┌─ Need different types at runtime? ────── YES → &dyn Trait / Box<dyn Trait>")
+        .text("│")
+        .text("└─ NO → Compile-time known types")
+        .text("    ├─ Simple function parameter? ───── YES → &impl Trait")
+        .text("    ├─ Complex constraints? ─────────── YES → <T> where T: Multiple + Bounds")
+        .text("    ├─ Performance critical? ────────── YES → <T: Trait>")
+        .text("    └─ Default choice ─────────────────────── <T: Trait>");
+    
+    report
+        .section("🚀 PERFORMANCE COMPARISON:")
+        .bullet("<T: Trait>      : Zero-cost ✨ (inlined, optimized)")
+        .bullet("&impl Trait     : Zero-cost ✨ (same as above)")
+        .bullet("&dyn Trait      : Small cost 📊 (vtable lookup)")
+        .bullet("Box<dyn Trait>  : Higher cost 💰 (heap allocation + vtable)")
+        .bullet("enum + match    : Zero-cost ✨ (closed set of types, no heap)")
+        .text("📏 Measure it: cargo bench -p rust-basics --bench dispatch_benchmarks");
+    
+    report
+}
+
+pub fn demonstrate_traits() {
+    print!("{}", traits_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct TraitsModule;
+
+impl LearningModule for TraitsModule {
+    fn number(&self) -> u8 {
+        1
+    }
+
+    fn name(&self) -> &'static str {
+        "traits"
+    }
+
+    fn title(&self) -> &'static str {
+        "TRAIT SYSTEM DEEP STUDY"
+    }
+
+    fn description(&self) -> &'static str {
+        "Trait System"
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_traits() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { traits_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&TraitsModule) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_records_what_each_dispatch_style_printed() {
+        let report = traits_report();
+        let basics = report.find("BASIC TRAIT IMPLEMENTATIONS").unwrap();
+        assert_eq!(basics.output("book.print()"), Some("📚 Book: 'Programming Rust' by Jim Blandy (624 pages)"));
+
+        // Static and dynamic dispatch reach the same render()
+        let generic: Vec<_> = report.find("GENERIC FUNCTIONS").unwrap().outputs().collect();
+        let dynamic: Vec<_> = report.find("DYNAMIC DISPATCH").unwrap().outputs().take(3).collect();
+        assert_eq!(generic, dynamic);
+
+        let associated = report.find("ASSOCIATED FUNCTIONS").unwrap();
+        assert_eq!(associated.output("Tweet::type_name()"), Some("Tweet"));
+        assert_eq!(report.find("OPERATOR OVERLOADING").unwrap().output("a + b"), Some("(4, 2)"));
+    }
+
+    #[test]
+    fn owned_ptr_moves_between_threads_and_drops_once() {
+        let value = std::sync::Arc::new(());
+        let mut owned = OwnedPtr::new((std::sync::Arc::clone(&value), 1));
+        owned.get_mut().1 += 1;
+        let n = std::thread::spawn(move || owned.get().1).join().unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(std::sync::Arc::strong_count(&value), 1, "dropped on the other thread");
+    }
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = Vector2D::new(1.0, 2.0);
+        let b = Vector2D::new(3.0, -1.0);
+        assert_eq!(a + b, Vector2D::new(4.0, 1.0));
+        assert_eq!(<Vector2D as Add<&Vector2D>>::add(a, &b), a + b);
+        assert_eq!(a - b, Vector2D::new(-2.0, 3.0));
+        assert_eq!(a * 2.0, Vector2D::new(2.0, 4.0));
+        assert_eq!(-a, Vector2D::new(-1.0, -2.0));
+
+        let sum = [a, b, a].iter().fold(Vector2D::default(), |sum, v| sum + v);
+        assert_eq!(sum, Vector2D::new(5.0, 3.0));
+    }
+
+    #[test]
+    fn vectors_are_ordered_by_length() {
+        let short = Vector2D::new(1.0, 0.0);
+        let long = Vector2D::new(0.0, -5.0);
+        assert!(short < long);
+        assert!(long >= short);
+        // derive(PartialOrd) would compare x first and say the opposite
+        assert!(short.x > long.x);
+
+        // Same length, different vectors: unordered but consistent with ==
+        let (a, b) = (Vector2D::new(3.0, 4.0), Vector2D::new(4.0, 3.0));
+        assert_eq!(a.partial_cmp(&b), None);
+        assert_ne!(a, b);
+        assert_eq!(a.partial_cmp(&a), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn vector_display_honors_precision() {
+        let v = Vector2D::new(1.0, 2.25);
+        assert_eq!(v.to_string(), "(1, 2.25)");
+        assert_eq!(format!("{:.1}", v), "(1.0, 2.2)");
+    }
+
+    #[test]
+    fn matrix_index_and_index_mut() {
+        let mut m = Matrix::zeros(2, 3);
+        m[(1, 2)] = 7.0;
+        assert_eq!(m[(1, 2)], 7.0);
+        assert_eq!(m[(0, 0)], 0.0);
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "index (2, 0) out of bounds for a 2x3 matrix")]
+    fn matrix_index_out_of_bounds_panics() {
+        let _ = Matrix::zeros(2, 3)[(2, 0)];
+    }
+
+    #[test]
+    fn matrix_addition_by_value_and_by_reference() {
+        let a = Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::identity(2);
+        let expected = Matrix::from_rows(&[[2.0, 2.0], [3.0, 5.0]]);
+
+        assert_eq!(&a + &b, expected);
+        assert_eq!(a.checked_add(&b), Some(expected.clone()));
+        // a and b are still usable after &a + &b
+        assert_eq!(a + b, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add a 3x2 matrix to a 2x2 matrix")]
+    fn adding_mismatched_shapes_panics() {
+        let a = Matrix::zeros(2, 2);
+        assert_eq!(a.checked_add(&Matrix::zeros(3, 2)), None);
+        let _ = &a + &Matrix::zeros(3, 2);
+    }
+
+    #[test]
+    fn matrix_product_and_display() {
+        let a = Matrix::from_rows(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = Matrix::from_rows(&[[1.0], [0.0], [-1.0]]);
+        assert_eq!(&a * &b, Matrix::from_rows(&[[-2.0], [-2.0]]));
+        assert_eq!(&a * &Matrix::identity(3), a);
+
+        let m = Matrix::from_rows(&[[1.0, 2.5], [10.0, -1.0]]);
+        assert_eq!(m.to_string(), "[  1 2.5]\n[ 10  -1]");
+        assert_eq!(format!("{:.1}", Matrix::identity(2)), "[1.0 0.0]\n[0.0 1.0]");
+    }
+
+    #[test]
+    fn plugins_are_registered_once_and_sorted() {
+        let names: Vec<_> = plugins().iter().map(|plugin| plugin.name()).collect();
+        assert_eq!(names, ["reverse-words", "rot13", "squeeze", "uppercase"]);
+        assert_eq!(find_plugin("rot13").map(|plugin| plugin.name()), Some("rot13"));
+        assert!(find_plugin("ROT13").is_none());
+    }
+
+    #[test]
+    fn pipeline_applies_plugins_in_order() {
+        let input = "  Hello   World ";
+        assert_eq!(run_pipeline(&[], input), Ok(input.to_string()));
+        assert_eq!(run_pipeline(&["squeeze", "reverse-words"], input), Ok("World Hello".to_string()));
+        assert_eq!(run_pipeline(&["rot13", "rot13"], input), Ok(input.to_string()));
+        assert_eq!(run_pipeline(&["squeeze", "rot13", "uppercase"], input), Ok("URYYB JBEYQ".to_string()));
+    }
+
+    #[test]
+    fn downcasting_matches_exact_types_only() {
+        assert_eq!(describe_any(&5_i32), "i32 5");
+        assert_eq!(describe_any(&5_i64), "something else", "i64 is not i32");
+        assert_eq!(describe_any(&"hi"), "&str \"hi\"");
+        assert_eq!(describe_any(&String::from("hi")), "String \"hi\"");
+
+        let bag: Vec<Box<dyn Any>> = vec![Box::new(1), Box::new(String::from("a")), Box::new(2), Box::new(3_u8)];
+        assert_eq!(sum_i32s(&bag), 3);
+        let (strings, rest) = take_strings(bag);
+        assert_eq!(strings, ["a"]);
+        assert_eq!(rest.len(), 3);
+    }
+
+    #[test]
+    fn a_box_coerces_to_a_different_any() {
+        let boxed: Box<dyn Any> = Box::new(7_i32);
+        assert!(!(&boxed as &dyn Any).is::<i32>());
+        assert!((&boxed as &dyn Any).is::<Box<dyn Any>>());
+        assert_eq!(boxed.downcast_ref::<i32>(), Some(&7));
+    }
+
+    #[test]
+    fn typed_extensions_store_one_value_per_type() {
+        let mut extensions = TypedExtensions::new();
+        assert!(extensions.is_empty());
+        assert_eq!(handle_request(&extensions), "request #0: 401 unauthorized");
+
+        assert_eq!(extensions.insert(RequestId(1)), None);
+        assert_eq!(extensions.insert(RequestId(2)), Some(RequestId(1)));
+        extensions.insert(CurrentUser("ana".to_string()));
+        extensions.insert(RetryCount(0));
+        extensions.get_mut::<RetryCount>().unwrap().0 += 2;
+        assert_eq!(extensions.len(), 3);
+        assert_eq!(extensions.get::<RetryCount>(), Some(&RetryCount(2)));
+        assert_eq!(handle_request(&extensions), "request #2: hello, ana");
+
+        assert_eq!(extensions.remove::<CurrentUser>(), Some(CurrentUser("ana".to_string())));
+        assert!(!extensions.contains::<CurrentUser>());
+        assert_eq!(extensions.get::<u64>(), None);
+    }
+
+    #[test]
+    fn downcasting_a_closed_set_misses_what_an_enum_match_catches() {
+        let tweet = Tweet { username: "a".to_string(), content: "one two three".to_string(), reply_to: None };
+        assert_eq!(word_count_by_downcast(&tweet), Some(3));
+        let item = PrintableItem::Tweet(tweet);
+        assert_eq!(word_count_by_downcast(&item), None);
+        assert_eq!(word_count(&item), Some(3));
+    }
+
+    #[test]
+    fn pipeline_rejects_unknown_plugins_before_running() {
+        let err = run_pipeline(&["squeeze", "shout"], "x").unwrap_err();
+        assert_eq!(err, UnknownPlugin("shout".to_string()));
+        assert_eq!(err.to_string(), "unknown plugin 'shout'");
+    }
+}
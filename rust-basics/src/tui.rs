@@ -0,0 +1,367 @@
+// ===== INTERACTIVE TUI =====
+//
+// `rust-basics tui` opens a full-screen menu built with ratatui + crossterm:
+//
+//   ┌ Modules ──────────┐┌ Output: traits ───────────────────────┐
+//   │ ▶ ✅ 1. Traits     ││ 🦀 RUST TRAIT SYSTEM DEEP STUDY 🦀    │
+//   │      2. Lifetimes  ││ ...                                   │
+//   └───────────────────┘└───────────────────────────────────────┘
+//    ↑/↓ select · Enter run · Tab output · q quit   1/11 completed this session
+//
+// HOW OUTPUT IS CAPTURED:
+// Learning modules build a report (LearningModule::report), which is rendered
+// straight into the pane; the background thread drives the report's future
+// on the CLI's tokio runtime. The capstone projects print to stdout, which
+// the TUI owns while it is open. Instead of redirecting stdout, a project
+// runs in a child process (this same executable with `run <module> --quiet`)
+// and its piped output is shown in the scrollable pane. Either way a background thread
+// does the work so the interface keeps responding while a module runs.
+//
+// "Completed" means the module ran to the end and exited successfully; the
+// set only lives for this session.
+
+use std::collections::HashSet;
+use std::io;
+use std::process::{Command, ExitStatus, Output, Stdio};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::Duration;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use tokio::runtime::Handle;
+
+use rust_basics::registry::{self, LearningModule};
+
+pub fn run() -> io::Result<()> {
+    ratatui::run(|terminal| App::new().run(terminal))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Focus {
+    Modules,
+    Output,
+}
+
+struct Running {
+    module: &'static dyn LearningModule,
+    result: Receiver<io::Result<Output>>,
+}
+
+struct App {
+    modules: ListState,
+    focus: Focus,
+    completed: HashSet<&'static str>,
+    output_title: String,
+    output: Vec<String>,
+    scroll: usize,
+    output_height: usize, // Rows visible in the output pane, updated on every draw
+    running: Option<Running>,
+    quit: bool,
+}
+
+impl App {
+    fn new() -> Self {
+        App {
+            modules: ListState::default().with_selected(Some(0)),
+            focus: Focus::Modules,
+            completed: HashSet::new(),
+            output_title: "Output".to_string(),
+            output: vec!["Select a module and press Enter to run it.".to_string()],
+            scroll: 0,
+            output_height: 0,
+            running: None,
+            quit: false,
+        }
+    }
+
+    fn run(mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.quit {
+            terminal.draw(|frame| self.draw(frame))?;
+            self.check_running();
+
+            // Poll with a timeout so a finished module shows up without a keypress
+            if event::poll(Duration::from_millis(100))?
+                && let Event::Key(key) = event::read()?
+            {
+                self.handle_key(key);
+            }
+        }
+        Ok(())
+    }
+
+    // ===== EVENTS =====
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match (self.focus, key.code) {
+            (_, KeyCode::Char('q') | KeyCode::Esc) => self.quit = true,
+            (_, KeyCode::Tab | KeyCode::BackTab) => {
+                self.focus = match self.focus {
+                    Focus::Modules => Focus::Output,
+                    Focus::Output => Focus::Modules,
+                }
+            }
+            (Focus::Modules, KeyCode::Up | KeyCode::Char('k')) => self.select(-1),
+            (Focus::Modules, KeyCode::Down | KeyCode::Char('j')) => self.select(1),
+            (Focus::Modules, KeyCode::Enter) => self.start_selected(),
+            (Focus::Output, KeyCode::Up | KeyCode::Char('k')) => self.scroll_by(-1),
+            (Focus::Output, KeyCode::Down | KeyCode::Char('j')) => self.scroll_by(1),
+            (Focus::Output, KeyCode::PageUp) => self.scroll_by(-(self.output_height as isize)),
+            (Focus::Output, KeyCode::PageDown) => self.scroll_by(self.output_height as isize),
+            (Focus::Output, KeyCode::Home | KeyCode::Char('g')) => self.scroll = 0,
+            (Focus::Output, KeyCode::End | KeyCode::Char('G')) => self.scroll = self.max_scroll(),
+            _ => {}
+        }
+    }
+
+    // Wraps around at both ends of the list
+    fn select(&mut self, delta: isize) {
+        let len = registry::modules().len() as isize;
+        let current = self.modules.selected().unwrap_or(0) as isize;
+        self.modules.select(Some((current + delta).rem_euclid(len) as usize));
+    }
+
+    fn selected_module(&self) -> &'static dyn LearningModule {
+        registry::modules()[self.modules.selected().unwrap_or(0)]
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.output.len().saturating_sub(self.output_height)
+    }
+
+    fn scroll_by(&mut self, delta: isize) {
+        self.scroll = self.scroll.saturating_add_signed(delta).min(self.max_scroll());
+    }
+
+    // ===== RUNNING A MODULE =====
+
+    fn start_selected(&mut self) {
+        if self.running.is_some() {
+            return; // One module at a time
+        }
+        let module = self.selected_module();
+        let (tx, rx) = mpsc::channel();
+        // The TUI is opened from inside the CLI's tokio::main
+        let runtime = Handle::current();
+
+        thread::spawn(move || {
+            let _ = tx.send(run_module(module, &runtime));
+        });
+
+        self.running = Some(Running { module, result: rx });
+        self.output_title = format!("Output: {} (running...)", module.name());
+        self.output = vec![format!("⏳ Running {}...", module.heading())];
+        self.scroll = 0;
+    }
+
+    fn check_running(&mut self) {
+        let Some(running) = &self.running else {
+            return;
+        };
+        let result = match running.result.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => Err(io::Error::other("runner thread exited")),
+        };
+        let module = running.module;
+        self.running = None;
+        self.finish_run(module, result);
+    }
+
+    fn finish_run(&mut self, module: &'static dyn LearningModule, result: io::Result<Output>) {
+        self.scroll = 0;
+        match result {
+            Ok(output) => {
+                let mut lines = to_lines(&output.stdout);
+                if !output.stderr.is_empty() {
+                    lines.push(String::new());
+                    lines.push("── stderr ──".to_string());
+                    lines.extend(to_lines(&output.stderr));
+                }
+                if output.status.success() {
+                    self.completed.insert(module.name());
+                    self.output_title = format!("Output: {} ✅", module.name());
+                } else {
+                    self.output_title = format!("Output: {} ❌ ({})", module.name(), output.status);
+                }
+                self.output = lines;
+            }
+            Err(err) => {
+                self.output_title = format!("Output: {} ❌", module.name());
+                self.output = vec![format!("Failed to start the module: {}", err)];
+            }
+        }
+    }
+
+    // ===== DRAWING =====
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [list_area, output_area] =
+            Layout::horizontal([Constraint::Length(34), Constraint::Min(0)]).areas(main);
+
+        let focused = |focus| {
+            if self.focus == focus {
+                Style::new().cyan()
+            } else {
+                Style::new()
+            }
+        };
+
+        let items: Vec<ListItem> = registry::modules()
+            .iter()
+            .map(|module| {
+                let mark = if self.completed.contains(module.name()) { "✅" } else { "  " };
+                ListItem::new(format!("{} {:>2}. {}", mark, module.number(), module.description()))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::bordered().title(" Modules ").border_style(focused(Focus::Modules)))
+            .highlight_symbol("▶ ")
+            .highlight_style(Style::new().bold().reversed());
+        frame.render_stateful_widget(list, list_area, &mut self.modules);
+
+        // Borders take two rows
+        self.output_height = output_area.height.saturating_sub(2) as usize;
+        self.scroll = self.scroll.min(self.max_scroll());
+        let visible: Vec<Line> = self
+            .output
+            .iter()
+            .skip(self.scroll)
+            .take(self.output_height)
+            .map(|line| Line::raw(line.as_str()))
+            .collect();
+        let output = Paragraph::new(visible).block(
+            Block::bordered()
+                .title(format!(" {} ", self.output_title))
+                .title_bottom(Line::from(format!(
+                    " {}/{} ",
+                    (self.scroll + self.output_height).min(self.output.len()),
+                    self.output.len()
+                )).right_aligned())
+                .border_style(focused(Focus::Output)),
+        );
+        frame.render_widget(output, output_area);
+
+        let help = match self.focus {
+            Focus::Modules => " ↑/↓ select · Enter run · Tab output · q quit",
+            Focus::Output => " ↑/↓ scroll · PgUp/PgDn page · g/G top/bottom · Tab modules · q quit",
+        };
+        let progress = format!(
+            "{}/{} completed this session ",
+            self.completed.len(),
+            registry::modules().len()
+        );
+        let [help_area, progress_area] = Layout::horizontal([
+            Constraint::Min(0),
+            Constraint::Length(progress.chars().count() as u16),
+        ])
+        .areas(footer);
+        frame.render_widget(Line::raw(help).dim(), help_area);
+        frame.render_widget(Line::raw(progress).green(), progress_area);
+    }
+}
+
+// A report needs no child process; it is shaped like one that exited cleanly
+fn run_module(module: &dyn LearningModule, runtime: &Handle) -> io::Result<Output> {
+    if let Some(report) = module.report() {
+        return Ok(Output {
+            status: ExitStatus::default(),
+            stdout: runtime.block_on(report).to_string().into_bytes(),
+            stderr: Vec::new(),
+        });
+    }
+    std::env::current_exe().and_then(|exe| {
+        Command::new(exe)
+            .args(["run", module.name(), "--quiet"])
+            .stdin(Stdio::null())
+            .output()
+    })
+}
+
+// Tabs are expanded because the terminal buffer treats them as one cell
+fn to_lines(bytes: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(bytes)
+        .lines()
+        .map(|line| line.replace('\t', "    "))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+    use std::os::unix::process::ExitStatusExt;
+
+    fn output(stdout: &str, code: i32) -> io::Result<Output> {
+        Ok(Output {
+            status: ExitStatus::from_raw(code << 8),
+            stdout: stdout.as_bytes().to_vec(),
+            stderr: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn selection_wraps_around() {
+        let mut app = App::new();
+        app.select(-1);
+        assert_eq!(app.modules.selected(), Some(registry::modules().len() - 1));
+        app.select(1);
+        assert_eq!(app.modules.selected(), Some(0));
+    }
+
+    #[test]
+    fn only_successful_runs_count_as_completed() {
+        let mut app = App::new();
+        let traits = registry::find("traits").unwrap();
+        let errors = registry::find("errors").unwrap();
+
+        app.finish_run(errors, output("partial\n", 101));
+        app.finish_run(traits, output("line 1\nline 2\n", 0));
+
+        assert_eq!(app.completed, HashSet::from(["traits"]));
+        assert_eq!(app.output, ["line 1", "line 2"]);
+    }
+
+    #[test]
+    fn modules_with_a_report_render_it_in_process() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        // errors awaits timers, so this also drives an async report
+        let errors = registry::find("errors").unwrap();
+        let output = run_module(errors, runtime.handle()).unwrap();
+        assert!(output.status.success());
+
+        let mut app = App::new();
+        app.finish_run(errors, Ok(output));
+        assert_eq!(app.output[0], "🦀 ERROR HANDLING DEEP STUDY 🦀");
+        assert!(app.output.iter().any(|line| line.starts_with("• WARN")));
+        assert!(app.completed.contains("errors"));
+    }
+
+    #[test]
+    fn scrolling_is_clamped_to_the_output() {
+        let mut app = App::new();
+        app.finish_run(registry::find("traits").unwrap(), output(&"x\n".repeat(50), 0));
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        assert_eq!(app.output_height, 9);
+
+        app.scroll_by(1_000);
+        assert_eq!(app.scroll, 50 - 9);
+        app.scroll_by(-1_000);
+        assert_eq!(app.scroll, 0);
+
+        let screen = format!("{:?}", terminal.backend().buffer());
+        assert!(screen.contains(&format!("1/{} completed this session", registry::modules().len())));
+    }
+}
@@ -0,0 +1,133 @@
+// ===== WEBASSEMBLY EXPORTS =====
+//
+// A few functions from traits.rs and errors.rs, exported to JavaScript with
+// wasm-bindgen so they run in a browser (harness: wasm/index.html).
+//
+// BUILDING IT:
+//   rustup target add wasm32-unknown-unknown
+//   cargo install wasm-bindgen-cli
+//   cargo build -p rust-basics --lib --release --target wasm32-unknown-unknown
+//   wasm-bindgen --target web --out-dir rust-basics/wasm/pkg \
+//       target/wasm32-unknown-unknown/release/rust_basics.wasm
+//   python3 -m http.server -d rust-basics/wasm     → http://localhost:8000
+//
+// CONDITIONAL COMPILATION:
+// • #[cfg(target_arch = "wasm32")] keeps an item only in the wasm build;
+//   cfg!(...) is the same test as a bool inside ordinary code
+// • #[cfg_attr(target_arch = "wasm32", wasm_bindgen)] adds the attribute only
+//   there - so the functions below are plain Rust everywhere else, and the
+//   native tests check them without a browser
+// • Cargo.toml does the same for dependencies: tokio, clap and ratatui are
+//   native-only, wasm-bindgen is wasm-only. lib.rs leaves out the modules
+//   built on tokio
+//
+// WHAT wasm32-unknown-unknown DOESN'T HAVE:
+// "unknown" means no operating system, so std compiles but some of it can't
+// work. Some calls return an error, others panic:
+// • Errors: fs (Unsupported), thread::available_parallelism, Backtrace
+//   (status Unsupported)
+// • Panics: thread::spawn, thread::sleep, Instant::now, SystemTime::now -
+//   the browser equivalents come from js-sys/web-sys
+// • Nothing to return to: main() and process::exit - exported functions are
+//   called by JavaScript instead
+// platform_report() below probes the ones that fail gracefully.
+
+use crate::errors::{self, report_error};
+use crate::traits::{self, Vector2D};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+// ===== 1. TRAITS =====
+
+// Names of the registered text plugins; inventory works in wasm too
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn plugin_names() -> Vec<String> {
+    traits::plugins().iter().map(|plugin| plugin.name().to_string()).collect()
+}
+
+// `pipeline` is a list of plugin names separated by spaces or commas.
+// Result<_, String> becomes a thrown exception in JavaScript
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn apply_plugins(input: &str, pipeline: &str) -> Result<String, String> {
+    let names: Vec<&str> = pipeline.split([' ', ',']).filter(|name| !name.is_empty()).collect();
+    traits::run_pipeline(&names, input).map_err(|e| e.to_string())
+}
+
+// The operator-overloading example: a + b, and the length of the sum
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn add_vectors(ax: f64, ay: f64, bx: f64, by: f64) -> String {
+    let sum = Vector2D::new(ax, ay) + Vector2D::new(bx, by);
+    format!("{:.2} + {:.2} = {:.2} (length {:.2})", Vector2D::new(ax, ay), Vector2D::new(bx, by), sum, sum.length())
+}
+
+// ===== 2. ERRORS =====
+
+// TaskError crosses into JavaScript as the report_error() text
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn check_user(name: &str, email: &str, age: &str) -> Result<String, String> {
+    errors::validate_user_input(name, email, age)
+        .map(|(name, email, age)| format!("{} <{}>, age {}", name, email, age))
+        .map_err(|e| report_error(&e))
+}
+
+// ===== 3. THE PLATFORM =====
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn platform_report() -> String {
+    let mut lines = vec![format!("target: {} / {}", std::env::consts::ARCH, std::env::consts::OS)];
+
+    // Compiles everywhere; without an OS it fails with ErrorKind::Unsupported
+    lines.push(match std::fs::metadata("Cargo.toml") {
+        Ok(_) => "filesystem: available".to_string(),
+        Err(e) => format!("filesystem: {} ({:?})", e, e.kind()),
+    });
+
+    lines.push(match std::thread::available_parallelism() {
+        Ok(threads) => format!("threads: {} available", threads),
+        Err(e) => format!("threads: {}", e),
+    });
+
+    lines.push(format!("backtraces: {:?}", std::backtrace::Backtrace::capture().status()));
+
+    // Calling SystemTime::now() here would panic, so don't even try
+    lines.push(if cfg!(target_arch = "wasm32") {
+        "clock: none in std - use js_sys::Date::now()".to_string()
+    } else {
+        match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            Ok(elapsed) => format!("clock: {}s since the Unix epoch", elapsed.as_secs()),
+            Err(e) => format!("clock: {}", e),
+        }
+    });
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exports_are_plain_rust_off_wasm() {
+        assert!(plugin_names().contains(&"rot13".to_string()));
+        assert_eq!(apply_plugins("hello   wasm", "squeeze, uppercase"), Ok("HELLO WASM".to_string()));
+        assert_eq!(apply_plugins("x", "uppercase shout"), Err("unknown plugin 'shout'".to_string()));
+        assert_eq!(add_vectors(3.0, 0.0, 0.0, 4.0), "(3.00, 0.00) + (0.00, 4.00) = (3.00, 4.00) (length 5.00)");
+    }
+
+    #[test]
+    fn errors_cross_as_reports() {
+        assert_eq!(check_user("Ada", "ada@example.com", "36"), Ok("Ada <ada@example.com>, age 36".to_string()));
+        assert_eq!(
+            check_user("", "ada@example.com", "36"),
+            Err("error: Invalid input: Name cannot be empty".to_string())
+        );
+    }
+
+    #[test]
+    fn native_platform_has_the_basics() {
+        let report = platform_report();
+        assert!(report.starts_with(&format!("target: {}", std::env::consts::ARCH)));
+        assert!(report.contains("clock: ") && !report.contains("js_sys"));
+    }
+}
@@ -0,0 +1,438 @@
+// ===== ZERO-COPY PARSING WITH COW =====
+//
+// A parser that returns String for every field copies the whole input into
+// fresh allocations, even though almost every field already sits in the
+// input, byte for byte. Only escaped fields - a CSV "" or a %2C in a URL -
+// differ from their source text. Cow<str> (see smart_pointers.rs) lets one
+// return type cover both:
+//
+//   input:  name,"says ""hi""",42
+//           ^^^^                 ^^   Cow::Borrowed - a slice of the input
+//                ^^^^^^^^^^^^^^       Cow::Owned    - unescaped into a String
+//
+// Both parsers below share their scanning code with an always-allocate
+// twin, so benches/zero_copy_benchmarks.rs measures the allocations and
+// nothing else.
+
+use std::borrow::Cow;
+
+use thiserror::Error;
+
+use crate::report::Report;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CsvError {
+    #[error("quote opened at column {column} is never closed")]
+    UnterminatedQuote { column: usize },
+
+    #[error("unexpected text after a closing quote at column {column}")]
+    TextAfterQuote { column: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum QueryError {
+    #[error("bad percent escape in {0:?}")]
+    BadEscape(String),
+
+    #[error("percent escapes in {0:?} don't decode to UTF-8")]
+    InvalidUtf8(String),
+}
+
+// Takes the Cow itself, not &str: which variant it is is the whole question
+#[allow(clippy::ptr_arg)]
+pub fn is_borrowed(value: &Cow<'_, str>) -> bool {
+    matches!(value, Cow::Borrowed(_))
+}
+
+// ===== 1. CSV FIELDS =====
+//
+// UNDERSTANDING THE FORMAT (RFC 4180, one line at a time):
+// • Fields are separated by commas: a,b,c
+// • A field containing a comma or a quote is wrapped in quotes, and a quote
+//   inside it is doubled: "say ""hi""" means: say "hi"
+// • An unquoted field, or a quoted one with no "" inside, IS a slice of the
+//   line - only the "" case needs a new String
+// • Quoted fields spanning several lines are out of scope here: the
+//   splitting into lines happens first
+//
+// UNDERSTANDING THE SPLIT:
+// • Fields (the iterator) finds where each field is and whether it needs
+//   unescaping, borrowing everything
+// • RawField::into_cow and RawField::into_string are the only difference
+//   between the zero-copy parser and the allocating one
+
+// Where a field is in the line, before any unescaping
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawField<'a> {
+    text: &'a str,
+    doubled_quotes: bool,
+}
+
+impl<'a> RawField<'a> {
+    pub fn into_cow(self) -> Cow<'a, str> {
+        if self.doubled_quotes { Cow::Owned(self.text.replace("\"\"", "\"")) } else { Cow::Borrowed(self.text) }
+    }
+
+    pub fn into_string(self) -> String {
+        if self.doubled_quotes { self.text.replace("\"\"", "\"") } else { self.text.to_string() }
+    }
+}
+
+pub struct Fields<'a> {
+    line: &'a str,
+    position: usize,
+    done: bool,
+}
+
+pub fn fields(line: &str) -> Fields<'_> {
+    Fields { line, position: 0, done: false }
+}
+
+impl<'a> Fields<'a> {
+    // A quoted field starting at self.position; the opening quote is skipped
+    fn quoted(&mut self) -> Result<RawField<'a>, CsvError> {
+        let bytes = self.line.as_bytes();
+        let start = self.position + 1;
+        let mut i = start;
+        let mut doubled_quotes = false;
+        loop {
+            match bytes[i..].iter().position(|&b| b == b'"') {
+                None => return Err(CsvError::UnterminatedQuote { column: self.position }),
+                Some(offset) if bytes.get(i + offset + 1) == Some(&b'"') => {
+                    doubled_quotes = true;
+                    i += offset + 2;
+                }
+                Some(offset) => {
+                    let close = i + offset;
+                    match bytes.get(close + 1) {
+                        None => self.done = true,
+                        Some(b',') => self.position = close + 2,
+                        Some(_) => return Err(CsvError::TextAfterQuote { column: close + 1 }),
+                    }
+                    return Ok(RawField { text: &self.line[start..close], doubled_quotes });
+                }
+            }
+        }
+    }
+
+    fn unquoted(&mut self) -> RawField<'a> {
+        let rest = &self.line[self.position..];
+        let text = match rest.find(',') {
+            Some(comma) => {
+                self.position += comma + 1;
+                &rest[..comma]
+            }
+            None => {
+                self.done = true;
+                rest
+            }
+        };
+        RawField { text, doubled_quotes: false }
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = Result<RawField<'a>, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if self.line[self.position..].starts_with('"') {
+            let field = self.quoted();
+            // Nothing after an error can be trusted
+            self.done |= field.is_err();
+            Some(field)
+        } else {
+            Some(Ok(self.unquoted()))
+        }
+    }
+}
+
+// Borrows every field that doesn't contain ""
+pub fn parse_csv_line(line: &str) -> Result<Vec<Cow<'_, str>>, CsvError> {
+    fields(line).map(|field| field.map(RawField::into_cow)).collect()
+}
+
+// The always-allocate twin: one String per field
+pub fn parse_csv_line_owned(line: &str) -> Result<Vec<String>, CsvError> {
+    fields(line).map(|field| field.map(RawField::into_string)).collect()
+}
+
+pub fn parse_csv(text: &str) -> Result<Vec<Vec<Cow<'_, str>>>, CsvError> {
+    text.lines().filter(|line| !line.is_empty()).map(parse_csv_line).collect()
+}
+
+pub fn parse_csv_owned(text: &str) -> Result<Vec<Vec<String>>, CsvError> {
+    text.lines().filter(|line| !line.is_empty()).map(parse_csv_line_owned).collect()
+}
+
+// ===== 2. QUERY STRINGS =====
+//
+// UNDERSTANDING application/x-www-form-urlencoded:
+// • key=value pairs joined by &: q=rust&page=2
+// • '+' means a space, %XX is a byte in hex: a%2Cb is "a,b"
+// • Decoded bytes must still be UTF-8: %C3%A9 is é, a lone %FF is not
+// • A component without '+' or '%' decodes to itself - borrow it
+
+fn needs_decoding(component: &str) -> bool {
+    component.bytes().any(|b| b == b'+' || b == b'%')
+}
+
+fn decode_escapes(component: &str) -> Result<String, QueryError> {
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let byte = component
+                    .get(i + 1..i + 3)
+                    // from_str_radix alone would accept "+1"
+                    .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or_else(|| QueryError::BadEscape(component.to_string()))?;
+                decoded.push(byte);
+                i += 2;
+            }
+            other => decoded.push(other),
+        }
+        i += 1;
+    }
+    String::from_utf8(decoded).map_err(|_| QueryError::InvalidUtf8(component.to_string()))
+}
+
+pub fn decode_component(component: &str) -> Result<Cow<'_, str>, QueryError> {
+    if needs_decoding(component) { decode_escapes(component).map(Cow::Owned) } else { Ok(Cow::Borrowed(component)) }
+}
+
+pub fn decode_component_owned(component: &str) -> Result<String, QueryError> {
+    if needs_decoding(component) { decode_escapes(component) } else { Ok(component.to_string()) }
+}
+
+// Empty segments (a&&b) are skipped; a key without '=' has an empty value
+fn pairs(query: &str) -> impl Iterator<Item = (&str, &str)> {
+    query.split('&').filter(|segment| !segment.is_empty()).map(|segment| segment.split_once('=').unwrap_or((segment, "")))
+}
+
+pub type QueryPair<'a> = (Cow<'a, str>, Cow<'a, str>);
+
+pub fn parse_query(query: &str) -> Result<Vec<QueryPair<'_>>, QueryError> {
+    pairs(query).map(|(key, value)| Ok((decode_component(key)?, decode_component(value)?))).collect()
+}
+
+pub fn parse_query_owned(query: &str) -> Result<Vec<(String, String)>, QueryError> {
+    pairs(query).map(|(key, value)| Ok((decode_component_owned(key)?, decode_component_owned(value)?))).collect()
+}
+
+// ===== 3. LIFETIMES: WHEN BORROWING ENDS =====
+//
+// UNDERSTANDING THE TRADE:
+// • Vec<Cow<'a, str>> borrows from the input: the input must outlive it.
+//   Parse a line from a reused read buffer and the fields can't be kept
+//   after the next read
+// • into_owned() detaches a Cow: Borrowed is copied, Owned moves for free,
+//   and the result has no lifetime - Cow<'static, str> or plain String
+// • So: parse borrowed, filter and look things up borrowed, and call
+//   into_owned() on just the fields you keep
+
+// Keeps the values of one column past the input's lifetime
+pub fn column_owned(text: &str, index: usize) -> Result<Vec<String>, CsvError> {
+    let rows = parse_csv(text)?;
+    Ok(rows.into_iter().filter_map(|row| row.into_iter().nth(index)).map(Cow::into_owned).collect())
+}
+
+// ===== 4. DEMONSTRATION FUNCTION =====
+
+pub const SAMPLE_CSV: &str = "\
+id,name,city,quote
+1,Ada Lovelace,London,\"The Analytical Engine weaves algebraic patterns\"
+2,Grace Hopper,\"Arlington, VA\",\"It's easier to ask forgiveness\"
+3,Alan Turing,Wilmslow,\"We can only see a short distance ahead\"
+4,Edsger Dijkstra,Nuenen,\"Simplicity is \"\"prerequisite\"\" for reliability\"
+";
+
+pub const SAMPLE_QUERY: &str = "q=zero+copy&lang=en&page=2&tags=rust%2Ccow&sort=date&user=ren%C3%A9";
+
+// "B" for each borrowed value, "O" for each owned one
+fn ownership_map<'a>(values: impl IntoIterator<Item = &'a Cow<'a, str>>) -> String {
+    values.into_iter().map(|value| if is_borrowed(value) { 'B' } else { 'O' }).collect()
+}
+
+pub fn zero_copy_report() -> Report {
+    let mut report = Report::new("🦀 ZERO-COPY PARSING WITH COW 🦀");
+
+    let section = report.section("1️⃣ CSV FIELDS (B = borrowed from the input, O = owned):");
+    match parse_csv(SAMPLE_CSV) {
+        Ok(rows) => {
+            for row in &rows {
+                section.example(ownership_map(row), format!("{:?}", row.iter().map(|field| field.as_ref()).collect::<Vec<_>>()));
+            }
+            let total: usize = rows.iter().map(Vec::len).sum();
+            let owned = rows.iter().flatten().filter(|field| !is_borrowed(field)).count();
+            section
+                .example("fields that needed an allocation (Cow)", format!("{} of {}", owned, total))
+                .example("fields that needed an allocation (String)", format!("{} of {}", total, total));
+        }
+        Err(err) => {
+            section.example("parse_csv(SAMPLE_CSV)", err);
+        }
+    }
+    for bad in ["a,\"open", "\"closed\"x,b"] {
+        section.example(format!("parse_csv_line({:?})", bad), format!("{:?}", parse_csv_line(bad).map_err(|err| err.to_string())));
+    }
+
+    let section = report.section("2️⃣ QUERY STRINGS:");
+    section.text(SAMPLE_QUERY);
+    match parse_query(SAMPLE_QUERY) {
+        Ok(pairs) => {
+            for (key, value) in &pairs {
+                section.example(format!("{} ({})", key, ownership_map([key, value])), value);
+            }
+        }
+        Err(err) => {
+            section.example("parse_query(SAMPLE_QUERY)", err);
+        }
+    }
+    for bad in ["x=%G1", "x=%FF"] {
+        section.example(format!("parse_query({:?})", bad), format!("{:?}", parse_query(bad).map_err(|err| err.to_string())));
+    }
+
+    let cities = column_owned(SAMPLE_CSV, 2).unwrap_or_default();
+    report
+        .section("3️⃣ KEEPING VALUES: into_owned():")
+        .example("column_owned(SAMPLE_CSV, 2) (Vec<String>, no borrow)", format!("{:?}", cities))
+        .bullet("parse borrowed, then into_owned() only what outlives the input");
+
+    report
+        .section("4️⃣ THE BENCHMARK:")
+        .text("cargo bench -p rust-basics --bench zero_copy_benchmarks")
+        .bullet("cow vs owned on mostly-plain input: the owned parser pays one allocation per field")
+        .bullet("cow vs owned on all-escaped input: both allocate, so the gap closes");
+
+    report
+        .section("🎯 ZERO-COPY SUMMARY:")
+        .check("Return Cow<str> when most outputs are slices of the input")
+        .check("Allocate only for the fields that actually change (escapes)")
+        .check("Keep the scanning code shared so borrowing vs owning is the only difference")
+        .check("The result borrows the input - into_owned() what must outlive it")
+        .check("Measure: when every field is escaped, Cow saves nothing");
+
+    report
+}
+
+pub fn demonstrate_zero_copy() {
+    print!("{}", zero_copy_report());
+}
+
+// ===== REGISTRATION =====
+//
+// Makes this module discoverable by the CLI (see registry.rs).
+
+use crate::registry::{DemoFuture, LearningModule, ModuleRegistration, ReportFuture};
+
+pub struct ZeroCopyModule;
+
+impl LearningModule for ZeroCopyModule {
+    fn number(&self) -> u8 {
+        24
+    }
+
+    fn name(&self) -> &'static str {
+        "zero_copy"
+    }
+
+    fn title(&self) -> &'static str {
+        "ZERO-COPY PARSING WITH COW"
+    }
+
+    fn description(&self) -> &'static str {
+        "Cow<str> CSV & Query Parsers"
+    }
+
+    fn prerequisites(&self) -> &'static [&'static str] {
+        &["smart_pointers", "parsing"]
+    }
+
+    fn run(&self) -> DemoFuture {
+        Box::pin(async { demonstrate_zero_copy() })
+    }
+
+    fn report(&self) -> Option<ReportFuture> {
+        Some(Box::pin(async { zero_copy_report() }))
+    }
+}
+
+inventory::submit! { ModuleRegistration(&ZeroCopyModule) }
+
+// ===== KEY TAKEAWAYS =====
+//
+// ZERO-COPY BEST PRACTICES:
+// 1. Scan with byte offsets and hand out &input[a..b] slices
+// 2. Decide per field whether it needs unescaping before allocating
+// 3. Let the return type say it: Cow<'a, str> ties the result to the input
+// 4. into_owned() at the boundary where values must live longer
+// 5. Benchmark on realistic input - the win depends on how rare escapes are
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_and_quoted_fields_are_borrowed() {
+        let line = "a,\"b, c\",,\"\"";
+        let parsed = parse_csv_line(line).unwrap();
+        assert_eq!(parsed, ["a", "b, c", "", ""]);
+        assert!(parsed.iter().all(is_borrowed));
+        // Borrowed means pointing into the line itself
+        assert!(std::ptr::eq(parsed[1].as_ptr(), line[3..].as_ptr()));
+
+        assert_eq!(parse_csv_line("").unwrap(), [""]);
+        assert_eq!(parse_csv_line("x,").unwrap(), ["x", ""]);
+    }
+
+    #[test]
+    fn doubled_quotes_allocate() {
+        let parsed = parse_csv_line("1,\"say \"\"hi\"\"\",2").unwrap();
+        assert_eq!(parsed, ["1", "say \"hi\"", "2"]);
+        assert_eq!(ownership_map(&parsed), "BOB");
+        assert_eq!(parse_csv_line("\"\"\"\"").unwrap(), ["\""]);
+    }
+
+    #[test]
+    fn malformed_lines_are_errors() {
+        assert_eq!(parse_csv_line("a,\"open"), Err(CsvError::UnterminatedQuote { column: 2 }));
+        assert_eq!(parse_csv_line("\"x\"y"), Err(CsvError::TextAfterQuote { column: 3 }));
+        assert!(parse_csv(SAMPLE_CSV).is_ok());
+    }
+
+    #[test]
+    fn query_strings_decode_only_when_needed() {
+        let pairs = parse_query("q=zero+copy&&lang=en&flag&name=ren%C3%A9").unwrap();
+        let flat: Vec<(&str, &str)> = pairs.iter().map(|(k, v)| (k.as_ref(), v.as_ref())).collect();
+        assert_eq!(flat, [("q", "zero copy"), ("lang", "en"), ("flag", ""), ("name", "rené")]);
+        assert_eq!(pairs.iter().filter(|(_, v)| is_borrowed(v)).count(), 2);
+
+        assert_eq!(decode_component("%2"), Err(QueryError::BadEscape("%2".to_string())));
+        assert_eq!(decode_component("%zz"), Err(QueryError::BadEscape("%zz".to_string())));
+        assert_eq!(decode_component("%+1"), Err(QueryError::BadEscape("%+1".to_string())));
+        assert_eq!(decode_component("%FF"), Err(QueryError::InvalidUtf8("%FF".to_string())));
+    }
+
+    #[test]
+    fn both_twins_agree() {
+        let cow = parse_csv(SAMPLE_CSV).unwrap();
+        let owned = parse_csv_owned(SAMPLE_CSV).unwrap();
+        assert_eq!(cow, owned);
+        assert_eq!(cow.len(), 5);
+        assert_eq!(cow[4][3], "Simplicity is \"prerequisite\" for reliability");
+
+        let cow = parse_query(SAMPLE_QUERY).unwrap();
+        let owned = parse_query_owned(SAMPLE_QUERY).unwrap();
+        assert!(cow.iter().zip(&owned).all(|((k, v), (ok, ov))| k == ok && v == ov));
+        assert_eq!(parse_csv_line_owned("a,\"b\"\"\"").unwrap(), ["a", "b\""]);
+
+        assert_eq!(column_owned(SAMPLE_CSV, 2).unwrap(), ["city", "London", "Arlington, VA", "Wilmslow", "Nuenen"]);
+    }
+}
@@ -0,0 +1,16 @@
+// ===== SHARED TEST FIXTURES =====
+//
+// Files directly in tests/ are each compiled as a separate test crate.
+// Helpers in tests/common/mod.rs are NOT a test crate; each integration test
+// pulls them in with `mod common;`.
+
+use rust_basics::testing::Inventory;
+
+// A small, known inventory for tests to start from
+pub fn stocked_inventory() -> Inventory {
+    let mut inventory = Inventory::new();
+    inventory.add("apple", 5);
+    inventory.add("pear", 2);
+    inventory.add("plum", 10);
+    inventory
+}
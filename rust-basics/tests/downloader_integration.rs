@@ -0,0 +1,62 @@
+// ===== DOWNLOADER INTEGRATION TESTS =====
+//
+// The downloader against real HTTP on localhost: the fixture server binds to
+// port 0, so tests can run in parallel.
+
+use rust_basics::errors::{Jitter, RetryPolicy};
+use rust_basics::projects::downloader::{
+    DownloadConfig, Failures, Fetch, FetchError, HttpFetcher, download_all, spawn_fixture_server,
+};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+fn config(concurrency: usize) -> DownloadConfig {
+    let retry = RetryPolicy { initial_delay: Duration::from_millis(1), jitter: Jitter::None, ..RetryPolicy::default() };
+    DownloadConfig { concurrency, retry }
+}
+
+#[tokio::test]
+async fn fetches_bodies_and_statuses() {
+    let server = spawn_fixture_server().await.unwrap();
+    let fetcher = HttpFetcher::default();
+    assert_eq!(fetcher.fetch(&server.url("/bytes/5")).await.unwrap(), b"xxxxx");
+    assert!(matches!(fetcher.fetch(&server.url("/status/500")).await, Err(FetchError::Status(500))));
+    assert!(matches!(fetcher.fetch(&server.url("/nowhere")).await, Err(FetchError::Status(404))));
+
+    let slow = HttpFetcher { timeout: Duration::from_millis(20) };
+    assert!(matches!(slow.fetch(&server.url("/slow/200")).await, Err(FetchError::Timeout(_))));
+    server.shutdown().await;
+}
+
+#[tokio::test]
+async fn concurrency_shortens_the_run() {
+    let server = spawn_fixture_server().await.unwrap();
+    let urls: Vec<String> = (0..6).map(|_| server.url("/slow/50")).collect();
+    let urls: Vec<&str> = urls.iter().map(String::as_str).collect();
+
+    let start = Instant::now();
+    let report = download_all(Arc::new(HttpFetcher::default()), &urls, config(6)).await;
+    assert_eq!(report.succeeded.len(), 6);
+    // Serially this is 300ms; all at once it's one 50ms wait plus overhead
+    assert!(start.elapsed() < Duration::from_millis(250), "{:?}", start.elapsed());
+    assert_eq!(server.shutdown().await, 6);
+}
+
+#[tokio::test]
+async fn retries_then_reports_what_failed() {
+    let server = spawn_fixture_server().await.unwrap();
+    let urls = [server.url("/flaky/2/a"), server.url("/status/404"), server.url("/flaky/9/b"), server.url("/bytes/3")];
+    let urls: Vec<&str> = urls.iter().map(String::as_str).collect();
+    let report = download_all(Arc::new(HttpFetcher::default()), &urls, config(2)).await;
+
+    let attempts: Vec<_> = report.succeeded.iter().map(|d| (d.bytes.as_slice(), d.attempts)).collect();
+    assert_eq!(attempts, [(b"a recovered".as_slice(), 3), (b"xxx".as_slice(), 1)]);
+
+    let error = report.into_result().unwrap_err();
+    assert_eq!(error.to_string(), "2 of 4 downloads failed");
+    let failures = &error.downcast_ref::<Failures>().unwrap().0;
+    assert!(matches!(failures[0].error, FetchError::Status(404)));
+    assert!(matches!(failures[1].error, FetchError::Status(503)));
+    // 1 + 1 + 4 (max_attempts) + 1 requests
+    assert_eq!(server.shutdown().await, 3 + 1 + 4 + 1);
+}
@@ -0,0 +1,86 @@
+// ===== KV STORE INTEGRATION TESTS =====
+//
+// The kv_store capstone end to end: real sockets on localhost (port 0, so
+// tests can run in parallel) against both store implementations.
+
+use rust_basics::projects::kv_store::{KvClient, KvError, LockedStore, ManualClock, ShardedStore, Storage, serve};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn concurrent_clients_share_one_store() {
+    let store = Arc::new(ShardedStore::new(8));
+    let server = serve(Arc::clone(&store), "127.0.0.1:0").unwrap();
+    let clients: Vec<_> = (0..8)
+        .map(|n| {
+            let addr = server.addr();
+            thread::spawn(move || {
+                let mut client = KvClient::connect(addr).unwrap();
+                for i in 0..25 {
+                    client.set(&format!("c{}-{}", n, i), &format!("value {}", i), None).unwrap();
+                }
+                assert_eq!(client.get(&format!("c{}-7", n)).unwrap().as_deref(), Some("value 7"));
+                assert!(client.delete(&format!("c{}-0", n)).unwrap());
+                assert!(!client.delete(&format!("c{}-0", n)).unwrap());
+                client.quit().unwrap();
+            })
+        })
+        .collect();
+    for client in clients {
+        client.join().unwrap();
+    }
+    assert_eq!(store.len(), 8 * 24);
+    assert_eq!(server.shutdown(), 8);
+}
+
+#[test]
+fn ttl_over_tcp_follows_the_servers_clock() {
+    let clock = Arc::new(ManualClock::default());
+    let store = Arc::new(LockedStore::with_clock(Arc::clone(&clock)));
+    let server = serve(store, "127.0.0.1:0").unwrap();
+    let mut client = KvClient::connect(server.addr()).unwrap();
+
+    client.set("session", "abc", Some(Duration::from_secs(60))).unwrap();
+    client.set("user", "ada", None).unwrap();
+    clock.advance(Duration::from_secs(59));
+    assert_eq!(client.get("session").unwrap().as_deref(), Some("abc"));
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(client.get("session").unwrap(), None);
+    assert_eq!(client.count().unwrap(), 1);
+    client.quit().unwrap();
+    server.shutdown();
+}
+
+#[test]
+fn protocol_errors_keep_the_connection_open() {
+    let server = serve(Arc::new(LockedStore::new()), "127.0.0.1:0").unwrap();
+    let mut client = KvClient::connect(server.addr()).unwrap();
+
+    assert!(matches!(client.request("FLY away"), Err(KvError::Server(m)) if m == "unknown command 'FLY'"));
+    assert!(matches!(client.request("SETEX k soon v"), Err(KvError::Server(_))));
+    assert!(matches!(client.request(""), Err(KvError::Server(m)) if m == "empty command"));
+    // Rejected by the client before anything is sent
+    assert!(matches!(client.set("two words", "v", None), Err(KvError::InvalidKey(_))));
+    assert!(matches!(client.set("k", "line\nbreak", None), Err(KvError::Usage(_))));
+
+    client.ping().unwrap();
+    client.set("k", "still here", None).unwrap();
+    assert_eq!(client.get("k").unwrap().as_deref(), Some("still here"));
+    client.quit().unwrap();
+    server.shutdown();
+}
+
+#[test]
+fn raw_session_matches_the_documented_protocol() {
+    let server = serve(Arc::new(ShardedStore::new(2)), "127.0.0.1:0").unwrap();
+    let mut stream = TcpStream::connect(server.addr()).unwrap();
+    stream.write_all(b"set a hello world\nGET a\nsetex b 10 x\nLEN\ndel a\nget a\nquit\nPING\n").unwrap();
+
+    let replies: Vec<String> = BufReader::new(stream).lines().map(Result::unwrap).collect();
+    // The server stops reading after BYE, so the trailing PING gets no reply
+    assert_eq!(replies, ["OK", "VALUE hello world", "OK", "INT 2", "INT 1", "NIL", "BYE"]);
+    server.shutdown();
+}
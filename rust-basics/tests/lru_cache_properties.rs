@@ -0,0 +1,69 @@
+// ===== LRU CACHE PROPERTY TESTS =====
+//
+// MODEL-BASED TESTING: proptest generates random sequences of operations,
+// runs each against the arena LruCache and the obviously-correct VecLru, and
+// requires identical answers after every step. A failure shrinks to the
+// shortest sequence that still disagrees.
+
+use proptest::prelude::*;
+use rust_basics::lru_cache::{LruCache, VecLru};
+
+#[derive(Debug, Clone)]
+enum Op {
+    Get(u8),
+    Peek(u8),
+    Put(u8, u16),
+    Remove(u8),
+    PopLru,
+}
+
+// A small key space, so gets hit, puts collide and evictions happen
+fn op() -> impl Strategy<Value = Op> {
+    let key = 0u8..8;
+    prop_oneof![
+        3 => key.clone().prop_map(Op::Get),
+        1 => key.clone().prop_map(Op::Peek),
+        4 => (key.clone(), any::<u16>()).prop_map(|(k, v)| Op::Put(k, v)),
+        1 => key.prop_map(Op::Remove),
+        1 => Just(Op::PopLru),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn matches_the_reference_model(capacity in 0usize..6, ops in prop::collection::vec(op(), 0..200)) {
+        let mut cache = LruCache::new(capacity);
+        let mut model = VecLru::new(capacity);
+
+        for op in ops {
+            match op {
+                Op::Get(k) => prop_assert_eq!(cache.get(&k), model.get(&k), "get({})", k),
+                Op::Peek(k) => prop_assert_eq!(cache.peek(&k), model.peek(&k), "peek({})", k),
+                Op::Put(k, v) => prop_assert_eq!(cache.put(k, v), model.put(k, v), "put({}, {})", k, v),
+                Op::Remove(k) => prop_assert_eq!(cache.remove(&k), model.remove(&k), "remove({})", k),
+                Op::PopLru => prop_assert_eq!(cache.pop_lru(), model.pop_lru()),
+            }
+
+            // Walking forwards checks the next links, backwards the prev links
+            prop_assert_eq!(cache.iter().collect::<Vec<_>>(), model.iter().collect::<Vec<_>>());
+            prop_assert_eq!(cache.iter().rev().collect::<Vec<_>>(), model.iter().rev().collect::<Vec<_>>());
+            prop_assert_eq!(cache.len(), model.len());
+            prop_assert!(cache.len() <= capacity);
+        }
+    }
+
+    // Mixing next() and next_back() meets in the middle without repeats
+    #[test]
+    fn iter_from_both_ends(keys in prop::collection::vec(0u8..20, 0..20), fronts in 0usize..20) {
+        let mut cache = LruCache::new(10);
+        for k in keys {
+            cache.put(k, ());
+        }
+        let all: Vec<u8> = cache.iter().map(|(k, _)| *k).collect();
+        let mut iter = cache.iter();
+        let front: Vec<u8> = iter.by_ref().take(fronts).map(|(k, _)| *k).collect();
+        let mut back: Vec<u8> = iter.rev().map(|(k, _)| *k).collect();
+        back.reverse();
+        prop_assert_eq!([front, back].concat(), all);
+    }
+}
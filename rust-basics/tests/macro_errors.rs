@@ -0,0 +1,12 @@
+// ===== COMPILE-FAIL TESTS =====
+//
+// trybuild compiles each file in tests/ui on its own and checks that it
+// fails with exactly the error in the .stderr file next to it.
+// After changing a message, regenerate them with:
+//   TRYBUILD=overwrite cargo test -p rust-basics --test macro_errors
+
+#[test]
+fn settings_rejects_what_it_cannot_parse() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/settings_*.rs");
+}
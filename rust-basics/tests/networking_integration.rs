@@ -0,0 +1,73 @@
+// ===== NETWORKING INTEGRATION TESTS =====
+//
+// Real sockets on localhost. Every server binds to port 0, so the OS picks a
+// free port and tests can run in parallel.
+
+use rust_basics::networking::{
+    MAX_FRAME_LEN, echo_async, echo_blocking, read_frame, read_frame_async, spawn_echo_server,
+    spawn_echo_server_async, write_frame_async,
+};
+use std::io::Write;
+use std::net::TcpStream;
+
+#[test]
+fn blocking_client_and_server() {
+    let server = spawn_echo_server("127.0.0.1:0").unwrap();
+    assert_eq!(echo_blocking(server.addr(), &["ping", "", "pong"]).unwrap(), ["ping", "", "pong"]);
+    assert_eq!(echo_blocking(server.addr(), &[]).unwrap(), Vec::<String>::new());
+    assert_eq!(server.shutdown(), 3);
+}
+
+#[tokio::test]
+async fn async_client_and_server() {
+    let server = spawn_echo_server_async("127.0.0.1:0").await.unwrap();
+    let clients: Vec<_> = (0..10)
+        .map(|n| {
+            let addr = server.addr();
+            tokio::spawn(async move { echo_async(addr, &[&n.to_string(), "done"]).await.unwrap() })
+        })
+        .collect();
+    for (n, client) in clients.into_iter().enumerate() {
+        assert_eq!(client.await.unwrap(), [n.to_string(), "done".to_string()]);
+    }
+    assert_eq!(server.shutdown().await, 20);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn the_two_implementations_speak_the_same_protocol() {
+    let blocking = spawn_echo_server("127.0.0.1:0").unwrap();
+    let replies = echo_async(blocking.addr(), &["async → blocking"]).await.unwrap();
+    assert_eq!(replies, ["async → blocking"]);
+
+    let async_server = spawn_echo_server_async("127.0.0.1:0").await.unwrap();
+    let addr = async_server.addr();
+    let replies = tokio::task::spawn_blocking(move || echo_blocking(addr, &["blocking → async"]))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(replies, ["blocking → async"]);
+
+    assert_eq!(tokio::task::spawn_blocking(move || blocking.shutdown()).await.unwrap(), 1);
+    assert_eq!(async_server.shutdown().await, 1);
+}
+
+#[test]
+fn oversized_frames_close_the_connection() {
+    let server = spawn_echo_server("127.0.0.1:0").unwrap();
+    let mut stream = TcpStream::connect(server.addr()).unwrap();
+    stream.write_all(&((MAX_FRAME_LEN + 1) as u32).to_be_bytes()).unwrap();
+    // The server drops the connection without echoing anything
+    assert!(!matches!(read_frame(&mut stream), Ok(Some(_))));
+    assert_eq!(server.shutdown(), 0);
+}
+
+#[tokio::test]
+async fn async_shutdown_closes_idle_connections_cleanly() {
+    let server = spawn_echo_server_async("127.0.0.1:0").await.unwrap();
+    let (mut reader, mut writer) = tokio::net::TcpStream::connect(server.addr()).await.unwrap().into_split();
+    write_frame_async(&mut writer, b"hello").await.unwrap();
+    assert_eq!(read_frame_async(&mut reader).await.unwrap().as_deref(), Some(&b"hello"[..]));
+
+    assert_eq!(server.shutdown().await, 1);
+    assert_eq!(read_frame_async(&mut reader).await.unwrap(), None, "EOF, not a reset");
+}
@@ -0,0 +1,42 @@
+// ===== INTEGRATION TESTS =====
+//
+// This file is compiled as its own crate: it can only use rust_basics'
+// PUBLIC API, exactly like a downstream user would.
+
+mod common;
+
+use rust_basics::testing::{DurationParseError, fibonacci_iterative, parse_duration};
+use std::time::Duration;
+
+#[test]
+fn parse_duration_through_public_api() {
+    assert_eq!(parse_duration("1h1m1s"), Ok(Duration::from_secs(3661)));
+    assert!(matches!(parse_duration("3d"), Err(DurationParseError::UnknownUnit(unit)) if unit == "d"));
+}
+
+#[test]
+fn inventory_workflow() {
+    let mut inventory = common::stocked_inventory();
+
+    inventory.add("apple", 1);
+    assert_eq!(inventory.quantity("apple"), 6);
+
+    assert_eq!(inventory.remove("plum", 10), 0);
+    assert_eq!(inventory.quantity("plum"), 0);
+    assert_eq!(inventory.total_items(), 8);
+}
+
+#[test]
+#[should_panic(expected = "insufficient stock")]
+fn inventory_rejects_overdraw() {
+    let mut inventory = common::stocked_inventory();
+    inventory.remove("kiwi", 1);
+}
+
+#[test]
+fn fibonacci_known_values() {
+    let expected = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34];
+    for (n, &value) in expected.iter().enumerate() {
+        assert_eq!(fibonacci_iterative(n as u32), value, "fib({})", n);
+    }
+}
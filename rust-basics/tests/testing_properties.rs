@@ -0,0 +1,45 @@
+// ===== PROPERTY-BASED TESTS =====
+//
+// proptest generates random inputs for each test and, on failure, shrinks
+// the input to the smallest case that still fails.
+
+use proptest::prelude::*;
+use rust_basics::testing::{
+    fibonacci_iterative, fibonacci_recursive, parse_duration, run_length_decode, run_length_encode,
+};
+use std::time::Duration;
+
+proptest! {
+    // ROUND TRIP: decoding an encoding gives back the original
+    #[test]
+    fn run_length_round_trip(input in "\\PC*") {
+        prop_assert_eq!(run_length_decode(&run_length_encode(&input)), input);
+    }
+
+    // INVARIANT: runs are non-empty and neighbours differ
+    #[test]
+    fn run_length_is_canonical(input in "[ab]{0,50}") {
+        let runs = run_length_encode(&input);
+        prop_assert!(runs.iter().all(|&(_, count)| count > 0));
+        prop_assert!(runs.windows(2).all(|pair| pair[0].0 != pair[1].0));
+    }
+
+    // ORACLE: any formatted duration parses back to the same value
+    #[test]
+    fn formatted_durations_parse_back(h in 0u64..100, m in 0u64..60, s in 0u64..60) {
+        let text = format!("{}h{}m{}s", h, m, s);
+        prop_assert_eq!(parse_duration(&text), Ok(Duration::from_secs(h * 3600 + m * 60 + s)));
+    }
+
+    // ROBUSTNESS: arbitrary input never panics
+    #[test]
+    fn parse_duration_never_panics(input in "[0-9hms ]{0,12}") {
+        let _ = parse_duration(&input);
+    }
+
+    // EQUIVALENCE: two implementations agree
+    #[test]
+    fn fibonacci_implementations_agree(n in 0u32..20) {
+        prop_assert_eq!(fibonacci_recursive(n), fibonacci_iterative(n));
+    }
+}
@@ -0,0 +1,4 @@
+// The entries before the bad one parse; the error names the bad token
+fn main() {
+    let _ = rust_basics::settings! { port = 8080, verbose, 42 };
+}
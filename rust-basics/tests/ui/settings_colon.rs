@@ -0,0 +1,4 @@
+// A `:` where settings! expects `=`
+fn main() {
+    let _ = rust_basics::settings! { port: 8080 };
+}
@@ -0,0 +1,4 @@
+// Keys are identifiers, not strings
+fn main() {
+    let _ = rust_basics::settings! { "port" = 8080 };
+}
@@ -0,0 +1,4 @@
+// Two bare keys need a `,` between them
+fn main() {
+    let _ = rust_basics::settings! { verbose debug };
+}